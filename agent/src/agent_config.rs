@@ -19,6 +19,7 @@ use common::DEFAULT_SERVER_ADDRESS;
 use grpc::security::read_pem_file;
 
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fmt;
 use std::fs::read_to_string;
 use std::path::PathBuf;
@@ -75,6 +76,8 @@ pub struct AgentConfig {
     pub run_folder: String,
     #[serde(default)]
     pub insecure: bool,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
     ca_pem: Option<String>,
     crt_pem: Option<String>,
     key_pem: Option<String>,
@@ -91,6 +94,7 @@ impl Default for AgentConfig {
             server_url: get_default_url(),
             run_folder: get_default_run_folder(),
             insecure: bool::default(),
+            labels: HashMap::new(),
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
@@ -158,6 +162,9 @@ impl AgentConfig {
             self.insecure = insecure;
         }
 
+        // [impl->swdd~agent-supports-labels~2]
+        self.labels.extend(args.labels.iter().cloned());
+
         if let Some(ca_pem_path) = &args.ca_pem {
             self.ca_pem = Some(ca_pem_path.to_owned());
             let ca_pem_content = read_pem_file(ca_pem_path, false).unwrap_or_default();
@@ -186,6 +193,7 @@ impl AgentConfig {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::io::Write;
     use std::path::PathBuf;
     use tempfile::NamedTempFile;
@@ -277,6 +285,7 @@ mod tests {
             ca_pem: Some(CA_PEM_PATH.to_string()),
             crt_pem: Some(CRT_PEM_PATH.to_string()),
             key_pem: Some(KEY_PEM_PATH.to_string()),
+            labels: vec![("zone".to_string(), "front".to_string())],
         };
 
         agent_config.update_with_args(&args);
@@ -288,6 +297,52 @@ mod tests {
         assert_eq!(agent_config.ca_pem, Some(CA_PEM_PATH.to_string()));
         assert_eq!(agent_config.crt_pem, Some(CRT_PEM_PATH.to_string()));
         assert_eq!(agent_config.key_pem, Some(KEY_PEM_PATH.to_string()));
+        assert_eq!(
+            agent_config.labels,
+            HashMap::from([("zone".to_string(), "front".to_string())])
+        );
+    }
+
+    // [utest->swdd~agent-supports-labels~2]
+    #[test]
+    fn utest_agent_config_update_with_args_merges_labels() {
+        let agent_config_content = r"#
+        version = 'v1'
+        [labels]
+        zone = 'front'
+        arch = 'aarch64'
+        #";
+
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{}", agent_config_content).unwrap();
+
+        let mut agent_config =
+            AgentConfig::from_file(PathBuf::from(tmp_config_file.path())).unwrap();
+        let args = Arguments {
+            config_path: None,
+            agent_name: None,
+            server_url: None,
+            run_folder: None,
+            insecure: None,
+            ca_pem: None,
+            crt_pem: None,
+            key_pem: None,
+            labels: vec![
+                ("zone".to_string(), "rear".to_string()),
+                ("gpu".to_string(), "false".to_string()),
+            ],
+        };
+
+        agent_config.update_with_args(&args);
+
+        assert_eq!(
+            agent_config.labels,
+            HashMap::from([
+                ("zone".to_string(), "rear".to_string()),
+                ("arch".to_string(), "aarch64".to_string()),
+                ("gpu".to_string(), "false".to_string()),
+            ])
+        );
     }
 
     // [utest->swdd~agent-loads-config-file~1]
@@ -317,6 +372,7 @@ mod tests {
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
+            labels: vec![],
         };
 
         agent_config.update_with_args(&args);
@@ -28,7 +28,8 @@ use crate::workload_state::workload_state_store::WorkloadStateStore;
 use crate::runtime_manager::RuntimeManager;
 use crate::workload_state::WorkloadStateReceiver;
 
-const RESOURCE_MEASUREMENT_INTERVAL_TICK: std::time::Duration = tokio::time::Duration::from_secs(2);
+const RESOURCE_MEASUREMENT_INTERVAL_TICK: std::time::Duration =
+    tokio::time::Duration::from_millis(common::AGENT_LOAD_STATUS_INTERVAL_MS);
 
 struct ResourceMonitor {
     refresh_kind: RefreshKind,
@@ -189,10 +190,42 @@ impl AgentManager {
 
                 Some(())
             }
+            FromServer::WorkloadLogsRequest(logs_request) => {
+                log::debug!(
+                    "Agent '{}' received WorkloadLogsRequest: {:?}",
+                    self.agent_name,
+                    logs_request
+                );
+
+                // [impl->swdd~agent-starts-log-collection-for-workload-logs-requests~1]
+                self.runtime_manager
+                    .start_log_collection(logs_request)
+                    .await;
+                Some(())
+            }
+            FromServer::WorkloadLogsCancel(logs_cancel) => {
+                log::debug!(
+                    "Agent '{}' received WorkloadLogsCancel: {:?}",
+                    self.agent_name,
+                    logs_cancel
+                );
+
+                // [impl->swdd~agent-stops-log-collection-on-workload-logs-cancel~1]
+                self.runtime_manager
+                    .stop_log_collection(&logs_cancel.request_id);
+                Some(())
+            }
             FromServer::Stop(_method_obj) => {
                 log::debug!("Agent '{}' received Stop from server", self.agent_name);
                 None
             }
+            FromServer::DisconnectAgent(_method_obj) => {
+                log::warn!(
+                    "Agent '{}' received unexpected DisconnectAgent from server",
+                    self.agent_name
+                );
+                Some(())
+            }
         }
     }
 
@@ -274,7 +307,7 @@ mod tests {
     };
     use api::ank_base;
     use common::{
-        commands::UpdateWorkloadState,
+        commands::{UpdateWorkloadState, WorkloadLogsRequest},
         from_server_interface::FromServerInterface,
         objects::{generate_test_workload_spec_with_param, ExecutionState},
         to_server_interface::ToServer,
@@ -484,6 +517,71 @@ mod tests {
         assert!(join!(handle).0.is_ok());
     }
 
+    // [utest->swdd~agent-starts-log-collection-for-workload-logs-requests~1]
+    // [utest->swdd~agent-stops-log-collection-on-workload-logs-cancel~1]
+    #[tokio::test]
+    async fn utest_agent_manager_starts_and_stops_log_collection() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_wl_state_store_context = MockWorkloadStateStore::default();
+        mock_parameter_storage_new_returns(mock_wl_state_store_context);
+
+        let (to_manager, manager_receiver) = channel(BUFFER_SIZE);
+        let (to_server, _) = channel(BUFFER_SIZE);
+        let (_workload_state_sender, workload_state_receiver) = channel(BUFFER_SIZE);
+
+        let logs_request = WorkloadLogsRequest {
+            request_id: REQUEST_ID.to_string(),
+            instance_name: generate_test_workload_spec_with_param(
+                AGENT_NAME.to_string(),
+                WORKLOAD_1_NAME.to_string(),
+                RUNTIME_NAME.to_string(),
+            )
+            .instance_name,
+            runtime: RUNTIME_NAME.to_string(),
+            follow: true,
+            since: None,
+            tail: None,
+        };
+
+        let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_start_log_collection()
+            .with(eq(logs_request.clone()))
+            .once()
+            .return_const(());
+        mock_runtime_manager
+            .expect_stop_log_collection()
+            .with(eq(REQUEST_ID))
+            .once()
+            .return_const(());
+
+        let mut agent_manager = AgentManager::new(
+            AGENT_NAME.to_string(),
+            manager_receiver,
+            mock_runtime_manager,
+            to_server,
+            workload_state_receiver,
+        );
+
+        let handle = tokio::spawn(async move { agent_manager.start().await });
+
+        to_manager
+            .workload_logs_request(logs_request)
+            .await
+            .unwrap();
+        to_manager
+            .workload_logs_cancel(REQUEST_ID.to_string(), AGENT_NAME.to_string())
+            .await
+            .unwrap();
+
+        // Terminate the infinite receiver loop
+        to_manager.stop().await.unwrap();
+        assert!(join!(handle).0.is_ok());
+    }
+
     // [utest->swdd~agent-manager-receives-workload-states-of-its-workloads~1]
     // [utest->swdd~agent-stores-workload-states-of-its-workloads~1]
     // [utest->swdd~agent-sends-workload-states-of-its-workloads-to-server~2]
@@ -16,6 +16,7 @@ use clap::{ArgAction, Parser};
 
 // [impl->swdd~agent-supports-cli-argument-for-insecure-communication~1]
 // [impl->swdd~agent-supports-pem-file-paths-as-cli-arguments~1]
+// [impl->swdd~agent-supports-labels~2]
 #[derive(Parser, Debug)]
 #[clap( author="The Ankaios team",
         version=env!("CARGO_PKG_VERSION"),
@@ -48,8 +49,61 @@ pub struct Arguments {
     #[clap(long = "key_pem", env = "ANKAGENT_KEY_PEM")]
     /// Path to agent key pem file.
     pub key_pem: Option<String>,
+    #[clap(short = 'l', long = "label", value_parser = parse_label)]
+    /// Label of the agent formatted as: "--label key1=value1 --label key2=value2".
+    /// Labels given here overwrite the labels with the same key from the agent config file.
+    pub labels: Vec<(String, String)>,
+}
+
+fn parse_label(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid KEY=value: no `=` found in `{s}`"))?;
+    if key.is_empty() {
+        return Err(format!("invalid KEY=value: empty key in `{s}`"));
+    }
+    Ok((key.to_string(), value.to_string()))
 }
 
 pub fn parse() -> Arguments {
     Arguments::parse()
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::parse_label;
+
+    // [utest->swdd~agent-supports-labels~2]
+    #[test]
+    fn utest_parse_label() {
+        assert_eq!(
+            parse_label("zone=front=left"),
+            Ok(("zone".to_string(), "front=left".to_string()))
+        );
+        assert_eq!(
+            parse_label("zone="),
+            Ok(("zone".to_string(), String::new()))
+        );
+    }
+
+    // [utest->swdd~agent-supports-labels~2]
+    #[test]
+    fn utest_parse_label_rejects_missing_separator_and_empty_key() {
+        assert_eq!(
+            parse_label("zone"),
+            Err("invalid KEY=value: no `=` found in `zone`".to_string())
+        );
+        assert_eq!(
+            parse_label("=front"),
+            Err("invalid KEY=value: empty key in `=front`".to_string())
+        );
+    }
+}
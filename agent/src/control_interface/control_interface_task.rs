@@ -125,6 +125,7 @@ impl ControlInterfaceTask {
                                         request_id: request.request_id,
                                         response_content: Some(ank_base::response::ResponseContent::Error(ank_base::Error {
                                             message: "Access denied".into(),
+                                            ..Default::default()
                                         })),
                                     };
                                     let _ = self.forward_from_server(error).await;
@@ -252,6 +253,7 @@ mod tests {
             request_content: Some(ank_base::request::RequestContent::CompleteStateRequest(
                 ank_base::CompleteStateRequest {
                     field_mask: vec![field_mask.into()],
+                    label_selector: None,
                 },
             )),
         };
@@ -325,7 +327,10 @@ mod tests {
                 ank_base::Request {
                     request_id: REQUEST_ID.into(),
                     request_content: Some(ank_base::request::RequestContent::CompleteStateRequest(
-                        ank_base::CompleteStateRequest { field_mask: vec![] },
+                        ank_base::CompleteStateRequest {
+                            field_mask: vec![],
+                            label_selector: None,
+                        },
                     )),
                 },
             )),
@@ -354,13 +359,14 @@ mod tests {
             .expect_read_protobuf_data()
             .once()
             .in_sequence(&mut mockall_seq)
-            .returning(move || Err(Error::new(std::io::ErrorKind::Other, "error")));
+            .returning(move || Err(Error::other("error")));
 
         let error = ank_base::Response {
             request_id: REQUEST_ID.into(),
             response_content: Some(ank_base::response::ResponseContent::Error(
                 ank_base::Error {
                     message: "Access denied".into(),
+                    ..Default::default()
                 },
             )),
         };
@@ -415,6 +421,7 @@ mod tests {
             request_content: Some(ank_base::request::RequestContent::CompleteStateRequest(
                 ank_base::CompleteStateRequest {
                     field_mask: vec!["desiredState.workloads.nginx".to_string()],
+                    label_selector: None,
                 },
             )),
         };
@@ -447,7 +454,7 @@ mod tests {
             .expect_read_protobuf_data()
             .once()
             .in_sequence(&mut mockall_seq)
-            .returning(move || Err(Error::new(std::io::ErrorKind::Other, "error")));
+            .returning(move || Err(Error::other("error")));
 
         let output_stream_mock = MockReopenFile::default();
 
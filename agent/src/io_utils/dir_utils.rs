@@ -43,7 +43,6 @@ pub fn prepare_agent_run_directory(
     Directory::new(agent_run_folder)
 }
 
-
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
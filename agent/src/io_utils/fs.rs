@@ -388,7 +388,7 @@ mod tests {
             .unwrap()
             .push_back(FakeCall::create_dir_all(
                 Path::new("test_dir").to_path_buf(),
-                Err(std::io::Error::new(std::io::ErrorKind::Other, "some error")),
+                Err(std::io::Error::other("some error")),
             ));
 
         assert_eq!(
@@ -449,7 +449,7 @@ mod tests {
         let _test_lock = TEST_LOCK.lock();
         FAKE_CALL_LIST.lock().unwrap().push_back(FakeCall::metadata(
             Path::new("test_fifo").to_path_buf(),
-            Err(std::io::Error::new(ErrorKind::Other, "oh no!")),
+            Err(std::io::Error::other("oh no!")),
         ));
 
         assert!(!filesystem::is_fifo(Path::new("test_fifo")));
@@ -475,7 +475,7 @@ mod tests {
             .unwrap()
             .push_back(FakeCall::remove_dir_all(
                 Path::new("test_dir").to_path_buf(),
-                Err(Error::new(ErrorKind::Other, "Some Error!")),
+                Err(Error::other("Some Error!")),
             ));
 
         assert!(matches!(
@@ -526,7 +526,7 @@ mod tests {
             .unwrap()
             .push_back(FakeCall::remove_dir_all(
                 path.to_path_buf(),
-                Err(Error::new(ErrorKind::Other, "Some Error!")),
+                Err(Error::other("Some Error!")),
             ));
 
         let result = filesystem_async::remove_dir_all(path).await;
@@ -559,7 +559,7 @@ mod tests {
             .unwrap()
             .push_back(FakeCall::remove_file(
                 Path::new("test_file").to_path_buf(),
-                Err(Error::new(ErrorKind::Other, "Some Error!")),
+                Err(Error::other("Some Error!")),
             ));
 
         assert!(matches!(
@@ -595,7 +595,7 @@ mod tests {
         FAKE_CALL_LIST.lock().unwrap().push_back(FakeCall::write(
             path.to_path_buf(),
             file_content.clone(),
-            Err(Error::new(io_error_kind, "Some Error!")),
+            Err(Error::other("Some Error!")),
         ));
 
         let result = filesystem_async::write_file(path, file_content).await;
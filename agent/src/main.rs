@@ -186,6 +186,8 @@ async fn main() {
         agent_config.server_url,
         // [impl->swdd~agent-fails-on-missing-file-paths-and-insecure-cli-arguments~1]
         tls_config.unwrap_or_exit("Missing certificate file"),
+        // [impl->swdd~agent-supports-labels~2]
+        agent_config.labels,
     )
     .unwrap_or_exit("Failed to create communications client.");
 
@@ -13,8 +13,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::process::Stdio;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::Command;
+use tokio::sync::mpsc;
+
+const OUTPUT_STREAM_BUFFER_SIZE: usize = 100;
 
 #[cfg(test)]
 pub use tests::MockCliCommand;
@@ -89,6 +92,72 @@ impl<'a> CliCommand<'a> {
         }
     }
 
+    // Spawns the command and forwards its stdout and stderr line by line. The command is
+    // killed as soon as the returned receiver is dropped.
+    pub fn stream_output(&mut self) -> Result<mpsc::Receiver<String>, String> {
+        let mut child = self
+            .command
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|err| {
+                format!(
+                    "Error: '{}'. Could not spawn command '{:?}'.",
+                    err, self.command
+                )
+            })?;
+
+        let mut stdout = BufReader::new(
+            child
+                .stdout
+                .take()
+                .ok_or_else(|| "Could not access commands stdout".to_string())?,
+        )
+        .lines();
+        let mut stderr = BufReader::new(
+            child
+                .stderr
+                .take()
+                .ok_or_else(|| "Could not access commands stderr".to_string())?,
+        )
+        .lines();
+
+        let (output_tx, output_rx) = mpsc::channel(OUTPUT_STREAM_BUFFER_SIZE);
+        tokio::spawn(async move {
+            let mut stdout_open = true;
+            let mut stderr_open = true;
+            while stdout_open || stderr_open {
+                let line = tokio::select! {
+                    line = stdout.next_line(), if stdout_open => match line {
+                        Ok(Some(line)) => Some(line),
+                        _ => {
+                            stdout_open = false;
+                            None
+                        }
+                    },
+                    line = stderr.next_line(), if stderr_open => match line {
+                        Ok(Some(line)) => Some(line),
+                        _ => {
+                            stderr_open = false;
+                            None
+                        }
+                    },
+                    _ = output_tx.closed() => break,
+                };
+
+                if let Some(line) = line {
+                    if output_tx.send(line).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            // dropping the child kills the command if it is still running
+            drop(child);
+        });
+
+        Ok(output_rx)
+    }
+
     fn get_quoted_args(&self) -> String {
         self.args
             .iter()
@@ -198,6 +267,24 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn utest_cli_command_stream_output_forwards_stdout_and_stderr() {
+        let mut output = CliCommand::new("bash")
+            .args(&["-c", "echo output; sleep 0.1; echo error >&2"])
+            .stream_output()
+            .unwrap();
+
+        assert_eq!(output.recv().await, Some("output".to_string()));
+        assert_eq!(output.recv().await, Some("error".to_string()));
+        assert_eq!(output.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn utest_cli_command_stream_output_fail_on_not_existing_command() {
+        let result = CliCommand::new("non_existing_command").stream_output();
+        assert!(matches!(result, Err(x) if x.contains("Could not spawn command")));
+    }
+
     lazy_static::lazy_static! {
         static ref MOCK_CLI_COMMANDS: Mutex<HashMap<String, VecDeque<MockCliCommand>>> =
             Default::default();
@@ -270,5 +357,17 @@ mod tests {
 
             self.result.take().unwrap()
         }
+
+        pub fn stream_output(&mut self) -> Result<tokio::sync::mpsc::Receiver<String>, String> {
+            assert!(self.args.is_empty());
+
+            let output = self.result.take().unwrap()?;
+            let lines: Vec<String> = output.lines().map(str::to_string).collect();
+            let (output_tx, output_rx) = tokio::sync::mpsc::channel(lines.len().max(1));
+            for line in lines {
+                output_tx.try_send(line).unwrap();
+            }
+            Ok(output_rx)
+        }
     }
 }
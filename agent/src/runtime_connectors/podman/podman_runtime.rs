@@ -24,8 +24,8 @@ use common::{
 use crate::{
     generic_polling_state_checker::GenericPollingStateChecker,
     runtime_connectors::{
-        podman_cli::PodmanStartConfig, ReusableWorkloadState, RuntimeConnector, RuntimeError,
-        RuntimeStateGetter, StateChecker,
+        podman_cli::PodmanStartConfig, LogLineReceiver, LogRequestOptions, ReusableWorkloadState,
+        RuntimeConnector, RuntimeError, RuntimeStateGetter, StateChecker,
     },
     workload_state::WorkloadStateSender,
 };
@@ -280,6 +280,16 @@ impl RuntimeConnector<PodmanWorkloadId, GenericPollingStateChecker> for PodmanRu
             .await
             .map_err(|err| RuntimeError::Delete(err.to_string()))
     }
+
+    // [impl->swdd~podman-get-logs-uses-podman-logs~1]
+    async fn get_logs(
+        &self,
+        workload_id: &PodmanWorkloadId,
+        options: &LogRequestOptions,
+    ) -> Result<LogLineReceiver, RuntimeError> {
+        log::debug!("Collecting logs of workload with id '{}'", workload_id.id);
+        PodmanCli::logs(&workload_id.id, options).map_err(RuntimeError::Logs)
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -304,7 +314,9 @@ mod tests {
     use super::PodmanCli;
     use super::PodmanRuntime;
     use super::{PodmanStateGetter, PodmanWorkloadId, PODMAN_RUNTIME_NAME};
-    use crate::runtime_connectors::{RuntimeConnector, RuntimeError, RuntimeStateGetter};
+    use crate::runtime_connectors::{
+        LogRequestOptions, RuntimeConnector, RuntimeError, RuntimeStateGetter,
+    };
     use crate::test_helper::MOCKALL_CONTEXT_SYNC;
 
     const BUFFER_SIZE: usize = 20;
@@ -786,4 +798,60 @@ mod tests {
         let res = podman_runtime.delete_workload(&workload_id).await;
         assert_eq!(res, Err(RuntimeError::Delete("simulated error".into())));
     }
+
+    // [utest->swdd~podman-get-logs-uses-podman-logs~1]
+    #[tokio::test]
+    async fn utest_get_logs_succeeds() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let options = LogRequestOptions {
+            follow: true,
+            ..Default::default()
+        };
+
+        let context = PodmanCli::logs_context();
+        context
+            .expect()
+            .with(
+                mockall::predicate::eq("test_id"),
+                mockall::predicate::eq(options.clone()),
+            )
+            .return_once(|_, _| {
+                let (log_tx, log_rx) = tokio::sync::mpsc::channel(BUFFER_SIZE);
+                log_tx.try_send("log line".to_string()).unwrap();
+                Ok(log_rx)
+            });
+
+        let workload_id = PodmanWorkloadId {
+            id: "test_id".into(),
+        };
+
+        let podman_runtime = PodmanRuntime {};
+        let mut res = podman_runtime
+            .get_logs(&workload_id, &options)
+            .await
+            .unwrap();
+        assert_eq!(res.recv().await, Some("log line".to_string()));
+    }
+
+    // [utest->swdd~podman-get-logs-uses-podman-logs~1]
+    #[tokio::test]
+    async fn utest_get_logs_fails() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+
+        let context = PodmanCli::logs_context();
+        context
+            .expect()
+            .return_once(|_, _| Err("simulated error".into()));
+
+        let workload_id = PodmanWorkloadId {
+            id: "test_id".into(),
+        };
+
+        let podman_runtime = PodmanRuntime {};
+        let res = podman_runtime
+            .get_logs(&workload_id, &LogRequestOptions::default())
+            .await;
+        assert!(matches!(res, Err(RuntimeError::Logs(msg)) if msg == "simulated error"));
+    }
 }
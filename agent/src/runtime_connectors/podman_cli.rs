@@ -28,6 +28,7 @@ use tokio::sync::Mutex;
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::runtime_connectors::cli_command::CliCommand;
+use crate::runtime_connectors::{LogLineReceiver, LogRequestOptions};
 
 const PODMAN_CMD: &str = "podman";
 const API_PIPES_MOUNT_POINT: &str = "/run/ankaios/control_interface";
@@ -213,7 +214,8 @@ impl PodmanCli {
         args.extend(["kube", "play", "--quiet"]);
         args.extend(play_options.iter().map(|x| x as &str));
         args.push("-");
-        log::debug!("Executing play kube with args: {args:?}");
+        // The options are rendered with the secrets of the workload, hence they are not logged.
+        log::debug!("Executing play kube");
         let result = CliCommand::new(PODMAN_CMD)
             .args(&args)
             .stdin(kube_yml)
@@ -365,7 +367,7 @@ impl PodmanCli {
 
         args.append(&mut run_config.command_args);
 
-        log::debug!("The args are: '{:?}'", args);
+        // The args are not logged as the runtime config is rendered with the secrets of the workload.
         let id = CliCommand::new(PODMAN_CMD)
             .args(&args.iter().map(|x| &**x).collect::<Vec<&str>>())
             .exec()
@@ -505,6 +507,26 @@ impl PodmanCli {
         Ok(())
     }
 
+    // [impl->swdd~podman-get-logs-uses-podman-logs~1]
+    pub fn logs(workload_id: &str, options: &LogRequestOptions) -> Result<LogLineReceiver, String> {
+        let mut args = vec!["logs".to_string()];
+        args.append(&mut log_options_to_args(options));
+        args.push(workload_id.to_string());
+        CliCommand::new(PODMAN_CMD)
+            .args(&args.iter().map(String::as_str).collect::<Vec<&str>>())
+            .stream_output()
+    }
+
+    // [impl->swdd~podman-kube-get-logs-uses-podman-pod-logs~1]
+    pub fn pod_logs(pod: &str, options: &LogRequestOptions) -> Result<LogLineReceiver, String> {
+        let mut args = vec!["pod".to_string(), "logs".to_string()];
+        args.append(&mut log_options_to_args(options));
+        args.push(pod.to_string());
+        CliCommand::new(PODMAN_CMD)
+            .args(&args.iter().map(String::as_str).collect::<Vec<&str>>())
+            .stream_output()
+    }
+
     pub async fn remove_workloads_by_id(workload_id: &str) -> Result<(), String> {
         // Containers may have "--rm" flag -> it can happen, that they already do not exist.
         let args = vec!["stop", "--ignore", workload_id];
@@ -515,6 +537,20 @@ impl PodmanCli {
     }
 }
 
+fn log_options_to_args(options: &LogRequestOptions) -> Vec<String> {
+    let mut args = Vec::new();
+    if options.follow {
+        args.push("--follow".to_string());
+    }
+    if let Some(since) = &options.since {
+        args.push(format!("--since={since}"));
+    }
+    if let Some(tail) = options.tail {
+        args.push(format!("--tail={tail}"));
+    }
+    args
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 struct Volume {
@@ -562,6 +598,7 @@ mod tests {
     use super::{ContainerState, PodmanCli, PodmanPsCache};
 
     use super::PodmanContainerInfo;
+    use crate::runtime_connectors::LogRequestOptions;
     use crate::test_helper::MOCKALL_CONTEXT_SYNC;
     use common::objects::ExecutionState;
     use common::test_utils::serialize_as_map;
@@ -2178,6 +2215,78 @@ mod tests {
         assert_eq!(res, Ok(()));
     }
 
+    // [utest->swdd~podman-get-logs-uses-podman-logs~1]
+    #[tokio::test]
+    async fn utest_logs_passes_options_to_podman_logs() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&["logs", "--follow", "--since=10m", "--tail=5", "test_id"])
+                .exec_returns(Ok("line 1\nline 2\n".to_string())),
+        );
+
+        let mut res = PodmanCli::logs(
+            "test_id",
+            &LogRequestOptions {
+                follow: true,
+                since: Some("10m".to_string()),
+                tail: Some(5),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(res.recv().await, Some("line 1".to_string()));
+        assert_eq!(res.recv().await, Some("line 2".to_string()));
+        assert_eq!(res.recv().await, None);
+    }
+
+    // [utest->swdd~podman-get-logs-uses-podman-logs~1]
+    #[tokio::test]
+    async fn utest_logs_command_fails() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&["logs", "test_id"])
+                .exec_returns(Err(SAMPLE_ERROR_MESSAGE.to_string())),
+        );
+
+        let res = PodmanCli::logs("test_id", &LogRequestOptions::default());
+
+        assert!(matches!(res, Err(msg) if msg == SAMPLE_ERROR_MESSAGE));
+    }
+
+    // [utest->swdd~podman-kube-get-logs-uses-podman-pod-logs~1]
+    #[tokio::test]
+    async fn utest_pod_logs_passes_options_to_podman_pod_logs() {
+        let _guard = MOCKALL_CONTEXT_SYNC.get_lock_async().await;
+        super::CliCommand::reset();
+
+        super::CliCommand::new_expect(
+            "podman",
+            super::CliCommand::default()
+                .expect_args(&["pod", "logs", "--tail=1", "pod_1"])
+                .exec_returns(Ok("line 1\n".to_string())),
+        );
+
+        let mut res = PodmanCli::pod_logs(
+            "pod_1",
+            &LogRequestOptions {
+                tail: Some(1),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(res.recv().await, Some("line 1".to_string()));
+        assert_eq!(res.recv().await, None);
+    }
+
     #[derive(Serialize, Clone, Default)]
     #[serde(rename_all = "PascalCase")]
     struct TestPodmanContainerInfo<'a> {
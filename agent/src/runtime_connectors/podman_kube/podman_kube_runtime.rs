@@ -28,8 +28,8 @@ use crate::runtime_connectors::podman_cli::PodmanCli;
 use crate::{
     generic_polling_state_checker::GenericPollingStateChecker,
     runtime_connectors::{
-        podman_cli, ReusableWorkloadState, RuntimeConnector, RuntimeError, RuntimeStateGetter,
-        StateChecker,
+        podman_cli, LogLineReceiver, LogRequestOptions, ReusableWorkloadState, RuntimeConnector,
+        RuntimeError, RuntimeStateGetter, StateChecker,
     },
     workload_state::WorkloadStateSender,
 };
@@ -39,6 +39,7 @@ use super::podman_kube_runtime_config::PodmanKubeRuntimeConfig;
 pub const PODMAN_KUBE_RUNTIME_NAME: &str = "podman-kube";
 const CONFIG_VOLUME_SUFFIX: &str = ".config";
 const PODS_VOLUME_SUFFIX: &str = ".pods";
+const MERGED_LOGS_BUFFER_SIZE: usize = 100;
 
 #[derive(Debug, Clone)]
 pub struct PodmanKubeRuntime {}
@@ -302,6 +303,49 @@ impl RuntimeConnector<PodmanKubeWorkloadId, GenericPollingStateChecker> for Podm
             .unwrap_or_else(|err| log::warn!("Could not remove configs volume: '{}'", err));
         Ok(())
     }
+
+    // [impl->swdd~podman-kube-get-logs-uses-podman-pod-logs~1]
+    async fn get_logs(
+        &self,
+        workload_id: &PodmanKubeWorkloadId,
+        options: &LogRequestOptions,
+    ) -> Result<LogLineReceiver, RuntimeError> {
+        let Some(pods) = &workload_id.pods else {
+            return Err(RuntimeError::Logs(format!(
+                "No pods known for the workload '{}'",
+                workload_id.name.workload_name()
+            )));
+        };
+
+        let pod_log_receivers = pods
+            .iter()
+            .map(|pod| PodmanCli::pod_logs(pod, options))
+            .collect::<Result<Vec<LogLineReceiver>, String>>()
+            .map_err(RuntimeError::Logs)?;
+
+        // [impl->swdd~podman-kube-get-logs-merges-logs-of-all-pods~1]
+        let (merged_tx, merged_rx) = tokio::sync::mpsc::channel(MERGED_LOGS_BUFFER_SIZE);
+        for mut pod_log_receiver in pod_log_receivers {
+            let merged_tx = merged_tx.clone();
+            tokio::spawn(async move {
+                loop {
+                    tokio::select! {
+                        line = pod_log_receiver.recv() => {
+                            let Some(line) = line else {
+                                break;
+                            };
+                            if merged_tx.send(line).await.is_err() {
+                                break;
+                            }
+                        }
+                        _ = merged_tx.closed() => break,
+                    }
+                }
+            });
+        }
+
+        Ok(merged_rx)
+    }
 }
 
 #[async_trait]
@@ -409,7 +453,10 @@ mod tests {
 
     use super::PodmanCli;
     use crate::runtime_connectors::podman_cli::__mock_MockPodmanCli as podman_cli_mock;
-    use crate::runtime_connectors::{podman_cli::ContainerState, RuntimeConnector, RuntimeError};
+    use crate::runtime_connectors::{
+        podman_cli::ContainerState, LogLineReceiver, LogRequestOptions, RuntimeConnector,
+        RuntimeError,
+    };
 
     use super::{
         PodmanKubeRuntime, PodmanKubeWorkloadId, CONFIG_VOLUME_SUFFIX, PODMAN_KUBE_RUNTIME_NAME,
@@ -1197,6 +1244,71 @@ mod tests {
         assert_eq!(execution_state, ExecutionState::succeeded());
     }
 
+    // [utest->swdd~podman-kube-get-logs-uses-podman-pod-logs~1]
+    // [utest->swdd~podman-kube-get-logs-merges-logs-of-all-pods~1]
+    #[tokio::test]
+    async fn utest_get_logs_merges_logs_of_all_pods() {
+        let mock_context = MockContext::new().await;
+
+        mock_context
+            .pod_logs("pod1")
+            .returns(Ok(log_receiver(&["pod1 line"])));
+        mock_context
+            .pod_logs("pod2")
+            .returns(Ok(log_receiver(&["pod2 line"])));
+
+        let runtime = PodmanKubeRuntime {};
+        let mut logs = runtime
+            .get_logs(&WORKLOAD_ID, &LogRequestOptions::default())
+            .await
+            .unwrap();
+
+        let mut lines = vec![logs.recv().await.unwrap(), logs.recv().await.unwrap()];
+        lines.sort();
+        assert_eq!(lines, vec!["pod1 line", "pod2 line"]);
+        assert_eq!(logs.recv().await, None);
+    }
+
+    // [utest->swdd~podman-kube-get-logs-uses-podman-pod-logs~1]
+    #[tokio::test]
+    async fn utest_get_logs_fails_as_command_fails() {
+        let mock_context = MockContext::new().await;
+
+        mock_context
+            .pod_logs("pod1")
+            .returns(Err(SAMPLE_ERROR.into()));
+
+        let runtime = PodmanKubeRuntime {};
+        let logs = runtime
+            .get_logs(&WORKLOAD_ID, &LogRequestOptions::default())
+            .await;
+
+        assert!(matches!(logs, Err(RuntimeError::Logs(msg)) if msg == SAMPLE_ERROR));
+    }
+
+    #[tokio::test]
+    async fn utest_get_logs_fails_as_pods_unknown() {
+        let workload_id = PodmanKubeWorkloadId {
+            pods: None,
+            ..WORKLOAD_ID.clone()
+        };
+
+        let runtime = PodmanKubeRuntime {};
+        let logs = runtime
+            .get_logs(&workload_id, &LogRequestOptions::default())
+            .await;
+
+        assert!(matches!(logs, Err(RuntimeError::Logs(..))));
+    }
+
+    fn log_receiver(lines: &[&str]) -> LogLineReceiver {
+        let (log_tx, log_rx) = tokio::sync::mpsc::channel(lines.len());
+        for line in lines {
+            log_tx.try_send(line.to_string()).unwrap();
+        }
+        log_rx
+    }
+
     struct MockContext<'a> {
         list_volumes_by_name: podman_cli_mock::__list_volumes_by_name::Context,
         store_data: podman_cli_mock::__store_data_as_volume::Context,
@@ -1206,6 +1318,7 @@ mod tests {
         remove_volume: podman_cli_mock::__remove_volume::Context,
         list_states_from_pods: podman_cli_mock::__list_states_from_pods::Context,
         reset_ps_cache: podman_cli_mock::__reset_ps_cache::Context,
+        pod_logs: podman_cli_mock::__pod_logs::Context,
         _guard: tokio::sync::MutexGuard<'a, ()>, // The guard shall be dropped last
     }
 
@@ -1220,6 +1333,7 @@ mod tests {
                 remove_volume: PodmanCli::remove_volume_context(),
                 list_states_from_pods: PodmanCli::list_states_from_pods_context(),
                 reset_ps_cache: PodmanCli::reset_ps_cache_context(),
+                pod_logs: PodmanCli::pod_logs_context(),
                 _guard: MOCKALL_CONTEXT_SYNC.get_lock_async().await,
             }
         }
@@ -1346,6 +1460,23 @@ mod tests {
                 },
             }
         }
+
+        fn pod_logs(
+            &self,
+            pod: impl ToString,
+        ) -> ReturnsStruct<impl FnOnce(Result<LogLineReceiver, String>) + '_> {
+            let pod_logs = &self.pod_logs;
+            let pod = pod.to_string();
+            ReturnsStruct {
+                function: |result| {
+                    pod_logs
+                        .expect()
+                        .withf(move |actual_pod, _| actual_pod == pod)
+                        .once()
+                        .return_once(|_, _| result);
+                },
+            }
+        }
     }
 
     struct ReturnsStruct<F> {
@@ -22,11 +22,14 @@ use common::objects::{
 
 use crate::{runtime_connectors::StateChecker, workload_state::WorkloadStateSender};
 
+pub type LogLineReceiver = tokio::sync::mpsc::Receiver<String>;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum RuntimeError {
     Create(String),
     Delete(String),
     List(String),
+    Logs(String),
     Unsupported(String),
 }
 
@@ -42,6 +45,9 @@ impl Display for RuntimeError {
             RuntimeError::List(msg) => {
                 write!(f, "{}", msg)
             }
+            RuntimeError::Logs(msg) => {
+                write!(f, "{}", msg)
+            }
             RuntimeError::Unsupported(msg) => {
                 write!(f, "{}", msg)
             }
@@ -71,6 +77,13 @@ impl ReusableWorkloadState {
     }
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogRequestOptions {
+    pub follow: bool,
+    pub since: Option<String>,
+    pub tail: Option<i32>,
+}
+
 // [impl->swdd~functions-required-by-runtime-connector~1]
 #[async_trait]
 pub trait RuntimeConnector<WorkloadId, StChecker>: Sync + Send
@@ -107,6 +120,12 @@ where
     ) -> Result<StChecker, RuntimeError>;
 
     async fn delete_workload(&self, workload_id: &WorkloadId) -> Result<(), RuntimeError>;
+
+    async fn get_logs(
+        &self,
+        workload_id: &WorkloadId,
+        options: &LogRequestOptions,
+    ) -> Result<LogLineReceiver, RuntimeError>;
 }
 
 pub trait OwnableRuntime<WorkloadId, StChecker>: RuntimeConnector<WorkloadId, StChecker>
@@ -153,7 +172,7 @@ pub mod test {
         workload_state::WorkloadStateSender,
     };
 
-    use super::{RuntimeConnector, RuntimeError};
+    use super::{LogLineReceiver, LogRequestOptions, RuntimeConnector, RuntimeError};
 
     #[async_trait]
     impl RuntimeStateGetter<String> for StubStateChecker {
@@ -222,6 +241,11 @@ pub mod test {
             Result<StubStateChecker, RuntimeError>,
         ),
         DeleteWorkload(String, Result<(), RuntimeError>),
+        GetLogs(
+            String,
+            LogRequestOptions,
+            Result<LogLineReceiver, RuntimeError>,
+        ),
     }
 
     #[derive(Debug)]
@@ -423,5 +447,23 @@ pub mod test {
                 }
             }
         }
+
+        async fn get_logs(
+            &self,
+            workload_id: &String,
+            options: &LogRequestOptions,
+        ) -> Result<LogLineReceiver, RuntimeError> {
+            match self.get_expected_call().await {
+                RuntimeCall::GetLogs(expected_workload_id, expected_options, result)
+                    if expected_workload_id == *workload_id && expected_options == *options =>
+                {
+                    return result;
+                }
+                expected_call => {
+                    self.unexpected_call().await;
+                    panic!("Unexpected get_logs call. Expected: '{expected_call:?}'\n\nGot: {workload_id:?}, {options:?}");
+                }
+            }
+        }
     }
 }
@@ -32,7 +32,10 @@ use crate::control_interface::control_interface_info::ControlInterfaceInfo;
 use crate::io_utils::filesystem_async;
 
 use crate::{
-    runtime_connectors::{OwnableRuntime, ReusableWorkloadState, RuntimeError, StateChecker},
+    runtime_connectors::{
+        LogLineReceiver, LogRequestOptions, OwnableRuntime, ReusableWorkloadState, RuntimeError,
+        StateChecker,
+    },
     workload_operation::ReusableWorkloadSpec,
     workload_state::{WorkloadStateSender, WorkloadStateSenderInterface},
 };
@@ -73,6 +76,12 @@ pub trait RuntimeFacade: Send + Sync + 'static {
         instance_name: WorkloadInstanceName,
         update_state_tx: &WorkloadStateSender,
     );
+
+    async fn get_logs(
+        &self,
+        instance_name: WorkloadInstanceName,
+        options: LogRequestOptions,
+    ) -> Result<LogLineReceiver, RuntimeError>;
 }
 
 pub struct GenericRuntimeFacade<
@@ -158,6 +167,21 @@ impl<
     ) {
         let _task_handle = Self::delete_workload_non_blocking(self, instance_name, update_state_tx);
     }
+
+    // [impl->swdd~agent-facade-forwards-get-logs-call~1]
+    async fn get_logs(
+        &self,
+        instance_name: WorkloadInstanceName,
+        options: LogRequestOptions,
+    ) -> Result<LogLineReceiver, RuntimeError> {
+        log::debug!(
+            "Collecting logs of '{}' workload '{}'.",
+            self.runtime.name(),
+            instance_name.workload_name()
+        );
+        let workload_id = self.runtime.get_workload_id(&instance_name).await?;
+        self.runtime.get_logs(&workload_id, &options).await
+    }
 }
 
 impl<
@@ -420,7 +444,8 @@ mod tests {
         io_utils::mock_filesystem_async,
         runtime_connectors::{
             runtime_connector::test::{MockRuntimeConnector, RuntimeCall, StubStateChecker},
-            GenericRuntimeFacade, OwnableRuntime, ReusableWorkloadState, RuntimeFacade,
+            GenericRuntimeFacade, LogRequestOptions, OwnableRuntime, ReusableWorkloadState,
+            RuntimeError, RuntimeFacade,
         },
         workload::{ControlLoopState, MockWorkload, MockWorkloadControlLoop},
         workload_operation::ReusableWorkloadSpec,
@@ -800,4 +825,77 @@ mod tests {
 
         runtime_mock.assert_all_expectations().await;
     }
+
+    // [utest->swdd~agent-facade-forwards-get-logs-call~1]
+    #[tokio::test]
+    async fn utest_runtime_facade_get_logs() {
+        let mut runtime_mock = MockRuntimeConnector::new();
+
+        let workload_instance_name = WorkloadInstanceName::builder()
+            .workload_name(WORKLOAD_1_NAME)
+            .build();
+        let options = LogRequestOptions {
+            follow: true,
+            ..Default::default()
+        };
+
+        let (log_tx, log_rx) = tokio::sync::mpsc::channel(TEST_CHANNEL_BUFFER_SIZE);
+        log_tx.send("log line".to_string()).await.unwrap();
+
+        runtime_mock
+            .expect(vec![
+                RuntimeCall::GetWorkloadId(
+                    workload_instance_name.clone(),
+                    Ok(WORKLOAD_ID.to_string()),
+                ),
+                RuntimeCall::GetLogs(WORKLOAD_ID.to_string(), options.clone(), Ok(log_rx)),
+            ])
+            .await;
+
+        let ownable_runtime_mock: Box<dyn OwnableRuntime<String, StubStateChecker>> =
+            Box::new(runtime_mock.clone());
+        let test_runtime_facade = Box::new(GenericRuntimeFacade::<String, StubStateChecker>::new(
+            ownable_runtime_mock,
+            RUN_FOLDER.into(),
+        ));
+
+        let mut log_lines = test_runtime_facade
+            .get_logs(workload_instance_name, options)
+            .await
+            .unwrap();
+        assert_eq!(log_lines.recv().await, Some("log line".to_string()));
+
+        runtime_mock.assert_all_expectations().await;
+    }
+
+    // [utest->swdd~agent-facade-forwards-get-logs-call~1]
+    #[tokio::test]
+    async fn utest_runtime_facade_get_logs_fails_on_unknown_workload_id() {
+        let mut runtime_mock = MockRuntimeConnector::new();
+
+        let workload_instance_name = WorkloadInstanceName::builder()
+            .workload_name(WORKLOAD_1_NAME)
+            .build();
+
+        runtime_mock
+            .expect(vec![RuntimeCall::GetWorkloadId(
+                workload_instance_name.clone(),
+                Err(RuntimeError::List("workload not found".to_string())),
+            )])
+            .await;
+
+        let ownable_runtime_mock: Box<dyn OwnableRuntime<String, StubStateChecker>> =
+            Box::new(runtime_mock.clone());
+        let test_runtime_facade = Box::new(GenericRuntimeFacade::<String, StubStateChecker>::new(
+            ownable_runtime_mock,
+            RUN_FOLDER.into(),
+        ));
+
+        let result = test_runtime_facade
+            .get_logs(workload_instance_name, LogRequestOptions::default())
+            .await;
+        assert!(matches!(result, Err(RuntimeError::List(_))));
+
+        runtime_mock.assert_all_expectations().await;
+    }
 }
@@ -20,13 +20,15 @@ use crate::control_interface::authorizer::Authorizer;
 use api::ank_base;
 
 use common::{
+    commands::WorkloadLogsRequest,
     objects::{
         AgentName, DeletedWorkload, ExecutionState, WorkloadInstanceName, WorkloadSpec,
         WorkloadState,
     },
     request_id_prepending::detach_prefix_from_request_id,
-    to_server_interface::ToServerSender,
+    to_server_interface::{ToServerInterface, ToServerSender},
 };
+use tokio::task::JoinHandle;
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::control_interface::control_interface_info::ControlInterfaceInfo;
@@ -39,7 +41,7 @@ use crate::workload_scheduler::scheduler::WorkloadScheduler;
 #[cfg_attr(test, mockall_double::double)]
 use crate::workload_state::workload_state_store::WorkloadStateStore;
 use crate::{
-    runtime_connectors::RuntimeFacade,
+    runtime_connectors::{LogLineReceiver, LogRequestOptions, RuntimeFacade},
     workload_operation::{ReusableWorkloadSpec, WorkloadOperation},
     workload_state::{WorkloadStateSender, WorkloadStateSenderInterface},
 };
@@ -50,6 +52,8 @@ use crate::workload::Workload;
 #[cfg(test)]
 use mockall::automock;
 
+const MAX_LOG_ENTRIES_PER_RESPONSE: usize = 100;
+
 fn flatten(
     mut runtime_workload_map: HashMap<String, HashMap<String, WorkloadSpec>>,
 ) -> Vec<ReusableWorkloadSpec> {
@@ -84,6 +88,7 @@ pub struct RuntimeManager {
     runtime_map: HashMap<String, Box<dyn RuntimeFacade>>,
     update_state_tx: WorkloadStateSender,
     workload_queue: WorkloadScheduler,
+    log_collections: HashMap<String, JoinHandle<()>>,
 }
 
 #[cfg_attr(test, automock)]
@@ -103,6 +108,7 @@ impl RuntimeManager {
             runtime_map,
             update_state_tx: update_state_tx.clone(),
             workload_queue: WorkloadScheduler::new(update_state_tx),
+            log_collections: HashMap::new(),
         }
     }
 
@@ -202,6 +208,88 @@ impl RuntimeManager {
         }
     }
 
+    // [impl->swdd~agent-starts-log-collection-for-workload-logs-requests~1]
+    pub async fn start_log_collection(&mut self, logs_request: WorkloadLogsRequest) {
+        let request_id = logs_request.request_id;
+        let instance_name = logs_request.instance_name;
+
+        let Some(runtime) = self.runtime_map.get(&logs_request.runtime) else {
+            self.send_log_collection_error(
+                request_id,
+                format!(
+                    "Could not collect logs of workload '{}': unknown runtime '{}'",
+                    instance_name.workload_name(),
+                    logs_request.runtime
+                ),
+            )
+            .await;
+            return;
+        };
+
+        let options = LogRequestOptions {
+            follow: logs_request.follow,
+            since: logs_request.since,
+            tail: logs_request.tail,
+        };
+
+        match runtime.get_logs(instance_name.clone(), options).await {
+            Ok(log_lines) => {
+                log::debug!(
+                    "Starting log collection '{}' for workload '{}'.",
+                    request_id,
+                    instance_name.workload_name()
+                );
+                self.log_collections
+                    .retain(|_, log_collection| !log_collection.is_finished());
+                let log_collection = tokio::spawn(forward_log_lines(
+                    request_id.clone(),
+                    instance_name,
+                    log_lines,
+                    self.control_interface_tx.clone(),
+                ));
+                self.log_collections.insert(request_id, log_collection);
+            }
+            Err(err) => {
+                self.send_log_collection_error(
+                    request_id,
+                    format!(
+                        "Could not collect logs of workload '{}': '{}'",
+                        instance_name.workload_name(),
+                        err
+                    ),
+                )
+                .await;
+            }
+        }
+    }
+
+    // [impl->swdd~agent-stops-log-collection-on-workload-logs-cancel~1]
+    pub fn stop_log_collection(&mut self, request_id: &str) {
+        if let Some(log_collection) = self.log_collections.remove(request_id) {
+            log::debug!("Stopping log collection '{}'.", request_id);
+            // dropping the log line receiver terminates the runtime's log command
+            log_collection.abort();
+        } else {
+            log::debug!("No log collection '{}' to stop.", request_id);
+        }
+    }
+
+    async fn send_log_collection_error(&self, request_id: String, message: String) {
+        log::warn!("{}", message);
+        self.control_interface_tx
+            .response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(
+                    ank_base::Error {
+                        message,
+                        code: ank_base::ErrorCode::Unspecified.into(),
+                    },
+                )),
+            })
+            .await
+            .unwrap_or_else(|err| log::error!("Could not send log collection error: '{}'", err));
+    }
+
     // [impl->swdd~agent-initial-list-existing-workloads~1]
     async fn resume_and_remove_from_added_workloads(
         &mut self,
@@ -256,7 +344,10 @@ impl RuntimeManager {
                                 new_workload_spec.instance_name.clone();
 
                             // [impl->swdd~agent-existing-workloads-resume-existing~2]
-                            if Self::is_resumable_workload(&workload_state, &new_instance_name) {
+                            // [impl->swdd~agent-existing-workloads-replace-suspended~1]
+                            if !new_workload_spec.suspended
+                                && Self::is_resumable_workload(&workload_state, &new_instance_name)
+                            {
                                 // [impl->swdd~agent-control-interface-created-for-eligible-workloads~1]
                                 let control_interface_info =
                                     if new_workload_spec.needs_control_interface() {
@@ -289,11 +380,13 @@ impl RuntimeManager {
                                         &self.update_state_tx,
                                     ),
                                 );
-                            } else if Self::is_reusable_workload(
-                                &workload_state,
-                                &workload_id,
-                                &new_instance_name,
-                            ) {
+                            } else if !new_workload_spec.suspended
+                                && Self::is_reusable_workload(
+                                    &workload_state,
+                                    &workload_id,
+                                    &new_instance_name,
+                                )
+                            {
                                 // [impl->swdd~agent-existing-workloads-reuse-unmodified~1]
 
                                 log::info!(
@@ -628,6 +721,44 @@ impl RuntimeManager {
     }
 }
 
+// [impl->swdd~agent-forwards-collected-log-lines-to-server~1]
+async fn forward_log_lines(
+    request_id: String,
+    instance_name: WorkloadInstanceName,
+    mut log_lines: LogLineReceiver,
+    to_server: ToServerSender,
+) {
+    while let Some(log_line) = log_lines.recv().await {
+        let mut log_entries = vec![log_line];
+        while log_entries.len() < MAX_LOG_ENTRIES_PER_RESPONSE {
+            match log_lines.try_recv() {
+                Ok(log_line) => log_entries.push(log_line),
+                Err(_) => break,
+            }
+        }
+
+        let log_entries = log_entries
+            .into_iter()
+            .map(|message| ank_base::LogEntry {
+                workload_name: Some(instance_name.clone().into()),
+                message,
+            })
+            .collect();
+        if let Err(err) = to_server
+            .log_entries_response(request_id.clone(), log_entries)
+            .await
+        {
+            log::warn!("Could not forward logs for '{}': '{}'", request_id, err);
+            return;
+        }
+    }
+
+    to_server
+        .logs_stop_response(request_id, instance_name)
+        .await
+        .unwrap_or_else(|err| log::warn!("Could not send the end of the logs: '{}'", err));
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -646,7 +777,9 @@ mod tests {
         authorizer::MockAuthorizer, control_interface_info::MockControlInterfaceInfo,
         MockControlInterface,
     };
-    use crate::runtime_connectors::{MockRuntimeFacade, ReusableWorkloadState, RuntimeError};
+    use crate::runtime_connectors::{
+        LogRequestOptions, MockRuntimeFacade, ReusableWorkloadState, RuntimeError,
+    };
     use crate::runtime_manager::ToReusableWorkloadSpecs;
     use crate::workload::{MockWorkload, WorkloadError};
     use crate::workload_operation::ReusableWorkloadSpec;
@@ -655,6 +788,7 @@ mod tests {
     use crate::workload_state::WorkloadStateReceiver;
     use ank_base::response::ResponseContent;
     use api::ank_base::Files;
+    use common::commands::{ConnectionResponse, WorkloadLogsRequest};
     use common::objects::{
         self, generate_test_control_interface_access,
         generate_test_workload_spec_with_control_interface_access,
@@ -665,7 +799,7 @@ mod tests {
         self, generate_test_complete_state, generate_test_deleted_workload,
         generate_test_deleted_workload_with_dependencies,
     };
-    use common::to_server_interface::ToServerReceiver;
+    use common::to_server_interface::{ToServer, ToServerReceiver};
     use mockall::{predicate, Sequence};
     use std::collections::HashMap;
     use std::{any::Any, path::Path};
@@ -1127,6 +1261,68 @@ mod tests {
         assert!(runtime_manager.workloads.contains_key(WORKLOAD_1_NAME)); // existing workload is resumed
     }
 
+    // [utest->swdd~agent-existing-workloads-replace-suspended~1]
+    #[tokio::test]
+    async fn utest_replace_existing_running_workload_if_suspended() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut suspended_workload = generate_test_workload_spec_with_control_interface_access(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+        suspended_workload.suspended = true;
+
+        let added_workloads = vec![suspended_workload.clone()];
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let reusable_workload_state_running = ReusableWorkloadState::new(
+            suspended_workload.instance_name.clone(),
+            ExecutionState::running(),
+            None,
+        );
+
+        let mut runtime_facade_mock = MockRuntimeFacade::new();
+        runtime_facade_mock
+            .expect_get_reusable_workloads()
+            .once()
+            .return_once(|_| Box::pin(async { Ok(vec![reusable_workload_state_running]) }));
+
+        runtime_facade_mock
+            .expect_resume_workload()
+            .once()
+            .return_once(|_, _, _| MockWorkload::default());
+
+        let (_, mut runtime_manager, _) = RuntimeManagerBuilder::default()
+            .with_runtime(
+                RUNTIME_NAME,
+                Box::new(runtime_facade_mock) as Box<dyn RuntimeFacade>,
+            )
+            .build();
+
+        let expected_new_added_workloads: Vec<ReusableWorkloadSpec> =
+            added_workloads.clone().into_reusable_workload_specs();
+        let (new_added_workloads, deleted_workloads) = runtime_manager
+            .resume_and_remove_from_added_workloads(added_workloads)
+            .await;
+
+        assert_eq!(expected_new_added_workloads, new_added_workloads);
+        assert_eq!(
+            vec![DeletedWorkload {
+                instance_name: suspended_workload.instance_name,
+                ..Default::default()
+            }],
+            deleted_workloads
+        );
+    }
+
     // [utest->swdd~agent-existing-workloads-replace-updated~4]
     #[tokio::test]
     async fn utest_replace_existing_not_running_workload_after_agent_restart() {
@@ -2276,6 +2472,10 @@ mod tests {
                                 configs: Some(ank_base::ConfigMappings {
                                     configs: Default::default()}),
                                 files: Some(Files::default()),
+                                scheduling_constraints: None,
+                                secrets: Some(Default::default()),
+                                suspended: None,
+                                restart_generation: None,
                             })];
         let mut complete_state = test_utils::generate_test_proto_complete_state(&workloads);
         complete_state.workload_states = Some(ank_base::WorkloadStatesMap {
@@ -2307,6 +2507,8 @@ mod tests {
                 objects::AgentAttributes {
                     cpu_usage: Some(objects::CpuUsage { cpu_usage: 42 }),
                     free_memory: Some(objects::FreeMemory { free_memory: 42 }),
+                    labels: HashMap::new(),
+                    last_seen: None,
                 }
                 .into(),
             )]),
@@ -2868,6 +3070,223 @@ mod tests {
             .await;
     }
 
+    fn generate_test_logs_request(runtime: &str) -> WorkloadLogsRequest {
+        WorkloadLogsRequest {
+            request_id: REQUEST_ID.to_string(),
+            instance_name: generate_test_workload_spec_with_param(
+                AGENT_NAME.to_string(),
+                WORKLOAD_1_NAME.to_string(),
+                RUNTIME_NAME.to_string(),
+            )
+            .instance_name,
+            runtime: runtime.to_string(),
+            follow: true,
+            since: Some("1m".to_string()),
+            tail: Some(10),
+        }
+    }
+
+    // [utest->swdd~agent-starts-log-collection-for-workload-logs-requests~1]
+    // [utest->swdd~agent-forwards-collected-log-lines-to-server~1]
+    #[tokio::test]
+    async fn utest_start_log_collection_forwards_log_lines_and_end_of_logs() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let logs_request = generate_test_logs_request(RUNTIME_NAME);
+        let instance_name = logs_request.instance_name.clone();
+
+        let (log_tx, log_rx) = channel(BUFFER_SIZE);
+        log_tx.send("line 1".to_string()).await.unwrap();
+        log_tx.send("line 2".to_string()).await.unwrap();
+        drop(log_tx);
+
+        let mut runtime_facade_mock = MockRuntimeFacade::new();
+        runtime_facade_mock
+            .expect_get_logs()
+            .once()
+            .with(
+                predicate::eq(instance_name.clone()),
+                predicate::eq(LogRequestOptions {
+                    follow: true,
+                    since: Some("1m".to_string()),
+                    tail: Some(10),
+                }),
+            )
+            .return_once(|_, _| Box::pin(async { Ok(log_rx) }));
+
+        let (mut server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default()
+                .with_runtime(
+                    RUNTIME_NAME,
+                    Box::new(runtime_facade_mock) as Box<dyn RuntimeFacade>,
+                )
+                .build();
+
+        runtime_manager.start_log_collection(logs_request).await;
+
+        let log_entry = |message: &str| ank_base::LogEntry {
+            workload_name: Some(instance_name.clone().into()),
+            message: message.to_string(),
+        };
+        assert_eq!(
+            server_receiver.recv().await,
+            Some(ToServer::Response(ConnectionResponse {
+                connection_name: String::default(),
+                response: ank_base::Response {
+                    request_id: REQUEST_ID.to_string(),
+                    response_content: Some(ResponseContent::LogEntriesResponse(
+                        ank_base::LogEntriesResponse {
+                            log_entries: vec![log_entry("line 1"), log_entry("line 2")],
+                        }
+                    )),
+                },
+            }))
+        );
+        assert_eq!(
+            server_receiver.recv().await,
+            Some(ToServer::Response(ConnectionResponse {
+                connection_name: String::default(),
+                response: ank_base::Response {
+                    request_id: REQUEST_ID.to_string(),
+                    response_content: Some(ResponseContent::LogsStopResponse(
+                        ank_base::LogsStopResponse {
+                            workload_name: Some(instance_name.into()),
+                        }
+                    )),
+                },
+            }))
+        );
+    }
+
+    // [utest->swdd~agent-starts-log-collection-for-workload-logs-requests~1]
+    #[tokio::test]
+    async fn utest_start_log_collection_fails_on_unknown_runtime() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let (mut server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default().build();
+
+        runtime_manager
+            .start_log_collection(generate_test_logs_request(RUNTIME_NAME_2))
+            .await;
+
+        assert!(matches!(
+            server_receiver.recv().await,
+            Some(ToServer::Response(ConnectionResponse {
+                response: ank_base::Response {
+                    request_id,
+                    response_content: Some(ResponseContent::Error(_)),
+                },
+                ..
+            })) if request_id == REQUEST_ID
+        ));
+        assert!(runtime_manager.log_collections.is_empty());
+    }
+
+    // [utest->swdd~agent-starts-log-collection-for-workload-logs-requests~1]
+    #[tokio::test]
+    async fn utest_start_log_collection_fails_on_runtime_error() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let mut runtime_facade_mock = MockRuntimeFacade::new();
+        runtime_facade_mock
+            .expect_get_logs()
+            .once()
+            .return_once(|_, _| {
+                Box::pin(async { Err(RuntimeError::Logs("logs failed".to_string())) })
+            });
+
+        let (mut server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default()
+                .with_runtime(
+                    RUNTIME_NAME,
+                    Box::new(runtime_facade_mock) as Box<dyn RuntimeFacade>,
+                )
+                .build();
+
+        runtime_manager
+            .start_log_collection(generate_test_logs_request(RUNTIME_NAME))
+            .await;
+
+        assert!(matches!(
+            server_receiver.recv().await,
+            Some(ToServer::Response(ConnectionResponse {
+                response: ank_base::Response {
+                    request_id,
+                    response_content: Some(ResponseContent::Error(ank_base::Error { message, .. })),
+                },
+                ..
+            })) if request_id == REQUEST_ID && message.contains("logs failed")
+        ));
+        assert!(runtime_manager.log_collections.is_empty());
+    }
+
+    // [utest->swdd~agent-stops-log-collection-on-workload-logs-cancel~1]
+    #[tokio::test]
+    async fn utest_stop_log_collection_drops_log_line_receiver() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| MockWorkloadScheduler::default());
+
+        let (log_tx, log_rx) = channel::<String>(BUFFER_SIZE);
+
+        let mut runtime_facade_mock = MockRuntimeFacade::new();
+        runtime_facade_mock
+            .expect_get_logs()
+            .once()
+            .return_once(|_, _| Box::pin(async { Ok(log_rx) }));
+
+        let (_server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default()
+                .with_runtime(
+                    RUNTIME_NAME,
+                    Box::new(runtime_facade_mock) as Box<dyn RuntimeFacade>,
+                )
+                .build();
+
+        runtime_manager
+            .start_log_collection(generate_test_logs_request(RUNTIME_NAME))
+            .await;
+        assert!(runtime_manager.log_collections.contains_key(REQUEST_ID));
+
+        runtime_manager.stop_log_collection(REQUEST_ID);
+
+        assert!(runtime_manager.log_collections.is_empty());
+        tokio::time::timeout(std::time::Duration::from_secs(1), log_tx.closed())
+            .await
+            .unwrap();
+    }
+
     fn setup_from_authorizer() -> Box<dyn Any> {
         let authorizer_from_context_mock = MockAuthorizer::from_context();
         authorizer_from_context_mock
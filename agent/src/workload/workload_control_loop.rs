@@ -120,21 +120,7 @@ impl WorkloadControlLoop {
                         Some(WorkloadCommand::Create) => {
                             log::debug!("Received WorkloadCommand::Create.");
 
-                            Self::send_workload_state_to_agent(
-                                &control_loop_state.to_agent_workload_state_sender,
-                                control_loop_state.instance_name(),
-                                ExecutionState::starting_triggered(),
-                            )
-                            .await;
-
-                            let retry_token = control_loop_state.retry_manager.new_token();
-
-                            control_loop_state = Self::create_workload_on_runtime(
-                                control_loop_state,
-                                retry_token,
-                                Self::send_retry_for_workload,
-                            )
-                            .await;
+                            control_loop_state = Self::start_or_suspend_workload_on_runtime(control_loop_state).await;
                         }
                         // [impl->swdd~agent-workload-control-loop-executes-resume~1]
                         Some(WorkloadCommand::Resume) => {
@@ -533,23 +519,51 @@ impl WorkloadControlLoop {
             control_loop_state.workload_spec = *spec;
             control_loop_state.control_interface_path = control_interface_path;
 
+            control_loop_state =
+                Self::start_or_suspend_workload_on_runtime(control_loop_state).await;
+        }
+        control_loop_state
+    }
+
+    // [impl->swdd~agent-workload-control-loop-keeps-suspended-workload-stopped~1]
+    async fn start_or_suspend_workload_on_runtime<WorkloadId, StChecker>(
+        mut control_loop_state: ControlLoopState<WorkloadId, StChecker>,
+    ) -> ControlLoopState<WorkloadId, StChecker>
+    where
+        WorkloadId: ToString + FromStr + Clone + Send + Sync + 'static,
+        StChecker: StateChecker<WorkloadId> + Send + Sync + 'static,
+    {
+        if control_loop_state.workload_spec.suspended {
+            log::info!(
+                "Not starting workload '{}' as it is suspended.",
+                control_loop_state.instance_name().workload_name()
+            );
+
             Self::send_workload_state_to_agent(
                 &control_loop_state.to_agent_workload_state_sender,
                 control_loop_state.instance_name(),
-                ExecutionState::starting_triggered(),
+                ExecutionState::suspended(),
             )
             .await;
 
-            let retry_token = control_loop_state.retry_manager.new_token();
-
-            control_loop_state = Self::create_workload_on_runtime(
-                control_loop_state,
-                retry_token,
-                Self::send_retry_for_workload,
-            )
-            .await;
+            return control_loop_state;
         }
-        control_loop_state
+
+        Self::send_workload_state_to_agent(
+            &control_loop_state.to_agent_workload_state_sender,
+            control_loop_state.instance_name(),
+            ExecutionState::starting_triggered(),
+        )
+        .await;
+
+        let retry_token = control_loop_state.retry_manager.new_token();
+
+        Self::create_workload_on_runtime(
+            control_loop_state,
+            retry_token,
+            Self::send_retry_for_workload,
+        )
+        .await
     }
 
     async fn retry_create_workload_on_runtime<WorkloadId, StChecker>(
@@ -1464,6 +1478,163 @@ mod tests {
         runtime_mock.assert_all_expectations().await;
     }
 
+    // [utest->swdd~agent-workload-control-loop-keeps-suspended-workload-stopped~1]
+    #[tokio::test]
+    async fn utest_workload_obj_run_create_suspended_workload_not_started() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
+        let (state_change_tx, state_change_rx) = mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+        workload_spec.suspended = true;
+
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock.expect(vec![]).await;
+
+        workload_command_sender.create().await.unwrap();
+        // Send also a delete command so that we can properly get out of the loop
+        workload_command_sender.delete().await.unwrap();
+
+        let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
+        mock_remove_dir.expect().returning(|_| Ok(()));
+
+        let instance_name = workload_spec.instance_name.clone();
+
+        let mut control_loop_state = ControlLoopState::builder()
+            .workload_spec(workload_spec)
+            .workload_state_sender(state_change_tx)
+            .run_folder(RUN_FOLDER.into())
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+
+        control_loop_state
+            .retry_manager
+            .expect_invalidate()
+            .return_const(());
+        control_loop_state.retry_manager.expect_new_token().never();
+
+        assert!(timeout(
+            Duration::from_millis(100),
+            WorkloadControlLoop::run(control_loop_state)
+        )
+        .await
+        .is_ok());
+
+        assert_execution_state_sequence(
+            state_change_rx,
+            vec![
+                (&instance_name, ExecutionState::suspended()),
+                (&instance_name, ExecutionState::stopping_requested()),
+                (&instance_name, ExecutionState::removed()),
+            ],
+        )
+        .await;
+
+        assert!(workload_command_receiver2.is_closed());
+        assert!(workload_command_receiver2.is_empty());
+        runtime_mock.assert_all_expectations().await;
+    }
+
+    // [utest->swdd~agent-workload-control-loop-keeps-suspended-workload-stopped~1]
+    #[tokio::test]
+    async fn utest_workload_obj_run_update_to_suspended_workload_stops_workload() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (workload_command_sender, workload_command_receiver) = WorkloadCommandSender::new();
+        let (workload_command_sender2, workload_command_receiver2) = WorkloadCommandSender::new();
+        let (state_change_tx, state_change_rx) = mpsc::channel(TEST_EXEC_COMMAND_BUFFER_SIZE);
+
+        let mut old_mock_state_checker = StubStateChecker::new();
+        old_mock_state_checker.panic_if_not_stopped();
+
+        let old_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_string(),
+            WORKLOAD_1_NAME.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut new_workload_spec = old_workload_spec.clone();
+        new_workload_spec.suspended = true;
+
+        let mut runtime_mock = MockRuntimeConnector::new();
+        runtime_mock
+            .expect(vec![RuntimeCall::DeleteWorkload(
+                OLD_WORKLOAD_ID.to_string(),
+                Ok(()),
+            )])
+            .await;
+
+        let mock_remove_dir = mock_filesystem_async::remove_dir_all_context();
+        mock_remove_dir.expect().returning(|_| Ok(()));
+
+        workload_command_sender
+            .update(
+                Some(new_workload_spec.clone()),
+                CONTROL_INTERFACE_PATH.clone(),
+            )
+            .await
+            .unwrap();
+        // Send also a delete command so that we can properly get out of the loop
+        workload_command_sender.delete().await.unwrap();
+
+        let instance_name = old_workload_spec.instance_name.clone();
+
+        let mut control_loop_state = ControlLoopState::builder()
+            .workload_spec(old_workload_spec)
+            .workload_state_sender(state_change_tx)
+            .run_folder(RUN_FOLDER.into())
+            .runtime(Box::new(runtime_mock.clone()))
+            .workload_command_receiver(workload_command_receiver)
+            .retry_sender(workload_command_sender2)
+            .build()
+            .unwrap();
+
+        control_loop_state
+            .retry_manager
+            .expect_invalidate()
+            .return_const(());
+        control_loop_state.retry_manager.expect_new_token().never();
+
+        control_loop_state.workload_id = Some(OLD_WORKLOAD_ID.to_string());
+        control_loop_state.state_checker = Some(old_mock_state_checker);
+
+        assert!(timeout(
+            Duration::from_millis(200),
+            WorkloadControlLoop::run(control_loop_state)
+        )
+        .await
+        .is_ok());
+
+        assert_execution_state_sequence(
+            state_change_rx,
+            vec![
+                (&instance_name, ExecutionState::stopping_requested()),
+                (&instance_name, ExecutionState::removed()),
+                (&instance_name, ExecutionState::suspended()),
+                (&instance_name, ExecutionState::stopping_requested()),
+                (&instance_name, ExecutionState::removed()),
+            ],
+        )
+        .await;
+
+        assert!(workload_command_receiver2.is_closed());
+        assert!(workload_command_receiver2.is_empty());
+        runtime_mock.assert_all_expectations().await;
+    }
+
     // [utest->swdd~agent-workload-control-loop-executes-resume~1]
     #[tokio::test]
     async fn utest_resume_workload() {
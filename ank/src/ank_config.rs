@@ -153,7 +153,7 @@ impl<'de> Visitor<'de> for AnkConfigVisitor {
             match key.as_str() {
                 k if k == self.table_key => {
                     if let Value::Table(inner) = value {
-                        merged.extend(inner.into_iter());
+                        merged.extend(inner);
                     } else {
                         return Err(V::Error::custom(format!(
                             "Expected '{}' to be a table",
@@ -392,6 +392,7 @@ mod tests {
                 command: Some(GetCommands::State {
                     output_format: crate::cli::OutputFormat::Yaml,
                     object_field_mask: Vec::new(),
+                    label_selector: None,
                 }),
             }),
             server_url: Some(TEST_SERVER_URL.to_string()),
@@ -404,6 +405,7 @@ mod tests {
             ca_pem: Some(CA_PEM_PATH.to_string()),
             crt_pem: Some(CRT_PEM_PATH.to_string()),
             key_pem: Some(KEY_PEM_PATH.to_string()),
+            session_name: None,
         };
 
         ank_config.update_with_args(&args);
@@ -442,6 +444,7 @@ mod tests {
                 command: Some(GetCommands::State {
                     output_format: crate::cli::OutputFormat::Yaml,
                     object_field_mask: Vec::new(),
+                    label_selector: None,
                 }),
             }),
             server_url: Some(DEFAULT_SERVER_ADDRESS.to_string()),
@@ -454,6 +457,7 @@ mod tests {
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
+            session_name: None,
         };
 
         ank_config.update_with_args(&args);
@@ -492,6 +496,7 @@ mod tests {
                 command: Some(GetCommands::State {
                     output_format: crate::cli::OutputFormat::Yaml,
                     object_field_mask: Vec::new(),
+                    label_selector: None,
                 }),
             }),
             server_url: Some(DEFAULT_SERVER_ADDRESS.to_string()),
@@ -504,6 +509,7 @@ mod tests {
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
+            session_name: None,
         };
 
         ank_config.update_with_args(&args);
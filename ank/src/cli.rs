@@ -12,13 +12,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{error::Error, ffi::OsStr};
+use std::{error::Error, ffi::OsStr, str::FromStr};
 
-use clap::{command, ArgAction, CommandFactory, Parser, Subcommand, ValueHint};
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueHint};
 
 use clap_complete::{ArgValueCompleter, CompleteEnv, CompletionCandidate};
+use common::objects::LabelSelector;
 
 use crate::filtered_complete_state::FilteredCompleteState;
+use crate::json_path::{JsonPath, JsonPathTemplate};
 
 const ANK_SERVER_URL_ENV_KEY: &str = "ANK_SERVER_URL";
 
@@ -138,6 +140,29 @@ fn object_field_mask_completer(current: &OsStr) -> Vec<CompletionCandidate> {
     completions_object_field_mask(state_from_command(""), current)
 }
 
+fn completions_output_format(current: &OsStr) -> Vec<CompletionCandidate> {
+    const OUTPUT_FORMATS: [&str; 6] = [
+        "table",
+        "wide",
+        "json",
+        "yaml",
+        "jsonpath=",
+        "custom-columns=",
+    ];
+
+    let cur = current.to_str().unwrap_or("");
+    OUTPUT_FORMATS
+        .into_iter()
+        .filter(|s| s.starts_with(cur))
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+// [impl->swdd~cli-shell-completion~1]
+fn output_format_completer(current: &OsStr) -> Vec<CompletionCandidate> {
+    completions_output_format(current)
+}
+
 // [impl->swdd~cli-supports-server-url-cli-argument~1]
 // [impl->swdd~cli-supports-pem-file-paths-as-cli-arguments~1]
 // [impl->swdd~cli-supports-cli-argument-for-insecure-communication~1]
@@ -180,6 +205,10 @@ pub struct AnkCli {
     #[clap(long = "key_pem", env = "ANK_KEY_PEM")]
     /// Path to cli key pem file.
     pub key_pem: Option<String>,
+    #[clap(long = "session-name", env = "ANK_SESSION_NAME")]
+    /// The name of the session shown e.g. in the audit log of the Ankaios server.
+    /// The default is the name of the current user.
+    pub session_name: Option<String>,
 }
 
 /// Supported actions
@@ -188,13 +217,30 @@ pub enum Commands {
     #[command(arg_required_else_help = true)]
     Get(GetArgs),
     #[command(arg_required_else_help = true)]
+    Describe(DescribeArgs),
+    #[command(arg_required_else_help = true)]
     Set(SetArgs),
     #[command(arg_required_else_help = true)]
     Delete(DeleteArgs),
     #[command(arg_required_else_help = true)]
+    Stop(StopArgs),
+    #[command(arg_required_else_help = true)]
+    Start(StartArgs),
+    #[command(arg_required_else_help = true)]
+    Restart(RestartArgs),
+    #[command(arg_required_else_help = true)]
     Run(RunArgs),
     #[command(arg_required_else_help = true)]
     Apply(ApplyArgs),
+    #[command(arg_required_else_help = true)]
+    Diff(DiffArgs),
+    #[command(arg_required_else_help = true)]
+    Rollback(RollbackArgs),
+    #[command(arg_required_else_help = true)]
+    Logs(LogsArgs),
+    Backup(BackupArgs),
+    #[command(arg_required_else_help = true)]
+    Restore(RestoreArgs),
 }
 
 /// Retrieve information about the current Ankaios system
@@ -205,10 +251,80 @@ pub struct GetArgs {
     pub command: Option<GetCommands>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+const OUTPUT_FORMAT_HELP: &str =
+    "table, wide, json, yaml, jsonpath=<template> or custom-columns=<HEADER:path,...>";
+
+// [impl->swdd~cli-supports-uniform-output-formats~1]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OutputFormat {
+    Table,
+    Wide,
     Yaml,
     Json,
+    JsonPath(JsonPathTemplate),
+    CustomColumns(Vec<CustomColumn>),
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(output_format: &str) -> Result<Self, Self::Err> {
+        const JSON_PATH_PREFIX: &str = "jsonpath=";
+        const CUSTOM_COLUMNS_PREFIX: &str = "custom-columns=";
+
+        match output_format {
+            "table" => Ok(OutputFormat::Table),
+            "wide" => Ok(OutputFormat::Wide),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "json" => Ok(OutputFormat::Json),
+            _ => {
+                if let Some(template) = output_format.strip_prefix(JSON_PATH_PREFIX) {
+                    Ok(OutputFormat::JsonPath(template.parse()?))
+                } else if let Some(columns) = output_format.strip_prefix(CUSTOM_COLUMNS_PREFIX) {
+                    columns
+                        .split(',')
+                        .map(str::parse)
+                        .collect::<Result<_, _>>()
+                        .map(OutputFormat::CustomColumns)
+                } else {
+                    Err(format!("expected one of {OUTPUT_FORMAT_HELP}"))
+                }
+            }
+        }
+    }
+}
+
+// A column of the custom-columns output format, e.g. 'NAME:.name'.
+// [impl->swdd~cli-supports-custom-columns-output~1]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomColumn {
+    pub header: String,
+    pub path: JsonPath,
+}
+
+impl FromStr for CustomColumn {
+    type Err = String;
+
+    fn from_str(column: &str) -> Result<Self, Self::Err> {
+        let Some((header, path)) = column.split_once(':') else {
+            return Err(format!(
+                "Invalid custom column '{column}': expected '<HEADER>:<path>'"
+            ));
+        };
+        if header.trim().is_empty() {
+            return Err(format!("Invalid custom column '{column}': empty header"));
+        }
+        let path = path.trim();
+        let path = path
+            .strip_prefix('{')
+            .and_then(|path| path.strip_suffix('}'))
+            .unwrap_or(path);
+
+        Ok(CustomColumn {
+            header: header.trim().to_string(),
+            path: path.parse()?,
+        })
+    }
 }
 
 /// Get commands
@@ -216,16 +332,18 @@ pub enum OutputFormat {
 pub enum GetCommands {
     /// State information of Ankaios system
     State {
-        /// Specify the output format
-        #[arg(short = 'o', value_enum, default_value_t = OutputFormat::Yaml)]
+        /// Specify the output format: yaml, json or jsonpath=<template>
+        #[arg(short = 'o', long = "output", default_value = "yaml", value_parser = OutputFormat::from_str, add = ArgValueCompleter::new(output_format_completer))]
         output_format: OutputFormat,
         /// Select which parts of the state object shall be output e.g. 'desiredState.workloads.nginx' [default: empty = the complete state]
         #[arg(add = ArgValueCompleter::new(object_field_mask_completer))]
         object_field_mask: Vec<String>,
+        /// Only workloads whose tags match the selector shall be contained in the state, e.g. 'team=infotainment,env!=dev,tier in (a,b)'
+        #[arg(short = 'l', long = "selector", value_parser = LabelSelector::from_str)]
+        label_selector: Option<LabelSelector>,
     },
     /// Information about workloads of the Ankaios system
-    /// For automation use "ank get state -o json" and process the workloadStates
-    #[clap(visible_alias("workloads"), verbatim_doc_comment)]
+    #[clap(visible_alias("workloads"))]
     Workload {
         /// Only workloads of the given agent shall be output
         #[arg(short = 'a', long = "agent", required = false)]
@@ -233,18 +351,72 @@ pub enum GetCommands {
         /// Only workloads in the given state shall be output
         #[arg(short = 's', long = "state", required = false)]
         state: Option<String>,
+        /// Only workloads whose tags match the selector shall be output, e.g. 'team=infotainment,env!=dev,tier in (a,b)'
+        #[arg(short = 'l', long = "selector", value_parser = LabelSelector::from_str)]
+        label_selector: Option<LabelSelector>,
         /// Select which workload(s) shall be returned [default: empty = all workloads]
         #[arg(add = ArgValueCompleter::new(workload_completer))]
         workload_name: Vec<String>,
+        /// Keep the table open and update it on changes until interrupted with Ctrl-C
+        #[arg(short = 'w', long = "watch")]
+        watch: bool,
+        /// Specify the output format: table, wide, json, yaml, jsonpath=<template> or custom-columns=<HEADER:path,...>
+        #[arg(short = 'o', long = "output", default_value = "table", value_parser = OutputFormat::from_str, add = ArgValueCompleter::new(output_format_completer))]
+        output_format: OutputFormat,
     },
     /// Information about the Ankaios agents connected to the Ankaios server
-    /// For automation use "ank get state -o json" and process the agents
-    #[clap(visible_alias("agents"), verbatim_doc_comment)]
-    Agent {},
+    #[clap(visible_alias("agents"))]
+    Agent {
+        /// Keep the table open and update it on changes until interrupted with Ctrl-C
+        #[arg(short = 'w', long = "watch")]
+        watch: bool,
+        /// Specify the output format: table, wide, json, yaml, jsonpath=<template> or custom-columns=<HEADER:path,...>
+        #[arg(short = 'o', long = "output", default_value = "table", value_parser = OutputFormat::from_str, add = ArgValueCompleter::new(output_format_completer))]
+        output_format: OutputFormat,
+    },
     /// Information about the Ankaios configs present in the Ankaios system
-    /// For automation use "ank get state -o json" and process desiredState.configs
-    #[clap(visible_alias("configs"), verbatim_doc_comment)]
-    Config {},
+    #[clap(visible_alias("configs"))]
+    Config {
+        /// Specify the output format: table, wide, json, yaml, jsonpath=<template> or custom-columns=<HEADER:path,...>
+        #[arg(short = 'o', long = "output", default_value = "table", value_parser = OutputFormat::from_str, add = ArgValueCompleter::new(output_format_completer))]
+        output_format: OutputFormat,
+    },
+    /// Revisions of the desired state kept by the Ankaios server
+    #[clap(visible_alias("revisions"))]
+    Revision {
+        /// Specify the output format: table, wide, json, yaml, jsonpath=<template> or custom-columns=<HEADER:path,...>
+        #[arg(short = 'o', long = "output", default_value = "table", value_parser = OutputFormat::from_str, add = ArgValueCompleter::new(output_format_completer))]
+        output_format: OutputFormat,
+    },
+    /// Accepted and rejected changes of the desired state recorded in the audit log of the Ankaios server
+    #[clap(visible_alias("audit-log"))]
+    Audit {
+        /// Only print the given number of entries from the end of the audit log, the server sends at most 1000 entries
+        #[arg(long = "tail")]
+        tail: Option<u32>,
+        /// Specify the output format: table, wide, json, yaml, jsonpath=<template> or custom-columns=<HEADER:path,...>
+        #[arg(short = 'o', long = "output", default_value = "table", value_parser = OutputFormat::from_str, add = ArgValueCompleter::new(output_format_completer))]
+        output_format: OutputFormat,
+    },
+}
+
+/// Explain a single object of the Ankaios system in detail
+#[derive(clap::Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct DescribeArgs {
+    #[command(subcommand)]
+    pub command: Option<DescribeCommands>,
+}
+
+/// Describe commands
+#[derive(Debug, Subcommand)]
+pub enum DescribeCommands {
+    /// Stored and rendered configuration, dependencies, dependents and recent execution states of a workload
+    Workload {
+        /// Name of the workload to describe
+        #[arg(required = true, add = ArgValueCompleter::new(workload_completer))]
+        workload_name: String,
+    },
 }
 
 /// Update the state of Ankaios system
@@ -266,6 +438,12 @@ pub enum SetCommands {
         /// A file containing the new State Object Description in yaml format
         #[arg(required = true, value_hint = ValueHint::FilePath)]
         state_object_file: String,
+        /// Update the state even if it was changed since the revision provided in the file
+        #[arg(long)]
+        force: bool,
+        /// Only validate the update and show which workloads would be started and stopped without applying it
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -283,8 +461,11 @@ pub enum DeleteCommands {
     #[clap(visible_alias("workloads"))]
     Workload {
         /// One or more workload(s) to be deleted
-        #[arg(required = true, add = ArgValueCompleter::new(workload_completer))]
+        #[arg(required_unless_present = "label_selector", add = ArgValueCompleter::new(workload_completer))]
         workload_name: Vec<String>,
+        /// Only workloads whose tags match the selector shall be deleted, e.g. 'team=infotainment,env!=dev,tier in (a,b)'
+        #[arg(short = 'l', long = "selector", value_parser = LabelSelector::from_str, conflicts_with = "workload_name")]
+        label_selector: Option<LabelSelector>,
     },
     #[clap(visible_alias("configs"))]
     Config {
@@ -294,6 +475,72 @@ pub enum DeleteCommands {
     },
 }
 
+/// Stop the workload but keep it in the desired state
+#[derive(clap::Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct StopArgs {
+    #[command(subcommand)]
+    pub command: Option<StopCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StopCommands {
+    /// Stop a workload(s) by suspending it
+    #[clap(visible_alias("workloads"))]
+    Workload {
+        /// One or more workload(s) to be stopped
+        #[arg(required_unless_present = "label_selector", add = ArgValueCompleter::new(workload_completer))]
+        workload_name: Vec<String>,
+        /// Only workloads whose tags match the selector shall be stopped, e.g. 'team=infotainment,env!=dev,tier in (a,b)'
+        #[arg(short = 'l', long = "selector", value_parser = LabelSelector::from_str, conflicts_with = "workload_name")]
+        label_selector: Option<LabelSelector>,
+    },
+}
+
+/// Start a previously stopped workload
+#[derive(clap::Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct StartArgs {
+    #[command(subcommand)]
+    pub command: Option<StartCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StartCommands {
+    /// Start a suspended workload(s)
+    #[clap(visible_alias("workloads"))]
+    Workload {
+        /// One or more workload(s) to be started
+        #[arg(required_unless_present = "label_selector", add = ArgValueCompleter::new(workload_completer))]
+        workload_name: Vec<String>,
+        /// Only workloads whose tags match the selector shall be started, e.g. 'team=infotainment,env!=dev,tier in (a,b)'
+        #[arg(short = 'l', long = "selector", value_parser = LabelSelector::from_str, conflicts_with = "workload_name")]
+        label_selector: Option<LabelSelector>,
+    },
+}
+
+/// Restart the workload with its unchanged configuration
+#[derive(clap::Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct RestartArgs {
+    #[command(subcommand)]
+    pub command: Option<RestartCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RestartCommands {
+    /// Restart a workload(s) by deleting and creating it again
+    #[clap(visible_alias("workloads"))]
+    Workload {
+        /// One or more workload(s) to be restarted
+        #[arg(required_unless_present = "label_selector", add = ArgValueCompleter::new(workload_completer))]
+        workload_name: Vec<String>,
+        /// Only workloads whose tags match the selector shall be restarted, e.g. 'team=infotainment,env!=dev,tier in (a,b)'
+        #[arg(short = 'l', long = "selector", value_parser = LabelSelector::from_str, conflicts_with = "workload_name")]
+        label_selector: Option<LabelSelector>,
+    },
+}
+
 /// Run the workload
 #[derive(clap::Args, Debug)]
 #[command(args_conflicts_with_subcommands = true)]
@@ -341,6 +588,87 @@ pub struct ApplyArgs {
     /// Delete mode activated
     #[arg(short)]
     pub delete_mode: bool,
+    /// Apply the manifests even if the state was changed during the apply, since the confirmed diff or since the apply set was read
+    #[arg(long)]
+    pub force: bool,
+    /// Only validate the manifests and show which workloads would be started and stopped without applying them
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Show the differences to the current desired state and ask for confirmation before applying the manifests
+    #[arg(long, conflicts_with = "dry_run")]
+    pub confirm: bool,
+    /// Record the workloads, configs and secrets of the manifests as members of the given apply set
+    #[arg(long = "apply-set", conflicts_with = "delete_mode")]
+    pub apply_set: Option<String>,
+    /// Delete the members of the apply set which are no longer contained in the manifests
+    #[arg(long, requires = "apply_set")]
+    pub prune: bool,
+}
+
+/// Show the differences between Ankaios manifest(s) and the current desired state
+/// Exits with the code 1 if differences exist
+#[derive(clap::Args, Debug)]
+#[command(verbatim_doc_comment)]
+pub struct DiffArgs {
+    #[arg(value_name = "Ankaios manifest file(s) or '-' for stdin", value_hint = ValueHint::FilePath)]
+    pub manifest_files: Vec<String>,
+    /// Specify on which agent the workloads of the Ankaios manifests shall be compared.
+    /// If not specified, the agent(s) must be specified in the Ankaios manifest(s)
+    #[arg(long = "agent")]
+    pub agent_name: Option<String>,
+}
+
+/// Roll the desired state of the Ankaios system back to a previous revision
+#[derive(clap::Args, Debug)]
+pub struct RollbackArgs {
+    /// Revision to roll back to, as listed by 'ank get revisions'
+    pub revision: u64,
+}
+
+/// Print the logs of a workload
+#[derive(clap::Args, Debug)]
+pub struct LogsArgs {
+    /// Name of the workload to print the logs of
+    #[arg(add = ArgValueCompleter::new(workload_completer))]
+    pub workload_name: String,
+    /// Keep printing new log entries until the workload or its agent is gone
+    #[arg(short = 'f', long = "follow")]
+    pub follow: bool,
+    /// Only print log entries newer than the given timestamp or relative time, e.g. '10m'
+    #[arg(long = "since")]
+    pub since: Option<String>,
+    /// Only print the given number of log entries from the end of the logs
+    #[arg(long = "tail")]
+    pub tail: Option<i32>,
+}
+
+/// Write a versioned backup of the desired state of the Ankaios system
+///
+/// The values of the secrets are redacted in the backup.
+#[derive(clap::Args, Debug)]
+pub struct BackupArgs {
+    /// File to write the backup to [default: stdout]
+    #[arg(short = 'o', long = "output", value_hint = ValueHint::FilePath)]
+    pub output_file: Option<String>,
+    /// Include a snapshot of the workload states and agents for diagnostics
+    #[arg(long)]
+    pub diagnostics: bool,
+}
+
+/// Replace the desired state of the Ankaios system with a backup
+///
+/// The redacted secret values of a backup can only be restored if the secrets exist on the Ankaios server.
+/// Otherwise, replace the redacted values with the secret values before restoring the backup.
+#[derive(clap::Args, Debug)]
+pub struct RestoreArgs {
+    #[arg(value_name = "Backup file or '-' for stdin", value_hint = ValueHint::FilePath)]
+    pub backup_file: String,
+    /// Reject the restore if the desired state was changed since the backup was created
+    #[arg(long)]
+    pub expect_revision: bool,
+    /// Only validate the backup and show which workloads would be started and stopped without restoring it
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 fn parse_key_val<K, V>(s: &str) -> Result<(K, V), Box<dyn Error + Send + Sync + 'static>>
@@ -371,7 +699,10 @@ pub fn parse() -> AnkCli {
 #[cfg(test)]
 mod tests {
 
-    use super::{completions_object_field_mask, completions_workloads};
+    use super::{
+        completions_object_field_mask, completions_output_format, completions_workloads,
+        OutputFormat,
+    };
     use clap_complete::CompletionCandidate;
     use std::ffi::OsStr;
 
@@ -544,4 +875,49 @@ mod tests {
         completions.sort();
         assert_eq!(completions, vec![], "Completions do not match");
     }
+
+    // [utest->swdd~cli-shell-completion~1]
+    #[test]
+    fn utest_completions_output_format() {
+        assert_eq!(
+            completions_output_format(OsStr::new("j")),
+            vec![
+                CompletionCandidate::new("json"),
+                CompletionCandidate::new("jsonpath="),
+            ],
+            "Completions do not match"
+        );
+    }
+
+    // [utest->swdd~cli-supports-uniform-output-formats~1]
+    // [utest->swdd~cli-supports-custom-columns-output~1]
+    #[test]
+    fn utest_output_format_from_str() {
+        assert_eq!("table".parse(), Ok(OutputFormat::Table));
+        assert_eq!("wide".parse(), Ok(OutputFormat::Wide));
+        assert_eq!("yaml".parse(), Ok(OutputFormat::Yaml));
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!(
+            "jsonpath={.workloads[*].name}".parse(),
+            Ok(OutputFormat::JsonPath(
+                "{.workloads[*].name}".parse().unwrap()
+            ))
+        );
+
+        let Ok(OutputFormat::CustomColumns(columns)) =
+            "custom-columns=NAME:.name,STATE:{.executionState}".parse::<OutputFormat>()
+        else {
+            panic!("Custom columns not parsed");
+        };
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].header, "NAME");
+        assert_eq!(columns[0].path, ".name".parse().unwrap());
+        assert_eq!(columns[1].header, "STATE");
+        assert_eq!(columns[1].path, ".executionState".parse().unwrap());
+
+        assert!("xml".parse::<OutputFormat>().is_err());
+        assert!("jsonpath={.name".parse::<OutputFormat>().is_err());
+        assert!("custom-columns=NAME".parse::<OutputFormat>().is_err());
+        assert!("custom-columns=:.name".parse::<OutputFormat>().is_err());
+    }
 }
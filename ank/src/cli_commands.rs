@@ -20,29 +20,50 @@ pub mod server_connection;
 mod wait_list;
 use grpc::security::TLSConfig;
 mod cli_table;
+mod item_list;
 use tokio::time::interval;
 use wait_list::WaitList;
 mod workload_table_row;
 use workload_table_row::WorkloadTableRow;
 mod agent_table_row;
+mod audit_table_row;
 mod config_table_row;
+mod revision_table_row;
 mod wait_list_display;
+mod workload_change_table_row;
 
 // CLI commands implemented in another files
 mod apply_manifests;
+mod apply_set;
+mod backup;
 mod delete_configs;
 mod delete_workloads;
+mod describe_workload;
+mod diff_manifests;
+mod dry_run;
 mod get_agents;
+mod get_audit_log;
 mod get_configs;
+mod get_revisions;
 mod get_state;
 mod get_workloads;
+mod logs;
+mod restart_workloads;
+mod restore;
+mod rollback;
 mod run_workload;
 mod set_state;
+mod suspend_workloads;
+mod watch;
 
+use api::ank_base;
 use common::{
     communications_error::CommunicationMiddlewareError,
     from_server_interface::FromServer,
-    objects::{CompleteState, State, WorkloadInstanceName, WorkloadState, WorkloadStatesMap},
+    objects::{
+        CompleteState, LabelSelector, State, WorkloadInstanceName, WorkloadState, WorkloadStatesMap,
+    },
+    PATH_SEPARATOR,
 };
 
 use wait_list_display::WaitListDisplay;
@@ -53,7 +74,7 @@ use crate::{
     cli_commands::wait_list::ParsedUpdateStateSuccess,
     cli_error::CliError,
     filtered_complete_state::{FilteredCompleteState, FilteredWorkloadSpec},
-    output, output_debug,
+    output, output_debug, output_warn,
 };
 
 #[cfg(test)]
@@ -70,8 +91,39 @@ fn open_manifest(
     }
 }
 
+pub const DESIRED_STATE: &str = "desiredState";
 pub const DESIRED_STATE_CONFIGS: &str = "desiredState.configs";
 pub const DESIRED_STATE_WORKLOADS: &str = "desiredState.workloads";
+pub const DESIRED_STATE_SECRETS: &str = "desiredState.secrets";
+pub const WORKLOAD_STATES: &str = "workloadStates";
+pub const AGENTS: &str = "agents";
+
+// Only the updated workloads and the workload states are read to watch an update,
+// so that the update does not require read access to the complete state.
+// [impl->swdd~cli-requests-update-state-with-watch~3]
+fn create_watch_field_mask(update_mask: &[String]) -> Vec<String> {
+    let workloads_prefix = format!("{DESIRED_STATE_WORKLOADS}{PATH_SEPARATOR}");
+    let mut watch_field_mask: Vec<String> = Vec::new();
+    for path in update_mask {
+        let workload_path = if path == DESIRED_STATE || path == DESIRED_STATE_WORKLOADS {
+            DESIRED_STATE_WORKLOADS.to_owned()
+        } else if let Some(workload_subpath) = path.strip_prefix(&workloads_prefix) {
+            let workload_name = workload_subpath
+                .split(PATH_SEPARATOR)
+                .next()
+                .unwrap_or_default();
+            format!("{workloads_prefix}{workload_name}")
+        } else {
+            continue;
+        };
+
+        if !watch_field_mask.contains(&workload_path) {
+            watch_field_mask.push(workload_path);
+        }
+    }
+    watch_field_mask.push(WORKLOAD_STATES.to_owned());
+    watch_field_mask
+}
 
 pub fn get_input_sources(manifest_files: &[String]) -> Result<Vec<InputSourcePair>, String> {
     if let Some(first_arg) = manifest_files.first() {
@@ -104,6 +156,21 @@ pub fn get_input_sources(manifest_files: &[String]) -> Result<Vec<InputSourcePai
 
 pub type InputSourcePair = (String, Box<dyn std::io::Read + Send + Sync + 'static>);
 
+#[cfg(not(test))]
+pub fn read_confirmation(question: &str) -> Result<bool, String> {
+    use std::io::Write;
+    print!("{question} [y/N] ");
+    std::io::stdout()
+        .flush()
+        .map_err(|err| format!("Could not ask for confirmation: {err}"))?;
+
+    let mut answer = String::new();
+    std::io::stdin()
+        .read_line(&mut answer)
+        .map_err(|err| format!("Could not read the confirmation: {err}"))?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 #[derive(Debug)]
 pub struct WorkloadInfos(Vec<(WorkloadInstanceName, WorkloadTableRow)>);
 
@@ -186,6 +253,59 @@ impl CliCommands {
         Ok(self.transform_into_workload_infos(res_complete_state))
     }
 
+    // [impl->swdd~cli-selects-workloads-by-label-selector~1]
+    async fn get_selected_workload_names(
+        &mut self,
+        label_selector: &LabelSelector,
+    ) -> Result<Vec<String>, CliError> {
+        let selected_complete_state = self
+            .server_connection
+            .get_selected_complete_state(&[DESIRED_STATE_WORKLOADS.to_string()], label_selector)
+            .await?;
+
+        let mut workload_names: Vec<String> = selected_complete_state
+            .desired_state
+            .and_then(|desired_state| desired_state.workloads)
+            .unwrap_or_default()
+            .into_keys()
+            .collect();
+        workload_names.sort();
+        Ok(workload_names)
+    }
+
+    async fn get_existing_workloads(
+        &mut self,
+        workload_names: &[String],
+    ) -> Result<HashMap<String, FilteredWorkloadSpec>, CliError> {
+        let field_masks: Vec<String> = workload_names
+            .iter()
+            .map(|workload_name| format!("{}.{}", DESIRED_STATE_WORKLOADS, workload_name))
+            .collect();
+
+        let existing_workloads = self
+            .server_connection
+            .get_complete_state(&field_masks)
+            .await?
+            .desired_state
+            .and_then(|desired_state| desired_state.workloads)
+            .unwrap_or_default();
+
+        let unknown_workloads: Vec<&str> = workload_names
+            .iter()
+            .filter(|workload_name| !existing_workloads.contains_key(*workload_name))
+            .map(String::as_str)
+            .collect();
+
+        if unknown_workloads.is_empty() {
+            Ok(existing_workloads)
+        } else {
+            Err(CliError::ExecutionError(format!(
+                "Workload(s) '{}' not found.",
+                unknown_workloads.join("', '")
+            )))
+        }
+    }
+
     // [impl->swdd~processes-complete-state-to-list-workloads~1]
     fn transform_into_workload_infos(
         &self,
@@ -213,10 +333,11 @@ impl CliCommands {
                 .iter()
                 .find(|&(wl_name, wl_spec)| {
                     *wl_name == table_row.name
+                        // workloads without an agent are scheduled by the server
                         && wl_spec
                             .agent
                             .as_deref()
-                            .is_some_and(|x| x == table_row.agent)
+                            .is_some_and(|x| x == table_row.agent || x.is_empty())
                         && wl_spec.runtime.as_ref().is_some()
                 })
                 // runtime is valid because the filter above has found one
@@ -229,23 +350,55 @@ impl CliCommands {
         workload_infos
     }
 
-    // [impl->swdd~cli-requests-update-state-with-watch~2]
+    // [impl->swdd~cli-requests-update-state-with-watch~3]
     async fn update_state_and_wait_for_complete(
         &mut self,
         new_state: CompleteState,
         update_mask: Vec<String>,
     ) -> Result<(), CliError> {
+        let watch_field_mask = create_watch_field_mask(&update_mask);
+
         /* to keep track of deleted not initially started workloads in the wait mode
         the current workloads before the update must be stored in an ordered map. Affects only user output.
         The updated state is created directly, independent of fetching the current workloads. */
-        let current_workload_infos: BTreeMap<WorkloadInstanceName, WorkloadTableRow> =
-            self.get_workloads().await?.into_iter().collect();
+        let current_workload_infos: BTreeMap<WorkloadInstanceName, WorkloadTableRow> = match self
+            .server_connection
+            .get_complete_state(&watch_field_mask)
+            .await
+        {
+            Ok(current_complete_state) => self
+                .transform_into_workload_infos(current_complete_state)
+                .into_iter()
+                .collect(),
+            // the update itself might still be allowed, e.g., for a role without read access to the workload states
+            Err(err) => {
+                output_debug!(
+                    "Could not get the workloads before the update: {}",
+                    CliError::from(err)
+                );
+                BTreeMap::new()
+            }
+        };
 
         let update_state_success = self
             .server_connection
             .update_state(new_state, update_mask)
             .await?;
 
+        self.process_update_state_success(
+            update_state_success,
+            current_workload_infos,
+            watch_field_mask,
+        )
+        .await
+    }
+
+    async fn process_update_state_success(
+        &mut self,
+        update_state_success: ank_base::UpdateStateSuccess,
+        current_workload_infos: BTreeMap<WorkloadInstanceName, WorkloadTableRow>,
+        watch_field_mask: Vec<String>,
+    ) -> Result<(), CliError> {
         output_debug!("Got update success: {:?}", update_state_success);
 
         // [impl->swdd~cli-requests-update-state-with-watch-error~1]
@@ -260,16 +413,21 @@ impl CliCommands {
             Ok(())
         } else {
             // [impl->swdd~cli-requests-update-state-with-watch-success~1]
-            self.wait_for_complete(update_state_success, current_workload_infos)
-                .await
+            self.wait_for_complete(
+                update_state_success,
+                current_workload_infos,
+                watch_field_mask,
+            )
+            .await
         }
     }
 
-    // [impl->swdd~cli-watches-workloads~1]
+    // [impl->swdd~cli-watches-workloads~2]
     async fn wait_for_complete(
         &mut self,
         update_state_success: ParsedUpdateStateSuccess,
         mut previous_workload_infos: BTreeMap<WorkloadInstanceName, WorkloadTableRow>,
+        mut watch_field_mask: Vec<String>,
     ) -> Result<(), CliError> {
         output_debug!("updated state success: {:?}", update_state_success);
 
@@ -284,11 +442,21 @@ impl CliCommands {
             output!("Successfully applied the manifest(s).\nWaiting for workload(s) to reach desired states (press Ctrl+C to interrupt).\n");
         }
 
-        let field_mask_whole_complete_state = Vec::new();
-        let mut new_complete_state = self
+        watch_field_mask.push(AGENTS.to_owned());
+        let mut new_complete_state = match self
             .server_connection
-            .get_complete_state(&field_mask_whole_complete_state)
-            .await?;
+            .get_complete_state(&watch_field_mask)
+            .await
+        {
+            Ok(new_complete_state) => new_complete_state,
+            Err(err) => {
+                output_warn!(
+                    "The update was applied, but the workloads cannot be watched: {}",
+                    CliError::from(err)
+                );
+                return Ok(());
+            }
+        };
 
         let connected_agents: HashSet<String> = new_complete_state
             .agents
@@ -301,7 +469,7 @@ impl CliCommands {
         let new_workload_infos = self.transform_into_workload_infos(new_complete_state);
 
         // new workloads were added through an updated state or the previous ones might already have reached the next state
-        previous_workload_infos.extend(new_workload_infos.into_iter());
+        previous_workload_infos.extend(new_workload_infos);
 
         let changed_workload_infos = previous_workload_infos
             .into_iter()
@@ -370,7 +538,7 @@ mod tests {
 
     use std::io;
 
-    use super::{get_input_sources, InputSourcePair};
+    use super::{create_watch_field_mask, get_input_sources, InputSourcePair};
 
     mockall::lazy_static! {
         pub static ref FAKE_OPEN_MANIFEST_MOCK_RESULT_LIST: std::sync::Mutex<std::collections::VecDeque<io::Result<InputSourcePair>>>  =
@@ -458,4 +626,36 @@ mod tests {
             actual.iter().map(get_file_name).collect::<Vec<String>>()
         )
     }
+
+    // [utest->swdd~cli-requests-update-state-with-watch~3]
+    #[test]
+    fn utest_create_watch_field_mask_contains_updated_workloads_and_workload_states() {
+        let update_mask = vec![
+            "desiredState.workloads.nginx.restartGeneration".to_string(),
+            "desiredState.workloads.nginx.suspended".to_string(),
+            "desiredState.workloads.db".to_string(),
+            "desiredState.configs.web".to_string(),
+        ];
+
+        assert_eq!(
+            create_watch_field_mask(&update_mask),
+            vec![
+                "desiredState.workloads.nginx".to_string(),
+                "desiredState.workloads.db".to_string(),
+                "workloadStates".to_string(),
+            ]
+        );
+    }
+
+    // [utest->swdd~cli-requests-update-state-with-watch~3]
+    #[test]
+    fn utest_create_watch_field_mask_for_complete_desired_state() {
+        assert_eq!(
+            create_watch_field_mask(&["desiredState".to_string()]),
+            vec![
+                "desiredState.workloads".to_string(),
+                "workloadStates".to_string(),
+            ]
+        );
+    }
 }
@@ -25,4 +25,8 @@ pub struct AgentTableRow {
     pub cpu_usage: String,
     #[tabled(rename = "FREE MEMORY")]
     pub free_memory: String,
+    #[tabled(rename = "LAST SEEN")]
+    pub last_seen: String,
+    #[tabled(rename = "LABELS")]
+    pub labels: String,
 }
@@ -12,7 +12,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{CliCommands, InputSourcePair};
+use super::{
+    apply_set::verify_no_apply_set_records, diff_manifests::format_diff, CliCommands,
+    InputSourcePair,
+};
 use crate::cli_commands::State;
 use crate::cli_error::CliError;
 use crate::output;
@@ -23,9 +26,11 @@ use std::collections::HashSet;
 
 #[cfg(test)]
 use self::tests::get_input_sources_mock as get_input_sources;
+#[cfg(test)]
+use self::tests::read_confirmation_mock as read_confirmation;
 
 #[cfg(not(test))]
-use super::get_input_sources;
+use super::{get_input_sources, read_confirmation};
 
 const WORKLOAD_LEVEL: usize = 1;
 
@@ -131,7 +136,8 @@ pub fn create_filter_masks_from_paths(
 // [impl->swdd~cli-apply-generates-filter-masks-from-ankaios-manifests~1]
 pub fn generate_state_obj_and_filter_masks_from_manifests(
     manifests: &mut [InputSourcePair],
-    apply_args: &ApplyArgs,
+    agent_name: &Option<String>,
+    delete_mode: bool,
 ) -> Result<Option<(CompleteState, Vec<String>)>, String> {
     let mut req_obj: Object = State::default().try_into().unwrap();
     let mut req_paths: Vec<common::state_manipulation::Path> = Vec::new();
@@ -151,13 +157,12 @@ pub fn generate_state_obj_and_filter_masks_from_manifests(
     let filter_masks = create_filter_masks_from_paths(&req_paths, "desiredState");
     output_debug!("\nfilter_masks:\n{:?}\n", filter_masks);
 
-    let complete_state_req_obj = if apply_args.delete_mode {
+    let complete_state_req_obj = if delete_mode {
         CompleteState {
             ..Default::default()
         }
     } else {
-        let state_from_req_obj =
-            handle_agent_overwrite(&req_paths, &apply_args.agent_name, req_obj)?;
+        let state_from_req_obj = handle_agent_overwrite(&req_paths, agent_name, req_obj)?;
         CompleteState {
             desired_state: state_from_req_obj,
             ..Default::default()
@@ -171,22 +176,92 @@ pub fn generate_state_obj_and_filter_masks_from_manifests(
 impl CliCommands {
     // [impl->swdd~cli-apply-accepts-list-of-ankaios-manifests~1]
     pub async fn apply_manifests(&mut self, apply_args: ApplyArgs) -> Result<(), CliError> {
-        match get_input_sources(&apply_args.manifest_files) {
-            Ok(mut manifests) => {
-                if let Some((complete_state_req_obj, filter_masks)) =
-                    generate_state_obj_and_filter_masks_from_manifests(&mut manifests, &apply_args)
-                        .map_err(CliError::ExecutionError)?
-                {
-                    // [impl->swdd~cli-apply-send-update-state~1]
-                    self.update_state_and_wait_for_complete(complete_state_req_obj, filter_masks)
-                        .await
-                } else {
+        // [impl->swdd~cli-apply-confirm-shows-diff-before-update~1]
+        if apply_args.confirm && apply_args.manifest_files.iter().any(|file| file == "-") {
+            return Err(CliError::ExecutionError(
+                "The confirmation cannot be read when the manifests are read from stdin."
+                    .to_owned(),
+            ));
+        }
+
+        let mut manifests = get_input_sources(&apply_args.manifest_files)
+            .map_err(|err| CliError::ExecutionError(err.to_string()))?;
+
+        let (mut complete_state_req_obj, mut filter_masks) =
+            match generate_state_obj_and_filter_masks_from_manifests(
+                &mut manifests,
+                &apply_args.agent_name,
+                apply_args.delete_mode,
+            )
+            .map_err(CliError::ExecutionError)?
+            {
+                Some(state_and_filter_masks) => state_and_filter_masks,
+                // [impl->swdd~cli-apply-prunes-apply-set~1]
+                None if apply_args.prune => (CompleteState::default(), Vec::new()),
+                None => {
                     output!("Nothing to update.");
-                    Ok(())
+                    return Ok(());
                 }
+            };
+
+        // [impl->swdd~cli-apply-rejects-apply-set-records-in-manifests~1]
+        verify_no_apply_set_records(&filter_masks)?;
+
+        // [impl->swdd~cli-apply-records-apply-set~1]
+        if let Some(apply_set) = &apply_args.apply_set {
+            self.add_apply_set_to_update(
+                apply_set,
+                apply_args.prune,
+                !apply_args.force,
+                &mut complete_state_req_obj,
+                &mut filter_masks,
+            )
+            .await?;
+        }
+
+        // [impl->swdd~cli-dry-runs-update-state~1]
+        if apply_args.dry_run {
+            let dry_run_output = self
+                .dry_run_update_state(complete_state_req_obj, filter_masks)
+                .await?;
+            output!("{}", dry_run_output);
+            return Ok(());
+        }
+
+        // [impl->swdd~cli-apply-confirm-shows-diff-before-update~1]
+        if apply_args.confirm {
+            let (diff, revision) = self
+                .diff_with_desired_state(complete_state_req_obj.clone(), &filter_masks)
+                .await?;
+            if diff.is_empty() {
+                output!("No differences to the current desired state.");
+                return Ok(());
+            }
+
+            output!("{}", format_diff(&diff));
+            if !read_confirmation("Apply these changes?").map_err(CliError::ExecutionError)? {
+                output!("Apply aborted.");
+                return Ok(());
+            }
+
+            // reject the update if the desired state was changed after showing the diff
+            // [impl->swdd~cli-sends-revision-with-update-state~2]
+            if !apply_args.force && complete_state_req_obj.revision.is_none() {
+                complete_state_req_obj.revision = revision;
             }
-            Err(err) => Err(CliError::ExecutionError(err.to_string())),
+        } else if !apply_args.force && complete_state_req_obj.revision.is_none() {
+            // reject the update if the desired state was changed during the apply
+            // [impl->swdd~cli-sends-revision-with-update-state~2]
+            complete_state_req_obj.revision = self
+                .server_connection
+                .get_complete_state(&filter_masks)
+                .await?
+                .revision;
         }
+
+        // [impl->swdd~cli-apply-send-update-state~1]
+        self.update_state_and_wait_for_complete(complete_state_req_obj, filter_masks)
+            .await
     }
 }
 
@@ -224,9 +299,10 @@ mod tests {
                 create_filter_masks_from_paths, generate_state_obj_and_filter_masks_from_manifests,
                 handle_agent_overwrite, parse_manifest, update_request_obj,
             },
-            server_connection::MockServerConnection,
+            server_connection::{MockServerConnection, ServerConnectionError},
             CliCommands, InputSourcePair,
         },
+        cli_error::CliError,
         filtered_complete_state::FilteredCompleteState,
     };
 
@@ -235,6 +311,19 @@ mod tests {
         std::sync::Mutex::new(std::collections::VecDeque::new());
     }
 
+    mockall::lazy_static! {
+        pub static ref FAKE_READ_CONFIRMATION_MOCK_RESULT_LIST: std::sync::Mutex<std::collections::VecDeque<Result<bool, String>>>  =
+        std::sync::Mutex::new(std::collections::VecDeque::new());
+    }
+
+    pub fn read_confirmation_mock(_question: &str) -> Result<bool, String> {
+        FAKE_READ_CONFIRMATION_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap()
+    }
+
     pub fn get_input_sources_mock(
         _manifest_files: &[String],
     ) -> Result<Vec<InputSourcePair>, String> {
@@ -575,14 +664,7 @@ mod tests {
 
         assert_eq!(
             Ok(Some((expected_complete_state_obj, expected_filter_masks))),
-            generate_state_obj_and_filter_masks_from_manifests(
-                &mut manifests[..],
-                &ApplyArgs {
-                    agent_name: None,
-                    manifest_files: vec![manifest_file_name.to_string()],
-                    delete_mode: false,
-                },
-            )
+            generate_state_obj_and_filter_masks_from_manifests(&mut manifests[..], &None, false,)
         );
     }
 
@@ -612,19 +694,12 @@ mod tests {
 
         assert_eq!(
             Ok(Some((expected_complete_state_obj, expected_filter_masks))),
-            generate_state_obj_and_filter_masks_from_manifests(
-                &mut manifests[..],
-                &ApplyArgs {
-                    agent_name: None,
-                    manifest_files: vec![manifest_file_name.to_string()],
-                    delete_mode: true,
-                },
-            )
+            generate_state_obj_and_filter_masks_from_manifests(&mut manifests[..], &None, true,)
         );
     }
 
     //[utest->swdd~cli-apply-send-update-state~1]
-    // [utest->swdd~cli-watches-workloads~1]
+    // [utest->swdd~cli-watches-workloads~2]
     #[tokio::test]
     async fn utest_apply_manifests_delete_mode_ok() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
@@ -659,13 +734,26 @@ mod tests {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec![],
                     deleted_workloads: vec!["name4.abc.agent_B".to_string()],
+                    dry_run: false,
                 })
             });
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.simple_manifest1".to_string()
+            ]))
+            .once()
+            .return_once(|_| Ok(FilteredCompleteState::default()));
         let updated_state_clone = updated_state.clone();
         mock_server_connection
             .expect_get_complete_state()
             .times(2)
-            .with(eq(vec![]))
+            .withf(|field_mask| {
+                field_mask.starts_with(&[
+                    "desiredState.workloads.simple_manifest1".to_string(),
+                    "workloadStates".to_string(),
+                ])
+            })
             .returning(move |_| {
                 Ok((ank_base::CompleteState::from(updated_state_clone.clone())).into())
             });
@@ -705,13 +793,18 @@ mod tests {
                 agent_name: None,
                 delete_mode: true,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
             })
             .await;
         assert!(apply_result.is_ok());
     }
 
     //[utest->swdd~cli-apply-send-update-state~1]
-    // [utest->swdd~cli-watches-workloads~1]
+    // [utest->swdd~cli-watches-workloads~2]
     #[tokio::test]
     async fn utest_apply_manifests_workloads_updated_ok() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
@@ -746,16 +839,21 @@ mod tests {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec!["simple_manifest1.abc.agent_B".to_string()],
                     deleted_workloads: vec![],
+                    dry_run: false,
                 })
             });
         mock_server_connection
             .expect_get_complete_state()
-            .once()
+            .times(2)
             .returning(|_| Ok(FilteredCompleteState::default()));
 
         mock_server_connection
             .expect_get_complete_state()
-            .with(eq(vec![]))
+            .with(eq(vec![
+                "desiredState.workloads.simple_manifest1".to_string(),
+                "workloadStates".to_string(),
+                "agents".to_string(),
+            ]))
             .return_once(|_| {
                 Ok((ank_base::CompleteState::from(CompleteState {
                     desired_state: updated_state.desired_state,
@@ -817,6 +915,11 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
             })
             .await;
         assert!(apply_result.is_ok());
@@ -854,7 +957,7 @@ mod tests {
 
         mock_server_connection
             .expect_get_complete_state()
-            .return_once(|_| Ok(FilteredCompleteState::default()));
+            .returning(|_| Ok(FilteredCompleteState::default()));
 
         mock_server_connection
             .expect_take_missed_from_server_messages()
@@ -883,6 +986,11 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
             })
             .await;
         assert!(apply_result.is_ok());
@@ -930,11 +1038,65 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
             })
             .await;
         assert!(apply_result.is_ok());
     }
 
+    // [utest->swdd~cli-apply-rejects-apply-set-records-in-manifests~1]
+    #[tokio::test]
+    async fn utest_apply_manifests_rejects_apply_set_record() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nconfigs:\n  ankaios-apply-set-frontend:\n    configs: []",
+        );
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection.expect_get_complete_state().never();
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: Some("frontend".to_string()),
+                prune: false,
+            })
+            .await;
+        assert_eq!(
+            apply_result,
+            Err(CliError::ExecutionError(
+                "The manifests contain the config(s) 'ankaios-apply-set-frontend'. The prefix 'ankaios-apply-set-' is reserved for the records of apply sets.".to_string()
+            ))
+        );
+    }
+
     #[tokio::test]
     async fn utest_apply_manifest_invalid_names() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
@@ -969,6 +1131,7 @@ mod tests {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec!["simple_manifest1.abc.agent_B".to_string()],
                     deleted_workloads: vec![],
+                    dry_run: false,
                 })
             });
         mock_server_connection
@@ -1035,6 +1198,511 @@ mod tests {
                 agent_name: None,
                 delete_mode: false,
                 manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
+            })
+            .await;
+        assert!(apply_result.is_err());
+    }
+
+    fn revision_test_manifest() -> (io::Cursor<&'static [u8]>, CompleteState) {
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads:
+        simple_manifest1:
+          runtime: podman
+          agent: agent_A
+          runtimeConfig: \"\"
+            "
+            .as_slice(),
+        );
+
+        let mut manifest_data = String::new();
+        let _ = manifest_content.clone().read_to_string(&mut manifest_data);
+
+        let updated_state = CompleteState {
+            desired_state: serde_yaml::from_str(&manifest_data).unwrap(),
+            revision: Some(3),
+            ..Default::default()
+        };
+        (manifest_content, updated_state)
+    }
+
+    fn mock_revision_read_before_apply(mock_server_connection: &mut MockServerConnection) {
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.simple_manifest1".to_string()
+            ]))
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(3),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.simple_manifest1".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(4),
+                    ..Default::default()
+                })
+            });
+    }
+
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_sends_revision_read_before_apply() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (manifest_content, updated_state) = revision_test_manifest();
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_revision_read_before_apply(&mut mock_server_connection);
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(updated_state),
+                eq(vec!["desiredState.workloads.simple_manifest1".to_string()]),
+            )
+            .once()
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_fails_on_stale_revision() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let (manifest_content, updated_state) = revision_test_manifest();
+        let stale_revision_error = "UpdateState failed with: 'stale revision'. Fetch the current state and retry or use '--force' to overwrite the changes.";
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_revision_read_before_apply(&mut mock_server_connection);
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(updated_state),
+                eq(vec!["desiredState.workloads.simple_manifest1".to_string()]),
+            )
+            .once()
+            .return_once(|_, _| {
+                Err(ServerConnectionError::ExecutionError(
+                    stale_revision_error.to_string(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
+            })
+            .await;
+        assert_eq!(
+            apply_result,
+            Err(CliError::ExecutionError(stale_revision_error.to_string()))
+        );
+    }
+
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_force_sends_no_revision() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads:
+        simple_manifest1:
+          runtime: podman
+          agent: agent_A
+          runtimeConfig: \"\"
+            ",
+        );
+
+        let mut manifest_data = String::new();
+        let _ = manifest_content.clone().read_to_string(&mut manifest_data);
+
+        let updated_state = CompleteState {
+            desired_state: serde_yaml::from_str(&manifest_data).unwrap(),
+            revision: None,
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.simple_manifest1".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(3),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(updated_state),
+                eq(vec!["desiredState.workloads.simple_manifest1".to_string()]),
+            )
+            .once()
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec![],
+                    deleted_workloads: vec![],
+                    dry_run: false,
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                force: true,
+                dry_run: false,
+                confirm: false,
+                apply_set: None,
+                prune: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-dry-runs-update-state~1]
+    #[tokio::test]
+    async fn utest_apply_manifests_dry_run_does_not_update_state() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads:
+        simple_manifest1:
+          runtime: podman
+          agent: agent_A
+          runtimeConfig: \"\"
+            ",
+        );
+
+        let mut manifest_data = String::new();
+        let _ = manifest_content.clone().read_to_string(&mut manifest_data);
+
+        let updated_state = CompleteState {
+            desired_state: serde_yaml::from_str(&manifest_data).unwrap(),
+            revision: None,
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .with(
+                eq(updated_state),
+                eq(vec!["desiredState.workloads.simple_manifest1".to_string()]),
+            )
+            .once()
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec!["simple_manifest1.id.agent_A".to_owned()],
+                    deleted_workloads: vec![],
+                    dry_run: true,
+                })
+            });
+        mock_server_connection.expect_update_state().never();
+        mock_server_connection.expect_get_complete_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                force: true,
+                dry_run: true,
+                confirm: false,
+                apply_set: None,
+                prune: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-apply-confirm-shows-diff-before-update~1]
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_apply_manifests_confirm_accepted_updates_with_revision_of_diff() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads:
+        simple_manifest1:
+          runtime: podman
+          agent: agent_A
+          runtimeConfig: \"\"
+            ",
+        );
+
+        let mut manifest_data = String::new();
+        let _ = manifest_content.clone().read_to_string(&mut manifest_data);
+
+        let updated_state = CompleteState {
+            desired_state: serde_yaml::from_str(&manifest_data).unwrap(),
+            revision: Some(4),
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.simple_manifest1".to_string()
+            ]))
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(4),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.simple_manifest1".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(5),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(updated_state),
+                eq(vec!["desiredState.workloads.simple_manifest1".to_string()]),
+            )
+            .once()
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec![],
+                    deleted_workloads: vec![],
+                    dry_run: false,
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+        FAKE_READ_CONFIRMATION_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(true));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: true,
+                apply_set: None,
+                prune: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-apply-confirm-shows-diff-before-update~1]
+    #[tokio::test]
+    async fn utest_apply_manifests_confirm_declined_does_not_update() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let manifest_content = io::Cursor::new(
+            b"apiVersion: \"v0.1\"\nworkloads:
+        simple_manifest1:
+          runtime: podman
+          agent: agent_A
+          runtimeConfig: \"\"
+            ",
+        );
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(4),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(manifest_content),
+            )]));
+        FAKE_READ_CONFIRMATION_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(false));
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["manifest_yaml".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: true,
+                apply_set: None,
+                prune: false,
+            })
+            .await;
+        assert!(apply_result.is_ok());
+    }
+
+    // [utest->swdd~cli-apply-confirm-shows-diff-before-update~1]
+    #[tokio::test]
+    async fn utest_apply_manifests_confirm_rejects_manifests_from_stdin() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection.expect_get_complete_state().never();
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let apply_result = cmd
+            .apply_manifests(ApplyArgs {
+                agent_name: None,
+                delete_mode: false,
+                manifest_files: vec!["-".to_string()],
+                force: false,
+                dry_run: false,
+                confirm: true,
+                apply_set: None,
+                prune: false,
             })
             .await;
         assert!(apply_result.is_err());
@@ -0,0 +1,499 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeSet, HashMap};
+
+use common::objects::{CompleteState, ConfigItem, Tag};
+
+use super::{CliCommands, DESIRED_STATE_CONFIGS, DESIRED_STATE_SECRETS, DESIRED_STATE_WORKLOADS};
+use crate::{cli_error::CliError, output_debug};
+
+pub const APPLY_SET_TAG_KEY: &str = "ankaios-apply-set";
+const APPLY_SET_RECORD_PREFIX: &str = "ankaios-apply-set-";
+const RECORD_CONFIGS: &str = "configs";
+const RECORD_SECRETS: &str = "secrets";
+
+// Configs and secrets cannot carry a tag, hence their names are recorded in a config item per apply set.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ApplySetRecord {
+    configs: BTreeSet<String>,
+    secrets: BTreeSet<String>,
+}
+
+impl ApplySetRecord {
+    fn is_empty(&self) -> bool {
+        self.configs.is_empty() && self.secrets.is_empty()
+    }
+
+    fn extend(&mut self, other: ApplySetRecord) {
+        self.configs.extend(other.configs);
+        self.secrets.extend(other.secrets);
+    }
+}
+
+impl From<&ConfigItem> for ApplySetRecord {
+    fn from(value: &ConfigItem) -> Self {
+        let names_of = |key: &str| -> BTreeSet<String> {
+            match value {
+                ConfigItem::ConfigObject(record) => match record.get(key) {
+                    Some(ConfigItem::ConfigArray(names)) => names
+                        .iter()
+                        .filter_map(|name| match name {
+                            ConfigItem::String(name) => Some(name.clone()),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => BTreeSet::new(),
+                },
+                _ => BTreeSet::new(),
+            }
+        };
+
+        ApplySetRecord {
+            configs: names_of(RECORD_CONFIGS),
+            secrets: names_of(RECORD_SECRETS),
+        }
+    }
+}
+
+impl From<ApplySetRecord> for ConfigItem {
+    fn from(value: ApplySetRecord) -> Self {
+        let to_config_array = |names: BTreeSet<String>| {
+            ConfigItem::ConfigArray(names.into_iter().map(ConfigItem::String).collect())
+        };
+
+        ConfigItem::ConfigObject(HashMap::from([
+            (RECORD_CONFIGS.to_owned(), to_config_array(value.configs)),
+            (RECORD_SECRETS.to_owned(), to_config_array(value.secrets)),
+        ]))
+    }
+}
+
+fn verify_apply_set_name(apply_set: &str) -> Result<(), CliError> {
+    if apply_set.is_empty()
+        || !apply_set
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(CliError::ExecutionError(format!(
+            "Invalid apply set name '{apply_set}'. Only alphanumeric characters, '_' and '-' are allowed."
+        )));
+    }
+    Ok(())
+}
+
+// The apply set records are only maintained by `ank apply --apply-set`, manifests changing them
+// would corrupt the membership of the apply sets.
+// [impl->swdd~cli-apply-rejects-apply-set-records-in-manifests~1]
+pub(crate) fn verify_no_apply_set_records(filter_masks: &[String]) -> Result<(), CliError> {
+    let record_names: Vec<&str> = filter_masks
+        .iter()
+        .filter_map(|mask| mask.strip_prefix(DESIRED_STATE_CONFIGS))
+        .filter_map(|config| config.strip_prefix('.'))
+        .filter(|config| config.starts_with(APPLY_SET_RECORD_PREFIX))
+        .collect();
+    if record_names.is_empty() {
+        return Ok(());
+    }
+
+    Err(CliError::ExecutionError(format!(
+        "The manifests contain the config(s) '{}'. The prefix '{APPLY_SET_RECORD_PREFIX}' is reserved for the records of apply sets.",
+        record_names.join("', '")
+    )))
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-apply-records-apply-set~1]
+    // [impl->swdd~cli-apply-prunes-apply-set~1]
+    pub(crate) async fn add_apply_set_to_update(
+        &mut self,
+        apply_set: &str,
+        prune: bool,
+        check_revision: bool,
+        new_state: &mut CompleteState,
+        filter_masks: &mut Vec<String>,
+    ) -> Result<(), CliError> {
+        verify_apply_set_name(apply_set)?;
+
+        let record_name = format!("{APPLY_SET_RECORD_PREFIX}{apply_set}");
+        let record_mask = format!("{DESIRED_STATE_CONFIGS}.{record_name}");
+        let mut request_masks = vec![record_mask.clone()];
+        if prune {
+            request_masks.push(DESIRED_STATE_WORKLOADS.to_owned());
+        }
+
+        let current_state = self
+            .server_connection
+            .get_complete_state(&request_masks)
+            .await?;
+
+        // [impl->swdd~cli-sends-revision-with-update-state~2]
+        if check_revision && new_state.revision.is_none() {
+            new_state.revision = current_state.revision;
+        }
+
+        let (current_workloads, current_configs) = current_state
+            .desired_state
+            .map(|state| (state.workloads, state.configs))
+            .unwrap_or_default();
+        let current_record: ApplySetRecord = current_configs
+            .unwrap_or_default()
+            .get(&record_name)
+            .map(Into::into)
+            .unwrap_or_default();
+
+        let desired_state = &mut new_state.desired_state;
+        for workload in desired_state.workloads.values_mut() {
+            workload.tags.retain(|tag| tag.key != APPLY_SET_TAG_KEY);
+            workload.tags.push(Tag {
+                key: APPLY_SET_TAG_KEY.to_owned(),
+                value: apply_set.to_owned(),
+            });
+        }
+
+        let mut record = ApplySetRecord {
+            configs: desired_state.configs.keys().cloned().collect(),
+            secrets: desired_state.secrets.keys().cloned().collect(),
+        };
+
+        if prune {
+            // workloads, configs and secrets missing in the update mask of the new state are deleted
+            let pruned_workloads = current_workloads
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|(name, workload)| {
+                    !desired_state.workloads.contains_key(name)
+                        && workload
+                            .tags
+                            .iter()
+                            .flatten()
+                            .any(|tag| tag.key == APPLY_SET_TAG_KEY && tag.value == apply_set)
+                })
+                .map(|(name, _)| format!("{DESIRED_STATE_WORKLOADS}.{name}"));
+            let pruned_configs = current_record
+                .configs
+                .difference(&record.configs)
+                .map(|name| format!("{DESIRED_STATE_CONFIGS}.{name}"));
+            let pruned_secrets = current_record
+                .secrets
+                .difference(&record.secrets)
+                .map(|name| format!("{DESIRED_STATE_SECRETS}.{name}"));
+
+            let pruned_masks: Vec<String> = pruned_workloads
+                .chain(pruned_configs)
+                .chain(pruned_secrets)
+                .collect();
+            output_debug!("Pruning from apply set '{apply_set}': {:?}", pruned_masks);
+            filter_masks.extend(pruned_masks);
+        } else {
+            record.extend(current_record);
+        }
+
+        // an empty record is removed from the desired state by keeping it only in the update mask
+        if !record.is_empty() {
+            desired_state.configs.insert(record_name, record.into());
+        }
+        filter_masks.push(record_mask);
+        filter_masks.sort();
+        filter_masks.dedup();
+
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::ank_base;
+    use common::objects::{CompleteState, ConfigItem, Tag};
+    use mockall::predicate::eq;
+
+    use super::{ApplySetRecord, APPLY_SET_TAG_KEY};
+    use crate::{
+        cli_commands::{server_connection::MockServerConnection, CliCommands},
+        filtered_complete_state::FilteredCompleteState,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const APPLY_SET: &str = "frontend";
+    const RECORD_MASK: &str = "desiredState.configs.ankaios-apply-set-frontend";
+    const MANIFEST: &str = r#"apiVersion: "v0.1"
+workloads:
+  nginx:
+    runtime: podman
+    agent: agent_A
+    runtimeConfig: ""
+configs:
+  web: value
+"#;
+    const CURRENT_STATE: &str = r#"apiVersion: "v0.1"
+workloads:
+  nginx:
+    runtime: podman
+    agent: agent_A
+    runtimeConfig: ""
+    tags:
+      - key: ankaios-apply-set
+        value: frontend
+  old_proxy:
+    runtime: podman
+    agent: agent_A
+    runtimeConfig: ""
+    tags:
+      - key: ankaios-apply-set
+        value: frontend
+  foreign:
+    runtime: podman
+    agent: agent_A
+    runtimeConfig: ""
+    tags:
+      - key: ankaios-apply-set
+        value: backend
+configs:
+  ankaios-apply-set-frontend:
+    configs: [web, old_config]
+    secrets: [old_secret]
+"#;
+
+    fn state_from_yaml(state: &str) -> CompleteState {
+        CompleteState {
+            desired_state: serde_yaml::from_str(state).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    fn current_state(revision: u64) -> FilteredCompleteState {
+        let mut current_state: FilteredCompleteState =
+            ank_base::CompleteState::from(state_from_yaml(CURRENT_STATE)).into();
+        current_state.revision = Some(revision);
+        current_state
+    }
+
+    fn expected_record(configs: &[&str], secrets: &[&str]) -> ConfigItem {
+        ApplySetRecord {
+            configs: configs.iter().map(|name| name.to_string()).collect(),
+            secrets: secrets.iter().map(|name| name.to_string()).collect(),
+        }
+        .into()
+    }
+
+    // [utest->swdd~cli-apply-records-apply-set~1]
+    #[test]
+    fn utest_apply_set_record_conversion() {
+        let record = ApplySetRecord {
+            configs: ["b".to_owned(), "a".to_owned()].into(),
+            secrets: ["s".to_owned()].into(),
+        };
+        let config_item: ConfigItem = record.into();
+
+        assert_eq!(
+            ConfigItem::ConfigObject(HashMap::from([
+                (
+                    "configs".to_owned(),
+                    ConfigItem::ConfigArray(vec![
+                        ConfigItem::String("a".to_owned()),
+                        ConfigItem::String("b".to_owned())
+                    ])
+                ),
+                (
+                    "secrets".to_owned(),
+                    ConfigItem::ConfigArray(vec![ConfigItem::String("s".to_owned())])
+                ),
+            ])),
+            config_item
+        );
+        assert_eq!(
+            ApplySetRecord {
+                configs: ["a".to_owned(), "b".to_owned()].into(),
+                secrets: ["s".to_owned()].into(),
+            },
+            ApplySetRecord::from(&config_item)
+        );
+        assert_eq!(
+            ApplySetRecord::default(),
+            ApplySetRecord::from(&ConfigItem::String("invalid".to_owned()))
+        );
+    }
+
+    // [utest->swdd~cli-apply-records-apply-set~1]
+    #[tokio::test]
+    async fn utest_add_apply_set_to_update_tags_workloads_and_merges_record() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![RECORD_MASK.to_owned()]))
+            .once()
+            .return_once(|_| Ok(current_state(7)));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let mut new_state = state_from_yaml(MANIFEST);
+        let mut filter_masks = vec![
+            "desiredState.workloads.nginx".to_owned(),
+            "desiredState.configs.web".to_owned(),
+        ];
+        let result = cmd
+            .add_apply_set_to_update(APPLY_SET, false, true, &mut new_state, &mut filter_masks)
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(Some(7), new_state.revision);
+        assert_eq!(
+            vec![Tag {
+                key: APPLY_SET_TAG_KEY.to_owned(),
+                value: APPLY_SET.to_owned()
+            }],
+            new_state.desired_state.workloads["nginx"].tags
+        );
+        assert_eq!(
+            Some(&expected_record(&["old_config", "web"], &["old_secret"])),
+            new_state
+                .desired_state
+                .configs
+                .get("ankaios-apply-set-frontend")
+        );
+        assert_eq!(
+            vec![
+                "desiredState.configs.ankaios-apply-set-frontend".to_owned(),
+                "desiredState.configs.web".to_owned(),
+                "desiredState.workloads.nginx".to_owned(),
+            ],
+            filter_masks
+        );
+    }
+
+    // [utest->swdd~cli-apply-prunes-apply-set~1]
+    #[tokio::test]
+    async fn utest_add_apply_set_to_update_prunes_removed_objects() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                RECORD_MASK.to_owned(),
+                "desiredState.workloads".to_owned(),
+            ]))
+            .once()
+            .return_once(|_| Ok(current_state(7)));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let mut new_state = state_from_yaml(MANIFEST);
+        let mut filter_masks = vec![
+            "desiredState.workloads.nginx".to_owned(),
+            "desiredState.configs.web".to_owned(),
+        ];
+        let result = cmd
+            .add_apply_set_to_update(APPLY_SET, true, false, &mut new_state, &mut filter_masks)
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(None, new_state.revision);
+        assert_eq!(
+            Some(&expected_record(&["web"], &[])),
+            new_state
+                .desired_state
+                .configs
+                .get("ankaios-apply-set-frontend")
+        );
+        assert_eq!(
+            vec![
+                "desiredState.configs.ankaios-apply-set-frontend".to_owned(),
+                "desiredState.configs.old_config".to_owned(),
+                "desiredState.configs.web".to_owned(),
+                "desiredState.secrets.old_secret".to_owned(),
+                "desiredState.workloads.nginx".to_owned(),
+                "desiredState.workloads.old_proxy".to_owned(),
+            ],
+            filter_masks
+        );
+    }
+
+    // [utest->swdd~cli-apply-prunes-apply-set~1]
+    #[tokio::test]
+    async fn utest_add_apply_set_to_update_prunes_everything_for_empty_manifests() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .once()
+            .return_once(|_| Ok(current_state(7)));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let mut new_state = CompleteState::default();
+        let mut filter_masks = vec![];
+        let result = cmd
+            .add_apply_set_to_update(APPLY_SET, true, true, &mut new_state, &mut filter_masks)
+            .await;
+
+        assert!(result.is_ok());
+        assert!(new_state.desired_state.configs.is_empty());
+        assert_eq!(
+            vec![
+                "desiredState.configs.ankaios-apply-set-frontend".to_owned(),
+                "desiredState.configs.old_config".to_owned(),
+                "desiredState.configs.web".to_owned(),
+                "desiredState.secrets.old_secret".to_owned(),
+                "desiredState.workloads.nginx".to_owned(),
+                "desiredState.workloads.old_proxy".to_owned(),
+            ],
+            filter_masks
+        );
+    }
+
+    // [utest->swdd~cli-apply-records-apply-set~1]
+    #[tokio::test]
+    async fn utest_add_apply_set_to_update_rejects_invalid_name() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection.expect_get_complete_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let result = cmd
+            .add_apply_set_to_update(
+                "front.end",
+                false,
+                true,
+                &mut CompleteState::default(),
+                &mut vec![],
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::WorkloadStatesMap;
+use serde::Serialize;
+
+use super::{CliCommands, DESIRED_STATE};
+use crate::{
+    cli_error::CliError,
+    filtered_complete_state::{FilteredAgentMap, FilteredCompleteState, FilteredState},
+    output_debug,
+};
+
+pub const BACKUP_VERSION: &str = "v1";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateBackup {
+    pub backup_version: String,
+    pub created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+    pub desired_state: FilteredState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostics: Option<BackupDiagnostics>,
+}
+
+// The diagnostics are only informative and ignored when restoring the backup.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupDiagnostics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workload_states: Option<WorkloadStatesMap>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub agents: Option<FilteredAgentMap>,
+}
+
+// [impl->swdd~cli-provides-backup-of-desired-state~1]
+fn create_backup(
+    complete_state: FilteredCompleteState,
+    with_diagnostics: bool,
+    created_at: String,
+) -> Result<StateBackup, CliError> {
+    let desired_state = complete_state.desired_state.ok_or_else(|| {
+        CliError::ExecutionError("The server did not return the desired state.".to_owned())
+    })?;
+
+    Ok(StateBackup {
+        backup_version: BACKUP_VERSION.to_owned(),
+        created_at,
+        revision: complete_state.revision,
+        desired_state,
+        diagnostics: with_diagnostics.then_some(BackupDiagnostics {
+            workload_states: complete_state.workload_states,
+            agents: complete_state.agents,
+        }),
+    })
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-backup-of-desired-state~1]
+    pub async fn backup(&mut self, with_diagnostics: bool) -> Result<String, CliError> {
+        let field_masks = if with_diagnostics {
+            Vec::new()
+        } else {
+            vec![DESIRED_STATE.to_owned()]
+        };
+        let complete_state = self
+            .server_connection
+            .get_complete_state(&field_masks)
+            .await?;
+        output_debug!("Got complete state for the backup: {:?}", complete_state);
+
+        let backup = create_backup(
+            complete_state,
+            with_diagnostics,
+            chrono::Utc::now().format(TIMESTAMP_FORMAT).to_string(),
+        )?;
+        Ok(serde_yaml::to_string(&backup)?)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use api::ank_base;
+    use common::objects::{
+        generate_test_workload_spec_with_param, CompleteState, ConfigItem, State, WorkloadStatesMap,
+    };
+    use mockall::predicate::eq;
+
+    use super::create_backup;
+    use crate::{
+        cli_commands::{server_connection::MockServerConnection, CliCommands},
+        filtered_complete_state::FilteredCompleteState,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const CREATED_AT: &str = "2025-01-01T00:00:00Z";
+
+    fn generate_test_filtered_complete_state() -> FilteredCompleteState {
+        let workload = generate_test_workload_spec_with_param(
+            "agent_A".into(),
+            "nginx".into(),
+            "podman".into(),
+        );
+        let complete_state = CompleteState {
+            desired_state: State {
+                workloads: [(
+                    workload.instance_name.workload_name().to_owned(),
+                    workload.into(),
+                )]
+                .into(),
+                configs: [("c1".to_owned(), ConfigItem::String("value".to_owned()))].into(),
+                ..Default::default()
+            },
+            workload_states: WorkloadStatesMap::default(),
+            ..Default::default()
+        };
+        let mut filtered_complete_state: FilteredCompleteState =
+            ank_base::CompleteState::from(complete_state).into();
+        filtered_complete_state.revision = Some(4);
+        filtered_complete_state
+    }
+
+    // [utest->swdd~cli-provides-backup-of-desired-state~1]
+    #[test]
+    fn utest_create_backup_without_diagnostics() {
+        let complete_state = generate_test_filtered_complete_state();
+
+        let backup = create_backup(complete_state.clone(), false, CREATED_AT.to_owned()).unwrap();
+
+        assert_eq!("v1", backup.backup_version);
+        assert_eq!(CREATED_AT, backup.created_at);
+        assert_eq!(Some(4), backup.revision);
+        assert_eq!(complete_state.desired_state.unwrap(), backup.desired_state);
+        assert!(backup.diagnostics.is_none());
+    }
+
+    // [utest->swdd~cli-provides-backup-of-desired-state~1]
+    #[test]
+    fn utest_create_backup_with_diagnostics() {
+        let complete_state = generate_test_filtered_complete_state();
+
+        let backup = create_backup(complete_state.clone(), true, CREATED_AT.to_owned()).unwrap();
+
+        let diagnostics = backup.diagnostics.unwrap();
+        assert_eq!(complete_state.workload_states, diagnostics.workload_states);
+        assert_eq!(complete_state.agents, diagnostics.agents);
+    }
+
+    // [utest->swdd~cli-provides-backup-of-desired-state~1]
+    #[test]
+    fn utest_create_backup_fails_without_desired_state() {
+        assert!(create_backup(
+            FilteredCompleteState::default(),
+            false,
+            CREATED_AT.to_owned()
+        )
+        .is_err());
+    }
+
+    // [utest->swdd~cli-provides-backup-of-desired-state~1]
+    #[tokio::test]
+    async fn utest_backup_requests_desired_state_only() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec!["desiredState".to_owned()]))
+            .once()
+            .return_once(|_| Ok(generate_test_filtered_complete_state()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let backup = cmd.backup(false).await.unwrap();
+        let backup: serde_yaml::Value = serde_yaml::from_str(&backup).unwrap();
+
+        assert_eq!(Some("v1"), backup["backupVersion"].as_str());
+        assert_eq!(Some(4), backup["revision"].as_u64());
+        assert_eq!(
+            Some("value"),
+            backup["desiredState"]["configs"]["c1"].as_str()
+        );
+        assert!(backup["desiredState"]["workloads"]["nginx"].is_mapping());
+        assert!(backup.get("diagnostics").is_none());
+    }
+
+    // [utest->swdd~cli-provides-backup-of-desired-state~1]
+    #[tokio::test]
+    async fn utest_backup_with_diagnostics_requests_complete_state() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .once()
+            .return_once(|_| Ok(generate_test_filtered_complete_state()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let backup = cmd.backup(true).await.unwrap();
+        let backup: serde_yaml::Value = serde_yaml::from_str(&backup).unwrap();
+
+        assert!(backup.get("diagnostics").is_some());
+    }
+}
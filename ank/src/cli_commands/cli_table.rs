@@ -22,6 +22,7 @@ fn terminal_width() -> usize {
 
 use common::std_extensions::UnreachableOption;
 use tabled::{
+    builder::Builder,
     settings::{object::Columns, Modify, Padding, Style, Width},
     Table, Tabled,
 };
@@ -37,6 +38,9 @@ impl fmt::Display for CliTableError {
     }
 }
 
+const FIRST_COLUMN_POS: usize = 0;
+const ZERO_PADDING: usize = 0;
+
 pub struct CliTable<'a, RowType> {
     rows: &'a [RowType],
     table: Table,
@@ -47,8 +51,6 @@ where
     RowType: Tabled,
 {
     const TRUNCATED_COLUMN_SUFFIX: &'static str = "...";
-    const FIRST_COLUMN_POS: usize = 0;
-    const ZERO_PADDING: usize = 0;
 
     pub fn new(rows: &'a [RowType]) -> Self {
         let table = Table::new(rows);
@@ -111,39 +113,11 @@ where
     }
 
     fn style_blank(&mut self) {
-        self.table.with(Style::blank());
+        style_blank(&mut self.table);
     }
 
     fn disable_surrounding_padding(&mut self) {
-        let last_column_pos = self.table.count_columns() - 1;
-
-        let first_column_default_padding = self
-            .table
-            .get_config()
-            .get_padding(tabled::grid::config::Entity::Column(Self::FIRST_COLUMN_POS));
-
-        /* Set the left padding of the first and the right padding of the last column to zero
-        to align the table content to the full terminal width for better output quality. */
-        self.table
-            .with(Modify::new(Columns::first()).with(Padding::new(
-                Self::ZERO_PADDING,
-                first_column_default_padding.right.size,
-                first_column_default_padding.top.size,
-                first_column_default_padding.bottom.size,
-            )));
-
-        let last_column_default_padding = self
-            .table
-            .get_config()
-            .get_padding(tabled::grid::config::Entity::Column(last_column_pos));
-
-        self.table
-            .with(Modify::new(Columns::last()).with(Padding::new(
-                last_column_default_padding.left.size,
-                Self::ZERO_PADDING,
-                last_column_default_padding.top.size,
-                last_column_default_padding.bottom.size,
-            )));
+        disable_surrounding_padding(&mut self.table);
     }
 
     // [impl->swdd~cli-table-wrapped-truncated-column-width-depends-on-terminal-width~1]
@@ -200,6 +174,52 @@ where
     }
 }
 
+// Creates a table with the default style from the given header and rows without truncating
+// the lines to the terminal width, e.g. for the wide and the custom columns output.
+// [impl->swdd~cli-table-provides-table-output-from-records~1]
+pub fn create_table_from_records(header: Vec<String>, rows: Vec<Vec<String>>) -> String {
+    let mut builder = Builder::default();
+    builder.push_record(header);
+    rows.into_iter().for_each(|row| builder.push_record(row));
+
+    let mut table = builder.build();
+    style_blank(&mut table);
+    disable_surrounding_padding(&mut table);
+    table.to_string()
+}
+
+fn style_blank(table: &mut Table) {
+    table.with(Style::blank());
+}
+
+fn disable_surrounding_padding(table: &mut Table) {
+    let last_column_pos = table.count_columns() - 1;
+
+    let first_column_default_padding = table
+        .get_config()
+        .get_padding(tabled::grid::config::Entity::Column(FIRST_COLUMN_POS));
+
+    /* Set the left padding of the first and the right padding of the last column to zero
+    to align the table content to the full terminal width for better output quality. */
+    table.with(Modify::new(Columns::first()).with(Padding::new(
+        ZERO_PADDING,
+        first_column_default_padding.right.size,
+        first_column_default_padding.top.size,
+        first_column_default_padding.bottom.size,
+    )));
+
+    let last_column_default_padding = table
+        .get_config()
+        .get_padding(tabled::grid::config::Entity::Column(last_column_pos));
+
+    table.with(Modify::new(Columns::last()).with(Padding::new(
+        last_column_default_padding.left.size,
+        ZERO_PADDING,
+        last_column_default_padding.top.size,
+        last_column_default_padding.bottom.size,
+    )));
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -210,7 +230,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{CliTable, Tabled};
+    use super::{create_table_from_records, CliTable, Tabled};
 
     #[derive(Debug, Tabled, Clone)]
     #[tabled(rename_all = "UPPERCASE")]
@@ -389,4 +409,25 @@ mod tests {
 
         assert_eq!(expected_table_output, table_output);
     }
+
+    // [utest->swdd~cli-table-provides-table-output-from-records~1]
+    #[test]
+    fn utest_create_table_from_records_without_truncating() {
+        let table_output = create_table_from_records(
+            vec!["NAME".to_string(), "VALUE".to_string()],
+            vec![
+                vec!["config_1".to_string(), "x".repeat(80)],
+                vec!["config_2".to_string(), "<none>".to_string()],
+            ],
+        );
+
+        let expected_table_output = [
+            format!("NAME       VALUE{}", " ".repeat(75)),
+            format!("config_1   {}", "x".repeat(80)),
+            format!("config_2   <none>{}", " ".repeat(74)),
+        ]
+        .join("\n");
+
+        assert_eq!(table_output, expected_table_output);
+    }
 }
@@ -87,6 +87,7 @@ mod tests {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec![],
                     deleted_workloads: vec![],
+                    dry_run: false,
                 })
             });
         mock_server_connection
@@ -106,7 +107,10 @@ mod tests {
         assert!(delete_result.is_ok());
 
         // Verify that the deleted configs no longer exist in the desired state
-        let get_result = cmd.get_configs().await.unwrap();
+        let get_result = cmd
+            .get_configs(&crate::cli::OutputFormat::Table)
+            .await
+            .unwrap();
 
         assert!(!get_result.contains(CONFIG_1));
         assert!(!get_result.contains(CONFIG_2));
@@ -132,6 +136,7 @@ mod tests {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec![],
                     deleted_workloads: vec![],
+                    dry_run: false,
                 })
             });
 
@@ -12,9 +12,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use common::objects::CompleteState;
+use common::objects::{CompleteState, LabelSelector};
 
-use crate::{cli_commands::DESIRED_STATE_WORKLOADS, cli_error::CliError, output_debug};
+use crate::{cli_commands::DESIRED_STATE_WORKLOADS, cli_error::CliError, output, output_debug};
 
 use super::CliCommands;
 
@@ -39,6 +39,20 @@ impl CliCommands {
         self.update_state_and_wait_for_complete(complete_state_update, update_mask)
             .await
     }
+
+    // [impl->swdd~cli-deletes-workloads-by-label-selector~1]
+    pub async fn delete_selected_workloads(
+        &mut self,
+        label_selector: LabelSelector,
+    ) -> Result<(), CliError> {
+        let workload_names = self.get_selected_workload_names(&label_selector).await?;
+        if workload_names.is_empty() {
+            output!("No workloads match the selector '{}'.", label_selector);
+            return Ok(());
+        }
+
+        self.delete_workloads(workload_names).await
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -54,7 +68,11 @@ mod tests {
     use common::{
         commands::UpdateWorkloadState,
         from_server_interface::FromServer,
-        objects::{self, CompleteState, ExecutionState, WorkloadState},
+        objects::{
+            self, generate_test_workload_spec_with_param, CompleteState, ExecutionState,
+            LabelSelector, WorkloadState,
+        },
+        test_utils,
     };
     use mockall::predicate::eq;
 
@@ -67,7 +85,7 @@ mod tests {
 
     // [utest->swdd~cli-provides-delete-workload~1]
     // [utest->swdd~cli-blocks-until-ankaios-server-responds-delete-workload~2]
-    // [utest->swdd~cli-watches-workloads~1]
+    // [utest->swdd~cli-watches-workloads~2]
     #[tokio::test]
     async fn utest_delete_workloads_two_workloads() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
@@ -93,13 +111,20 @@ mod tests {
                         "name1.abc.agent_B".to_string(),
                         "name2.abc.agent_B".to_string(),
                     ],
+                    dry_run: false,
                 })
             });
 
         mock_server_connection
             .expect_get_complete_state()
             .times(2)
-            .with(eq(vec![]))
+            .withf(|field_mask| {
+                field_mask.starts_with(&[
+                    "desiredState.workloads.name1".to_string(),
+                    "desiredState.workloads.name2".to_string(),
+                    "workloadStates".to_string(),
+                ])
+            })
             .returning(move |_| {
                 Ok((ank_base::CompleteState::from(complete_state_update.clone())).into())
             });
@@ -164,6 +189,7 @@ mod tests {
                 Ok(UpdateStateSuccess {
                     added_workloads: vec![],
                     deleted_workloads: vec![],
+                    dry_run: false,
                 })
             });
 
@@ -178,4 +204,85 @@ mod tests {
             .await;
         assert!(delete_result.is_ok());
     }
+
+    // [utest->swdd~cli-deletes-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_delete_selected_workloads() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let selected_state = test_utils::generate_test_complete_state(vec![
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "name2".to_string(),
+                "runtime".to_string(),
+            ),
+            generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "name1".to_string(),
+                "runtime".to_string(),
+            ),
+        ]);
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .with(
+                eq(vec!["desiredState.workloads".to_string()]),
+                eq("team=infotainment".parse::<LabelSelector>().unwrap()),
+            )
+            .return_once(|_, _| Ok(ank_base::CompleteState::from(selected_state).into()));
+        mock_server_connection
+            .expect_get_complete_state()
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(CompleteState::default()),
+                eq(vec![
+                    "desiredState.workloads.name1".to_string(),
+                    "desiredState.workloads.name2".to_string(),
+                ]),
+            )
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec![],
+                    deleted_workloads: vec![],
+                    dry_run: false,
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .delete_selected_workloads("team=infotainment".parse().unwrap())
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-deletes-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_delete_selected_workloads_nothing_selected() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .return_once(|_, _| Ok(FilteredCompleteState::default()));
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .delete_selected_workloads("team=infotainment".parse().unwrap())
+            .await
+            .is_ok());
+    }
 }
@@ -0,0 +1,456 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use api::ank_base;
+use common::objects::{
+    AddCondition, ConfigItem, ExecutionState, File, WorkloadInstanceName, WorkloadState,
+};
+use serde::Serialize;
+
+use super::{CliCommands, DESIRED_STATE_CONFIGS, DESIRED_STATE_WORKLOADS, WORKLOAD_STATES};
+use crate::{
+    cli_error::CliError,
+    filtered_complete_state::{FilteredCompleteState, FilteredWorkloadSpec},
+    output_debug,
+};
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct WorkloadDescription {
+    name: String,
+    agent: String,
+    instance_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execution_state: Option<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    additional_info: String,
+    spec: FilteredWorkloadSpec,
+    rendered_runtime_config: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rendered_files: Vec<File>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    configs: BTreeMap<String, ResolvedConfig>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    dependencies: BTreeMap<String, DependencyDescription>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dependents: Vec<String>,
+    state_history: Vec<StateRecord>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct ResolvedConfig {
+    config: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<ConfigItem>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct DependencyDescription {
+    condition: AddCondition,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    execution_states: Vec<String>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct StateRecord {
+    timestamp: String,
+    agent: String,
+    instance_id: String,
+    execution_state: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    additional_info: String,
+}
+
+impl From<ank_base::ExecutionStateRecord> for StateRecord {
+    fn from(record: ank_base::ExecutionStateRecord) -> Self {
+        let instance_name: WorkloadInstanceName = record.instance_name.unwrap_or_default().into();
+        let execution_state: ExecutionState = record.execution_state.unwrap_or_default().into();
+        StateRecord {
+            timestamp: record.timestamp,
+            agent: instance_name.agent_name().to_owned(),
+            instance_id: instance_name.id().to_owned(),
+            execution_state: execution_state.state.to_string(),
+            additional_info: execution_state.additional_info,
+        }
+    }
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-workload-description~1]
+    pub async fn describe_workload(&mut self, workload_name: String) -> Result<String, CliError> {
+        let complete_state = self
+            .server_connection
+            .get_complete_state(&[
+                DESIRED_STATE_WORKLOADS.to_string(),
+                DESIRED_STATE_CONFIGS.to_string(),
+                WORKLOAD_STATES.to_string(),
+            ])
+            .await?;
+
+        // the server only renders the workload with the configs listed in the request
+        let mut config_names: Vec<String> = complete_state
+            .desired_state
+            .as_ref()
+            .and_then(|desired_state| desired_state.workloads.as_ref())
+            .and_then(|workloads| workloads.get(&workload_name))
+            .and_then(|workload| workload.configs.as_ref())
+            .map(|configs| configs.values().cloned().collect())
+            .unwrap_or_default();
+        config_names.sort();
+        config_names.dedup();
+
+        let workload_description = self
+            .server_connection
+            .get_workload_description(workload_name.clone(), config_names)
+            .await?;
+        output_debug!("Got workload description: {:?}", workload_description);
+
+        let description =
+            create_workload_description(workload_name, workload_description, complete_state)?;
+        Ok(serde_yaml::to_string(&description)?)
+    }
+}
+
+// [impl->swdd~cli-provides-workload-description~1]
+fn create_workload_description(
+    workload_name: String,
+    workload_description: ank_base::WorkloadDescription,
+    complete_state: FilteredCompleteState,
+) -> Result<WorkloadDescription, CliError> {
+    let (workloads, configs) = complete_state
+        .desired_state
+        .map(|desired_state| {
+            (
+                desired_state.workloads.unwrap_or_default(),
+                desired_state.configs.unwrap_or_default(),
+            )
+        })
+        .unwrap_or_default();
+    let workload_states: Vec<WorkloadState> =
+        complete_state.workload_states.unwrap_or_default().into();
+
+    let Some(spec) = workloads.get(&workload_name).cloned() else {
+        return Err(CliError::ExecutionError(format!(
+            "Workload '{}' is not part of the desired state",
+            workload_name
+        )));
+    };
+
+    let instance_name: WorkloadInstanceName = workload_description
+        .instance_name
+        .unwrap_or_default()
+        .into();
+    let current_state = workload_states
+        .iter()
+        .find(|workload_state| workload_state.instance_name == instance_name)
+        .map(|workload_state| &workload_state.execution_state);
+
+    let rendered_files = workload_description
+        .rendered_files
+        .into_iter()
+        .map(File::try_from)
+        .collect::<Result<Vec<File>, _>>()
+        .map_err(CliError::ExecutionError)?;
+
+    let resolved_configs = spec
+        .configs
+        .iter()
+        .flatten()
+        .map(|(alias, config_name)| {
+            (
+                alias.clone(),
+                ResolvedConfig {
+                    config: config_name.clone(),
+                    value: configs.get(config_name).cloned(),
+                },
+            )
+        })
+        .collect();
+
+    let dependencies = spec
+        .dependencies
+        .iter()
+        .flatten()
+        .map(|(dependency_name, condition)| {
+            (
+                dependency_name.clone(),
+                DependencyDescription {
+                    condition: *condition,
+                    execution_states: workload_states
+                        .iter()
+                        .filter(|workload_state| {
+                            workload_state.instance_name.workload_name() == dependency_name
+                        })
+                        .map(|workload_state| workload_state.execution_state.state.to_string())
+                        .collect(),
+                },
+            )
+        })
+        .collect();
+
+    let mut dependents: Vec<String> = workloads
+        .iter()
+        .filter(|(_, workload)| {
+            workload
+                .dependencies
+                .as_ref()
+                .is_some_and(|dependencies| dependencies.contains_key(&workload_name))
+        })
+        .map(|(dependent_name, _)| dependent_name.clone())
+        .collect();
+    dependents.sort();
+
+    Ok(WorkloadDescription {
+        name: workload_name,
+        agent: instance_name.agent_name().to_owned(),
+        instance_id: instance_name.id().to_owned(),
+        execution_state: current_state.map(|execution_state| execution_state.state.to_string()),
+        additional_info: current_state
+            .map(|execution_state| execution_state.additional_info.clone())
+            .unwrap_or_default(),
+        spec,
+        rendered_runtime_config: workload_description.rendered_runtime_config,
+        rendered_files,
+        configs: resolved_configs,
+        dependencies,
+        dependents,
+        state_history: workload_description
+            .state_history
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    })
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::ank_base;
+    use common::objects::{
+        generate_test_stored_workload_spec, AddCondition, CompleteState, ConfigItem,
+        ExecutionState, State, WorkloadInstanceName, WorkloadStatesMap,
+    };
+    use mockall::predicate::eq;
+
+    use crate::cli_commands::{
+        server_connection::{MockServerConnection, ServerConnectionError},
+        CliCommands,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const AGENT_A: &str = "agent_A";
+    const RUNTIME: &str = "runtime";
+    const WORKLOAD_NAME: &str = "nginx";
+    const DEPENDENCY_NAME: &str = "database";
+    const DEPENDENT_NAME: &str = "frontend";
+    const INSTANCE_ID: &str = "1234";
+
+    fn instance_name(workload_name: &str, id: &str) -> WorkloadInstanceName {
+        WorkloadInstanceName::builder()
+            .agent_name(AGENT_A)
+            .workload_name(workload_name)
+            .id(id)
+            .build()
+    }
+
+    fn complete_state() -> ank_base::CompleteState {
+        let mut workload = generate_test_stored_workload_spec(AGENT_A, RUNTIME);
+        workload.dependencies =
+            HashMap::from([(DEPENDENCY_NAME.to_owned(), AddCondition::AddCondRunning)]);
+        workload.configs = HashMap::from([("port".to_owned(), "web_port".to_owned())]);
+        let mut dependency = generate_test_stored_workload_spec(AGENT_A, RUNTIME);
+        dependency.dependencies = HashMap::new();
+        let mut dependent = generate_test_stored_workload_spec(AGENT_A, RUNTIME);
+        dependent.dependencies =
+            HashMap::from([(WORKLOAD_NAME.to_owned(), AddCondition::AddCondSucceeded)]);
+
+        let mut workload_states = WorkloadStatesMap::default();
+        workload_states.process_new_states(vec![
+            common::objects::WorkloadState {
+                instance_name: instance_name(WORKLOAD_NAME, INSTANCE_ID),
+                execution_state: ExecutionState::failed("exit code 1"),
+            },
+            common::objects::WorkloadState {
+                instance_name: instance_name(DEPENDENCY_NAME, "5678"),
+                execution_state: ExecutionState::running(),
+            },
+        ]);
+
+        CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([
+                    (WORKLOAD_NAME.to_owned(), workload),
+                    (DEPENDENCY_NAME.to_owned(), dependency),
+                    (DEPENDENT_NAME.to_owned(), dependent),
+                ]),
+                configs: HashMap::from([(
+                    "web_port".to_owned(),
+                    ConfigItem::String("8080".to_owned()),
+                )]),
+                ..Default::default()
+            },
+            workload_states,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn workload_description() -> ank_base::WorkloadDescription {
+        ank_base::WorkloadDescription {
+            instance_name: Some(instance_name(WORKLOAD_NAME, INSTANCE_ID).into()),
+            rendered_runtime_config: "port: 8080".to_owned(),
+            rendered_files: vec![],
+            state_history: vec![
+                ank_base::ExecutionStateRecord {
+                    timestamp: "2025-01-01T00:00:00Z".to_owned(),
+                    instance_name: Some(instance_name(WORKLOAD_NAME, INSTANCE_ID).into()),
+                    execution_state: Some(ExecutionState::running().into()),
+                },
+                ank_base::ExecutionStateRecord {
+                    timestamp: "2025-01-01T00:00:10Z".to_owned(),
+                    instance_name: Some(instance_name(WORKLOAD_NAME, INSTANCE_ID).into()),
+                    execution_state: Some(ExecutionState::failed("exit code 1").into()),
+                },
+            ],
+        }
+    }
+
+    // [utest->swdd~cli-provides-workload-description~1]
+    #[tokio::test]
+    async fn utest_describe_workload() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads".to_string(),
+                "desiredState.configs".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .once()
+            .return_once(|_| Ok(complete_state().into()));
+        mock_server_connection
+            .expect_get_workload_description()
+            .with(
+                eq(WORKLOAD_NAME.to_string()),
+                eq(vec!["web_port".to_string()]),
+            )
+            .once()
+            .return_once(|_, _| Ok(workload_description()));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let description: serde_yaml::Value = serde_yaml::from_str(
+            &cmd.describe_workload(WORKLOAD_NAME.to_string())
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(description["name"], WORKLOAD_NAME);
+        assert_eq!(description["agent"], AGENT_A);
+        assert_eq!(description["instanceId"], INSTANCE_ID);
+        assert_eq!(description["executionState"], "Failed(ExecFailed)");
+        assert_eq!(description["additionalInfo"], "exit code 1");
+        assert_eq!(description["spec"]["runtime"], RUNTIME);
+        assert_eq!(description["renderedRuntimeConfig"], "port: 8080");
+        assert_eq!(description["configs"]["port"]["config"], "web_port");
+        assert_eq!(description["configs"]["port"]["value"], "8080");
+        assert_eq!(
+            description["dependencies"][DEPENDENCY_NAME]["condition"],
+            "ADD_COND_RUNNING"
+        );
+        assert_eq!(
+            description["dependencies"][DEPENDENCY_NAME]["executionStates"][0],
+            "Running(Ok)"
+        );
+        assert_eq!(
+            description["dependents"],
+            serde_yaml::to_value(vec![DEPENDENT_NAME]).unwrap()
+        );
+        assert_eq!(
+            description["stateHistory"][0]["executionState"],
+            "Running(Ok)"
+        );
+        assert_eq!(
+            description["stateHistory"][1]["additionalInfo"],
+            "exit code 1"
+        );
+    }
+
+    // [utest->swdd~cli-provides-workload-description~1]
+    #[tokio::test]
+    async fn utest_describe_workload_fails_on_server_error() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .return_once(|_| Ok(ank_base::CompleteState::default().into()));
+        mock_server_connection
+            .expect_get_workload_description()
+            .with(eq(WORKLOAD_NAME.to_string()), eq(vec![]))
+            .return_once(|_, _| {
+                Err(ServerConnectionError::ExecutionError(
+                    "not part of the desired state".to_string(),
+                ))
+            });
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .describe_workload(WORKLOAD_NAME.to_string())
+            .await
+            .is_err());
+    }
+
+    // [utest->swdd~cli-provides-workload-description~1]
+    #[tokio::test]
+    async fn utest_describe_workload_fails_if_workload_was_deleted_meanwhile() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .return_once(|_| Ok(ank_base::CompleteState::default().into()));
+        mock_server_connection
+            .expect_get_workload_description()
+            .return_once(|_, _| Ok(workload_description()));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .describe_workload(WORKLOAD_NAME.to_string())
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,447 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use common::{objects::CompleteState, state_manipulation::Object};
+use crossterm::style::Stylize;
+
+use super::{apply_manifests::generate_state_obj_and_filter_masks_from_manifests, CliCommands};
+use crate::{
+    cli::DiffArgs, cli_error::CliError, filtered_complete_state::FilteredCompleteState,
+    log::interactive, output, output_debug,
+};
+
+#[cfg(test)]
+use self::tests::get_input_sources_mock as get_input_sources;
+
+#[cfg(not(test))]
+use super::get_input_sources;
+
+const DIFF_CONTEXT_LINES: usize = 3;
+const CURRENT_STATE_PREFIX: &str = "current";
+const MANIFEST_PREFIX: &str = "manifest";
+
+// [impl->swdd~cli-diff-compares-manifests-with-desired-state~1]
+pub fn create_manifest_diff(
+    current_state: FilteredCompleteState,
+    mut new_state: CompleteState,
+    filter_masks: &[String],
+) -> Result<Vec<String>, CliError> {
+    // The server only returns redacted secrets, hence only the structure of the secrets can be compared.
+    new_state
+        .desired_state
+        .secrets
+        .values_mut()
+        .for_each(|secret| *secret = secret.redacted());
+
+    // Both states are compared in the representation of the CLI output to hide internal defaults.
+    let new_state: FilteredCompleteState = ank_base::CompleteState::from(new_state).into();
+    let current_state_obj = Object::from(serde_yaml::to_value(current_state)?);
+    let new_state_obj = Object::from(serde_yaml::to_value(new_state)?);
+
+    let mut diff = Vec::new();
+    for filter_mask in filter_masks {
+        let current_lines = to_yaml_lines(current_state_obj.get(&filter_mask.into()))?;
+        let new_lines = to_yaml_lines(new_state_obj.get(&filter_mask.into()))?;
+
+        if current_lines != new_lines {
+            diff.extend(
+                difflib::unified_diff(
+                    &current_lines,
+                    &new_lines,
+                    &format!("{CURRENT_STATE_PREFIX}/{filter_mask}"),
+                    &format!("{MANIFEST_PREFIX}/{filter_mask}"),
+                    "",
+                    "",
+                    DIFF_CONTEXT_LINES,
+                )
+                .iter()
+                .map(|line| line.trim_end().to_owned()),
+            );
+        }
+    }
+    Ok(diff)
+}
+
+fn to_yaml_lines(value: Option<&serde_yaml::Value>) -> Result<Vec<String>, CliError> {
+    match value {
+        Some(value) => Ok(serde_yaml::to_string(value)?
+            .lines()
+            .map(str::to_owned)
+            .collect()),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub fn format_diff(diff: &[String]) -> String {
+    if !interactive() {
+        return diff.join("\n");
+    }
+
+    diff.iter()
+        .map(|line| {
+            if line.starts_with("---") || line.starts_with("+++") {
+                line.as_str().bold().to_string()
+            } else if line.starts_with("@@") {
+                line.as_str().cyan().to_string()
+            } else if line.starts_with('+') {
+                line.as_str().green().to_string()
+            } else if line.starts_with('-') {
+                line.as_str().red().to_string()
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-diff-of-manifests~2]
+    pub async fn diff_manifests(&mut self, diff_args: DiffArgs) -> Result<bool, CliError> {
+        let mut manifests =
+            get_input_sources(&diff_args.manifest_files).map_err(CliError::ExecutionError)?;
+
+        let Some((new_state, filter_masks)) = generate_state_obj_and_filter_masks_from_manifests(
+            &mut manifests,
+            &diff_args.agent_name,
+            false,
+        )
+        .map_err(CliError::ExecutionError)?
+        else {
+            output!("Nothing to compare.");
+            return Ok(false);
+        };
+
+        let (diff, _) = self
+            .diff_with_desired_state(new_state, &filter_masks)
+            .await?;
+        if diff.is_empty() {
+            output!("No differences to the current desired state.");
+            return Ok(false);
+        }
+
+        output!("{}", format_diff(&diff));
+        Ok(true)
+    }
+
+    // Returns the diff together with the revision of the desired state the diff was created for.
+    pub(crate) async fn diff_with_desired_state(
+        &mut self,
+        new_state: CompleteState,
+        filter_masks: &[String],
+    ) -> Result<(Vec<String>, Option<u64>), CliError> {
+        let current_state = self
+            .server_connection
+            .get_complete_state(filter_masks)
+            .await?;
+        output_debug!("Got current state for diff: {:?}", current_state);
+
+        let revision = current_state.revision;
+        Ok((
+            create_manifest_diff(current_state, new_state, filter_masks)?,
+            revision,
+        ))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use api::ank_base;
+    use common::objects::{CompleteState, ConfigItem, State, REDACTED_SECRET_VALUE};
+    use mockall::predicate::eq;
+
+    use super::create_manifest_diff;
+    use crate::{
+        cli::DiffArgs,
+        cli_commands::{server_connection::MockServerConnection, CliCommands, InputSourcePair},
+        filtered_complete_state::FilteredCompleteState,
+    };
+
+    mockall::lazy_static! {
+        pub static ref FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST: std::sync::Mutex<std::collections::VecDeque<Result<Vec<InputSourcePair>, String>>>  =
+        std::sync::Mutex::new(std::collections::VecDeque::new());
+    }
+
+    pub fn get_input_sources_mock(
+        _manifest_files: &[String],
+    ) -> Result<Vec<InputSourcePair>, String> {
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap()
+    }
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const WORKLOAD_MASK: &str = "desiredState.workloads.nginx";
+    const SECRET_MASK: &str = "desiredState.secrets.db";
+    const MANIFEST: &str = r#"apiVersion: "v0.1"
+workloads:
+  nginx:
+    runtime: podman
+    agent: agent_A
+    runtimeConfig: |
+      image: docker.io/nginx:latest
+"#;
+
+    fn state_from_manifest(manifest: &str) -> CompleteState {
+        CompleteState {
+            desired_state: serde_yaml::from_str(manifest).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    fn current_state_from_manifest(manifest: &str, revision: u64) -> FilteredCompleteState {
+        let mut current_state: FilteredCompleteState =
+            ank_base::CompleteState::from(state_from_manifest(manifest)).into();
+        current_state.revision = Some(revision);
+        current_state
+    }
+
+    // [utest->swdd~cli-diff-compares-manifests-with-desired-state~1]
+    #[test]
+    fn utest_create_manifest_diff_no_differences() {
+        let diff = create_manifest_diff(
+            current_state_from_manifest(MANIFEST, 1),
+            state_from_manifest(MANIFEST),
+            &[WORKLOAD_MASK.to_owned()],
+        );
+
+        assert_eq!(Ok(vec![]), diff);
+    }
+
+    // [utest->swdd~cli-diff-compares-manifests-with-desired-state~1]
+    #[test]
+    fn utest_create_manifest_diff_changed_workload() {
+        let diff = create_manifest_diff(
+            current_state_from_manifest(MANIFEST, 1),
+            state_from_manifest(&MANIFEST.replace("latest", "1.27")),
+            &[WORKLOAD_MASK.to_owned()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                "--- current/desiredState.workloads.nginx",
+                "+++ manifest/desiredState.workloads.nginx",
+                "@@ -4,7 +4,7 @@",
+                " restartPolicy: NEVER",
+                " runtime: podman",
+                " runtimeConfig: |",
+                "-  image: docker.io/nginx:latest",
+                "+  image: docker.io/nginx:1.27",
+                " configs: {}",
+                " secrets: {}",
+                " files: []",
+            ],
+            diff
+        );
+    }
+
+    // [utest->swdd~cli-diff-compares-manifests-with-desired-state~1]
+    #[test]
+    fn utest_create_manifest_diff_new_workload() {
+        let diff = create_manifest_diff(
+            FilteredCompleteState {
+                revision: Some(1),
+                ..Default::default()
+            },
+            state_from_manifest(MANIFEST),
+            &[WORKLOAD_MASK.to_owned()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            "--- current/desiredState.workloads.nginx",
+            diff.first().unwrap()
+        );
+        assert!(diff.iter().any(|line| line == "+agent: agent_A"));
+        assert!(diff
+            .iter()
+            .skip(3)
+            .all(|line| line.starts_with('+') || line.starts_with("@@")));
+    }
+
+    // [utest->swdd~cli-diff-compares-manifests-with-desired-state~1]
+    #[test]
+    fn utest_create_manifest_diff_compares_redacted_secrets() {
+        let new_state = CompleteState {
+            desired_state: State {
+                secrets: [(
+                    "db".to_owned(),
+                    ConfigItem::ConfigObject(
+                        [(
+                            "password".to_owned(),
+                            ConfigItem::String("secret".to_owned()),
+                        )]
+                        .into(),
+                    ),
+                )]
+                .into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut current_state = new_state.clone();
+        current_state.desired_state.secrets = current_state
+            .desired_state
+            .secrets
+            .iter()
+            .map(|(name, secret)| (name.clone(), secret.redacted()))
+            .collect();
+        let current_state: FilteredCompleteState =
+            ank_base::CompleteState::from(current_state).into();
+
+        let diff = create_manifest_diff(
+            current_state.clone(),
+            new_state.clone(),
+            &[SECRET_MASK.to_owned()],
+        );
+        assert_eq!(Ok(vec![]), diff);
+
+        let mut extended_state = new_state;
+        if let Some(ConfigItem::ConfigObject(secret)) =
+            extended_state.desired_state.secrets.get_mut("db")
+        {
+            secret.insert("user".to_owned(), ConfigItem::String("admin".to_owned()));
+        }
+        let diff =
+            create_manifest_diff(current_state, extended_state, &[SECRET_MASK.to_owned()]).unwrap();
+        assert!(diff
+            .iter()
+            .any(|line| line == &format!("+user: '{REDACTED_SECRET_VALUE}'")));
+    }
+
+    // [utest->swdd~cli-provides-diff-of-manifests~2]
+    // [utest->swdd~cli-apply-ankaios-manifest-agent-name-overwrite~1]
+    #[tokio::test]
+    async fn utest_diff_manifests_with_agent_overwrite_reports_differences() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![WORKLOAD_MASK.to_owned()]))
+            .once()
+            .return_once(|_| Ok(current_state_from_manifest(MANIFEST, 2)));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(io::Cursor::new(MANIFEST)),
+            )]));
+
+        let diff_result = cmd
+            .diff_manifests(DiffArgs {
+                manifest_files: vec!["manifest.yml".to_owned()],
+                agent_name: Some("agent_B".to_owned()),
+            })
+            .await;
+
+        assert_eq!(Ok(true), diff_result);
+    }
+
+    // [utest->swdd~cli-provides-diff-of-manifests~2]
+    #[tokio::test]
+    async fn utest_diff_manifests_without_differences() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![WORKLOAD_MASK.to_owned()]))
+            .once()
+            .return_once(|_| Ok(current_state_from_manifest(MANIFEST, 2)));
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(io::Cursor::new(MANIFEST)),
+            )]));
+
+        let diff_result = cmd
+            .diff_manifests(DiffArgs {
+                manifest_files: vec!["manifest.yml".to_owned()],
+                agent_name: None,
+            })
+            .await;
+
+        assert_eq!(Ok(false), diff_result);
+    }
+
+    // [utest->swdd~cli-provides-diff-of-manifests~2]
+    #[tokio::test]
+    async fn utest_diff_manifests_fails_on_invalid_manifest() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection.expect_get_complete_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "manifest.yml".to_string(),
+                Box::new(io::Cursor::new(MANIFEST.replace("v0.1", "v0.0"))),
+            )]));
+
+        assert!(cmd
+            .diff_manifests(DiffArgs {
+                manifest_files: vec!["manifest.yml".to_owned()],
+                agent_name: None,
+            })
+            .await
+            .is_err());
+    }
+}
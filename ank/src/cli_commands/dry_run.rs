@@ -0,0 +1,255 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::CompleteState;
+
+use super::CliCommands;
+use crate::{
+    cli_commands::{
+        cli_table::CliTable, wait_list::ParsedUpdateStateSuccess,
+        workload_change_table_row::WorkloadChangeTableRow,
+    },
+    cli_error::CliError,
+    output_debug,
+};
+
+const CHANGE_ADD: &str = "add";
+const CHANGE_UPDATE: &str = "update";
+const CHANGE_DELETE: &str = "delete";
+
+impl CliCommands {
+    // [impl->swdd~cli-dry-runs-update-state~1]
+    pub(crate) async fn dry_run_update_state(
+        &mut self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<String, CliError> {
+        let update_state_success = self
+            .server_connection
+            .dry_run_update_state(new_state, update_mask)
+            .await?;
+        output_debug!("Got dry run result: {:?}", update_state_success);
+
+        let update_state_success = ParsedUpdateStateSuccess::try_from(update_state_success)
+            .map_err(|error| {
+                CliError::ExecutionError(format!(
+                    "Could not parse UpdateStateSuccess message: {error}"
+                ))
+            })?;
+
+        let workload_change_table_rows = transform_into_table_rows(update_state_success);
+        if workload_change_table_rows.is_empty() {
+            return Ok("Dry run successful. No workloads would be updated.".to_owned());
+        }
+
+        Ok(format!(
+            "Dry run successful. The update would change the following workloads:\n{}",
+            CliTable::new(&workload_change_table_rows).create_default_table()
+        ))
+    }
+}
+
+// An added and a deleted workload with the same name are an update of the workload.
+fn transform_into_table_rows(
+    update_state_success: ParsedUpdateStateSuccess,
+) -> Vec<WorkloadChangeTableRow> {
+    let ParsedUpdateStateSuccess {
+        added_workloads,
+        deleted_workloads,
+    } = update_state_success;
+
+    let mut workload_change_table_rows: Vec<WorkloadChangeTableRow> = added_workloads
+        .iter()
+        .map(|added| {
+            let replaced = deleted_workloads
+                .iter()
+                .find(|deleted| deleted.workload_name() == added.workload_name());
+            let agent = match replaced {
+                Some(replaced) if replaced.agent_name() != added.agent_name() => {
+                    format!("{} -> {}", replaced.agent_name(), added.agent_name())
+                }
+                _ => added.agent_name().to_owned(),
+            };
+            WorkloadChangeTableRow {
+                name: added.workload_name().to_owned(),
+                agent,
+                change: if replaced.is_some() {
+                    CHANGE_UPDATE
+                } else {
+                    CHANGE_ADD
+                }
+                .to_owned(),
+                id: added.id().to_owned(),
+            }
+        })
+        .collect();
+
+    workload_change_table_rows.extend(
+        deleted_workloads
+            .iter()
+            .filter(|deleted| {
+                !added_workloads
+                    .iter()
+                    .any(|added| added.workload_name() == deleted.workload_name())
+            })
+            .map(|deleted| WorkloadChangeTableRow {
+                name: deleted.workload_name().to_owned(),
+                agent: deleted.agent_name().to_owned(),
+                change: CHANGE_DELETE.to_owned(),
+                id: deleted.id().to_owned(),
+            }),
+    );
+
+    // sort to ensure consistent output
+    workload_change_table_rows.sort_by(|a, b| a.name.cmp(&b.name));
+    workload_change_table_rows
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::cli_commands::{
+        server_connection::{MockServerConnection, ServerConnectionError},
+        CliCommands,
+    };
+
+    use api::ank_base;
+    use common::objects::CompleteState;
+    use mockall::predicate::eq;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const UPDATE_MASK: &str = "desiredState.workloads";
+
+    // [utest->swdd~cli-dry-runs-update-state~1]
+    #[tokio::test]
+    async fn utest_dry_run_update_state_presents_changes_as_table() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .with(
+                eq(CompleteState {
+                    revision: Some(3),
+                    ..Default::default()
+                }),
+                eq(vec![UPDATE_MASK.to_owned()]),
+            )
+            .return_once(|_, _| {
+                Ok(ank_base::UpdateStateSuccess {
+                    added_workloads: vec![
+                        "nginx.id_2.agent_B".to_owned(),
+                        "api.id_3.agent_A".to_owned(),
+                    ],
+                    deleted_workloads: vec![
+                        "nginx.id_1.agent_A".to_owned(),
+                        "db.id_4.agent_A".to_owned(),
+                    ],
+                    dry_run: true,
+                })
+            });
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let dry_run_result = cmd
+            .dry_run_update_state(
+                CompleteState {
+                    revision: Some(3),
+                    ..Default::default()
+                },
+                vec![UPDATE_MASK.to_owned()],
+            )
+            .await;
+
+        let expected_output = [
+            "Dry run successful. The update would change the following workloads:",
+            "WORKLOAD NAME   AGENT                CHANGE   INSTANCE ID",
+            "api             agent_A              add      id_3       ",
+            "db              agent_A              delete   id_4       ",
+            "nginx           agent_A -> agent_B   update   id_2       ",
+        ]
+        .join("\n");
+
+        assert_eq!(Ok(expected_output), dry_run_result);
+    }
+
+    // [utest->swdd~cli-dry-runs-update-state~1]
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_dry_run_update_state_does_not_add_revision_of_current_state() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection.expect_get_complete_state().never();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .with(
+                eq(CompleteState::default()),
+                eq(vec![UPDATE_MASK.to_owned()]),
+            )
+            .return_once(|_, _| {
+                Ok(ank_base::UpdateStateSuccess {
+                    dry_run: true,
+                    ..Default::default()
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let dry_run_result = cmd
+            .dry_run_update_state(CompleteState::default(), vec![UPDATE_MASK.to_owned()])
+            .await;
+
+        assert_eq!(
+            Ok("Dry run successful. No workloads would be updated.".to_owned()),
+            dry_run_result
+        );
+    }
+
+    // [utest->swdd~cli-dry-runs-update-state~1]
+    #[tokio::test]
+    async fn utest_dry_run_update_state_fails() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .return_once(|_, _| {
+                Err(ServerConnectionError::ExecutionError(
+                    "DryRun failed with: 'Update rejected'".to_owned(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .dry_run_update_state(CompleteState::default(), vec![])
+            .await
+            .is_err());
+    }
+}
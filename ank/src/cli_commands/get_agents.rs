@@ -11,66 +11,140 @@
 // under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-use super::CliCommands;
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use super::{CliCommands, AGENTS, WORKLOAD_STATES};
 use crate::{
-    cli_commands::{agent_table_row::AgentTableRow, cli_table::CliTable},
+    cli::OutputFormat,
+    cli_commands::{agent_table_row::AgentTableRow, cli_table::CliTable, item_list::ItemList},
     cli_error::CliError,
-    filtered_complete_state::FilteredAgentAttributes,
+    filtered_complete_state::{FilteredAgentAttributes, FilteredCompleteState},
     output_debug,
 };
 
 use common::objects::WorkloadStatesMap;
 
 const EMPTY_FILTER_MASK: [String; 0] = [];
+const AGENTS_KIND: &str = "agents";
+const WIDE_COLUMNS: &[(&str, &str)] = &[
+    ("NAME", ".name"),
+    ("WORKLOADS", ".workloads"),
+    ("CPU USAGE", ".cpuUsage"),
+    ("FREE MEMORY", ".freeMemory"),
+    ("LAST SEEN", ".lastSeen"),
+    ("LABELS", ".labels"),
+];
+
+// [impl->swdd~cli-provides-machine-readable-item-lists~1]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AgentItem {
+    name: String,
+    workloads: u32,
+    cpu_usage: Option<u32>,
+    free_memory: Option<u64>,
+    last_seen: Option<String>,
+    labels: BTreeMap<String, String>,
+}
 
 impl CliCommands {
     // [impl->swdd~cli-provides-list-of-agents~1]
     // [impl->swdd~cli-processes-complete-state-to-provide-connected-agents~1]
-    pub async fn get_agents(&mut self) -> Result<String, CliError> {
+    pub async fn get_agents(&mut self, output_format: &OutputFormat) -> Result<String, CliError> {
         let filtered_complete_state = self
             .server_connection
             .get_complete_state(&EMPTY_FILTER_MASK)
             .await?;
 
-        let workload_states_map = filtered_complete_state.workload_states.unwrap_or_default();
+        format_agents(filtered_complete_state, output_format)
+    }
 
-        let connected_agents = filtered_complete_state
-            .agents
-            .and_then(|agents| agents.agents)
-            .unwrap_or_default()
-            .into_iter();
+    // [impl->swdd~cli-watches-list-of-agents~1]
+    pub async fn watch_agents(&mut self, output_format: &OutputFormat) -> Result<(), CliError> {
+        let field_mask = vec![AGENTS.to_string(), WORKLOAD_STATES.to_string()];
+        self.watch_state(field_mask, |_, complete_state| {
+            format_agents(complete_state, output_format)
+        })
+        .await
+    }
+}
 
-        let agent_table_rows = transform_into_table_rows(connected_agents, &workload_states_map);
+// [impl->swdd~cli-processes-complete-state-to-provide-connected-agents~1]
+fn format_agents(
+    filtered_complete_state: FilteredCompleteState,
+    output_format: &OutputFormat,
+) -> Result<String, CliError> {
+    let workload_states_map = filtered_complete_state.workload_states.unwrap_or_default();
 
-        output_debug!("Got agents of complete state: {:?}", agent_table_rows);
+    let connected_agents = filtered_complete_state
+        .agents
+        .and_then(|agents| agents.agents)
+        .unwrap_or_default()
+        .into_iter();
 
-        // [impl->swdd~cli-presents-connected-agents-as-table~2]
-        Ok(CliTable::new(&agent_table_rows).create_default_table())
+    let (agent_items, agent_table_rows) =
+        transform_into_items_and_table_rows(connected_agents, &workload_states_map);
+
+    output_debug!("Got agents of complete state: {:?}", agent_table_rows);
+
+    ItemList {
+        kind: AGENTS_KIND,
+        items: &agent_items,
+        wide_columns: WIDE_COLUMNS,
     }
+    .format(output_format, || {
+        // [impl->swdd~cli-presents-connected-agents-as-table~4]
+        CliTable::new(&agent_table_rows).create_default_table()
+    })
 }
 
-fn transform_into_table_rows(
+fn transform_into_items_and_table_rows(
     agents_map: impl Iterator<Item = (String, FilteredAgentAttributes)>,
     workload_states_map: &WorkloadStatesMap,
-) -> Vec<AgentTableRow> {
-    let mut agent_table_rows: Vec<AgentTableRow> = agents_map
+) -> (Vec<AgentItem>, Vec<AgentTableRow>) {
+    let mut agents: Vec<(AgentItem, AgentTableRow)> = agents_map
         .map(|(agent_name, mut agent_attributes)| {
             let workload_states_count = workload_states_map
                 .get_workload_state_for_agent(&agent_name)
                 .len() as u32;
 
-            AgentTableRow {
+            let agent_item = AgentItem {
+                name: agent_name.clone(),
+                workloads: workload_states_count,
+                cpu_usage: agent_attributes
+                    .cpu_usage
+                    .as_ref()
+                    .and_then(|cpu_usage| cpu_usage.cpu_usage),
+                free_memory: agent_attributes
+                    .free_memory
+                    .as_ref()
+                    .and_then(|free_memory| free_memory.free_memory),
+                last_seen: agent_attributes.last_seen.clone(),
+                labels: agent_attributes
+                    .labels
+                    .clone()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect(),
+            };
+
+            let agent_table_row = AgentTableRow {
                 agent_name,
                 workloads: workload_states_count,
                 cpu_usage: agent_attributes.get_cpu_usage_as_string(),
                 free_memory: agent_attributes.get_free_memory_as_string(),
-            }
+                last_seen: agent_attributes.last_seen.take().unwrap_or_default(),
+                labels: agent_attributes.get_labels_as_string(),
+            };
+            (agent_item, agent_table_row)
         })
         .collect();
 
     // sort to ensure consistent output
-    agent_table_rows.sort_by(|a, b| a.agent_name.cmp(&b.agent_name));
-    agent_table_rows
+    agents.sort_by(|a, b| a.0.name.cmp(&b.0.name));
+    agents.into_iter().unzip()
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -83,6 +157,7 @@ fn transform_into_table_rows(
 
 #[cfg(test)]
 mod tests {
+    use crate::cli::OutputFormat;
     use crate::cli_commands::{
         server_connection::{MockServerConnection, ServerConnectionError},
         CliCommands,
@@ -97,6 +172,7 @@ mod tests {
         test_utils,
     };
     use mockall::predicate::eq;
+    use std::collections::HashMap;
 
     const RESPONSE_TIMEOUT_MS: u64 = 3000;
     const AGENT_A_NAME: &str = "agent_A";
@@ -106,7 +182,49 @@ mod tests {
     const WORKLOAD_NAME_2: &str = "workload_2";
     const RUNTIME_NAME: &str = "runtime";
 
-    // [utest->swdd~cli-presents-connected-agents-as-table~2]
+    // [utest->swdd~cli-provides-machine-readable-item-lists~1]
+    #[tokio::test]
+    async fn utest_get_agents_json() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .return_once(|_| {
+                Ok(
+                    ank_base::CompleteState::from(test_utils::generate_test_complete_state(vec![
+                        generate_test_workload_spec_with_param(
+                            AGENT_A_NAME.to_string(),
+                            WORKLOAD_NAME_1.to_string(),
+                            RUNTIME_NAME.to_string(),
+                        ),
+                    ]))
+                    .into(),
+                )
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let json_output = cmd.get_agents(&OutputFormat::Json).await.unwrap();
+        let json_output: serde_json::Value = serde_json::from_str(&json_output).unwrap();
+
+        assert_eq!(
+            json_output,
+            serde_json::json!({"agents": [{
+                "name": AGENT_A_NAME,
+                "workloads": 1,
+                "cpuUsage": 42,
+                "freeMemory": 42,
+                "lastSeen": null,
+                "labels": {},
+            }]})
+        );
+    }
+
+    // [utest->swdd~cli-presents-connected-agents-as-table~4]
     // [utest->swdd~cli-provides-list-of-agents~1]
     // [utest->swdd~cli-processes-complete-state-to-provide-connected-agents~1]
     #[tokio::test]
@@ -139,12 +257,12 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_agents().await;
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
 
         let expected_table_output = [
-            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY",
-            "agent_A   1           42%         42B        ",
-            "agent_B   1           42%         42B        ",
+            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY   LAST SEEN   LABELS",
+            "agent_A   1           42%         42B                             ",
+            "agent_B   1           42%         42B                             ",
         ]
         .join("\n");
 
@@ -177,9 +295,10 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_agents().await;
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
 
-        let expected_table_output = "NAME   WORKLOADS   CPU USAGE   FREE MEMORY".to_string();
+        let expected_table_output =
+            "NAME   WORKLOADS   CPU USAGE   FREE MEMORY   LAST SEEN   LABELS".to_string();
 
         assert_eq!(Ok(expected_table_output), table_output_result);
     }
@@ -204,11 +323,88 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_agents().await;
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
+
+        let expected_table_output = [
+            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY   LAST SEEN   LABELS",
+            "agent_A   0           42%         42B                             ",
+        ]
+        .join("\n");
+
+        assert_eq!(Ok(expected_table_output), table_output_result);
+    }
+
+    // [utest->swdd~cli-presents-connected-agents-as-table~4]
+    #[tokio::test]
+    async fn test_get_agents_with_labels() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .return_once(|_| {
+                let mut complete_state = test_utils::generate_test_complete_state(vec![]);
+
+                complete_state.agents = generate_test_agent_map(AGENT_A_NAME);
+                complete_state
+                    .agents
+                    .entry(AGENT_A_NAME.to_string())
+                    .and_modify(|agent| {
+                        agent.labels = HashMap::from([
+                            ("zone".to_string(), "front".to_string()),
+                            ("arch".to_string(), "arm".to_string()),
+                        ]);
+                    });
+                Ok(ank_base::CompleteState::from(complete_state).into())
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
+
+        let expected_table_output = [
+            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY   LAST SEEN   LABELS             ",
+            "agent_A   0           42%         42B                       arch=arm,zone=front",
+        ]
+        .join("\n");
+
+        assert_eq!(Ok(expected_table_output), table_output_result);
+    }
+
+    // [utest->swdd~cli-presents-connected-agents-as-table~4]
+    #[tokio::test]
+    async fn test_get_agents_with_last_seen() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .return_once(|_| {
+                let mut complete_state = test_utils::generate_test_complete_state(vec![]);
+
+                complete_state.agents = generate_test_agent_map(AGENT_A_NAME);
+                complete_state
+                    .agents
+                    .entry(AGENT_A_NAME.to_string())
+                    .and_modify(|agent| {
+                        agent.last_seen = Some("2026-10-18T11:09:00Z".to_string());
+                    });
+                Ok(ank_base::CompleteState::from(complete_state).into())
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
 
         let expected_table_output = [
-            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY",
-            "agent_A   0           42%         42B        ",
+            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY   LAST SEEN              LABELS",
+            "agent_A   0           42%         42B           2026-10-18T11:09:00Z         ",
         ]
         .join("\n");
 
@@ -234,7 +430,7 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_agents().await;
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
         assert!(table_output_result.is_err());
     }
 
@@ -270,11 +466,11 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_agents().await;
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
 
         let expected_table_output = [
-            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY",
-            "agent_A   1           42%         42B        ",
+            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY   LAST SEEN   LABELS",
+            "agent_A   1           42%         42B                             ",
         ]
         .join("\n");
 
@@ -307,14 +503,34 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_agents().await;
+        let table_output_result = cmd.get_agents(&OutputFormat::Table).await;
 
         let expected_table_output = [
-            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY",
-            "agent_A   1           42%         42B        ",
+            "NAME      WORKLOADS   CPU USAGE   FREE MEMORY   LAST SEEN   LABELS",
+            "agent_A   1           42%         42B                             ",
         ]
         .join("\n");
 
         assert_eq!(Ok(expected_table_output), table_output_result);
     }
+
+    // [utest->swdd~cli-watches-list-of-agents~1]
+    #[tokio::test]
+    async fn utest_watch_agents_subscribes_to_agents_and_workload_states() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_subscribe_events()
+            .with(eq(vec!["agents".to_string(), "workloadStates".to_string()]))
+            .return_once(|_| Ok("request_id".to_string()));
+        mock_server_connection
+            .expect_read_next_event()
+            .return_once(|_| Ok(None));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.watch_agents(&OutputFormat::Table).await.is_ok());
+    }
 }
@@ -0,0 +1,274 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+use serde::Serialize;
+
+use super::CliCommands;
+use crate::{
+    cli::OutputFormat,
+    cli_commands::{audit_table_row::AuditTableRow, cli_table::CliTable, item_list::ItemList},
+    cli_error::CliError,
+    output_debug,
+};
+use api::ank_base;
+
+const AUDIT_LOG_KIND: &str = "auditLog";
+const WIDE_COLUMNS: &[(&str, &str)] = &[
+    ("TIMESTAMP", ".timestamp"),
+    ("REQUESTER", ".requester"),
+    ("REQUEST", ".request"),
+    ("ACCEPTED", ".accepted"),
+    ("UPDATE MASK", ".updateMask[*]"),
+    ("ADDED", ".addedWorkloads[*]"),
+    ("DELETED", ".deletedWorkloads[*]"),
+    ("REVISION", ".revision"),
+    ("REASON", ".reason"),
+];
+
+// [impl->swdd~cli-provides-machine-readable-item-lists~1]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AuditLogItem {
+    timestamp: String,
+    requester: String,
+    request: String,
+    accepted: bool,
+    update_mask: Vec<String>,
+    added_workloads: Vec<String>,
+    deleted_workloads: Vec<String>,
+    revision: Option<u64>,
+    reason: Option<String>,
+}
+
+impl From<&ank_base::AuditLogEntry> for AuditLogItem {
+    fn from(entry: &ank_base::AuditLogEntry) -> Self {
+        AuditLogItem {
+            timestamp: entry.timestamp.clone(),
+            requester: entry.requester.clone(),
+            request: entry.request.clone(),
+            accepted: entry.accepted,
+            update_mask: entry.update_mask.clone(),
+            added_workloads: entry.added_workloads.clone(),
+            deleted_workloads: entry.deleted_workloads.clone(),
+            revision: entry.revision,
+            reason: entry.reason.clone(),
+        }
+    }
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-audit-log~1]
+    pub async fn get_audit_log(
+        &mut self,
+        tail: Option<u32>,
+        output_format: &OutputFormat,
+    ) -> Result<String, CliError> {
+        let audit_log = self.server_connection.get_audit_log(tail).await?;
+
+        output_debug!("Got audit log: {:?}", audit_log.entries);
+
+        let audit_log_items: Vec<AuditLogItem> =
+            audit_log.entries.iter().map(AuditLogItem::from).collect();
+
+        ItemList {
+            kind: AUDIT_LOG_KIND,
+            items: &audit_log_items,
+            wide_columns: WIDE_COLUMNS,
+        }
+        .format(output_format, || {
+            let audit_table_rows: Vec<AuditTableRow> = audit_log
+                .entries
+                .into_iter()
+                .map(transform_into_table_row)
+                .collect();
+
+            // [impl->swdd~cli-presents-audit-log-as-table~1]
+            CliTable::new(&audit_table_rows)
+                .table_with_wrapped_column_to_remaining_terminal_width(AuditTableRow::DETAILS_POS)
+                .unwrap_or_else(|_err| CliTable::new(&audit_table_rows).create_default_table())
+        })
+    }
+}
+
+// The entries are kept in the order they were written by the server.
+fn transform_into_table_row(entry: ank_base::AuditLogEntry) -> AuditTableRow {
+    let mut details = Vec::new();
+    if !entry.update_mask.is_empty() {
+        details.push(format!("mask: {}", entry.update_mask.join(", ")));
+    }
+    if !entry.added_workloads.is_empty() {
+        details.push(format!("added: {}", entry.added_workloads.join(", ")));
+    }
+    if !entry.deleted_workloads.is_empty() {
+        details.push(format!("deleted: {}", entry.deleted_workloads.join(", ")));
+    }
+    if let Some(revision) = entry.revision {
+        details.push(format!("revision: {}", revision));
+    }
+    if let Some(reason) = entry.reason {
+        details.push(format!("reason: {}", reason));
+    }
+
+    AuditTableRow {
+        timestamp: entry.timestamp,
+        requester: entry.requester,
+        request: entry.request,
+        result: if entry.accepted {
+            "accepted".to_string()
+        } else {
+            "rejected".to_string()
+        },
+        details: details.join("; "),
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::transform_into_table_row;
+    use crate::cli::OutputFormat;
+    use crate::cli_commands::{
+        server_connection::{MockServerConnection, ServerConnectionError},
+        CliCommands,
+    };
+
+    use api::ank_base;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+
+    // [utest->swdd~cli-provides-audit-log~1]
+    // [utest->swdd~cli-presents-audit-log-as-table~1]
+    #[tokio::test]
+    async fn utest_get_audit_log() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_audit_log()
+            .with(mockall::predicate::eq(Some(2)))
+            .return_once(|_| {
+                Ok(ank_base::AuditLog {
+                    entries: vec![
+                        ank_base::AuditLogEntry {
+                            timestamp: "2025-01-01T00:00:01Z".to_string(),
+                            requester: "cli-conn-1".to_string(),
+                            request: "UpdateStateRequest".to_string(),
+                            added_workloads: vec!["nginx.1234.agent_A".to_string()],
+                            accepted: true,
+                            revision: Some(2),
+                            ..Default::default()
+                        },
+                        ank_base::AuditLogEntry {
+                            timestamp: "2025-01-01T00:00:02Z".to_string(),
+                            requester: "cli-conn-1".to_string(),
+                            request: "RollbackRequest".to_string(),
+                            accepted: false,
+                            reason: Some("unknown revision".to_string()),
+                            ..Default::default()
+                        },
+                    ],
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let table_output = cmd
+            .get_audit_log(Some(2), &OutputFormat::Table)
+            .await
+            .unwrap();
+
+        // the DETAILS column is wrapped to the remaining terminal width
+        let expected_table_output = [
+            "TIMESTAMP              REQUESTER    REQUEST              RESULT     DETAILS",
+            "2025-01-01T00:00:01Z   cli-conn-1   UpdateStateRequest   accepted   added:",
+            "2025-01-01T00:00:02Z   cli-conn-1   RollbackRequest      rejected   reason:",
+        ];
+        let table_lines: Vec<&str> = table_output
+            .lines()
+            .filter(|line| line.starts_with("TIMESTAMP") || line.starts_with("2025"))
+            .collect();
+        assert_eq!(table_lines.len(), expected_table_output.len());
+        for (line, expected_line) in table_lines.into_iter().zip(expected_table_output) {
+            assert!(
+                line.starts_with(expected_line),
+                "'{line}' does not start with '{expected_line}'"
+            );
+        }
+    }
+
+    // [utest->swdd~cli-presents-audit-log-as-table~1]
+    #[test]
+    fn utest_transform_into_table_row_combines_details() {
+        let table_row = transform_into_table_row(ank_base::AuditLogEntry {
+            timestamp: "2025-01-01T00:00:01Z".to_string(),
+            requester: "agent_A@controller".to_string(),
+            request: "UpdateStateRequest".to_string(),
+            update_mask: vec![
+                "desiredState.workloads.nginx".to_string(),
+                "desiredState.configs".to_string(),
+            ],
+            added_workloads: vec!["nginx.1234.agent_A".to_string()],
+            deleted_workloads: vec!["nginx.5678.agent_A".to_string()],
+            accepted: true,
+            reason: None,
+            revision: Some(2),
+        });
+
+        assert_eq!(table_row.requester, "agent_A@controller");
+        assert_eq!(table_row.result, "accepted");
+        assert_eq!(
+            table_row.details,
+            "mask: desiredState.workloads.nginx, desiredState.configs; added: nginx.1234.agent_A; deleted: nginx.5678.agent_A; revision: 2"
+        );
+
+        let table_row = transform_into_table_row(ank_base::AuditLogEntry {
+            request: "RollbackRequest".to_string(),
+            accepted: false,
+            reason: Some("unknown revision".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(table_row.result, "rejected");
+        assert_eq!(table_row.details, "reason: unknown revision");
+    }
+
+    // [utest->swdd~cli-provides-audit-log~1]
+    #[tokio::test]
+    async fn utest_get_audit_log_fails() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_audit_log()
+            .return_once(|_| {
+                Err(ServerConnectionError::ExecutionError(
+                    "connection error".to_string(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.get_audit_log(None, &OutputFormat::Table).await.is_err());
+    }
+}
@@ -11,17 +11,31 @@
 // under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
+use serde::Serialize;
+
 use super::CliCommands;
+use crate::cli::OutputFormat;
 use crate::cli_commands::config_table_row::ConfigTableRow;
+use crate::cli_commands::item_list::ItemList;
 use crate::cli_commands::DESIRED_STATE_CONFIGS;
 use crate::filtered_complete_state::FilteredCompleteState;
 use crate::{cli_commands::cli_table::CliTable, cli_error::CliError, output_debug};
 use common::objects::ConfigItem;
 
+const CONFIGS_KIND: &str = "configs";
+const WIDE_COLUMNS: &[(&str, &str)] = &[("CONFIG", ".name"), ("VALUE", ".value")];
+
+// [impl->swdd~cli-provides-machine-readable-item-lists~1]
+#[derive(Debug, Serialize)]
+struct ConfigListItem {
+    name: String,
+    value: ConfigItem,
+}
+
 impl CliCommands {
     // [impl->swdd~cli-provides-list-of-configs~1]
     // [impl->swdd~cli-processes-complete-state-to-provide-connected-agents~1]
-    pub async fn get_configs(&mut self) -> Result<String, CliError> {
+    pub async fn get_configs(&mut self, output_format: &OutputFormat) -> Result<String, CliError> {
         let filtered_complete_state: FilteredCompleteState = self
             .server_connection
             .get_complete_state(&[DESIRED_STATE_CONFIGS.to_string()])
@@ -34,26 +48,40 @@ impl CliCommands {
             .into_iter();
 
         // [impl->swdd~cli-shall-present-list-of-configs~1]
-        let config_table_rows = transform_into_table_rows(configs);
-
-        output_debug!("Got configs: {:?}", config_table_rows);
-
-        // [impl->swdd~cli-shall-present-configs-as-table~1]
-        Ok(CliTable::new(&config_table_rows).create_default_table())
+        let config_items = transform_into_items(configs);
+
+        output_debug!("Got configs: {:?}", config_items);
+
+        ItemList {
+            kind: CONFIGS_KIND,
+            items: &config_items,
+            wide_columns: WIDE_COLUMNS,
+        }
+        .format(output_format, || {
+            let config_table_rows: Vec<ConfigTableRow> = config_items
+                .iter()
+                .map(|config_item| ConfigTableRow {
+                    config: config_item.name.clone(),
+                })
+                .collect();
+
+            // [impl->swdd~cli-shall-present-configs-as-table~1]
+            CliTable::new(&config_table_rows).create_default_table()
+        })
     }
 }
 
-fn transform_into_table_rows(
+fn transform_into_items(
     configs: impl Iterator<Item = (String, ConfigItem)>,
-) -> Vec<ConfigTableRow> {
-    let mut config_table_rows: Vec<ConfigTableRow> = configs
-        .map(|(config_str, _config_item)| ConfigTableRow { config: config_str })
+) -> Vec<ConfigListItem> {
+    let mut config_items: Vec<ConfigListItem> = configs
+        .map(|(name, value)| ConfigListItem { name, value })
         .collect();
 
     // sort in order to ensure consistent output
     // [impl->swdd~cli-shall-sort-list-of-configs~1]
-    config_table_rows.sort_by(|a, b| a.config.cmp(&b.config));
-    config_table_rows
+    config_items.sort_by(|a, b| a.name.cmp(&b.name));
+    config_items
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -66,6 +94,7 @@ fn transform_into_table_rows(
 
 #[cfg(test)]
 mod tests {
+    use crate::cli::OutputFormat;
     use crate::cli_commands::{
         server_connection::{MockServerConnection, ServerConnectionError},
         CliCommands, DESIRED_STATE_CONFIGS,
@@ -106,7 +135,7 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_configs().await;
+        let table_output_result = cmd.get_configs(&OutputFormat::Table).await;
 
         let expected_table_output = ["CONFIG  ", "config_1", "config_2"].join("\n");
 
@@ -128,7 +157,7 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_configs().await;
+        let table_output_result = cmd.get_configs(&OutputFormat::Table).await;
 
         let expected_table_output = "CONFIG".to_string();
 
@@ -154,7 +183,7 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let table_output_result = cmd.get_configs().await;
+        let table_output_result = cmd.get_configs(&OutputFormat::Table).await;
         assert!(table_output_result.is_err());
     }
 }
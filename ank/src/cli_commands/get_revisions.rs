@@ -0,0 +1,178 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+use serde::Serialize;
+
+use super::CliCommands;
+use crate::{
+    cli::OutputFormat,
+    cli_commands::{
+        cli_table::CliTable, item_list::ItemList, revision_table_row::RevisionTableRow,
+    },
+    cli_error::CliError,
+    output_debug,
+};
+use api::ank_base;
+
+const REVISIONS_KIND: &str = "revisions";
+const WIDE_COLUMNS: &[(&str, &str)] = &[
+    ("REVISION", ".revision"),
+    ("CREATED", ".timestamp"),
+    ("REQUESTER", ".requester"),
+    ("UPDATE MASK", ".updateMask[*]"),
+];
+
+// [impl->swdd~cli-provides-machine-readable-item-lists~1]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RevisionItem {
+    revision: u64,
+    timestamp: String,
+    requester: String,
+    update_mask: Vec<String>,
+}
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-list-of-revisions~1]
+    pub async fn get_revisions(
+        &mut self,
+        output_format: &OutputFormat,
+    ) -> Result<String, CliError> {
+        let revisions = self.server_connection.get_revisions().await?;
+
+        let revision_items = transform_into_items(revisions.revisions);
+
+        output_debug!("Got revisions: {:?}", revision_items);
+
+        ItemList {
+            kind: REVISIONS_KIND,
+            items: &revision_items,
+            wide_columns: WIDE_COLUMNS,
+        }
+        .format(output_format, || {
+            let revision_table_rows: Vec<RevisionTableRow> = revision_items
+                .iter()
+                .map(|revision| RevisionTableRow {
+                    revision: revision.revision,
+                    timestamp: revision.timestamp.clone(),
+                    requester: revision.requester.clone(),
+                    update_mask: revision.update_mask.join(", "),
+                })
+                .collect();
+
+            // [impl->swdd~cli-presents-revisions-as-table~1]
+            CliTable::new(&revision_table_rows).create_default_table()
+        })
+    }
+}
+
+fn transform_into_items(revisions: Vec<ank_base::Revision>) -> Vec<RevisionItem> {
+    let mut revision_items: Vec<RevisionItem> = revisions
+        .into_iter()
+        .map(|revision| RevisionItem {
+            revision: revision.revision,
+            timestamp: revision.timestamp,
+            requester: revision.requester,
+            update_mask: revision.update_mask,
+        })
+        .collect();
+
+    // sort to ensure consistent output
+    revision_items.sort_by_key(|item| item.revision);
+    revision_items
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::OutputFormat;
+    use crate::cli_commands::{
+        server_connection::{MockServerConnection, ServerConnectionError},
+        CliCommands,
+    };
+
+    use api::ank_base;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+
+    fn generate_test_revision(revision: u64, update_mask: &[&str]) -> ank_base::Revision {
+        ank_base::Revision {
+            revision,
+            timestamp: format!("2025-01-01T00:00:0{revision}Z"),
+            requester: "cli-conn-1".to_string(),
+            update_mask: update_mask.iter().map(|mask| mask.to_string()).collect(),
+        }
+    }
+
+    // [utest->swdd~cli-provides-list-of-revisions~1]
+    // [utest->swdd~cli-presents-revisions-as-table~1]
+    #[tokio::test]
+    async fn utest_get_revisions() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .return_once(|| {
+                Ok(ank_base::Revisions {
+                    revisions: vec![
+                        generate_test_revision(2, &[]),
+                        generate_test_revision(1, &["desiredState.configs"]),
+                    ],
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let table_output_result = cmd.get_revisions(&OutputFormat::Table).await;
+
+        let expected_table_output = [
+            "REVISION   CREATED                REQUESTER    UPDATE MASK         ",
+            "1          2025-01-01T00:00:01Z   cli-conn-1   desiredState.configs",
+            "2          2025-01-01T00:00:02Z   cli-conn-1                       ",
+        ]
+        .join("\n");
+
+        assert_eq!(Ok(expected_table_output), table_output_result);
+    }
+
+    // [utest->swdd~cli-provides-list-of-revisions~1]
+    #[tokio::test]
+    async fn utest_get_revisions_fails() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_revisions()
+            .return_once(|| {
+                Err(ServerConnectionError::ExecutionError(
+                    "connection error".to_string(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.get_revisions(&OutputFormat::Table).await.is_err());
+    }
+}
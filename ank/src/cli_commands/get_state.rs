@@ -12,6 +12,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use common::objects::LabelSelector;
+
 use crate::{cli::OutputFormat, cli_error::CliError, output_debug};
 
 use super::CliCommands;
@@ -20,19 +22,30 @@ impl CliCommands {
     pub async fn get_state(
         &mut self,
         object_field_mask: Vec<String>,
+        label_selector: Option<LabelSelector>,
         output_format: OutputFormat,
     ) -> Result<String, CliError> {
         output_debug!(
-            "Got: object_field_mask={:?} output_format={:?}",
+            "Got: object_field_mask={:?} label_selector={:?} output_format={:?}",
             object_field_mask,
+            label_selector,
             output_format
         );
 
         // [impl->swdd~cli-returns-compact-state-object-when-object-field-mask-provided~1]
-        let filtered_complete_state = self
-            .server_connection
-            .get_complete_state(&object_field_mask)
-            .await?;
+        let filtered_complete_state = match label_selector {
+            // [impl->swdd~cli-selects-workloads-by-label-selector~1]
+            Some(label_selector) => {
+                self.server_connection
+                    .get_selected_complete_state(&object_field_mask, &label_selector)
+                    .await?
+            }
+            None => {
+                self.server_connection
+                    .get_complete_state(&object_field_mask)
+                    .await?
+            }
+        };
 
         output_debug!("Raw complete state: {:?}", filtered_complete_state);
 
@@ -42,6 +55,15 @@ impl CliCommands {
             OutputFormat::Yaml => Ok(serde_yaml::to_string(&serialized_state)?),
             // [impl -> swdd~cli-shall-support-desired-state-json~1]
             OutputFormat::Json => Ok(serde_json::to_string_pretty(&serialized_state)?),
+            // [impl->swdd~cli-supports-jsonpath-output~1]
+            OutputFormat::JsonPath(template) => template
+                .render(&serde_json::to_value(&serialized_state)?)
+                .map_err(CliError::ExecutionError),
+            OutputFormat::Table | OutputFormat::Wide | OutputFormat::CustomColumns(_) => {
+                Err(CliError::ExecutionError(
+                    "The state can only be output as yaml, json or jsonpath".to_string(),
+                ))
+            }
         }
     }
 }
@@ -56,6 +78,7 @@ impl CliCommands {
 #[cfg(test)]
 mod tests {
     use api::ank_base;
+    use common::objects::LabelSelector;
     use common::test_utils::{
         self, generate_test_proto_complete_state, generate_test_proto_workload_with_param,
     };
@@ -104,7 +127,7 @@ mod tests {
         };
 
         let cmd_text = cmd
-            .get_state(vec![], crate::cli::OutputFormat::Yaml)
+            .get_state(vec![], None, crate::cli::OutputFormat::Yaml)
             .await
             .unwrap();
         let expected_text = serde_yaml::to_string(&test_data).unwrap();
@@ -144,7 +167,7 @@ mod tests {
         };
 
         let cmd_text = cmd
-            .get_state(vec![], crate::cli::OutputFormat::Json)
+            .get_state(vec![], None, crate::cli::OutputFormat::Json)
             .await
             .unwrap();
 
@@ -189,6 +212,7 @@ mod tests {
         let cmd_text = cmd
             .get_state(
                 vec!["desiredState.workloads.name3.runtime".to_owned()],
+                None,
                 crate::cli::OutputFormat::Yaml,
             )
             .await
@@ -243,6 +267,7 @@ mod tests {
                     "desiredState.workloads.name1.runtime".to_owned(),
                     "desiredState.workloads.name2.runtime".to_owned(),
                 ],
+                None,
                 crate::cli::OutputFormat::Yaml,
             )
             .await
@@ -272,6 +297,7 @@ mod tests {
         let cmd_text = cmd
             .get_state(
                 vec!["workloadStates".to_owned()],
+                None,
                 crate::cli::OutputFormat::Yaml,
             )
             .await
@@ -280,4 +306,101 @@ mod tests {
         let expected_text = serde_yaml::to_string(&test_data).unwrap();
         assert_eq!(cmd_text, expected_text);
     }
+
+    // [utest->swdd~cli-supports-jsonpath-output~1]
+    #[tokio::test]
+    async fn utest_get_state_jsonpath() {
+        let test_data = filtered_complete_state::FilteredCompleteState::from(
+            generate_test_proto_complete_state(&[
+                (
+                    "name1",
+                    generate_test_proto_workload_with_param("agent_A", "runtime"),
+                ),
+                (
+                    "name2",
+                    generate_test_proto_workload_with_param("agent_B", "runtime"),
+                ),
+            ]),
+        );
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .return_once(|_| Ok(test_data));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let cmd_text = cmd
+            .get_state(
+                vec![],
+                None,
+                "jsonpath={.desiredState.workloads.*.agent}"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(cmd_text, "agent_A agent_B");
+    }
+
+    // [utest->swdd~cli-supports-uniform-output-formats~1]
+    #[tokio::test]
+    async fn utest_get_state_rejects_table_output_formats() {
+        for output_format in ["table", "wide", "custom-columns=NAME:.name"] {
+            let mut mock_server_connection = MockServerConnection::default();
+            mock_server_connection
+                .expect_get_complete_state()
+                .return_once(|_| Ok(Default::default()));
+            let mut cmd = CliCommands {
+                _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+                no_wait: false,
+                server_connection: mock_server_connection,
+            };
+
+            assert!(cmd
+                .get_state(vec![], None, output_format.parse().unwrap())
+                .await
+                .is_err());
+        }
+    }
+
+    // [utest->swdd~cli-selects-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_get_state_with_label_selector() {
+        let selected_state = filtered_complete_state::FilteredCompleteState::from(
+            generate_test_proto_complete_state(&[(
+                "name1",
+                generate_test_proto_workload_with_param("agent_A", "runtime"),
+            )]),
+        );
+
+        let mut mock_server_connection = MockServerConnection::default();
+        let selected_state_clone = selected_state.clone();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .with(
+                eq(vec!["desiredState.workloads".to_owned()]),
+                eq("team=infotainment".parse::<LabelSelector>().unwrap()),
+            )
+            .return_once(|_, _| Ok(selected_state_clone));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let cmd_text = cmd
+            .get_state(
+                vec!["desiredState.workloads".to_owned()],
+                Some("team=infotainment".parse().unwrap()),
+                crate::cli::OutputFormat::Yaml,
+            )
+            .await
+            .unwrap();
+        assert_eq!(cmd_text, serde_yaml::to_string(&selected_state).unwrap());
+    }
 }
@@ -11,61 +11,189 @@
 // under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-use crate::{cli_error::CliError, output_debug};
+use std::collections::HashSet;
+
+use common::objects::LabelSelector;
+use serde::Serialize;
+
+use crate::{
+    cli::OutputFormat, cli_error::CliError, filtered_complete_state::FilteredCompleteState,
+    output_debug,
+};
 
 use super::cli_table::CliTable;
+use super::item_list::ItemList;
 use super::workload_table_row::WorkloadTableRow;
-use super::CliCommands;
+use super::{CliCommands, WorkloadInfos, DESIRED_STATE_WORKLOADS, WORKLOAD_STATES};
+
+const WORKLOADS_KIND: &str = "workloads";
+const WIDE_COLUMNS: &[(&str, &str)] = &[
+    ("WORKLOAD NAME", ".name"),
+    ("AGENT", ".agent"),
+    ("RUNTIME", ".runtime"),
+    ("EXECUTION STATE", ".executionState"),
+    ("ADDITIONAL INFO", ".additionalInfo"),
+    ("INSTANCE ID", ".instanceId"),
+];
+
+// [impl->swdd~cli-provides-machine-readable-item-lists~1]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkloadItem {
+    name: String,
+    agent: String,
+    runtime: String,
+    instance_id: String,
+    execution_state: String,
+    additional_info: String,
+}
 
 impl CliCommands {
     // [impl->swdd~cli-provides-list-of-workloads~1]
-    pub async fn get_workloads_table(
+    pub async fn list_workloads(
         &mut self,
         agent_name: Option<String>,
         state: Option<String>,
         workload_name: Vec<String>,
+        label_selector: Option<LabelSelector>,
+        output_format: &OutputFormat,
     ) -> Result<String, CliError> {
         // [impl->swdd~cli-blocks-until-ankaios-server-responds-list-workloads~1]
-        let mut workload_infos = self.get_workloads().await?;
-        output_debug!("The table before filtering:\n{:?}", workload_infos);
-
-        // [impl->swdd~cli-shall-filter-list-of-workloads~1]
-        if let Some(agent_name) = agent_name {
-            workload_infos
-                .get_mut()
-                .retain(|wi| wi.1.agent == agent_name);
-        }
-
-        // [impl->swdd~cli-shall-filter-list-of-workloads~1]
-        if let Some(state) = state {
-            workload_infos
-                .get_mut()
-                .retain(|wi| wi.1.execution_state.to_lowercase() == state.to_lowercase());
-        }
-
-        // [impl->swdd~cli-shall-filter-list-of-workloads~1]
-        if !workload_name.is_empty() {
-            workload_infos
-                .get_mut()
-                .retain(|wi| workload_name.iter().any(|wn| wn == &wi.1.name));
-        }
-
-        // The order of workloads in RequestCompleteState is not sable -> make sure that the user sees always the same order.
-        // [impl->swdd~cli-shall-sort-list-of-workloads~1]
-        workload_infos.get_mut().sort_by_key(|wi| wi.1.name.clone());
-
-        output_debug!("The table after filtering:\n{:?}", workload_infos);
-
-        // [impl->swdd~cli-shall-present-list-of-workloads~1]
-        let table_rows: Vec<WorkloadTableRow> = workload_infos.into_iter().map(|x| x.1).collect();
+        let workload_infos = match label_selector {
+            // [impl->swdd~cli-selects-workloads-by-label-selector~1]
+            Some(label_selector) => {
+                let complete_state = self
+                    .server_connection
+                    .get_selected_complete_state(&[], &label_selector)
+                    .await?;
+                self.transform_into_workload_infos(complete_state)
+            }
+            None => self.get_workloads().await?,
+        };
+        format_workloads(
+            workload_infos,
+            &agent_name,
+            &state,
+            &workload_name,
+            output_format,
+        )
+    }
+
+    // [impl->swdd~cli-watches-list-of-workloads~1]
+    pub async fn watch_workloads(
+        &mut self,
+        agent_name: Option<String>,
+        state: Option<String>,
+        workload_name: Vec<String>,
+        label_selector: Option<LabelSelector>,
+        output_format: &OutputFormat,
+    ) -> Result<(), CliError> {
+        let field_mask = vec![
+            DESIRED_STATE_WORKLOADS.to_string(),
+            WORKLOAD_STATES.to_string(),
+        ];
+        self.watch_state(field_mask, |cmd, mut complete_state| {
+            // events are not filtered by the server, so the selector is evaluated on each update
+            // [impl->swdd~cli-selects-workloads-by-label-selector~1]
+            if let Some(label_selector) = &label_selector {
+                select_workloads(&mut complete_state, label_selector);
+            }
+            format_workloads(
+                cmd.transform_into_workload_infos(complete_state),
+                &agent_name,
+                &state,
+                &workload_name,
+                output_format,
+            )
+        })
+        .await
+    }
+}
+
+// Keeps only the desired workloads matching the selector and their workload states.
+fn select_workloads(complete_state: &mut FilteredCompleteState, label_selector: &LabelSelector) {
+    let mut selected_workloads = HashSet::new();
+    if let Some(workloads) = complete_state
+        .desired_state
+        .as_mut()
+        .and_then(|desired_state| desired_state.workloads.as_mut())
+    {
+        workloads.retain(|_, workload| {
+            label_selector.matches(workload.tags.as_deref().unwrap_or_default())
+        });
+        selected_workloads.extend(workloads.keys().cloned());
+    }
+    if let Some(workload_states) = complete_state.workload_states.as_mut() {
+        workload_states
+            .retain_workloads(|workload_name| selected_workloads.contains(workload_name));
+    }
+}
+
+fn format_workloads(
+    mut workload_infos: WorkloadInfos,
+    agent_name: &Option<String>,
+    state: &Option<String>,
+    workload_name: &[String],
+    output_format: &OutputFormat,
+) -> Result<String, CliError> {
+    output_debug!("The table before filtering:\n{:?}", workload_infos);
+
+    // [impl->swdd~cli-shall-filter-list-of-workloads~1]
+    if let Some(agent_name) = agent_name {
+        workload_infos
+            .get_mut()
+            .retain(|wi| &wi.1.agent == agent_name);
+    }
+
+    // [impl->swdd~cli-shall-filter-list-of-workloads~1]
+    if let Some(state) = state {
+        workload_infos
+            .get_mut()
+            .retain(|wi| wi.1.execution_state.to_lowercase() == state.to_lowercase());
+    }
+
+    // [impl->swdd~cli-shall-filter-list-of-workloads~1]
+    if !workload_name.is_empty() {
+        workload_infos
+            .get_mut()
+            .retain(|wi| workload_name.iter().any(|wn| wn == &wi.1.name));
+    }
 
+    // The order of workloads in RequestCompleteState is not sable -> make sure that the user sees always the same order.
+    // [impl->swdd~cli-shall-sort-list-of-workloads~1]
+    workload_infos.get_mut().sort_by_key(|wi| wi.1.name.clone());
+
+    output_debug!("The table after filtering:\n{:?}", workload_infos);
+
+    // [impl->swdd~cli-shall-present-list-of-workloads~1]
+    let (items, table_rows): (Vec<WorkloadItem>, Vec<WorkloadTableRow>) = workload_infos
+        .into_iter()
+        .map(|(instance_name, table_row)| {
+            let item = WorkloadItem {
+                name: table_row.name.clone(),
+                agent: table_row.agent.clone(),
+                runtime: table_row.runtime.clone(),
+                instance_id: instance_name.id().to_string(),
+                execution_state: table_row.execution_state.clone(),
+                additional_info: table_row.additional_info.clone(),
+            };
+            (item, table_row)
+        })
+        .unzip();
+
+    ItemList {
+        kind: WORKLOADS_KIND,
+        items: &items,
+        wide_columns: WIDE_COLUMNS,
+    }
+    .format(output_format, || {
         // [impl->swdd~cli-shall-present-workloads-as-table~1]
-        Ok(CliTable::new(&table_rows)
+        CliTable::new(&table_rows)
             .table_with_wrapped_column_to_remaining_terminal_width(
                 WorkloadTableRow::ADDITIONAL_INFO_POS,
             )
-            .unwrap_or_else(|_err| CliTable::new(&table_rows).create_default_table()))
-    }
+            .unwrap_or_else(|_err| CliTable::new(&table_rows).create_default_table())
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -81,13 +209,16 @@ mod tests {
     use common::{
         objects::{
             self, generate_test_workload_spec_with_param,
-            generate_test_workload_states_map_with_data, ExecutionState,
+            generate_test_workload_states_map_with_data, ExecutionState, LabelSelector,
         },
         test_utils,
     };
     use mockall::predicate::eq;
 
+    use super::select_workloads;
+    use crate::cli::OutputFormat;
     use crate::cli_commands::{server_connection::MockServerConnection, CliCommands};
+    use crate::filtered_complete_state::FilteredCompleteState;
 
     const RESPONSE_TIMEOUT_MS: u64 = 3000;
 
@@ -112,7 +243,9 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let cmd_text = cmd.get_workloads_table(None, None, Vec::new()).await;
+        let cmd_text = cmd
+            .list_workloads(None, None, Vec::new(), None, &OutputFormat::Table)
+            .await;
         assert!(cmd_text.is_ok());
 
         let expected_table_output =
@@ -159,7 +292,9 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let cmd_text = cmd.get_workloads_table(None, None, Vec::new()).await;
+        let cmd_text = cmd
+            .list_workloads(None, None, Vec::new(), None, &OutputFormat::Table)
+            .await;
         assert!(cmd_text.is_ok());
 
         let expected_table_output = [
@@ -173,6 +308,47 @@ mod tests {
         assert_eq!(cmd_text.unwrap(), expected_table_output);
     }
 
+    // [utest->swdd~processes-complete-state-to-list-workloads~1]
+    #[tokio::test]
+    async fn utest_get_workloads_scheduled_workload() {
+        let mut test_data =
+            test_utils::generate_test_complete_state(vec![generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "name1".to_string(),
+                "runtime".to_string(),
+            )]);
+        test_data
+            .desired_state
+            .workloads
+            .get_mut("name1")
+            .unwrap()
+            .agent = "".to_string();
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .return_once(|_| Ok((ank_base::CompleteState::from(test_data)).into()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let cmd_text = cmd
+            .list_workloads(None, None, Vec::new(), None, &OutputFormat::Table)
+            .await;
+
+        let expected_table_output = [
+            "WORKLOAD NAME   AGENT     RUNTIME   EXECUTION STATE   ADDITIONAL INFO",
+            "name1           agent_A   runtime   Running(Ok)                      ",
+        ]
+        .join("\n");
+
+        assert_eq!(cmd_text.unwrap(), expected_table_output);
+    }
+
     // [utest->swdd~cli-shall-filter-list-of-workloads~1]
     #[tokio::test]
     async fn utest_get_workloads_filter_workload_name() {
@@ -206,7 +382,13 @@ mod tests {
         };
 
         let cmd_text = cmd
-            .get_workloads_table(None, None, vec!["name1".to_string()])
+            .list_workloads(
+                None,
+                None,
+                vec!["name1".to_string()],
+                None,
+                &OutputFormat::Table,
+            )
             .await;
         assert!(cmd_text.is_ok());
 
@@ -251,7 +433,13 @@ mod tests {
             server_connection: mock_server_connection,
         };
         let cmd_text = cmd
-            .get_workloads_table(Some("agent_B".to_string()), None, Vec::new())
+            .list_workloads(
+                Some("agent_B".to_string()),
+                None,
+                Vec::new(),
+                None,
+                &OutputFormat::Table,
+            )
             .await;
         assert!(cmd_text.is_ok());
 
@@ -297,7 +485,13 @@ mod tests {
             server_connection: mock_server_connection,
         };
         let cmd_text = cmd
-            .get_workloads_table(None, Some("Failed".to_string()), Vec::new())
+            .list_workloads(
+                None,
+                Some("Failed".to_string()),
+                Vec::new(),
+                None,
+                &OutputFormat::Table,
+            )
             .await;
         assert!(cmd_text.is_ok());
 
@@ -331,7 +525,9 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let cmd_text = cmd.get_workloads_table(None, None, Vec::new()).await;
+        let cmd_text = cmd
+            .list_workloads(None, None, Vec::new(), None, &OutputFormat::Table)
+            .await;
         assert!(cmd_text.is_ok());
 
         let expected_table_output = [
@@ -342,4 +538,180 @@ mod tests {
 
         assert_eq!(cmd_text.unwrap(), expected_table_output);
     }
+
+    // [utest->swdd~cli-provides-machine-readable-item-lists~1]
+    // [utest->swdd~cli-supports-uniform-output-formats~1]
+    #[tokio::test]
+    async fn utest_get_workloads_json_and_wide() {
+        let workload_name1 = generate_test_workload_spec_with_param(
+            "agent_A".to_string(),
+            "name1".to_string(),
+            "runtime".to_string(),
+        );
+        let instance_id_name1 = workload_name1.instance_name.id().to_string();
+        let test_data = test_utils::generate_test_complete_state(vec![
+            generate_test_workload_spec_with_param(
+                "agent_B".to_string(),
+                "name2".to_string(),
+                "runtime".to_string(),
+            ),
+            workload_name1,
+        ]);
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![]))
+            .times(2)
+            .returning(move |_| Ok((ank_base::CompleteState::from(test_data.clone())).into()));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let json_output = cmd
+            .list_workloads(
+                Some("agent_A".to_string()),
+                None,
+                Vec::new(),
+                None,
+                &OutputFormat::Json,
+            )
+            .await
+            .unwrap();
+        let json_output: serde_json::Value = serde_json::from_str(&json_output).unwrap();
+        assert_eq!(
+            json_output,
+            serde_json::json!({"workloads": [{
+                "name": "name1",
+                "agent": "agent_A",
+                "runtime": "runtime",
+                "instanceId": instance_id_name1,
+                "executionState": "Running(Ok)",
+                "additionalInfo": "",
+            }]})
+        );
+
+        let wide_output = cmd
+            .list_workloads(None, None, Vec::new(), None, &OutputFormat::Wide)
+            .await
+            .unwrap();
+        let wide_lines: Vec<&str> = wide_output.lines().collect();
+        assert_eq!(wide_lines.len(), 3);
+        assert!(wide_lines[0]
+            .trim_end()
+            .ends_with("ADDITIONAL INFO   INSTANCE ID"));
+        assert!(wide_lines[1].starts_with("name1"));
+        assert!(wide_lines[1].contains(&instance_id_name1));
+        assert!(wide_lines[2].starts_with("name2"));
+    }
+
+    // [utest->swdd~cli-selects-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_get_workloads_filter_label_selector() {
+        let selected_state =
+            test_utils::generate_test_complete_state(vec![generate_test_workload_spec_with_param(
+                "agent_A".to_string(),
+                "name1".to_string(),
+                "runtime".to_string(),
+            )]);
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .with(
+                eq(vec![]),
+                eq("team=infotainment".parse::<LabelSelector>().unwrap()),
+            )
+            .return_once(|_, _| Ok((ank_base::CompleteState::from(selected_state)).into()));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let cmd_text = cmd
+            .list_workloads(
+                None,
+                None,
+                Vec::new(),
+                Some("team=infotainment".parse().unwrap()),
+                &OutputFormat::Table,
+            )
+            .await;
+
+        let expected_table_output = [
+            "WORKLOAD NAME   AGENT     RUNTIME   EXECUTION STATE   ADDITIONAL INFO",
+            "name1           agent_A   runtime   Running(Ok)                      ",
+        ]
+        .join("\n");
+        assert_eq!(cmd_text.unwrap(), expected_table_output);
+    }
+
+    // [utest->swdd~cli-selects-workloads-by-label-selector~1]
+    #[test]
+    fn utest_select_workloads_keeps_matching_workloads_and_their_states() {
+        let mut infotainment_workload = generate_test_workload_spec_with_param(
+            "agent_A".to_string(),
+            "name1".to_string(),
+            "runtime".to_string(),
+        );
+        infotainment_workload.tags = vec![objects::Tag {
+            key: "team".to_string(),
+            value: "infotainment".to_string(),
+        }];
+        let other_workload = generate_test_workload_spec_with_param(
+            "agent_B".to_string(),
+            "name2".to_string(),
+            "runtime".to_string(),
+        );
+        let mut complete_state: FilteredCompleteState =
+            ank_base::CompleteState::from(test_utils::generate_test_complete_state(vec![
+                infotainment_workload,
+                other_workload,
+            ]))
+            .into();
+
+        select_workloads(&mut complete_state, &"team=infotainment".parse().unwrap());
+
+        let workloads = complete_state.desired_state.unwrap().workloads.unwrap();
+        assert_eq!(workloads.keys().collect::<Vec<_>>(), vec!["name1"]);
+        let workload_states = complete_state.workload_states.unwrap();
+        assert!(workload_states
+            .get_workload_state_for_agent("agent_B")
+            .is_empty());
+        assert_eq!(
+            workload_states
+                .get_workload_state_for_agent("agent_A")
+                .len(),
+            1
+        );
+    }
+
+    // [utest->swdd~cli-watches-list-of-workloads~1]
+    #[tokio::test]
+    async fn utest_watch_workloads_subscribes_to_workloads_and_workload_states() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_subscribe_events()
+            .with(eq(vec![
+                "desiredState.workloads".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .return_once(|_| Ok("request_id".to_string()));
+        mock_server_connection
+            .expect_read_next_event()
+            .return_once(|_| Ok(None));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .watch_workloads(None, None, Vec::new(), None, &OutputFormat::Table)
+            .await
+            .is_ok());
+    }
 }
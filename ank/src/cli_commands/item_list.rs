@@ -0,0 +1,222 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use common::std_extensions::UnreachableResult;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::cli_table::create_table_from_records;
+use crate::{
+    cli::{CustomColumn, OutputFormat},
+    cli_error::CliError,
+    json_path::value_to_string,
+};
+
+const NO_VALUE: &str = "<none>";
+const VALUE_SEPARATOR: &str = ",";
+
+// The items listed by a get command. The json and yaml output is an object with
+// the kind as the only key and the serialized items as value, e.g. '{"workloads": [...]}'.
+// [impl->swdd~cli-provides-machine-readable-item-lists~1]
+pub struct ItemList<'a, T> {
+    pub kind: &'static str,
+    pub items: &'a [T],
+    // The header and path of each column of the wide table
+    pub wide_columns: &'static [(&'static str, &'static str)],
+}
+
+impl<T> ItemList<'_, T>
+where
+    T: Serialize,
+{
+    // [impl->swdd~cli-supports-uniform-output-formats~1]
+    pub fn format(
+        &self,
+        output_format: &OutputFormat,
+        create_table: impl FnOnce() -> String,
+    ) -> Result<String, CliError> {
+        match output_format {
+            OutputFormat::Table => Ok(create_table()),
+            OutputFormat::Wide => {
+                let wide_columns: Vec<CustomColumn> = self
+                    .wide_columns
+                    .iter()
+                    .map(|(header, path)| CustomColumn {
+                        header: header.to_string(),
+                        path: path.parse().unwrap_or_unreachable(),
+                    })
+                    .collect();
+                self.custom_columns_table(&wide_columns)
+            }
+            OutputFormat::Yaml => Ok(serde_yaml::to_string(&self.as_object())?),
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(&self.as_object())?),
+            // [impl->swdd~cli-supports-jsonpath-output~1]
+            OutputFormat::JsonPath(template) => template
+                .render(&serde_json::to_value(self.as_object())?)
+                .map_err(CliError::ExecutionError),
+            OutputFormat::CustomColumns(columns) => self.custom_columns_table(columns),
+        }
+    }
+
+    fn as_object(&self) -> BTreeMap<&str, &[T]> {
+        BTreeMap::from([(self.kind, self.items)])
+    }
+
+    // [impl->swdd~cli-supports-custom-columns-output~1]
+    fn custom_columns_table(&self, columns: &[CustomColumn]) -> Result<String, CliError> {
+        let header = columns.iter().map(|column| column.header.clone()).collect();
+        let rows = self
+            .items
+            .iter()
+            .map(|item| {
+                let item = serde_json::to_value(item)?;
+                Ok(columns
+                    .iter()
+                    .map(|column| column_value(&item, column))
+                    .collect())
+            })
+            .collect::<Result<_, CliError>>()?;
+
+        Ok(create_table_from_records(header, rows))
+    }
+}
+
+// Multiple matches are joined and a column without a match is shown as '<none>'.
+fn column_value(item: &Value, column: &CustomColumn) -> String {
+    let values: Vec<String> = column
+        .path
+        .select(item)
+        .into_iter()
+        .filter(|value| !value.is_null())
+        .map(value_to_string)
+        .collect();
+
+    if values.is_empty() {
+        NO_VALUE.to_string()
+    } else {
+        values.join(VALUE_SEPARATOR)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::ItemList;
+    use crate::cli::OutputFormat;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestItem {
+        name: String,
+        update_mask: Vec<String>,
+        revision: Option<u64>,
+    }
+
+    const TABLE_OUTPUT: &str = "the default table";
+
+    fn test_items() -> Vec<TestItem> {
+        vec![
+            TestItem {
+                name: "item_1".to_string(),
+                update_mask: vec!["mask_1".to_string(), "mask_2".to_string()],
+                revision: Some(1),
+            },
+            TestItem {
+                name: "item_2".to_string(),
+                update_mask: vec![],
+                revision: None,
+            },
+        ]
+    }
+
+    fn format(output_format: &str) -> Result<String, crate::cli_error::CliError> {
+        let items = test_items();
+        ItemList {
+            kind: "items",
+            items: &items,
+            wide_columns: &[
+                ("NAME", ".name"),
+                ("REVISION", ".revision"),
+                ("UPDATE MASK", ".updateMask[*]"),
+            ],
+        }
+        .format(&output_format.parse::<OutputFormat>().unwrap(), || {
+            TABLE_OUTPUT.to_string()
+        })
+    }
+
+    // [utest->swdd~cli-supports-uniform-output-formats~1]
+    // [utest->swdd~cli-provides-machine-readable-item-lists~1]
+    #[test]
+    fn utest_item_list_formats_table_json_and_yaml() {
+        assert_eq!(format("table").unwrap(), TABLE_OUTPUT);
+
+        let json: serde_json::Value = serde_json::from_str(&format("json").unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"items": [
+                {"name": "item_1", "updateMask": ["mask_1", "mask_2"], "revision": 1},
+                {"name": "item_2", "updateMask": [], "revision": null},
+            ]})
+        );
+
+        let yaml: serde_json::Value = serde_yaml::from_str(&format("yaml").unwrap()).unwrap();
+        assert_eq!(yaml, json);
+    }
+
+    // [utest->swdd~cli-supports-uniform-output-formats~1]
+    // [utest->swdd~cli-supports-custom-columns-output~1]
+    #[test]
+    fn utest_item_list_formats_wide_and_custom_columns() {
+        let expected_wide_output = [
+            "NAME     REVISION   UPDATE MASK  ",
+            "item_1   1          mask_1,mask_2",
+            "item_2   <none>     <none>       ",
+        ]
+        .join("\n");
+        assert_eq!(format("wide").unwrap(), expected_wide_output);
+
+        let expected_custom_columns_output = [
+            "ID       FIRST MASK",
+            "item_1   mask_1    ",
+            "item_2   <none>    ",
+        ]
+        .join("\n");
+        assert_eq!(
+            format("custom-columns=ID:.name,FIRST MASK:{.updateMask[0]}").unwrap(),
+            expected_custom_columns_output
+        );
+    }
+
+    // [utest->swdd~cli-supports-jsonpath-output~1]
+    #[test]
+    fn utest_item_list_formats_jsonpath() {
+        assert_eq!(
+            format("jsonpath={.items[*].name}").unwrap(),
+            "item_1 item_2"
+        );
+        assert!(format("jsonpath={.items[*].unknown}").is_err());
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::commands::LogsRequest;
+
+use crate::{cli::LogsArgs, cli_error::CliError, output, output_debug};
+
+use super::CliCommands;
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-workload-logs~1]
+    pub async fn logs(&mut self, logs_args: LogsArgs) -> Result<(), CliError> {
+        output_debug!(
+            "Getting logs of workload '{}' with follow='{}', since='{:?}', tail='{:?}'",
+            logs_args.workload_name,
+            logs_args.follow,
+            logs_args.since,
+            logs_args.tail
+        );
+
+        let request_id = self
+            .server_connection
+            .request_logs(LogsRequest {
+                workload_name: logs_args.workload_name,
+                follow: logs_args.follow,
+                since: logs_args.since,
+                tail: logs_args.tail,
+            })
+            .await?;
+
+        // [impl->swdd~cli-prints-log-entries-until-logs-end~1]
+        while let Some(log_entries) = self
+            .server_connection
+            .read_next_log_entries(&request_id)
+            .await?
+        {
+            for log_entry in log_entries {
+                output!("{}", log_entry.message);
+            }
+        }
+        output_debug!("The logs have ended.");
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use api::ank_base;
+    use common::commands::LogsRequest;
+    use mockall::{predicate::eq, Sequence};
+
+    use crate::{
+        cli::LogsArgs,
+        cli_commands::{
+            server_connection::{MockServerConnection, ServerConnectionError},
+            CliCommands,
+        },
+        cli_error::CliError,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const REQUEST_ID: &str = "request_id";
+    const WORKLOAD_NAME: &str = "workload_1";
+
+    fn logs_args() -> LogsArgs {
+        LogsArgs {
+            workload_name: WORKLOAD_NAME.into(),
+            follow: true,
+            since: Some("10m".into()),
+            tail: Some(5),
+        }
+    }
+
+    // [utest->swdd~cli-provides-workload-logs~1]
+    // [utest->swdd~cli-prints-log-entries-until-logs-end~1]
+    #[tokio::test]
+    async fn utest_logs_reads_log_entries_until_logs_end() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut seq = Sequence::new();
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_request_logs()
+            .with(eq(LogsRequest {
+                workload_name: WORKLOAD_NAME.into(),
+                follow: true,
+                since: Some("10m".into()),
+                tail: Some(5),
+            }))
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| Ok(REQUEST_ID.into()));
+        mock_server_connection
+            .expect_read_next_log_entries()
+            .with(eq(REQUEST_ID))
+            .times(2)
+            .in_sequence(&mut seq)
+            .returning(|_| {
+                Ok(Some(vec![ank_base::LogEntry {
+                    workload_name: None,
+                    message: "log line".into(),
+                }]))
+            });
+        mock_server_connection
+            .expect_read_next_log_entries()
+            .with(eq(REQUEST_ID))
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| Ok(None));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.logs(logs_args()).await.is_ok());
+    }
+
+    // [utest->swdd~cli-provides-workload-logs~1]
+    #[tokio::test]
+    async fn utest_logs_fails_on_error_response() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_request_logs()
+            .once()
+            .return_once(|_| Ok(REQUEST_ID.into()));
+        mock_server_connection
+            .expect_read_next_log_entries()
+            .once()
+            .return_once(|_| {
+                Err(ServerConnectionError::ExecutionError(
+                    "workload does not exist".into(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert_eq!(
+            cmd.logs(logs_args()).await,
+            Err(CliError::ExecutionError("workload does not exist".into()))
+        );
+    }
+}
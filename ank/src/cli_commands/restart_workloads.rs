@@ -0,0 +1,405 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::{CompleteState, LabelSelector, State, StoredWorkloadSpec};
+
+use crate::{cli_commands::DESIRED_STATE_WORKLOADS, cli_error::CliError, output, output_debug};
+
+use super::CliCommands;
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-restart-workload~1]
+    // [impl->swdd~cli-blocks-until-ankaios-server-responds-restart-workload~1]
+    pub async fn restart_workloads(&mut self, workload_names: Vec<String>) -> Result<(), CliError> {
+        let existing_workloads = self.get_existing_workloads(&workload_names).await?;
+
+        let workloads = existing_workloads
+            .into_iter()
+            .map(|(workload_name, workload)| {
+                (
+                    workload_name,
+                    StoredWorkloadSpec {
+                        restart_generation: workload.restart_generation.unwrap_or_default() + 1,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+
+        let complete_state_update = CompleteState {
+            desired_state: State {
+                workloads,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let update_mask = workload_names
+            .iter()
+            .map(|workload_name| {
+                format!(
+                    "{}.{}.restartGeneration",
+                    DESIRED_STATE_WORKLOADS, workload_name
+                )
+            })
+            .collect();
+
+        output_debug!(
+            "Updating with complete state '{:?}' and update mask {:?}",
+            complete_state_update,
+            update_mask
+        );
+
+        self.update_state_and_wait_for_complete(complete_state_update, update_mask)
+            .await
+    }
+
+    // [impl->swdd~cli-restarts-workloads-by-label-selector~1]
+    pub async fn restart_selected_workloads(
+        &mut self,
+        label_selector: LabelSelector,
+    ) -> Result<(), CliError> {
+        let workload_names = self.get_selected_workload_names(&label_selector).await?;
+        if workload_names.is_empty() {
+            output!("No workloads match the selector '{}'.", label_selector);
+            return Ok(());
+        }
+
+        self.restart_workloads(workload_names).await
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::ank_base::{self, UpdateStateSuccess};
+    use common::{
+        objects::{
+            generate_test_workload_spec_with_param, AccessRightsRule, CompleteState,
+            ControlInterfaceAccess, LabelSelector, ReadWriteEnum, State, StateRule,
+            StoredWorkloadSpec, WorkloadSpec,
+        },
+        test_utils,
+    };
+    use grpc::cli_roles::{CliAuthorizer, CliRole, CliRoles};
+    use mockall::predicate::eq;
+
+    use crate::{
+        cli_commands::{
+            server_connection::{MockServerConnection, ServerConnectionError},
+            CliCommands,
+        },
+        cli_error::CliError,
+        filtered_complete_state::FilteredCompleteState,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+
+    fn generate_test_workload(workload_name: &str, restart_generation: u64) -> WorkloadSpec {
+        let mut workload = generate_test_workload_spec_with_param(
+            "agent_A".to_string(),
+            workload_name.to_string(),
+            "runtime".to_string(),
+        );
+        workload.restart_generation = restart_generation;
+        workload
+    }
+
+    fn generate_test_existing_state(workloads: Vec<WorkloadSpec>) -> FilteredCompleteState {
+        ank_base::CompleteState::from(test_utils::generate_test_complete_state(workloads)).into()
+    }
+
+    fn generate_test_update_state_success() -> UpdateStateSuccess {
+        UpdateStateSuccess {
+            added_workloads: vec![],
+            deleted_workloads: vec![],
+            dry_run: false,
+        }
+    }
+
+    // [utest->swdd~cli-provides-restart-workload~1]
+    // [utest->swdd~cli-blocks-until-ankaios-server-responds-restart-workload~1]
+    #[tokio::test]
+    async fn utest_restart_workloads_increases_restart_generation() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let complete_state_update = CompleteState {
+            desired_state: State {
+                workloads: [
+                    (
+                        "name1".to_string(),
+                        StoredWorkloadSpec {
+                            restart_generation: 1,
+                            ..Default::default()
+                        },
+                    ),
+                    (
+                        "name2".to_string(),
+                        StoredWorkloadSpec {
+                            restart_generation: 4,
+                            ..Default::default()
+                        },
+                    ),
+                ]
+                .into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "desiredState.workloads.name2".to_string(),
+            ]))
+            .return_once(|_| {
+                Ok(generate_test_existing_state(vec![
+                    generate_test_workload("name1", 0),
+                    generate_test_workload("name2", 3),
+                ]))
+            });
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "desiredState.workloads.name2".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(complete_state_update),
+                eq(vec![
+                    "desiredState.workloads.name1.restartGeneration".to_string(),
+                    "desiredState.workloads.name2.restartGeneration".to_string(),
+                ]),
+            )
+            .return_once(|_, _| Ok(generate_test_update_state_success()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .restart_workloads(vec!["name1".to_string(), "name2".to_string()])
+            .await
+            .is_ok());
+    }
+
+    fn team_a_authorizer() -> CliAuthorizer {
+        CliRoles::new(HashMap::from([(
+            "team_a".to_string(),
+            CliRole {
+                identities: vec!["alice".to_string()],
+                access: ControlInterfaceAccess {
+                    allow_rules: vec![AccessRightsRule::StateRule(StateRule {
+                        operation: ReadWriteEnum::ReadWrite,
+                        filter_mask: vec!["desiredState.workloads.teamA_*".to_string()],
+                    })],
+                    deny_rules: vec![],
+                },
+            },
+        )]))
+        .unwrap()
+        .authorizer_for(&["alice".to_string()])
+        .unwrap()
+    }
+
+    fn complete_state_request(field_mask: &[String]) -> ank_base::Request {
+        ank_base::Request {
+            request_id: "request_id".to_string(),
+            request_content: Some(ank_base::request::RequestContent::CompleteStateRequest(
+                ank_base::CompleteStateRequest {
+                    field_mask: field_mask.to_vec(),
+                    label_selector: None,
+                },
+            )),
+        }
+    }
+
+    fn update_state_request(update_mask: &[String]) -> ank_base::Request {
+        ank_base::Request {
+            request_id: "request_id".to_string(),
+            request_content: Some(ank_base::request::RequestContent::UpdateStateRequest(
+                Box::new(ank_base::UpdateStateRequest {
+                    new_state: Some(ank_base::CompleteState::default()),
+                    update_mask: update_mask.to_vec(),
+                    dry_run: false,
+                }),
+            )),
+        }
+    }
+
+    // A role limited to its own workloads can neither read the complete state nor the workload states.
+    // [utest->swdd~cli-requests-update-state-with-watch~3]
+    // [utest->swdd~cli-watches-workloads~2]
+    #[tokio::test]
+    async fn utest_restart_workloads_with_role_restricted_to_own_workloads() {
+        let authorizer = team_a_authorizer();
+        let update_authorizer = authorizer.clone();
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .returning(move |field_mask| {
+                if authorizer.authorize(&complete_state_request(field_mask)) {
+                    Ok(generate_test_existing_state(vec![generate_test_workload(
+                        "teamA_nginx",
+                        0,
+                    )]))
+                } else {
+                    Err(ServerConnectionError::ExecutionError(
+                        "Access denied".to_string(),
+                    ))
+                }
+            });
+        mock_server_connection
+            .expect_update_state()
+            .withf(move |_, update_mask| {
+                update_authorizer.authorize(&update_state_request(update_mask))
+            })
+            .once()
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec!["teamA_nginx.abc.agent_A".to_string()],
+                    deleted_workloads: vec!["teamA_nginx.abc.agent_A".to_string()],
+                    dry_run: false,
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .restart_workloads(vec!["teamA_nginx".to_string()])
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-provides-restart-workload~1]
+    #[tokio::test]
+    async fn utest_restart_workloads_unknown_workload() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .return_once(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert_eq!(
+            cmd.restart_workloads(vec!["unknown".to_string()]).await,
+            Err(CliError::ExecutionError(
+                "Workload(s) 'unknown' not found.".to_string()
+            ))
+        );
+    }
+
+    // [utest->swdd~cli-restarts-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_restart_selected_workloads() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .with(
+                eq(vec!["desiredState.workloads".to_string()]),
+                eq("team=infotainment".parse::<LabelSelector>().unwrap()),
+            )
+            .return_once(|_, _| {
+                Ok(generate_test_existing_state(vec![generate_test_workload(
+                    "name1", 0,
+                )]))
+            });
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec!["desiredState.workloads.name1".to_string()]))
+            .return_once(|_| {
+                Ok(generate_test_existing_state(vec![generate_test_workload(
+                    "name1", 0,
+                )]))
+            });
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .withf(|_, update_mask| {
+                update_mask == &["desiredState.workloads.name1.restartGeneration".to_string()]
+            })
+            .return_once(|_, _| Ok(generate_test_update_state_success()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .restart_selected_workloads("team=infotainment".parse().unwrap())
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-restarts-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_restart_selected_workloads_nothing_selected() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .return_once(|_, _| Ok(FilteredCompleteState::default()));
+        mock_server_connection.expect_get_complete_state().never();
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .restart_selected_workloads("team=infotainment".parse().unwrap())
+            .await
+            .is_ok());
+    }
+}
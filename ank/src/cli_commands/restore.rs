@@ -0,0 +1,540 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::Read;
+
+use common::objects::{CompleteState, State, REDACTED_SECRET_VALUE};
+use serde::Deserialize;
+
+use super::{backup::BACKUP_VERSION, CliCommands, DESIRED_STATE, DESIRED_STATE_SECRETS};
+use crate::{cli_error::CliError, output, output_debug};
+
+#[cfg(test)]
+use self::tests::get_input_sources_mock as get_input_sources;
+
+#[cfg(not(test))]
+use super::get_input_sources;
+
+// Only the desired state is restored, the diagnostics of the backup are ignored.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StateRestore {
+    backup_version: String,
+    #[serde(default)]
+    revision: Option<u64>,
+    desired_state: State,
+}
+
+// [impl->swdd~cli-restores-desired-state-from-backup~1]
+fn parse_backup(backup: &str) -> Result<CompleteState, CliError> {
+    let backup: StateRestore = serde_yaml::from_str(backup)
+        .map_err(|err| CliError::ExecutionError(format!("Invalid backup provided: {err}")))?;
+
+    if backup.backup_version != BACKUP_VERSION {
+        return Err(CliError::ExecutionError(format!(
+            "Unsupported backup version. Received '{}', expected '{}'.",
+            backup.backup_version, BACKUP_VERSION
+        )));
+    }
+    State::verify_api_version(&backup.desired_state).map_err(CliError::ExecutionError)?;
+
+    Ok(CompleteState {
+        desired_state: backup.desired_state,
+        revision: backup.revision,
+        ..Default::default()
+    })
+}
+
+impl CliCommands {
+    // The secrets of a backup are redacted. The Ankaios server only replaces the redacted values
+    // with the values of its existing secrets, hence the other secrets cannot be restored.
+    // [impl->swdd~cli-rejects-backup-with-unrestorable-secrets~1]
+    async fn verify_redacted_secrets(&mut self, state: &State) -> Result<(), CliError> {
+        let redacted_secret_names: Vec<&String> = state
+            .secrets
+            .iter()
+            .filter(|(_, secret)| (*secret).clone().restore_redacted(None).is_err())
+            .map(|(secret_name, _)| secret_name)
+            .collect();
+        if redacted_secret_names.is_empty() {
+            return Ok(());
+        }
+
+        let field_mask: Vec<String> = redacted_secret_names
+            .iter()
+            .map(|secret_name| format!("{DESIRED_STATE_SECRETS}.{secret_name}"))
+            .collect();
+        let current_secrets = self
+            .server_connection
+            .get_complete_state(&field_mask)
+            .await?
+            .desired_state
+            .and_then(|desired_state| desired_state.secrets)
+            .unwrap_or_default();
+
+        let mut unrestorable_secret_names: Vec<&str> = redacted_secret_names
+            .into_iter()
+            .filter(|secret_name| {
+                state.secrets[*secret_name]
+                    .clone()
+                    .restore_redacted(current_secrets.get(*secret_name))
+                    .is_err()
+            })
+            .map(String::as_str)
+            .collect();
+        if unrestorable_secret_names.is_empty() {
+            return Ok(());
+        }
+
+        unrestorable_secret_names.sort();
+        Err(CliError::ExecutionError(format!(
+            "The backup contains the redacted secret(s) '{}', which do not exist on the Ankaios server. \
+            Replace the redacted values '{}' of these secrets in the backup before restoring it.",
+            unrestorable_secret_names.join("', '"),
+            REDACTED_SECRET_VALUE
+        )))
+    }
+
+    // [impl->swdd~cli-restores-desired-state-from-backup~1]
+    pub async fn restore(
+        &mut self,
+        backup_file: String,
+        expect_revision: bool,
+        dry_run: bool,
+    ) -> Result<(), CliError> {
+        let mut input_sources =
+            get_input_sources(&[backup_file]).map_err(CliError::ExecutionError)?;
+        let Some((source_name, backup_reader)) = input_sources.first_mut() else {
+            return Err(CliError::ExecutionError(
+                "No backup file provided.".to_owned(),
+            ));
+        };
+
+        let mut backup = String::new();
+        backup_reader.read_to_string(&mut backup).map_err(|err| {
+            CliError::ExecutionError(format!("Could not read the backup '{source_name}': {err}"))
+        })?;
+
+        let mut new_state = parse_backup(&backup)?;
+
+        // A backup is usually restored after the desired state was changed, hence the revision
+        // of the backup is only checked on request.
+        // [impl->swdd~cli-sends-revision-with-update-state~2]
+        if !expect_revision {
+            new_state.revision = None;
+        }
+        output_debug!("Restoring the desired state {:?}", new_state);
+
+        self.verify_redacted_secrets(&new_state.desired_state)
+            .await?;
+
+        // the complete desired state is replaced with a single update
+        let update_mask = vec![DESIRED_STATE.to_owned()];
+
+        // [impl->swdd~cli-dry-runs-update-state~1]
+        if dry_run {
+            let dry_run_output = self.dry_run_update_state(new_state, update_mask).await?;
+            output!("{}", dry_run_output);
+            return Ok(());
+        }
+
+        self.update_state_and_wait_for_complete(new_state, update_mask)
+            .await
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io};
+
+    use api::ank_base::{self, UpdateStateSuccess};
+    use common::objects::{
+        generate_test_configs, generate_test_control_interface_access, CompleteState, ConfigItem,
+        REDACTED_SECRET_VALUE,
+    };
+    use common::test_utils::generate_test_state;
+    use mockall::predicate::eq;
+
+    use super::parse_backup;
+    use crate::{
+        cli_commands::{server_connection::MockServerConnection, CliCommands, InputSourcePair},
+        cli_error::CliError,
+        filtered_complete_state::{FilteredCompleteState, FilteredState},
+    };
+
+    mockall::lazy_static! {
+        pub static ref FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST: std::sync::Mutex<std::collections::VecDeque<Result<Vec<InputSourcePair>, String>>>  =
+        std::sync::Mutex::new(std::collections::VecDeque::new());
+    }
+
+    pub fn get_input_sources_mock(
+        _manifest_files: &[String],
+    ) -> Result<Vec<InputSourcePair>, String> {
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap()
+    }
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const BACKUP: &str = r#"backupVersion: v1
+createdAt: 2025-01-01T00:00:00Z
+revision: 3
+desiredState:
+  apiVersion: v0.1
+  workloads:
+    nginx:
+      agent: agent_A
+      tags: []
+      runtime: podman
+      runtimeConfig: |
+        image: nginx
+  configs:
+    c1: value
+diagnostics:
+  workloadStates: {}
+"#;
+
+    fn push_backup(backup: String) {
+        FAKE_GET_INPUT_SOURCE_MOCK_RESULT_LIST
+            .lock()
+            .unwrap()
+            .push_back(Ok(vec![(
+                "backup.yaml".to_owned(),
+                Box::new(io::Cursor::new(backup)),
+            )]));
+    }
+
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    #[test]
+    fn utest_parse_backup_ok() {
+        let complete_state = parse_backup(BACKUP).unwrap();
+        let desired_state = complete_state.desired_state;
+
+        assert_eq!(Some(3), complete_state.revision);
+        assert!(desired_state.workloads.contains_key("nginx"));
+        assert_eq!(
+            Some(&ConfigItem::String("value".to_owned())),
+            desired_state.configs.get("c1")
+        );
+    }
+
+    // [utest->swdd~cli-provides-backup-of-desired-state~1]
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    #[tokio::test]
+    async fn utest_parse_backup_accepts_created_backup() {
+        let mut desired_state = generate_test_state();
+        desired_state.configs = generate_test_configs();
+        desired_state.workloads.values_mut().for_each(|workload| {
+            workload.control_interface_access = generate_test_control_interface_access()
+        });
+        assert!(desired_state
+            .workloads
+            .values()
+            .all(|workload| !workload.dependencies.is_empty() && !workload.configs.is_empty()));
+
+        let complete_state = CompleteState {
+            desired_state: desired_state.clone(),
+            ..Default::default()
+        };
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .once()
+            .return_once(|_| Ok(ank_base::CompleteState::from(complete_state).into()));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let backup = cmd.backup(false).await.unwrap();
+
+        assert_eq!(desired_state, parse_backup(&backup).unwrap().desired_state);
+    }
+
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    #[test]
+    fn utest_parse_backup_rejects_unsupported_backup_version() {
+        assert!(parse_backup(&BACKUP.replace("backupVersion: v1", "backupVersion: v2")).is_err());
+    }
+
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    #[test]
+    fn utest_parse_backup_rejects_unsupported_api_version() {
+        assert!(parse_backup(&BACKUP.replace("apiVersion: v0.1", "apiVersion: v0.0")).is_err());
+    }
+
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    #[test]
+    fn utest_parse_backup_rejects_plain_state() {
+        assert!(parse_backup("desiredState:\n  apiVersion: v0.1\n").is_err());
+    }
+
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_restore_replaces_desired_state_without_revision() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let expected_state = CompleteState {
+            desired_state: parse_backup(BACKUP).unwrap().desired_state,
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(7),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_update_state()
+            .with(eq(expected_state), eq(vec!["desiredState".to_owned()]))
+            .once()
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        push_backup(BACKUP.to_owned());
+
+        assert!(cmd
+            .restore("backup.yaml".to_owned(), false, false)
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_restore_with_expect_revision_sends_backup_revision() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let expected_state = CompleteState {
+            desired_state: parse_backup(BACKUP).unwrap().desired_state,
+            revision: Some(3),
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .with(eq(expected_state), eq(vec!["desiredState".to_owned()]))
+            .once()
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        push_backup(BACKUP.to_owned());
+
+        assert!(cmd
+            .restore("backup.yaml".to_owned(), true, false)
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    // [utest->swdd~cli-dry-runs-update-state~1]
+    #[tokio::test]
+    async fn utest_restore_dry_run_does_not_update_state() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_dry_run_update_state()
+            .with(
+                eq(CompleteState {
+                    desired_state: parse_backup(BACKUP).unwrap().desired_state,
+                    ..Default::default()
+                }),
+                eq(vec!["desiredState".to_owned()]),
+            )
+            .once()
+            .return_once(|_, _| {
+                Ok(UpdateStateSuccess {
+                    dry_run: true,
+                    ..Default::default()
+                })
+            });
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        push_backup(BACKUP.to_owned());
+
+        assert!(cmd
+            .restore("backup.yaml".to_owned(), false, true)
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-restores-desired-state-from-backup~1]
+    #[tokio::test]
+    async fn utest_restore_invalid_backup_is_not_sent() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection.expect_get_complete_state().never();
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        push_backup("invalid".to_owned());
+
+        assert!(cmd
+            .restore("backup.yaml".to_owned(), false, false)
+            .await
+            .is_err());
+    }
+
+    fn backup_with_redacted_secret() -> String {
+        BACKUP.replace(
+            "  configs:\n    c1: value\n",
+            "  configs:\n    c1: value\n  secrets:\n    token: \"******\"\n",
+        )
+    }
+
+    // [utest->swdd~cli-rejects-backup-with-unrestorable-secrets~1]
+    #[tokio::test]
+    async fn utest_restore_rejects_redacted_secret_missing_on_server() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec!["desiredState.secrets.token".to_owned()]))
+            .once()
+            .return_once(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection.expect_update_state().never();
+        mock_server_connection.expect_dry_run_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        push_backup(backup_with_redacted_secret());
+
+        assert_eq!(
+            cmd.restore("backup.yaml".to_owned(), false, false).await,
+            Err(CliError::ExecutionError(
+                "The backup contains the redacted secret(s) 'token', which do not exist on the Ankaios server. \
+                Replace the redacted values '******' of these secrets in the backup before restoring it."
+                    .to_owned()
+            ))
+        );
+    }
+
+    // [utest->swdd~cli-rejects-backup-with-unrestorable-secrets~1]
+    #[tokio::test]
+    async fn utest_restore_sends_redacted_secret_existing_on_server() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let expected_state = CompleteState {
+            desired_state: parse_backup(&backup_with_redacted_secret())
+                .unwrap()
+                .desired_state,
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec!["desiredState.secrets.token".to_owned()]))
+            .once()
+            .return_once(|_| {
+                Ok(FilteredCompleteState {
+                    desired_state: Some(FilteredState {
+                        api_version: "v0.1".to_owned(),
+                        workloads: None,
+                        configs: None,
+                        secrets: Some(HashMap::from([(
+                            "token".to_owned(),
+                            ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()),
+                        )])),
+                    }),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_get_complete_state()
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .with(eq(expected_state), eq(vec!["desiredState".to_owned()]))
+            .once()
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        push_backup(backup_with_redacted_secret());
+
+        assert!(cmd
+            .restore("backup.yaml".to_owned(), false, false)
+            .await
+            .is_ok());
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use common::objects::WorkloadInstanceName;
+
+use crate::{cli_error::CliError, output_debug};
+
+use super::{create_watch_field_mask, CliCommands, WorkloadTableRow, DESIRED_STATE};
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-rollback~1]
+    pub async fn rollback(&mut self, revision: u64) -> Result<(), CliError> {
+        let current_workload_infos: BTreeMap<WorkloadInstanceName, WorkloadTableRow> =
+            self.get_workloads().await?.into_iter().collect();
+
+        output_debug!("Rolling back the desired state to revision '{}'", revision);
+
+        let update_state_success = self.server_connection.rollback(revision).await?;
+
+        self.process_update_state_success(
+            update_state_success,
+            current_workload_infos,
+            create_watch_field_mask(&[DESIRED_STATE.to_owned()]),
+        )
+        .await
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use api::ank_base::UpdateStateSuccess;
+    use mockall::predicate::eq;
+
+    use crate::{
+        cli_commands::{
+            server_connection::{MockServerConnection, ServerConnectionError},
+            CliCommands,
+        },
+        filtered_complete_state::FilteredCompleteState,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const REVISION: u64 = 2;
+
+    // [utest->swdd~cli-provides-rollback~1]
+    #[tokio::test]
+    async fn utest_rollback_without_workload_changes() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .once()
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_rollback()
+            .with(eq(REVISION))
+            .once()
+            .return_once(|_| {
+                Ok(UpdateStateSuccess {
+                    added_workloads: vec![],
+                    deleted_workloads: vec![],
+                    dry_run: false,
+                })
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.rollback(REVISION).await.is_ok());
+    }
+
+    // [utest->swdd~cli-provides-rollback~1]
+    #[tokio::test]
+    async fn utest_rollback_rejected_by_server() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .once()
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_rollback()
+            .with(eq(REVISION))
+            .once()
+            .return_once(|_| {
+                Err(ServerConnectionError::ExecutionError(
+                    "revision not found".to_string(),
+                ))
+            });
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert_eq!(
+            cmd.rollback(REVISION).await,
+            Err(crate::cli_error::CliError::ExecutionError(
+                "revision not found".to_string()
+            ))
+        );
+    }
+}
@@ -87,7 +87,7 @@ mod tests {
 
     // [utest->swdd~cli-provides-run-workload~1]
     // [utest->swdd~cli-blocks-until-ankaios-server-responds-run-workload~2]
-    // [utest->swdd~cli-watches-workloads~1]
+    // [utest->swdd~cli-watches-workloads~2]
     #[tokio::test]
     async fn utest_run_workload_one_new_workload() {
         const TEST_WORKLOAD_NAME: &str = "name4";
@@ -118,7 +118,10 @@ mod tests {
         let mut mock_server_connection = MockServerConnection::default();
         mock_server_connection
             .expect_get_complete_state()
-            .with(eq(vec![]))
+            .with(eq(vec![
+                "desiredState.workloads.name4".to_string(),
+                "workloadStates".to_string(),
+            ]))
             .once()
             .return_once(|_| Ok(FilteredCompleteState::default()));
         mock_server_connection
@@ -137,13 +140,18 @@ mod tests {
                         TEST_WORKLOAD_NAME.to_string()
                     )],
                     deleted_workloads: vec![],
+                    dry_run: false,
                 })
             });
 
         mock_server_connection
             .expect_get_complete_state()
             .once()
-            .with(eq(vec![]))
+            .with(eq(vec![
+                "desiredState.workloads.name4".to_string(),
+                "workloadStates".to_string(),
+                "agents".to_string(),
+            ]))
             .return_once(|_| Ok((ank_base::CompleteState::from(complete_state_update)).into()));
         mock_server_connection
             .expect_take_missed_from_server_messages()
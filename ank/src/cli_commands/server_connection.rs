@@ -12,6 +12,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 use std::{mem::take, time::Duration};
 
 use crate::filtered_complete_state::FilteredCompleteState;
@@ -21,9 +25,9 @@ use common::communications_client::CommunicationsClient;
 use common::communications_error::CommunicationMiddlewareError;
 use common::to_server_interface::ToServer;
 use common::{
-    commands::{CompleteStateRequest, UpdateWorkloadState},
+    commands::{CompleteStateRequest, LogsRequest, UpdateWorkloadState},
     from_server_interface::{FromServer, FromServerReceiver},
-    objects::CompleteState,
+    objects::{CompleteState, LabelSelector},
     to_server_interface::{ToServerInterface, ToServerSender},
 };
 use grpc::client::GRPCCommunicationsClient;
@@ -39,6 +43,8 @@ pub struct ServerConnection {
     from_server: FromServerReceiver,
     task: tokio::task::JoinHandle<()>,
     missed_from_server_messages: Vec<FromServer>,
+    // set while receiving events, as a closed connection then only ends the events
+    receiving_events: Arc<AtomicBool>,
 }
 
 #[cfg_attr(test, automock)]
@@ -61,12 +67,19 @@ impl ServerConnection {
         let (to_cli, cli_receiver) = tokio::sync::mpsc::channel::<FromServer>(BUFFER_SIZE);
         let (to_server, server_receiver) = tokio::sync::mpsc::channel::<ToServer>(BUFFER_SIZE);
 
+        let receiving_events = Arc::new(AtomicBool::new(false));
+        let task_receiving_events = receiving_events.clone();
         let task = tokio::spawn(async move {
             if let Err(err) = grpc_communications_client
                 .run(server_receiver, to_cli.clone())
                 .await
             {
-                output_and_error!("{err}");
+                // [impl->swdd~cli-subscribes-to-events-for-watching~1]
+                if task_receiving_events.load(Ordering::Relaxed) {
+                    output_debug!("{err}");
+                } else {
+                    output_and_error!("{err}");
+                }
             }
         });
 
@@ -75,6 +88,7 @@ impl ServerConnection {
             from_server: cli_receiver,
             task,
             missed_from_server_messages: Vec::new(),
+            receiving_events,
         })
     }
 
@@ -95,15 +109,40 @@ impl ServerConnection {
             object_field_mask
         );
 
+        self.request_complete_state(CompleteStateRequest {
+            field_mask: object_field_mask.to_vec(),
+            label_selector: None,
+        })
+        .await
+    }
+
+    // [impl->swdd~cli-selects-workloads-by-label-selector~1]
+    pub async fn get_selected_complete_state(
+        &mut self,
+        object_field_mask: &[String],
+        label_selector: &LabelSelector,
+    ) -> Result<FilteredCompleteState, ServerConnectionError> {
+        output_debug!(
+            "get_selected_complete_state: object_field_mask={:?}, label_selector='{}' ",
+            object_field_mask,
+            label_selector
+        );
+
+        self.request_complete_state(CompleteStateRequest {
+            field_mask: object_field_mask.to_vec(),
+            label_selector: Some(label_selector.to_string()),
+        })
+        .await
+    }
+
+    async fn request_complete_state(
+        &mut self,
+        complete_state_request: CompleteStateRequest,
+    ) -> Result<FilteredCompleteState, ServerConnectionError> {
         let request_id = uuid::Uuid::new_v4().to_string();
 
         self.to_server
-            .request_complete_state(
-                request_id.to_owned(),
-                CompleteStateRequest {
-                    field_mask: object_field_mask.to_vec(),
-                },
-            )
+            .request_complete_state(request_id.to_owned(), complete_state_request)
             .await
             .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
 
@@ -118,7 +157,13 @@ impl ServerConnection {
                         output_debug!("Received from server: {res:?} ");
                         return Ok(res.into());
                     }
-                    None => return Err("Channel preliminary closed."),
+                    Some(FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                    })) if received_request_id == request_id => {
+                        return Err(error.message);
+                    }
+                    None => return Err("Channel preliminary closed.".into()),
                     Some(message) => {
                         // [impl->swdd~cli-stores-unexpected-message~1]
                         self.missed_from_server_messages.push(message);
@@ -149,6 +194,195 @@ impl ServerConnection {
             .await
             .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
 
+        self.wait_for_update_state_success(request_id, "SetState")
+            .await
+    }
+
+    // [impl->swdd~cli-dry-runs-update-state~1]
+    pub async fn dry_run_update_state(
+        &mut self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<ank_base::UpdateStateSuccess, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!("Sending the new state {:?} for a dry run", new_state);
+        self.to_server
+            .dry_run_update_state(request_id.clone(), new_state, update_mask)
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        self.wait_for_update_state_success(request_id, "DryRun")
+            .await
+    }
+
+    // [impl->swdd~cli-provides-list-of-revisions~1]
+    pub async fn get_revisions(&mut self) -> Result<ank_base::Revisions, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!("Requesting the revisions of the desired state");
+        self.to_server
+            .request_revisions(request_id.clone())
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        let poll_revisions = async {
+            loop {
+                let Some(server_message) = self.from_server.recv().await else {
+                    return Err(ServerConnectionError::ExecutionError(
+                        "Connection to server interrupted".into(),
+                    ));
+                };
+                match server_message {
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content:
+                            Some(ank_base::response::ResponseContent::Revisions(revisions)),
+                    }) if received_request_id == request_id => return Ok(revisions),
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                    }) if received_request_id == request_id => {
+                        return Err(ServerConnectionError::ExecutionError(format!(
+                            "Getting the revisions failed with: '{}'",
+                            error.message
+                        )));
+                    }
+                    message => {
+                        // [impl->swdd~cli-stores-unexpected-message~1]
+                        self.missed_from_server_messages.push(message);
+                    }
+                }
+            }
+        };
+        match tokio::time::timeout(WAIT_TIME_MS, poll_revisions).await {
+            Ok(result) => result,
+            Err(_) => Err(ServerConnectionError::ExecutionError(format!(
+                "Failed to get the revisions in time (timeout={WAIT_TIME_MS:?})."
+            ))),
+        }
+    }
+
+    // [impl->swdd~cli-provides-audit-log~1]
+    pub async fn get_audit_log(
+        &mut self,
+        tail: Option<u32>,
+    ) -> Result<ank_base::AuditLog, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!("Requesting the audit log");
+        self.to_server
+            .request_audit_log(request_id.clone(), tail)
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        let poll_audit_log = async {
+            loop {
+                let Some(server_message) = self.from_server.recv().await else {
+                    return Err(ServerConnectionError::ExecutionError(
+                        "Connection to server interrupted".into(),
+                    ));
+                };
+                match server_message {
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content:
+                            Some(ank_base::response::ResponseContent::AuditLog(audit_log)),
+                    }) if received_request_id == request_id => return Ok(audit_log),
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                    }) if received_request_id == request_id => {
+                        return Err(ServerConnectionError::ExecutionError(format!(
+                            "Getting the audit log failed with: '{}'",
+                            error.message
+                        )));
+                    }
+                    message => {
+                        // [impl->swdd~cli-stores-unexpected-message~1]
+                        self.missed_from_server_messages.push(message);
+                    }
+                }
+            }
+        };
+        match tokio::time::timeout(WAIT_TIME_MS, poll_audit_log).await {
+            Ok(result) => result,
+            Err(_) => Err(ServerConnectionError::ExecutionError(format!(
+                "Failed to get the audit log in time (timeout={WAIT_TIME_MS:?})."
+            ))),
+        }
+    }
+
+    // [impl->swdd~cli-provides-workload-description~1]
+    pub async fn get_workload_description(
+        &mut self,
+        workload_name: String,
+        config_names: Vec<String>,
+    ) -> Result<ank_base::WorkloadDescription, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!("Requesting the description of workload '{}'", workload_name);
+        self.to_server
+            .request_workload_description(request_id.clone(), workload_name, config_names)
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        let poll_workload_description = async {
+            loop {
+                let Some(server_message) = self.from_server.recv().await else {
+                    return Err(ServerConnectionError::ExecutionError(
+                        "Connection to server interrupted".into(),
+                    ));
+                };
+                match server_message {
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content:
+                            Some(ank_base::response::ResponseContent::WorkloadDescription(
+                                workload_description,
+                            )),
+                    }) if received_request_id == request_id => return Ok(workload_description),
+                    FromServer::Response(ank_base::Response {
+                        request_id: received_request_id,
+                        response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                    }) if received_request_id == request_id => {
+                        return Err(ServerConnectionError::ExecutionError(format!(
+                            "Describing the workload failed with: '{}'",
+                            error.message
+                        )));
+                    }
+                    message => {
+                        // [impl->swdd~cli-stores-unexpected-message~1]
+                        self.missed_from_server_messages.push(message);
+                    }
+                }
+            }
+        };
+        match tokio::time::timeout(WAIT_TIME_MS, poll_workload_description).await {
+            Ok(result) => result,
+            Err(_) => Err(ServerConnectionError::ExecutionError(format!(
+                "Failed to describe the workload in time (timeout={WAIT_TIME_MS:?})."
+            ))),
+        }
+    }
+
+    // [impl->swdd~cli-provides-rollback~1]
+    pub async fn rollback(
+        &mut self,
+        revision: u64,
+    ) -> Result<ank_base::UpdateStateSuccess, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!("Requesting the rollback to revision '{}'", revision);
+        self.to_server
+            .rollback(request_id.clone(), revision)
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+
+        self.wait_for_update_state_success(request_id, "Rollback")
+            .await
+    }
+
+    async fn wait_for_update_state_success(
+        &mut self,
+        request_id: String,
+        request_name: &str,
+    ) -> Result<ank_base::UpdateStateSuccess, ServerConnectionError> {
         let poll_update_state_success = async {
             loop {
                 let Some(server_message) = self.from_server.recv().await else {
@@ -169,9 +403,16 @@ impl ServerConnection {
                         request_id: received_request_id,
                         response_content: Some(ank_base::response::ResponseContent::Error(error)),
                     }) if received_request_id == request_id => {
+                        // [impl->swdd~cli-reports-stale-revision~1]
+                        if error.code == ank_base::ErrorCode::StaleRevision as i32 {
+                            return Err(ServerConnectionError::ExecutionError(format!(
+                                "{} failed with: '{}'. Fetch the current state and retry or use '--force' to overwrite the changes.",
+                                request_name, error.message
+                            )));
+                        }
                         return Err(ServerConnectionError::ExecutionError(format!(
-                            "SetState failed with: '{}'",
-                            error.message
+                            "{} failed with: '{}'",
+                            request_name, error.message
                         )));
                     }
                     message => {
@@ -216,6 +457,124 @@ impl ServerConnection {
         }
     }
 
+    // [impl->swdd~cli-requests-logs~1]
+    pub async fn request_logs(
+        &mut self,
+        logs_request: LogsRequest,
+    ) -> Result<String, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!(
+            "Requesting the logs of workload '{}'",
+            logs_request.workload_name
+        );
+        self.to_server
+            .request_logs(request_id.clone(), logs_request)
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+        Ok(request_id)
+    }
+
+    // Returns the next received log entries of the logs request or None if the logs have ended.
+    // [impl->swdd~cli-requests-logs~1]
+    pub async fn read_next_log_entries(
+        &mut self,
+        request_id: &str,
+    ) -> Result<Option<Vec<ank_base::LogEntry>>, ServerConnectionError> {
+        loop {
+            let Some(server_message) = self.from_server.recv().await else {
+                return Err(ServerConnectionError::ExecutionError(
+                    "Connection to server interrupted".into(),
+                ));
+            };
+            match server_message {
+                FromServer::Response(ank_base::Response {
+                    request_id: received_request_id,
+                    response_content:
+                        Some(ank_base::response::ResponseContent::LogEntriesResponse(
+                            log_entries_response,
+                        )),
+                }) if received_request_id == request_id => {
+                    return Ok(Some(log_entries_response.log_entries))
+                }
+                FromServer::Response(ank_base::Response {
+                    request_id: received_request_id,
+                    response_content: Some(ank_base::response::ResponseContent::LogsStopResponse(_)),
+                }) if received_request_id == request_id => return Ok(None),
+                FromServer::Response(ank_base::Response {
+                    request_id: received_request_id,
+                    response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                }) if received_request_id == request_id => {
+                    return Err(ServerConnectionError::ExecutionError(format!(
+                        "Getting the logs failed with: '{}'",
+                        error.message
+                    )));
+                }
+                message => {
+                    // [impl->swdd~cli-stores-unexpected-message~1]
+                    self.missed_from_server_messages.push(message);
+                }
+            }
+        }
+    }
+
+    // [impl->swdd~cli-subscribes-to-events-for-watching~1]
+    pub async fn subscribe_events(
+        &mut self,
+        field_mask: Vec<String>,
+    ) -> Result<String, ServerConnectionError> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        output_debug!("Subscribing to events with field mask '{:?}'", field_mask);
+        self.to_server
+            .request_events(request_id.clone(), field_mask)
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))?;
+        Ok(request_id)
+    }
+
+    // Returns the next received event of the subscription or None if the connection to the server was closed.
+    // [impl->swdd~cli-subscribes-to-events-for-watching~1]
+    pub async fn read_next_event(
+        &mut self,
+        request_id: &str,
+    ) -> Result<Option<ank_base::Event>, ServerConnectionError> {
+        loop {
+            let Some(server_message) = self.from_server.recv().await else {
+                return Ok(None);
+            };
+            match server_message {
+                FromServer::Response(ank_base::Response {
+                    request_id: received_request_id,
+                    response_content: Some(ank_base::response::ResponseContent::Event(event)),
+                }) if received_request_id == request_id => {
+                    self.receiving_events.store(true, Ordering::Relaxed);
+                    return Ok(Some(event));
+                }
+                FromServer::Response(ank_base::Response {
+                    request_id: received_request_id,
+                    response_content: Some(ank_base::response::ResponseContent::Error(error)),
+                }) if received_request_id == request_id => {
+                    return Err(ServerConnectionError::ExecutionError(format!(
+                        "Watching the state failed with: '{}'",
+                        error.message
+                    )));
+                }
+                message => {
+                    // [impl->swdd~cli-stores-unexpected-message~1]
+                    self.missed_from_server_messages.push(message);
+                }
+            }
+        }
+    }
+
+    // [impl->swdd~cli-subscribes-to-events-for-watching~1]
+    pub async fn cancel_events(&mut self, request_id: &str) -> Result<(), ServerConnectionError> {
+        output_debug!("Cancelling the event subscription '{}'", request_id);
+        self.to_server
+            .cancel_events(request_id.to_owned())
+            .await
+            .map_err(|err| ServerConnectionError::ExecutionError(err.to_string()))
+    }
+
     pub fn take_missed_from_server_messages(&mut self) -> Vec<FromServer> {
         take(&mut self.missed_from_server_messages)
     }
@@ -239,7 +598,11 @@ mod tests {
 
     use super::ank_base::{self, UpdateStateSuccess};
     use common::{
-        commands::{CompleteStateRequest, RequestContent, UpdateStateRequest, UpdateWorkloadState},
+        commands::{
+            AuditLogRequest, CompleteStateRequest, EventsCancelRequest, EventsRequest, LogsRequest,
+            RequestContent, RevisionsRequest, RollbackRequest, UpdateStateRequest,
+            UpdateWorkloadState, WorkloadDescriptionRequest,
+        },
         from_server_interface::FromServer,
         objects::{
             CompleteState, ExecutionState, State, StoredWorkloadSpec, WorkloadInstanceName,
@@ -250,7 +613,7 @@ mod tests {
     };
     use tokio::sync::mpsc::Receiver;
 
-    use super::ServerConnection;
+    use super::{ServerConnection, ServerConnectionError};
 
     const WORKLOAD_NAME_1: &str = "workload_1";
     const WORKLOAD_NAME_2: &str = "workload_2";
@@ -260,6 +623,7 @@ mod tests {
     const OTHER_REQUEST: &str = "other_request";
     const FIELD_MASK: &str = "field_mask";
     const ID: &str = "id";
+    const REVISION: u64 = 3;
 
     #[derive(Default)]
     struct CommunicationSimulator {
@@ -330,6 +694,7 @@ mod tests {
                     from_server: cli_receiver,
                     task: tokio::spawn(async {}),
                     missed_from_server_messages: Vec::new(),
+                    receiving_events: Default::default(),
                 },
             )
         }
@@ -409,6 +774,7 @@ mod tests {
             REQUEST,
             RequestContent::CompleteStateRequest(CompleteStateRequest {
                 field_mask: vec![FIELD_MASK.into()],
+                label_selector: None,
             }),
         );
         sim.will_send_response(
@@ -434,6 +800,10 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        scheduling_constraints: None,
+                        secrets: Some(Default::default()),
+                        suspended: None,
+                        restart_generation: None,
                     },
                 )]),
             ),
@@ -466,6 +836,10 @@ mod tests {
                         .into()
                     }),
                     files: Some(generate_test_proto_workload_files()),
+                    scheduling_constraints: None,
+                    secrets: Some(Default::default()),
+                    suspended: None,
+                    restart_generation: None,
                 },
             )])
             .into())
@@ -473,6 +847,33 @@ mod tests {
         checker.check_communication();
     }
 
+    // [utest->swdd~cli-selects-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_get_selected_complete_state() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::CompleteStateRequest(CompleteStateRequest {
+                field_mask: vec![FIELD_MASK.into()],
+                label_selector: Some("team=infotainment,env!=dev".into()),
+            }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::CompleteState(Default::default()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .get_selected_complete_state(
+                &[FIELD_MASK.into()],
+                &"team=infotainment, env != dev".parse().unwrap(),
+            )
+            .await;
+        assert!(result.is_ok());
+        checker.check_communication();
+    }
+
     #[tokio::test]
     async fn utest_get_complete_state_fails_at_request() {
         let sim = CommunicationSimulator::default();
@@ -494,6 +895,7 @@ mod tests {
             REQUEST,
             RequestContent::CompleteStateRequest(CompleteStateRequest {
                 field_mask: vec![FIELD_MASK.into()],
+                label_selector: None,
             }),
         );
         let (_checker, mut server_connection) = sim.create_server_connection();
@@ -504,6 +906,34 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn utest_get_complete_state_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::CompleteStateRequest(CompleteStateRequest {
+                field_mask: vec![FIELD_MASK.into()],
+                label_selector: None,
+            }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "Access denied".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .get_complete_state(&[FIELD_MASK.into()])
+            .await;
+        assert!(
+            matches!(result, Err(ServerConnectionError::ExecutionError(message)) if message.contains("Access denied"))
+        );
+        checker.check_communication();
+    }
+
     #[tokio::test]
     async fn utest_get_complete_state_fails_response_timeout() {
         let mut sim = CommunicationSimulator::default();
@@ -511,6 +941,7 @@ mod tests {
             REQUEST,
             RequestContent::CompleteStateRequest(CompleteStateRequest {
                 field_mask: vec![FIELD_MASK.into()],
+                label_selector: None,
             }),
         );
         let (checker, mut server_connection) = sim.create_server_connection();
@@ -550,6 +981,10 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        scheduling_constraints: None,
+                        secrets: Some(Default::default()),
+                        suspended: None,
+                        restart_generation: None,
                     },
                 )]),
             )),
@@ -560,6 +995,7 @@ mod tests {
             REQUEST,
             RequestContent::CompleteStateRequest(CompleteStateRequest {
                 field_mask: vec![FIELD_MASK.into()],
+                label_selector: None,
             }),
         );
         sim.will_send_message(other_response.clone());
@@ -586,6 +1022,10 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        scheduling_constraints: None,
+                        secrets: Some(Default::default()),
+                        suspended: None,
+                        restart_generation: None,
                     },
                 )]),
             ),
@@ -618,6 +1058,10 @@ mod tests {
                         .into()
                     }),
                     files: Some(generate_test_proto_workload_files()),
+                    scheduling_constraints: None,
+                    secrets: Some(Default::default()),
+                    suspended: None,
+                    restart_generation: None,
                 },
             )])
             .into())
@@ -641,6 +1085,7 @@ mod tests {
             REQUEST,
             RequestContent::CompleteStateRequest(CompleteStateRequest {
                 field_mask: vec![FIELD_MASK.into()],
+                label_selector: None,
             }),
         );
         sim.will_send_message(other_message.clone());
@@ -667,6 +1112,10 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        scheduling_constraints: None,
+                        secrets: Some(Default::default()),
+                        suspended: None,
+                        restart_generation: None,
                     },
                 )]),
             ),
@@ -699,6 +1148,10 @@ mod tests {
                         .into()
                     }),
                     files: Some(generate_test_proto_workload_files()),
+                    scheduling_constraints: None,
+                    secrets: Some(Default::default()),
+                    suspended: None,
+                    restart_generation: None,
                 },
             )])
             .into())
@@ -715,6 +1168,7 @@ mod tests {
         let update_state_success = UpdateStateSuccess {
             added_workloads: vec![WORKLOAD_NAME_1.into()],
             deleted_workloads: vec![],
+            dry_run: false,
         };
 
         let mut sim = CommunicationSimulator::default();
@@ -723,6 +1177,7 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
             })),
         );
         sim.will_send_response(
@@ -740,15 +1195,48 @@ mod tests {
         checker.check_communication();
     }
 
+    // [utest->swdd~cli-dry-runs-update-state~1]
     #[tokio::test]
-    async fn utest_update_state_fails_at_request() {
-        let sim = CommunicationSimulator::default();
-        let (_, mut server_connection) = sim.create_server_connection();
-        // sending the GetCompleteState request to the server, shall already fail
-        let (to_server, _) = tokio::sync::mpsc::channel(1);
-        server_connection.to_server = to_server;
+    async fn utest_dry_run_update_state() {
+        let update_state_success = UpdateStateSuccess {
+            added_workloads: vec![WORKLOAD_NAME_1.into()],
+            deleted_workloads: vec![],
+            dry_run: true,
+        };
 
-        let result = server_connection
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
+                state: complete_state(WORKLOAD_NAME_1),
+                update_mask: vec![FIELD_MASK.into()],
+                dry_run: true,
+            })),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::UpdateStateSuccess(update_state_success.clone()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .dry_run_update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), update_state_success);
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_update_state_fails_at_request() {
+        let sim = CommunicationSimulator::default();
+        let (_, mut server_connection) = sim.create_server_connection();
+        // sending the GetCompleteState request to the server, shall already fail
+        let (to_server, _) = tokio::sync::mpsc::channel(1);
+        server_connection.to_server = to_server;
+
+        let result = server_connection
             .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
             .await;
 
@@ -763,6 +1251,7 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
             })),
         );
 
@@ -783,11 +1272,15 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
             })),
         );
         sim.will_send_response(
             REQUEST,
-            ank_base::response::ResponseContent::Error(ank_base::Error { message: "".into() }),
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
         );
 
         let (checker, mut server_connection) = sim.create_server_connection();
@@ -800,6 +1293,38 @@ mod tests {
         checker.check_communication();
     }
 
+    // [utest->swdd~cli-reports-stale-revision~1]
+    #[tokio::test]
+    async fn utest_update_state_fails_stale_revision_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
+                state: complete_state(WORKLOAD_NAME_1),
+                update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
+            })),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "stale".into(),
+                code: ank_base::ErrorCode::StaleRevision.into(),
+            }),
+        );
+
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .update_state(complete_state(WORKLOAD_NAME_1), vec![FIELD_MASK.into()])
+            .await;
+
+        assert!(
+            matches!(result, Err(super::ServerConnectionError::ExecutionError(message)) if message.contains("--force"))
+        );
+        checker.check_communication();
+    }
+
     #[tokio::test]
     async fn utest_update_state_fails_response_timeout() {
         let mut sim = CommunicationSimulator::default();
@@ -808,6 +1333,7 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
             })),
         );
 
@@ -829,6 +1355,7 @@ mod tests {
         let update_state_success = UpdateStateSuccess {
             added_workloads: vec![WORKLOAD_NAME_1.into()],
             deleted_workloads: vec![],
+            dry_run: false,
         };
         let other_response = FromServer::Response(ank_base::Response {
             request_id: OTHER_REQUEST.into(),
@@ -853,6 +1380,10 @@ mod tests {
                             .into(),
                         }),
                         files: Some(generate_test_proto_workload_files()),
+                        scheduling_constraints: None,
+                        secrets: Some(Default::default()),
+                        suspended: None,
+                        restart_generation: None,
                     },
                 )]),
             )),
@@ -864,6 +1395,7 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
             })),
         );
         sim.will_send_message(other_response.clone());
@@ -892,6 +1424,7 @@ mod tests {
         let update_state_success = UpdateStateSuccess {
             added_workloads: vec![WORKLOAD_NAME_1.into()],
             deleted_workloads: vec![],
+            dry_run: false,
         };
         let other_message = FromServer::UpdateWorkloadState(UpdateWorkloadState {
             workload_states: vec![],
@@ -903,6 +1436,7 @@ mod tests {
             RequestContent::UpdateStateRequest(Box::new(UpdateStateRequest {
                 state: complete_state(WORKLOAD_NAME_1),
                 update_mask: vec![FIELD_MASK.into()],
+                dry_run: false,
             })),
         );
         sim.will_send_message(other_message.clone());
@@ -925,6 +1459,212 @@ mod tests {
         checker.check_communication();
     }
 
+    #[tokio::test]
+    async fn utest_get_revisions() {
+        let revisions = ank_base::Revisions {
+            revisions: vec![ank_base::Revision {
+                revision: REVISION,
+                timestamp: "2025-01-01T00:00:00Z".into(),
+                requester: "cli-conn-1".into(),
+                update_mask: vec![FIELD_MASK.into()],
+            }],
+        };
+
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::RevisionsRequest(RevisionsRequest {}),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Revisions(revisions.clone()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.get_revisions().await;
+
+        assert_eq!(result.unwrap(), revisions);
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_get_revisions_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::RevisionsRequest(RevisionsRequest {}),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.get_revisions().await;
+
+        assert!(result.is_err());
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_get_audit_log() {
+        let audit_log = ank_base::AuditLog {
+            entries: vec![ank_base::AuditLogEntry {
+                timestamp: "2025-01-01T00:00:00Z".into(),
+                requester: "cli-conn-1".into(),
+                request: "UpdateStateRequest".into(),
+                update_mask: vec![FIELD_MASK.into()],
+                accepted: true,
+                revision: Some(REVISION),
+                ..Default::default()
+            }],
+        };
+
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::AuditLogRequest(AuditLogRequest { tail: Some(1) }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::AuditLog(audit_log.clone()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.get_audit_log(Some(1)).await;
+
+        assert_eq!(result.unwrap(), audit_log);
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_get_audit_log_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::AuditLogRequest(AuditLogRequest { tail: None }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.get_audit_log(None).await;
+
+        assert!(result.is_err());
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_get_workload_description() {
+        let workload_description = ank_base::WorkloadDescription {
+            rendered_runtime_config: "image: alpine".into(),
+            ..Default::default()
+        };
+
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::WorkloadDescriptionRequest(WorkloadDescriptionRequest {
+                workload_name: WORKLOAD_NAME_1.into(),
+                config_names: vec!["config_1".into()],
+            }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::WorkloadDescription(workload_description.clone()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .get_workload_description(WORKLOAD_NAME_1.into(), vec!["config_1".into()])
+            .await;
+
+        assert_eq!(result.unwrap(), workload_description);
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_get_workload_description_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::WorkloadDescriptionRequest(WorkloadDescriptionRequest {
+                workload_name: WORKLOAD_NAME_1.into(),
+                config_names: vec!["config_1".into()],
+            }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection
+            .get_workload_description(WORKLOAD_NAME_1.into(), vec!["config_1".into()])
+            .await;
+
+        assert!(result.is_err());
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_rollback() {
+        let update_state_success = UpdateStateSuccess {
+            added_workloads: vec![WORKLOAD_NAME_1.into()],
+            deleted_workloads: vec![WORKLOAD_NAME_2.into()],
+            dry_run: false,
+        };
+
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::RollbackRequest(RollbackRequest { revision: REVISION }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::UpdateStateSuccess(update_state_success.clone()),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.rollback(REVISION).await;
+
+        assert_eq!(result.unwrap(), update_state_success);
+        checker.check_communication();
+    }
+
+    #[tokio::test]
+    async fn utest_rollback_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::RollbackRequest(RollbackRequest { revision: REVISION }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let result = server_connection.rollback(REVISION).await;
+
+        assert!(result.is_err());
+        checker.check_communication();
+    }
+
     #[tokio::test]
     async fn utest_read_next_update_workload_state() {
         let update_workload_state = UpdateWorkloadState {
@@ -953,7 +1693,10 @@ mod tests {
         let other_message = FromServer::Response(ank_base::Response {
             request_id: REQUEST.into(),
             response_content: Some(ank_base::response::ResponseContent::Error(
-                ank_base::Error { message: "".into() },
+                ank_base::Error {
+                    message: "".into(),
+                    ..Default::default()
+                },
             )),
         });
         let update_workload_state = UpdateWorkloadState {
@@ -991,4 +1734,229 @@ mod tests {
 
         checker.check_communication();
     }
+
+    fn logs_request() -> LogsRequest {
+        LogsRequest {
+            workload_name: WORKLOAD_NAME_1.into(),
+            follow: true,
+            since: None,
+            tail: Some(10),
+        }
+    }
+
+    fn log_entries(message: &str) -> Vec<ank_base::LogEntry> {
+        vec![ank_base::LogEntry {
+            workload_name: Some(instance_name(WORKLOAD_NAME_1).into()),
+            message: message.into(),
+        }]
+    }
+
+    // [utest->swdd~cli-requests-logs~1]
+    #[tokio::test]
+    async fn utest_request_and_read_logs() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(REQUEST, RequestContent::LogsRequest(logs_request()));
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::LogEntriesResponse(ank_base::LogEntriesResponse {
+                log_entries: log_entries("line 1"),
+            }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::LogsStopResponse(ank_base::LogsStopResponse {
+                workload_name: Some(instance_name(WORKLOAD_NAME_1).into()),
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let request_id = server_connection
+            .request_logs(logs_request())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            server_connection
+                .read_next_log_entries(&request_id)
+                .await
+                .unwrap(),
+            Some(log_entries("line 1"))
+        );
+        assert_eq!(
+            server_connection
+                .read_next_log_entries(&request_id)
+                .await
+                .unwrap(),
+            None
+        );
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-requests-logs~1]
+    // [utest->swdd~cli-stores-unexpected-message~1]
+    #[tokio::test]
+    async fn utest_read_logs_other_message_in_between() {
+        let other_message = FromServer::UpdateWorkloadState(UpdateWorkloadState {
+            workload_states: vec![],
+        });
+
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(REQUEST, RequestContent::LogsRequest(logs_request()));
+        sim.will_send_message(other_message.clone());
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::LogEntriesResponse(ank_base::LogEntriesResponse {
+                log_entries: log_entries("line 1"),
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let request_id = server_connection
+            .request_logs(logs_request())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            server_connection
+                .read_next_log_entries(&request_id)
+                .await
+                .unwrap(),
+            Some(log_entries("line 1"))
+        );
+        assert_eq!(
+            server_connection.take_missed_from_server_messages(),
+            vec![other_message]
+        );
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-requests-logs~1]
+    #[tokio::test]
+    async fn utest_read_logs_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(REQUEST, RequestContent::LogsRequest(logs_request()));
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "workload does not exist".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let request_id = server_connection
+            .request_logs(logs_request())
+            .await
+            .unwrap();
+
+        assert!(server_connection
+            .read_next_log_entries(&request_id)
+            .await
+            .is_err());
+        checker.check_communication();
+    }
+
+    fn event(added_field: &str) -> ank_base::Event {
+        ank_base::Event {
+            complete_state: Some(ank_base::CompleteState::default()),
+            added_fields: vec![added_field.into()],
+            ..Default::default()
+        }
+    }
+
+    // [utest->swdd~cli-subscribes-to-events-for-watching~1]
+    // [utest->swdd~cli-stores-unexpected-message~1]
+    #[tokio::test]
+    async fn utest_subscribe_read_and_cancel_events() {
+        let other_message = FromServer::UpdateWorkloadState(UpdateWorkloadState {
+            workload_states: vec![],
+        });
+
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::EventsRequest(EventsRequest {
+                field_mask: vec![FIELD_MASK.into()],
+            }),
+        );
+        sim.will_send_message(other_message.clone());
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Event(event(FIELD_MASK)),
+        );
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::EventsCancelRequest(EventsCancelRequest {}),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let request_id = server_connection
+            .subscribe_events(vec![FIELD_MASK.into()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            server_connection
+                .read_next_event(&request_id)
+                .await
+                .unwrap(),
+            Some(event(FIELD_MASK))
+        );
+        assert!(server_connection
+            .receiving_events
+            .load(std::sync::atomic::Ordering::Relaxed));
+        assert!(server_connection.cancel_events(&request_id).await.is_ok());
+        // the connection is closed after the simulator has received the cancel request
+        assert_eq!(
+            server_connection
+                .read_next_event(&request_id)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            server_connection.take_missed_from_server_messages(),
+            vec![other_message]
+        );
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-subscribes-to-events-for-watching~1]
+    #[tokio::test]
+    async fn utest_read_next_event_fails_error_response() {
+        let mut sim = CommunicationSimulator::default();
+        sim.expect_receive_request(
+            REQUEST,
+            RequestContent::EventsRequest(EventsRequest { field_mask: vec![] }),
+        );
+        sim.will_send_response(
+            REQUEST,
+            ank_base::response::ResponseContent::Error(ank_base::Error {
+                message: "access denied".into(),
+                ..Default::default()
+            }),
+        );
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        let request_id = server_connection.subscribe_events(vec![]).await.unwrap();
+
+        assert!(server_connection
+            .read_next_event(&request_id)
+            .await
+            .is_err());
+        checker.check_communication();
+    }
+
+    // [utest->swdd~cli-subscribes-to-events-for-watching~1]
+    #[tokio::test]
+    async fn utest_read_next_event_returns_none_on_closed_connection() {
+        let sim = CommunicationSimulator::default();
+        let (checker, mut server_connection) = sim.create_server_connection();
+
+        assert_eq!(
+            server_connection.read_next_event(REQUEST).await.unwrap(),
+            None
+        );
+        checker.check_communication();
+    }
 }
@@ -22,12 +22,14 @@ use std::io::{self, Read};
 fn read_file_to_string(file: String) -> std::io::Result<String> {
     std::fs::read_to_string(file)
 }
-use crate::{cli_error::CliError, output_debug};
+use crate::{cli_error::CliError, output, output_debug};
 #[cfg(test)]
 use tests::read_to_string_mock as read_file_to_string;
 
 use super::CliCommands;
 
+const REVISION_FIELD: &str = "revision";
+
 fn create_state_with_default_workload_specs(update_mask: &[String]) -> CompleteState {
     let mut complete_state = CompleteState::default();
     const WORKLOAD_ATTRIBUTE_LEVEL: usize = 4;
@@ -90,6 +92,12 @@ async fn process_inputs<R: Read>(reader: R, state_object_file: &str) -> Result<O
     }
 }
 
+fn get_revision(state_object: &Object) -> Option<u64> {
+    state_object
+        .get(&REVISION_FIELD.into())
+        .and_then(|revision| revision.as_u64())
+}
+
 fn overwrite_using_field_mask(
     mut complete_state_object: Object,
     object_field_mask: &Vec<String>,
@@ -113,11 +121,15 @@ impl CliCommands {
         &mut self,
         object_field_mask: Vec<String>,
         state_object_file: String,
+        force: bool,
+        dry_run: bool,
     ) -> Result<(), CliError> {
         output_debug!(
-            "Got: object_field_mask={:?} state_object_file={:?}",
+            "Got: object_field_mask={:?} state_object_file={:?} force={:?} dry_run={:?}",
             object_field_mask,
-            state_object_file
+            state_object_file,
+            force,
+            dry_run
         );
 
         let temp_obj = process_inputs(io::stdin(), &state_object_file).await?;
@@ -127,13 +139,27 @@ impl CliCommands {
         let mut complete_state_object: Object = default_complete_state.try_into()?;
         complete_state_object =
             overwrite_using_field_mask(complete_state_object, &object_field_mask, &temp_obj)?;
-        let new_complete_state = complete_state_object.try_into()?;
+        let mut new_complete_state: CompleteState = complete_state_object.try_into()?;
+
+        // [impl->swdd~cli-sends-revision-with-update-state~2]
+        if !force {
+            new_complete_state.revision = get_revision(&temp_obj);
+        }
 
         output_debug!(
             "Send UpdateState request with the CompleteState {:?}",
             new_complete_state
         );
 
+        // [impl->swdd~cli-dry-runs-update-state~1]
+        if dry_run {
+            let dry_run_output = self
+                .dry_run_update_state(new_complete_state, object_field_mask)
+                .await?;
+            output!("{}", dry_run_output);
+            return Ok(());
+        }
+
         // [impl->swdd~cli-blocks-until-ankaios-server-responds-set-desired-state~2]
         self.update_state_and_wait_for_complete(new_complete_state, object_field_mask)
             .await
@@ -339,7 +365,143 @@ mod tests {
             server_connection: mock_server_connection,
         };
 
-        let set_state_result = cmd.set_state(update_mask, state_object_file).await;
+        let set_state_result = cmd
+            .set_state(update_mask, state_object_file, false, false)
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_set_state_sends_revision_from_state_object_file() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+        let state_object_file = format!("revision: 2\n{SAMPLE_CONFIG}");
+
+        let workload_spec = StoredWorkloadSpec {
+            restart_policy: RestartPolicy::Always,
+            ..Default::default()
+        };
+        let updated_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([("nginx".to_string(), workload_spec)]),
+                ..Default::default()
+            },
+            revision: Some(2),
+            ..Default::default()
+        };
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .returning(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(5),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_update_state()
+            .with(eq(updated_state), eq(update_mask.clone()))
+            .once()
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(update_mask, state_object_file, false, false)
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_set_state_without_revision_in_state_object_file_sends_no_revision() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+
+        let workload_spec = StoredWorkloadSpec {
+            restart_policy: RestartPolicy::Always,
+            ..Default::default()
+        };
+        let updated_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([("nginx".to_string(), workload_spec)]),
+                ..Default::default()
+            },
+            revision: None,
+            ..Default::default()
+        };
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .returning(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(5),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_update_state()
+            .with(eq(updated_state), eq(update_mask.clone()))
+            .once()
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(update_mask, SAMPLE_CONFIG.to_owned(), false, false)
+            .await;
+        assert!(set_state_result.is_ok());
+    }
+
+    // [utest->swdd~cli-sends-revision-with-update-state~2]
+    #[tokio::test]
+    async fn utest_set_state_force_sends_no_revision() {
+        let update_mask = vec!["desiredState.workloads.nginx.restartPolicy".to_string()];
+        let state_object_file = format!("revision: 2\n{SAMPLE_CONFIG}");
+
+        let workload_spec = StoredWorkloadSpec {
+            restart_policy: RestartPolicy::Always,
+            ..Default::default()
+        };
+        let updated_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([("nginx".to_string(), workload_spec)]),
+                ..Default::default()
+            },
+            revision: None,
+            ..Default::default()
+        };
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .returning(|_| {
+                Ok(FilteredCompleteState {
+                    revision: Some(5),
+                    ..Default::default()
+                })
+            });
+        mock_server_connection
+            .expect_update_state()
+            .with(eq(updated_state), eq(update_mask.clone()))
+            .once()
+            .return_once(|_, _| Ok(UpdateStateSuccess::default()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: true,
+            server_connection: mock_server_connection,
+        };
+
+        let set_state_result = cmd
+            .set_state(update_mask, state_object_file, true, false)
+            .await;
         assert!(set_state_result.is_ok());
     }
 }
@@ -0,0 +1,359 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::{CompleteState, LabelSelector, State, StoredWorkloadSpec};
+
+use crate::{cli_commands::DESIRED_STATE_WORKLOADS, cli_error::CliError, output, output_debug};
+
+use super::CliCommands;
+
+impl CliCommands {
+    // [impl->swdd~cli-provides-stop-workload~1]
+    pub async fn stop_workloads(&mut self, workload_names: Vec<String>) -> Result<(), CliError> {
+        self.set_workloads_suspended(workload_names, true).await
+    }
+
+    // [impl->swdd~cli-provides-start-workload~1]
+    pub async fn start_workloads(&mut self, workload_names: Vec<String>) -> Result<(), CliError> {
+        self.set_workloads_suspended(workload_names, false).await
+    }
+
+    // [impl->swdd~cli-stops-and-starts-workloads-by-label-selector~1]
+    pub async fn stop_selected_workloads(
+        &mut self,
+        label_selector: LabelSelector,
+    ) -> Result<(), CliError> {
+        let workload_names = self.get_selected_workload_names(&label_selector).await?;
+        if workload_names.is_empty() {
+            output!("No workloads match the selector '{}'.", label_selector);
+            return Ok(());
+        }
+
+        self.stop_workloads(workload_names).await
+    }
+
+    // [impl->swdd~cli-stops-and-starts-workloads-by-label-selector~1]
+    pub async fn start_selected_workloads(
+        &mut self,
+        label_selector: LabelSelector,
+    ) -> Result<(), CliError> {
+        let workload_names = self.get_selected_workload_names(&label_selector).await?;
+        if workload_names.is_empty() {
+            output!("No workloads match the selector '{}'.", label_selector);
+            return Ok(());
+        }
+
+        self.start_workloads(workload_names).await
+    }
+
+    // [impl->swdd~cli-blocks-until-ankaios-server-responds-stop-start-workload~1]
+    async fn set_workloads_suspended(
+        &mut self,
+        workload_names: Vec<String>,
+        suspended: bool,
+    ) -> Result<(), CliError> {
+        self.get_existing_workloads(&workload_names).await?;
+
+        // An unsuspended workload has no suspended attribute, thus the update removes it.
+        let workloads = if suspended {
+            workload_names
+                .iter()
+                .map(|workload_name| {
+                    (
+                        workload_name.clone(),
+                        StoredWorkloadSpec {
+                            suspended: true,
+                            ..Default::default()
+                        },
+                    )
+                })
+                .collect()
+        } else {
+            Default::default()
+        };
+
+        let complete_state_update = CompleteState {
+            desired_state: State {
+                workloads,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let update_mask = workload_names
+            .iter()
+            .map(|workload_name| format!("{}.{}.suspended", DESIRED_STATE_WORKLOADS, workload_name))
+            .collect();
+
+        output_debug!(
+            "Updating with complete state '{:?}' and update mask {:?}",
+            complete_state_update,
+            update_mask
+        );
+
+        self.update_state_and_wait_for_complete(complete_state_update, update_mask)
+            .await
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use api::ank_base::{self, UpdateStateSuccess};
+    use common::{
+        objects::{
+            generate_test_workload_spec_with_param, CompleteState, LabelSelector, State,
+            StoredWorkloadSpec,
+        },
+        test_utils,
+    };
+    use mockall::predicate::eq;
+
+    use crate::{
+        cli_commands::{server_connection::MockServerConnection, CliCommands},
+        cli_error::CliError,
+        filtered_complete_state::FilteredCompleteState,
+    };
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+
+    fn generate_test_existing_state(workload_names: &[&str]) -> FilteredCompleteState {
+        ank_base::CompleteState::from(test_utils::generate_test_complete_state(
+            workload_names
+                .iter()
+                .map(|workload_name| {
+                    generate_test_workload_spec_with_param(
+                        "agent_A".to_string(),
+                        workload_name.to_string(),
+                        "runtime".to_string(),
+                    )
+                })
+                .collect(),
+        ))
+        .into()
+    }
+
+    fn generate_test_update_state_success() -> UpdateStateSuccess {
+        UpdateStateSuccess {
+            added_workloads: vec![],
+            deleted_workloads: vec![],
+            dry_run: false,
+        }
+    }
+
+    // [utest->swdd~cli-provides-stop-workload~1]
+    // [utest->swdd~cli-blocks-until-ankaios-server-responds-stop-start-workload~1]
+    #[tokio::test]
+    async fn utest_stop_workloads_suspends_workloads() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let suspended_workload = StoredWorkloadSpec {
+            suspended: true,
+            ..Default::default()
+        };
+        let complete_state_update = CompleteState {
+            desired_state: State {
+                workloads: [
+                    ("name1".to_string(), suspended_workload.clone()),
+                    ("name2".to_string(), suspended_workload),
+                ]
+                .into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "desiredState.workloads.name2".to_string(),
+            ]))
+            .return_once(|_| Ok(generate_test_existing_state(&["name1", "name2"])));
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "desiredState.workloads.name2".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(complete_state_update),
+                eq(vec![
+                    "desiredState.workloads.name1.suspended".to_string(),
+                    "desiredState.workloads.name2.suspended".to_string(),
+                ]),
+            )
+            .return_once(|_, _| Ok(generate_test_update_state_success()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .stop_workloads(vec!["name1".to_string(), "name2".to_string()])
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-provides-start-workload~1]
+    // [utest->swdd~cli-blocks-until-ankaios-server-responds-stop-start-workload~1]
+    #[tokio::test]
+    async fn utest_start_workloads_removes_suspended_attribute() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec!["desiredState.workloads.name1".to_string()]))
+            .return_once(|_| Ok(generate_test_existing_state(&["name1"])));
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .with(
+                eq(CompleteState::default()),
+                eq(vec!["desiredState.workloads.name1.suspended".to_string()]),
+            )
+            .return_once(|_, _| Ok(generate_test_update_state_success()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd.start_workloads(vec!["name1".to_string()]).await.is_ok());
+    }
+
+    // [utest->swdd~cli-provides-stop-workload~1]
+    #[tokio::test]
+    async fn utest_stop_workloads_unknown_workload() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_complete_state()
+            .return_once(|_| Ok(generate_test_existing_state(&["name1"])));
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert_eq!(
+            cmd.stop_workloads(vec!["name1".to_string(), "unknown".to_string()])
+                .await,
+            Err(CliError::ExecutionError(
+                "Workload(s) 'unknown' not found.".to_string()
+            ))
+        );
+    }
+
+    // [utest->swdd~cli-stops-and-starts-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_stop_selected_workloads() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .with(
+                eq(vec!["desiredState.workloads".to_string()]),
+                eq("team=infotainment".parse::<LabelSelector>().unwrap()),
+            )
+            .return_once(|_, _| Ok(generate_test_existing_state(&["name2", "name1"])));
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "desiredState.workloads.name2".to_string(),
+            ]))
+            .return_once(|_| Ok(generate_test_existing_state(&["name1", "name2"])));
+        mock_server_connection
+            .expect_get_complete_state()
+            .with(eq(vec![
+                "desiredState.workloads.name1".to_string(),
+                "desiredState.workloads.name2".to_string(),
+                "workloadStates".to_string(),
+            ]))
+            .returning(|_| Ok(FilteredCompleteState::default()));
+        mock_server_connection
+            .expect_update_state()
+            .withf(|_, update_mask| {
+                update_mask
+                    == &[
+                        "desiredState.workloads.name1.suspended".to_string(),
+                        "desiredState.workloads.name2.suspended".to_string(),
+                    ]
+            })
+            .return_once(|_, _| Ok(generate_test_update_state_success()));
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .stop_selected_workloads("team=infotainment".parse().unwrap())
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~cli-stops-and-starts-workloads-by-label-selector~1]
+    #[tokio::test]
+    async fn utest_start_selected_workloads_nothing_selected() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_get_selected_complete_state()
+            .return_once(|_, _| Ok(FilteredCompleteState::default()));
+        mock_server_connection.expect_get_complete_state().never();
+        mock_server_connection.expect_update_state().never();
+
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        assert!(cmd
+            .start_selected_workloads("team=infotainment".parse().unwrap())
+            .await
+            .is_ok());
+    }
+}
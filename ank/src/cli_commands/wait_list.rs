@@ -100,20 +100,20 @@ impl<T: WaitListDisplayTrait> WaitList<T> {
                 common::objects::ExecutionStateEnum::Running(_)
                 | common::objects::ExecutionStateEnum::Succeeded(_)
                 | common::objects::ExecutionStateEnum::Failed(_)
-                | common::objects::ExecutionStateEnum::NotScheduled => {
-                    if self.added_workloads.remove(&workload_state.instance_name) {
-                        self.display.set_complete(&workload_state.instance_name)
-                    }
+                | common::objects::ExecutionStateEnum::NotScheduled
+                    if self.added_workloads.remove(&workload_state.instance_name) =>
+                {
+                    self.display.set_complete(&workload_state.instance_name)
                 }
-                common::objects::ExecutionStateEnum::Pending(PendingSubstate::StartingFailed) => {
-                    if self.added_workloads.remove(&workload_state.instance_name) {
-                        self.display.set_complete(&workload_state.instance_name)
-                    }
+                common::objects::ExecutionStateEnum::Pending(PendingSubstate::StartingFailed)
+                    if self.added_workloads.remove(&workload_state.instance_name) =>
+                {
+                    self.display.set_complete(&workload_state.instance_name)
                 }
-                common::objects::ExecutionStateEnum::Removed => {
-                    if self.deleted_workloads.remove(&workload_state.instance_name) {
-                        self.display.set_complete(&workload_state.instance_name)
-                    }
+                common::objects::ExecutionStateEnum::Removed
+                    if self.deleted_workloads.remove(&workload_state.instance_name) =>
+                {
+                    self.display.set_complete(&workload_state.instance_name)
                 }
                 common::objects::ExecutionStateEnum::AgentDisconnected => {
                     if self.added_workloads.remove(&workload_state.instance_name) {
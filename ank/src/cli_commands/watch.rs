@@ -0,0 +1,378 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use common::state_manipulation::{Object, Path};
+
+use crate::{
+    cli_error::CliError, filtered_complete_state::FilteredCompleteState, output_debug,
+    output_update, output_warn,
+};
+
+use super::CliCommands;
+
+impl CliCommands {
+    // Subscribes to the events of the field mask and redraws the output of the render function
+    // in place whenever the watched part of the state changes.
+    // [impl->swdd~cli-watches-state-until-interrupted~1]
+    pub(crate) async fn watch_state<F>(
+        &mut self,
+        field_mask: Vec<String>,
+        render: F,
+    ) -> Result<(), CliError>
+    where
+        F: Fn(&Self, FilteredCompleteState) -> Result<String, CliError>,
+    {
+        let request_id = self.server_connection.subscribe_events(field_mask).await?;
+
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+
+        let mut watched_state = Object::default();
+        loop {
+            tokio::select! {
+                event = self.server_connection.read_next_event(&request_id) => {
+                    let Some(event) = event? else {
+                        output_warn!("The connection to the Ankaios server was closed.");
+                        break;
+                    };
+                    output_debug!("Got event: {:?}", event);
+                    apply_event(&mut watched_state, event)?;
+                    output_update!("{}", render(self, to_filtered_complete_state(&watched_state)?)?);
+                }
+                _ = &mut ctrl_c => {
+                    output_debug!("Watching interrupted.");
+                    self.server_connection.cancel_events(&request_id).await?;
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// The watched state is kept in the representation of the CLI output, which omits unset fields,
+// so that the altered fields can be set without running into empty parents.
+// [impl->swdd~cli-watches-state-until-interrupted~1]
+fn apply_event(watched_state: &mut Object, event: ank_base::Event) -> Result<(), CliError> {
+    let event_state: FilteredCompleteState = event.complete_state.unwrap_or_default().into();
+    let event_state = Object::from(serde_yaml::to_value(event_state)?);
+
+    // The initial event contains the whole watched state and no altered fields.
+    if event.added_fields.is_empty()
+        && event.updated_fields.is_empty()
+        && event.removed_fields.is_empty()
+    {
+        *watched_state = event_state;
+        return Ok(());
+    }
+
+    for field in event.removed_fields {
+        remove_field(watched_state, &field.into())?;
+    }
+
+    for field in event.added_fields.into_iter().chain(event.updated_fields) {
+        let path: Path = field.into();
+        match event_state.get(&path) {
+            Some(value) => watched_state
+                .set(&path, value.clone())
+                .map_err(CliError::ExecutionError)?,
+            // fields with default values are not transferred
+            None => remove_field(watched_state, &path)?,
+        }
+    }
+    Ok(())
+}
+
+fn to_filtered_complete_state(watched_state: &Object) -> Result<FilteredCompleteState, CliError> {
+    // the empty path addresses the whole object
+    let value = watched_state
+        .get(&Path::from(""))
+        .cloned()
+        .unwrap_or_default();
+    Ok(serde_yaml::from_value(value)?)
+}
+
+fn remove_field(watched_state: &mut Object, path: &Path) -> Result<(), CliError> {
+    if watched_state.check_if_provided_path_exists(path) {
+        watched_state
+            .remove(path)
+            .map_err(CliError::ExecutionError)?;
+    }
+    Ok(())
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap};
+
+    use api::ank_base;
+    use common::state_manipulation::Object;
+    use mockall::{predicate::eq, Sequence};
+
+    use super::{apply_event, to_filtered_complete_state};
+    use crate::cli_commands::{
+        server_connection::{MockServerConnection, ServerConnectionError},
+        CliCommands,
+    };
+    use crate::filtered_complete_state::FilteredCompleteState;
+
+    const RESPONSE_TIMEOUT_MS: u64 = 3000;
+    const REQUEST_ID: &str = "request_id";
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_NAME: &str = "workload_1";
+
+    fn agents_state(agents: &[(&str, u32)]) -> ank_base::CompleteState {
+        ank_base::CompleteState {
+            agents: Some(ank_base::AgentMap {
+                agents: agents
+                    .iter()
+                    .map(|(agent_name, cpu_usage)| {
+                        (
+                            agent_name.to_string(),
+                            ank_base::AgentAttributes {
+                                cpu_usage: Some(ank_base::CpuUsage {
+                                    cpu_usage: *cpu_usage,
+                                }),
+                                free_memory: Some(ank_base::FreeMemory { free_memory: 42 }),
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect::<HashMap<_, _>>(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn event(
+        complete_state: ank_base::CompleteState,
+        added: &[&str],
+        updated: &[&str],
+        removed: &[&str],
+    ) -> ank_base::Event {
+        let to_vec = |fields: &[&str]| fields.iter().map(|x| x.to_string()).collect();
+        ank_base::Event {
+            complete_state: Some(complete_state),
+            added_fields: to_vec(added),
+            updated_fields: to_vec(updated),
+            removed_fields: to_vec(removed),
+        }
+    }
+
+    fn to_object(complete_state: ank_base::CompleteState) -> Object {
+        Object::from(serde_yaml::to_value(FilteredCompleteState::from(complete_state)).unwrap())
+    }
+
+    // [utest->swdd~cli-watches-state-until-interrupted~1]
+    #[test]
+    fn utest_apply_event_initial_event_replaces_state() {
+        let mut watched_state = to_object(agents_state(&[(AGENT_A, 10)]));
+
+        apply_event(
+            &mut watched_state,
+            event(agents_state(&[(AGENT_B, 20)]), &[], &[], &[]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            to_filtered_complete_state(&watched_state).unwrap(),
+            FilteredCompleteState::from(agents_state(&[(AGENT_B, 20)]))
+        );
+    }
+
+    // [utest->swdd~cli-watches-state-until-interrupted~1]
+    #[test]
+    fn utest_apply_event_merges_altered_fields() {
+        let mut watched_state = to_object(agents_state(&[(AGENT_A, 10), (AGENT_B, 20)]));
+
+        apply_event(
+            &mut watched_state,
+            event(
+                agents_state(&[(AGENT_A, 30)]),
+                &[],
+                &["agents.agent_A"],
+                &["agents.agent_B"],
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            to_filtered_complete_state(&watched_state).unwrap(),
+            FilteredCompleteState::from(agents_state(&[(AGENT_A, 30)]))
+        );
+    }
+
+    // [utest->swdd~cli-watches-state-until-interrupted~1]
+    #[test]
+    fn utest_apply_event_ignores_removed_fields_not_watched() {
+        let mut watched_state = to_object(agents_state(&[(AGENT_A, 10)]));
+
+        apply_event(
+            &mut watched_state,
+            event(
+                agents_state(&[(AGENT_B, 20)]),
+                &["agents.agent_B"],
+                &[],
+                &["workloadStates.agent_A.workload_1.1234"],
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            to_filtered_complete_state(&watched_state).unwrap(),
+            FilteredCompleteState::from(agents_state(&[(AGENT_A, 10), (AGENT_B, 20)]))
+        );
+    }
+
+    // [utest->swdd~cli-watches-state-until-interrupted~1]
+    #[test]
+    fn utest_apply_event_adds_field_to_unset_parent() {
+        let desired_state = |workloads: Option<ank_base::WorkloadMap>| ank_base::CompleteState {
+            desired_state: Some(ank_base::State {
+                api_version: "v0.1".to_string(),
+                workloads,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let workloads = ank_base::WorkloadMap {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME.to_string(),
+                ank_base::Workload {
+                    agent: Some(AGENT_A.to_string()),
+                    runtime: Some("podman".to_string()),
+                    ..Default::default()
+                },
+            )]),
+        };
+        let mut watched_state = Object::default();
+
+        apply_event(
+            &mut watched_state,
+            event(desired_state(None), &[], &[], &[]),
+        )
+        .unwrap();
+        apply_event(
+            &mut watched_state,
+            event(
+                desired_state(Some(workloads.clone())),
+                &["desiredState.workloads.workload_1"],
+                &[],
+                &[],
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            to_filtered_complete_state(&watched_state)
+                .unwrap()
+                .desired_state,
+            FilteredCompleteState::from(desired_state(Some(workloads))).desired_state
+        );
+    }
+
+    // [utest->swdd~cli-watches-state-until-interrupted~1]
+    #[tokio::test]
+    async fn utest_watch_state_renders_until_connection_closed() {
+        let mut seq = Sequence::new();
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_subscribe_events()
+            .with(eq(vec!["agents".to_string()]))
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| Ok(REQUEST_ID.to_string()));
+        mock_server_connection
+            .expect_read_next_event()
+            .with(eq(REQUEST_ID))
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| Ok(Some(event(agents_state(&[(AGENT_A, 10)]), &[], &[], &[]))));
+        mock_server_connection
+            .expect_read_next_event()
+            .with(eq(REQUEST_ID))
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| {
+                Ok(Some(event(
+                    agents_state(&[(AGENT_A, 30)]),
+                    &[],
+                    &["agents.agent_A"],
+                    &[],
+                )))
+            });
+        mock_server_connection
+            .expect_read_next_event()
+            .with(eq(REQUEST_ID))
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| Ok(None));
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let rendered_states = RefCell::new(Vec::new());
+        let result = cmd
+            .watch_state(vec!["agents".to_string()], |_, complete_state| {
+                rendered_states.borrow_mut().push(complete_state);
+                Ok(String::new())
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            rendered_states.into_inner(),
+            vec![
+                FilteredCompleteState::from(agents_state(&[(AGENT_A, 10)])),
+                FilteredCompleteState::from(agents_state(&[(AGENT_A, 30)])),
+            ]
+        );
+    }
+
+    // [utest->swdd~cli-watches-state-until-interrupted~1]
+    #[tokio::test]
+    async fn utest_watch_state_fails_on_error() {
+        let mut mock_server_connection = MockServerConnection::default();
+        mock_server_connection
+            .expect_subscribe_events()
+            .return_once(|_| Ok(REQUEST_ID.to_string()));
+        mock_server_connection
+            .expect_read_next_event()
+            .return_once(|_| {
+                Err(ServerConnectionError::ExecutionError(
+                    "access denied".to_string(),
+                ))
+            });
+        let mut cmd = CliCommands {
+            _response_timeout_ms: RESPONSE_TIMEOUT_MS,
+            no_wait: false,
+            server_connection: mock_server_connection,
+        };
+
+        let result = cmd.watch_state(vec![], |_, _| Ok(String::new())).await;
+
+        assert!(result.is_err());
+    }
+}
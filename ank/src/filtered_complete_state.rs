@@ -18,8 +18,8 @@ use api::ank_base;
 use common::{
     helpers::serialize_to_ordered_map,
     objects::{
-        AddCondition, ConfigItem, ControlInterfaceAccess, File, RestartPolicy, Tag,
-        WorkloadStatesMap,
+        AddCondition, ConfigItem, ControlInterfaceAccess, File, RestartPolicy,
+        SchedulingConstraints, Tag, WorkloadStatesMap,
     },
 };
 use serde::{Deserialize, Serialize, Serializer};
@@ -52,6 +52,9 @@ pub struct FilteredCompleteState {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default, flatten)]
     pub agents: Option<FilteredAgentMap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub revision: Option<u64>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -65,6 +68,9 @@ pub struct FilteredState {
     #[serde(serialize_with = "serialize_option_to_ordered_map")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configs: Option<HashMap<String, ConfigItem>>,
+    #[serde(serialize_with = "serialize_option_to_ordered_map")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<HashMap<String, ConfigItem>>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -96,6 +102,11 @@ pub struct FilteredAgentAttributes {
     pub cpu_usage: Option<FilteredCpuUsage>,
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub free_memory: Option<FilteredFreeMemory>,
+    #[serde(serialize_with = "serialize_option_to_ordered_map")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
 }
 
 impl FilteredAgentAttributes {
@@ -122,6 +133,17 @@ impl FilteredAgentAttributes {
             "".to_string()
         }
     }
+
+    pub fn get_labels_as_string(&self) -> String {
+        let mut labels: Vec<String> = self
+            .labels
+            .iter()
+            .flatten()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+        labels.sort();
+        labels.join(",")
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -145,8 +167,17 @@ pub struct FilteredWorkloadSpec {
     #[serde(serialize_with = "serialize_option_to_ordered_map")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configs: Option<HashMap<String, String>>,
+    #[serde(serialize_with = "serialize_option_to_ordered_map")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<Vec<File>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduling_constraints: Option<SchedulingConstraints>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart_generation: Option<u64>,
 }
 
 impl From<ank_base::CompleteState> for FilteredCompleteState {
@@ -155,6 +186,7 @@ impl From<ank_base::CompleteState> for FilteredCompleteState {
             desired_state: value.desired_state.map(Into::into),
             workload_states: value.workload_states.map(Into::into),
             agents: value.agents.map(Into::into),
+            revision: value.revision,
         }
     }
 }
@@ -169,24 +201,28 @@ impl From<ank_base::State> for FilteredState {
                     .map(|(k, v)| (k, v.into()))
                     .collect()
             }),
-            configs: value.configs.map(|x| {
-                x.configs
-                    .into_iter()
-                    .filter_map(|(key, value)| -> Option<(String, ConfigItem)> {
-                        match value.try_into() {
-                            Ok(value) => Some((key, value)),
-                            Err(err) => {
-                                output_warn!("Config item could not be converted: {}", err);
-                                None
-                            }
-                        }
-                    })
-                    .collect()
-            }),
+            configs: value.configs.map(convert_config_map),
+            secrets: value.secrets.map(convert_config_map),
         }
     }
 }
 
+fn convert_config_map(config_map: ank_base::ConfigMap) -> HashMap<String, ConfigItem> {
+    config_map
+        .configs
+        .into_iter()
+        .filter_map(|(key, value)| -> Option<(String, ConfigItem)> {
+            match value.try_into() {
+                Ok(value) => Some((key, value)),
+                Err(err) => {
+                    output_warn!("Config item could not be converted: {}", err);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
 fn map_vec<T, F>(vec: Vec<T>) -> Vec<F>
 where
     F: From<T>,
@@ -220,9 +256,13 @@ impl From<ank_base::Workload> for FilteredWorkloadSpec {
                     output_and_error!("Could not convert the ControlInterfaceAccess.\nError: '{error}'. Check the Ankaios component compatibility.")
                 })),
             configs: value.configs.map(|x| x.configs),
+            secrets: value.secrets.map(|x| x.secrets),
             files: value.files.map(|files|files.files.into_iter().map(|file| file.try_into().unwrap_or_else(|error| {
                 output_and_error!("Could not convert files.\nError: '{error}'. Check the Ankaios component compatibility.")
             })).collect()),
+            scheduling_constraints: value.scheduling_constraints.map(Into::into),
+            suspended: value.suspended,
+            restart_generation: value.restart_generation,
         }
     }
 }
@@ -246,6 +286,8 @@ impl From<ank_base::AgentAttributes> for FilteredAgentAttributes {
         FilteredAgentAttributes {
             cpu_usage: value.cpu_usage.map(Into::into),
             free_memory: value.free_memory.map(Into::into),
+            labels: (!value.labels.is_empty()).then_some(value.labels),
+            last_seen: value.last_seen,
         }
     }
 }
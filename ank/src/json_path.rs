@@ -0,0 +1,322 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Field(String),
+    Index(i64),
+    Wildcard,
+}
+
+// A path into a JSON value, e.g. '.workloads[0].name' or '.agents[*].labels['app']'.
+// [impl->swdd~cli-supports-jsonpath-output~1]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath(Vec<Selector>);
+
+impl JsonPath {
+    pub fn select<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        self.0.iter().fold(vec![value], |selected, selector| {
+            selected
+                .into_iter()
+                .flat_map(|value| match (selector, value) {
+                    (Selector::Field(name), Value::Object(map)) => {
+                        map.get(name).into_iter().collect()
+                    }
+                    (Selector::Index(index), Value::Array(array)) => {
+                        let index = if *index < 0 {
+                            array.len() as i64 + index
+                        } else {
+                            *index
+                        };
+                        usize::try_from(index)
+                            .ok()
+                            .and_then(|index| array.get(index))
+                            .into_iter()
+                            .collect()
+                    }
+                    (Selector::Wildcard, Value::Array(array)) => array.iter().collect(),
+                    (Selector::Wildcard, Value::Object(map)) => map.values().collect(),
+                    _ => Vec::new(),
+                })
+                .collect()
+        })
+    }
+}
+
+impl FromStr for JsonPath {
+    type Err = String;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| format!("Invalid JSONPath '{path}': {reason}");
+        let mut selectors = Vec::new();
+        let mut rest = path.trim().strip_prefix('$').unwrap_or(path.trim());
+
+        while !rest.is_empty() {
+            if let Some(after_dot) = rest.strip_prefix('.') {
+                if let Some(after_wildcard) = after_dot.strip_prefix('*') {
+                    selectors.push(Selector::Wildcard);
+                    rest = after_wildcard;
+                    continue;
+                }
+                let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+                if end == 0 {
+                    // a single dot addresses the current value
+                    if after_dot.is_empty() || after_dot.starts_with('[') {
+                        rest = after_dot;
+                        continue;
+                    }
+                    return Err(invalid("empty field name"));
+                }
+                selectors.push(Selector::Field(after_dot[..end].to_string()));
+                rest = &after_dot[end..];
+            } else if let Some(after_bracket) = rest.strip_prefix('[') {
+                let (selector, after_selector) =
+                    parse_bracket(after_bracket).map_err(|reason| invalid(&reason))?;
+                selectors.push(selector);
+                rest = after_selector;
+            } else if selectors.is_empty() {
+                // a leading field without a dot, e.g. 'workloads[0]'
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                selectors.push(Selector::Field(rest[..end].to_string()));
+                rest = &rest[end..];
+            } else {
+                return Err(invalid(&format!("unexpected '{rest}'")));
+            }
+        }
+        Ok(JsonPath(selectors))
+    }
+}
+
+fn parse_bracket(input: &str) -> Result<(Selector, &str), String> {
+    let quote = input.chars().next().filter(|c| *c == '\'' || *c == '"');
+    if let Some(quote) = quote {
+        let content = &input[1..];
+        let end = content
+            .find(quote)
+            .ok_or_else(|| "missing closing quote".to_string())?;
+        let rest = content[end + 1..]
+            .strip_prefix(']')
+            .ok_or_else(|| "missing closing bracket".to_string())?;
+        return Ok((Selector::Field(content[..end].to_string()), rest));
+    }
+
+    let end = input
+        .find(']')
+        .ok_or_else(|| "missing closing bracket".to_string())?;
+    let selector = match input[..end].trim() {
+        "*" => Selector::Wildcard,
+        index => Selector::Index(
+            index
+                .parse()
+                .map_err(|_| format!("'{index}' is not an index"))?,
+        ),
+    };
+    Ok((selector, &input[end + 1..]))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TemplatePart {
+    Text(String),
+    Path(JsonPath),
+}
+
+// A template with JSONPath expressions in curly braces, e.g. '{.workloads[*].name}'.
+// A template without curly braces is handled as one expression.
+// [impl->swdd~cli-supports-jsonpath-output~1]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPathTemplate(Vec<TemplatePart>);
+
+impl JsonPathTemplate {
+    pub fn render(&self, value: &Value) -> Result<String, String> {
+        let mut output = String::new();
+        for part in &self.0 {
+            match part {
+                TemplatePart::Text(text) => output.push_str(text),
+                TemplatePart::Path(path) => {
+                    let selected = path.select(value);
+                    if selected.is_empty() {
+                        return Err(format!(
+                            "The JSONPath '{}' matches nothing",
+                            path_to_string(path)
+                        ));
+                    }
+                    output.push_str(
+                        &selected
+                            .into_iter()
+                            .map(value_to_string)
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    );
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+impl FromStr for JsonPathTemplate {
+    type Err = String;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        if !template.contains('{') {
+            return Ok(JsonPathTemplate(vec![TemplatePart::Path(
+                template.parse()?,
+            )]));
+        }
+
+        let mut parts = Vec::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                parts.push(TemplatePart::Text(unescape(&rest[..start])));
+            }
+            let end = rest[start..]
+                .find('}')
+                .ok_or_else(|| format!("Invalid JSONPath template '{template}': missing '}}'"))?;
+            parts.push(TemplatePart::Path(rest[start + 1..start + end].parse()?));
+            rest = &rest[start + end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Text(unescape(rest)));
+        }
+        Ok(JsonPathTemplate(parts))
+    }
+}
+
+// Strings are output without quotes, all other values as compact JSON.
+pub fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("\\n", "\n").replace("\\t", "\t")
+}
+
+fn path_to_string(path: &JsonPath) -> String {
+    path.0
+        .iter()
+        .map(|selector| match selector {
+            Selector::Field(name) => format!(".{name}"),
+            Selector::Index(index) => format!("[{index}]"),
+            Selector::Wildcard => "[*]".to_string(),
+        })
+        .collect()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{JsonPath, JsonPathTemplate};
+
+    fn test_value() -> serde_json::Value {
+        json!({
+            "workloads": [
+                {"name": "nginx", "agent": "agent_A", "labels": {"app.kubernetes": "web"}},
+                {"name": "db", "agent": "agent_B", "labels": {}},
+            ],
+            "revision": 3
+        })
+    }
+
+    fn select(path: &str) -> Vec<serde_json::Value> {
+        let value = test_value();
+        path.parse::<JsonPath>()
+            .unwrap()
+            .select(&value)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    // [utest->swdd~cli-supports-jsonpath-output~1]
+    #[test]
+    fn utest_json_path_selects_fields_and_indices() {
+        assert_eq!(select(".revision"), vec![json!(3)]);
+        assert_eq!(select("$.workloads[0].name"), vec![json!("nginx")]);
+        assert_eq!(select("workloads[-1].name"), vec![json!("db")]);
+        assert_eq!(
+            select(".workloads[5].name"),
+            Vec::<serde_json::Value>::new()
+        );
+        assert_eq!(
+            select(".workloads[0].labels['app.kubernetes']"),
+            vec![json!("web")]
+        );
+        assert_eq!(select("."), vec![test_value()]);
+    }
+
+    // [utest->swdd~cli-supports-jsonpath-output~1]
+    #[test]
+    fn utest_json_path_selects_wildcards() {
+        assert_eq!(
+            select(".workloads[*].agent"),
+            vec![json!("agent_A"), json!("agent_B")]
+        );
+        assert_eq!(
+            select(".workloads.*.name"),
+            vec![json!("nginx"), json!("db")]
+        );
+    }
+
+    // [utest->swdd~cli-supports-jsonpath-output~1]
+    #[test]
+    fn utest_json_path_rejects_invalid_paths() {
+        assert!(".workloads[".parse::<JsonPath>().is_err());
+        assert!(".workloads[abc]".parse::<JsonPath>().is_err());
+        assert!(".workloads['name]".parse::<JsonPath>().is_err());
+        assert!(".workloads..name".parse::<JsonPath>().is_err());
+    }
+
+    // [utest->swdd~cli-supports-jsonpath-output~1]
+    #[test]
+    fn utest_json_path_template_renders_text_and_values() {
+        let template: JsonPathTemplate = "names: {.workloads[*].name}\\nrevision: {.revision}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            template.render(&test_value()).unwrap(),
+            "names: nginx db\nrevision: 3"
+        );
+
+        let template: JsonPathTemplate = ".workloads[1]".parse().unwrap();
+        assert_eq!(
+            template.render(&test_value()).unwrap(),
+            r#"{"agent":"agent_B","labels":{},"name":"db"}"#
+        );
+    }
+
+    // [utest->swdd~cli-supports-jsonpath-output~1]
+    #[test]
+    fn utest_json_path_template_fails_on_missing_brace_or_no_match() {
+        assert!("{.workloads".parse::<JsonPathTemplate>().is_err());
+        let template: JsonPathTemplate = "{.missing}".parse().unwrap();
+        assert!(template.render(&test_value()).is_err());
+    }
+}
@@ -16,7 +16,10 @@ use std::{
     env, fmt,
     io::{self, IsTerminal},
     process::exit,
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Mutex,
+    },
 };
 
 use crossterm::{
@@ -29,6 +32,7 @@ pub const VERBOSITY_KEY: &str = "VERBOSE";
 pub const QUIET_KEY: &str = "SILENT";
 
 static ROWS_PREV_MSG: Mutex<u16> = Mutex::new(0);
+static ERROR_EXIT_CODE: AtomicI32 = AtomicI32::new(1);
 
 /// Prints the message, if the CLI command is not called with `--quiet` flag
 #[macro_export]
@@ -44,7 +48,7 @@ macro_rules! output_update {
 }
 
 // [impl->swdd~cli-use-proprietary-tracing~1]
-/// Prints the error message and immediately terminates the application with the exit code `1`.
+/// Prints the error message and immediately terminates the application with the error exit code, `1` by default.
 #[macro_export]
 macro_rules! output_and_error {
     ( $ ( $ arg : tt ) + ) => { $crate::log::output_and_error_fn ( format_args ! ( $ ( $ arg ) + ) ) }
@@ -71,9 +75,14 @@ macro_rules! output_warn {
     ( $ ( $ arg : tt ) + ) => { $crate::log::output_warn_fn ( format_args ! ( $ ( $ arg ) + ) ) }
 }
 
+/// Sets the exit code of `output_and_error!` for commands which use the exit code `1` as a result.
+pub(crate) fn set_error_exit_code(exit_code: i32) {
+    ERROR_EXIT_CODE.store(exit_code, Ordering::Relaxed);
+}
+
 pub(crate) fn output_and_error_fn(args: fmt::Arguments<'_>) -> ! {
     eprintln!("{} {}", "error:".bold().red(), args);
-    exit(1);
+    exit(ERROR_EXIT_CODE.load(Ordering::Relaxed));
 }
 
 pub(crate) fn output_and_exit_fn(args: fmt::Arguments<'_>) -> ! {
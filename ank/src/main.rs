@@ -24,8 +24,12 @@ use common::std_extensions::GracefulExitResult;
 use grpc::security::TLSConfig;
 mod cli_error;
 mod filtered_complete_state;
+mod json_path;
 mod log;
 
+// 'ank diff' exits with the code 1 if differences exist.
+const DIFF_ERROR_EXIT_CODE: i32 = 2;
+
 #[cfg(test)]
 pub mod test_helper;
 
@@ -38,7 +42,9 @@ fn handle_ank_config(config_path: &Option<String>, default_path: &str) -> AnkCon
                 "Loading server config from user provided path '{}'",
                 config_path.display()
             );
-            AnkConfig::from_file(config_path).unwrap_or_exit("Config file could not be parsed")
+            AnkConfig::from_file(config_path).unwrap_or_else(|err| {
+                output_and_error!("Config file could not be parsed: '{}'", err)
+            })
         }
         None => {
             let default_path = PathBuf::from(default_path.as_ref() as &std::path::Path);
@@ -60,12 +66,21 @@ fn handle_ank_config(config_path: &Option<String>, default_path: &str) -> AnkCon
 #[tokio::main]
 async fn main() {
     let args = cli::parse();
+    // [impl->swdd~cli-provides-diff-of-manifests~2]
+    if matches!(args.command, cli::Commands::Diff(_)) {
+        log::set_error_exit_code(DIFF_ERROR_EXIT_CODE);
+    }
 
     // [impl->swdd~cli-loads-config-file~1]
     let mut ank_config = handle_ank_config(&args.config_path, &DEFAULT_ANK_CONFIG_FILE_PATH);
     ank_config.update_with_args(&args);
 
-    let cli_name = "ank-cli";
+    // [impl->swdd~cli-connects-with-session-name~1]
+    let cli_name = args
+        .session_name
+        .clone()
+        .or_else(|| env::var("USER").ok())
+        .unwrap_or_else(|| "ank-cli".to_string());
     env::set_var(log::VERBOSITY_KEY, ank_config.verbose.to_string());
     env::set_var(log::QUIET_KEY, ank_config.quiet.to_string());
 
@@ -96,7 +111,7 @@ async fn main() {
 
     let mut cmd = CliCommands::init(
         ank_config.response_timeout,
-        cli_name.to_string(),
+        cli_name,
         ank_config.server_url.clone(),
         ank_config.no_wait,
         // [impl->swdd~cli-fails-on-missing-file-paths-and-insecure-cli-arguments~1]
@@ -115,15 +130,18 @@ async fn main() {
             // [impl->swdd~cli-provides-object-field-mask-arg-to-get-partial-desired-state~1]
             Some(cli::GetCommands::State {
                 object_field_mask,
+                label_selector,
                 output_format,
             }) => {
                 // [impl->swdd~cli-provides-get-desired-state~1]
                 // [impl->swdd~cli-blocks-until-ankaios-server-responds-get-desired-state~1]
-                if let Ok(out_text) = cmd.get_state(object_field_mask, output_format).await {
+                match cmd
+                    .get_state(object_field_mask, label_selector, output_format)
+                    .await
+                {
                     // [impl -> swdd~cli-returns-desired-state-from-server~1]
-                    output_and_exit!("{}", out_text);
-                } else {
-                    output_and_error!("Could not retrieve state.");
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Could not retrieve state: '{}'", error),
                 }
             }
 
@@ -132,40 +150,128 @@ async fn main() {
                 workload_name,
                 agent_name,
                 state,
+                label_selector,
+                watch,
+                output_format,
             }) => {
                 output_debug!(
-                    "Received get workload with workload_name='{:?}', agent_name='{:?}', state='{:?}'",
+                    "Received get workload with workload_name='{:?}', agent_name='{:?}', state='{:?}', label_selector='{:?}', watch='{}', output_format='{:?}'",
                     workload_name,
                     agent_name,
                     state,
+                    label_selector,
+                    watch,
+                    output_format,
                 );
 
-                match cmd
-                    .get_workloads_table(agent_name, state, workload_name)
-                    .await
-                {
-                    Ok(out_text) => output_and_exit!("{}", out_text),
-                    Err(error) => output_and_error!("Failed to get workloads: '{}'", error),
+                // [impl->swdd~cli-watches-list-of-workloads~1]
+                if watch {
+                    if let Err(error) = cmd
+                        .watch_workloads(
+                            agent_name,
+                            state,
+                            workload_name,
+                            label_selector,
+                            &output_format,
+                        )
+                        .await
+                    {
+                        output_and_error!("Failed to watch workloads: '{}'", error);
+                    }
+                } else {
+                    match cmd
+                        .list_workloads(
+                            agent_name,
+                            state,
+                            workload_name,
+                            label_selector,
+                            &output_format,
+                        )
+                        .await
+                    {
+                        Ok(out_text) => output_and_exit!("{}", out_text),
+                        Err(error) => output_and_error!("Failed to get workloads: '{}'", error),
+                    }
                 }
             }
             // [impl->swdd~cli-provides-list-of-agents~1]
-            Some(cli::GetCommands::Agent {}) => {
-                output_debug!("Received get agent.");
+            Some(cli::GetCommands::Agent {
+                watch,
+                output_format,
+            }) => {
+                output_debug!(
+                    "Received get agent with watch='{}', output_format='{:?}'.",
+                    watch,
+                    output_format
+                );
 
-                match cmd.get_agents().await {
-                    Ok(out_text) => output_and_exit!("{}", out_text),
-                    Err(error) => output_and_error!("Failed to get agents: '{}'", error),
+                // [impl->swdd~cli-watches-list-of-agents~1]
+                if watch {
+                    if let Err(error) = cmd.watch_agents(&output_format).await {
+                        output_and_error!("Failed to watch agents: '{}'", error);
+                    }
+                } else {
+                    match cmd.get_agents(&output_format).await {
+                        Ok(out_text) => output_and_exit!("{}", out_text),
+                        Err(error) => output_and_error!("Failed to get agents: '{}'", error),
+                    }
                 }
             }
             // [impl->swdd~cli-provides-list-of-configs~1]
-            Some(cli::GetCommands::Config {}) => {
-                output_debug!("Received get config.");
+            Some(cli::GetCommands::Config { output_format }) => {
+                output_debug!(
+                    "Received get config with output_format='{:?}'.",
+                    output_format
+                );
 
-                match cmd.get_configs().await {
+                match cmd.get_configs(&output_format).await {
                     Ok(out_text) => output_and_exit!("{}", out_text),
                     Err(error) => output_and_error!("Failed to get configs: '{}'", error),
                 }
             }
+            // [impl->swdd~cli-provides-list-of-revisions~1]
+            Some(cli::GetCommands::Revision { output_format }) => {
+                output_debug!(
+                    "Received get revision with output_format='{:?}'.",
+                    output_format
+                );
+
+                match cmd.get_revisions(&output_format).await {
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Failed to get revisions: '{}'", error),
+                }
+            }
+            // [impl->swdd~cli-provides-audit-log~1]
+            Some(cli::GetCommands::Audit {
+                tail,
+                output_format,
+            }) => {
+                output_debug!(
+                    "Received get audit with tail='{:?}', output_format='{:?}'.",
+                    tail,
+                    output_format
+                );
+
+                match cmd.get_audit_log(tail, &output_format).await {
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Failed to get the audit log: '{}'", error),
+                }
+            }
+            None => unreachable!("Unreachable code."),
+        },
+        cli::Commands::Describe(describe_args) => match describe_args.command {
+            // [impl->swdd~cli-provides-workload-description~1]
+            Some(cli::DescribeCommands::Workload { workload_name }) => {
+                output_debug!(
+                    "Received describe workload with workload_name = '{}'",
+                    workload_name
+                );
+
+                match cmd.describe_workload(workload_name).await {
+                    Ok(out_text) => output_and_exit!("{}", out_text),
+                    Err(error) => output_and_error!("Failed to describe the workload: '{}'", error),
+                }
+            }
             None => unreachable!("Unreachable code."),
         },
         cli::Commands::Set(set_args) => match set_args.command {
@@ -173,27 +279,43 @@ async fn main() {
             Some(cli::SetCommands::State {
                 object_field_mask,
                 state_object_file,
+                force,
+                dry_run,
             }) => {
                 output_debug!(
-                    "Received set with object_field_mask='{:?}' and state_object_file='{:?}'",
+                    "Received set with object_field_mask='{:?}', state_object_file='{:?}', force='{:?}' and dry_run='{:?}'",
                     object_field_mask,
-                    state_object_file
+                    state_object_file,
+                    force,
+                    dry_run
                 );
 
                 // [impl->swdd~cli-blocks-until-ankaios-server-responds-set-desired-state~2]
-                if let Err(err) = cmd.set_state(object_field_mask, state_object_file).await {
+                if let Err(err) = cmd
+                    .set_state(object_field_mask, state_object_file, force, dry_run)
+                    .await
+                {
                     output_and_error!("Failed to set state: '{}'", err)
                 }
             }
             None => unreachable!("Unreachable code."),
         },
         cli::Commands::Delete(delete_args) => match delete_args.command {
-            Some(cli::DeleteCommands::Workload { workload_name }) => {
+            Some(cli::DeleteCommands::Workload {
+                workload_name,
+                label_selector,
+            }) => {
                 output_debug!(
-                    "Received delete workload with workload_name = '{:?}'",
-                    workload_name
+                    "Received delete workload with workload_name = '{:?}', label_selector = '{:?}'",
+                    workload_name,
+                    label_selector
                 );
-                if let Err(error) = cmd.delete_workloads(workload_name).await {
+                let delete_result = match label_selector {
+                    // [impl->swdd~cli-deletes-workloads-by-label-selector~1]
+                    Some(label_selector) => cmd.delete_selected_workloads(label_selector).await,
+                    None => cmd.delete_workloads(workload_name).await,
+                };
+                if let Err(error) = delete_result {
                     output_and_error!("Failed to delete workloads: '{}'", error);
                 }
             }
@@ -209,6 +331,72 @@ async fn main() {
             }
             None => unreachable!("Unreachable code."),
         },
+        // [impl->swdd~cli-provides-stop-workload~1]
+        cli::Commands::Stop(stop_args) => match stop_args.command {
+            Some(cli::StopCommands::Workload {
+                workload_name,
+                label_selector,
+            }) => {
+                output_debug!(
+                    "Received stop workload with workload_name = '{:?}', label_selector = '{:?}'",
+                    workload_name,
+                    label_selector
+                );
+                let stop_result = match label_selector {
+                    // [impl->swdd~cli-stops-and-starts-workloads-by-label-selector~1]
+                    Some(label_selector) => cmd.stop_selected_workloads(label_selector).await,
+                    None => cmd.stop_workloads(workload_name).await,
+                };
+                if let Err(error) = stop_result {
+                    output_and_error!("Failed to stop workloads: '{}'", error);
+                }
+            }
+            None => unreachable!("Unreachable code."),
+        },
+        // [impl->swdd~cli-provides-start-workload~1]
+        cli::Commands::Start(start_args) => match start_args.command {
+            Some(cli::StartCommands::Workload {
+                workload_name,
+                label_selector,
+            }) => {
+                output_debug!(
+                    "Received start workload with workload_name = '{:?}', label_selector = '{:?}'",
+                    workload_name,
+                    label_selector
+                );
+                let start_result = match label_selector {
+                    // [impl->swdd~cli-stops-and-starts-workloads-by-label-selector~1]
+                    Some(label_selector) => cmd.start_selected_workloads(label_selector).await,
+                    None => cmd.start_workloads(workload_name).await,
+                };
+                if let Err(error) = start_result {
+                    output_and_error!("Failed to start workloads: '{}'", error);
+                }
+            }
+            None => unreachable!("Unreachable code."),
+        },
+        // [impl->swdd~cli-provides-restart-workload~1]
+        cli::Commands::Restart(restart_args) => match restart_args.command {
+            Some(cli::RestartCommands::Workload {
+                workload_name,
+                label_selector,
+            }) => {
+                output_debug!(
+                    "Received restart workload with workload_name = '{:?}', label_selector = '{:?}'",
+                    workload_name,
+                    label_selector
+                );
+                let restart_result = match label_selector {
+                    // [impl->swdd~cli-restarts-workloads-by-label-selector~1]
+                    Some(label_selector) => cmd.restart_selected_workloads(label_selector).await,
+                    None => cmd.restart_workloads(workload_name).await,
+                };
+                if let Err(error) = restart_result {
+                    output_and_error!("Failed to restart workloads: '{}'", error);
+                }
+            }
+            None => unreachable!("Unreachable code."),
+        },
         cli::Commands::Run(run_args) => match run_args.command {
             Some(cli::RunCommands::Workload {
                 workload_name,
@@ -245,6 +433,69 @@ async fn main() {
                 output_and_error!("{}", err);
             }
         }
+        // [impl->swdd~cli-provides-diff-of-manifests~2]
+        cli::Commands::Diff(diff_args) => match cmd.diff_manifests(diff_args).await {
+            Ok(true) => {
+                cmd.shut_down().await;
+                std::process::exit(1);
+            }
+            Ok(false) => {}
+            Err(err) => output_and_error!("Failed to diff the manifests: '{}'", err),
+        },
+        // [impl->swdd~cli-provides-rollback~1]
+        cli::Commands::Rollback(rollback_args) => {
+            output_debug!(
+                "Received rollback with revision = '{}'",
+                rollback_args.revision
+            );
+            if let Err(error) = cmd.rollback(rollback_args.revision).await {
+                output_and_error!("Failed to roll back: '{}'", error);
+            }
+        }
+        // [impl->swdd~cli-provides-workload-logs~1]
+        cli::Commands::Logs(logs_args) => {
+            output_debug!(
+                "Received logs with workload_name = '{}'",
+                logs_args.workload_name
+            );
+            if let Err(error) = cmd.logs(logs_args).await {
+                output_and_error!("Failed to get logs: '{}'", error);
+            }
+        }
+        // [impl->swdd~cli-provides-backup-of-desired-state~1]
+        cli::Commands::Backup(backup_args) => match cmd.backup(backup_args.diagnostics).await {
+            Ok(backup) => match backup_args.output_file {
+                Some(output_file) => {
+                    if let Err(error) = std::fs::write(&output_file, backup) {
+                        output_and_error!(
+                            "Failed to write the backup to '{}': '{}'",
+                            output_file,
+                            error
+                        );
+                    }
+                    output!("Backup written to '{}'.", output_file);
+                }
+                None => output_and_exit!("{}", backup),
+            },
+            Err(error) => output_and_error!("Failed to create the backup: '{}'", error),
+        },
+        // [impl->swdd~cli-restores-desired-state-from-backup~1]
+        cli::Commands::Restore(restore_args) => {
+            output_debug!(
+                "Received restore with backup_file = '{}'",
+                restore_args.backup_file
+            );
+            if let Err(error) = cmd
+                .restore(
+                    restore_args.backup_file,
+                    restore_args.expect_revision,
+                    restore_args.dry_run,
+                )
+                .await
+            {
+                output_and_error!("Failed to restore the backup: '{}'", error);
+            }
+        }
     }
     cmd.shut_down().await;
 }
@@ -29,6 +29,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .field_attribute("Workload.tags", "#[serde(flatten)]")
         .field_attribute("Workload.configs", "#[serde(flatten)]")
+        .field_attribute("Workload.secrets", "#[serde(flatten)]")
         .field_attribute("Workload.dependencies", "#[serde(flatten)]")
         .field_attribute("WorkloadStatesMap.agentStateMap", "#[serde(flatten)]")
         .field_attribute(
@@ -55,6 +56,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "#[serde(with = \"serde_yaml::with::singleton_map_recursive\")]",
         )
         .field_attribute("ControlInterfaceAccess.denyRules", "#[serde(default)]")
+        .field_attribute(
+            "SchedulingConstraints.requiredAgentLabels",
+            "#[serde(default)]",
+        )
+        .field_attribute("AgentAttributes.labels", "#[serde(default)]")
+        .field_attribute("UpdateStateRequest.dryRun", "#[serde(default)]")
+        .field_attribute("UpdateStateSuccess.dryRun", "#[serde(default)]")
         .compile(&["proto/control_api.proto"], &["proto"])
         .unwrap();
     Ok(())
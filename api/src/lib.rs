@@ -23,6 +23,7 @@ pub mod ank_base {
                 request_id,
                 response_content: response::ResponseContent::Error(Error {
                     message: "Access denied".into(),
+                    ..Default::default()
                 })
                 .into(),
             }
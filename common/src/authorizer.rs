@@ -0,0 +1,1137 @@
+// Copyright (c) 2024 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+mod path;
+mod path_pattern;
+mod rule;
+mod secret_rule;
+
+use crate::{
+    commands::{Request, UpdateStateRequest},
+    objects::{AccessRightsRule, ControlInterfaceAccess, ReadWriteEnum},
+};
+use path::Path;
+use path_pattern::{AllowPathPattern, DenyPathPattern, PathPattern, PathPatternMatcher};
+#[cfg(not(test))]
+use rule::Rule;
+use secret_rule::{SecretNameRule, ALL_SECRETS};
+
+#[cfg(test)]
+use test::MockRule as Rule;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Authorizer {
+    allow_write_state_rule: Vec<Rule<AllowPathPattern>>,
+    deny_write_state_rule: Vec<Rule<DenyPathPattern>>,
+    allow_read_state_rule: Vec<Rule<AllowPathPattern>>,
+    deny_read_state_rule: Vec<Rule<DenyPathPattern>>,
+    allow_read_write_state_rule: Vec<Rule<AllowPathPattern>>,
+    deny_read_write_state_rule: Vec<Rule<DenyPathPattern>>,
+    allow_read_secret_rule: Vec<SecretNameRule>,
+    deny_read_secret_rule: Vec<SecretNameRule>,
+    allow_write_secret_rule: Vec<SecretNameRule>,
+    deny_write_secret_rule: Vec<SecretNameRule>,
+}
+
+const DESIRED_STATE_PATH: &str = "desiredState";
+const WORKLOADS_PATH: &str = "workloads";
+const CONFIGS_PATH: &str = "configs";
+const SECRETS_PATH: &str = "secrets";
+
+enum SecretPath {
+    NoSecret,
+    ParentOfSecrets,
+    AllSecrets,
+    Secret(String),
+}
+
+impl From<&Path> for SecretPath {
+    fn from(path: &Path) -> Self {
+        match path.sections.as_slice() {
+            [] => SecretPath::ParentOfSecrets,
+            [desired_state] if desired_state == DESIRED_STATE_PATH => SecretPath::ParentOfSecrets,
+            [desired_state, secrets]
+                if desired_state == DESIRED_STATE_PATH && secrets == SECRETS_PATH =>
+            {
+                SecretPath::AllSecrets
+            }
+            [desired_state, secrets, secret_name, ..]
+                if desired_state == DESIRED_STATE_PATH && secrets == SECRETS_PATH =>
+            {
+                SecretPath::Secret(secret_name.clone())
+            }
+            _ => SecretPath::NoSecret,
+        }
+    }
+}
+
+impl Authorizer {
+    // [impl->swdd~agent-authorizing-request-operations~1]
+    // [impl->swdd~agent-authorizing-condition-element-filter-mask-allowed~1]
+    pub fn authorize(&self, request: &Request) -> bool {
+        match &request.request_content {
+            crate::commands::RequestContent::CompleteStateRequest(r) => {
+                self.authorize_read(&request.request_id, &r.field_mask)
+            }
+            crate::commands::RequestContent::UpdateStateRequest(r) => {
+                self.authorize_write(&request.request_id, &r.update_mask)
+                    && self.authorize_secret_references(&request.request_id, r)
+            }
+            // [impl->swdd~agent-authorizing-revision-requests~1]
+            crate::commands::RequestContent::RevisionsRequest(_) => {
+                self.authorize_read(&request.request_id, &[DESIRED_STATE_PATH.into()])
+            }
+            // [impl->swdd~agent-authorizing-revision-requests~1]
+            crate::commands::RequestContent::RollbackRequest(_) => {
+                self.authorize_write(&request.request_id, &[DESIRED_STATE_PATH.into()])
+            }
+            // [impl->swdd~agent-authorizing-events-requests~1]
+            crate::commands::RequestContent::EventsRequest(r) => {
+                self.authorize_read(&request.request_id, &r.field_mask)
+            }
+            // [impl->swdd~agent-authorizing-events-requests~1]
+            crate::commands::RequestContent::EventsCancelRequest(_) => true,
+            // [impl->swdd~agent-authorizing-logs-requests~1]
+            crate::commands::RequestContent::LogsRequest(r) => self.authorize_read(
+                &request.request_id,
+                &[format!(
+                    "{DESIRED_STATE_PATH}.{WORKLOADS_PATH}.{}",
+                    r.workload_name
+                )],
+            ),
+            // [impl->swdd~agent-authorizing-logs-requests~1]
+            crate::commands::RequestContent::LogsCancelRequest(_) => true,
+            // [impl->swdd~agent-authorizing-audit-log-requests~1]
+            crate::commands::RequestContent::AuditLogRequest(_) => {
+                self.authorize_read(&request.request_id, &[DESIRED_STATE_PATH.into()])
+            }
+            // [impl->swdd~agent-authorizing-workload-description-requests~2]
+            crate::commands::RequestContent::WorkloadDescriptionRequest(r) => {
+                // the description contains the runtime config rendered with the referenced configs
+                let field_mask: Vec<String> = std::iter::once(format!(
+                    "{DESIRED_STATE_PATH}.{WORKLOADS_PATH}.{}",
+                    r.workload_name
+                ))
+                .chain(r.config_names.iter().map(|config_name| {
+                    format!("{DESIRED_STATE_PATH}.{CONFIGS_PATH}.{config_name}")
+                }))
+                .collect();
+                self.authorize_read(&request.request_id, &field_mask)
+            }
+        }
+    }
+
+    fn authorize_read(&self, request_id: &str, field_mask: &[String]) -> bool {
+        let field_mask = if field_mask.is_empty() {
+            // [impl->swdd~agent-authorizing-request-without-filter-mask~1]
+            &["".into()]
+        } else {
+            field_mask
+        };
+        // [impl->swdd~agent-authorizing-all-elements-of-filter-mask-allowed~1]
+        field_mask.iter().all(|path_string| {
+            let path = path_string.as_str().into();
+            // [impl->swdd~agent-authorizing-secret-paths-with-secret-rules~1]
+            match SecretPath::from(&path) {
+                SecretPath::AllSecrets => {
+                    return self.authorize_secret_read(request_id, ALL_SECRETS);
+                }
+                SecretPath::Secret(secret_name) => {
+                    return self.authorize_secret_read(request_id, &secret_name);
+                }
+                SecretPath::NoSecret | SecretPath::ParentOfSecrets => {}
+            }
+
+            let allow_reason = if let (true, reason) = self.allow_read_state_rule.matches(&path) {
+                reason
+            } else if let (true, reason) = self.allow_read_write_state_rule.matches(&path) {
+                reason
+            } else {
+                log::info!(
+                    "Denying field mask '{}' of request '{}' as no rule matches",
+                    path_string,
+                    request_id
+                );
+                return false;
+            };
+
+            let deny_reason = if let (true, reason) = self.deny_read_state_rule.matches(&path) {
+                reason
+            } else if let (true, reason) = self.deny_read_write_state_rule.matches(&path) {
+                reason
+            } else {
+                log::debug!(
+                    "Allow field mask '{}' of request '{}' as '{}' is allowed",
+                    path_string,
+                    request_id,
+                    allow_reason
+                );
+                return true;
+            };
+
+            log::info!(
+                "Deny field mask '{}' of request '{}',also allowed by '{}', as denied by '{}'",
+                path_string,
+                request_id,
+                allow_reason,
+                deny_reason
+            );
+            false
+        })
+    }
+
+    fn authorize_write(&self, request_id: &str, update_mask: &[String]) -> bool {
+        let update_mask = if update_mask.is_empty() {
+            // [impl->swdd~agent-authorizing-request-without-filter-mask~1]
+            &["".into()]
+        } else {
+            update_mask
+        };
+        // [impl->swdd~agent-authorizing-all-elements-of-filter-mask-allowed~1]
+        update_mask.iter().all(|path_string| {
+            let path = path_string.as_str().into();
+            // [impl->swdd~agent-authorizing-secret-paths-with-secret-rules~1]
+            match SecretPath::from(&path) {
+                SecretPath::AllSecrets => {
+                    return self.authorize_secret_write(request_id, ALL_SECRETS);
+                }
+                SecretPath::Secret(secret_name) => {
+                    return self.authorize_secret_write(request_id, &secret_name);
+                }
+                SecretPath::ParentOfSecrets => {
+                    if !self.authorize_secret_write(request_id, ALL_SECRETS) {
+                        return false;
+                    }
+                }
+                SecretPath::NoSecret => {}
+            }
+
+            let allow_reason = if let (true, reason) = self.allow_write_state_rule.matches(&path) {
+                reason
+            } else if let (true, reason) = self.allow_read_write_state_rule.matches(&path) {
+                reason
+            } else {
+                log::info!(
+                    "Deny update mask '{}' of request '{}' as no rule matches",
+                    path_string,
+                    request_id
+                );
+                return false;
+            };
+
+            let deny_reason = if let (true, reason) = self.deny_write_state_rule.matches(&path) {
+                reason
+            } else if let (true, reason) = self.deny_read_write_state_rule.matches(&path) {
+                reason
+            } else {
+                log::debug!(
+                    "Allow update mask '{}' of request '{}' as '{}' is allowed",
+                    path_string,
+                    request_id,
+                    allow_reason
+                );
+                return true;
+            };
+
+            log::info!(
+                "Deny update mask '{}' of request '{}', also allowed by '{}', as denied by '{}'",
+                path_string,
+                request_id,
+                allow_reason,
+                deny_reason
+            );
+            false
+        })
+    }
+
+    // [impl->swdd~agent-authorizing-secret-references~1]
+    fn authorize_secret_references(&self, request_id: &str, request: &UpdateStateRequest) -> bool {
+        let update_mask: Vec<Path> = request
+            .update_mask
+            .iter()
+            .map(|path| path.as_str().into())
+            .collect();
+
+        request
+            .state
+            .desired_state
+            .workloads
+            .iter()
+            .filter(|(workload_name, _)| {
+                let workload_path = [DESIRED_STATE_PATH, WORKLOADS_PATH, workload_name.as_str()];
+                update_mask.is_empty()
+                    || update_mask.iter().any(|path| {
+                        path.sections
+                            .iter()
+                            .zip(workload_path.iter())
+                            .all(|(a, b)| a == b)
+                    })
+            })
+            .flat_map(|(_, workload)| workload.secrets.values())
+            .all(|secret_name| self.authorize_secret_read(request_id, secret_name))
+    }
+
+    fn authorize_secret_read(&self, request_id: &str, secret_name: &str) -> bool {
+        Self::authorize_secret(
+            request_id,
+            secret_name,
+            "read",
+            &self.allow_read_secret_rule,
+            &self.deny_read_secret_rule,
+        )
+    }
+
+    fn authorize_secret_write(&self, request_id: &str, secret_name: &str) -> bool {
+        Self::authorize_secret(
+            request_id,
+            secret_name,
+            "write",
+            &self.allow_write_secret_rule,
+            &self.deny_write_secret_rule,
+        )
+    }
+
+    // [impl->swdd~agent-authorizing-secret-rules~1]
+    fn authorize_secret(
+        request_id: &str,
+        secret_name: &str,
+        operation: &str,
+        allow_rules: &[SecretNameRule],
+        deny_rules: &[SecretNameRule],
+    ) -> bool {
+        if !allow_rules.iter().any(|rule| rule.covers(secret_name)) {
+            log::info!(
+                "Denying {} access to secret '{}' of request '{}' as no secret rule matches",
+                operation,
+                secret_name,
+                request_id
+            );
+            return false;
+        }
+        if deny_rules.iter().any(|rule| rule.intersects(secret_name)) {
+            log::info!(
+                "Denying {} access to secret '{}' of request '{}' as denied by a secret rule",
+                operation,
+                secret_name,
+                request_id
+            );
+            return false;
+        }
+        true
+    }
+}
+
+impl From<&ControlInterfaceAccess> for Authorizer {
+    fn from(value: &ControlInterfaceAccess) -> Self {
+        struct ReadWriteFiltered<T: PathPattern> {
+            read: Vec<Rule<T>>,
+            write: Vec<Rule<T>>,
+            read_write: Vec<Rule<T>>,
+            read_secret: Vec<SecretNameRule>,
+            write_secret: Vec<SecretNameRule>,
+        }
+
+        fn split_to_read_write_rules<T>(rule_list: &[AccessRightsRule]) -> ReadWriteFiltered<T>
+        where
+            T: PathPattern,
+            T: for<'a> From<&'a str>,
+        {
+            let mut res = ReadWriteFiltered {
+                read: Vec::new(),
+                write: Vec::new(),
+                read_write: Vec::new(),
+                read_secret: Vec::new(),
+                write_secret: Vec::new(),
+            };
+
+            for access_rights in rule_list {
+                let state_rule = match access_rights {
+                    AccessRightsRule::StateRule(state_rule) => state_rule,
+                    AccessRightsRule::SecretRule(secret_rule) => {
+                        let rule = SecretNameRule::create(secret_rule.secret_names.clone());
+                        match secret_rule.operation {
+                            ReadWriteEnum::Read => res.read_secret.push(rule),
+                            ReadWriteEnum::Write => res.write_secret.push(rule),
+                            ReadWriteEnum::ReadWrite => {
+                                res.read_secret.push(rule.clone());
+                                res.write_secret.push(rule);
+                            }
+                            ReadWriteEnum::Nothing => {}
+                        };
+                        continue;
+                    }
+                };
+                let rule = Rule::create(
+                    state_rule
+                        .filter_mask
+                        .iter()
+                        .map(|x| (**x).into())
+                        .collect(),
+                );
+                match state_rule.operation {
+                    ReadWriteEnum::Read => res.read.push(rule),
+                    ReadWriteEnum::Write => res.write.push(rule),
+                    ReadWriteEnum::ReadWrite => res.read_write.push(rule),
+                    ReadWriteEnum::Nothing => {}
+                };
+            }
+
+            res
+        }
+
+        let allow_rules = split_to_read_write_rules(&value.allow_rules);
+        let deny_rules = split_to_read_write_rules(&value.deny_rules);
+
+        Self {
+            allow_write_state_rule: allow_rules.write,
+            deny_write_state_rule: deny_rules.write,
+            allow_read_state_rule: allow_rules.read,
+            deny_read_state_rule: deny_rules.read,
+            allow_read_write_state_rule: allow_rules.read_write,
+            deny_read_write_state_rule: deny_rules.read_write,
+            allow_read_secret_rule: allow_rules.read_secret,
+            deny_read_secret_rule: deny_rules.read_secret,
+            allow_write_secret_rule: allow_rules.write_secret,
+            deny_write_secret_rule: deny_rules.write_secret,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        commands::{
+            AuditLogRequest, CompleteStateRequest, EventsCancelRequest, EventsRequest,
+            LogsCancelRequest, LogsRequest, Request, RevisionsRequest, RollbackRequest,
+            UpdateStateRequest, WorkloadDescriptionRequest,
+        },
+        objects::{
+            generate_test_stored_workload_spec, AccessRightsRule, CompleteState,
+            ControlInterfaceAccess, ReadWriteEnum, SecretRule, State, StateRule,
+        },
+    };
+
+    use super::super::authorizer::path_pattern::{AllowPathPattern, DenyPathPattern};
+
+    use super::{
+        path::Path, path_pattern::PathPatternMatcher, secret_rule::SecretNameRule, Authorizer,
+        DESIRED_STATE_PATH,
+    };
+
+    const MATCHING_PATH: &str = "matching.path";
+    const MATCHING_PATH_2: &str = "matching.path.2";
+    const NON_MATCHING_PATH: &str = "non.matching.path";
+    const MATCHING_WORKLOAD_PATH: &str = "desiredState.workloads.matching_workload";
+    const MATCHING_CONFIG_PATH: &str = "desiredState.configs.matching_config";
+
+    enum RuleType {
+        AllowWrite,
+        DenyWrite,
+        AllowRead,
+        DenyRead,
+        AllowReadWrite,
+        DenyReadWrite,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct MockRule<T> {
+        patterns: Option<Vec<T>>,
+    }
+
+    impl<T> MockRule<T> {
+        pub fn create(patterns: Vec<T>) -> Self {
+            Self {
+                patterns: Some(patterns),
+            }
+        }
+
+        fn default() -> Self {
+            Self { patterns: None }
+        }
+    }
+
+    impl<T> PathPatternMatcher for MockRule<T> {
+        fn matches(&self, path: &Path) -> (bool, String) {
+            if path.to_string() == MATCHING_PATH
+                || path.to_string() == MATCHING_PATH_2
+                || path.to_string() == DESIRED_STATE_PATH
+                || path.to_string() == MATCHING_WORKLOAD_PATH
+                || path.to_string() == MATCHING_CONFIG_PATH
+                || path.sections.is_empty()
+            {
+                (true, "".into())
+            } else {
+                (false, "".into())
+            }
+        }
+    }
+
+    fn create_authorizer(matching_rules: &[RuleType]) -> Authorizer {
+        // secret rules are tested separately, so they do not restrict the state rules here
+        let mut res = Authorizer {
+            allow_read_secret_rule: vec![SecretNameRule::create(vec!["*".into()])],
+            allow_write_secret_rule: vec![SecretNameRule::create(vec!["*".into()])],
+            ..Default::default()
+        };
+
+        for rule_to_change in matching_rules {
+            match rule_to_change {
+                RuleType::AllowWrite => res.allow_write_state_rule.push(MockRule::default()),
+                RuleType::DenyWrite => res.deny_write_state_rule.push(MockRule::default()),
+                RuleType::AllowRead => res.allow_read_state_rule.push(MockRule::default()),
+                RuleType::DenyRead => res.deny_read_state_rule.push(MockRule::default()),
+                RuleType::AllowReadWrite => {
+                    res.allow_read_write_state_rule.push(MockRule::default())
+                }
+                RuleType::DenyReadWrite => res.deny_read_write_state_rule.push(MockRule::default()),
+            }
+        }
+
+        res
+    }
+
+    // [utest->swdd~agent-authorizing-request-without-filter-mask~1]
+    #[test]
+    fn utest_denies_empty_request() {
+        let authorizer = create_authorizer(&[]);
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::CompleteStateRequest(
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None,
+                },
+            ),
+        };
+        assert!(!authorizer.authorize(&request));
+
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::UpdateStateRequest(Box::new(
+                UpdateStateRequest {
+                    state: Default::default(),
+                    update_mask: vec![],
+                    dry_run: false,
+                },
+            )),
+        };
+        assert!(!authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-request-without-filter-mask~1]
+    #[test]
+    fn utest_allow_empty_request() {
+        let authorizer = create_authorizer(&[RuleType::AllowReadWrite]);
+
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::CompleteStateRequest(
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None,
+                },
+            ),
+        };
+        assert!(authorizer.authorize(&request));
+
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::UpdateStateRequest(Box::new(
+                UpdateStateRequest {
+                    state: Default::default(),
+                    update_mask: vec![],
+                    dry_run: false,
+                },
+            )),
+        };
+        assert!(authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-request-operations~1]
+    // [utest->swdd~agent-authorizing-condition-element-filter-mask-allowed~1]
+    #[test]
+    fn utest_read_requests_operations() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::CompleteStateRequest(
+                CompleteStateRequest {
+                    field_mask: vec![MATCHING_PATH.into()],
+                    label_selector: None,
+                },
+            ),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowReadWrite]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyRead]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyReadWrite]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyWrite]);
+        assert!(authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-request-operations~1]
+    // [utest->swdd~agent-authorizing-condition-element-filter-mask-allowed~1]
+    #[test]
+    fn utest_write_requests_operations() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::UpdateStateRequest(Box::new(
+                UpdateStateRequest {
+                    update_mask: vec![MATCHING_PATH.into()],
+                    state: Default::default(),
+                    dry_run: false,
+                },
+            )),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowReadWrite]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite, RuleType::DenyWrite]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite, RuleType::DenyReadWrite]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite, RuleType::DenyRead]);
+        assert!(authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-revision-requests~1]
+    #[test]
+    fn utest_revisions_request_requires_read_access_to_desired_state() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::RevisionsRequest(RevisionsRequest {}),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyRead]);
+        assert!(!authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-audit-log-requests~1]
+    #[test]
+    fn utest_audit_log_request_requires_read_access_to_desired_state() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::AuditLogRequest(AuditLogRequest {
+                tail: None,
+            }),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyRead]);
+        assert!(!authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-revision-requests~1]
+    #[test]
+    fn utest_rollback_request_requires_write_access_to_desired_state() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::RollbackRequest(RollbackRequest {
+                revision: 1,
+            }),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite, RuleType::DenyReadWrite]);
+        assert!(!authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-events-requests~1]
+    #[test]
+    fn utest_events_request_requires_read_access_to_field_mask() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::EventsRequest(EventsRequest {
+                field_mask: vec![MATCHING_PATH.into()],
+            }),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyRead]);
+        assert!(!authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-events-requests~1]
+    #[test]
+    fn utest_events_cancel_request_is_always_allowed() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::EventsCancelRequest(
+                EventsCancelRequest {},
+            ),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-logs-requests~1]
+    #[test]
+    fn utest_logs_request_requires_read_access_to_workload() {
+        let logs_request = |workload_name: &str| Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::LogsRequest(LogsRequest {
+                workload_name: workload_name.into(),
+                follow: false,
+                since: None,
+                tail: None,
+            }),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&logs_request("matching_workload")));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(authorizer.authorize(&logs_request("matching_workload")));
+        assert!(!authorizer.authorize(&logs_request("other_workload")));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(!authorizer.authorize(&logs_request("matching_workload")));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyRead]);
+        assert!(!authorizer.authorize(&logs_request("matching_workload")));
+    }
+
+    fn workload_description_request(workload_name: &str, config_names: &[&str]) -> Request {
+        Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::WorkloadDescriptionRequest(
+                WorkloadDescriptionRequest {
+                    workload_name: workload_name.into(),
+                    config_names: config_names.iter().map(|x| x.to_string()).collect(),
+                },
+            ),
+        }
+    }
+
+    // [utest->swdd~agent-authorizing-workload-description-requests~2]
+    #[test]
+    fn utest_workload_description_request_requires_read_access_to_workload() {
+        let request = |workload_name: &str| workload_description_request(workload_name, &[]);
+
+        let authorizer = create_authorizer(&[]);
+        assert!(!authorizer.authorize(&request("matching_workload")));
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(authorizer.authorize(&request("matching_workload")));
+        assert!(!authorizer.authorize(&request("other_workload")));
+        let authorizer = create_authorizer(&[RuleType::AllowWrite]);
+        assert!(!authorizer.authorize(&request("matching_workload")));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyRead]);
+        assert!(!authorizer.authorize(&request("matching_workload")));
+    }
+
+    // [utest->swdd~agent-authorizing-workload-description-requests~2]
+    #[test]
+    fn utest_workload_description_request_requires_read_access_to_referenced_configs() {
+        let authorizer = create_authorizer(&[RuleType::AllowRead]);
+        assert!(authorizer.authorize(&workload_description_request(
+            "matching_workload",
+            &["matching_config"]
+        )));
+        assert!(!authorizer.authorize(&workload_description_request(
+            "matching_workload",
+            &["matching_config", "other_config"]
+        )));
+        let authorizer = create_authorizer(&[RuleType::AllowRead, RuleType::DenyRead]);
+        assert!(!authorizer.authorize(&workload_description_request(
+            "matching_workload",
+            &["matching_config"]
+        )));
+    }
+
+    // [utest->swdd~agent-authorizing-logs-requests~1]
+    #[test]
+    fn utest_logs_cancel_request_is_always_allowed() {
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::LogsCancelRequest(
+                LogsCancelRequest {},
+            ),
+        };
+
+        let authorizer = create_authorizer(&[]);
+        assert!(authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-all-elements-of-filter-mask-allowed~1]
+    #[test]
+    fn utest_matches_all_filter_entries() {
+        let authorizer = create_authorizer(&[RuleType::AllowReadWrite]);
+
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::CompleteStateRequest(
+                CompleteStateRequest {
+                    field_mask: vec![MATCHING_PATH.into(), MATCHING_PATH_2.into()],
+                    label_selector: None,
+                },
+            ),
+        };
+        assert!(authorizer.authorize(&request));
+
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::UpdateStateRequest(Box::new(
+                UpdateStateRequest {
+                    update_mask: vec![MATCHING_PATH.into(), MATCHING_PATH_2.into()],
+                    state: Default::default(),
+                    dry_run: false,
+                },
+            )),
+        };
+        assert!(authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-all-elements-of-filter-mask-allowed~1]
+    #[test]
+    fn utest_matches_not_all_filter_entries() {
+        let authorizer = create_authorizer(&[RuleType::AllowReadWrite]);
+
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::CompleteStateRequest(
+                CompleteStateRequest {
+                    field_mask: vec![MATCHING_PATH.into(), NON_MATCHING_PATH.into()],
+                    label_selector: None,
+                },
+            ),
+        };
+        assert!(!authorizer.authorize(&request));
+
+        let request = Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::UpdateStateRequest(Box::new(
+                UpdateStateRequest {
+                    update_mask: vec![MATCHING_PATH.into(), NON_MATCHING_PATH.into()],
+                    state: Default::default(),
+                    dry_run: false,
+                },
+            )),
+        };
+        assert!(!authorizer.authorize(&request));
+    }
+
+    #[test]
+    fn utest_authorizer_from_control_interface_access() {
+        let access_rights = ControlInterfaceAccess {
+            allow_rules: vec![
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::Nothing,
+                    filter_mask: vec!["allow.nothing".into()],
+                }),
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::Read,
+                    filter_mask: vec!["allow.read".into()],
+                }),
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::Write,
+                    filter_mask: vec!["allow.write".into()],
+                }),
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::ReadWrite,
+                    filter_mask: vec!["allow.read.write".into()],
+                }),
+                AccessRightsRule::SecretRule(SecretRule {
+                    operation: ReadWriteEnum::Read,
+                    secret_names: vec!["allow_read".into()],
+                }),
+                AccessRightsRule::SecretRule(SecretRule {
+                    operation: ReadWriteEnum::ReadWrite,
+                    secret_names: vec!["allow_read_write".into()],
+                }),
+            ],
+            deny_rules: vec![
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::Nothing,
+                    filter_mask: vec!["deny.nothing".into()],
+                }),
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::Read,
+                    filter_mask: vec!["deny.read".into()],
+                }),
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::Write,
+                    filter_mask: vec!["deny.write".into()],
+                }),
+                AccessRightsRule::StateRule(StateRule {
+                    operation: crate::objects::ReadWriteEnum::ReadWrite,
+                    filter_mask: vec!["deny.read.write".into()],
+                }),
+                AccessRightsRule::SecretRule(SecretRule {
+                    operation: ReadWriteEnum::Write,
+                    secret_names: vec!["deny_write".into()],
+                }),
+                AccessRightsRule::SecretRule(SecretRule {
+                    operation: ReadWriteEnum::Nothing,
+                    secret_names: vec!["deny_nothing".into()],
+                }),
+            ],
+        };
+
+        let authorizer = Authorizer::from(&access_rights);
+
+        assert_eq!(
+            authorizer.allow_read_state_rule,
+            vec![MockRule {
+                patterns: Some(vec![AllowPathPattern::from("allow.read")]),
+            }]
+        );
+        assert_eq!(
+            authorizer.allow_write_state_rule,
+            vec![MockRule {
+                patterns: Some(vec![AllowPathPattern::from("allow.write")]),
+            }]
+        );
+        assert_eq!(
+            authorizer.allow_read_write_state_rule,
+            vec![MockRule {
+                patterns: Some(vec![AllowPathPattern::from("allow.read.write")]),
+            }]
+        );
+
+        assert_eq!(
+            authorizer.deny_read_state_rule,
+            vec![MockRule {
+                patterns: Some(vec![DenyPathPattern::from("deny.read")]),
+            }]
+        );
+        assert_eq!(
+            authorizer.deny_write_state_rule,
+            vec![MockRule {
+                patterns: Some(vec![DenyPathPattern::from("deny.write")]),
+            }]
+        );
+        assert_eq!(
+            authorizer.deny_read_write_state_rule,
+            vec![MockRule {
+                patterns: Some(vec![DenyPathPattern::from("deny.read.write")]),
+            }]
+        );
+        assert_eq!(
+            authorizer.allow_read_secret_rule,
+            vec![
+                SecretNameRule::create(vec!["allow_read".into()]),
+                SecretNameRule::create(vec!["allow_read_write".into()])
+            ]
+        );
+        assert_eq!(
+            authorizer.allow_write_secret_rule,
+            vec![SecretNameRule::create(vec!["allow_read_write".into()])]
+        );
+        assert!(authorizer.deny_read_secret_rule.is_empty());
+        assert_eq!(
+            authorizer.deny_write_secret_rule,
+            vec![SecretNameRule::create(vec!["deny_write".into()])]
+        );
+    }
+
+    fn create_secret_authorizer(
+        allow_rules: &[SecretRule],
+        deny_rules: &[SecretRule],
+    ) -> Authorizer {
+        let mut authorizer = Authorizer::from(&ControlInterfaceAccess {
+            allow_rules: allow_rules
+                .iter()
+                .cloned()
+                .map(AccessRightsRule::SecretRule)
+                .collect(),
+            deny_rules: deny_rules
+                .iter()
+                .cloned()
+                .map(AccessRightsRule::SecretRule)
+                .collect(),
+        });
+        authorizer
+            .allow_read_write_state_rule
+            .push(MockRule::default());
+        authorizer
+    }
+
+    fn secret_rule(operation: ReadWriteEnum, secret_names: &[&str]) -> SecretRule {
+        SecretRule {
+            operation,
+            secret_names: secret_names.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn update_request(update_mask: &[&str], state: State) -> Request {
+        Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::UpdateStateRequest(Box::new(
+                UpdateStateRequest {
+                    state: CompleteState {
+                        desired_state: state,
+                        ..Default::default()
+                    },
+                    update_mask: update_mask.iter().map(ToString::to_string).collect(),
+                    dry_run: false,
+                },
+            )),
+        }
+    }
+
+    fn read_request(field_mask: &[&str]) -> Request {
+        Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::CompleteStateRequest(
+                CompleteStateRequest {
+                    field_mask: field_mask.iter().map(ToString::to_string).collect(),
+                    label_selector: None,
+                },
+            ),
+        }
+    }
+
+    // [utest->swdd~agent-authorizing-secret-paths-with-secret-rules~1]
+    // [utest->swdd~agent-authorizing-secret-rules~1]
+    #[test]
+    fn utest_secret_writes_require_secret_rule() {
+        let request = update_request(&["desiredState.secrets.db_password"], State::default());
+
+        let authorizer = create_secret_authorizer(&[], &[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer =
+            create_secret_authorizer(&[secret_rule(ReadWriteEnum::Read, &["db_password"])], &[]);
+        assert!(!authorizer.authorize(&request));
+        let authorizer =
+            create_secret_authorizer(&[secret_rule(ReadWriteEnum::Write, &["db_password"])], &[]);
+        assert!(authorizer.authorize(&request));
+        let authorizer =
+            create_secret_authorizer(&[secret_rule(ReadWriteEnum::ReadWrite, &["*"])], &[]);
+        assert!(authorizer.authorize(&request));
+        let authorizer = create_secret_authorizer(
+            &[secret_rule(ReadWriteEnum::Write, &["*"])],
+            &[secret_rule(ReadWriteEnum::Write, &["db_password"])],
+        );
+        assert!(!authorizer.authorize(&request));
+        let authorizer = create_secret_authorizer(
+            &[secret_rule(ReadWriteEnum::Write, &["*"])],
+            &[secret_rule(ReadWriteEnum::Read, &["db_password"])],
+        );
+        assert!(authorizer.authorize(&request));
+    }
+
+    // [utest->swdd~agent-authorizing-secret-paths-with-secret-rules~1]
+    #[test]
+    fn utest_writes_of_parents_of_secrets_require_write_access_to_all_secrets() {
+        let authorizer =
+            create_secret_authorizer(&[secret_rule(ReadWriteEnum::Write, &["db_password"])], &[]);
+        assert!(!authorizer.authorize(&update_request(&["desiredState.secrets"], State::default())));
+        assert!(!authorizer.authorize(&update_request(&[DESIRED_STATE_PATH], State::default())));
+        assert!(!authorizer.authorize(&update_request(&[], State::default())));
+        assert!(!authorizer.authorize(&Request {
+            request_id: "".into(),
+            request_content: crate::commands::RequestContent::RollbackRequest(RollbackRequest {
+                revision: 1,
+            }),
+        }));
+        assert!(authorizer.authorize(&update_request(&[MATCHING_PATH], State::default())));
+
+        let authorizer =
+            create_secret_authorizer(&[secret_rule(ReadWriteEnum::Write, &["*"])], &[]);
+        assert!(authorizer.authorize(&update_request(&["desiredState.secrets"], State::default())));
+        assert!(authorizer.authorize(&update_request(&[DESIRED_STATE_PATH], State::default())));
+        assert!(authorizer.authorize(&update_request(&[], State::default())));
+
+        let authorizer = create_secret_authorizer(
+            &[secret_rule(ReadWriteEnum::Write, &["*"])],
+            &[secret_rule(ReadWriteEnum::Write, &["db_password"])],
+        );
+        assert!(!authorizer.authorize(&update_request(&[DESIRED_STATE_PATH], State::default())));
+    }
+
+    // [utest->swdd~agent-authorizing-secret-paths-with-secret-rules~1]
+    #[test]
+    fn utest_secret_reads_require_secret_rule() {
+        let authorizer =
+            create_secret_authorizer(&[secret_rule(ReadWriteEnum::Read, &["db_password"])], &[]);
+
+        assert!(authorizer.authorize(&read_request(&["desiredState.secrets.db_password"])));
+        assert!(!authorizer.authorize(&read_request(&["desiredState.secrets.api_token"])));
+        assert!(!authorizer.authorize(&read_request(&["desiredState.secrets"])));
+        // the values of secrets are always redacted, reading the parents is governed by state rules
+        assert!(authorizer.authorize(&read_request(&[DESIRED_STATE_PATH])));
+
+        let authorizer = create_secret_authorizer(&[], &[]);
+        assert!(!authorizer.authorize(&read_request(&["desiredState.secrets.db_password"])));
+    }
+
+    // [utest->swdd~agent-authorizing-secret-references~1]
+    #[test]
+    fn utest_referencing_secrets_requires_secret_read_access() {
+        let mut workload = generate_test_stored_workload_spec("agent_A", "runtime");
+        workload
+            .secrets
+            .insert("password".into(), "db_password".into());
+        let state = State {
+            workloads: [("matching_workload".to_string(), workload)].into(),
+            ..Default::default()
+        };
+
+        let authorizer = create_secret_authorizer(&[], &[]);
+        assert!(!authorizer.authorize(&update_request(&[MATCHING_WORKLOAD_PATH], state.clone())));
+        // workloads not covered by the update mask are not updated
+        assert!(authorizer.authorize(&update_request(&[MATCHING_PATH], state.clone())));
+
+        let authorizer =
+            create_secret_authorizer(&[secret_rule(ReadWriteEnum::Read, &["db_password"])], &[]);
+        assert!(authorizer.authorize(&update_request(&[MATCHING_WORKLOAD_PATH], state.clone())));
+
+        let authorizer = create_secret_authorizer(
+            &[secret_rule(ReadWriteEnum::ReadWrite, &["*"])],
+            &[secret_rule(ReadWriteEnum::Read, &["db_password"])],
+        );
+        assert!(!authorizer.authorize(&update_request(&[MATCHING_WORKLOAD_PATH], state)));
+    }
+}
@@ -14,7 +14,7 @@
 
 use std::fmt::Display;
 
-use common::PATH_SEPARATOR;
+use crate::PATH_SEPARATOR;
 
 #[derive(Clone, Debug)]
 pub struct Path {
@@ -50,7 +50,7 @@ impl Display for Path {
 #[cfg(test)]
 mod tests {
     use super::Path;
-    use common::PATH_SEPARATOR;
+    use crate::PATH_SEPARATOR;
 
     #[test]
     fn utest_from_empty_str() {
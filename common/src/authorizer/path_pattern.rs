@@ -15,7 +15,7 @@
 use std::fmt::Display;
 
 use super::path::Path;
-use common::PATH_SEPARATOR;
+use crate::PATH_SEPARATOR;
 
 const WILDCARD_SYMBOL: &str = "*";
 
@@ -129,6 +129,7 @@ impl PathPattern for DenyPathPattern {
 #[derive(Clone, Debug, PartialEq)]
 pub enum PathPatternSection {
     Wildcard,
+    Prefix(String),
     String(String),
 }
 
@@ -136,6 +137,8 @@ impl From<&str> for PathPatternSection {
     fn from(value: &str) -> Self {
         if value == WILDCARD_SYMBOL {
             Self::Wildcard
+        } else if let Some(prefix) = value.strip_suffix(WILDCARD_SYMBOL) {
+            Self::Prefix(prefix.into())
         } else {
             Self::String(value.into())
         }
@@ -146,16 +149,18 @@ impl Display for PathPatternSection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             PathPatternSection::Wildcard => write!(f, "*"),
+            PathPatternSection::Prefix(prefix) => write!(f, "{}*", prefix),
             PathPatternSection::String(s) => write!(f, "{}", s.clone()),
         }
     }
 }
 
 impl PathPatternSection {
-    // [impl->swdd~agent-authorizing-matching-rules-elements~1]
+    // [impl->swdd~agent-authorizing-matching-rules-elements~2]
     pub fn matches(&self, other: &String) -> bool {
         match self {
             PathPatternSection::Wildcard => true,
+            PathPatternSection::Prefix(prefix) => other.starts_with(prefix.as_str()),
             PathPatternSection::String(self_string) => self_string == other,
         }
     }
@@ -194,9 +199,7 @@ mod tests {
         }
     }
 
-    use crate::control_interface::authorizer::{
-        AllowPathPattern, DenyPathPattern, PathPattern, PathPatternMatcher,
-    };
+    use crate::authorizer::{AllowPathPattern, DenyPathPattern, PathPattern, PathPatternMatcher};
 
     // [utest->swdd~agent-authorizing-matching-allow-rules~1]
     #[test]
@@ -221,7 +224,7 @@ mod tests {
     }
 
     // [utest->swdd~agent-authorizing-matching-allow-rules~1]
-    // [utest->swdd~agent-authorizing-matching-rules-elements~1]
+    // [utest->swdd~agent-authorizing-matching-rules-elements~2]
     #[test]
     fn utest_allow_path_pattern_with_wildcard() {
         let p = AllowPathPattern::from("some.*.fix");
@@ -241,6 +244,49 @@ mod tests {
         assert!(!p.matches(&"some.pre2.test.2".into()).0);
     }
 
+    // [utest->swdd~agent-authorizing-matching-allow-rules~1]
+    // [utest->swdd~agent-authorizing-matching-rules-elements~2]
+    #[test]
+    fn utest_allow_path_pattern_with_prefix_wildcard() {
+        let p = AllowPathPattern::from("some.pre*.fix");
+
+        assert_eq!(p.matches(&"some.pre.fix".into()).1, "some.pre*.fix");
+        assert!(p.matches(&"some.pre.fix".into()).0);
+        assert!(p.matches(&"some.prefix.fix".into()).0);
+        assert!(p.matches(&"some.pre2.fix.test".into()).0);
+        assert!(!p.matches(&"some.pr.fix".into()).0);
+        assert!(!p.matches(&"some.xpre.fix".into()).0);
+        assert!(!p.matches(&"some.pre2".into()).0);
+    }
+
+    // Segments without a trailing wildcard symbol keep their literal meaning.
+    // [utest->swdd~agent-authorizing-matching-rules-elements~2]
+    #[test]
+    fn utest_path_patterns_with_literal_segments_match_only_same_segments() {
+        let allow = AllowPathPattern::from("desiredState.workloads.teamA");
+        assert!(allow.matches(&"desiredState.workloads.teamA".into()).0);
+        assert!(allow.matches(&"desiredState.workloads.teamA.tags".into()).0);
+        assert!(
+            !allow
+                .matches(&"desiredState.workloads.teamA_nginx".into())
+                .0
+        );
+
+        let deny = DenyPathPattern::from("desiredState.workloads.teamA");
+        assert!(deny.matches(&"desiredState.workloads.teamA".into()).0);
+        assert!(deny.matches(&"desiredState.workloads".into()).0);
+        assert!(!deny.matches(&"desiredState.workloads.teamA_nginx".into()).0);
+
+        let allow = AllowPathPattern::from("desiredState.workloads.team*A");
+        assert!(allow.matches(&"desiredState.workloads.team*A".into()).0);
+        assert!(!allow.matches(&"desiredState.workloads.teamA".into()).0);
+        assert!(!allow.matches(&"desiredState.workloads.team_A".into()).0);
+
+        let deny = DenyPathPattern::from("desiredState.workloads.team*A");
+        assert!(deny.matches(&"desiredState.workloads.team*A".into()).0);
+        assert!(!deny.matches(&"desiredState.workloads.team_A".into()).0);
+    }
+
     // [utest->swdd~agent-authorizing-matching-allow-rules~1]
     #[test]
     fn utest_allow_path_pattern_with_wildcard_only() {
@@ -284,7 +330,7 @@ mod tests {
     }
 
     // [utest->swdd~agent-authorizing-matching-deny-rules~1]
-    // [utest->swdd~agent-authorizing-matching-rules-elements~1]
+    // [utest->swdd~agent-authorizing-matching-rules-elements~2]
     #[test]
     fn utest_deny_path_pattern_with_wildcard() {
         let p = DenyPathPattern::from("some.*.fix");
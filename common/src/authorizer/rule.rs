@@ -54,7 +54,7 @@ impl<P: PathPattern> PathPatternMatcher for Rule<P> {
 #[cfg(test)]
 mod test {
     use super::{super::path::Path, Rule};
-    use crate::control_interface::authorizer::path_pattern::{
+    use crate::authorizer::path_pattern::{
         PathPattern, PathPatternMatchReason, PathPatternMatcher, PathPatternSection,
     };
 
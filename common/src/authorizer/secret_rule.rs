@@ -0,0 +1,86 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+pub const ALL_SECRETS: &str = "*";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretNameRule {
+    secret_names: Vec<String>,
+}
+
+impl SecretNameRule {
+    pub fn create(secret_names: Vec<String>) -> Self {
+        Self { secret_names }
+    }
+
+    // [impl->swdd~agent-authorizing-secret-rules~1]
+    pub fn covers(&self, secret_name: &str) -> bool {
+        self.secret_names
+            .iter()
+            .any(|name| name == ALL_SECRETS || name == secret_name)
+    }
+
+    // [impl->swdd~agent-authorizing-secret-rules~1]
+    pub fn intersects(&self, secret_name: &str) -> bool {
+        if secret_name == ALL_SECRETS {
+            !self.secret_names.is_empty()
+        } else {
+            self.covers(secret_name)
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{SecretNameRule, ALL_SECRETS};
+
+    // [utest->swdd~agent-authorizing-secret-rules~1]
+    #[test]
+    fn utest_secret_name_rule_covers_named_secrets() {
+        let rule = SecretNameRule::create(vec!["db_password".into(), "api_token".into()]);
+
+        assert!(rule.covers("db_password"));
+        assert!(rule.covers("api_token"));
+        assert!(!rule.covers("other"));
+        assert!(!rule.covers(ALL_SECRETS));
+    }
+
+    // [utest->swdd~agent-authorizing-secret-rules~1]
+    #[test]
+    fn utest_secret_name_rule_wildcard_covers_all_secrets() {
+        let rule = SecretNameRule::create(vec![ALL_SECRETS.into()]);
+
+        assert!(rule.covers("db_password"));
+        assert!(rule.covers(ALL_SECRETS));
+    }
+
+    // [utest->swdd~agent-authorizing-secret-rules~1]
+    #[test]
+    fn utest_secret_name_rule_intersects_all_secrets_if_not_empty() {
+        let rule = SecretNameRule::create(vec!["db_password".into()]);
+
+        assert!(rule.intersects("db_password"));
+        assert!(!rule.intersects("other"));
+        assert!(rule.intersects(ALL_SECRETS));
+        assert!(!SecretNameRule::create(vec![]).intersects(ALL_SECRETS));
+    }
+}
@@ -12,13 +12,17 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::objects::{CompleteState, CpuUsage, DeletedWorkload, FreeMemory, WorkloadSpec};
+use crate::objects::{
+    CompleteState, CpuUsage, DeletedWorkload, FreeMemory, WorkloadInstanceName, WorkloadSpec,
+};
 use api::ank_base;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct AgentHello {
     pub agent_name: String,
+    pub labels: HashMap<String, String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -76,6 +80,14 @@ impl TryFrom<ank_base::Request> for Request {
 pub enum RequestContent {
     CompleteStateRequest(CompleteStateRequest),
     UpdateStateRequest(Box<UpdateStateRequest>),
+    RevisionsRequest(RevisionsRequest),
+    RollbackRequest(RollbackRequest),
+    EventsRequest(EventsRequest),
+    EventsCancelRequest(EventsCancelRequest),
+    LogsRequest(LogsRequest),
+    LogsCancelRequest(LogsCancelRequest),
+    AuditLogRequest(AuditLogRequest),
+    WorkloadDescriptionRequest(WorkloadDescriptionRequest),
 }
 
 impl From<RequestContent> for ank_base::request::RequestContent {
@@ -87,6 +99,30 @@ impl From<RequestContent> for ank_base::request::RequestContent {
             RequestContent::UpdateStateRequest(content) => {
                 ank_base::request::RequestContent::UpdateStateRequest(Box::new((*content).into()))
             }
+            RequestContent::RevisionsRequest(content) => {
+                ank_base::request::RequestContent::RevisionsRequest(content.into())
+            }
+            RequestContent::RollbackRequest(content) => {
+                ank_base::request::RequestContent::RollbackRequest(content.into())
+            }
+            RequestContent::EventsRequest(content) => {
+                ank_base::request::RequestContent::EventsRequest(content.into())
+            }
+            RequestContent::EventsCancelRequest(content) => {
+                ank_base::request::RequestContent::EventsCancelRequest(content.into())
+            }
+            RequestContent::LogsRequest(content) => {
+                ank_base::request::RequestContent::LogsRequest(content.into())
+            }
+            RequestContent::LogsCancelRequest(content) => {
+                ank_base::request::RequestContent::LogsCancelRequest(content.into())
+            }
+            RequestContent::AuditLogRequest(content) => {
+                ank_base::request::RequestContent::AuditLogRequest(content.into())
+            }
+            RequestContent::WorkloadDescriptionRequest(content) => {
+                ank_base::request::RequestContent::WorkloadDescriptionRequest(content.into())
+            }
         }
     }
 }
@@ -101,6 +137,30 @@ impl TryFrom<ank_base::request::RequestContent> for RequestContent {
             ank_base::request::RequestContent::CompleteStateRequest(value) => {
                 RequestContent::CompleteStateRequest(value.into())
             }
+            ank_base::request::RequestContent::RevisionsRequest(value) => {
+                RequestContent::RevisionsRequest(value.into())
+            }
+            ank_base::request::RequestContent::RollbackRequest(value) => {
+                RequestContent::RollbackRequest(value.into())
+            }
+            ank_base::request::RequestContent::EventsRequest(value) => {
+                RequestContent::EventsRequest(value.into())
+            }
+            ank_base::request::RequestContent::EventsCancelRequest(value) => {
+                RequestContent::EventsCancelRequest(value.into())
+            }
+            ank_base::request::RequestContent::LogsRequest(value) => {
+                RequestContent::LogsRequest(value.into())
+            }
+            ank_base::request::RequestContent::LogsCancelRequest(value) => {
+                RequestContent::LogsCancelRequest(value.into())
+            }
+            ank_base::request::RequestContent::AuditLogRequest(value) => {
+                RequestContent::AuditLogRequest(value.into())
+            }
+            ank_base::request::RequestContent::WorkloadDescriptionRequest(value) => {
+                RequestContent::WorkloadDescriptionRequest(value.into())
+            }
         })
     }
 }
@@ -108,12 +168,14 @@ impl TryFrom<ank_base::request::RequestContent> for RequestContent {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompleteStateRequest {
     pub field_mask: Vec<String>,
+    pub label_selector: Option<String>,
 }
 
 impl From<CompleteStateRequest> for ank_base::CompleteStateRequest {
     fn from(item: CompleteStateRequest) -> Self {
         ank_base::CompleteStateRequest {
             field_mask: item.field_mask,
+            label_selector: item.label_selector,
         }
     }
 }
@@ -122,6 +184,7 @@ impl From<ank_base::CompleteStateRequest> for CompleteStateRequest {
     fn from(item: ank_base::CompleteStateRequest) -> Self {
         CompleteStateRequest {
             field_mask: item.field_mask,
+            label_selector: item.label_selector,
         }
     }
 }
@@ -130,6 +193,7 @@ impl From<ank_base::CompleteStateRequest> for CompleteStateRequest {
 pub struct UpdateStateRequest {
     pub state: CompleteState,
     pub update_mask: Vec<String>,
+    pub dry_run: bool,
 }
 
 impl From<UpdateStateRequest> for ank_base::UpdateStateRequest {
@@ -137,6 +201,7 @@ impl From<UpdateStateRequest> for ank_base::UpdateStateRequest {
         Self {
             new_state: Some(value.state.into()),
             update_mask: value.update_mask,
+            dry_run: value.dry_run,
         }
     }
 }
@@ -148,10 +213,186 @@ impl TryFrom<ank_base::UpdateStateRequest> for UpdateStateRequest {
         Ok(UpdateStateRequest {
             state: item.new_state.unwrap_or_default().try_into()?,
             update_mask: item.update_mask,
+            dry_run: item.dry_run,
         })
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevisionsRequest {}
+
+impl From<RevisionsRequest> for ank_base::RevisionsRequest {
+    fn from(_item: RevisionsRequest) -> Self {
+        ank_base::RevisionsRequest {}
+    }
+}
+
+impl From<ank_base::RevisionsRequest> for RevisionsRequest {
+    fn from(_item: ank_base::RevisionsRequest) -> Self {
+        RevisionsRequest {}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollbackRequest {
+    pub revision: u64,
+}
+
+impl From<RollbackRequest> for ank_base::RollbackRequest {
+    fn from(item: RollbackRequest) -> Self {
+        ank_base::RollbackRequest {
+            revision: item.revision,
+        }
+    }
+}
+
+impl From<ank_base::RollbackRequest> for RollbackRequest {
+    fn from(item: ank_base::RollbackRequest) -> Self {
+        RollbackRequest {
+            revision: item.revision,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditLogRequest {
+    pub tail: Option<u32>,
+}
+
+impl From<AuditLogRequest> for ank_base::AuditLogRequest {
+    fn from(item: AuditLogRequest) -> Self {
+        ank_base::AuditLogRequest { tail: item.tail }
+    }
+}
+
+impl From<ank_base::AuditLogRequest> for AuditLogRequest {
+    fn from(item: ank_base::AuditLogRequest) -> Self {
+        AuditLogRequest { tail: item.tail }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadDescriptionRequest {
+    pub workload_name: String,
+    pub config_names: Vec<String>,
+}
+
+impl From<WorkloadDescriptionRequest> for ank_base::WorkloadDescriptionRequest {
+    fn from(item: WorkloadDescriptionRequest) -> Self {
+        ank_base::WorkloadDescriptionRequest {
+            workload_name: item.workload_name,
+            config_names: item.config_names,
+        }
+    }
+}
+
+impl From<ank_base::WorkloadDescriptionRequest> for WorkloadDescriptionRequest {
+    fn from(item: ank_base::WorkloadDescriptionRequest) -> Self {
+        WorkloadDescriptionRequest {
+            workload_name: item.workload_name,
+            config_names: item.config_names,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventsRequest {
+    pub field_mask: Vec<String>,
+}
+
+impl From<EventsRequest> for ank_base::EventsRequest {
+    fn from(item: EventsRequest) -> Self {
+        ank_base::EventsRequest {
+            field_mask: item.field_mask,
+        }
+    }
+}
+
+impl From<ank_base::EventsRequest> for EventsRequest {
+    fn from(item: ank_base::EventsRequest) -> Self {
+        EventsRequest {
+            field_mask: item.field_mask,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventsCancelRequest {}
+
+impl From<EventsCancelRequest> for ank_base::EventsCancelRequest {
+    fn from(_item: EventsCancelRequest) -> Self {
+        ank_base::EventsCancelRequest {}
+    }
+}
+
+impl From<ank_base::EventsCancelRequest> for EventsCancelRequest {
+    fn from(_item: ank_base::EventsCancelRequest) -> Self {
+        EventsCancelRequest {}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogsRequest {
+    pub workload_name: String,
+    pub follow: bool,
+    pub since: Option<String>,
+    pub tail: Option<i32>,
+}
+
+impl From<LogsRequest> for ank_base::LogsRequest {
+    fn from(item: LogsRequest) -> Self {
+        ank_base::LogsRequest {
+            workload_name: item.workload_name,
+            follow: item.follow,
+            since: item.since,
+            tail: item.tail,
+        }
+    }
+}
+
+impl From<ank_base::LogsRequest> for LogsRequest {
+    fn from(item: ank_base::LogsRequest) -> Self {
+        LogsRequest {
+            workload_name: item.workload_name,
+            follow: item.follow,
+            since: item.since,
+            tail: item.tail,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogsCancelRequest {}
+
+impl From<LogsCancelRequest> for ank_base::LogsCancelRequest {
+    fn from(_item: LogsCancelRequest) -> Self {
+        ank_base::LogsCancelRequest {}
+    }
+}
+
+impl From<ank_base::LogsCancelRequest> for LogsCancelRequest {
+    fn from(_item: ank_base::LogsCancelRequest) -> Self {
+        LogsCancelRequest {}
+    }
+}
+
+// The request sent by the server to the agent managing the workload to collect its logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadLogsRequest {
+    pub request_id: String,
+    pub instance_name: WorkloadInstanceName,
+    pub runtime: String,
+    pub follow: bool,
+    pub since: Option<String>,
+    pub tail: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadLogsCancel {
+    pub request_id: String,
+    pub agent_name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct ServerHello {
     pub agent_name: Option<String>,
@@ -165,11 +406,26 @@ pub struct UpdateWorkload {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
-pub struct Goodbye {}
+pub struct Goodbye {
+    pub connection_name: String,
+}
+
+// A response to a request forwarded to a connection, e.g. log entries sent by an agent.
+// The connection name is set by the communication middleware of the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionResponse {
+    pub connection_name: String,
+    pub response: ank_base::Response,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Stop {}
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisconnectAgent {
+    pub agent_name: String,
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -185,19 +441,24 @@ mod tests {
 
     mod ank_base {
         pub use api::ank_base::{
-            request::RequestContent, CompleteState, CompleteStateRequest, ConfigMappings,
-            Dependencies, Request, RestartPolicy, State, Tag, Tags, UpdateStateRequest, Workload,
-            WorkloadMap,
+            request::RequestContent, AuditLogRequest, CompleteState, CompleteStateRequest,
+            ConfigMappings, Dependencies, EventsRequest, LogsRequest, Request, RestartPolicy,
+            RevisionsRequest, RollbackRequest, SchedulingConstraints, State, Tag, Tags,
+            UpdateStateRequest, Workload, WorkloadDescriptionRequest, WorkloadMap,
         };
     }
 
     mod ankaios {
         pub use crate::{
-            commands::{CompleteStateRequest, Request, RequestContent, UpdateStateRequest},
+            commands::{
+                AuditLogRequest, CompleteStateRequest, EventsCancelRequest, EventsRequest,
+                LogsCancelRequest, LogsRequest, Request, RequestContent, RevisionsRequest,
+                RollbackRequest, UpdateStateRequest, WorkloadDescriptionRequest,
+            },
             objects::{
                 generate_test_agent_map, generate_test_workload_states_map_with_data, Base64Data,
-                CompleteState, Data, ExecutionState, File, FileContent, RestartPolicy, State,
-                StoredWorkloadSpec, Tag,
+                CompleteState, Data, ExecutionState, File, FileContent, RestartPolicy,
+                SchedulingConstraints, State, StoredWorkloadSpec, Tag,
             },
         };
     }
@@ -210,6 +471,7 @@ mod tests {
     const RUNTIME: &str = "my_favorite_runtime";
     const RUNTIME_CONFIG: &str = "generalOptions: [\"--version\"]\ncommandOptions: [\"--network=host\"]\nimage: alpine:latest\ncommandArgs: [\"bash\"]\n";
     const HASH: &str = "hash_1";
+    const REVISION: u64 = 42;
 
     macro_rules! complete_state_request {
         ($expression:ident) => {{
@@ -218,6 +480,7 @@ mod tests {
                 request_content: $expression::RequestContent::CompleteStateRequest(
                     $expression::CompleteStateRequest {
                         field_mask: vec![FIELD_1.into(), FIELD_2.into()],
+                        label_selector: None,
                     },
                 )
                 .into(),
@@ -239,12 +502,14 @@ mod tests {
             ank_base::RequestContent::UpdateStateRequest(Box::new(ank_base::UpdateStateRequest {
                 new_state: complete_state!(ank_base).into(),
                 update_mask: vec![FIELD_1.into(), FIELD_2.into()],
+                dry_run: false,
             }))
         };
         (ankaios) => {
             ankaios::RequestContent::UpdateStateRequest(Box::new(ankaios::UpdateStateRequest {
                 state: complete_state!(ankaios),
                 update_mask: vec![FIELD_1.into(), FIELD_2.into()],
+                dry_run: false,
             }))
         };
     }
@@ -256,10 +521,12 @@ mod tests {
                     api_version: "v0.1".into(),
                     workloads: HashMap::from([("desired".into(), workload!(ankaios))]),
                     configs: HashMap::new(),
+                    secrets: Default::default(),
                 }
                 .into(),
                 workload_states: workload_states_map!(ankaios),
                 agents: agent_map!(ankaios),
+                revision: None,
             }
         };
         (ank_base) => {
@@ -270,9 +537,11 @@ mod tests {
                         workloads: HashMap::from([("desired".to_string(), workload!(ank_base))]),
                     }),
                     configs: Some(Default::default()),
+                    secrets: Some(Default::default()),
                 }),
                 workload_states: workload_states_map!(ank_base),
                 agents: agent_map!(ank_base),
+                revision: None,
             }
         };
     }
@@ -300,6 +569,14 @@ mod tests {
                     .into(),
                 }),
                 files: Some(generate_test_proto_workload_files()),
+                scheduling_constraints: Some(ank_base::SchedulingConstraints {
+                    required_agent_labels: [("zone".into(), "front".into())].into(),
+                    min_free_memory: Some(1024),
+                    max_cpu_usage: None,
+                }),
+                secrets: Some(Default::default()),
+                suspended: None,
+                restart_generation: None,
             }
         };
         (ankaios) => {
@@ -333,6 +610,14 @@ mod tests {
                         }),
                     },
                 ],
+                scheduling_constraints: Some(ankaios::SchedulingConstraints {
+                    required_agent_labels: [("zone".into(), "front".into())].into(),
+                    min_free_memory: Some(1024),
+                    max_cpu_usage: None,
+                }),
+                secrets: Default::default(),
+                suspended: false,
+                restart_generation: 0,
             }
         };
     }
@@ -408,6 +693,7 @@ mod tests {
                     workloads: HashMap::new(),
                 }),
                 configs: Some(Default::default()),
+                secrets: Some(Default::default()),
             }),
             ..Default::default()
         });
@@ -450,6 +736,7 @@ mod tests {
                 workloads: HashMap::new(),
             }),
             configs: Some(Default::default()),
+            secrets: Some(Default::default()),
         });
 
         let ankaios::RequestContent::UpdateStateRequest(ankaios_request_content) =
@@ -501,6 +788,186 @@ mod tests {
         assert!(ankaios::Request::try_from(proto_request_complete_state).is_err());
     }
 
+    #[test]
+    fn utest_converts_audit_log_request() {
+        let proto_request = ank_base::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: Some(ank_base::RequestContent::AuditLogRequest(
+                ank_base::AuditLogRequest { tail: Some(5) },
+            )),
+        };
+        let ankaios_request = ankaios::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: ankaios::RequestContent::AuditLogRequest(ankaios::AuditLogRequest {
+                tail: Some(5),
+            }),
+        };
+
+        assert_eq!(
+            ankaios::Request::try_from(proto_request.clone()).unwrap(),
+            ankaios_request
+        );
+        assert_eq!(ank_base::Request::from(ankaios_request), proto_request);
+    }
+
+    #[test]
+    fn utest_converts_workload_description_request() {
+        let proto_request = ank_base::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: Some(ank_base::RequestContent::WorkloadDescriptionRequest(
+                ank_base::WorkloadDescriptionRequest {
+                    workload_name: WORKLOAD_NAME_1.into(),
+                    config_names: vec!["config_1".into()],
+                },
+            )),
+        };
+        let ankaios_request = ankaios::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: ankaios::RequestContent::WorkloadDescriptionRequest(
+                ankaios::WorkloadDescriptionRequest {
+                    workload_name: WORKLOAD_NAME_1.into(),
+                    config_names: vec!["config_1".into()],
+                },
+            ),
+        };
+
+        assert_eq!(
+            ankaios::Request::try_from(proto_request.clone()).unwrap(),
+            ankaios_request
+        );
+        assert_eq!(ank_base::Request::from(ankaios_request), proto_request);
+    }
+
+    #[test]
+    fn utest_converts_from_proto_revisions_request() {
+        let proto_request = ank_base::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: Some(ank_base::RequestContent::RevisionsRequest(
+                ank_base::RevisionsRequest {},
+            )),
+        };
+
+        assert_eq!(
+            ankaios::Request::try_from(proto_request).unwrap(),
+            ankaios::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: ankaios::RequestContent::RevisionsRequest(
+                    ankaios::RevisionsRequest {}
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn utest_converts_to_proto_rollback_request() {
+        let ankaios_request = ankaios::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: ankaios::RequestContent::RollbackRequest(ankaios::RollbackRequest {
+                revision: REVISION,
+            }),
+        };
+
+        assert_eq!(
+            ank_base::Request::from(ankaios_request.clone()),
+            ank_base::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: Some(ank_base::RequestContent::RollbackRequest(
+                    ank_base::RollbackRequest { revision: REVISION }
+                )),
+            }
+        );
+        assert_eq!(
+            ankaios::Request::try_from(ank_base::Request::from(ankaios_request.clone())).unwrap(),
+            ankaios_request
+        );
+    }
+
+    #[test]
+    fn utest_converts_events_requests() {
+        let ankaios_events_request = ankaios::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: ankaios::RequestContent::EventsRequest(ankaios::EventsRequest {
+                field_mask: vec![FIELD_1.into()],
+            }),
+        };
+        let ankaios_events_cancel_request = ankaios::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: ankaios::RequestContent::EventsCancelRequest(
+                ankaios::EventsCancelRequest {},
+            ),
+        };
+
+        assert_eq!(
+            ank_base::Request::from(ankaios_events_request.clone()),
+            ank_base::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: Some(ank_base::RequestContent::EventsRequest(
+                    ank_base::EventsRequest {
+                        field_mask: vec![FIELD_1.into()]
+                    }
+                )),
+            }
+        );
+        assert_eq!(
+            ankaios::Request::try_from(ank_base::Request::from(ankaios_events_request.clone()))
+                .unwrap(),
+            ankaios_events_request
+        );
+        assert_eq!(
+            ankaios::Request::try_from(ank_base::Request::from(
+                ankaios_events_cancel_request.clone()
+            ))
+            .unwrap(),
+            ankaios_events_cancel_request
+        );
+    }
+
+    #[test]
+    fn utest_converts_logs_requests() {
+        let ankaios_logs_request = ankaios::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: ankaios::RequestContent::LogsRequest(ankaios::LogsRequest {
+                workload_name: WORKLOAD_NAME_1.into(),
+                follow: true,
+                since: Some("10m".into()),
+                tail: Some(5),
+            }),
+        };
+        let ankaios_logs_cancel_request = ankaios::Request {
+            request_id: REQUEST_ID.into(),
+            request_content: ankaios::RequestContent::LogsCancelRequest(
+                ankaios::LogsCancelRequest {},
+            ),
+        };
+
+        assert_eq!(
+            ank_base::Request::from(ankaios_logs_request.clone()),
+            ank_base::Request {
+                request_id: REQUEST_ID.into(),
+                request_content: Some(ank_base::RequestContent::LogsRequest(
+                    ank_base::LogsRequest {
+                        workload_name: WORKLOAD_NAME_1.into(),
+                        follow: true,
+                        since: Some("10m".into()),
+                        tail: Some(5),
+                    }
+                )),
+            }
+        );
+        assert_eq!(
+            ankaios::Request::try_from(ank_base::Request::from(ankaios_logs_request.clone()))
+                .unwrap(),
+            ankaios_logs_request
+        );
+        assert_eq!(
+            ankaios::Request::try_from(ank_base::Request::from(
+                ankaios_logs_cancel_request.clone()
+            ))
+            .unwrap(),
+            ankaios_logs_cancel_request
+        );
+    }
+
     #[test]
     fn utest_converts_from_proto_request_fails_empty_request_content() {
         let proto_request = ank_base::Request {
@@ -521,6 +988,7 @@ mod tests {
             request_content: ankaios::RequestContent::CompleteStateRequest(
                 ankaios::CompleteStateRequest {
                     field_mask: vec!["1".to_string(), "2".to_string()],
+                    label_selector: None,
                 },
             ),
         };
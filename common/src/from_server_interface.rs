@@ -13,7 +13,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::commands;
-use crate::objects::{DeletedWorkload, WorkloadSpec, WorkloadState};
+use crate::objects::{DeletedWorkload, WorkloadInstanceName, WorkloadSpec, WorkloadState};
 use api::ank_base;
 use async_trait::async_trait;
 use std::fmt;
@@ -33,13 +33,17 @@ impl From<SendError<FromServer>> for FromServerInterfaceError {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum FromServer {
     ServerHello(commands::ServerHello),
     UpdateWorkload(commands::UpdateWorkload),
     UpdateWorkloadState(commands::UpdateWorkloadState),
     Response(ank_base::Response),
+    WorkloadLogsRequest(commands::WorkloadLogsRequest),
+    WorkloadLogsCancel(commands::WorkloadLogsCancel),
     Stop(commands::Stop),
+    DisconnectAgent(commands::DisconnectAgent),
 }
 
 // [impl->swdd~from-server-channel~1]
@@ -71,12 +75,67 @@ pub trait FromServerInterface {
         added_workloads: Vec<String>,
         deleted_workloads: Vec<String>,
     ) -> Result<(), FromServerInterfaceError>;
+    async fn dry_run_update_state_success(
+        &self,
+        request_id: String,
+        added_workloads: Vec<String>,
+        deleted_workloads: Vec<String>,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn revisions(
+        &self,
+        request_id: String,
+        revisions: ank_base::Revisions,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn audit_log(
+        &self,
+        request_id: String,
+        audit_log: ank_base::AuditLog,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn workload_description(
+        &self,
+        request_id: String,
+        workload_description: ank_base::WorkloadDescription,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn event(
+        &self,
+        request_id: String,
+        event: ank_base::Event,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn events_cancel_accepted(
+        &self,
+        request_id: String,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn workload_logs_request(
+        &self,
+        workload_logs_request: commands::WorkloadLogsRequest,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn workload_logs_cancel(
+        &self,
+        request_id: String,
+        agent_name: String,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn logs_stop_response(
+        &self,
+        request_id: String,
+        instance_name: WorkloadInstanceName,
+    ) -> Result<(), FromServerInterfaceError>;
+    async fn logs_cancel_accepted(
+        &self,
+        request_id: String,
+    ) -> Result<(), FromServerInterfaceError>;
     async fn error(
         &self,
         request_id: String,
         message: String,
     ) -> Result<(), FromServerInterfaceError>;
+    async fn error_with_code(
+        &self,
+        request_id: String,
+        message: String,
+        code: ank_base::ErrorCode,
+    ) -> Result<(), FromServerInterfaceError>;
     async fn stop(&self) -> Result<(), FromServerInterfaceError>;
+    async fn disconnect_agent(&self, agent_name: String) -> Result<(), FromServerInterfaceError>;
 }
 
 pub type FromServerSender = tokio::sync::mpsc::Sender<FromServer>;
@@ -155,6 +214,140 @@ impl FromServerInterface for FromServerSender {
                     ank_base::UpdateStateSuccess {
                         added_workloads,
                         deleted_workloads,
+                        dry_run: false,
+                    },
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
+    async fn dry_run_update_state_success(
+        &self,
+        request_id: String,
+        added_workloads: Vec<String>,
+        deleted_workloads: Vec<String>,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::UpdateStateSuccess(
+                    ank_base::UpdateStateSuccess {
+                        added_workloads,
+                        deleted_workloads,
+                        dry_run: true,
+                    },
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
+    async fn revisions(
+        &self,
+        request_id: String,
+        revisions: ank_base::Revisions,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::Revisions(revisions).into(),
+            }))
+            .await?)
+    }
+
+    async fn audit_log(
+        &self,
+        request_id: String,
+        audit_log: ank_base::AuditLog,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::AuditLog(audit_log).into(),
+            }))
+            .await?)
+    }
+
+    async fn workload_description(
+        &self,
+        request_id: String,
+        workload_description: ank_base::WorkloadDescription,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::WorkloadDescription(
+                    workload_description,
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
+    async fn event(
+        &self,
+        request_id: String,
+        event: ank_base::Event,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::Event(event).into(),
+            }))
+            .await?)
+    }
+
+    async fn events_cancel_accepted(
+        &self,
+        request_id: String,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::EventsCancelAccepted(
+                    ank_base::EventsCancelAccepted {},
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
+    async fn workload_logs_request(
+        &self,
+        workload_logs_request: commands::WorkloadLogsRequest,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::WorkloadLogsRequest(workload_logs_request))
+            .await?)
+    }
+
+    async fn workload_logs_cancel(
+        &self,
+        request_id: String,
+        agent_name: String,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::WorkloadLogsCancel(
+                commands::WorkloadLogsCancel {
+                    request_id,
+                    agent_name,
+                },
+            ))
+            .await?)
+    }
+
+    async fn logs_stop_response(
+        &self,
+        request_id: String,
+        instance_name: WorkloadInstanceName,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::LogsStopResponse(
+                    ank_base::LogsStopResponse {
+                        workload_name: Some(instance_name.into()),
                     },
                 )
                 .into(),
@@ -162,16 +355,42 @@ impl FromServerInterface for FromServerSender {
             .await?)
     }
 
+    async fn logs_cancel_accepted(
+        &self,
+        request_id: String,
+    ) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: ank_base::response::ResponseContent::LogsCancelAccepted(
+                    ank_base::LogsCancelAccepted {},
+                )
+                .into(),
+            }))
+            .await?)
+    }
+
     async fn error(
         &self,
         request_id: String,
         message: String,
+    ) -> Result<(), FromServerInterfaceError> {
+        self.error_with_code(request_id, message, ank_base::ErrorCode::Unspecified)
+            .await
+    }
+
+    async fn error_with_code(
+        &self,
+        request_id: String,
+        message: String,
+        code: ank_base::ErrorCode,
     ) -> Result<(), FromServerInterfaceError> {
         Ok(self
             .send(FromServer::Response(ank_base::Response {
                 request_id,
                 response_content: ank_base::response::ResponseContent::Error(ank_base::Error {
                     message,
+                    code: code.into(),
                 })
                 .into(),
             }))
@@ -181,6 +400,14 @@ impl FromServerInterface for FromServerSender {
     async fn stop(&self) -> Result<(), FromServerInterfaceError> {
         Ok(self.send(FromServer::Stop(commands::Stop {})).await?)
     }
+
+    async fn disconnect_agent(&self, agent_name: String) -> Result<(), FromServerInterfaceError> {
+        Ok(self
+            .send(FromServer::DisconnectAgent(commands::DisconnectAgent {
+                agent_name,
+            }))
+            .await?)
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -302,12 +529,269 @@ mod tests {
                     ank_base::UpdateStateSuccess {
                         added_workloads,
                         deleted_workloads,
+                        dry_run: false,
                     },
                 )),
             })
         )
     }
 
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_dry_run_update_state_success() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let added_workloads = vec!["some_name".to_string()];
+        let deleted_workloads = vec!["some_name_1".to_string()];
+        assert!(tx
+            .dry_run_update_state_success(
+                REQUEST_ID.to_string(),
+                added_workloads.clone(),
+                deleted_workloads.clone()
+            )
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(
+                    ank_base::UpdateStateSuccess {
+                        added_workloads,
+                        deleted_workloads,
+                        dry_run: true,
+                    },
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_revisions() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let revisions = ank_base::Revisions {
+            revisions: vec![ank_base::Revision {
+                revision: 1,
+                timestamp: "2025-01-01T00:00:00Z".to_string(),
+                requester: "cli-conn-1".to_string(),
+                update_mask: vec!["desiredState.workloads".to_string()],
+            }],
+        };
+        assert!(tx
+            .revisions(REQUEST_ID.to_string(), revisions.clone())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::Revisions(revisions)),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_from_server_send_audit_log() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let audit_log = ank_base::AuditLog {
+            entries: vec![ank_base::AuditLogEntry {
+                timestamp: "2025-01-01T00:00:00Z".to_string(),
+                requester: "cli-conn-1".to_string(),
+                request: "UpdateStateRequest".to_string(),
+                update_mask: vec!["desiredState.workloads".to_string()],
+                accepted: true,
+                revision: Some(1),
+                ..Default::default()
+            }],
+        };
+        assert!(tx
+            .audit_log(REQUEST_ID.to_string(), audit_log.clone())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::AuditLog(audit_log)),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_from_server_send_workload_description() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let workload_description = ank_base::WorkloadDescription {
+            rendered_runtime_config: "image: alpine:latest".to_string(),
+            ..Default::default()
+        };
+        assert!(tx
+            .workload_description(REQUEST_ID.to_string(), workload_description.clone())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::WorkloadDescription(
+                    workload_description
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_event() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let event = ank_base::Event {
+            complete_state: Some(ank_base::CompleteState::default()),
+            added_fields: vec!["agents.agent_A".to_string()],
+            ..Default::default()
+        };
+        assert!(tx
+            .event(REQUEST_ID.to_string(), event.clone())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::Event(event)),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_events_cancel_accepted() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx
+            .events_cancel_accepted(REQUEST_ID.to_string())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::EventsCancelAccepted(
+                    ank_base::EventsCancelAccepted {}
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_workload_logs_request() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let workload_logs_request = commands::WorkloadLogsRequest {
+            request_id: REQUEST_ID.to_string(),
+            instance_name: generate_test_workload_spec().instance_name,
+            runtime: "runtime".to_string(),
+            follow: true,
+            since: None,
+            tail: Some(10),
+        };
+        assert!(tx
+            .workload_logs_request(workload_logs_request.clone())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::WorkloadLogsRequest(workload_logs_request)
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_workload_logs_cancel() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx
+            .workload_logs_cancel(REQUEST_ID.to_string(), AGENT_NAME.to_string())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::WorkloadLogsCancel(commands::WorkloadLogsCancel {
+                request_id: REQUEST_ID.to_string(),
+                agent_name: AGENT_NAME.to_string(),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_logs_stop_response() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let instance_name = generate_test_workload_spec().instance_name;
+        assert!(tx
+            .logs_stop_response(REQUEST_ID.to_string(), instance_name.clone())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::LogsStopResponse(
+                    ank_base::LogsStopResponse {
+                        workload_name: Some(instance_name.into()),
+                    }
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_logs_cancel_accepted() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx
+            .logs_cancel_accepted(REQUEST_ID.to_string())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::LogsCancelAccepted(
+                    ank_base::LogsCancelAccepted {}
+                )),
+            })
+        )
+    }
+
     // [utest->swdd~from-server-channel~1]
     #[tokio::test]
     async fn utest_to_server_send_error() {
@@ -316,6 +800,7 @@ mod tests {
 
         let error = ank_base::Error {
             message: "error".to_string(),
+            ..Default::default()
         };
         assert!(tx
             .error(REQUEST_ID.to_string(), error.message.clone())
@@ -330,4 +815,48 @@ mod tests {
             })
         )
     }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_error_with_code() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let error = ank_base::Error {
+            message: "stale revision".to_string(),
+            code: ank_base::ErrorCode::StaleRevision.into(),
+        };
+        assert!(tx
+            .error_with_code(
+                REQUEST_ID.to_string(),
+                error.message.clone(),
+                ank_base::ErrorCode::StaleRevision
+            )
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::Error(error)),
+            })
+        )
+    }
+
+    // [utest->swdd~from-server-channel~1]
+    #[tokio::test]
+    async fn utest_from_server_send_disconnect_agent() {
+        let (tx, mut rx): (FromServerSender, FromServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx.disconnect_agent(AGENT_NAME.to_string()).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            FromServer::DisconnectAgent(commands::DisconnectAgent {
+                agent_name: AGENT_NAME.to_string(),
+            })
+        )
+    }
 }
@@ -17,7 +17,10 @@ pub const DEFAULT_SOCKET_ADDRESS: &str = "127.0.0.1:25551";
 pub const DEFAULT_SERVER_ADDRESS: &str = "https://127.0.0.1:25551";
 pub const PATH_SEPARATOR: char = '.';
 pub const ANKAIOS_VERSION: &str = env!("CARGO_PKG_VERSION");
+// the agents send their AgentLoadStatus in this interval, which the server uses as heartbeat
+pub const AGENT_LOAD_STATUS_INTERVAL_MS: u64 = 2000;
 
+pub mod authorizer;
 pub mod commands;
 pub mod communications_client;
 pub mod communications_error;
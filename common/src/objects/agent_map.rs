@@ -42,6 +42,10 @@ pub struct FreeMemory {
 pub struct AgentAttributes {
     pub cpu_usage: Option<CpuUsage>,
     pub free_memory: Option<FreeMemory>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
@@ -65,12 +69,26 @@ impl AgentMap {
         self.0.remove(key);
     }
 
+    pub fn get(&self, key: &str) -> Option<&AgentAttributes> {
+        self.0.get(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&AgentName, &AgentAttributes)> {
+        self.0.iter()
+    }
+
     pub fn update_resource_availability(&mut self, agent_load_status: commands::AgentLoadStatus) {
         self.0.entry(agent_load_status.agent_name).and_modify(|e| {
             e.cpu_usage = Some(agent_load_status.cpu_usage);
             e.free_memory = Some(agent_load_status.free_memory);
         });
     }
+
+    pub fn update_last_seen(&mut self, agent_name: &str, timestamp: String) {
+        if let Some(agent_attributes) = self.0.get_mut(agent_name) {
+            agent_attributes.last_seen = Some(timestamp);
+        }
+    }
 }
 
 impl From<CpuUsage> for ank_base::CpuUsage {
@@ -114,6 +132,8 @@ impl From<AgentAttributes> for ank_base::AgentAttributes {
             free_memory: Some(ank_base::FreeMemory {
                 free_memory: item.free_memory.unwrap_or_default().free_memory,
             }),
+            labels: item.labels,
+            last_seen: item.last_seen,
         }
     }
 }
@@ -127,6 +147,8 @@ impl From<ank_base::AgentAttributes> for AgentAttributes {
             free_memory: Some(FreeMemory {
                 free_memory: item.free_memory.unwrap_or_default().free_memory,
             }),
+            labels: item.labels,
+            last_seen: item.last_seen,
         }
     }
 }
@@ -174,6 +196,8 @@ pub fn generate_test_agent_map(agent_name: impl Into<String>) -> AgentMap {
         .or_insert(AgentAttributes {
             cpu_usage: Some(CpuUsage { cpu_usage: 42 }),
             free_memory: Some(FreeMemory { free_memory: 42 }),
+            labels: HashMap::new(),
+            last_seen: None,
         });
     agent_map
 }
@@ -189,6 +213,8 @@ pub fn generate_test_agent_map_from_specs(workloads: &[crate::objects::WorkloadS
                 .or_insert(AgentAttributes {
                     cpu_usage: Some(CpuUsage { cpu_usage: 42 }),
                     free_memory: Some(FreeMemory { free_memory: 42 }),
+                    labels: HashMap::new(),
+                    last_seen: None,
                 });
             agent_map
         })
@@ -26,6 +26,8 @@ pub struct CompleteState {
     pub workload_states: WorkloadStatesMap,
     #[serde(default)]
     pub agents: AgentMap,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
 }
 
 impl From<CompleteState> for ank_base::CompleteState {
@@ -34,6 +36,7 @@ impl From<CompleteState> for ank_base::CompleteState {
             desired_state: Some(ank_base::State::from(item.desired_state)),
             workload_states: item.workload_states.into(),
             agents: item.agents.into(),
+            revision: item.revision,
         }
     }
 }
@@ -46,6 +49,7 @@ impl TryFrom<ank_base::CompleteState> for CompleteState {
             desired_state: item.desired_state.unwrap_or_default().try_into()?,
             workload_states: item.workload_states.unwrap_or_default().into(),
             agents: item.agents.unwrap_or_default().into(),
+            revision: item.revision,
         })
     }
 }
@@ -18,6 +18,8 @@ use crate::helpers::serialize_to_ordered_map;
 use api::ank_base::{self, config_item};
 use serde::{Deserialize, Serialize};
 
+pub const REDACTED_SECRET_VALUE: &str = "******";
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum ConfigItem {
@@ -26,6 +28,56 @@ pub enum ConfigItem {
     ConfigObject(#[serde(serialize_with = "serialize_to_ordered_map")] HashMap<String, ConfigItem>),
 }
 
+impl ConfigItem {
+    // [impl->swdd~common-redacts-secret-values~1]
+    pub fn redacted(&self) -> ConfigItem {
+        match self {
+            ConfigItem::String(_) => ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()),
+            ConfigItem::ConfigArray(array) => {
+                ConfigItem::ConfigArray(array.iter().map(ConfigItem::redacted).collect())
+            }
+            ConfigItem::ConfigObject(object) => ConfigItem::ConfigObject(
+                object
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.redacted()))
+                    .collect(),
+            ),
+        }
+    }
+
+    // [impl->swdd~common-restores-redacted-secret-values~1]
+    pub fn restore_redacted(&mut self, stored: Option<&ConfigItem>) -> Result<(), String> {
+        match self {
+            ConfigItem::String(value) if value == REDACTED_SECRET_VALUE => match stored {
+                Some(ConfigItem::String(stored_value)) => {
+                    *value = stored_value.clone();
+                    Ok(())
+                }
+                _ => Err("no stored value exists for the redacted value".to_owned()),
+            },
+            ConfigItem::String(_) => Ok(()),
+            ConfigItem::ConfigArray(array) => {
+                let stored_array = match stored {
+                    Some(ConfigItem::ConfigArray(stored_array)) => Some(stored_array),
+                    _ => None,
+                };
+                array.iter_mut().enumerate().try_for_each(|(index, item)| {
+                    item.restore_redacted(stored_array.and_then(|x| x.get(index)))
+                })
+            }
+            ConfigItem::ConfigObject(object) => {
+                let stored_object = match stored {
+                    Some(ConfigItem::ConfigObject(stored_object)) => Some(stored_object),
+                    _ => None,
+                };
+                object.iter_mut().try_for_each(|(key, item)| {
+                    item.restore_redacted(stored_object.and_then(|x| x.get(key)))
+                })
+            }
+        }
+    }
+}
+
 impl From<ConfigItem> for ank_base::ConfigItem {
     fn from(value: ConfigItem) -> Self {
         Self {
@@ -260,4 +312,77 @@ mod tests {
         let converted_config = ConfigItem::try_from(proto_config);
         assert_eq!(converted_config, Err("Value of ConfigItem is None".into()));
     }
+
+    // [utest->swdd~common-redacts-secret-values~1]
+    #[test]
+    fn utest_redacted_replaces_all_values() {
+        let config = sample_config!(internal);
+
+        assert_eq!(
+            config.redacted(),
+            internal::array([
+                internal::string(super::REDACTED_SECRET_VALUE),
+                internal::object([
+                    ("key_1", internal::string(super::REDACTED_SECRET_VALUE)),
+                    (
+                        "key_2",
+                        internal::object([
+                            ("key_2_1", internal::string(super::REDACTED_SECRET_VALUE)),
+                            ("key_2_2", internal::string(super::REDACTED_SECRET_VALUE)),
+                        ]),
+                    ),
+                    (
+                        "key_3",
+                        internal::array([
+                            internal::string(super::REDACTED_SECRET_VALUE),
+                            internal::string(super::REDACTED_SECRET_VALUE),
+                            internal::string(super::REDACTED_SECRET_VALUE),
+                        ]),
+                    ),
+                ]),
+            ])
+        );
+    }
+
+    // [utest->swdd~common-restores-redacted-secret-values~1]
+    #[test]
+    fn utest_restore_redacted_keeps_stored_values() {
+        let stored = sample_config!(internal);
+        let mut config = stored.redacted();
+
+        assert_eq!(config.restore_redacted(Some(&stored)), Ok(()));
+        assert_eq!(config, stored);
+    }
+
+    // [utest->swdd~common-restores-redacted-secret-values~1]
+    #[test]
+    fn utest_restore_redacted_keeps_new_values() {
+        let stored = internal::object([("user", internal::string("admin"))]);
+        let mut config = internal::object([
+            ("user", internal::string(super::REDACTED_SECRET_VALUE)),
+            ("password", internal::string("new_password")),
+        ]);
+
+        assert_eq!(config.restore_redacted(Some(&stored)), Ok(()));
+        assert_eq!(
+            config,
+            internal::object([
+                ("user", internal::string("admin")),
+                ("password", internal::string("new_password")),
+            ])
+        );
+    }
+
+    // [utest->swdd~common-restores-redacted-secret-values~1]
+    #[test]
+    fn utest_restore_redacted_fails_without_stored_value() {
+        let stored = internal::object([("user", internal::string("admin"))]);
+        let mut config =
+            internal::object([("password", internal::string(super::REDACTED_SECRET_VALUE))]);
+
+        assert!(config.restore_redacted(Some(&stored)).is_err());
+        assert!(internal::string(super::REDACTED_SECRET_VALUE)
+            .restore_redacted(None)
+            .is_err());
+    }
 }
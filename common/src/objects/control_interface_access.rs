@@ -69,6 +69,7 @@ fn convert_rule_vec(
 #[serde(tag = "type")]
 pub enum AccessRightsRule {
     StateRule(StateRule),
+    SecretRule(SecretRule),
 }
 
 impl AccessRightsRule {
@@ -86,6 +87,15 @@ impl AccessRightsRule {
                     Ok(())
                 })?;
             }
+            // [impl->swdd~common-access-rules-secret-names-convention~1]
+            AccessRightsRule::SecretRule(secret_rule) => {
+                if secret_rule.secret_names.iter().any(String::is_empty) {
+                    return Err(
+                        "Empty secret names are not allowed in Control Interface access rules"
+                            .to_string(),
+                    );
+                }
+            }
         }
         Ok(())
     }
@@ -102,6 +112,9 @@ impl TryFrom<api::ank_base::AccessRightsRule> for AccessRightsRule {
             api::ank_base::access_rights_rule::AccessRightsRuleEnum::StateRule(state_rule) => {
                 Ok(Self::StateRule(state_rule.try_into()?))
             }
+            api::ank_base::access_rights_rule::AccessRightsRuleEnum::SecretRule(secret_rule) => {
+                Ok(Self::SecretRule(secret_rule.try_into()?))
+            }
         }
     }
 }
@@ -115,6 +128,11 @@ impl From<AccessRightsRule> for api::ank_base::AccessRightsRule {
                         state.into(),
                     ),
                 ),
+                AccessRightsRule::SecretRule(secret) => Some(
+                    api::ank_base::access_rights_rule::AccessRightsRuleEnum::SecretRule(
+                        secret.into(),
+                    ),
+                ),
             },
         }
     }
@@ -146,6 +164,32 @@ impl From<StateRule> for api::ank_base::StateRule {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretRule {
+    pub operation: ReadWriteEnum,
+    pub secret_names: Vec<String>,
+}
+
+impl TryFrom<api::ank_base::SecretRule> for SecretRule {
+    type Error = String;
+    fn try_from(value: api::ank_base::SecretRule) -> Result<Self, Self::Error> {
+        Ok(Self {
+            operation: value.operation.try_into()?,
+            secret_names: value.secret_names,
+        })
+    }
+}
+
+impl From<SecretRule> for api::ank_base::SecretRule {
+    fn from(value: SecretRule) -> Self {
+        Self {
+            operation: value.operation.into(),
+            secret_names: value.secret_names,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum ReadWriteEnum {
     Nothing,
@@ -205,7 +249,8 @@ pub fn generate_test_control_interface_access() -> ControlInterfaceAccess {
 #[cfg(test)]
 mod tests {
     use crate::objects::{
-        generate_test_control_interface_access, AccessRightsRule, ReadWriteEnum, StateRule,
+        generate_test_control_interface_access, AccessRightsRule, ReadWriteEnum, SecretRule,
+        StateRule,
     };
 
     // [utest->swdd~common-access-rules-filter-mask-convention~1]
@@ -232,6 +277,49 @@ mod tests {
         assert!(state_rule.verify_format().is_ok());
     }
 
+    // [utest->swdd~common-access-rules-secret-names-convention~1]
+    #[test]
+    fn utest_access_rights_rule_verify_fails_on_empty_secret_name() {
+        let empty_secret_rule = AccessRightsRule::SecretRule(SecretRule {
+            operation: ReadWriteEnum::Read,
+            secret_names: vec!["db_password".to_string(), "".to_string()],
+        });
+
+        assert!(empty_secret_rule.verify_format().is_err_and(
+            |x| x == "Empty secret names are not allowed in Control Interface access rules"
+        ));
+
+        let secret_rule = AccessRightsRule::SecretRule(SecretRule {
+            operation: ReadWriteEnum::Read,
+            secret_names: vec!["*".to_string()],
+        });
+        assert!(secret_rule.verify_format().is_ok());
+    }
+
+    #[test]
+    fn utest_secret_rule_converts_to_and_from_proto() {
+        let secret_rule = AccessRightsRule::SecretRule(SecretRule {
+            operation: ReadWriteEnum::Write,
+            secret_names: vec!["db_password".to_string()],
+        });
+
+        let proto_rule = api::ank_base::AccessRightsRule::from(secret_rule.clone());
+        assert_eq!(
+            proto_rule,
+            api::ank_base::AccessRightsRule {
+                access_rights_rule_enum: Some(
+                    api::ank_base::access_rights_rule::AccessRightsRuleEnum::SecretRule(
+                        api::ank_base::SecretRule {
+                            operation: api::ank_base::ReadWriteEnum::RwWrite as i32,
+                            secret_names: vec!["db_password".to_string()],
+                        }
+                    )
+                ),
+            }
+        );
+        assert_eq!(AccessRightsRule::try_from(proto_rule), Ok(secret_rule));
+    }
+
     // [utest->swdd~common-access-rules-filter-mask-convention~1]
     #[test]
     fn utest_control_interface_access_verify_fails_on_empty_allow_rule_filter() {
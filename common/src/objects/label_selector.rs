@@ -0,0 +1,298 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt, str::FromStr};
+
+use super::Tag;
+
+const IN_OPERATOR: &str = "in";
+const NOT_IN_OPERATOR: &str = "notin";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Requirement {
+    Equals(String, String),
+    NotEquals(String, String),
+    In(String, Vec<String>),
+    NotIn(String, Vec<String>),
+    Exists(String),
+    DoesNotExist(String),
+}
+
+impl Requirement {
+    // A missing tag fulfills the negated requirements.
+    fn is_fulfilled_by(&self, tags: &[Tag]) -> bool {
+        let value_of = |key: &str| {
+            tags.iter()
+                .find(|tag| tag.key == key)
+                .map(|tag| tag.value.as_str())
+        };
+
+        match self {
+            Requirement::Equals(key, value) => value_of(key) == Some(value),
+            Requirement::NotEquals(key, value) => value_of(key) != Some(value),
+            Requirement::In(key, values) => {
+                value_of(key).is_some_and(|found| values.iter().any(|value| value == found))
+            }
+            Requirement::NotIn(key, values) => {
+                value_of(key).is_none_or(|found| values.iter().all(|value| value != found))
+            }
+            Requirement::Exists(key) => value_of(key).is_some(),
+            Requirement::DoesNotExist(key) => value_of(key).is_none(),
+        }
+    }
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Requirement::Equals(key, value) => write!(f, "{key}={value}"),
+            Requirement::NotEquals(key, value) => write!(f, "{key}!={value}"),
+            Requirement::In(key, values) => {
+                write!(f, "{key} {IN_OPERATOR} ({})", values.join(","))
+            }
+            Requirement::NotIn(key, values) => {
+                write!(f, "{key} {NOT_IN_OPERATOR} ({})", values.join(","))
+            }
+            Requirement::Exists(key) => write!(f, "{key}"),
+            Requirement::DoesNotExist(key) => write!(f, "!{key}"),
+        }
+    }
+}
+
+impl FromStr for Requirement {
+    type Err = String;
+
+    fn from_str(requirement: &str) -> Result<Self, Self::Err> {
+        if let Some(key) = requirement.strip_prefix('!') {
+            return Ok(Requirement::DoesNotExist(parse_key(key)?));
+        }
+        if let Some((key, value)) = requirement.split_once("!=") {
+            return Ok(Requirement::NotEquals(parse_key(key)?, parse_value(value)?));
+        }
+        if let Some((key, value)) = requirement
+            .split_once("==")
+            .or_else(|| requirement.split_once('='))
+        {
+            return Ok(Requirement::Equals(parse_key(key)?, parse_value(value)?));
+        }
+
+        let mut parts = requirement.trim().splitn(2, char::is_whitespace);
+        let key = parse_key(parts.next().unwrap_or_default())?;
+        let Some(set_expression) = parts.next().map(str::trim_start) else {
+            return Ok(Requirement::Exists(key));
+        };
+
+        if let Some(values) = set_expression.strip_prefix(NOT_IN_OPERATOR) {
+            Ok(Requirement::NotIn(key, parse_values(values)?))
+        } else if let Some(values) = set_expression.strip_prefix(IN_OPERATOR) {
+            Ok(Requirement::In(key, parse_values(values)?))
+        } else {
+            Err(format!(
+                "unknown operator in '{requirement}', expected one of '=', '==', '!=', '{IN_OPERATOR}', '{NOT_IN_OPERATOR}'"
+            ))
+        }
+    }
+}
+
+fn parse_key(key: &str) -> Result<String, String> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("empty tag key".to_string());
+    }
+    if !key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'))
+    {
+        return Err(format!(
+            "invalid tag key '{key}', expected only alphanumeric characters, '-', '_', '.' and '/'"
+        ));
+    }
+    Ok(key.to_string())
+}
+
+fn parse_value(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.contains(['(', ')', ',', '=', '!']) || value.contains(char::is_whitespace) {
+        return Err(format!("invalid tag value '{value}'"));
+    }
+    Ok(value.to_string())
+}
+
+fn parse_values(values: &str) -> Result<Vec<String>, String> {
+    let values = values.trim();
+    let Some(values) = values
+        .strip_prefix('(')
+        .and_then(|values| values.strip_suffix(')'))
+    else {
+        return Err(format!(
+            "expected a set of values in parentheses, got '{values}'"
+        ));
+    };
+    values.split(',').map(parse_value).collect()
+}
+
+// A selection of workloads by their tags, e.g. 'team=infotainment,env!=dev,tier in (a,b)'.
+// A workload is selected if it fulfills all requirements.
+// [impl->swdd~common-label-selector-selects-workloads-by-tags~1]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelSelector(Vec<Requirement>);
+
+impl LabelSelector {
+    // [impl->swdd~common-label-selector-selects-workloads-by-tags~1]
+    pub fn matches(&self, tags: &[Tag]) -> bool {
+        self.0
+            .iter()
+            .all(|requirement| requirement.is_fulfilled_by(tags))
+    }
+}
+
+impl fmt::Display for LabelSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let requirements: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", requirements.join(","))
+    }
+}
+
+impl FromStr for LabelSelector {
+    type Err = String;
+
+    // [impl->swdd~common-label-selector-selects-workloads-by-tags~1]
+    fn from_str(selector: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| format!("Invalid label selector '{selector}': {reason}");
+
+        // the values of a set are separated by commas as well
+        let mut requirements = Vec::new();
+        let mut nesting_level = 0;
+        let mut start = 0;
+        for (position, character) in selector.char_indices() {
+            match character {
+                '(' => nesting_level += 1,
+                ')' => nesting_level -= 1,
+                ',' if nesting_level == 0 => {
+                    requirements.push(&selector[start..position]);
+                    start = position + 1;
+                }
+                _ => {}
+            }
+        }
+        requirements.push(&selector[start..]);
+
+        if requirements
+            .iter()
+            .all(|requirement| requirement.trim().is_empty())
+        {
+            return Err(invalid("no requirements".to_string()));
+        }
+
+        requirements
+            .into_iter()
+            .map(|requirement| requirement.trim().parse().map_err(invalid))
+            .collect::<Result<_, _>>()
+            .map(LabelSelector)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::{LabelSelector, Tag};
+
+    fn tags(tags: &[(&str, &str)]) -> Vec<Tag> {
+        tags.iter()
+            .map(|(key, value)| Tag {
+                key: key.to_string(),
+                value: value.to_string(),
+            })
+            .collect()
+    }
+
+    fn matches(selector: &str, workload_tags: &[(&str, &str)]) -> bool {
+        selector
+            .parse::<LabelSelector>()
+            .unwrap()
+            .matches(&tags(workload_tags))
+    }
+
+    // [utest->swdd~common-label-selector-selects-workloads-by-tags~1]
+    #[test]
+    fn utest_label_selector_equality_requirements() {
+        let workload_tags = [("team", "infotainment"), ("env", "prod")];
+
+        assert!(matches("team=infotainment", &workload_tags));
+        assert!(matches("team==infotainment,env=prod", &workload_tags));
+        assert!(!matches("team=infotainment,env=dev", &workload_tags));
+        assert!(matches("env!=dev", &workload_tags));
+        assert!(!matches("env!=prod", &workload_tags));
+        assert!(matches("tier!=backend", &workload_tags));
+    }
+
+    // [utest->swdd~common-label-selector-selects-workloads-by-tags~1]
+    #[test]
+    fn utest_label_selector_set_and_existence_requirements() {
+        let workload_tags = [("team", "infotainment"), ("tier", "a")];
+
+        assert!(matches("tier in (a, b)", &workload_tags));
+        assert!(!matches("tier in (b,c)", &workload_tags));
+        assert!(!matches("env in (prod)", &workload_tags));
+        assert!(matches("tier notin (b,c)", &workload_tags));
+        assert!(matches("env notin (prod)", &workload_tags));
+        assert!(!matches("tier notin (a)", &workload_tags));
+        assert!(matches("team", &workload_tags));
+        assert!(!matches("env", &workload_tags));
+        assert!(matches("!env", &workload_tags));
+        assert!(matches("team,tier in (a,b),!env", &workload_tags));
+    }
+
+    // [utest->swdd~common-label-selector-selects-workloads-by-tags~1]
+    #[test]
+    fn utest_label_selector_rejects_invalid_selectors() {
+        for selector in [
+            "",
+            " , ",
+            "=value",
+            "team=a b",
+            "tier in a,b",
+            "tier between (a,b)",
+            "team x=y",
+            "!",
+        ] {
+            assert!(
+                selector.parse::<LabelSelector>().is_err(),
+                "'{selector}' shall be rejected"
+            );
+        }
+    }
+
+    // [utest->swdd~common-label-selector-selects-workloads-by-tags~1]
+    #[test]
+    fn utest_label_selector_to_string_parses_to_same_selector() {
+        let selector: LabelSelector =
+            "team == infotainment, env!=dev,tier in (a, b),tier notin (c),team,!env"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            selector.to_string(),
+            "team=infotainment,env!=dev,tier in (a,b),tier notin (c),team,!env"
+        );
+        assert_eq!(selector.to_string().parse::<LabelSelector>(), Ok(selector));
+    }
+}
@@ -44,6 +44,9 @@ pub use stored_workload_spec::{
 
 pub use stored_workload_spec::{StoredWorkloadSpec, STR_RE_CONFIG_REFERENCES};
 
+mod scheduling_constraints;
+pub use scheduling_constraints::SchedulingConstraints;
+
 mod workload_state;
 #[cfg(any(feature = "test_utils", test))]
 pub use workload_state::{
@@ -74,6 +77,9 @@ pub use workload_spec::{
 mod tag;
 pub use tag::Tag;
 
+mod label_selector;
+pub use label_selector::LabelSelector;
+
 mod workload_instance_name;
 #[cfg(any(feature = "test_utils", test))]
 pub use workload_instance_name::generate_test_workload_instance_name;
@@ -86,13 +92,13 @@ mod control_interface_access;
 #[cfg(any(feature = "test_utils", test))]
 pub use control_interface_access::generate_test_control_interface_access;
 pub use control_interface_access::{
-    AccessRightsRule, ControlInterfaceAccess, ReadWriteEnum, StateRule,
+    AccessRightsRule, ControlInterfaceAccess, ReadWriteEnum, SecretRule, StateRule,
 };
 
 mod config;
 #[cfg(any(feature = "test_utils", test))]
 pub use config::generate_test_configs;
-pub use config::ConfigItem;
+pub use config::{ConfigItem, REDACTED_SECRET_VALUE};
 
 mod file;
 #[cfg(any(feature = "test_utils", test))]
@@ -0,0 +1,190 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::serialize_to_ordered_map;
+
+use super::AgentAttributes;
+
+// [impl->swdd~common-object-serialization~1]
+#[derive(Debug, Clone, Serialize, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SchedulingConstraints {
+    #[serde(
+        serialize_with = "serialize_to_ordered_map",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub required_agent_labels: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_free_memory: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cpu_usage: Option<u32>,
+}
+
+impl SchedulingConstraints {
+    // [impl->swdd~common-scheduling-constraints-are-fulfilled-by-agent~1]
+    pub fn are_fulfilled_by(&self, agent_attributes: &AgentAttributes) -> bool {
+        self.are_labels_fulfilled_by(agent_attributes)
+            && self.min_free_memory.is_none_or(|min_free_memory| {
+                agent_attributes
+                    .free_memory
+                    .as_ref()
+                    .is_some_and(|free_memory| free_memory.free_memory >= min_free_memory)
+            })
+            && self.max_cpu_usage.is_none_or(|max_cpu_usage| {
+                agent_attributes
+                    .cpu_usage
+                    .as_ref()
+                    .is_some_and(|cpu_usage| cpu_usage.cpu_usage <= max_cpu_usage)
+            })
+    }
+
+    // [impl->swdd~common-scheduling-constraints-are-fulfilled-by-agent~1]
+    pub fn are_labels_fulfilled_by(&self, agent_attributes: &AgentAttributes) -> bool {
+        self.required_agent_labels
+            .iter()
+            .all(|(key, value)| agent_attributes.labels.get(key) == Some(value))
+    }
+}
+
+impl From<ank_base::SchedulingConstraints> for SchedulingConstraints {
+    fn from(item: ank_base::SchedulingConstraints) -> Self {
+        SchedulingConstraints {
+            required_agent_labels: item.required_agent_labels,
+            min_free_memory: item.min_free_memory,
+            max_cpu_usage: item.max_cpu_usage,
+        }
+    }
+}
+
+impl From<SchedulingConstraints> for ank_base::SchedulingConstraints {
+    fn from(item: SchedulingConstraints) -> Self {
+        ank_base::SchedulingConstraints {
+            required_agent_labels: item.required_agent_labels,
+            min_free_memory: item.min_free_memory,
+            max_cpu_usage: item.max_cpu_usage,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::ank_base;
+
+    use crate::objects::{AgentAttributes, CpuUsage, FreeMemory, SchedulingConstraints};
+
+    fn agent_attributes() -> AgentAttributes {
+        AgentAttributes {
+            cpu_usage: Some(CpuUsage { cpu_usage: 50 }),
+            free_memory: Some(FreeMemory { free_memory: 1024 }),
+            labels: HashMap::from([
+                ("zone".to_string(), "front".to_string()),
+                ("arch".to_string(), "aarch64".to_string()),
+            ]),
+            last_seen: None,
+        }
+    }
+
+    // [utest->swdd~common-scheduling-constraints-are-fulfilled-by-agent~1]
+    #[test]
+    fn utest_scheduling_constraints_fulfilled() {
+        assert!(SchedulingConstraints::default().are_fulfilled_by(&agent_attributes()));
+        assert!(SchedulingConstraints {
+            required_agent_labels: HashMap::from([("zone".to_string(), "front".to_string())]),
+            min_free_memory: Some(1024),
+            max_cpu_usage: Some(50),
+        }
+        .are_fulfilled_by(&agent_attributes()));
+    }
+
+    // [utest->swdd~common-scheduling-constraints-are-fulfilled-by-agent~1]
+    #[test]
+    fn utest_scheduling_constraints_not_fulfilled() {
+        let constraints = SchedulingConstraints {
+            required_agent_labels: HashMap::from([("zone".to_string(), "rear".to_string())]),
+            ..Default::default()
+        };
+        assert!(!constraints.are_fulfilled_by(&agent_attributes()));
+        assert!(!constraints.are_labels_fulfilled_by(&agent_attributes()));
+
+        let constraints = SchedulingConstraints {
+            required_agent_labels: HashMap::from([("gpu".to_string(), "true".to_string())]),
+            ..Default::default()
+        };
+        assert!(!constraints.are_fulfilled_by(&agent_attributes()));
+
+        let constraints = SchedulingConstraints {
+            min_free_memory: Some(1025),
+            ..Default::default()
+        };
+        assert!(!constraints.are_fulfilled_by(&agent_attributes()));
+        assert!(constraints.are_labels_fulfilled_by(&agent_attributes()));
+
+        let constraints = SchedulingConstraints {
+            max_cpu_usage: Some(49),
+            ..Default::default()
+        };
+        assert!(!constraints.are_fulfilled_by(&agent_attributes()));
+    }
+
+    // [utest->swdd~common-scheduling-constraints-are-fulfilled-by-agent~1]
+    #[test]
+    fn utest_scheduling_constraints_unknown_resources_not_fulfilled() {
+        let constraints = SchedulingConstraints {
+            min_free_memory: Some(1),
+            max_cpu_usage: Some(100),
+            ..Default::default()
+        };
+
+        assert!(!constraints.are_fulfilled_by(&AgentAttributes::default()));
+    }
+
+    #[test]
+    fn utest_scheduling_constraints_conversion() {
+        let proto_constraints = ank_base::SchedulingConstraints {
+            required_agent_labels: HashMap::from([("zone".to_string(), "front".to_string())]),
+            min_free_memory: Some(1024),
+            max_cpu_usage: None,
+        };
+
+        let constraints = SchedulingConstraints::from(proto_constraints.clone());
+
+        assert_eq!(
+            constraints,
+            SchedulingConstraints {
+                required_agent_labels: HashMap::from([("zone".to_string(), "front".to_string())]),
+                min_free_memory: Some(1024),
+                max_cpu_usage: None,
+            }
+        );
+        assert_eq!(
+            ank_base::SchedulingConstraints::from(constraints),
+            proto_constraints
+        );
+    }
+}
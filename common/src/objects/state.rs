@@ -14,7 +14,7 @@
 use serde::{Deserialize, Serialize};
 
 use regex::Regex;
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use crate::helpers::serialize_to_ordered_map;
 use crate::objects::ConfigItem;
@@ -26,7 +26,7 @@ pub const CURRENT_API_VERSION: &str = "v0.1";
 
 // [impl->swdd~common-object-representation~1]
 // [impl->swdd~common-object-serialization~1]
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct State {
     pub api_version: String,
@@ -34,6 +34,24 @@ pub struct State {
     pub workloads: HashMap<String, StoredWorkloadSpec>,
     #[serde(default)]
     pub configs: HashMap<String, ConfigItem>,
+    #[serde(default)]
+    pub secrets: HashMap<String, ConfigItem>,
+}
+
+// The desired state is logged by the Ankaios server and the Ankaios agent, hence only the names
+// of the secrets are shown.
+// [impl->swdd~common-state-debug-output-hides-secret-values~1]
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut secret_names: Vec<&String> = self.secrets.keys().collect();
+        secret_names.sort();
+        f.debug_struct("State")
+            .field("api_version", &self.api_version)
+            .field("workloads", &self.workloads)
+            .field("configs", &self.configs)
+            .field("secrets", &secret_names)
+            .finish()
+    }
 }
 
 impl Default for State {
@@ -42,6 +60,7 @@ impl Default for State {
             api_version: CURRENT_API_VERSION.into(),
             workloads: Default::default(),
             configs: Default::default(),
+            secrets: Default::default(),
         }
     }
 }
@@ -64,6 +83,13 @@ impl From<State> for ank_base::State {
                     .map(|(key, config_item)| (key, config_item.into()))
                     .collect(),
             }),
+            secrets: Some(ank_base::ConfigMap {
+                configs: item
+                    .secrets
+                    .into_iter()
+                    .map(|(key, secret_item)| (key, secret_item.into()))
+                    .collect(),
+            }),
         }
     }
 }
@@ -88,6 +114,13 @@ impl TryFrom<ank_base::State> for State {
                 .into_iter()
                 .map(|(k, v)| Ok((k, v.try_into()?)))
                 .collect::<Result<_, Self::Error>>()?,
+            secrets: item
+                .secrets
+                .unwrap_or_default()
+                .configs
+                .into_iter()
+                .map(|(k, v)| Ok((k, v.try_into()?)))
+                .collect::<Result<_, Self::Error>>()?,
         })
     }
 }
@@ -123,6 +156,50 @@ impl State {
         }
         Ok(())
     }
+
+    // [impl->swdd~common-secret-naming-convention~1]
+    pub fn verify_secrets_format(provided_state: &State) -> Result<(), String> {
+        let re_secret_names = Regex::new(STR_RE_CONFIG_REFERENCES).unwrap();
+        for secret_name in provided_state.secrets.keys() {
+            if !re_secret_names.is_match(secret_name.as_str()) {
+                return Err(format!(
+                    "Unsupported secret name. Received '{}', expected to have characters in {}",
+                    secret_name, STR_RE_CONFIG_REFERENCES
+                ));
+            }
+        }
+
+        for (workload_name, workload) in &provided_state.workloads {
+            StoredWorkloadSpec::verify_config_reference_format(&workload.secrets)?;
+            if let Some(alias) = workload
+                .secrets
+                .keys()
+                .find(|alias| workload.configs.contains_key(*alias))
+            {
+                return Err(format!(
+                    "Workload '{}' uses the alias '{}' for a config and a secret",
+                    workload_name, alias
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // [impl->swdd~common-redacts-secret-values~1]
+    pub fn redact_secrets(&mut self) {
+        self.secrets
+            .values_mut()
+            .for_each(|secret| *secret = secret.redacted());
+    }
+
+    // [impl->swdd~common-restores-redacted-secret-values~1]
+    pub fn restore_redacted_secrets(&mut self, current_state: &State) -> Result<(), String> {
+        self.secrets.iter_mut().try_for_each(|(name, secret)| {
+            secret
+                .restore_redacted(current_state.secrets.get(name))
+                .map_err(|err| format!("Could not restore secret '{}': {}", name, err))
+        })
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -142,7 +219,10 @@ mod tests {
     use std::collections::HashMap;
 
     use crate::{
-        objects::{generate_test_configs, generate_test_stored_workload_spec, ConfigItem, State},
+        objects::{
+            generate_test_configs, generate_test_stored_workload_spec, ConfigItem, State,
+            REDACTED_SECRET_VALUE,
+        },
         test_utils::{generate_test_proto_state, generate_test_state},
     };
 
@@ -167,6 +247,22 @@ mod tests {
         assert_eq!(State::try_from(proto_state), Ok(ankaios_state));
     }
 
+    // [utest->swdd~common-state-debug-output-hides-secret-values~1]
+    #[test]
+    fn utest_state_debug_output_hides_secret_values() {
+        let mut state = generate_test_state();
+        state.secrets = HashMap::from([(
+            "db_password".to_owned(),
+            ConfigItem::String("top_secret".to_owned()),
+        )]);
+
+        let debug_output = format!("{:?}", state);
+
+        assert!(!debug_output.contains("top_secret"));
+        assert!(debug_output.contains("secrets: [\"db_password\"]"));
+        assert!(debug_output.contains("workload_name_1"));
+    }
+
     #[test]
     fn utest_serialize_state_into_ordered_output() {
         // input: random sorted state
@@ -216,6 +312,7 @@ mod tests {
             configs: Some(ank_base::ConfigMap {
                 configs: HashMap::new(),
             }),
+            secrets: Some(Default::default()),
         };
         let state_ankaios_no_version = State::try_from(state_proto_no_version).unwrap();
 
@@ -236,6 +333,7 @@ mod tests {
             api_version: super::CURRENT_API_VERSION.into(),
             workloads: HashMap::from([(WORKLOAD_NAME_1.to_string(), workload)]),
             configs: generate_test_configs(),
+            secrets: Default::default(),
         };
 
         assert_eq!(State::verify_configs_format(&state), Ok(()));
@@ -286,4 +384,94 @@ mod tests {
             ))
         );
     }
+
+    // [utest->swdd~common-secret-naming-convention~1]
+    #[test]
+    fn utest_verify_secrets_format_incompatible_secret_name() {
+        let state = State {
+            secrets: HashMap::from([(
+                INVALID_CONFIG_KEY.to_owned(),
+                ConfigItem::String("value".to_string()),
+            )]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            State::verify_secrets_format(&state),
+            Err(format!(
+                "Unsupported secret name. Received '{}', expected to have characters in {}",
+                INVALID_CONFIG_KEY,
+                super::STR_RE_CONFIG_REFERENCES
+            ))
+        );
+    }
+
+    // [utest->swdd~common-secret-naming-convention~1]
+    #[test]
+    fn utest_verify_secrets_format_rejects_alias_used_for_config_and_secret() {
+        let mut workload = generate_test_stored_workload_spec(AGENT_A, RUNTIME);
+        workload
+            .secrets
+            .insert("ref1".to_owned(), "secret_1".to_string());
+        let mut state = State {
+            workloads: HashMap::from([(WORKLOAD_NAME_1.to_string(), workload)]),
+            secrets: HashMap::from([(
+                "secret_1".to_owned(),
+                ConfigItem::String("value".to_string()),
+            )]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            State::verify_secrets_format(&state),
+            Err(format!(
+                "Workload '{}' uses the alias 'ref1' for a config and a secret",
+                WORKLOAD_NAME_1
+            ))
+        );
+
+        let workload = state.workloads.get_mut(WORKLOAD_NAME_1).unwrap();
+        workload.secrets = HashMap::from([("password".to_owned(), "secret_1".to_string())]);
+        assert_eq!(State::verify_secrets_format(&state), Ok(()));
+    }
+
+    // [utest->swdd~common-redacts-secret-values~1]
+    // [utest->swdd~common-restores-redacted-secret-values~1]
+    #[test]
+    fn utest_redact_and_restore_secrets() {
+        let stored_state = State {
+            secrets: generate_test_configs(),
+            ..Default::default()
+        };
+
+        let mut redacted_state = stored_state.clone();
+        redacted_state.redact_secrets();
+        assert_eq!(
+            redacted_state.secrets.get("config_2"),
+            Some(&ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()))
+        );
+        assert_ne!(redacted_state, stored_state);
+
+        assert_eq!(
+            redacted_state.restore_redacted_secrets(&stored_state),
+            Ok(())
+        );
+        assert_eq!(redacted_state, stored_state);
+    }
+
+    // [utest->swdd~common-restores-redacted-secret-values~1]
+    #[test]
+    fn utest_restore_redacted_secrets_fails_for_unknown_secret() {
+        let mut state = State {
+            secrets: HashMap::from([(
+                "new_secret".to_owned(),
+                ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()),
+            )]),
+            ..Default::default()
+        };
+
+        assert!(state
+            .restore_redacted_secrets(&State::default())
+            .is_err_and(|err| err.contains("new_secret")));
+    }
 }
@@ -21,8 +21,8 @@ use serde::{Deserialize, Serialize};
 use crate::helpers::serialize_to_ordered_map;
 
 use super::{
-    control_interface_access::ControlInterfaceAccess, file::File, AddCondition, RestartPolicy, Tag,
-    WorkloadInstanceName, WorkloadSpec,
+    control_interface_access::ControlInterfaceAccess, file::File, AddCondition, RestartPolicy,
+    SchedulingConstraints, Tag, WorkloadInstanceName, WorkloadSpec,
 };
 
 pub const STR_RE_CONFIG_REFERENCES: &str = r"^[a-zA-Z0-9_-]*$";
@@ -43,8 +43,22 @@ pub struct StoredWorkloadSpec {
     pub control_interface_access: ControlInterfaceAccess,
     #[serde(default, serialize_with = "serialize_to_ordered_map")]
     pub configs: HashMap<String, String>,
+    #[serde(default, serialize_with = "serialize_to_ordered_map")]
+    pub secrets: HashMap<String, String>,
     #[serde(default)]
     pub files: Vec<File>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduling_constraints: Option<SchedulingConstraints>,
+    // [impl->swdd~common-workload-can-be-suspended~1]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub suspended: bool,
+    // [impl->swdd~common-workload-restart-generation~1]
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub restart_generation: u64,
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
 }
 
 impl StoredWorkloadSpec {
@@ -100,6 +114,7 @@ impl TryFrom<ank_base::Workload> for StoredWorkloadSpec {
                 .unwrap_or_default()
                 .try_into()?,
             configs: value.configs.unwrap_or_default().configs,
+            secrets: value.secrets.unwrap_or_default().secrets,
             files: value
                 .files
                 .unwrap_or_default()
@@ -107,6 +122,9 @@ impl TryFrom<ank_base::Workload> for StoredWorkloadSpec {
                 .into_iter()
                 .map(|file| file.try_into())
                 .collect::<Result<Vec<File>, String>>()?,
+            scheduling_constraints: value.scheduling_constraints.map(Into::into),
+            suspended: value.suspended.unwrap_or_default(),
+            restart_generation: value.restart_generation.unwrap_or_default(),
         })
     }
 }
@@ -132,9 +150,16 @@ impl From<StoredWorkloadSpec> for ank_base::Workload {
             configs: Some(ank_base::ConfigMappings {
                 configs: workload.configs,
             }),
+            secrets: Some(ank_base::SecretMappings {
+                secrets: workload.secrets,
+            }),
             files: Some(ank_base::Files {
                 files: workload.files.into_iter().map(Into::into).collect(),
             }),
+            scheduling_constraints: workload.scheduling_constraints.map(Into::into),
+            suspended: workload.suspended.then_some(true),
+            restart_generation: (workload.restart_generation != 0)
+                .then_some(workload.restart_generation),
         }
     }
 }
@@ -154,6 +179,8 @@ impl From<(String, StoredWorkloadSpec)> for WorkloadSpec {
             runtime_config: spec.runtime_config,
             files: spec.files,
             control_interface_access: spec.control_interface_access,
+            suspended: spec.suspended,
+            restart_generation: spec.restart_generation,
         }
     }
 }
@@ -169,7 +196,11 @@ impl From<WorkloadSpec> for StoredWorkloadSpec {
             runtime_config: value.runtime_config,
             control_interface_access: value.control_interface_access,
             configs: Default::default(),
+            secrets: Default::default(),
             files: value.files,
+            scheduling_constraints: None,
+            suspended: value.suspended,
+            restart_generation: value.restart_generation,
         }
     }
 }
@@ -207,7 +238,11 @@ pub fn generate_test_stored_workload_spec_with_config(
             ("ref2".into(), "config_2".into()),
         ]
         .into(),
+        secrets: HashMap::new(),
         files: vec![],
+        scheduling_constraints: None,
+        suspended: false,
+        restart_generation: 0,
     }
 }
 
@@ -260,4 +295,68 @@ mod tests {
             ))
         );
     }
+
+    // [utest->swdd~common-workload-can-be-suspended~1]
+    #[test]
+    fn utest_suspended_only_serialized_if_set() {
+        let workload = StoredWorkloadSpec::default();
+        let serialized = serde_yaml::to_string(&workload).unwrap();
+        assert!(!serialized.contains("suspended"));
+
+        let suspended_workload = StoredWorkloadSpec {
+            suspended: true,
+            ..Default::default()
+        };
+        let serialized = serde_yaml::to_string(&suspended_workload).unwrap();
+        assert!(serialized.contains("suspended: true"));
+
+        let deserialized: StoredWorkloadSpec = serde_yaml::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, suspended_workload);
+    }
+
+    // [utest->swdd~common-workload-can-be-suspended~1]
+    #[test]
+    fn utest_suspended_conversion_to_and_from_proto() {
+        let workload = StoredWorkloadSpec::default();
+        assert_eq!(api::ank_base::Workload::from(workload).suspended, None);
+
+        let suspended_workload = StoredWorkloadSpec {
+            suspended: true,
+            ..Default::default()
+        };
+        let proto_workload = api::ank_base::Workload::from(suspended_workload.clone());
+        assert_eq!(proto_workload.suspended, Some(true));
+        assert_eq!(
+            StoredWorkloadSpec::try_from(proto_workload).unwrap(),
+            suspended_workload
+        );
+    }
+
+    // [utest->swdd~common-workload-restart-generation~1]
+    #[test]
+    fn utest_restart_generation_only_serialized_if_set() {
+        let workload = StoredWorkloadSpec::default();
+        assert!(!serde_yaml::to_string(&workload)
+            .unwrap()
+            .contains("restartGeneration"));
+        assert_eq!(
+            api::ank_base::Workload::from(workload).restart_generation,
+            None
+        );
+
+        let restarted_workload = StoredWorkloadSpec {
+            restart_generation: 2,
+            ..Default::default()
+        };
+        assert!(serde_yaml::to_string(&restarted_workload)
+            .unwrap()
+            .contains("restartGeneration: 2"));
+
+        let proto_workload = api::ank_base::Workload::from(restarted_workload.clone());
+        assert_eq!(proto_workload.restart_generation, Some(2));
+        assert_eq!(
+            StoredWorkloadSpec::try_from(proto_workload).unwrap(),
+            restarted_workload
+        );
+    }
 }
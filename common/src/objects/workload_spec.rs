@@ -15,6 +15,7 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 use crate::helpers::serialize_to_ordered_map;
 use crate::objects::Tag;
@@ -30,6 +31,7 @@ pub type DeletedWorkloadCollection = Vec<DeletedWorkload>;
 const MAX_CHARACTERS_WORKLOAD_NAME: usize = 63;
 pub const STR_RE_WORKLOAD: &str = r"^[a-zA-Z0-9_-]+$";
 pub const STR_RE_AGENT: &str = r"^[a-zA-Z0-9_-]+$";
+const HIDDEN_CONTENT: &str = "<hidden>";
 
 // [impl->swdd~common-object-serialization~1]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -40,7 +42,7 @@ pub struct DeletedWorkload {
 }
 
 // [impl->swdd~common-object-serialization~1]
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(default, rename_all = "camelCase")]
 pub struct WorkloadSpec {
     pub instance_name: WorkloadInstanceName,
@@ -52,6 +54,35 @@ pub struct WorkloadSpec {
     pub runtime_config: String,
     pub files: Vec<File>,
     pub control_interface_access: ControlInterfaceAccess,
+    pub suspended: bool,
+    pub restart_generation: u64,
+}
+
+// The runtime config and the files are rendered with the configs and secrets
+// of the workload and must not end up in the logs.
+// [impl->swdd~common-workload-spec-debug-output-hides-rendered-content~1]
+impl fmt::Debug for WorkloadSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkloadSpec")
+            .field("instance_name", &self.instance_name)
+            .field("tags", &self.tags)
+            .field("dependencies", &self.dependencies)
+            .field("restart_policy", &self.restart_policy)
+            .field("runtime", &self.runtime)
+            .field("runtime_config", &HIDDEN_CONTENT)
+            .field(
+                "files",
+                &self
+                    .files
+                    .iter()
+                    .map(|file| file.mount_point.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .field("control_interface_access", &self.control_interface_access)
+            .field("suspended", &self.suspended)
+            .field("restart_generation", &self.restart_generation)
+            .finish()
+    }
 }
 
 // [impl->swdd~common-workload-needs-control-interface~1]
@@ -69,8 +100,15 @@ impl WorkloadSpec {
     // [impl->swdd~common-agent-naming-convention~2]
     // [impl->swdd~common-access-rules-filter-mask-convention~1]
     pub fn verify_fields_format(workload_spec: &WorkloadSpec) -> Result<(), String> {
-        Self::verify_workload_name_format(workload_spec.instance_name.workload_name())?;
         Self::verify_agent_name_format(workload_spec.instance_name.agent_name())?;
+        Self::verify_fields_format_without_agent(workload_spec)
+    }
+
+    /// Verifies all fields except the agent name, e.g., for workloads the server assigns an agent to.
+    // [impl->swdd~common-workload-naming-convention~1]
+    // [impl->swdd~common-access-rules-filter-mask-convention~1]
+    pub fn verify_fields_format_without_agent(workload_spec: &WorkloadSpec) -> Result<(), String> {
+        Self::verify_workload_name_format(workload_spec.instance_name.workload_name())?;
         workload_spec.control_interface_access.verify_format()?;
         Ok(())
     }
@@ -324,6 +362,8 @@ pub fn generate_test_workload_spec_with_runtime_config(
         runtime_config,
         control_interface_access: Default::default(),
         files: Default::default(),
+        suspended: false,
+        restart_generation: 0,
     }
 }
 
@@ -584,6 +624,26 @@ mod tests {
         assert!(workload_spec.needs_control_interface());
     }
 
+    // [utest->swdd~common-workload-spec-debug-output-hides-rendered-content~1]
+    #[test]
+    fn utest_workload_spec_debug_output_hides_runtime_config_and_files() {
+        let mut workload_spec = generate_test_workload_spec();
+        workload_spec.runtime_config = "password: top_secret_1".to_string();
+        workload_spec.files = vec![File {
+            mount_point: "/etc/secret.conf".to_string(),
+            file_content: FileContent::Data(Data {
+                data: "password: top_secret_2".to_string(),
+            }),
+        }];
+
+        let debug_output = format!("{:?}", workload_spec);
+
+        assert!(!debug_output.contains("top_secret"));
+        assert!(debug_output.contains("runtime_config: \"<hidden>\""));
+        assert!(debug_output.contains("/etc/secret.conf"));
+        assert!(debug_output.contains(workload_spec.instance_name.workload_name()));
+    }
+
     // [utest->swdd~common-workload-naming-convention~1]
     // [utest->swdd~common-agent-naming-convention~2]
     // [utest->swdd~common-access-rules-filter-mask-convention~1]
@@ -649,6 +709,30 @@ mod tests {
         );
     }
 
+    // [utest->swdd~common-workload-naming-convention~1]
+    #[test]
+    fn utest_workload_verify_fields_format_without_agent() {
+        let spec_without_agent = generate_test_workload_spec_with_param(
+            "".to_owned(),
+            "workload_1".to_owned(),
+            RUNTIME.to_owned(),
+        );
+        assert_eq!(
+            WorkloadSpec::verify_fields_format_without_agent(&spec_without_agent),
+            Ok(())
+        );
+
+        let spec_with_wrong_workload_name = generate_test_workload_spec_with_param(
+            "".to_owned(),
+            "incompatible.workload_name".to_owned(),
+            RUNTIME.to_owned(),
+        );
+        assert!(
+            WorkloadSpec::verify_fields_format_without_agent(&spec_with_wrong_workload_name)
+                .is_err()
+        );
+    }
+
     // [utest->swdd~common-workload-naming-convention~1]
     #[test]
     fn utest_verify_workload_name_format_inordinately_long_workload_name() {
@@ -109,11 +109,15 @@ impl Display for StoppingSubstate {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum SucceededSubstate {
     Ok = 0,
+    Suspended = 1,
 }
 
 impl From<i32> for SucceededSubstate {
-    fn from(_x: i32) -> Self {
-        SucceededSubstate::Ok
+    fn from(x: i32) -> Self {
+        match x {
+            x if x == SucceededSubstate::Suspended as i32 => SucceededSubstate::Suspended,
+            _ => SucceededSubstate::Ok,
+        }
     }
 }
 
@@ -121,6 +125,7 @@ impl Display for SucceededSubstate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             SucceededSubstate::Ok => write!(f, "Ok"),
+            SucceededSubstate::Suspended => write!(f, "Suspended"),
         }
     }
 }
@@ -258,7 +263,7 @@ impl From<ank_base::execution_state::ExecutionStateEnum> for ExecutionStateEnum
     }
 }
 
-// [impl->swdd~common-workload-states-supported-states~1]
+// [impl->swdd~common-workload-states-supported-states~2]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(default, rename_all = "camelCase")]
 pub struct ExecutionState {
@@ -289,6 +294,10 @@ impl ExecutionState {
         ExecutionStateEnum::Succeeded(SucceededSubstate::Ok) == self.state
     }
 
+    pub fn is_suspended(&self) -> bool {
+        ExecutionStateEnum::Succeeded(SucceededSubstate::Suspended) == self.state
+    }
+
     pub fn is_failed(&self) -> bool {
         ExecutionStateEnum::Failed(FailedSubstate::ExecFailed) == self.state
     }
@@ -375,6 +384,13 @@ impl ExecutionState {
         }
     }
 
+    pub fn suspended() -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Succeeded(SucceededSubstate::Suspended),
+            ..Default::default()
+        }
+    }
+
     pub fn running() -> Self {
         ExecutionState {
             state: ExecutionStateEnum::Running(RunningSubstate::Ok),
@@ -684,7 +700,7 @@ mod tests {
     }
 
     // [utest->swdd~common-workload-state-additional-information~1]
-    // [utest->swdd~common-workload-states-supported-states~1]
+    // [utest->swdd~common-workload-states-supported-states~2]
     #[test]
     fn utest_execution_state_to_proto_mapping() {
         let additional_info = "some additional info";
@@ -757,6 +773,17 @@ mod tests {
             },
             ExecutionState::succeeded().into(),
         );
+        assert_eq!(
+            ank_base::ExecutionState {
+                additional_info: "".to_string(),
+                execution_state_enum: Some(
+                    ank_base::execution_state::ExecutionStateEnum::Succeeded(
+                        ank_base::Succeeded::Suspended.into(),
+                    )
+                ),
+            },
+            ExecutionState::suspended().into(),
+        );
         assert_eq!(
             ank_base::ExecutionState {
                 additional_info: "".to_string(),
@@ -789,7 +816,7 @@ mod tests {
     }
 
     // [utest->swdd~common-workload-state-additional-information~1]
-    // [utest->swdd~common-workload-states-supported-states~1]
+    // [utest->swdd~common-workload-states-supported-states~2]
     #[test]
     fn utest_execution_state_from_proto_mapping() {
         let additional_info = "some additional info";
@@ -869,6 +896,18 @@ mod tests {
             }
             .into(),
         );
+        assert_eq!(
+            ExecutionState::suspended(),
+            ank_base::ExecutionState {
+                additional_info: "".to_string(),
+                execution_state_enum: Some(
+                    ank_base::execution_state::ExecutionStateEnum::Succeeded(
+                        ank_base::Succeeded::Suspended.into(),
+                    )
+                ),
+            }
+            .into(),
+        );
         assert_eq!(
             ExecutionState::running(),
             ank_base::ExecutionState {
@@ -904,7 +943,7 @@ mod tests {
     }
 
     // [utest->swdd~common-workload-state-additional-information~1]
-    // [utest->swdd~common-workload-states-supported-states~1]
+    // [utest->swdd~common-workload-states-supported-states~2]
     #[test]
     fn utest_execution_state_to_string_basic_mapping() {
         let additional_info = "some additional info";
@@ -940,6 +979,10 @@ mod tests {
             ExecutionState::succeeded().to_string(),
             String::from("Succeeded(Ok)")
         );
+        assert_eq!(
+            ExecutionState::suspended().to_string(),
+            String::from("Succeeded(Suspended)")
+        );
         assert_eq!(
             ExecutionState::running().to_string(),
             String::from("Running(Ok)")
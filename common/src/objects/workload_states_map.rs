@@ -92,6 +92,29 @@ impl WorkloadStatesMap {
             .and_then(|id_map| id_map.get(instance_name.id()))
     }
 
+    pub fn contains_workload(&self, workload_name: &str) -> bool {
+        self.0
+            .values()
+            .any(|name_map| name_map.contains_key(workload_name))
+    }
+
+    // Agents without any remaining workload state are removed as well.
+    pub fn retain_workloads(&mut self, keep_workload: impl Fn(&str) -> bool) {
+        self.0.retain(|_, name_map| {
+            name_map.retain(|workload_name, _| keep_workload(workload_name));
+            !name_map.is_empty()
+        });
+    }
+
+    pub fn iter_execution_states(&self) -> impl Iterator<Item = (&str, &ExecutionState)> {
+        self.0.iter().flat_map(|(agent_name, name_map)| {
+            name_map
+                .values()
+                .flat_map(|id_map| id_map.values())
+                .map(move |exec_state| (agent_name.as_str(), exec_state))
+        })
+    }
+
     pub fn agent_disconnected(&mut self, agent_name: &str) {
         if let Some(agent_states) = self.0.get_mut(agent_name) {
             agent_states.iter_mut().for_each(|(_, name_map)| {
@@ -646,4 +669,44 @@ mod tests {
             .get_workload_state_for_workload(&wl_state.instance_name)
             .is_none())
     }
+
+    #[test]
+    fn utest_contains_workload() {
+        let wls_db = create_test_setup();
+
+        assert!(wls_db.contains_workload(WORKLOAD_NAME_1));
+        assert!(wls_db.contains_workload(WORKLOAD_NAME_3));
+        assert!(!wls_db.contains_workload("not_existing_workload"));
+    }
+
+    #[test]
+    fn utest_retain_workloads() {
+        let mut wls_db = create_test_setup();
+
+        wls_db.retain_workloads(|workload_name| workload_name == WORKLOAD_NAME_1);
+
+        assert!(wls_db.contains_workload(WORKLOAD_NAME_1));
+        assert!(!wls_db.contains_workload(WORKLOAD_NAME_3));
+        assert!(wls_db.get_workload_state_for_agent(AGENT_B).is_empty());
+        assert!(!wls_db.0.contains_key(AGENT_B));
+    }
+
+    #[test]
+    fn utest_iter_execution_states_returns_states_with_agent_name() {
+        let wls_db = create_test_setup();
+
+        let mut execution_states: Vec<(&str, &ExecutionState)> =
+            wls_db.iter_execution_states().collect();
+        execution_states
+            .sort_by_key(|(agent_name, exec_state)| (*agent_name, exec_state.to_string()));
+
+        assert_eq!(
+            execution_states,
+            vec![
+                (AGENT_A, &ExecutionState::starting("additional_info")),
+                (AGENT_A, &ExecutionState::succeeded()),
+                (AGENT_B, &ExecutionState::running()),
+            ]
+        )
+    }
 }
@@ -362,6 +362,7 @@ mod tests {
                 ExecutionState::running(),
             ),
             agents: agent_map,
+            revision: None,
         };
 
         let expected = Object {
@@ -396,6 +397,7 @@ mod tests {
                 ExecutionState::running(),
             ),
             agents: agent_map,
+            revision: None,
         };
         let actual: CompleteState = object.try_into().unwrap();
 
@@ -813,6 +815,7 @@ mod tests {
                                     .entry("ref1", "config_1")
                                     .entry("ref2", "config_2")
                             )
+                            .entry("secrets", Mapping::default())
                             .entry("files", vec![
                                 Mapping::default()
                                     .entry("mountPoint", "/file.json")
@@ -830,6 +833,7 @@ mod tests {
                         .entry("config_2", "value 2")
                         .entry("config_3", "value 3")
                 )
+                .entry("secrets", Mapping::default())
         }
 
         pub fn generate_test_value_object() -> Value {
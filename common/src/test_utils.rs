@@ -51,6 +51,7 @@ pub fn generate_test_state_from_workloads(workloads: Vec<WorkloadSpec>) -> State
             ("config_3".into(), ConfigItem::String("value 3".into())),
         ]
         .into(),
+        secrets: Default::default(),
     }
 }
 
@@ -67,9 +68,11 @@ pub fn generate_test_proto_complete_state(
                     .collect(),
             }),
             configs: Some(Default::default()),
+            secrets: Some(Default::default()),
         }),
         workload_states: None,
         agents: None,
+        revision: None,
     }
 }
 
@@ -89,9 +92,11 @@ pub fn generate_test_complete_state(workloads: Vec<WorkloadSpec>) -> crate::obje
                 .map(|v| (v.instance_name.workload_name().to_owned(), v.into()))
                 .collect(),
             configs: HashMap::new(),
+            secrets: Default::default(),
         },
         workload_states: generate_test_workload_states_map_from_specs(workloads),
         agents,
+        revision: None,
     }
 }
 
@@ -139,6 +144,7 @@ pub fn generate_test_state() -> State {
         api_version: API_VERSION.into(),
         workloads: ankaios_workloads,
         configs: HashMap::new(),
+        secrets: Default::default(),
     }
 }
 
@@ -155,6 +161,7 @@ pub fn generate_test_proto_state() -> ank_base::State {
         api_version: API_VERSION.into(),
         workloads: proto_workloads,
         configs: Some(Default::default()),
+        secrets: Some(Default::default()),
     }
 }
 
@@ -201,6 +208,10 @@ pub fn generate_test_proto_workload_with_param(
             ("ref2".into(), "config_2".into()),
         ].into()}),
         files: Some(generate_test_proto_workload_files()),
+        scheduling_constraints: None,
+        secrets: Some(Default::default()),
+        suspended: None,
+        restart_generation: None,
     }
 }
 
@@ -222,6 +233,10 @@ pub fn generate_test_proto_workload() -> ank_base::Workload {
             ("ref2".into(), "config_2".into()),
         ].into()}),
         files: Some(generate_test_proto_workload_files()),
+        scheduling_constraints: None,
+        secrets: Some(Default::default()),
+        suspended: None,
+        restart_generation: None,
     }
 }
 
@@ -278,11 +293,11 @@ impl MockAllContextSync {
             mutex_std: std::sync::Mutex::new(()),
         }
     }
-    pub async fn get_lock_async(&self) -> tokio::sync::MutexGuard<()> {
+    pub async fn get_lock_async(&self) -> tokio::sync::MutexGuard<'_, ()> {
         self.mutex_tokio.lock().await
     }
 
-    pub fn get_lock(&self) -> std::sync::MutexGuard<()> {
+    pub fn get_lock(&self) -> std::sync::MutexGuard<'_, ()> {
         match self.mutex_std.lock() {
             Ok(guard) => guard,
             Err(poisoned) => poisoned.into_inner(),
@@ -14,19 +14,21 @@
 
 use crate::{
     commands::{self, RequestContent},
-    objects::CompleteState,
+    objects::{CompleteState, WorkloadInstanceName},
 };
+use api::ank_base;
 use async_trait::async_trait;
-use std::fmt;
+use std::{collections::HashMap, fmt};
 use tokio::sync::mpsc::error::SendError;
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum ToServer {
     AgentHello(commands::AgentHello),
     AgentLoadStatus(commands::AgentLoadStatus),
     AgentGone(commands::AgentGone),
     Request(commands::Request),
+    Response(commands::ConnectionResponse),
     UpdateWorkloadState(commands::UpdateWorkloadState),
     Stop(commands::Stop),
     Goodbye(commands::Goodbye),
@@ -50,7 +52,11 @@ impl fmt::Display for ToServerError {
 // [impl->swdd~to-server-channel~1]
 #[async_trait]
 pub trait ToServerInterface {
-    async fn agent_hello(&self, agent_name: String) -> Result<(), ToServerError>;
+    async fn agent_hello(
+        &self,
+        agent_name: String,
+        labels: HashMap<String, String>,
+    ) -> Result<(), ToServerError>;
     async fn agent_load_status(
         &self,
         agent_resource: commands::AgentLoadStatus,
@@ -62,6 +68,12 @@ pub trait ToServerInterface {
         state: CompleteState,
         update_mask: Vec<String>,
     ) -> Result<(), ToServerError>;
+    async fn dry_run_update_state(
+        &self,
+        request_id: String,
+        state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<(), ToServerError>;
     async fn update_workload_state(
         &self,
         workload_running: Vec<crate::objects::WorkloadState>,
@@ -71,6 +83,43 @@ pub trait ToServerInterface {
         request_id: String,
         request_complete_state: commands::CompleteStateRequest,
     ) -> Result<(), ToServerError>;
+    async fn request_revisions(&self, request_id: String) -> Result<(), ToServerError>;
+    async fn rollback(&self, request_id: String, revision: u64) -> Result<(), ToServerError>;
+    async fn request_audit_log(
+        &self,
+        request_id: String,
+        tail: Option<u32>,
+    ) -> Result<(), ToServerError>;
+    async fn request_workload_description(
+        &self,
+        request_id: String,
+        workload_name: String,
+        config_names: Vec<String>,
+    ) -> Result<(), ToServerError>;
+    async fn request_events(
+        &self,
+        request_id: String,
+        field_mask: Vec<String>,
+    ) -> Result<(), ToServerError>;
+    async fn cancel_events(&self, request_id: String) -> Result<(), ToServerError>;
+    async fn request_logs(
+        &self,
+        request_id: String,
+        logs_request: commands::LogsRequest,
+    ) -> Result<(), ToServerError>;
+    async fn cancel_logs(&self, request_id: String) -> Result<(), ToServerError>;
+    async fn response(&self, response: ank_base::Response) -> Result<(), ToServerError>;
+    async fn log_entries_response(
+        &self,
+        request_id: String,
+        log_entries: Vec<ank_base::LogEntry>,
+    ) -> Result<(), ToServerError>;
+    async fn logs_stop_response(
+        &self,
+        request_id: String,
+        instance_name: WorkloadInstanceName,
+    ) -> Result<(), ToServerError>;
+    async fn goodbye(&self, connection_name: String) -> Result<(), ToServerError>;
     async fn stop(&self) -> Result<(), ToServerError>;
 }
 
@@ -79,9 +128,16 @@ pub type ToServerReceiver = tokio::sync::mpsc::Receiver<ToServer>;
 
 #[async_trait]
 impl ToServerInterface for ToServerSender {
-    async fn agent_hello(&self, agent_name: String) -> Result<(), ToServerError> {
+    async fn agent_hello(
+        &self,
+        agent_name: String,
+        labels: HashMap<String, String>,
+    ) -> Result<(), ToServerError> {
         Ok(self
-            .send(ToServer::AgentHello(commands::AgentHello { agent_name }))
+            .send(ToServer::AgentHello(commands::AgentHello {
+                agent_name,
+                labels,
+            }))
             .await?)
     }
 
@@ -110,7 +166,31 @@ impl ToServerInterface for ToServerSender {
             .send(ToServer::Request(commands::Request {
                 request_id,
                 request_content: commands::RequestContent::UpdateStateRequest(Box::new(
-                    commands::UpdateStateRequest { state, update_mask },
+                    commands::UpdateStateRequest {
+                        state,
+                        update_mask,
+                        dry_run: false,
+                    },
+                )),
+            }))
+            .await?)
+    }
+
+    async fn dry_run_update_state(
+        &self,
+        request_id: String,
+        state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: commands::RequestContent::UpdateStateRequest(Box::new(
+                    commands::UpdateStateRequest {
+                        state,
+                        update_mask,
+                        dry_run: true,
+                    },
                 )),
             }))
             .await?)
@@ -137,15 +217,160 @@ impl ToServerInterface for ToServerSender {
         Ok(self
             .send(ToServer::Request(commands::Request {
                 request_id,
-                request_content: RequestContent::CompleteStateRequest(
-                    commands::CompleteStateRequest {
-                        field_mask: request_complete_state.field_mask,
+                request_content: RequestContent::CompleteStateRequest(request_complete_state),
+            }))
+            .await?)
+    }
+
+    async fn request_revisions(&self, request_id: String) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::RevisionsRequest(commands::RevisionsRequest {}),
+            }))
+            .await?)
+    }
+
+    async fn rollback(&self, request_id: String, revision: u64) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::RollbackRequest(commands::RollbackRequest {
+                    revision,
+                }),
+            }))
+            .await?)
+    }
+
+    async fn request_audit_log(
+        &self,
+        request_id: String,
+        tail: Option<u32>,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::AuditLogRequest(commands::AuditLogRequest {
+                    tail,
+                }),
+            }))
+            .await?)
+    }
+
+    async fn request_workload_description(
+        &self,
+        request_id: String,
+        workload_name: String,
+        config_names: Vec<String>,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::WorkloadDescriptionRequest(
+                    commands::WorkloadDescriptionRequest {
+                        workload_name,
+                        config_names,
                     },
                 ),
             }))
             .await?)
     }
 
+    async fn request_events(
+        &self,
+        request_id: String,
+        field_mask: Vec<String>,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::EventsRequest(commands::EventsRequest {
+                    field_mask,
+                }),
+            }))
+            .await?)
+    }
+
+    async fn cancel_events(&self, request_id: String) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::EventsCancelRequest(
+                    commands::EventsCancelRequest {},
+                ),
+            }))
+            .await?)
+    }
+
+    async fn request_logs(
+        &self,
+        request_id: String,
+        logs_request: commands::LogsRequest,
+    ) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::LogsRequest(logs_request),
+            }))
+            .await?)
+    }
+
+    async fn cancel_logs(&self, request_id: String) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Request(commands::Request {
+                request_id,
+                request_content: RequestContent::LogsCancelRequest(commands::LogsCancelRequest {}),
+            }))
+            .await?)
+    }
+
+    async fn response(&self, response: ank_base::Response) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Response(commands::ConnectionResponse {
+                connection_name: String::default(),
+                response,
+            }))
+            .await?)
+    }
+
+    async fn log_entries_response(
+        &self,
+        request_id: String,
+        log_entries: Vec<ank_base::LogEntry>,
+    ) -> Result<(), ToServerError> {
+        self.response(ank_base::Response {
+            request_id,
+            response_content: ank_base::response::ResponseContent::LogEntriesResponse(
+                ank_base::LogEntriesResponse { log_entries },
+            )
+            .into(),
+        })
+        .await
+    }
+
+    async fn logs_stop_response(
+        &self,
+        request_id: String,
+        instance_name: WorkloadInstanceName,
+    ) -> Result<(), ToServerError> {
+        self.response(ank_base::Response {
+            request_id,
+            response_content: ank_base::response::ResponseContent::LogsStopResponse(
+                ank_base::LogsStopResponse {
+                    workload_name: Some(instance_name.into()),
+                },
+            )
+            .into(),
+        })
+        .await
+    }
+
+    async fn goodbye(&self, connection_name: String) -> Result<(), ToServerError> {
+        Ok(self
+            .send(ToServer::Goodbye(commands::Goodbye { connection_name }))
+            .await?)
+    }
+
     async fn stop(&self) -> Result<(), ToServerError> {
         Ok(self.send(ToServer::Stop(commands::Stop {})).await?)
     }
@@ -171,15 +396,19 @@ mod tests {
         to_server_interface::{ToServer, ToServerInterface},
     };
 
-    use super::{ToServerReceiver, ToServerSender};
+    use super::{ank_base, ToServerReceiver, ToServerSender};
+    use std::collections::HashMap;
 
     const TEST_CHANNEL_CAPA: usize = 5;
     const WORKLOAD_NAME: &str = "X";
+    const CONFIG_NAME: &str = "config_1";
     const AGENT_NAME: &str = "agent_A";
     const REQUEST_ID: &str = "emkw489ejf89ml";
     const FIELD_MASK: &str = "desiredState.bla_bla";
     const CPU_USAGE: CpuUsage = CpuUsage { cpu_usage: 42 };
     const FREE_MEMORY: FreeMemory = FreeMemory { free_memory: 42 };
+    const REVISION: u64 = 3;
+    const CONNECTION_NAME: &str = "cli-conn-1";
 
     // [utest->swdd~to-server-channel~1]
     #[tokio::test]
@@ -187,12 +416,17 @@ mod tests {
         let (tx, mut rx): (ToServerSender, ToServerReceiver) =
             tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
 
-        assert!(tx.agent_hello(AGENT_NAME.to_string()).await.is_ok());
+        let labels = HashMap::from([("zone".to_string(), "front".to_string())]);
+        assert!(tx
+            .agent_hello(AGENT_NAME.to_string(), labels.clone())
+            .await
+            .is_ok());
 
         assert_eq!(
             rx.recv().await.unwrap(),
             ToServer::AgentHello(commands::AgentHello {
-                agent_name: AGENT_NAME.to_string()
+                agent_name: AGENT_NAME.to_string(),
+                labels,
             })
         )
     }
@@ -262,7 +496,40 @@ mod tests {
                 request_content: commands::RequestContent::UpdateStateRequest(Box::new(
                     commands::UpdateStateRequest {
                         state: complete_state,
-                        update_mask: vec![FIELD_MASK.to_string()]
+                        update_mask: vec![FIELD_MASK.to_string()],
+                        dry_run: false,
+                    },
+                )),
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_dry_run_update_state() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let workload1 = generate_test_workload_spec();
+        let complete_state = generate_test_complete_state(vec![workload1]);
+        assert!(tx
+            .dry_run_update_state(
+                REQUEST_ID.to_string(),
+                complete_state.clone(),
+                vec![FIELD_MASK.to_string()]
+            )
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: commands::RequestContent::UpdateStateRequest(Box::new(
+                    commands::UpdateStateRequest {
+                        state: complete_state,
+                        update_mask: vec![FIELD_MASK.to_string()],
+                        dry_run: true,
                     },
                 )),
             })
@@ -297,6 +564,7 @@ mod tests {
 
         let complete_state_request = commands::CompleteStateRequest {
             field_mask: vec![FIELD_MASK.to_string()],
+            label_selector: None,
         };
         let request_content = RequestContent::CompleteStateRequest(complete_state_request.clone());
         assert!(tx
@@ -312,4 +580,232 @@ mod tests {
             })
         )
     }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_request_revisions() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx.request_revisions(REQUEST_ID.to_string()).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::RevisionsRequest(commands::RevisionsRequest {})
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_rollback() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx.rollback(REQUEST_ID.to_string(), REVISION).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::RollbackRequest(commands::RollbackRequest {
+                    revision: REVISION
+                })
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_request_audit_log() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx
+            .request_audit_log(REQUEST_ID.to_string(), Some(10))
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::AuditLogRequest(commands::AuditLogRequest {
+                    tail: Some(10)
+                })
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_request_workload_description() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx
+            .request_workload_description(
+                REQUEST_ID.to_string(),
+                WORKLOAD_NAME.to_string(),
+                vec![CONFIG_NAME.to_string()]
+            )
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::WorkloadDescriptionRequest(
+                    commands::WorkloadDescriptionRequest {
+                        workload_name: WORKLOAD_NAME.to_string(),
+                        config_names: vec![CONFIG_NAME.to_string()],
+                    }
+                )
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_request_events() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx
+            .request_events(REQUEST_ID.to_string(), vec![FIELD_MASK.to_string()])
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::EventsRequest(commands::EventsRequest {
+                    field_mask: vec![FIELD_MASK.to_string()]
+                })
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_cancel_events() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx.cancel_events(REQUEST_ID.to_string()).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::EventsCancelRequest(
+                    commands::EventsCancelRequest {}
+                )
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_goodbye() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        assert!(tx.goodbye(CONNECTION_NAME.to_string()).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Goodbye(commands::Goodbye {
+                connection_name: CONNECTION_NAME.to_string()
+            })
+        )
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_logs_requests() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let logs_request = commands::LogsRequest {
+            workload_name: WORKLOAD_NAME.to_string(),
+            follow: true,
+            since: None,
+            tail: None,
+        };
+        assert!(tx
+            .request_logs(REQUEST_ID.to_string(), logs_request.clone())
+            .await
+            .is_ok());
+        assert!(tx.cancel_logs(REQUEST_ID.to_string()).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::LogsRequest(logs_request),
+            })
+        );
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Request(commands::Request {
+                request_id: REQUEST_ID.to_string(),
+                request_content: RequestContent::LogsCancelRequest(commands::LogsCancelRequest {}),
+            })
+        );
+    }
+
+    // [utest->swdd~to-server-channel~1]
+    #[tokio::test]
+    async fn utest_to_server_send_log_responses() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let instance_name = generate_test_workload_spec().instance_name;
+        let log_entries = vec![ank_base::LogEntry {
+            workload_name: Some(instance_name.clone().into()),
+            message: "log line".to_string(),
+        }];
+        assert!(tx
+            .log_entries_response(REQUEST_ID.to_string(), log_entries.clone())
+            .await
+            .is_ok());
+        assert!(tx
+            .logs_stop_response(REQUEST_ID.to_string(), instance_name.clone())
+            .await
+            .is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Response(commands::ConnectionResponse {
+                connection_name: String::default(),
+                response: ank_base::Response {
+                    request_id: REQUEST_ID.to_string(),
+                    response_content: Some(
+                        ank_base::response::ResponseContent::LogEntriesResponse(
+                            ank_base::LogEntriesResponse { log_entries }
+                        )
+                    ),
+                },
+            })
+        );
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::Response(commands::ConnectionResponse {
+                connection_name: String::default(),
+                response: ank_base::Response {
+                    request_id: REQUEST_ID.to_string(),
+                    response_content: Some(ank_base::response::ResponseContent::LogsStopResponse(
+                        ank_base::LogsStopResponse {
+                            workload_name: Some(instance_name.into()),
+                        }
+                    )),
+                },
+            })
+        );
+    }
 }
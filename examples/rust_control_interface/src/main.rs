@@ -113,6 +113,7 @@ fn create_request_for_complete_state() -> ToAnkaios {
             request_id: REQUEST_ID.to_string(),
             request_content: Some(RequestContent::CompleteStateRequest(CompleteStateRequest {
                 field_mask: vec![String::from("workloadStates.agent_A.dynamic_nginx")],
+                label_selector: None,
             })),
         })),
     }
@@ -15,10 +15,15 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     tonic_build::configure()
         .build_server(true)
-        .compile(
-            &["proto/grpc_api.proto"],
-            &["proto", "../api/proto"],
+        .type_attribute(
+            "FromServer.FromServerEnum",
+            "#[allow(clippy::large_enum_variant)]",
         )
+        .type_attribute(
+            "ToServer.ToServerEnum",
+            "#[allow(clippy::large_enum_variant)]",
+        )
+        .compile(&["proto/grpc_api.proto"], &["proto", "../api/proto"])
         .unwrap();
     Ok(())
 }
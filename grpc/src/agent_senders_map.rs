@@ -13,6 +13,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 use common::std_extensions::IllegalStateResult;
@@ -23,9 +24,15 @@ use crate::grpc_api::FromServer;
 
 type ShareableHashMap<K, V> = Arc<Mutex<HashMap<K, V>>>;
 
+// Identifies a single connection, as an agent can reconnect under the same name
+// while the task of its previous connection is still running.
+pub type ConnectionId = u64;
+
+static NEXT_CONNECTION_ID: AtomicU64 = AtomicU64::new(0);
+
 #[derive(Debug, Clone)]
 pub struct AgentSendersMap {
-    agent_senders: ShareableHashMap<String, Sender<Result<FromServer, Status>>>,
+    agent_senders: ShareableHashMap<String, (ConnectionId, Sender<Result<FromServer, Status>>)>,
 }
 
 // Beside improving readability by hiding the lock steps, this trait helps improve the
@@ -46,14 +53,15 @@ impl AgentSendersMap {
             .lock()
             .unwrap_or_illegal_state()
             .get(name)
-            .cloned()
+            .map(|(_, sender)| sender.clone())
     }
 
-    pub fn insert(&self, name: &str, sender: Sender<Result<FromServer, Status>>) {
+    pub fn insert(&self, name: &str, sender: Sender<Result<FromServer, Status>>) -> ConnectionId {
+        let connection_id = NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed);
         self.agent_senders
             .lock()
             .unwrap_or_illegal_state()
-            .insert(name.to_owned(), sender)
+            .insert(name.to_owned(), (connection_id, sender))
             .map_or_else(
                 || {
                     log::trace!("Successfully added a new agent sender.");
@@ -64,6 +72,7 @@ impl AgentSendersMap {
                     )
                 },
             );
+        connection_id
     }
 
     pub fn get_all_agent_names(&self) -> Vec<String> {
@@ -81,6 +90,20 @@ impl AgentSendersMap {
             .unwrap_or_illegal_state()
             .remove(name);
     }
+
+    // Removes the sender only if it still belongs to the given connection
+    // and returns if the connection was the current one.
+    // [impl->swdd~grpc-agent-connection-removes-only-own-sender~1]
+    pub fn remove_connection(&self, name: &str, connection_id: ConnectionId) -> bool {
+        let mut agent_senders = self.agent_senders.lock().unwrap_or_illegal_state();
+        match agent_senders.get(name) {
+            Some((current_connection_id, _)) if *current_connection_id == connection_id => {
+                agent_senders.remove(name);
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 impl Default for AgentSendersMap {
@@ -88,3 +111,55 @@ impl Default for AgentSendersMap {
         AgentSendersMap::new()
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::AgentSendersMap;
+
+    const AGENT_A: &str = "agent_A";
+
+    // [utest->swdd~grpc-agent-connection-removes-only-own-sender~1]
+    #[test]
+    fn utest_agent_senders_map_remove_connection_removes_current_connection() {
+        let agent_senders = AgentSendersMap::new();
+        let (sender, _receiver) = tokio::sync::mpsc::channel(1);
+        let connection_id = agent_senders.insert(AGENT_A, sender);
+
+        assert!(agent_senders.remove_connection(AGENT_A, connection_id));
+        assert!(agent_senders.get(AGENT_A).is_none());
+    }
+
+    // [utest->swdd~grpc-agent-connection-removes-only-own-sender~1]
+    #[test]
+    fn utest_agent_senders_map_remove_connection_keeps_sender_of_reconnected_agent() {
+        let agent_senders = AgentSendersMap::new();
+        let (old_sender, _old_receiver) = tokio::sync::mpsc::channel(1);
+        let old_connection_id = agent_senders.insert(AGENT_A, old_sender);
+        let (new_sender, _new_receiver) = tokio::sync::mpsc::channel(1);
+        agent_senders.insert(AGENT_A, new_sender.clone());
+
+        assert!(!agent_senders.remove_connection(AGENT_A, old_connection_id));
+        assert!(agent_senders
+            .get(AGENT_A)
+            .is_some_and(|sender| sender.same_channel(&new_sender)));
+    }
+
+    // [utest->swdd~grpc-agent-connection-removes-only-own-sender~1]
+    #[test]
+    fn utest_agent_senders_map_remove_connection_of_disconnected_agent() {
+        let agent_senders = AgentSendersMap::new();
+        let (sender, _receiver) = tokio::sync::mpsc::channel(1);
+        let connection_id = agent_senders.insert(AGENT_A, sender);
+        agent_senders.remove(AGENT_A);
+
+        assert!(!agent_senders.remove_connection(AGENT_A, connection_id));
+    }
+}
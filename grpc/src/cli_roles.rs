@@ -0,0 +1,417 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use api::ank_base;
+use common::{authorizer::Authorizer, commands, objects::ControlInterfaceAccess};
+use serde::Deserialize;
+use x509_parser::{certificate::X509Certificate, extensions::GeneralName, prelude::FromDer};
+
+const ANY_IDENTITY: &str = "*";
+
+// A role grants the CLI connections with one of the identities the access described by the
+// allow and deny rules. The rules have the same format as the controlInterfaceAccess of a workload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct CliRole {
+    #[serde(default)]
+    pub identities: Vec<String>,
+    #[serde(flatten)]
+    pub access: ControlInterfaceAccess,
+}
+
+#[derive(Debug, Clone)]
+struct CliRoleAuthorizer {
+    name: String,
+    identities: Vec<String>,
+    authorizer: Authorizer,
+}
+
+// [impl->swdd~grpc-server-maps-cli-identity-to-roles~1]
+#[derive(Debug, Clone, Default)]
+pub struct CliRoles {
+    roles: Vec<CliRoleAuthorizer>,
+}
+
+impl CliRoles {
+    pub fn new(roles: HashMap<String, CliRole>) -> Result<Self, String> {
+        let mut roles = roles
+            .into_iter()
+            .map(|(name, role)| {
+                role.access.verify_format().map_err(|err| {
+                    format!("Invalid access rules of CLI role '{}': {}", name, err)
+                })?;
+                Ok(CliRoleAuthorizer {
+                    authorizer: Authorizer::from(&role.access),
+                    identities: role.identities,
+                    name,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        // sort to ensure consistent log output
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { roles })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.roles.is_empty()
+    }
+
+    // Returns None if no role is assigned to any of the identities.
+    pub fn authorizer_for(&self, identities: &[String]) -> Option<CliAuthorizer> {
+        let roles: Vec<&CliRoleAuthorizer> = self
+            .roles
+            .iter()
+            .filter(|role| {
+                role.identities.iter().any(|role_identity| {
+                    role_identity == ANY_IDENTITY || identities.contains(role_identity)
+                })
+            })
+            .collect();
+
+        if roles.is_empty() {
+            return None;
+        }
+
+        Some(CliAuthorizer {
+            role_names: roles.iter().map(|role| role.name.clone()).collect(),
+            authorizers: roles
+                .into_iter()
+                .map(|role| role.authorizer.clone())
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CliAuthorizer {
+    role_names: Vec<String>,
+    authorizers: Vec<Authorizer>,
+}
+
+impl CliAuthorizer {
+    pub fn role_names(&self) -> &[String] {
+        &self.role_names
+    }
+
+    // A request is allowed if at least one of the assigned roles allows it.
+    // [impl->swdd~grpc-cli-connection-authorizes-requests~1]
+    pub fn authorize(&self, request: &ank_base::Request) -> bool {
+        match commands::Request::try_from(request.clone()) {
+            Ok(request) => self
+                .authorizers
+                .iter()
+                .any(|authorizer| authorizer.authorize(&request)),
+            Err(err) => {
+                log::info!(
+                    "Denying request '{}' as it could not be converted: '{}'",
+                    request.request_id,
+                    err
+                );
+                false
+            }
+        }
+    }
+}
+
+// The identities of a client certificate are the common names of the subject,
+// the complete subject and the DNS names of the subject alternative names.
+// [impl->swdd~grpc-server-extracts-cli-identity-from-certificate~1]
+pub fn certificate_identities(certificate_der: &[u8]) -> Result<Vec<String>, String> {
+    let (_, certificate) = X509Certificate::from_der(certificate_der)
+        .map_err(|err| format!("Could not parse the client certificate: '{}'", err))?;
+
+    let mut identities: Vec<String> = certificate
+        .subject()
+        .iter_common_name()
+        .filter_map(|common_name| common_name.as_str().ok())
+        .map(str::to_owned)
+        .collect();
+    identities.push(certificate.subject().to_string());
+
+    if let Some(subject_alt_names) = certificate
+        .subject_alternative_name()
+        .map_err(|err| format!("Could not get the subject alt names: '{}'", err))?
+    {
+        for name in subject_alt_names.value.general_names.iter() {
+            if let GeneralName::DNSName(dns_name) = name {
+                if !identities.iter().any(|identity| identity == dns_name) {
+                    identities.push(dns_name.to_string());
+                }
+            }
+        }
+    }
+    Ok(identities)
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::ank_base;
+    use common::objects::{AccessRightsRule, ControlInterfaceAccess, ReadWriteEnum, StateRule};
+    use x509_parser::pem::parse_x509_pem;
+
+    use super::{certificate_identities, CliRole, CliRoles};
+
+    const MONITORING_ROLE: &str = "monitoring";
+    const TEAM_A_ROLE: &str = "team_a";
+    const MONITORING_IDENTITY: &str = "prometheus";
+    const TEAM_A_IDENTITY: &str = "team-a.example.com";
+
+    // CN = ank, DNS.1 = ank
+    static TEST_CLI_CRT_PEM_CONTENT: &str = r#"-----BEGIN CERTIFICATE-----
+MIIBaTCCARugAwIBAgIUPVofRMnPbUO+G3SKGvzWuBJcK+YwBQYDK2VwMBUxEzAR
+BgNVBAMMCmFua2Fpb3MtY2EwHhcNMjQwODE2MDY1OTU2WhcNMzQwODE0MDY1OTU2
+WjAOMQwwCgYDVQQDDANhbmswKjAFBgMrZXADIQCeIh2/waLl3MQJoTq8n9zFIi58
+o0acX5ByX9IRDHBzG6OBgzCBgDAOBgNVHREEBzAFggNhbmswEwYDVR0lBAwwCgYI
+KwYBBQUHAwIwHQYDVR0OBBYEFNSsV4GwsSWTxecmQ5wvY99Ei5y+MDoGA1UdIwQz
+MDGhGaQXMBUxEzARBgNVBAMMCmFua2Fpb3MtY2GCFEx6qcLj6qpootqABRL9GCA0
+NVyGMAUGAytlcANBAHK4j36evJ3NVIz7K/AdUyz9ZYXwYecjV3BtZqwFF4dqJWqW
+yfe14kBC0Dk6J90fbktfPs9VT7VdJ3u4xhcddAQ=
+-----END CERTIFICATE-----"#;
+
+    fn state_rule(operation: ReadWriteEnum, filter_mask: &[&str]) -> AccessRightsRule {
+        AccessRightsRule::StateRule(StateRule {
+            operation,
+            filter_mask: filter_mask.iter().map(|x| x.to_string()).collect(),
+        })
+    }
+
+    fn test_roles() -> CliRoles {
+        CliRoles::new(HashMap::from([
+            (
+                MONITORING_ROLE.to_string(),
+                CliRole {
+                    identities: vec![MONITORING_IDENTITY.to_string()],
+                    access: ControlInterfaceAccess {
+                        allow_rules: vec![state_rule(ReadWriteEnum::Read, &["workloadStates"])],
+                        deny_rules: vec![],
+                    },
+                },
+            ),
+            (
+                TEAM_A_ROLE.to_string(),
+                CliRole {
+                    identities: vec![TEAM_A_IDENTITY.to_string()],
+                    access: ControlInterfaceAccess {
+                        allow_rules: vec![state_rule(
+                            ReadWriteEnum::ReadWrite,
+                            &["desiredState.workloads.teamA_*"],
+                        )],
+                        deny_rules: vec![state_rule(
+                            ReadWriteEnum::Write,
+                            &["desiredState.workloads.teamA_protected"],
+                        )],
+                    },
+                },
+            ),
+        ]))
+        .unwrap()
+    }
+
+    fn complete_state_request(field_mask: &[&str]) -> ank_base::Request {
+        ank_base::Request {
+            request_id: "request_id".to_string(),
+            request_content: Some(ank_base::request::RequestContent::CompleteStateRequest(
+                ank_base::CompleteStateRequest {
+                    field_mask: field_mask.iter().map(|x| x.to_string()).collect(),
+                    label_selector: None,
+                },
+            )),
+        }
+    }
+
+    fn update_state_request(update_mask: &[&str]) -> ank_base::Request {
+        ank_base::Request {
+            request_id: "request_id".to_string(),
+            request_content: Some(ank_base::request::RequestContent::UpdateStateRequest(
+                Box::new(ank_base::UpdateStateRequest {
+                    new_state: Some(ank_base::CompleteState::default()),
+                    update_mask: update_mask.iter().map(|x| x.to_string()).collect(),
+                    dry_run: false,
+                }),
+            )),
+        }
+    }
+
+    // [utest->swdd~grpc-server-maps-cli-identity-to-roles~1]
+    #[test]
+    fn utest_cli_roles_without_matching_identity_have_no_authorizer() {
+        let roles = test_roles();
+
+        assert!(roles.is_enabled());
+        assert!(roles.authorizer_for(&["unknown".to_string()]).is_none());
+        assert!(!CliRoles::default().is_enabled());
+    }
+
+    // [utest->swdd~grpc-server-maps-cli-identity-to-roles~1]
+    #[test]
+    fn utest_cli_roles_collects_all_matching_roles() {
+        let roles = CliRoles::new(HashMap::from([
+            (
+                "everyone".to_string(),
+                CliRole {
+                    identities: vec!["*".to_string()],
+                    ..Default::default()
+                },
+            ),
+            (
+                MONITORING_ROLE.to_string(),
+                CliRole {
+                    identities: vec![MONITORING_IDENTITY.to_string()],
+                    ..Default::default()
+                },
+            ),
+        ]))
+        .unwrap();
+
+        let authorizer = roles
+            .authorizer_for(&[MONITORING_IDENTITY.to_string()])
+            .unwrap();
+        assert_eq!(
+            authorizer.role_names(),
+            &["everyone".to_string(), MONITORING_ROLE.to_string()]
+        );
+    }
+
+    // [utest->swdd~grpc-server-maps-cli-identity-to-roles~1]
+    #[test]
+    fn utest_cli_roles_rejects_empty_filter_mask() {
+        let result = CliRoles::new(HashMap::from([(
+            MONITORING_ROLE.to_string(),
+            CliRole {
+                identities: vec![MONITORING_IDENTITY.to_string()],
+                access: ControlInterfaceAccess {
+                    allow_rules: vec![state_rule(ReadWriteEnum::Read, &[""])],
+                    deny_rules: vec![],
+                },
+            },
+        )]));
+
+        assert!(result.is_err());
+    }
+
+    // [utest->swdd~grpc-cli-connection-authorizes-requests~1]
+    #[test]
+    fn utest_cli_authorizer_allows_read_of_allowed_fields_only() {
+        let authorizer = test_roles()
+            .authorizer_for(&[MONITORING_IDENTITY.to_string()])
+            .unwrap();
+
+        assert!(authorizer.authorize(&complete_state_request(&["workloadStates"])));
+        assert!(authorizer.authorize(&complete_state_request(&["workloadStates.agent_A"])));
+        assert!(!authorizer.authorize(&complete_state_request(&[])));
+        assert!(!authorizer.authorize(&complete_state_request(&["desiredState"])));
+        assert!(!authorizer.authorize(&update_state_request(&["workloadStates"])));
+    }
+
+    // [utest->swdd~grpc-cli-connection-authorizes-requests~1]
+    #[test]
+    fn utest_cli_authorizer_applies_allow_and_deny_rules() {
+        let authorizer = test_roles()
+            .authorizer_for(&[TEAM_A_IDENTITY.to_string()])
+            .unwrap();
+
+        assert!(authorizer.authorize(&update_state_request(&[
+            "desiredState.workloads.teamA_nginx"
+        ])));
+        assert!(!authorizer.authorize(&update_state_request(&[
+            "desiredState.workloads.teamA_protected"
+        ])));
+        assert!(!authorizer.authorize(&update_state_request(&[
+            "desiredState.workloads.teamB_nginx"
+        ])));
+        assert!(!authorizer.authorize(&update_state_request(&[])));
+        assert!(authorizer.authorize(&complete_state_request(&[
+            "desiredState.workloads.teamA_protected"
+        ])));
+    }
+
+    // [utest->swdd~agent-authorizing-workload-description-requests~2]
+    #[test]
+    fn utest_cli_authorizer_denies_workload_description_with_denied_config() {
+        let roles = CliRoles::new(HashMap::from([(
+            TEAM_A_ROLE.to_string(),
+            CliRole {
+                identities: vec![TEAM_A_IDENTITY.to_string()],
+                access: ControlInterfaceAccess {
+                    allow_rules: vec![state_rule(
+                        ReadWriteEnum::Read,
+                        &["desiredState.workloads.teamA_*", "desiredState.configs.*"],
+                    )],
+                    deny_rules: vec![state_rule(
+                        ReadWriteEnum::Read,
+                        &["desiredState.configs.db_password"],
+                    )],
+                },
+            },
+        )]))
+        .unwrap();
+        let authorizer = roles
+            .authorizer_for(&[TEAM_A_IDENTITY.to_string()])
+            .unwrap();
+        let workload_description_request = |config_names: &[&str]| ank_base::Request {
+            request_id: "request_id".to_string(),
+            request_content: Some(
+                ank_base::request::RequestContent::WorkloadDescriptionRequest(
+                    ank_base::WorkloadDescriptionRequest {
+                        workload_name: "teamA_nginx".to_string(),
+                        config_names: config_names.iter().map(|x| x.to_string()).collect(),
+                    },
+                ),
+            ),
+        };
+
+        assert!(authorizer.authorize(&workload_description_request(&["db_host"])));
+        assert!(!authorizer.authorize(&workload_description_request(&["db_host", "db_password"])));
+    }
+
+    // [utest->swdd~grpc-cli-connection-authorizes-requests~1]
+    #[test]
+    fn utest_cli_authorizer_allows_request_allowed_by_any_role() {
+        let authorizer = test_roles()
+            .authorizer_for(&[MONITORING_IDENTITY.to_string(), TEAM_A_IDENTITY.to_string()])
+            .unwrap();
+
+        assert!(authorizer.authorize(&complete_state_request(&["workloadStates"])));
+        assert!(authorizer.authorize(&update_state_request(&[
+            "desiredState.workloads.teamA_nginx"
+        ])));
+    }
+
+    // [utest->swdd~grpc-server-extracts-cli-identity-from-certificate~1]
+    #[test]
+    fn utest_certificate_identities_contain_common_name_subject_and_dns_names() {
+        let (_, pem) = parse_x509_pem(TEST_CLI_CRT_PEM_CONTENT.as_bytes()).unwrap();
+
+        assert_eq!(
+            certificate_identities(&pem.contents),
+            Ok(vec!["ank".to_string(), "CN=ank".to_string()])
+        );
+    }
+
+    #[test]
+    fn utest_certificate_identities_fail_on_invalid_certificate() {
+        assert!(certificate_identities(b"no certificate").is_err());
+    }
+}
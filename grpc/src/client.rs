@@ -29,6 +29,8 @@ use common::from_server_interface::FromServerSender;
 use common::std_extensions::IllegalStateResult;
 use common::to_server_interface::ToServerReceiver;
 
+use std::collections::HashMap;
+
 use regex::Regex;
 use tokio::select;
 use tokio::sync::mpsc::Receiver;
@@ -50,6 +52,7 @@ pub struct GRPCCommunicationsClient {
     server_address: String,
     connection_type: ConnectionType,
     tls_config: Option<TLSConfig>,
+    labels: HashMap<String, String>,
 }
 
 fn get_server_url(server_address: &str, tls_config: &Option<TLSConfig>) -> String {
@@ -76,6 +79,7 @@ impl GRPCCommunicationsClient {
         name: String,
         server_address: String,
         tls_config: Option<TLSConfig>,
+        labels: HashMap<String, String>,
     ) -> Result<Self, CommunicationMiddlewareError> {
         verify_address_format(&server_address)?;
 
@@ -84,6 +88,7 @@ impl GRPCCommunicationsClient {
             server_address: get_server_url(&server_address, &tls_config),
             connection_type: ConnectionType::Agent,
             tls_config,
+            labels,
         })
     }
 
@@ -99,6 +104,7 @@ impl GRPCCommunicationsClient {
             server_address: get_server_url(&server_address, &tls_config),
             connection_type: ConnectionType::Cli,
             tls_config,
+            labels: HashMap::new(),
         })
     }
 }
@@ -125,8 +131,13 @@ impl CommunicationsClient for GRPCCommunicationsClient {
             }
 
             match self.connection_type {
+                // [impl->swdd~grpc-client-reports-closed-connection~1]
                 ConnectionType::Agent => {
-                    log::warn!("Connection to server interrupted: '{:?}'", result);
+                    if let Err(GrpcMiddlewareError::ConnectionClosed(reason)) = result {
+                        log::info!("The server closed the connection: '{reason}'. Reconnecting.");
+                    } else {
+                        log::warn!("Connection to server interrupted: '{:?}'", result);
+                    }
 
                     use tokio::time::{sleep, Duration};
                     sleep(Duration::from_secs(RECONNECT_TIMEOUT_SECONDS)).await;
@@ -147,6 +158,13 @@ impl CommunicationsClient for GRPCCommunicationsClient {
                                 "The connection to the Ankaios Server was interrupted: '{err}'"
                             );
                         }
+                        // [impl->swdd~grpc-client-reports-closed-connection~1]
+                        Err(GrpcMiddlewareError::ConnectionClosed(reason)) => {
+                            return Err(CommunicationMiddlewareError(format!(
+                                "The Ankaios server closed the connection: '{}'",
+                                reason
+                            )));
+                        }
                         Err(GrpcMiddlewareError::CertificateError(err)) => {
                             return Err(CommunicationMiddlewareError(format!(
                                 "Certificate error: '{}'.",
@@ -181,18 +199,25 @@ impl GRPCCommunicationsClient {
             tokio::sync::mpsc::channel::<grpc_api::ToServer>(common::CHANNEL_CAPACITY);
 
         // [impl->swdd~grpc-client-sends-supported-version~1]
+        // [impl->swdd~grpc-client-sends-agent-labels~1]
         match self.connection_type {
             ConnectionType::Agent => {
                 grpc_tx
                     .send(grpc_api::ToServer {
-                        to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(&self.name))),
+                        to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(
+                            &self.name,
+                            self.labels.clone(),
+                        ))),
                     })
                     .await?;
             }
             ConnectionType::Cli => {
+                // [impl->swdd~grpc-client-sends-session-name~1]
                 grpc_tx
                     .send(grpc_api::ToServer {
-                        to_server_enum: Some(ToServerEnum::CommanderHello(CommanderHello::new())),
+                        to_server_enum: Some(ToServerEnum::CommanderHello(CommanderHello::new(
+                            &self.name,
+                        ))),
                     })
                     .await?;
             }
@@ -213,7 +238,12 @@ impl GRPCCommunicationsClient {
             to_server_proxy::forward_from_ankaios_to_proto(grpc_tx, server_rx);
 
         select! {
-            _ = forward_exec_from_proto_task => {log::debug!("Forward from server message from proto to Ankaios task completed");}
+            forward_result = forward_exec_from_proto_task => {
+                log::debug!("Forward from server message from proto to Ankaios task completed");
+                if let Err(GrpcMiddlewareError::ConnectionClosed(reason)) = forward_result {
+                    return Err(GrpcMiddlewareError::ConnectionClosed(reason));
+                }
+            }
             _ = forward_to_server_from_ank_task => {log::debug!("Forward from server message from Ankaios to proto task completed");}
         };
 
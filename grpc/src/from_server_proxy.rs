@@ -31,6 +31,10 @@ use common::request_id_prepending::detach_prefix_from_request_id;
 
 use tonic::Streaming;
 
+const CONNECTION_CLOSED_REASON_SHUTDOWN: &str = "The Ankaios server is shutting down.";
+const CONNECTION_CLOSED_REASON_HEARTBEAT_TIMEOUT: &str =
+    "The Ankaios server did not receive a heartbeat in time.";
+
 pub struct GRPCFromServerStreaming {
     inner: Streaming<grpc_api::FromServer>,
 }
@@ -54,7 +58,13 @@ pub async fn forward_from_proto_to_ankaios(
     agent_tx: &FromServerSender,
 ) -> Result<(), GrpcMiddlewareError> {
     while let Some(value) = grpc_streaming.message().await? {
-        log::trace!("RESPONSE={:?}", value);
+        // The added workloads contain the rendered secrets and are only logged by the agent.
+        if !matches!(
+            value.from_server_enum,
+            Some(FromServerEnum::ServerHello(_) | FromServerEnum::UpdateWorkload(_))
+        ) {
+            log::trace!("RESPONSE={:?}", value);
+        }
 
         let try_block = async {
             match value
@@ -101,13 +111,36 @@ pub async fn forward_from_proto_to_ankaios(
                     // [impl->swdd~agent-adds-workload-prefix-id-control-interface-request~1]
                     agent_tx.response(response).await?;
                 }
+                FromServerEnum::WorkloadLogsRequest(obj) => {
+                    agent_tx
+                        .workload_logs_request(
+                            obj.try_into()
+                                .map_err(GrpcMiddlewareError::ConversionError)?,
+                        )
+                        .await?;
+                }
+                FromServerEnum::WorkloadLogsCancel(obj) => {
+                    agent_tx
+                        .workload_logs_cancel(obj.request_id, obj.agent_name)
+                        .await?;
+                }
+                // [impl->swdd~grpc-client-reports-closed-connection~1]
+                FromServerEnum::ConnectionClosed(obj) => {
+                    return Err(GrpcMiddlewareError::ConnectionClosed(obj.reason));
+                }
             }
             Ok(()) as Result<(), GrpcMiddlewareError>
         }
         .await;
 
-        if let Err::<(), GrpcMiddlewareError>(error) = try_block {
-            log::debug!("Could not forward from server message: {}", error);
+        match try_block {
+            Err::<(), GrpcMiddlewareError>(GrpcMiddlewareError::ConnectionClosed(reason)) => {
+                return Err(GrpcMiddlewareError::ConnectionClosed(reason));
+            }
+            Err(error) => {
+                log::debug!("Could not forward from server message: {}", error);
+            }
+            Ok(()) => {}
         }
     }
 
@@ -118,7 +151,7 @@ pub async fn forward_from_proto_to_ankaios(
 pub async fn forward_from_ankaios_to_proto(
     agent_senders: &AgentSendersMap,
     receiver: &mut FromServerReceiver,
-) {
+) -> Result<(), GrpcMiddlewareError> {
     while let Some(from_server_msg) = receiver.recv().await {
         match from_server_msg {
             FromServer::ServerHello(method_obj) => {
@@ -196,13 +229,95 @@ pub async fn forward_from_ankaios_to_proto(
                     log::warn!("Unknown agent with name: '{}'", agent_name);
                 }
             }
+            // [impl->swdd~grpc-server-forwards-workload-logs-requests-to-agent~1]
+            FromServer::WorkloadLogsRequest(workload_logs_request) => {
+                let agent_name = workload_logs_request.instance_name.agent_name().to_owned();
+                send_to_agent(
+                    agent_senders,
+                    &agent_name,
+                    FromServerEnum::WorkloadLogsRequest(workload_logs_request.into()),
+                )
+                .await;
+            }
+            // [impl->swdd~grpc-server-forwards-workload-logs-requests-to-agent~1]
+            FromServer::WorkloadLogsCancel(workload_logs_cancel) => {
+                let agent_name = workload_logs_cancel.agent_name.clone();
+                send_to_agent(
+                    agent_senders,
+                    &agent_name,
+                    FromServerEnum::WorkloadLogsCancel(workload_logs_cancel.into()),
+                )
+                .await;
+            }
+            // [impl->swdd~grpc-server-closes-connections-on-stop~1]
             FromServer::Stop(_method_obj) => {
-                log::debug!("Received Stop from server.");
-                // TODO: handle the call
-                break;
+                log::debug!("Received Stop from server. Closing all connections.");
+                close_all_connections(agent_senders).await;
+                return Ok(());
+            }
+            // [impl->swdd~grpc-server-closes-connection-of-disconnected-agent~1]
+            FromServer::DisconnectAgent(method_obj) => {
+                log::debug!(
+                    "Received DisconnectAgent for '{}' from server. Closing its connection.",
+                    method_obj.agent_name
+                );
+                close_connection(
+                    agent_senders,
+                    &method_obj.agent_name,
+                    CONNECTION_CLOSED_REASON_HEARTBEAT_TIMEOUT,
+                )
+                .await;
             }
         }
     }
+
+    Err(GrpcMiddlewareError::ConnectionInterrupted(
+        "Connection between Ankaios server and the communication middleware dropped.".into(),
+    ))
+}
+
+async fn close_all_connections(agent_senders: &AgentSendersMap) {
+    for connection_name in agent_senders.get_all_agent_names() {
+        close_connection(
+            agent_senders,
+            &connection_name,
+            CONNECTION_CLOSED_REASON_SHUTDOWN,
+        )
+        .await;
+    }
+}
+
+async fn close_connection(agent_senders: &AgentSendersMap, connection_name: &str, reason: &str) {
+    send_to_agent(
+        agent_senders,
+        connection_name,
+        FromServerEnum::ConnectionClosed(grpc_api::ConnectionClosed {
+            reason: reason.into(),
+        }),
+    )
+    .await;
+    // Removing the sender ends the response stream of the connection.
+    agent_senders.remove(connection_name);
+}
+
+async fn send_to_agent(
+    agent_senders: &AgentSendersMap,
+    agent_name: &str,
+    from_server_enum: FromServerEnum,
+) {
+    if let Some(sender) = agent_senders.get(agent_name) {
+        log::trace!("Sending to agent '{}': {:?}.", agent_name, from_server_enum);
+        let result = sender
+            .send(Ok(grpc_api::FromServer {
+                from_server_enum: Some(from_server_enum),
+            }))
+            .await;
+        if result.is_err() {
+            log::warn!("Could not send message to agent '{}'", agent_name);
+        }
+    } else {
+        log::warn!("Unknown agent with name: '{}'", agent_name);
+    }
 }
 
 // [impl->swdd~grpc-server-forwards-from-server-messages-to-grpc-client~1]
@@ -311,6 +426,7 @@ mod tests {
     use super::ank_base;
     use super::{forward_from_ankaios_to_proto, forward_from_proto_to_ankaios};
     use crate::grpc_api::{self, from_server::FromServerEnum, FromServer, UpdateWorkload};
+    use crate::grpc_middleware_error::GrpcMiddlewareError;
     use crate::{agent_senders_map::AgentSendersMap, from_server_proxy::GRPCStreaming};
     use api::ank_base::{response, WorkloadMap};
     use async_trait::async_trait;
@@ -402,7 +518,7 @@ mod tests {
 
         // The receiver in the agent receives the message and terminates the infinite waiting-loop.
         drop(to_manager);
-        join!(handle).0;
+        let _ = join!(handle).0;
 
         //if this returns the test is successful
         let result = agent_rx.recv().await.unwrap().unwrap();
@@ -414,6 +530,143 @@ mod tests {
         ))
     }
 
+    // [utest->swdd~grpc-server-forwards-workload-logs-requests-to-agent~1]
+    #[tokio::test]
+    async fn utest_from_server_proxy_forward_from_ankaios_to_proto_workload_logs_request() {
+        let agent = "agent_X";
+        let (to_manager, mut manager_receiver, _, mut agent_rx, agent_senders_map) =
+            create_test_setup(agent);
+
+        let workload_logs_request = common::commands::WorkloadLogsRequest {
+            request_id: "cli-conn-1@request_id".into(),
+            instance_name: common::objects::WorkloadInstanceName::builder()
+                .agent_name(agent)
+                .workload_name("workload_1")
+                .config(&"config".to_string())
+                .build(),
+            runtime: "my_runtime".into(),
+            follow: true,
+            since: None,
+            tail: Some(10),
+        };
+        to_manager
+            .workload_logs_request(workload_logs_request.clone())
+            .await
+            .unwrap();
+        to_manager
+            .workload_logs_cancel("cli-conn-1@request_id".into(), agent.into())
+            .await
+            .unwrap();
+        to_manager
+            .workload_logs_cancel("cli-conn-1@request_id".into(), "unknown_agent".into())
+            .await
+            .unwrap();
+
+        let handle = forward_from_ankaios_to_proto(&agent_senders_map, &mut manager_receiver);
+        drop(to_manager);
+        let _ = join!(handle).0;
+
+        let result = agent_rx.recv().await.unwrap().unwrap();
+        assert_eq!(
+            result.from_server_enum,
+            Some(FromServerEnum::WorkloadLogsRequest(
+                workload_logs_request.into()
+            ))
+        );
+        let result = agent_rx.recv().await.unwrap().unwrap();
+        assert!(matches!(
+            result.from_server_enum,
+            Some(FromServerEnum::WorkloadLogsCancel(_))
+        ));
+        assert!(matches!(agent_rx.try_recv(), Err(TryRecvError::Empty)));
+    }
+
+    // [utest->swdd~grpc-server-closes-connections-on-stop~1]
+    #[tokio::test]
+    async fn utest_from_server_proxy_forward_from_ankaios_to_proto_stop_closes_connections() {
+        let agent = "agent_X";
+        let cli_connection = "cli-conn-1";
+        let (to_manager, mut manager_receiver, _, mut agent_rx, agent_senders_map) =
+            create_test_setup(agent);
+        let (cli_tx, mut cli_rx) =
+            mpsc::channel::<Result<FromServer, tonic::Status>>(common::CHANNEL_CAPACITY);
+        agent_senders_map.insert(cli_connection, cli_tx);
+
+        to_manager.stop().await.unwrap();
+
+        let forward_result =
+            forward_from_ankaios_to_proto(&agent_senders_map, &mut manager_receiver).await;
+        assert!(forward_result.is_ok());
+
+        let expected_message = FromServer {
+            from_server_enum: Some(FromServerEnum::ConnectionClosed(
+                grpc_api::ConnectionClosed {
+                    reason: super::CONNECTION_CLOSED_REASON_SHUTDOWN.into(),
+                },
+            )),
+        };
+        assert_eq!(agent_rx.recv().await.unwrap().unwrap(), expected_message);
+        assert_eq!(cli_rx.recv().await.unwrap().unwrap(), expected_message);
+        assert!(agent_senders_map.get_all_agent_names().is_empty());
+    }
+
+    // [utest->swdd~grpc-server-closes-connection-of-disconnected-agent~1]
+    #[tokio::test]
+    async fn utest_from_server_proxy_forward_from_ankaios_to_proto_disconnect_agent() {
+        let agent = "agent_X";
+        let other_agent = "agent_Y";
+        let (to_manager, mut manager_receiver, _, mut agent_rx, agent_senders_map) =
+            create_test_setup(agent);
+        let (other_agent_tx, mut other_agent_rx) =
+            mpsc::channel::<Result<FromServer, tonic::Status>>(common::CHANNEL_CAPACITY);
+        agent_senders_map.insert(other_agent, other_agent_tx);
+
+        to_manager
+            .disconnect_agent(agent.to_string())
+            .await
+            .unwrap();
+        drop(to_manager);
+
+        let forward_result =
+            forward_from_ankaios_to_proto(&agent_senders_map, &mut manager_receiver).await;
+        assert!(forward_result.is_err());
+
+        assert_eq!(
+            agent_rx.recv().await.unwrap().unwrap(),
+            FromServer {
+                from_server_enum: Some(FromServerEnum::ConnectionClosed(
+                    grpc_api::ConnectionClosed {
+                        reason: super::CONNECTION_CLOSED_REASON_HEARTBEAT_TIMEOUT.into(),
+                    },
+                )),
+            }
+        );
+        assert!(matches!(
+            other_agent_rx.try_recv(),
+            Err(TryRecvError::Empty)
+        ));
+        assert_eq!(
+            agent_senders_map.get_all_agent_names(),
+            vec![other_agent.to_string()]
+        );
+    }
+
+    // [utest->swdd~grpc-server-forwards-from-server-messages-to-grpc-client~1]
+    #[tokio::test]
+    async fn utest_from_server_proxy_forward_from_ankaios_to_proto_fails_on_dropped_channel() {
+        let (to_manager, mut manager_receiver, _, _agent_rx, agent_senders_map) =
+            create_test_setup("agent_X");
+        drop(to_manager);
+
+        let forward_result =
+            forward_from_ankaios_to_proto(&agent_senders_map, &mut manager_receiver).await;
+
+        assert!(matches!(
+            forward_result,
+            Err(GrpcMiddlewareError::ConnectionInterrupted(_))
+        ));
+    }
+
     #[tokio::test]
     async fn utest_from_server_proxy_forward_from_ankaios_to_proto_update_workload_state() {
         let (to_manager, mut manager_receiver, _, mut agent_rx, agent_senders_map) =
@@ -434,7 +687,7 @@ mod tests {
 
         // The receiver in the agent receives the message and terminates the infinite waiting-loop.
         drop(to_manager);
-        join!(handle).0;
+        let _ = join!(handle).0;
 
         //if this returns the test is successful
         let result = agent_rx.recv().await.unwrap().unwrap();
@@ -593,6 +846,42 @@ mod tests {
     }
 
     // [utest->swdd~grpc-client-forwards-from-server-messages-to-agent~1]
+    // [utest->swdd~grpc-client-reports-closed-connection~1]
+    #[tokio::test]
+    async fn utest_from_server_proxy_forward_from_proto_to_ankaios_connection_closed() {
+        let (to_agent, mut agent_receiver) =
+            mpsc::channel::<common::from_server_interface::FromServer>(common::CHANNEL_CAPACITY);
+
+        let mut mock_grpc_ex_request_streaming =
+            MockGRPCFromServerStreaming::new(LinkedList::from([
+                Some(FromServer {
+                    from_server_enum: Some(FromServerEnum::ConnectionClosed(
+                        grpc_api::ConnectionClosed {
+                            reason: "shutting down".into(),
+                        },
+                    )),
+                }),
+                Some(FromServer {
+                    from_server_enum: Some(FromServerEnum::UpdateWorkloadState(
+                        grpc_api::UpdateWorkloadState::default(),
+                    )),
+                }),
+                None,
+            ]));
+
+        let forward_result =
+            forward_from_proto_to_ankaios(&mut mock_grpc_ex_request_streaming, &to_agent).await;
+
+        assert!(matches!(
+            forward_result,
+            Err(GrpcMiddlewareError::ConnectionClosed(reason)) if reason == "shutting down"
+        ));
+        assert!(matches!(
+            agent_receiver.try_recv(),
+            Err(TryRecvError::Empty)
+        ));
+    }
+
     #[tokio::test]
     async fn utest_from_server_proxy_forward_from_proto_to_ankaios_update_workload_state() {
         let (to_agent, mut agent_receiver) =
@@ -732,7 +1021,7 @@ mod tests {
 
         // The receiver in the agent receives the message and terminates the infinite waiting-loop.
         drop(to_manager);
-        join!(handle).0;
+        let _ = join!(handle).0;
 
         //if this returns the test is successful
         let result = agent_rx.recv().await.unwrap().unwrap();
@@ -14,6 +14,7 @@
 
 use std::pin::Pin;
 
+use async_trait::async_trait;
 use common::check_version_compatibility;
 use common::std_extensions::GracefulExitResult;
 use tokio::sync::mpsc::Sender;
@@ -27,6 +28,8 @@ use x509_parser::der_parser::asn1_rs::FromDer;
 use x509_parser::extensions::GeneralName;
 
 use crate::agent_senders_map::AgentSendersMap;
+use crate::ankaios_streaming::GRPCStreaming;
+use crate::cli_roles::certificate_identities;
 use crate::grpc_api::{self, agent_connection_server::AgentConnection, to_server::ToServerEnum};
 use crate::to_server_proxy::{forward_from_proto_to_ankaios, GRPCToServerStreaming};
 use common::to_server_interface::{self, ToServerInterface};
@@ -35,16 +38,83 @@ use common::to_server_interface::{self, ToServerInterface};
 pub struct GRPCAgentConnection {
     agent_senders: AgentSendersMap,
     to_ankaios_server: Sender<to_server_interface::ToServer>,
+    bind_agent_names: bool,
 }
 
 impl GRPCAgentConnection {
     pub fn new(
         agent_senders: AgentSendersMap,
         to_ankaios_server: Sender<to_server_interface::ToServer>,
+        bind_agent_names: bool,
     ) -> Self {
         Self {
             agent_senders,
             to_ankaios_server,
+            bind_agent_names,
+        }
+    }
+}
+
+// Rejects the workload states and load status an agent reports for other agents.
+// Without a bound agent name all messages are handed over unchanged.
+// [impl->swdd~grpc-agent-connection-rejects-workload-states-of-other-agents~1]
+// [impl->swdd~grpc-agent-connection-rejects-load-status-of-other-agents~1]
+struct AgentNameBoundStreaming<S> {
+    inner: S,
+    bound_agent_name: Option<String>,
+}
+
+#[async_trait]
+impl<S> GRPCStreaming<grpc_api::ToServer> for AgentNameBoundStreaming<S>
+where
+    S: GRPCStreaming<grpc_api::ToServer> + Send,
+{
+    async fn message(&mut self) -> Result<Option<grpc_api::ToServer>, Status> {
+        loop {
+            let message = self.inner.message().await?;
+            let Some(agent_name) = &self.bound_agent_name else {
+                return Ok(message);
+            };
+
+            match &message {
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::UpdateWorkloadState(update_workload_state)),
+                }) => {
+                    let foreign_workloads: Vec<String> = update_workload_state
+                        .workload_states
+                        .iter()
+                        .filter_map(|workload_state| workload_state.instance_name.as_ref())
+                        .filter(|instance_name| instance_name.agent_name != *agent_name)
+                        .map(|instance_name| {
+                            format!(
+                                "{}.{}",
+                                instance_name.workload_name, instance_name.agent_name
+                            )
+                        })
+                        .collect();
+                    if foreign_workloads.is_empty() {
+                        return Ok(message);
+                    }
+
+                    log::warn!(
+                        "Rejected UpdateWorkloadState from agent '{}' as it contains states of workloads of other agents: {:?}",
+                        agent_name,
+                        foreign_workloads
+                    );
+                }
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::AgentLoadStatus(agent_load_status)),
+                }) if agent_load_status.agent_name != *agent_name => {
+                    log::warn!(
+                        "Rejected AgentLoadStatus from agent '{}' as it is sent for agent '{}'",
+                        agent_name,
+                        agent_load_status.agent_name
+                    );
+                }
+                // Responses are forwarded with the bound agent name as connection name
+                // and the server only accepts them for the log campaigns of this agent.
+                _ => return Ok(message),
+            }
         }
     }
 }
@@ -64,6 +134,7 @@ impl AgentConnection for GRPCAgentConnection {
         request: Request<tonic::Streaming<grpc_api::ToServer>>,
     ) -> Result<Response<Self::ConnectAgentStream>, Status> {
         let mut sans: Vec<String> = vec![];
+        let mut bound_identities: Option<Vec<String>> = None;
         if let Some(peer_certs) = &request.peer_certs() {
             if has_multiple_peer_certs(peer_certs) {
                 return Err(Status::unauthenticated(
@@ -72,6 +143,16 @@ impl AgentConnection for GRPCAgentConnection {
             }
 
             let client_cert = &peer_certs[0];
+            if self.bind_agent_names {
+                // The complete subject and the wildcard are not valid agent names.
+                bound_identities = Some(
+                    certificate_identities(client_cert.as_ref())
+                        .map_err(Status::unauthenticated)?
+                        .into_iter()
+                        .filter(|identity| identity != "*" && !identity.contains('='))
+                        .collect(),
+                );
+            }
             let client_cert = X509Certificate::from_der(client_cert.as_ref())
                 .unwrap_or_exit("Could not parse certificate.")
                 .1;
@@ -119,6 +200,7 @@ impl AgentConnection for GRPCAgentConnection {
             ToServerEnum::AgentHello(grpc_api::AgentHello {
                 agent_name,
                 protocol_version,
+                labels,
             }) => {
                 log::trace!("Received a hello from '{}'", agent_name);
 
@@ -127,22 +209,44 @@ impl AgentConnection for GRPCAgentConnection {
                     log::warn!("Refused connection from agent '{agent_name}' due to unsupported version: '{protocol_version}'");
                     Status::failed_precondition(err)})?;
 
+                // [impl->swdd~grpc-agent-connection-binds-agent-name-to-certificate~1]
+                if let Some(identities) = &bound_identities {
+                    if !identities.contains(&agent_name) {
+                        log::warn!(
+                            "Refused connection from agent '{agent_name}' as the name does not match the names {:?} of the agent certificate",
+                            identities
+                        );
+                        return Err(Status::permission_denied(format!(
+                            "Agent name '{agent_name}' does not match the names {:?} of the agent certificate!",
+                            identities
+                        )));
+                    }
+                }
+
                 if sans.is_empty()
                     || sans.contains(&agent_name)
                     || sans.contains(&String::from("*"))
                 {
                     // [impl->swdd~grpc-agent-connection-stores-from-server-channel-tx~1]
-                    self.agent_senders
+                    let connection_id = self
+                        .agent_senders
                         .insert(&agent_name, new_agent_sender.to_owned());
                     // [impl->swdd~grpc-agent-connection-forwards-hello-to-ankaios-server~1]
-                    if let Err(error) = self.to_ankaios_server.agent_hello(agent_name.clone()).await
+                    if let Err(error) = self
+                        .to_ankaios_server
+                        .agent_hello(agent_name.clone(), labels)
+                        .await
                     {
                         log::error!("Could not send agent hello: '{error}'");
                     }
 
                     // [impl->swdd~grpc-agent-connection-forwards-commands-to-server~1]
+                    let bound_agent_name = bound_identities.is_some().then(|| agent_name.clone());
                     let _x = tokio::spawn(async move {
-                        let mut stream = GRPCToServerStreaming::new(stream);
+                        let mut stream = AgentNameBoundStreaming {
+                            inner: GRPCToServerStreaming::new(stream),
+                            bound_agent_name,
+                        };
                         if let Err(error) = forward_from_proto_to_ankaios(
                             agent_name.clone(),
                             &mut stream,
@@ -156,7 +260,14 @@ impl AgentConnection for GRPCAgentConnection {
                                 error
                             );
 
-                            agent_senders.remove(&agent_name);
+                            // [impl->swdd~grpc-agent-connection-removes-only-own-sender~1]
+                            if !agent_senders.remove_connection(&agent_name, connection_id) {
+                                log::debug!(
+                                    "The interrupted connection of agent '{}' is not the current one. Keeping the agent sender.",
+                                    agent_name
+                                );
+                                return;
+                            }
                             log::trace!(
                             "The connection is interrupted or has been closed. Deleting the agent sender '{}'",
                             agent_name
@@ -191,3 +302,190 @@ impl AgentConnection for GRPCAgentConnection {
 fn invalid_argument_empty() -> Status {
     Status::invalid_argument("Empty")
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use api::ank_base;
+    use async_trait::async_trait;
+
+    use common::commands::ConnectionResponse;
+    use common::to_server_interface::ToServer;
+
+    use super::AgentNameBoundStreaming;
+    use crate::ankaios_streaming::GRPCStreaming;
+    use crate::grpc_api::{self, to_server::ToServerEnum};
+    use crate::to_server_proxy::forward_from_proto_to_ankaios;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+
+    struct MockGRPCToServerStreaming {
+        msgs: VecDeque<grpc_api::ToServer>,
+    }
+
+    #[async_trait]
+    impl GRPCStreaming<grpc_api::ToServer> for MockGRPCToServerStreaming {
+        async fn message(&mut self) -> Result<Option<grpc_api::ToServer>, tonic::Status> {
+            Ok(self.msgs.pop_front())
+        }
+    }
+
+    fn update_workload_state(agent_names: &[&str]) -> grpc_api::ToServer {
+        grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::UpdateWorkloadState(
+                grpc_api::UpdateWorkloadState {
+                    workload_states: agent_names
+                        .iter()
+                        .map(|agent_name| ank_base::WorkloadState {
+                            instance_name: Some(ank_base::WorkloadInstanceName {
+                                workload_name: "nginx".into(),
+                                agent_name: agent_name.to_string(),
+                                id: "1234".into(),
+                            }),
+                            execution_state: None,
+                        })
+                        .collect(),
+                },
+            )),
+        }
+    }
+
+    fn agent_load_status(agent_name: &str) -> grpc_api::ToServer {
+        grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::AgentLoadStatus(grpc_api::AgentLoadStatus {
+                agent_name: agent_name.to_string(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn streaming(
+        bound_agent_name: Option<&str>,
+        msgs: Vec<grpc_api::ToServer>,
+    ) -> AgentNameBoundStreaming<MockGRPCToServerStreaming> {
+        AgentNameBoundStreaming {
+            inner: MockGRPCToServerStreaming { msgs: msgs.into() },
+            bound_agent_name: bound_agent_name.map(str::to_owned),
+        }
+    }
+
+    // [utest->swdd~grpc-agent-connection-rejects-workload-states-of-other-agents~1]
+    #[tokio::test]
+    async fn utest_agent_name_bound_streaming_forwards_own_workload_states() {
+        let mut streaming = streaming(Some(AGENT_A), vec![update_workload_state(&[AGENT_A])]);
+
+        assert_eq!(
+            streaming.message().await.unwrap(),
+            Some(update_workload_state(&[AGENT_A]))
+        );
+        assert_eq!(streaming.message().await.unwrap(), None);
+    }
+
+    // [utest->swdd~grpc-agent-connection-rejects-workload-states-of-other-agents~1]
+    #[tokio::test]
+    async fn utest_agent_name_bound_streaming_rejects_workload_states_of_other_agents() {
+        let mut streaming = streaming(
+            Some(AGENT_A),
+            vec![
+                update_workload_state(&[AGENT_A, AGENT_B]),
+                update_workload_state(&[AGENT_B]),
+                update_workload_state(&[AGENT_A]),
+            ],
+        );
+
+        assert_eq!(
+            streaming.message().await.unwrap(),
+            Some(update_workload_state(&[AGENT_A]))
+        );
+        assert_eq!(streaming.message().await.unwrap(), None);
+    }
+
+    // [utest->swdd~grpc-agent-connection-rejects-workload-states-of-other-agents~1]
+    #[tokio::test]
+    async fn utest_agent_name_bound_streaming_without_bound_agent_name_forwards_all() {
+        let mut streaming = streaming(None, vec![update_workload_state(&[AGENT_B])]);
+
+        assert_eq!(
+            streaming.message().await.unwrap(),
+            Some(update_workload_state(&[AGENT_B]))
+        );
+    }
+
+    // [utest->swdd~grpc-agent-connection-rejects-workload-states-of-other-agents~1]
+    #[tokio::test]
+    async fn utest_agent_name_bound_streaming_forwards_other_messages() {
+        let goodbye = grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::Goodbye(grpc_api::Goodbye {})),
+        };
+        let mut streaming = streaming(Some(AGENT_A), vec![goodbye.clone()]);
+
+        assert_eq!(streaming.message().await.unwrap(), Some(goodbye));
+    }
+
+    // [utest->swdd~grpc-agent-connection-rejects-load-status-of-other-agents~1]
+    #[tokio::test]
+    async fn utest_agent_name_bound_streaming_rejects_load_status_of_other_agents() {
+        let mut streaming = streaming(
+            Some(AGENT_A),
+            vec![agent_load_status(AGENT_B), agent_load_status(AGENT_A)],
+        );
+
+        assert_eq!(
+            streaming.message().await.unwrap(),
+            Some(agent_load_status(AGENT_A))
+        );
+        assert_eq!(streaming.message().await.unwrap(), None);
+    }
+
+    // [utest->swdd~grpc-agent-connection-rejects-load-status-of-other-agents~1]
+    #[tokio::test]
+    async fn utest_agent_name_bound_streaming_without_bound_agent_name_forwards_load_status() {
+        let mut streaming = streaming(None, vec![agent_load_status(AGENT_B)]);
+
+        assert_eq!(
+            streaming.message().await.unwrap(),
+            Some(agent_load_status(AGENT_B))
+        );
+    }
+
+    // [utest->swdd~grpc-agent-connection-forwards-responses-to-server~2]
+    #[tokio::test]
+    async fn utest_agent_name_bound_streaming_forwards_responses_with_bound_agent_name() {
+        let response = ank_base::Response {
+            request_id: "cli-conn-1@request_id".into(),
+            response_content: Some(ank_base::response::ResponseContent::LogsStopResponse(
+                ank_base::LogsStopResponse::default(),
+            )),
+        };
+        let mut streaming = streaming(
+            Some(AGENT_A),
+            vec![grpc_api::ToServer {
+                to_server_enum: Some(ToServerEnum::Response(response.clone())),
+            }],
+        );
+        let (server_tx, mut server_rx) = tokio::sync::mpsc::channel(1);
+
+        assert!(
+            forward_from_proto_to_ankaios(AGENT_A.to_string(), &mut streaming, server_tx)
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            server_rx.recv().await,
+            Some(ToServer::Response(ConnectionResponse {
+                connection_name: AGENT_A.to_string(),
+                response,
+            }))
+        );
+    }
+}
@@ -22,10 +22,11 @@ use std::collections::HashMap;
 tonic::include_proto!("grpc_api"); // The string specified here must match the proto package name
 
 impl AgentHello {
-    pub fn new(agent_name: impl Into<String>) -> Self {
+    pub fn new(agent_name: impl Into<String>, labels: HashMap<String, String>) -> Self {
         AgentHello {
             agent_name: agent_name.into(),
             protocol_version: common::ANKAIOS_VERSION.into(),
+            labels,
         }
     }
 }
@@ -34,14 +35,16 @@ impl From<AgentHello> for commands::AgentHello {
     fn from(item: AgentHello) -> Self {
         commands::AgentHello {
             agent_name: item.agent_name,
+            labels: item.labels,
         }
     }
 }
 
 impl CommanderHello {
-    pub fn new() -> Self {
+    pub fn new(session_name: &str) -> Self {
         CommanderHello {
             protocol_version: common::ANKAIOS_VERSION.into(),
+            session_name: session_name.to_owned(),
         }
     }
 }
@@ -126,9 +129,68 @@ impl TryFrom<from_server_interface::FromServer> for FromServer {
             from_server_interface::FromServer::Response(response) => Ok(FromServer {
                 from_server_enum: Some(from_server::FromServerEnum::Response(response)),
             }),
+            from_server_interface::FromServer::WorkloadLogsRequest(ankaios) => Ok(FromServer {
+                from_server_enum: Some(from_server::FromServerEnum::WorkloadLogsRequest(
+                    ankaios.into(),
+                )),
+            }),
+            from_server_interface::FromServer::WorkloadLogsCancel(ankaios) => Ok(FromServer {
+                from_server_enum: Some(from_server::FromServerEnum::WorkloadLogsCancel(
+                    ankaios.into(),
+                )),
+            }),
             from_server_interface::FromServer::Stop(_) => {
                 Err("Stop command not implemented in proto")
             }
+            from_server_interface::FromServer::DisconnectAgent(_) => {
+                Err("DisconnectAgent command not implemented in proto")
+            }
+        }
+    }
+}
+
+impl From<commands::WorkloadLogsRequest> for WorkloadLogsRequest {
+    fn from(item: commands::WorkloadLogsRequest) -> Self {
+        WorkloadLogsRequest {
+            request_id: item.request_id,
+            instance_name: Some(item.instance_name.into()),
+            runtime: item.runtime,
+            follow: item.follow,
+            since: item.since,
+            tail: item.tail,
+        }
+    }
+}
+
+impl TryFrom<WorkloadLogsRequest> for commands::WorkloadLogsRequest {
+    type Error = String;
+
+    fn try_from(item: WorkloadLogsRequest) -> Result<Self, Self::Error> {
+        Ok(commands::WorkloadLogsRequest {
+            request_id: item.request_id,
+            instance_name: item.instance_name.ok_or("No instance name")?.into(),
+            runtime: item.runtime,
+            follow: item.follow,
+            since: item.since,
+            tail: item.tail,
+        })
+    }
+}
+
+impl From<commands::WorkloadLogsCancel> for WorkloadLogsCancel {
+    fn from(item: commands::WorkloadLogsCancel) -> Self {
+        WorkloadLogsCancel {
+            request_id: item.request_id,
+            agent_name: item.agent_name,
+        }
+    }
+}
+
+impl From<WorkloadLogsCancel> for commands::WorkloadLogsCancel {
+    fn from(item: WorkloadLogsCancel) -> Self {
+        commands::WorkloadLogsCancel {
+            request_id: item.request_id,
+            agent_name: item.agent_name,
         }
     }
 }
@@ -188,6 +250,8 @@ impl TryFrom<AddedWorkload> for objects::WorkloadSpec {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            suspended: workload.suspended,
+            restart_generation: workload.restart_generation,
         })
     }
 }
@@ -208,6 +272,8 @@ impl From<objects::WorkloadSpec> for AddedWorkload {
             tags: workload.tags.into_iter().map(|x| x.into()).collect(),
             files: workload.files.into_iter().map(Into::into).collect(),
             control_interface_access: workload.control_interface_access.into(),
+            suspended: workload.suspended,
+            restart_generation: workload.restart_generation,
         }
     }
 }
@@ -235,9 +301,15 @@ impl TryFrom<ToServer> for to_server_interface::ToServer {
             ToServerEnum::Request(protobuf) => {
                 to_server_interface::ToServer::Request(protobuf.try_into()?)
             }
-            ToServerEnum::Goodbye(_) => {
-                to_server_interface::ToServer::Goodbye(commands::Goodbye {})
+            ToServerEnum::Response(protobuf) => {
+                to_server_interface::ToServer::Response(commands::ConnectionResponse {
+                    connection_name: String::default(),
+                    response: protobuf,
+                })
             }
+            ToServerEnum::Goodbye(_) => to_server_interface::ToServer::Goodbye(commands::Goodbye {
+                connection_name: String::default(),
+            }),
         })
     }
 }
@@ -295,7 +367,7 @@ mod tests {
     use crate::{
         from_server::FromServerEnum, generate_test_proto_deleted_workload, to_server::ToServerEnum,
         AddedWorkload, AgentHello, AgentLoadStatus, DeletedWorkload, FromServer, ToServer,
-        UpdateWorkload, UpdateWorkloadState,
+        UpdateWorkload, UpdateWorkloadState, WorkloadLogsCancel, WorkloadLogsRequest,
     };
 
     use api::ank_base::{self, Dependencies};
@@ -320,12 +392,17 @@ mod tests {
     #[test]
     fn utest_convert_proto_to_server_agent_hello() {
         let agent_name = "agent_A".to_string();
+        let labels = HashMap::from([("zone".to_string(), "front".to_string())]);
 
         let proto_request = ToServer {
-            to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(&agent_name))),
+            to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(
+                &agent_name,
+                labels.clone(),
+            ))),
         };
 
-        let ankaios_command = ankaios::ToServer::AgentHello(ankaios::AgentHello { agent_name });
+        let ankaios_command =
+            ankaios::ToServer::AgentHello(ankaios::AgentHello { agent_name, labels });
 
         assert_eq!(
             ankaios::ToServer::try_from(proto_request),
@@ -400,6 +477,7 @@ mod tests {
                         },
                         ..Default::default()
                     },
+                    dry_run: false,
                 },
             )),
         };
@@ -435,6 +513,7 @@ mod tests {
                             "test_workload",
                             workloads,
                         )])),
+                        dry_run: false,
                     }),
                 )),
             })),
@@ -454,6 +533,7 @@ mod tests {
                 request_content: Some(ank_base::request::RequestContent::CompleteStateRequest(
                     ank_base::CompleteStateRequest {
                         field_mask: field_mask.clone(),
+                        label_selector: None,
                     },
                 )),
             })),
@@ -462,7 +542,10 @@ mod tests {
         let ankaios_command = ankaios::ToServer::Request(ankaios::Request {
             request_id,
             request_content: ankaios::RequestContent::CompleteStateRequest(
-                ankaios::CompleteStateRequest { field_mask },
+                ankaios::CompleteStateRequest {
+                    field_mask,
+                    label_selector: None,
+                },
             ),
         });
 
@@ -551,6 +634,85 @@ mod tests {
 
         assert_eq!(FromServer::try_from(ankaios_msg), proto_msg);
     }
+    #[test]
+    fn utest_convert_from_server_to_proto_workload_logs_request() {
+        let instance_name = generate_test_workload_spec().instance_name;
+        let ankaios_request = ankaios::WorkloadLogsRequest {
+            request_id: "req_id".to_owned(),
+            instance_name: instance_name.clone(),
+            runtime: "runtime".to_owned(),
+            follow: true,
+            since: Some("10m".to_owned()),
+            tail: None,
+        };
+        let proto_request = WorkloadLogsRequest {
+            request_id: "req_id".to_owned(),
+            instance_name: Some(instance_name.into()),
+            runtime: "runtime".to_owned(),
+            follow: true,
+            since: Some("10m".to_owned()),
+            tail: None,
+        };
+
+        assert_eq!(
+            FromServer::try_from(ankaios::FromServer::WorkloadLogsRequest(
+                ankaios_request.clone()
+            )),
+            Ok(FromServer {
+                from_server_enum: Some(FromServerEnum::WorkloadLogsRequest(proto_request.clone())),
+            })
+        );
+        assert_eq!(
+            ankaios::WorkloadLogsRequest::try_from(proto_request),
+            Ok(ankaios_request)
+        );
+    }
+
+    #[test]
+    fn utest_convert_from_server_to_proto_workload_logs_cancel() {
+        let ankaios_cancel = ankaios::WorkloadLogsCancel {
+            request_id: "req_id".to_owned(),
+            agent_name: "agent".to_owned(),
+        };
+        let proto_cancel = WorkloadLogsCancel {
+            request_id: "req_id".to_owned(),
+            agent_name: "agent".to_owned(),
+        };
+
+        assert_eq!(
+            FromServer::try_from(ankaios::FromServer::WorkloadLogsCancel(
+                ankaios_cancel.clone()
+            )),
+            Ok(FromServer {
+                from_server_enum: Some(FromServerEnum::WorkloadLogsCancel(proto_cancel.clone())),
+            })
+        );
+        assert_eq!(
+            ankaios::WorkloadLogsCancel::from(proto_cancel),
+            ankaios_cancel
+        );
+    }
+
+    #[test]
+    fn utest_convert_proto_to_server_response() {
+        let proto_response = ank_base::Response {
+            request_id: "req_id".to_owned(),
+            response_content: Some(ank_base::response::ResponseContent::LogEntriesResponse(
+                ank_base::LogEntriesResponse::default(),
+            )),
+        };
+
+        assert_eq!(
+            ankaios::ToServer::try_from(ToServer {
+                to_server_enum: Some(ToServerEnum::Response(proto_response.clone())),
+            }),
+            Ok(ankaios::ToServer::Response(ankaios::ConnectionResponse {
+                connection_name: String::default(),
+                response: proto_response,
+            }))
+        );
+    }
+
     ///////////////////////////////////////////////////////////////////////////
     // WorkloadSpec tests
     ///////////////////////////////////////////////////////////////////////////
@@ -624,6 +786,8 @@ mod tests {
                     )),
                 },
             ],
+            suspended: false,
+            restart_generation: 0,
         };
 
         assert_eq!(AddedWorkload::from(workload_spec), proto_workload);
@@ -652,6 +816,8 @@ mod tests {
             runtime_config: String::from("some config"),
             control_interface_access: Default::default(),
             files: generate_test_rendered_workload_files(),
+            suspended: false,
+            restart_generation: 0,
         };
 
         let proto_workload = AddedWorkload {
@@ -687,6 +853,8 @@ mod tests {
                     )),
                 },
             ],
+            suspended: false,
+            restart_generation: 0,
         };
 
         assert_eq!(
@@ -719,6 +887,8 @@ mod tests {
             tags: vec![],
             control_interface_access: Default::default(),
             files: Default::default(),
+            suspended: false,
+            restart_generation: 0,
         };
 
         assert!(ankaios::WorkloadSpec::try_from(proto_workload).is_err());
@@ -13,8 +13,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::pin::Pin;
+use std::sync::Arc;
 
-use common::{check_version_compatibility, to_server_interface};
+use api::ank_base;
+use async_trait::async_trait;
+use common::{
+    check_version_compatibility,
+    to_server_interface::{self, ToServerInterface},
+};
 use tokio::sync::mpsc::Sender;
 use tokio_stream::wrappers::ReceiverStream;
 
@@ -22,6 +28,8 @@ use tonic::codegen::futures_core::Stream;
 use tonic::{Request, Response, Status};
 
 use crate::agent_senders_map::AgentSendersMap;
+use crate::ankaios_streaming::GRPCStreaming;
+use crate::cli_roles::{certificate_identities, CliAuthorizer, CliRoles};
 use crate::to_server::ToServerEnum;
 use crate::to_server_proxy::{forward_from_proto_to_ankaios, GRPCToServerStreaming};
 use grpc_api::cli_connection_server::CliConnection;
@@ -32,20 +40,111 @@ use crate::grpc_api;
 pub struct GRPCCliConnection {
     cli_senders: AgentSendersMap,
     to_ankaios_server: Sender<to_server_interface::ToServer>,
+    cli_roles: Arc<CliRoles>,
 }
 
 impl GRPCCliConnection {
     pub fn new(
         cli_senders: AgentSendersMap,
         to_ankaios_server: Sender<to_server_interface::ToServer>,
+        cli_roles: Arc<CliRoles>,
     ) -> Self {
         Self {
             cli_senders,
             to_ankaios_server,
+            cli_roles,
         }
     }
 }
 
+// Answers the requests denied by the roles of the CLI directly,
+// all other messages are handed over to the forwarding to the Ankaios server.
+// [impl->swdd~grpc-cli-connection-authorizes-requests~1]
+struct AuthorizingCliStreaming<S> {
+    inner: S,
+    authorizer: Option<CliAuthorizer>,
+    cli_sender: Sender<Result<grpc_api::FromServer, Status>>,
+}
+
+#[async_trait]
+impl<S> GRPCStreaming<grpc_api::ToServer> for AuthorizingCliStreaming<S>
+where
+    S: GRPCStreaming<grpc_api::ToServer> + Send,
+{
+    async fn message(&mut self) -> Result<Option<grpc_api::ToServer>, Status> {
+        loop {
+            let message = self.inner.message().await?;
+            let Some(authorizer) = &self.authorizer else {
+                return Ok(message);
+            };
+            let request = match &message {
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Request(request)),
+                }) => request,
+                None
+                | Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Goodbye(_)),
+                }) => return Ok(message),
+                // The other messages, e.g. workload states and agent load status, are reserved for the agents.
+                // [impl->swdd~grpc-cli-connection-drops-agent-messages-of-restricted-clis~1]
+                Some(_) => {
+                    log::warn!(
+                        "Dropping a message other than a request of the CLI with the roles {:?}",
+                        authorizer.role_names()
+                    );
+                    continue;
+                }
+            };
+            if authorizer.authorize(request) {
+                return Ok(message);
+            }
+
+            log::info!(
+                "Denying request '{}' of the CLI with the roles {:?}",
+                request.request_id,
+                authorizer.role_names()
+            );
+            self.cli_sender
+                .send(Ok(grpc_api::FromServer {
+                    from_server_enum: Some(grpc_api::from_server::FromServerEnum::Response(
+                        ank_base::Response {
+                            request_id: request.request_id.clone(),
+                            response_content: Some(ank_base::response::ResponseContent::Error(
+                                ank_base::Error {
+                                    message: "Access denied".into(),
+                                    ..Default::default()
+                                },
+                            )),
+                        },
+                    )),
+                }))
+                .await
+                .map_err(|_| Status::unavailable("The CLI connection is closed"))?;
+        }
+    }
+}
+
+// The session name identifies the CLI in the request ids and thus in the audit log of the server.
+// As the request ids are split at '@', only a restricted set of characters is kept.
+// [impl->swdd~grpc-cli-connection-name-contains-session-name~1]
+fn cli_connection_name(session_name: &str) -> String {
+    let session_name: String = session_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if session_name.is_empty() {
+        format!("cli-conn-{}", uuid::Uuid::new_v4())
+    } else {
+        format!("cli-conn-{}-{}", session_name, uuid::Uuid::new_v4())
+    }
+}
+
 #[tonic::async_trait]
 impl CliConnection for GRPCCliConnection {
     type ConnectCliStream =
@@ -56,6 +155,34 @@ impl CliConnection for GRPCCliConnection {
         &self,
         request: Request<tonic::Streaming<grpc_api::ToServer>>,
     ) -> Result<Response<Self::ConnectCliStream>, Status> {
+        // Without client certificates, i.e. in insecure mode, the CLI connections are not restricted.
+        // [impl->swdd~grpc-server-maps-cli-identity-to-roles~1]
+        let mut authorizer = None;
+        if let Some(peer_certs) = request.peer_certs().filter(|_| self.cli_roles.is_enabled()) {
+            let client_cert = peer_certs
+                .first()
+                .ok_or_else(|| Status::unauthenticated("No CLI certificate provided!"))?;
+            let identities =
+                certificate_identities(client_cert.as_ref()).map_err(Status::unauthenticated)?;
+
+            let Some(cli_authorizer) = self.cli_roles.authorizer_for(&identities) else {
+                log::warn!(
+                    "Refused CLI connection as no role is assigned to the identities {:?}",
+                    identities
+                );
+                return Err(Status::permission_denied(format!(
+                    "No role is assigned to the CLI certificate identities {:?}",
+                    identities
+                )));
+            };
+            log::debug!(
+                "Assigned the roles {:?} to the CLI with the identities {:?}",
+                cli_authorizer.role_names(),
+                identities
+            );
+            authorizer = Some(cli_authorizer);
+        }
+
         let mut stream = request.into_inner();
 
         // [impl->swdd~grpc-commander-connection-creates-from-server-channel~1]
@@ -63,9 +190,6 @@ impl CliConnection for GRPCCliConnection {
             Result<grpc_api::FromServer, tonic::Status>,
         >(common::CHANNEL_CAPACITY);
 
-        let cli_connection_name = format!("cli-conn-{}", uuid::Uuid::new_v4());
-        log::debug!("Connection to CLI (name={}) open.", cli_connection_name);
-
         let ankaios_tx = self.to_ankaios_server.clone();
         let cli_senders = self.cli_senders.clone();
 
@@ -77,8 +201,13 @@ impl CliConnection for GRPCCliConnection {
             .to_server_enum
             .ok_or(Status::invalid_argument("Empty"))?
         {
-            ToServerEnum::CommanderHello(grpc_api::CommanderHello { protocol_version }) => {
+            ToServerEnum::CommanderHello(grpc_api::CommanderHello {
+                protocol_version,
+                session_name,
+            }) => {
                 log::trace!("Received a hello from a cli/commander application.");
+                let cli_connection_name = cli_connection_name(&session_name);
+                log::debug!("Connection to CLI (name={}) open.", cli_connection_name);
 
                 // [impl->swdd~grpc-commander-connection-checks-version-compatibility~1]
                 check_version_compatibility(&protocol_version).map_err(|err| {
@@ -86,10 +215,15 @@ impl CliConnection for GRPCCliConnection {
                     Status::failed_precondition(err)})?;
 
                 // [impl->swdd~grpc-commander-connection-stores-from-server-channel-tx~1]
-                self.cli_senders.insert(&cli_connection_name, new_sender);
+                self.cli_senders
+                    .insert(&cli_connection_name, new_sender.clone());
                 // [impl->swdd~grpc-commander-connection-forwards-commands-to-server~1]
                 let _x = tokio::spawn(async move {
-                    let mut stream = GRPCToServerStreaming::new(stream);
+                    let mut stream = AuthorizingCliStreaming {
+                        inner: GRPCToServerStreaming::new(stream),
+                        authorizer,
+                        cli_sender: new_sender,
+                    };
                     let result = forward_from_proto_to_ankaios(
                         cli_connection_name.clone(),
                         &mut stream,
@@ -104,6 +238,13 @@ impl CliConnection for GRPCCliConnection {
                         );
                     }
                     cli_senders.remove(&cli_connection_name);
+                    // [impl->swdd~grpc-commander-connection-sends-goodbye-on-close~1]
+                    if let Err(error) = ankaios_tx.goodbye(cli_connection_name.clone()).await {
+                        log::warn!(
+                            "Could not inform the server about the closed CLI connection: '{}'",
+                            error
+                        );
+                    }
                     log::debug!(
                         "Connection to CLI (name={}) has been closed.",
                         cli_connection_name
@@ -116,3 +257,183 @@ impl CliConnection for GRPCCliConnection {
         }
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use api::ank_base;
+    use async_trait::async_trait;
+    use common::objects::{AccessRightsRule, ControlInterfaceAccess, ReadWriteEnum, StateRule};
+
+    use super::{cli_connection_name, AuthorizingCliStreaming};
+    use crate::ankaios_streaming::GRPCStreaming;
+    use crate::cli_roles::{CliRole, CliRoles};
+    use crate::grpc_api::{self, to_server::ToServerEnum};
+
+    const MONITORING_IDENTITY: &str = "prometheus";
+
+    struct MockGRPCToServerStreaming {
+        msgs: VecDeque<grpc_api::ToServer>,
+    }
+
+    #[async_trait]
+    impl GRPCStreaming<grpc_api::ToServer> for MockGRPCToServerStreaming {
+        async fn message(&mut self) -> Result<Option<grpc_api::ToServer>, tonic::Status> {
+            Ok(self.msgs.pop_front())
+        }
+    }
+
+    fn monitoring_roles() -> CliRoles {
+        CliRoles::new(HashMap::from([(
+            "monitoring".to_string(),
+            CliRole {
+                identities: vec![MONITORING_IDENTITY.to_string()],
+                access: ControlInterfaceAccess {
+                    allow_rules: vec![AccessRightsRule::StateRule(StateRule {
+                        operation: ReadWriteEnum::Read,
+                        filter_mask: vec!["workloadStates".to_string()],
+                    })],
+                    deny_rules: vec![],
+                },
+            },
+        )]))
+        .unwrap()
+    }
+
+    fn streaming(
+        restricted: bool,
+        msgs: Vec<grpc_api::ToServer>,
+    ) -> AuthorizingCliStreaming<MockGRPCToServerStreaming> {
+        let (cli_sender, _) = tokio::sync::mpsc::channel(1);
+        AuthorizingCliStreaming {
+            inner: MockGRPCToServerStreaming { msgs: msgs.into() },
+            authorizer: restricted
+                .then(|| monitoring_roles().authorizer_for(&[MONITORING_IDENTITY.to_string()]))
+                .flatten(),
+            cli_sender,
+        }
+    }
+
+    fn update_workload_state() -> grpc_api::ToServer {
+        grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::UpdateWorkloadState(
+                grpc_api::UpdateWorkloadState {
+                    workload_states: vec![ank_base::WorkloadState {
+                        instance_name: Some(ank_base::WorkloadInstanceName {
+                            workload_name: "nginx".into(),
+                            agent_name: "agent_A".into(),
+                            id: "1234".into(),
+                        }),
+                        execution_state: None,
+                    }],
+                },
+            )),
+        }
+    }
+
+    fn agent_load_status() -> grpc_api::ToServer {
+        grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::AgentLoadStatus(grpc_api::AgentLoadStatus {
+                agent_name: "agent_A".into(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn logs_response() -> grpc_api::ToServer {
+        grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::Response(ank_base::Response {
+                request_id: "cli-conn-1@request_id".into(),
+                response_content: Some(ank_base::response::ResponseContent::LogsStopResponse(
+                    ank_base::LogsStopResponse::default(),
+                )),
+            })),
+        }
+    }
+
+    fn workload_states_request() -> grpc_api::ToServer {
+        grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::Request(ank_base::Request {
+                request_id: "request_id".into(),
+                request_content: Some(ank_base::request::RequestContent::CompleteStateRequest(
+                    ank_base::CompleteStateRequest {
+                        field_mask: vec!["workloadStates".into()],
+                        label_selector: None,
+                    },
+                )),
+            })),
+        }
+    }
+
+    fn goodbye() -> grpc_api::ToServer {
+        grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::Goodbye(grpc_api::Goodbye {})),
+        }
+    }
+
+    // [utest->swdd~grpc-cli-connection-drops-agent-messages-of-restricted-clis~1]
+    #[tokio::test]
+    async fn utest_authorizing_cli_streaming_drops_agent_messages_of_restricted_cli() {
+        let mut streaming = streaming(
+            true,
+            vec![
+                update_workload_state(),
+                agent_load_status(),
+                logs_response(),
+                workload_states_request(),
+                goodbye(),
+            ],
+        );
+
+        assert_eq!(
+            streaming.message().await.unwrap(),
+            Some(workload_states_request())
+        );
+        assert_eq!(streaming.message().await.unwrap(), Some(goodbye()));
+        assert_eq!(streaming.message().await.unwrap(), None);
+    }
+
+    // [utest->swdd~grpc-cli-connection-drops-agent-messages-of-restricted-clis~1]
+    #[tokio::test]
+    async fn utest_authorizing_cli_streaming_forwards_all_messages_of_unrestricted_cli() {
+        let mut streaming = streaming(false, vec![update_workload_state()]);
+
+        assert_eq!(
+            streaming.message().await.unwrap(),
+            Some(update_workload_state())
+        );
+    }
+
+    // [utest->swdd~grpc-cli-connection-name-contains-session-name~1]
+    #[test]
+    fn utest_cli_connection_name_contains_session_name() {
+        let connection_name = cli_connection_name("alice");
+        assert!(connection_name.starts_with("cli-conn-alice-"));
+        assert_ne!(connection_name, cli_connection_name("alice"));
+    }
+
+    // [utest->swdd~grpc-cli-connection-name-contains-session-name~1]
+    #[test]
+    fn utest_cli_connection_name_replaces_separators_in_session_name() {
+        let connection_name = cli_connection_name("alice@host name");
+        assert!(connection_name.starts_with("cli-conn-alice_host_name-"));
+        assert!(!connection_name.contains('@'));
+    }
+
+    // [utest->swdd~grpc-cli-connection-name-contains-session-name~1]
+    #[test]
+    fn utest_cli_connection_name_without_session_name() {
+        let connection_name = cli_connection_name("");
+        let uuid = connection_name.strip_prefix("cli-conn-").unwrap();
+        assert!(uuid::Uuid::parse_str(uuid).is_ok());
+    }
+}
@@ -31,6 +31,7 @@ pub enum GrpcMiddlewareError {
     CertificateError(String),
     TLSError(String),
     VersionMismatch(String),
+    ConnectionClosed(String),
 }
 
 impl From<GrpcMiddlewareError> for CommunicationMiddlewareError {
@@ -106,6 +107,9 @@ impl fmt::Display for GrpcMiddlewareError {
             GrpcMiddlewareError::VersionMismatch(message) => {
                 write!(f, "Version mismatch: '{message}'")
             }
+            GrpcMiddlewareError::ConnectionClosed(reason) => {
+                write!(f, "Connection closed by the server: '{reason}'")
+            }
         }
     }
 }
@@ -133,6 +133,7 @@ pub mod security {
 }
 
 mod agent_senders_map;
+pub mod cli_roles;
 pub mod client;
 mod from_server_proxy;
 mod grpc_agent_connection;
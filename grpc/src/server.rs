@@ -19,8 +19,13 @@ use common::communications_server::CommunicationsServer;
 use tonic::transport::{Certificate, Identity, Server};
 
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::oneshot;
 
 use crate::agent_senders_map::AgentSendersMap;
+use crate::cli_roles::CliRoles;
 use crate::grpc_api::agent_connection_server::AgentConnectionServer;
 use crate::grpc_cli_connection::GRPCCliConnection;
 use crate::grpc_middleware_error::GrpcMiddlewareError;
@@ -35,11 +40,15 @@ use common::to_server_interface::ToServerSender;
 
 use async_trait::async_trait;
 
+const GRACEFUL_SHUTDOWN_TIMEOUT_MS: u64 = 3000;
+
 #[derive(Debug)]
 pub struct GRPCCommunicationsServer {
     sender: ToServerSender,
     agent_senders: AgentSendersMap,
     tls_config: Option<TLSConfig>,
+    cli_roles: Arc<CliRoles>,
+    bind_agent_names: bool,
 }
 
 #[async_trait]
@@ -50,16 +59,22 @@ impl CommunicationsServer for GRPCCommunicationsServer {
         addr: SocketAddr,
     ) -> Result<(), CommunicationMiddlewareError> {
         // [impl->swdd~grpc-server-creates-agent-connection~1]
-        let my_connection =
-            GRPCAgentConnection::new(self.agent_senders.clone(), self.sender.clone());
+        let my_connection = GRPCAgentConnection::new(
+            self.agent_senders.clone(),
+            self.sender.clone(),
+            self.bind_agent_names,
+        );
 
         // [impl->swdd~grpc-server-creates-cli-connection~1]
-        let my_cli_connection =
-            GRPCCliConnection::new(self.agent_senders.clone(), self.sender.clone());
+        let my_cli_connection = GRPCCliConnection::new(
+            self.agent_senders.clone(),
+            self.sender.clone(),
+            self.cli_roles.clone(),
+        );
 
         let agent_senders_clone = self.agent_senders.clone();
 
-        match &self.tls_config {
+        let mut server_builder = match &self.tls_config {
             // [impl->swdd~grpc-server-activate-mtls-when-certificates-and-key-provided-upon-start~1]
             Some(tls_config) => {
                 let ca = &tls_config.ca_pem;
@@ -70,57 +85,61 @@ impl CommunicationsServer for GRPCCommunicationsServer {
                 let tls = tonic::transport::ServerTlsConfig::new()
                     .client_ca_root(Certificate::from_pem(ca))
                     .identity(server_identity);
-                tokio::select! {
-                    // [impl->swdd~grpc-server-spawns-tonic-service~1]
-                    // [impl->swdd~grpc-delegate-workflow-to-external-library~1]
-                    result = Server::builder()
-                        .tls_config(tls).map_err(|err| CommunicationMiddlewareError(err.to_string()))?
-                        .add_service(AgentConnectionServer::new(my_connection))
-                        // [impl->swdd~grpc-server-provides-endpoint-for-cli-connection-handling~1]
-                        .add_service(CliConnectionServer::new(my_cli_connection))
-                        .serve(addr) => {
-                            result.map_err(|err| {
-                                GrpcMiddlewareError::StartError(format!("{err:?}"))
-                            })?
-                        }
-                    // [impl->swdd~grpc-server-forwards-from-server-messages-to-grpc-client~1]
-                    _ = from_server_proxy::forward_from_ankaios_to_proto(
-                        &agent_senders_clone,
-                        &mut receiver,
-                    ) => {
-                        Err(GrpcMiddlewareError::ConnectionInterrupted(
-                            "Connection between Ankaios server and the communication middleware dropped.".into())
-                        )?
-                    }
-                }
+                Server::builder()
+                    .tls_config(tls)
+                    .map_err(|err| CommunicationMiddlewareError(err.to_string()))?
             }
             // [impl->swdd~grpc-server-deactivate-mtls-when-no-certificates-and-no-key-provided-upon-start~1]
             None => {
                 log::warn!(
-                            "!!!ANKSERVER IS STARTED IN INSECURE MODE (-k, --insecure) -> TLS is disabled!!!"
-                        );
-                tokio::select! {
-                    // [impl->swdd~grpc-server-spawns-tonic-service~1]
-                    // [impl->swdd~grpc-delegate-workflow-to-external-library~1]
-                    result = Server::builder()
-                        .add_service(AgentConnectionServer::new(my_connection))
-                        // [impl->swdd~grpc-server-provides-endpoint-for-cli-connection-handling~1]
-                        .add_service(CliConnectionServer::new(my_cli_connection))
-                        .serve(addr) => {
-                            result.map_err(|err| {
-                                GrpcMiddlewareError::StartError(format!("{err:?}"))
-                            })?
-                        }
-                    // [impl->swdd~grpc-server-forwards-from-server-messages-to-grpc-client~1]
-                    _ = from_server_proxy::forward_from_ankaios_to_proto(
-                        &agent_senders_clone,
-                        &mut receiver,
-                    ) => {
-                        Err(GrpcMiddlewareError::ConnectionInterrupted(
-                            "Connection between Ankaios server and the communication middleware dropped.".into())
-                        )?
-                    }
-
+                    "!!!ANKSERVER IS STARTED IN INSECURE MODE (-k, --insecure) -> TLS is disabled!!!"
+                );
+                Server::builder()
+            }
+        };
+
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+
+        // [impl->swdd~grpc-server-spawns-tonic-service~1]
+        // [impl->swdd~grpc-delegate-workflow-to-external-library~1]
+        let tonic_server = server_builder
+            .add_service(AgentConnectionServer::new(my_connection))
+            // [impl->swdd~grpc-server-provides-endpoint-for-cli-connection-handling~1]
+            .add_service(CliConnectionServer::new(my_cli_connection))
+            .serve_with_shutdown(addr, async {
+                let _ = shutdown_receiver.await;
+            });
+        tokio::pin!(tonic_server);
+
+        tokio::select! {
+            result = &mut tonic_server => {
+                result.map_err(|err| {
+                    GrpcMiddlewareError::StartError(format!("{err:?}"))
+                })?
+            }
+            // [impl->swdd~grpc-server-forwards-from-server-messages-to-grpc-client~1]
+            forward_result = from_server_proxy::forward_from_ankaios_to_proto(
+                &agent_senders_clone,
+                &mut receiver,
+            ) => {
+                forward_result?;
+
+                // [impl->swdd~grpc-server-shuts-down-gracefully-on-stop~1]
+                log::debug!("Shutting down the gRPC server.");
+                let _ = shutdown_sender.send(());
+                match tokio::time::timeout(
+                    Duration::from_millis(GRACEFUL_SHUTDOWN_TIMEOUT_MS),
+                    &mut tonic_server,
+                )
+                .await
+                {
+                    Ok(result) => result.map_err(|err| {
+                        GrpcMiddlewareError::StartError(format!("{err:?}"))
+                    })?,
+                    Err(_) => log::warn!(
+                        "Not all connections were closed within {} ms. Stopping the gRPC server anyway.",
+                        GRACEFUL_SHUTDOWN_TIMEOUT_MS
+                    ),
                 }
             }
         }
@@ -134,6 +153,24 @@ impl GRPCCommunicationsServer {
             agent_senders: AgentSendersMap::new(),
             sender,
             tls_config,
+            cli_roles: Arc::new(CliRoles::default()),
+            bind_agent_names: false,
+        }
+    }
+
+    // [impl->swdd~grpc-agent-connection-binds-agent-name-to-certificate~1]
+    pub fn set_bind_agent_names(&mut self, bind_agent_names: bool) {
+        if bind_agent_names && self.tls_config.is_none() {
+            log::warn!("The agent names are not bound to certificates as TLS is disabled.");
+        }
+        self.bind_agent_names = bind_agent_names;
+    }
+
+    // [impl->swdd~grpc-server-maps-cli-identity-to-roles~1]
+    pub fn set_cli_roles(&mut self, cli_roles: CliRoles) {
+        if cli_roles.is_enabled() && self.tls_config.is_none() {
+            log::warn!("The CLI roles are ignored as TLS is disabled.");
         }
+        self.cli_roles = Arc::new(cli_roles);
     }
 }
@@ -17,11 +17,14 @@ use crate::grpc_middleware_error::GrpcMiddlewareError;
 
 use crate::grpc_api::{self, to_server::ToServerEnum};
 use api::ank_base::{
-    self, request::RequestContent, CompleteStateRequest, Request, UpdateStateRequest,
+    self, request::RequestContent, AuditLogRequest, CompleteStateRequest, EventsRequest, Request,
+    RollbackRequest, UpdateStateRequest, WorkloadDescriptionRequest,
 };
 
 use common::request_id_prepending::prepend_request_id;
-use common::to_server_interface::{ToServer, ToServerInterface, ToServerReceiver, ToServerSender};
+use common::to_server_interface::{
+    ToServer, ToServerError, ToServerInterface, ToServerReceiver, ToServerSender,
+};
 
 use tokio::sync::mpsc::Sender;
 use tonic::Streaming;
@@ -52,8 +55,6 @@ pub async fn forward_from_proto_to_ankaios(
     sink: ToServerSender,
 ) -> Result<(), GrpcMiddlewareError> {
     while let Some(message) = grpc_streaming.message().await? {
-        log::trace!("REQUEST={:?}", message);
-
         match message
             .to_server_enum
             .ok_or(GrpcMiddlewareError::ReceiveError(
@@ -75,9 +76,20 @@ pub async fn forward_from_proto_to_ankaios(
                         let UpdateStateRequest {
                             new_state,
                             update_mask,
+                            dry_run,
                         } = *update_state_request;
-                        log::debug!("Received UpdateStateRequest from '{}'", agent_name);
+                        // The new state is not logged as it contains the secrets.
+                        log::debug!(
+                            "Received UpdateStateRequest '{}' from '{}' with update mask '{:?}'",
+                            request_id,
+                            agent_name,
+                            update_mask
+                        );
                         match new_state.unwrap_or_default().try_into() {
+                            Ok(new_state) if dry_run => {
+                                sink.dry_run_update_state(request_id, new_state, update_mask)
+                                    .await?;
+                            }
                             Ok(new_state) => {
                                 sink.update_state(request_id, new_state, update_mask)
                                     .await?;
@@ -90,17 +102,76 @@ pub async fn forward_from_proto_to_ankaios(
                             }
                         };
                     }
-                    RequestContent::CompleteStateRequest(CompleteStateRequest { field_mask }) => {
+                    RequestContent::CompleteStateRequest(CompleteStateRequest {
+                        field_mask,
+                        label_selector,
+                    }) => {
                         log::trace!("Received RequestCompleteState from '{}'", agent_name);
                         sink.request_complete_state(
                             request_id,
-                            ank_base::CompleteStateRequest { field_mask }.into(),
+                            ank_base::CompleteStateRequest {
+                                field_mask,
+                                label_selector,
+                            }
+                            .into(),
                         )
                         .await?;
                     }
+                    RequestContent::RevisionsRequest(_) => {
+                        log::trace!("Received RevisionsRequest from '{}'", agent_name);
+                        sink.request_revisions(request_id).await?;
+                    }
+                    RequestContent::RollbackRequest(RollbackRequest { revision }) => {
+                        log::debug!("Received RollbackRequest from '{}'", agent_name);
+                        sink.rollback(request_id, revision).await?;
+                    }
+                    RequestContent::EventsRequest(EventsRequest { field_mask }) => {
+                        log::debug!("Received EventsRequest from '{}'", agent_name);
+                        sink.request_events(request_id, field_mask).await?;
+                    }
+                    RequestContent::EventsCancelRequest(_) => {
+                        log::debug!("Received EventsCancelRequest from '{}'", agent_name);
+                        sink.cancel_events(request_id).await?;
+                    }
+                    RequestContent::LogsRequest(logs_request) => {
+                        log::debug!("Received LogsRequest from '{}'", agent_name);
+                        sink.request_logs(request_id, logs_request.into()).await?;
+                    }
+                    RequestContent::LogsCancelRequest(_) => {
+                        log::debug!("Received LogsCancelRequest from '{}'", agent_name);
+                        sink.cancel_logs(request_id).await?;
+                    }
+                    RequestContent::AuditLogRequest(AuditLogRequest { tail }) => {
+                        log::trace!("Received AuditLogRequest from '{}'", agent_name);
+                        sink.request_audit_log(request_id, tail).await?;
+                    }
+                    RequestContent::WorkloadDescriptionRequest(WorkloadDescriptionRequest {
+                        workload_name,
+                        config_names,
+                    }) => {
+                        log::trace!("Received WorkloadDescriptionRequest from '{}'", agent_name);
+                        sink.request_workload_description(request_id, workload_name, config_names)
+                            .await?;
+                    }
                 }
             }
 
+            // [impl->swdd~grpc-agent-connection-forwards-responses-to-server~2]
+            ToServerEnum::Response(response) => {
+                log::trace!(
+                    "Received Response for request '{}' from '{}'",
+                    response.request_id,
+                    agent_name
+                );
+                // The connection name lets the server check that the response comes from the addressed agent.
+                sink.send(ToServer::Response(common::commands::ConnectionResponse {
+                    connection_name: agent_name.clone(),
+                    response,
+                }))
+                .await
+                .map_err(ToServerError::from)?;
+            }
+
             ToServerEnum::UpdateWorkloadState(update_workload_state) => {
                 log::trace!("Received UpdateWorkloadState from '{}'", agent_name);
 
@@ -194,6 +265,15 @@ pub async fn forward_from_ankaios_to_proto(
                     .await?;
             }
 
+            ToServer::Response(connection_response) => {
+                log::trace!("Received Response from agent");
+                grpc_tx
+                    .send(grpc_api::ToServer {
+                        to_server_enum: Some(ToServerEnum::Response(connection_response.response)),
+                    })
+                    .await?;
+            }
+
             ToServer::AgentGone(_) => {
                 panic!("AgentGone internal messages is not intended to be sent over the network");
             }
@@ -334,6 +414,51 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    // [utest->swdd~grpc-agent-connection-forwards-responses-to-server~2]
+    #[tokio::test]
+    async fn utest_to_server_command_forward_from_proto_to_ankaios_response() {
+        let agent_name = "agent_A".to_string();
+        let response = ank_base::Response {
+            request_id: "cli-conn-1@request_id".into(),
+            response_content: Some(ank_base::response::ResponseContent::LogEntriesResponse(
+                ank_base::LogEntriesResponse {
+                    log_entries: vec![ank_base::LogEntry {
+                        workload_name: None,
+                        message: "log line".into(),
+                    }],
+                },
+            )),
+        };
+
+        let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
+
+        let mut mock_grpc_ex_request_streaming =
+            MockGRPCToServerStreaming::new(LinkedList::from([
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Response(response.clone())),
+                }),
+                None,
+            ]));
+
+        let forward_result = forward_from_proto_to_ankaios(
+            agent_name,
+            &mut mock_grpc_ex_request_streaming,
+            server_tx,
+        )
+        .await;
+        assert!(forward_result.is_ok());
+
+        let result = server_rx.recv().await.unwrap();
+
+        assert_eq!(
+            result,
+            ToServer::Response(common::commands::ConnectionResponse {
+                connection_name: "agent_A".to_string(),
+                response
+            })
+        );
+    }
+
     // [utest->swdd~grpc-client-forwards-commands-to-grpc-agent-connection~1]
     #[tokio::test]
     async fn utest_to_server_command_forward_from_ankaios_to_proto_update_workload() {
@@ -512,6 +637,7 @@ mod tests {
                                 ank_base::UpdateStateRequest {
                                     new_state: Some(ankaios_state),
                                     update_mask: ankaios_update_mask.clone(),
+                                    dry_run: false,
                                 },
                             )),
                         ),
@@ -556,6 +682,7 @@ mod tests {
                                 ank_base::UpdateStateRequest {
                                     new_state: Some(ankaios_state.clone().into()),
                                     update_mask: ankaios_update_mask.clone(),
+                                    dry_run: false,
                                 },
                             )),
                         ),
@@ -587,6 +714,58 @@ mod tests {
             if request_id == expected_prefixed_my_request_id && update_request.state == ankaios_state && update_request.update_mask == ankaios_update_mask));
     }
 
+    // [utest->swdd~grpc-agent-connection-forwards-commands-to-server~1]
+    #[tokio::test]
+    async fn utest_to_server_command_forward_from_proto_to_ankaios_dry_run_update_state() {
+        let agent_name = "fake_agent";
+        let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
+
+        let ankaios_state =
+            generate_test_complete_state(vec![generate_test_workload_spec_with_param(
+                agent_name.into(),
+                "name".to_string(),
+                "my_runtime".into(),
+            )]);
+
+        let mut mock_grpc_ex_request_streaming =
+            MockGRPCToServerStreaming::new(LinkedList::from([
+                Some(grpc_api::ToServer {
+                    to_server_enum: Some(ToServerEnum::Request(ank_base::Request {
+                        request_id: "my_request_id".to_owned(),
+                        request_content: Some(
+                            ank_base::request::RequestContent::UpdateStateRequest(Box::new(
+                                ank_base::UpdateStateRequest {
+                                    new_state: Some(ankaios_state.clone().into()),
+                                    update_mask: vec![],
+                                    dry_run: true,
+                                },
+                            )),
+                        ),
+                    })),
+                }),
+                None,
+            ]));
+
+        let forward_result = forward_from_proto_to_ankaios(
+            agent_name.into(),
+            &mut mock_grpc_ex_request_streaming,
+            server_tx,
+        )
+        .await;
+
+        assert!(forward_result.is_ok());
+
+        let result = server_rx.recv().await.unwrap();
+
+        assert!(matches!(
+            result,
+            ToServer::Request(common::commands::Request {
+                request_content: common::commands::RequestContent::UpdateStateRequest(update_request),
+                ..
+            })
+            if update_request.state == ankaios_state && update_request.dry_run));
+    }
+
     // [utest->swdd~grpc-agent-connection-forwards-commands-to-server~1]
     #[tokio::test]
     async fn utest_to_server_command_forward_from_proto_to_ankaios_update_workload_state() {
@@ -648,7 +827,10 @@ mod tests {
                         request_id: "my_request_id".to_owned(),
                         request_content: Some(
                             ank_base::request::RequestContent::CompleteStateRequest(
-                                ank_base::CompleteStateRequest { field_mask: vec![] },
+                                ank_base::CompleteStateRequest {
+                                    field_mask: vec![],
+                                    label_selector: None,
+                                },
                             ),
                         ),
                     })),
@@ -675,7 +857,7 @@ mod tests {
                 request_id,
                 request_content:
                     common::commands::RequestContent::CompleteStateRequest(
-                        common::commands::CompleteStateRequest { field_mask },
+                        common::commands::CompleteStateRequest { field_mask, label_selector: None },
                     ),
             }) if request_id == expected_prefixed_my_request_id && field_mask == exepected_empty_field_mask)
         );
@@ -686,7 +868,10 @@ mod tests {
         let (server_tx, mut server_rx) = mpsc::channel::<ToServer>(common::CHANNEL_CAPACITY);
         let (grpc_tx, mut grpc_rx) = mpsc::channel::<grpc_api::ToServer>(common::CHANNEL_CAPACITY);
 
-        let request_complete_state = common::commands::CompleteStateRequest { field_mask: vec![] };
+        let request_complete_state = common::commands::CompleteStateRequest {
+            field_mask: vec![],
+            label_selector: None,
+        };
 
         let request_complete_state_result = server_tx
             .request_complete_state("my_request_id".to_owned(), request_complete_state.clone())
@@ -708,7 +893,7 @@ mod tests {
             request_id,
             request_content:
                 Some(ank_base::request::RequestContent::CompleteStateRequest(
-                    ank_base::CompleteStateRequest { field_mask },
+                    ank_base::CompleteStateRequest { field_mask, label_selector: None },
                 )),
         }))
         if request_id == "my_request_id" && field_mask == vec![] as Vec<String>));
@@ -16,6 +16,7 @@
 mod grpc_tests {
 
     use std::{
+        collections::HashMap,
         fs::File,
         io::{self, Write},
         os::unix::fs::PermissionsExt,
@@ -23,16 +24,21 @@ mod grpc_tests {
         time::Duration,
     };
 
+    use api::ank_base;
     use common::{
         commands::{self, CompleteStateRequest, Request, RequestContent},
         communications_client::CommunicationsClient,
         communications_error::CommunicationMiddlewareError,
         communications_server::CommunicationsServer,
         from_server_interface::{FromServer, FromServerSender},
-        objects::CompleteState,
+        objects::{
+            generate_test_workload_state_with_agent, AccessRightsRule, CompleteState,
+            ControlInterfaceAccess, ExecutionState, ReadWriteEnum, StateRule,
+        },
         to_server_interface::{ToServer, ToServerInterface, ToServerReceiver, ToServerSender},
     };
     use grpc::{
+        cli_roles::{CliRole, CliRoles},
         client::GRPCCommunicationsClient,
         security::{self, read_pem_file, TLSConfig},
         server::GRPCCommunicationsServer,
@@ -222,6 +228,7 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
                 test_request_id.to_owned(),
                 url,
                 tls_config,
+                HashMap::from([("zone".to_string(), "front".to_string())]),
             ),
         };
 
@@ -323,7 +330,10 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
         let request_complete_state_result = to_grpc_client
             .request_complete_state(
                 test_request_id.to_owned(),
-                CompleteStateRequest { field_mask: vec![] },
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None,
+                },
             )
             .await;
 
@@ -340,7 +350,8 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
                 Request{
                     request_id,
                     request_content: RequestContent::CompleteStateRequest(CompleteStateRequest {
-                        field_mask
+                        field_mask,
+                        label_selector: None,
                     })
                 }
             ))) if request_id.contains(test_request_id) && field_mask.is_empty()
@@ -367,7 +378,10 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
         let request_complete_state_result = to_grpc_client
             .request_complete_state(
                 test_request_id.to_owned(),
-                CompleteStateRequest { field_mask: vec![] },
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None,
+                },
             )
             .await;
 
@@ -382,7 +396,8 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
                 Request{
                     request_id,
                     request_content: RequestContent::CompleteStateRequest(CompleteStateRequest {
-                        field_mask
+                        field_mask,
+                        label_selector: None,
                     })
                 }
             ))) if request_id.contains(test_request_id) && field_mask.is_empty()
@@ -425,6 +440,7 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
     }
 
     // [itest->swdd~grpc-agent-deactivate-mtls-when-no-certificates-and-no-key-provided-upon-start~1]
+    // [itest->swdd~grpc-client-sends-agent-labels~1]
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)] // set worker_threads = 1 to solve the failing of the test on woodpecker
     async fn itest_grpc_communication_client_agent_connection_grpc_server_received_agent_hello() {
         let test_agent_name = "test_agent_name";
@@ -442,6 +458,7 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
             result,
             Ok(Some(ToServer::AgentHello(commands::AgentHello {
                 agent_name: test_agent_name.to_owned(),
+                labels: HashMap::from([("zone".to_string(), "front".to_string())]),
             })))
         );
     }
@@ -467,8 +484,203 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
         assert_eq!(
             result,
             Ok(Some(ToServer::AgentHello(commands::AgentHello {
-                agent_name: test_agent_name.to_owned()
+                agent_name: test_agent_name.to_owned(),
+                labels: HashMap::from([("zone".to_string(), "front".to_string())]),
             })))
         );
     }
+
+    // [itest->swdd~grpc-server-extracts-cli-identity-from-certificate~1]
+    // [itest->swdd~grpc-server-maps-cli-identity-to-roles~1]
+    // [itest->swdd~grpc-cli-connection-authorizes-requests~1]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)] // set worker_threads = 1 to solve the failing of the test on woodpecker
+    async fn itest_grpc_cli_connection_with_tls_denies_requests_not_allowed_by_role() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let test_pem_files_package = TestPEMFilesPackage::new().unwrap();
+        let server_addr = "0.0.0.0:50055";
+
+        let (to_server, mut server_receiver) = tokio::sync::mpsc::channel::<ToServer>(20);
+        let (_to_grpc_server, grpc_server_receiver) = tokio::sync::mpsc::channel::<FromServer>(20);
+        let (to_cli, mut cli_receiver) = tokio::sync::mpsc::channel::<FromServer>(20);
+
+        let mut communications_server = GRPCCommunicationsServer::new(
+            to_server,
+            Some(test_pem_files_package.get_server_tls_config()),
+        );
+        communications_server.set_cli_roles(
+            CliRoles::new(HashMap::from([(
+                "monitoring".to_string(),
+                CliRole {
+                    identities: vec!["ank".to_string()],
+                    access: ControlInterfaceAccess {
+                        allow_rules: vec![AccessRightsRule::StateRule(StateRule {
+                            operation: ReadWriteEnum::Read,
+                            filter_mask: vec!["workloadStates".to_string()],
+                        })],
+                        deny_rules: vec![],
+                    },
+                },
+            )]))
+            .unwrap(),
+        );
+        let socket_addr: std::net::SocketAddr = server_addr.parse().unwrap();
+        let _grpc_server_task = tokio::spawn(async move {
+            communications_server
+                .start(grpc_server_receiver, socket_addr)
+                .await
+        });
+
+        let (to_grpc_client, _grpc_client_task) = generate_test_grpc_communication_client(
+            server_addr,
+            &CommunicationType::Cli,
+            "test_cli",
+            to_cli,
+            Some(test_pem_files_package.get_cli_tls_config()),
+        )
+        .await;
+
+        to_grpc_client
+            .request_complete_state(
+                "denied_request".to_owned(),
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None,
+                },
+            )
+            .await
+            .unwrap();
+        to_grpc_client
+            .request_complete_state(
+                "allowed_request".to_owned(),
+                CompleteStateRequest {
+                    field_mask: vec!["workloadStates".to_string()],
+                    label_selector: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let denied = timeout(Duration::from_secs(10), cli_receiver.recv()).await;
+        assert!(matches!(
+            denied,
+            Ok(Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(ank_base::Error { message, .. })),
+            }))) if request_id == "denied_request" && message == "Access denied"
+        ));
+
+        let allowed = timeout(Duration::from_secs(10), server_receiver.recv()).await;
+        assert!(matches!(
+            allowed,
+            Ok(Some(ToServer::Request(Request {
+                request_id,
+                request_content: RequestContent::CompleteStateRequest(CompleteStateRequest { field_mask, label_selector: None }),
+            }))) if request_id.ends_with("allowed_request") && field_mask == vec!["workloadStates".to_string()]
+        ));
+    }
+
+    // The test agent certificate has the common name "ank-agent" and the subject alternative name "*".
+    const TEST_AGENT_CERTIFICATE_NAME: &str = "ank-agent";
+
+    fn start_test_grpc_server_with_bound_agent_names(
+        server_addr: &str,
+        test_pem_files_package: &TestPEMFilesPackage,
+    ) -> (
+        ToServerReceiver,
+        tokio::task::JoinHandle<Result<(), CommunicationMiddlewareError>>,
+    ) {
+        let (to_server, server_receiver) = tokio::sync::mpsc::channel::<ToServer>(20);
+        let (to_grpc_server, grpc_server_receiver) = tokio::sync::mpsc::channel::<FromServer>(20);
+
+        let mut communications_server = GRPCCommunicationsServer::new(
+            to_server,
+            Some(test_pem_files_package.get_server_tls_config()),
+        );
+        communications_server.set_bind_agent_names(true);
+        let socket_addr: std::net::SocketAddr = server_addr.parse().unwrap();
+        let grpc_server_task = tokio::spawn(async move {
+            // keep the sender to the gRPC server alive as long as the server runs
+            let _to_grpc_server = to_grpc_server;
+            communications_server
+                .start(grpc_server_receiver, socket_addr)
+                .await
+        });
+
+        (server_receiver, grpc_server_task)
+    }
+
+    // [itest->swdd~grpc-agent-connection-binds-agent-name-to-certificate~1]
+    // [itest->swdd~grpc-agent-connection-rejects-workload-states-of-other-agents~1]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)] // set worker_threads = 1 to solve the failing of the test on woodpecker
+    async fn itest_grpc_agent_connection_with_bound_agent_names_accepts_matching_agent_name() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let test_pem_files_package = TestPEMFilesPackage::new().unwrap();
+        let server_addr = "0.0.0.0:50056";
+        let (mut server_receiver, _grpc_server_task) =
+            start_test_grpc_server_with_bound_agent_names(server_addr, &test_pem_files_package);
+
+        let (to_agent, _agent_receiver) = tokio::sync::mpsc::channel::<FromServer>(20);
+        let (to_grpc_client, _grpc_client_task) = generate_test_grpc_communication_client(
+            server_addr,
+            &CommunicationType::Agent,
+            TEST_AGENT_CERTIFICATE_NAME,
+            to_agent,
+            Some(test_pem_files_package.get_agent_tls_config()),
+        )
+        .await;
+
+        let result = timeout(Duration::from_secs(10), server_receiver.recv()).await;
+        assert!(matches!(
+            result,
+            Ok(Some(ToServer::AgentHello(commands::AgentHello { agent_name, .. })))
+                if agent_name == TEST_AGENT_CERTIFICATE_NAME
+        ));
+
+        let foreign_workload_state =
+            generate_test_workload_state_with_agent("nginx", "agent_B", ExecutionState::running());
+        let own_workload_state = generate_test_workload_state_with_agent(
+            "nginx",
+            TEST_AGENT_CERTIFICATE_NAME,
+            ExecutionState::running(),
+        );
+        to_grpc_client
+            .update_workload_state(vec![foreign_workload_state])
+            .await
+            .unwrap();
+        to_grpc_client
+            .update_workload_state(vec![own_workload_state.clone()])
+            .await
+            .unwrap();
+
+        let result = timeout(Duration::from_secs(10), server_receiver.recv()).await;
+        assert!(matches!(
+            result,
+            Ok(Some(ToServer::UpdateWorkloadState(commands::UpdateWorkloadState { workload_states })))
+                if workload_states == vec![own_workload_state]
+        ));
+    }
+
+    // [itest->swdd~grpc-agent-connection-binds-agent-name-to-certificate~1]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)] // set worker_threads = 1 to solve the failing of the test on woodpecker
+    async fn itest_grpc_agent_connection_with_bound_agent_names_rejects_other_agent_name() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let test_pem_files_package = TestPEMFilesPackage::new().unwrap();
+        let server_addr = "0.0.0.0:50057";
+        let (mut server_receiver, _grpc_server_task) =
+            start_test_grpc_server_with_bound_agent_names(server_addr, &test_pem_files_package);
+
+        // the wildcard subject alternative name of the certificate does not match when the agent names are bound
+        let (to_agent, _agent_receiver) = tokio::sync::mpsc::channel::<FromServer>(20);
+        let (_to_grpc_client, _grpc_client_task) = generate_test_grpc_communication_client(
+            server_addr,
+            &CommunicationType::Agent,
+            "agent_A",
+            to_agent,
+            Some(test_pem_files_package.get_agent_tls_config()),
+        )
+        .await;
+
+        let result = timeout(Duration::from_secs(2), server_receiver.recv()).await;
+        assert!(result.is_err(), "Unexpected message: {:?}", result);
+    }
 }
@@ -12,31 +12,60 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+mod agent_liveness;
+mod audit_log;
 mod config_renderer;
 mod cycle_check;
 mod delete_graph;
+mod event_handler;
+mod log_campaign_store;
+mod revision_history;
+mod scheduler;
+mod secret_cipher;
+mod server_metrics;
 mod server_state;
+mod state_store;
+mod workload_state_history;
 
 use api::ank_base;
-use common::commands::{Request, UpdateWorkload};
+use common::commands::{
+    CompleteStateRequest, EventsRequest, LogsRequest, Request, RollbackRequest, UpdateWorkload,
+    WorkloadDescriptionRequest, WorkloadLogsRequest,
+};
 use common::from_server_interface::{FromServerReceiver, FromServerSender};
 use common::objects::{
-    CompleteState, DeletedWorkload, ExecutionState, State, WorkloadState, WorkloadStatesMap,
+    CompleteState, DeletedWorkload, ExecutionState, LabelSelector, State, WorkloadSpec,
+    WorkloadState, WorkloadStatesMap,
 };
 
 use common::std_extensions::IllegalStateResult;
 use common::to_server_interface::{ToServerReceiver, ToServerSender};
 
+use agent_liveness::AgentLiveness;
+use audit_log::{AuditEntry, MAX_AUDIT_LOG_ENTRIES};
+pub use audit_log::{AuditLog, FileAuditLog};
+use event_handler::{agent_path, AlteredFields, EventHandler, StateSnapshot};
+use log_campaign_store::LogCampaignStore;
+use revision_history::RevisionHistory;
+pub use secret_cipher::SecretCipher;
+pub use server_metrics::ServerMetrics;
 #[cfg_attr(test, mockall_double::double)]
 use server_state::ServerState;
+use state_store::PersistedState;
+pub use state_store::{FileStateStore, StateStore};
+use workload_state_history::WorkloadStateHistory;
 
 use common::{
     from_server_interface::{FromServer, FromServerInterface},
     to_server_interface::ToServer,
 };
 
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::channel;
 
+const STARTUP_REQUESTER: &str = "ank-server";
+const AGENT_HEARTBEAT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 pub type ToServerChannel = (ToServerSender, ToServerReceiver);
 pub type FromServerChannel = (FromServerSender, FromServerReceiver);
 
@@ -47,6 +76,13 @@ pub fn create_from_server_channel(capacity: usize) -> FromServerChannel {
     channel::<FromServer>(capacity)
 }
 
+// An update rejected before the ServerState is asked to apply it.
+struct UpdateRejection {
+    reason: &'static str,
+    message: String,
+    code: Option<ank_base::ErrorCode>,
+}
+
 pub struct AnkaiosServer {
     // [impl->swdd~server-uses-async-channels~1]
     receiver: ToServerReceiver,
@@ -54,6 +90,14 @@ pub struct AnkaiosServer {
     to_agents: FromServerSender,
     server_state: ServerState,
     workload_states_map: WorkloadStatesMap,
+    workload_state_history: WorkloadStateHistory,
+    state_store: Option<Box<dyn StateStore>>,
+    revision_history: RevisionHistory,
+    event_handler: EventHandler,
+    log_campaign_store: LogCampaignStore,
+    agent_liveness: AgentLiveness,
+    metrics: Option<ServerMetrics>,
+    audit_log: Option<Box<dyn AuditLog>>,
 }
 
 impl AnkaiosServer {
@@ -63,15 +107,55 @@ impl AnkaiosServer {
             to_agents,
             server_state: ServerState::default(),
             workload_states_map: WorkloadStatesMap::default(),
+            workload_state_history: WorkloadStateHistory::default(),
+            state_store: None,
+            revision_history: RevisionHistory::default(),
+            event_handler: EventHandler::default(),
+            log_campaign_store: LogCampaignStore::default(),
+            agent_liveness: AgentLiveness::default(),
+            metrics: None,
+            audit_log: None,
         }
     }
 
+    pub fn set_state_store(&mut self, state_store: Box<dyn StateStore>) {
+        self.state_store = Some(state_store);
+    }
+
+    // [impl->swdd~server-state-schedules-workloads-without-agent~1]
+    pub fn enable_scheduler(&mut self) {
+        self.server_state.enable_scheduler();
+    }
+
+    // [impl->swdd~server-tracks-agent-heartbeats~1]
+    pub fn set_agent_heartbeat_timeout(&mut self, timeout: Duration) {
+        self.agent_liveness.set_timeout(timeout);
+    }
+
+    // [impl->swdd~server-collects-metrics~1]
+    pub fn set_metrics(&mut self, metrics: ServerMetrics) {
+        self.metrics = Some(metrics);
+    }
+
+    // [impl->swdd~server-writes-audit-log~1]
+    pub fn set_audit_log(&mut self, audit_log: Box<dyn AuditLog>) {
+        self.audit_log = Some(audit_log);
+    }
+
     pub async fn start(&mut self, startup_state: Option<CompleteState>) -> Result<(), String> {
+        let startup_state = self.restore_persisted_state(startup_state)?;
+
         if let Some(state) = startup_state {
             State::verify_api_version(&state.desired_state)?;
 
-            match self.server_state.update(state, vec![]) {
+            match self
+                .server_state
+                .update(state, vec![], &self.revision_history)
+            {
                 Ok(Some((added_workloads, deleted_workloads))) => {
+                    // [impl->swdd~server-creates-revision-on-accepted-update~1]
+                    self.add_revision(STARTUP_REQUESTER.to_string(), vec![]);
+
                     // [impl->swdd~server-sets-state-of-new-workloads-to-pending~1]
                     self.workload_states_map.initial_state(&added_workloads);
 
@@ -85,7 +169,11 @@ impl AnkaiosServer {
                         .await
                         .unwrap_or_illegal_state();
                 }
-                Ok(None) => log::info!("No initial workloads to send to agents."),
+                Ok(None) => {
+                    // [impl->swdd~server-creates-revision-on-accepted-update~1]
+                    self.add_revision(STARTUP_REQUESTER.to_string(), vec![]);
+                    log::info!("No initial workloads to send to agents.")
+                }
                 Err(err) => {
                     // [impl->swdd~server-fails-on-invalid-startup-state~1]
                     return Err(err.to_string());
@@ -101,7 +189,26 @@ impl AnkaiosServer {
 
     async fn listen_to_agents(&mut self) {
         log::debug!("Start listening to agents...");
-        while let Some(to_server_command) = self.receiver.recv().await {
+        let mut heartbeat_check = tokio::time::interval(AGENT_HEARTBEAT_CHECK_INTERVAL);
+        self.update_state_metrics();
+        // [impl->swdd~server-reports-readiness~1]
+        if let Some(metrics) = &self.metrics {
+            metrics.set_ready(true);
+        }
+        loop {
+            let to_server_command = tokio::select! {
+                to_server_command = self.receiver.recv() => to_server_command,
+                // [impl->swdd~server-detects-stale-agents~1]
+                _ = heartbeat_check.tick(), if self.agent_liveness.is_enabled() => {
+                    self.disconnect_stale_agents().await;
+                    self.reschedule_workloads_of_lost_agents().await;
+                    self.update_state_metrics();
+                    continue;
+                }
+            };
+            let Some(to_server_command) = to_server_command else {
+                break;
+            };
             match to_server_command {
                 ToServer::AgentHello(method_obj) => {
                     log::info!("Received AgentHello from '{}'", method_obj.agent_name);
@@ -128,6 +235,17 @@ impl AnkaiosServer {
                         log::debug!("No workload states to send.");
                     }
 
+                    let snapshot = self.take_snapshot();
+                    // [impl->swdd~server-stores-newly-connected-agent~1]
+                    // [impl->swdd~server-stores-labels-of-connected-agent~1]
+                    self.server_state
+                        .add_agent(agent_name.clone(), method_obj.labels);
+                    // [impl->swdd~server-tracks-agent-heartbeats~1]
+                    self.agent_liveness.agent_connected(&agent_name);
+
+                    // [impl->swdd~server-schedules-pending-workloads-on-agent-hello~1]
+                    self.schedule_pending_workloads(Some(&agent_name)).await;
+
                     // Send this agent all workloads in the current state which are assigned to him
                     // [impl->swdd~agent-from-agent-field~1]
                     let added_workloads = self.server_state.get_workloads_for_agent(&agent_name);
@@ -144,8 +262,15 @@ impl AnkaiosServer {
                         .await
                         .unwrap_or_illegal_state();
 
-                    // [impl->swdd~server-stores-newly-connected-agent~1]
-                    self.server_state.add_agent(agent_name);
+                    // [impl->swdd~server-sends-events-on-altered-fields~1]
+                    self.send_events(
+                        snapshot,
+                        AlteredFields {
+                            added: vec![agent_path(&agent_name)],
+                            ..Default::default()
+                        },
+                    )
+                    .await;
                 }
                 // [impl->swdd~server-receives-resource-availability~1]
                 ToServer::AgentLoadStatus(method_obj) => {
@@ -156,172 +281,76 @@ impl AnkaiosServer {
                         method_obj.free_memory.free_memory,
                     );
 
+                    // [impl->swdd~server-tracks-agent-heartbeats~1]
+                    self.agent_liveness.heartbeat(&method_obj.agent_name);
+                    let agent_name = method_obj.agent_name.clone();
                     self.server_state
                         .update_agent_resource_availability(method_obj);
+
+                    let snapshot = self.take_snapshot();
+                    // [impl->swdd~server-schedules-pending-workloads-on-agent-load-status~1]
+                    self.schedule_pending_workloads(None).await;
+                    // [impl->swdd~server-sends-events-on-altered-fields~1]
+                    // [impl->swdd~server-sends-event-on-agent-load-status~1]
+                    self.send_events(
+                        snapshot,
+                        AlteredFields {
+                            updated: vec![agent_path(&agent_name)],
+                            ..Default::default()
+                        },
+                    )
+                    .await;
                 }
                 ToServer::AgentGone(method_obj) => {
                     log::debug!("Received AgentGone from '{}'", method_obj.agent_name);
-                    let agent_name = method_obj.agent_name;
-
-                    // [impl->swdd~server-removes-disconnected-agents-from-state~1]
-                    self.server_state.remove_agent(&agent_name);
-
-                    // [impl->swdd~server-set-workload-state-on-disconnect~1]
-                    self.workload_states_map.agent_disconnected(&agent_name);
-
-                    // communicate the workload execution states to other agents
-                    // [impl->swdd~server-distribute-workload-state-on-disconnect~1]
-                    self.to_agents
-                        .update_workload_state(
-                            self.workload_states_map
-                                .get_workload_state_for_agent(&agent_name),
-                        )
-                        .await
-                        .unwrap_or_illegal_state();
-                }
-                // [impl->swdd~server-provides-update-desired-state-interface~1]
-                ToServer::Request(Request {
-                    request_id,
-                    request_content,
-                }) => match request_content {
-                    // [impl->swdd~server-provides-interface-get-complete-state~2]
-                    // [impl->swdd~server-includes-id-in-control-interface-response~1]
-                    common::commands::RequestContent::CompleteStateRequest(
-                        complete_state_request,
-                    ) => {
+                    // [impl->swdd~server-ignores-agent-gone-of-disconnected-agents~1]
+                    if !self
+                        .server_state
+                        .contains_connected_agent(&method_obj.agent_name)
+                    {
                         log::debug!(
-                            "Received CompleteStateRequest with id '{}' and field mask: '{:?}'",
-                            request_id,
-                            complete_state_request.field_mask
+                            "Ignoring AgentGone as agent '{}' is not connected.",
+                            method_obj.agent_name
                         );
-                        match self.server_state.get_complete_state_by_field_mask(
-                            complete_state_request,
-                            &self.workload_states_map,
-                        ) {
-                            Ok(complete_state) => self
-                                .to_agents
-                                .complete_state(request_id, complete_state)
-                                .await
-                                .unwrap_or_illegal_state(),
-                            Err(error) => {
-                                log::error!("Failed to get complete state: '{}'", error);
-                                self.to_agents
-                                    .complete_state(
-                                        request_id,
-                                        ank_base::CompleteState {
-                                            ..Default::default()
-                                        },
-                                    )
-                                    .await
-                                    .unwrap_or_illegal_state();
-                            }
-                        }
+                        continue;
                     }
-
-                    // [impl->swdd~server-provides-update-desired-state-interface~1]
-                    common::commands::RequestContent::UpdateStateRequest(update_state_request) => {
-                        log::debug!(
-                            "Received UpdateState. State '{:?}', update mask '{:?}'",
-                            update_state_request.state,
-                            update_state_request.update_mask
-                        );
-
-                        // [impl->swdd~update-desired-state-with-invalid-version~1]
-                        // [impl->swdd~update-desired-state-with-missing-version~1]
-                        // [impl->swdd~server-desired-state-field-conventions~1]
-                        let updated_desired_state = &update_state_request.state.desired_state;
-                        if let Err(error_message) = State::verify_api_version(updated_desired_state)
-                            .and_then(|_| State::verify_configs_format(updated_desired_state))
-                        {
-                            log::warn!("The CompleteState in the request has wrong format. {} -> ignoring the request", error_message);
-
-                            self.to_agents
-                                .error(request_id, error_message)
-                                .await
-                                .unwrap_or_illegal_state();
-                            continue;
-                        }
-
-                        // [impl->swdd~update-desired-state-with-update-mask~1]
-                        // [impl->swdd~update-desired-state-empty-update-mask~1]
-                        match self
-                            .server_state
-                            .update(update_state_request.state, update_state_request.update_mask)
-                        {
-                            Ok(Some((added_workloads, deleted_workloads))) => {
-                                log::info!(
-                                        "The update has {} new or updated workloads, {} workloads to delete",
-                                        added_workloads.len(),
-                                        deleted_workloads.len()
-                                    );
-
-                                // [impl->swdd~server-sets-state-of-new-workloads-to-pending~1]
-                                self.workload_states_map.initial_state(&added_workloads);
-
-                                let added_workloads_names = added_workloads
-                                    .iter()
-                                    .map(|x| x.instance_name.to_string())
-                                    .collect();
-                                let deleted_workloads_names = deleted_workloads
-                                    .iter()
-                                    .map(|x| x.instance_name.to_string())
-                                    .collect();
-
-                                // [impl->swdd~server-handles-not-started-deleted-workloads~1]
-                                let retained_deleted_workloads = self
-                                    .handle_not_started_deleted_workloads(deleted_workloads)
-                                    .await;
-
-                                let from_server_command =
-                                    FromServer::UpdateWorkload(UpdateWorkload {
-                                        added_workloads,
-                                        deleted_workloads: retained_deleted_workloads,
-                                    });
-                                self.to_agents
-                                    .send(from_server_command)
-                                    .await
-                                    .unwrap_or_illegal_state();
-                                log::debug!("Send UpdateStateSuccess for request '{}'", request_id);
-                                // [impl->swdd~server-update-state-success-response~1]
-                                self.to_agents
-                                    .update_state_success(
-                                        request_id,
-                                        added_workloads_names,
-                                        deleted_workloads_names,
-                                    )
-                                    .await
-                                    .unwrap_or_illegal_state();
-                            }
-                            Ok(None) => {
-                                log::debug!(
-                                "The current state and new state are identical -> nothing to do"
-                            );
-                                self.to_agents
-                                    .update_state_success(request_id, vec![], vec![])
-                                    .await
-                                    .unwrap_or_illegal_state();
-                            }
-                            Err(error_msg) => {
-                                // [impl->swdd~server-continues-on-invalid-updated-state~1]
-                                log::error!("Update rejected: '{error_msg}'",);
-                                self.to_agents
-                                    .error(request_id, format!("Update rejected: '{error_msg}'"))
-                                    .await
-                                    .unwrap_or_illegal_state();
-                            }
-                        }
+                    self.handle_agent_gone(method_obj.agent_name).await;
+                }
+                // [impl->swdd~server-provides-update-desired-state-interface~1]
+                ToServer::Request(request) => {
+                    let request_type = server_metrics::request_type(&request.request_content);
+                    let request_start = Instant::now();
+                    self.handle_request(request).await;
+                    // [impl->swdd~server-collects-metrics~1]
+                    if let Some(metrics) = &self.metrics {
+                        metrics.record_request(request_type, request_start.elapsed());
                     }
-                },
+                }
+                // [impl->swdd~server-relays-log-responses-to-requester~2]
+                ToServer::Response(connection_response) => {
+                    self.relay_log_response(
+                        &connection_response.connection_name,
+                        connection_response.response,
+                    )
+                    .await;
+                }
                 ToServer::UpdateWorkloadState(method_obj) => {
                     log::debug!(
                         "Received UpdateWorkloadState: '{:?}'",
                         method_obj.workload_states
                     );
 
+                    let snapshot = self.take_snapshot();
                     // [impl->swdd~server-stores-workload-state~1]
                     self.workload_states_map
                         .process_new_states(method_obj.workload_states.clone());
 
+                    // [impl->swdd~server-keeps-bounded-workload-state-history~1]
+                    self.workload_state_history
+                        .record(&method_obj.workload_states);
+                    self.workload_state_history
+                        .remove_unknown_workloads(&self.workload_states_map);
+
                     // [impl->swdd~server-cleans-up-state~1]
                     self.server_state.cleanup_state(&method_obj.workload_states);
 
@@ -330,110 +359,1087 @@ impl AnkaiosServer {
                         .update_workload_state(method_obj.workload_states)
                         .await
                         .unwrap_or_illegal_state();
+
+                    // [impl->swdd~server-sends-events-on-altered-fields~1]
+                    self.send_events(snapshot, AlteredFields::default()).await;
+                }
+                ToServer::Goodbye(goodbye) => {
+                    log::debug!("Received Goodbye from '{}'", goodbye.connection_name);
+                    // [impl->swdd~server-removes-subscriptions-of-gone-requesters~1]
+                    self.event_handler
+                        .remove_subscriptions_of_requester(&goodbye.connection_name);
+                    // [impl->swdd~server-cancels-log-campaigns-of-gone-requesters~1]
+                    self.cancel_log_campaigns_of_requester(&goodbye.connection_name)
+                        .await;
                 }
                 ToServer::Stop(_method_obj) => {
-                    log::debug!("Received Stop from communications server");
-                    // TODO: handle the call
+                    log::info!("Shutting down the Ankaios server.");
+                    // [impl->swdd~server-reports-readiness~1]
+                    if let Some(metrics) = &self.metrics {
+                        metrics.set_ready(false);
+                    }
+                    // [impl->swdd~server-stores-desired-state-on-stop~1]
+                    if let Err(error) = self
+                        .server_state
+                        .store_desired_state(self.revision_history.current_revision())
+                    {
+                        log::error!("Could not store the desired state on shutdown: '{}'", error);
+                    }
+                    // [impl->swdd~server-closes-connections-on-stop~1]
+                    self.to_agents.stop().await.unwrap_or_illegal_state();
                     break;
                 }
-                unknown_message => {
-                    log::warn!(
-                        "Received an unknown message from communications server: '{:?}'",
-                        unknown_message
+            }
+            self.update_state_metrics();
+        }
+    }
+
+    async fn handle_request(&mut self, request: Request) {
+        let Request {
+            request_id,
+            request_content,
+        } = request;
+        match request_content {
+            // [impl->swdd~server-provides-interface-get-complete-state~2]
+            // [impl->swdd~server-includes-id-in-control-interface-response~1]
+            common::commands::RequestContent::CompleteStateRequest(complete_state_request) => {
+                log::debug!(
+                    "Received CompleteStateRequest with id '{}' and field mask: '{:?}'",
+                    request_id,
+                    complete_state_request.field_mask
+                );
+                // [impl->swdd~server-rejects-invalid-label-selector~1]
+                if let Some(Err(error)) = complete_state_request
+                    .label_selector
+                    .as_deref()
+                    .map(str::parse::<LabelSelector>)
+                {
+                    log::warn!("Rejected CompleteStateRequest: '{}'", error);
+                    self.to_agents
+                        .error(request_id, error)
+                        .await
+                        .unwrap_or_illegal_state();
+                    return;
+                }
+                match self.server_state.get_complete_state_by_field_mask(
+                    complete_state_request,
+                    &self.workload_states_map,
+                ) {
+                    Ok(mut complete_state) => {
+                        // [impl->swdd~server-includes-revision-in-complete-state~1]
+                        complete_state.revision = Some(self.revision_history.current_revision());
+                        self.to_agents
+                            .complete_state(request_id, complete_state)
+                            .await
+                            .unwrap_or_illegal_state()
+                    }
+                    Err(error) => {
+                        log::error!("Failed to get complete state: '{}'", error);
+                        self.to_agents
+                            .complete_state(
+                                request_id,
+                                ank_base::CompleteState {
+                                    ..Default::default()
+                                },
+                            )
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
+                }
+            }
+
+            // [impl->swdd~server-provides-update-desired-state-interface~1]
+            common::commands::RequestContent::UpdateStateRequest(update_state_request) => {
+                if log::log_enabled!(log::Level::Debug) {
+                    // the secrets of the new state must not end up in the log
+                    let mut logged_state = update_state_request.state.desired_state.clone();
+                    logged_state.redact_secrets();
+                    log::debug!(
+                        "Received UpdateState. Desired state '{:?}', update mask '{:?}'",
+                        logged_state,
+                        update_state_request.update_mask
+                    );
+                }
+                if update_state_request.dry_run {
+                    self.dry_run_desired_state_update(
+                        request_id,
+                        update_state_request.state,
+                        update_state_request.update_mask,
+                    )
+                    .await;
+                } else {
+                    self.update_desired_state(
+                        request_id,
+                        update_state_request.state,
+                        update_state_request.update_mask,
+                        "UpdateStateRequest",
+                    )
+                    .await;
+                }
+            }
+            // [impl->swdd~server-provides-revisions-interface~1]
+            common::commands::RequestContent::RevisionsRequest(_) => {
+                log::debug!("Received RevisionsRequest with id '{}'", request_id);
+                self.to_agents
+                    .revisions(request_id, (&self.revision_history).into())
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+            // [impl->swdd~server-provides-rollback-interface~1]
+            common::commands::RequestContent::RollbackRequest(RollbackRequest { revision }) => {
+                log::debug!(
+                    "Received RollbackRequest with id '{}' to revision '{}'",
+                    request_id,
+                    revision
+                );
+                let Some(stored_revision) = self.revision_history.get(revision) else {
+                    log::warn!("Rollback rejected: revision '{}' not found.", revision);
+                    self.record_rejected_update(server_metrics::REJECTED_UNKNOWN_REVISION);
+                    let error_message = format!(
+                        "Rollback rejected: revision '{}' is not in the revision history",
+                        revision
+                    );
+                    // [impl->swdd~server-writes-audit-log~1]
+                    self.audit(
+                        AuditEntry::new(
+                            requester_from_request_id(&request_id),
+                            "RollbackRequest",
+                            vec![],
+                        )
+                        .reject(error_message.clone()),
                     );
+                    self.to_agents
+                        .error(request_id, error_message)
+                        .await
+                        .unwrap_or_illegal_state();
+                    return;
+                };
+
+                let rollback_state = CompleteState {
+                    desired_state: stored_revision.desired_state.clone(),
+                    ..Default::default()
+                };
+                log::info!("Rolling back the desired state to revision '{}'", revision);
+                self.update_desired_state(request_id, rollback_state, vec![], "RollbackRequest")
+                    .await;
+            }
+            // [impl->swdd~server-provides-events-interface~1]
+            common::commands::RequestContent::EventsRequest(EventsRequest { field_mask }) => {
+                log::debug!(
+                    "Received EventsRequest with id '{}' and field mask: '{:?}'",
+                    request_id,
+                    field_mask
+                );
+                self.subscribe_to_events(request_id, field_mask).await;
+            }
+            // [impl->swdd~server-provides-events-interface~1]
+            common::commands::RequestContent::EventsCancelRequest(_) => {
+                log::debug!("Received EventsCancelRequest with id '{}'", request_id);
+                if self.event_handler.remove_subscription(&request_id) {
+                    self.to_agents
+                        .events_cancel_accepted(request_id)
+                        .await
+                        .unwrap_or_illegal_state();
+                } else {
+                    self.to_agents
+                        .error(
+                            request_id.clone(),
+                            format!("No event subscription with id '{}'", request_id),
+                        )
+                        .await
+                        .unwrap_or_illegal_state();
+                }
+            }
+            // [impl->swdd~server-provides-audit-log-interface~1]
+            common::commands::RequestContent::AuditLogRequest(audit_log_request) => {
+                log::debug!("Received AuditLogRequest with id '{}'", request_id);
+                let Some(audit_log) = &self.audit_log else {
+                    self.to_agents
+                        .error(
+                            request_id,
+                            "The audit log is not enabled on the Ankaios server".to_string(),
+                        )
+                        .await
+                        .unwrap_or_illegal_state();
+                    return;
+                };
+                let max_entries = audit_log_request
+                    .tail
+                    .map_or(MAX_AUDIT_LOG_ENTRIES, |tail| {
+                        (tail as usize).min(MAX_AUDIT_LOG_ENTRIES)
+                    });
+                match audit_log.read(max_entries) {
+                    Ok(entries) => self
+                        .to_agents
+                        .audit_log(
+                            request_id,
+                            ank_base::AuditLog {
+                                entries: entries.into_iter().map(Into::into).collect(),
+                            },
+                        )
+                        .await
+                        .unwrap_or_illegal_state(),
+                    Err(err) => {
+                        log::error!("{}", err);
+                        self.to_agents
+                            .error(request_id, err.to_string())
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
+                }
+            }
+            // [impl->swdd~server-provides-workload-description-interface~1]
+            common::commands::RequestContent::WorkloadDescriptionRequest(
+                WorkloadDescriptionRequest {
+                    workload_name,
+                    config_names,
+                },
+            ) => {
+                log::debug!(
+                    "Received WorkloadDescriptionRequest with id '{}' for workload '{}'",
+                    request_id,
+                    workload_name
+                );
+                match self
+                    .server_state
+                    .get_redacted_workload_spec(&workload_name, &config_names)
+                {
+                    Ok(workload_spec) => {
+                        let workload_description = ank_base::WorkloadDescription {
+                            instance_name: Some(workload_spec.instance_name.into()),
+                            rendered_runtime_config: workload_spec.runtime_config,
+                            rendered_files: workload_spec
+                                .files
+                                .into_iter()
+                                .map(Into::into)
+                                .collect(),
+                            state_history: self
+                                .workload_state_history
+                                .get(&workload_name)
+                                .iter()
+                                .map(Into::into)
+                                .collect(),
+                        };
+                        self.to_agents
+                            .workload_description(request_id, workload_description)
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
+                    Err(error) => {
+                        log::warn!(
+                            "Could not describe workload '{}': '{}'",
+                            workload_name,
+                            error
+                        );
+                        self.to_agents
+                            .error(request_id, error)
+                            .await
+                            .unwrap_or_illegal_state();
+                    }
+                }
+            }
+            // [impl->swdd~server-forwards-logs-requests-to-agent~1]
+            common::commands::RequestContent::LogsRequest(logs_request) => {
+                log::debug!(
+                    "Received LogsRequest with id '{}' for workload '{}'",
+                    request_id,
+                    logs_request.workload_name
+                );
+                self.start_log_campaign(request_id, logs_request).await;
+            }
+            // [impl->swdd~server-cancels-log-campaigns~1]
+            common::commands::RequestContent::LogsCancelRequest(_) => {
+                log::debug!("Received LogsCancelRequest with id '{}'", request_id);
+                if let Some(instance_name) = self.log_campaign_store.remove_campaign(&request_id) {
+                    self.to_agents
+                        .workload_logs_cancel(
+                            request_id.clone(),
+                            instance_name.agent_name().to_owned(),
+                        )
+                        .await
+                        .unwrap_or_illegal_state();
+                    self.to_agents
+                        .logs_cancel_accepted(request_id)
+                        .await
+                        .unwrap_or_illegal_state();
+                } else {
+                    self.to_agents
+                        .error(
+                            request_id.clone(),
+                            format!("No log campaign with id '{}'", request_id),
+                        )
+                        .await
+                        .unwrap_or_illegal_state();
                 }
             }
         }
     }
 
-    // [impl->swdd~server-handles-not-started-deleted-workloads~1]
-    async fn handle_not_started_deleted_workloads(
+    // [impl->swdd~server-provides-update-desired-state-interface~1]
+    async fn update_desired_state(
         &mut self,
-        mut deleted_workloads: Vec<DeletedWorkload>,
-    ) -> Vec<DeletedWorkload> {
-        let mut deleted_states = vec![];
-        deleted_workloads.retain(|deleted_wl| {
-            if deleted_wl.instance_name.agent_name().is_empty()
-                || self.deleted_workload_never_started_on_agent(deleted_wl)
-            {
-                self.workload_states_map.remove(&deleted_wl.instance_name);
-                deleted_states.push(WorkloadState {
-                    instance_name: deleted_wl.instance_name.clone(),
-                    execution_state: ExecutionState::removed(),
+        request_id: String,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+        request: &str,
+    ) {
+        // [impl->swdd~server-writes-audit-log~1]
+        let audit_entry = AuditEntry::new(
+            requester_from_request_id(&request_id),
+            request,
+            update_mask.clone(),
+        );
+
+        if let Err(rejection) = self.verify_update(&new_state) {
+            self.record_rejected_update(rejection.reason);
+            self.audit(audit_entry.reject(rejection.message.clone()));
+            self.send_update_rejection(request_id, rejection).await;
+            return;
+        }
+
+        let snapshot = self.take_snapshot();
+        // [impl->swdd~update-desired-state-with-update-mask~1]
+        // [impl->swdd~update-desired-state-empty-update-mask~1]
+        match self
+            .server_state
+            .update(new_state, update_mask.clone(), &self.revision_history)
+        {
+            Ok(Some((added_workloads, deleted_workloads))) => {
+                log::info!(
+                    "The update has {} new or updated workloads, {} workloads to delete",
+                    added_workloads.len(),
+                    deleted_workloads.len()
+                );
+
+                // [impl->swdd~server-creates-revision-on-accepted-update~1]
+                self.add_revision(requester_from_request_id(&request_id), update_mask);
+
+                // [impl->swdd~server-sets-state-of-new-workloads-to-pending~1]
+                self.workload_states_map.initial_state(&added_workloads);
+
+                let added_workloads_names: Vec<String> = added_workloads
+                    .iter()
+                    .map(|x| x.instance_name.to_string())
+                    .collect();
+                let deleted_workloads_names: Vec<String> = deleted_workloads
+                    .iter()
+                    .map(|x| x.instance_name.to_string())
+                    .collect();
+                self.audit(audit_entry.accept(
+                    added_workloads_names.clone(),
+                    deleted_workloads_names.clone(),
+                    self.revision_history.current_revision(),
+                ));
+
+                // [impl->swdd~server-removes-subscriptions-of-gone-requesters~1]
+                for deleted_workload in &deleted_workloads {
+                    let instance_name = &deleted_workload.instance_name;
+                    if !added_workloads.iter().any(|added| {
+                        added.instance_name.agent_name() == instance_name.agent_name()
+                            && added.instance_name.workload_name() == instance_name.workload_name()
+                    }) {
+                        let requester = format!(
+                            "{}@{}",
+                            instance_name.agent_name(),
+                            instance_name.workload_name()
+                        );
+                        self.event_handler
+                            .remove_subscriptions_of_requester(&requester);
+                        // [impl->swdd~server-cancels-log-campaigns-of-gone-requesters~1]
+                        self.cancel_log_campaigns_of_requester(&requester).await;
+                    }
+                }
+
+                // [impl->swdd~server-handles-not-started-deleted-workloads~1]
+                let retained_deleted_workloads = self
+                    .handle_not_started_deleted_workloads(deleted_workloads)
+                    .await;
+
+                let from_server_command = FromServer::UpdateWorkload(UpdateWorkload {
+                    added_workloads,
+                    deleted_workloads: retained_deleted_workloads,
                 });
+                self.to_agents
+                    .send(from_server_command)
+                    .await
+                    .unwrap_or_illegal_state();
+                log::debug!("Send UpdateStateSuccess for request '{}'", request_id);
+                // [impl->swdd~server-update-state-success-response~1]
+                self.to_agents
+                    .update_state_success(
+                        request_id,
+                        added_workloads_names,
+                        deleted_workloads_names,
+                    )
+                    .await
+                    .unwrap_or_illegal_state();
+
+                // [impl->swdd~server-sends-events-on-altered-fields~1]
+                self.send_events(snapshot, AlteredFields::default()).await;
+            }
+            Ok(None) => {
+                log::debug!("The current state and new state are identical -> nothing to do");
+
+                // the update can still change fields not affecting workloads, e.g. config items
+                // [impl->swdd~server-creates-revision-on-accepted-update~1]
+                self.add_revision(requester_from_request_id(&request_id), update_mask);
+                self.audit(audit_entry.accept(
+                    vec![],
+                    vec![],
+                    self.revision_history.current_revision(),
+                ));
+                self.to_agents
+                    .update_state_success(request_id, vec![], vec![])
+                    .await
+                    .unwrap_or_illegal_state();
+
+                // [impl->swdd~server-sends-events-on-altered-fields~1]
+                self.send_events(snapshot, AlteredFields::default()).await;
+            }
+            Err(error_msg) => {
+                // [impl->swdd~server-continues-on-invalid-updated-state~1]
+                log::error!("Update rejected: '{error_msg}'",);
+                self.record_rejected_update(server_metrics::REJECTED_INVALID_STATE);
+                self.audit(audit_entry.reject(format!("Update rejected: '{error_msg}'")));
+                self.to_agents
+                    .error(request_id, format!("Update rejected: '{error_msg}'"))
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+        }
+    }
 
-                return false;
+    // [impl->swdd~server-dry-runs-update-state-request~1]
+    async fn dry_run_desired_state_update(
+        &mut self,
+        request_id: String,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) {
+        if let Err(rejection) = self.verify_update(&new_state) {
+            self.send_update_rejection(request_id, rejection).await;
+            return;
+        }
+
+        match self.server_state.dry_run_update(new_state, update_mask) {
+            Ok(added_deleted_workloads) => {
+                let (added_workloads, deleted_workloads) =
+                    added_deleted_workloads.unwrap_or_default();
+                log::debug!(
+                    "The dry run of the update has {} new or updated workloads, {} workloads to delete",
+                    added_workloads.len(),
+                    deleted_workloads.len()
+                );
+                self.to_agents
+                    .dry_run_update_state_success(
+                        request_id,
+                        added_workloads
+                            .iter()
+                            .map(|x| x.instance_name.to_string())
+                            .collect(),
+                        deleted_workloads
+                            .iter()
+                            .map(|x| x.instance_name.to_string())
+                            .collect(),
+                    )
+                    .await
+                    .unwrap_or_illegal_state();
             }
-            true
-        });
-        if !deleted_states.is_empty() {
-            log::debug!(
-                "Send UpdateWorkloadState for not started deleted workloads: '{:?}'",
-                deleted_states
+            Err(error_msg) => {
+                log::debug!("Dry run of the update rejected: '{error_msg}'");
+                self.to_agents
+                    .error(request_id, format!("Update rejected: '{error_msg}'"))
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+        }
+    }
+
+    // [impl->swdd~update-desired-state-with-invalid-version~1]
+    // [impl->swdd~update-desired-state-with-missing-version~1]
+    // [impl->swdd~server-desired-state-field-conventions~1]
+    // [impl->swdd~server-rejects-update-with-stale-revision~1]
+    fn verify_update(&self, new_state: &CompleteState) -> Result<(), UpdateRejection> {
+        let updated_desired_state = &new_state.desired_state;
+        if let Err(error_message) = State::verify_api_version(updated_desired_state)
+            .and_then(|_| State::verify_configs_format(updated_desired_state))
+            .and_then(|_| State::verify_secrets_format(updated_desired_state))
+        {
+            log::warn!(
+                "The CompleteState in the request has wrong format. {} -> ignoring the request",
+                error_message
             );
-            self.to_agents
-                .update_workload_state(deleted_states)
-                .await
-                .unwrap_or_illegal_state();
+            return Err(UpdateRejection {
+                reason: server_metrics::REJECTED_INVALID_FORMAT,
+                message: error_message,
+                code: None,
+            });
         }
 
-        deleted_workloads
+        let current_revision = self.revision_history.current_revision();
+        if let Some(expected_revision) = new_state.revision {
+            if expected_revision != current_revision {
+                log::warn!(
+                    "Update rejected: expected revision '{}', current revision is '{}'",
+                    expected_revision,
+                    current_revision
+                );
+                return Err(UpdateRejection {
+                    reason: server_metrics::REJECTED_STALE_REVISION,
+                    message: format!(
+                        "Update rejected: the desired state was changed concurrently (expected revision '{}', current revision '{}')",
+                        expected_revision, current_revision
+                    ),
+                    code: Some(ank_base::ErrorCode::StaleRevision),
+                });
+            }
+        }
+        Ok(())
     }
 
-    fn deleted_workload_never_started_on_agent(&self, deleted_workload: &DeletedWorkload) -> bool {
-        !self
-            .server_state
-            .contains_connected_agent(deleted_workload.instance_name.agent_name())
-            && self
-                .workload_states_map
-                .get_workload_state_for_workload(&deleted_workload.instance_name)
-                .is_some_and(|current_execution_state| {
-                    current_execution_state.is_pending_initial()
-                })
+    async fn send_update_rejection(&mut self, request_id: String, rejection: UpdateRejection) {
+        match rejection.code {
+            Some(code) => self
+                .to_agents
+                .error_with_code(request_id, rejection.message, code)
+                .await
+                .unwrap_or_illegal_state(),
+            None => self
+                .to_agents
+                .error(request_id, rejection.message)
+                .await
+                .unwrap_or_illegal_state(),
+        }
     }
-}
 
-//////////////////////////////////////////////////////////////////////////////
-//                 ########  #######    #########  #########                //
-//                    ##     ##        ##             ##                    //
-//                    ##     #####     #########      ##                    //
-//                    ##     ##                ##     ##                    //
-//                    ##     #######   #########      ##                    //
-//////////////////////////////////////////////////////////////////////////////
+    // [impl->swdd~server-provides-events-interface~1]
+    async fn subscribe_to_events(&mut self, request_id: String, field_mask: Vec<String>) {
+        match self.server_state.get_complete_state_by_field_mask(
+            CompleteStateRequest {
+                field_mask: field_mask.clone(),
+                label_selector: None,
+            },
+            &self.workload_states_map,
+        ) {
+            Ok(mut complete_state) => {
+                complete_state.revision = Some(self.revision_history.current_revision());
+                self.event_handler
+                    .add_subscription(request_id.clone(), field_mask);
+                // [impl->swdd~server-sends-initial-event~1]
+                self.to_agents
+                    .event(
+                        request_id,
+                        ank_base::Event {
+                            complete_state: Some(complete_state),
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+            Err(error) => {
+                log::warn!("Event subscription rejected: '{}'", error);
+                self.to_agents
+                    .error(
+                        request_id,
+                        format!("Event subscription rejected: '{}'", error),
+                    )
+                    .await
+                    .unwrap_or_illegal_state();
+            }
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use std::collections::HashMap;
+    // [impl->swdd~server-collects-metrics~1]
+    fn update_state_metrics(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.update_state(self.server_state.get_agent_map(), &self.workload_states_map);
+        }
+    }
 
-    use super::AnkaiosServer;
-    use crate::ankaios_server::server_state::{MockServerState, UpdateStateError};
-    use crate::ankaios_server::{create_from_server_channel, create_to_server_channel};
+    // [impl->swdd~server-collects-metrics~1]
+    fn record_rejected_update(&self, reason: &'static str) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_rejected_update(reason);
+        }
+    }
 
-    use super::ank_base;
-    use api::ank_base::WorkloadMap;
-    use common::commands::{
-        AgentLoadStatus, CompleteStateRequest, ServerHello, UpdateWorkload, UpdateWorkloadState,
-    };
-    use common::from_server_interface::FromServer;
-    use common::objects::{
-        generate_test_stored_workload_spec, generate_test_workload_spec_with_param,
-        generate_test_workload_states_map_with_data, CompleteState, CpuUsage, DeletedWorkload,
-        ExecutionState, ExecutionStateEnum, FreeMemory, PendingSubstate, State,
-        WorkloadInstanceName, WorkloadState,
-    };
-    use common::test_utils::generate_test_proto_workload_with_param;
-    use common::to_server_interface::ToServerInterface;
-    use mockall::predicate;
+    // Snapshots are only needed to detect altered fields for event subscribers.
+    fn take_snapshot(&self) -> Option<StateSnapshot> {
+        self.event_handler.has_subscribers().then(|| StateSnapshot {
+            desired_state: self.server_state.get_desired_state().clone(),
+            workload_states: self.workload_states_map.clone(),
+        })
+    }
 
-    const AGENT_A: &str = "agent_A";
-    const AGENT_B: &str = "agent_B";
-    const WORKLOAD_NAME_1: &str = "workload_1";
-    const WORKLOAD_NAME_2: &str = "workload_2";
-    const WORKLOAD_NAME_3: &str = "workload_3";
-    const RUNTIME_NAME: &str = "runtime";
-    const REQUEST_ID_A: &str = "agent_A@id1";
+    // [impl->swdd~server-sends-events-on-altered-fields~1]
+    async fn send_events(
+        &mut self,
+        snapshot: Option<StateSnapshot>,
+        mut altered_fields: AlteredFields,
+    ) {
+        let Some(snapshot) = snapshot else {
+            return;
+        };
+        altered_fields.extend(snapshot.altered_fields(
+            self.server_state.get_desired_state(),
+            &self.workload_states_map,
+        ));
+        if altered_fields.is_empty() {
+            return;
+        }
 
-    // [utest->swdd~server-uses-async-channels~1]
+        for (request_id, fields) in self.event_handler.matching_subscriptions(&altered_fields) {
+            let mut existing_fields = fields.added.clone();
+            existing_fields.extend(fields.updated.iter().cloned());
+            // an empty field mask would return the complete state
+            let mut complete_state = if existing_fields.is_empty() {
+                ank_base::CompleteState::default()
+            } else {
+                self.server_state
+                    .get_complete_state_by_field_mask(
+                        CompleteStateRequest {
+                            field_mask: existing_fields,
+                            label_selector: None,
+                        },
+                        &self.workload_states_map,
+                    )
+                    .unwrap_or_else(|error| {
+                        log::warn!(
+                            "Failed to get the state for event '{}': '{}'",
+                            request_id,
+                            error
+                        );
+                        ank_base::CompleteState::default()
+                    })
+            };
+            complete_state.revision = Some(self.revision_history.current_revision());
+
+            log::debug!("Sending event to '{}': '{:?}'", request_id, fields);
+            self.to_agents
+                .event(
+                    request_id,
+                    ank_base::Event {
+                        complete_state: Some(complete_state),
+                        added_fields: fields.added,
+                        updated_fields: fields.updated,
+                        removed_fields: fields.removed,
+                    },
+                )
+                .await
+                .unwrap_or_illegal_state();
+        }
+    }
+
+    // [impl->swdd~server-forwards-logs-requests-to-agent~1]
+    async fn start_log_campaign(&mut self, request_id: String, logs_request: LogsRequest) {
+        let error_message = match self
+            .server_state
+            .get_workload_spec(&logs_request.workload_name)
+        {
+            None => format!(
+                "Cannot get logs: workload '{}' does not exist",
+                logs_request.workload_name
+            ),
+            Some(workload_spec)
+                if !self
+                    .server_state
+                    .contains_connected_agent(workload_spec.instance_name.agent_name()) =>
+            {
+                format!(
+                    "Cannot get logs: agent '{}' of workload '{}' is not connected",
+                    workload_spec.instance_name.agent_name(),
+                    logs_request.workload_name
+                )
+            }
+            Some(workload_spec) => {
+                // [impl->swdd~server-stores-log-campaigns~1]
+                self.log_campaign_store
+                    .add_campaign(request_id.clone(), workload_spec.instance_name.clone());
+                self.to_agents
+                    .workload_logs_request(WorkloadLogsRequest {
+                        request_id,
+                        instance_name: workload_spec.instance_name,
+                        runtime: workload_spec.runtime,
+                        follow: logs_request.follow,
+                        since: logs_request.since,
+                        tail: logs_request.tail,
+                    })
+                    .await
+                    .unwrap_or_illegal_state();
+                return;
+            }
+        };
+
+        log::warn!("{}", error_message);
+        self.to_agents
+            .error(request_id, error_message)
+            .await
+            .unwrap_or_illegal_state();
+    }
+
+    // [impl->swdd~server-relays-log-responses-to-requester~2]
+    async fn relay_log_response(&mut self, connection_name: &str, response: ank_base::Response) {
+        match self
+            .log_campaign_store
+            .get_agent_of_campaign(&response.request_id)
+        {
+            None => {
+                log::debug!(
+                    "Dropping response '{}' of an unknown log campaign",
+                    response.request_id
+                );
+                return;
+            }
+            Some(agent_name) if agent_name != connection_name => {
+                log::warn!(
+                    "Dropping response '{}' from '{}' as the log campaign is served by agent '{}'",
+                    response.request_id,
+                    connection_name,
+                    agent_name
+                );
+                return;
+            }
+            Some(_) => {}
+        }
+
+        if matches!(
+            response.response_content,
+            Some(ank_base::response::ResponseContent::LogsStopResponse(_))
+                | Some(ank_base::response::ResponseContent::Error(_))
+        ) {
+            log::debug!("Log campaign '{}' ended", response.request_id);
+            self.log_campaign_store
+                .remove_campaign(&response.request_id);
+        }
+
+        self.to_agents
+            .response(response)
+            .await
+            .unwrap_or_illegal_state();
+    }
+
+    // [impl->swdd~server-stops-log-campaigns-of-gone-agents~1]
+    async fn stop_log_campaigns_of_agent(&mut self, agent_name: &str) {
+        for (request_id, instance_name) in self
+            .log_campaign_store
+            .remove_campaigns_of_agent(agent_name)
+        {
+            log::debug!(
+                "Stopping log campaign '{}' as agent '{}' is gone",
+                request_id,
+                agent_name
+            );
+            self.to_agents
+                .logs_stop_response(request_id, instance_name)
+                .await
+                .unwrap_or_illegal_state();
+        }
+    }
+
+    // [impl->swdd~server-cancels-log-campaigns-of-gone-requesters~1]
+    async fn cancel_log_campaigns_of_requester(&mut self, requester: &str) {
+        for (request_id, agent_name) in self
+            .log_campaign_store
+            .remove_campaigns_of_requester(requester)
+        {
+            log::debug!(
+                "Cancelling log campaign '{}' as its requester is gone",
+                request_id
+            );
+            self.to_agents
+                .workload_logs_cancel(request_id, agent_name)
+                .await
+                .unwrap_or_illegal_state();
+        }
+    }
+
+    // [impl->swdd~server-creates-revision-on-accepted-update~1]
+    fn add_revision(&mut self, requester: String, update_mask: Vec<String>) {
+        if let Some(revision) = self.revision_history.add(
+            self.server_state.get_desired_state(),
+            requester,
+            update_mask,
+        ) {
+            log::debug!("Created revision '{}' of the desired state.", revision);
+        }
+    }
+
+    // A failing audit log shall not block the update of the desired state.
+    // [impl->swdd~server-writes-audit-log~1]
+    fn audit(&mut self, entry: AuditEntry) {
+        if let Some(audit_log) = self.audit_log.as_mut() {
+            if let Err(err) = audit_log.append(&entry) {
+                log::error!("{}", err);
+            }
+        }
+    }
+
+    // [impl->swdd~server-restores-persisted-state~1]
+    fn restore_persisted_state(
+        &mut self,
+        startup_state: Option<CompleteState>,
+    ) -> Result<Option<CompleteState>, String> {
+        let Some(mut state_store) = self.state_store.take() else {
+            return Ok(startup_state);
+        };
+
+        let persisted_state = state_store.load().map_err(|err| err.to_string())?;
+        self.server_state.set_state_store(state_store);
+
+        match persisted_state {
+            Some(PersistedState {
+                desired_state,
+                last_revision,
+                scheduled_agents,
+            }) => {
+                // [impl->swdd~server-continues-persisted-revision~2]
+                self.revision_history.restore(
+                    &desired_state,
+                    last_revision,
+                    STARTUP_REQUESTER.to_string(),
+                );
+                // the restored agents are not connected yet and are lost if they do not connect in time
+                // [impl->swdd~server-detects-lost-agents~1]
+                for agent_name in scheduled_agents.values() {
+                    self.agent_liveness.agent_gone(agent_name);
+                }
+                // [impl->swdd~server-restores-scheduled-agents~1]
+                self.server_state.restore_scheduled_agents(scheduled_agents);
+                if startup_state.is_some() {
+                    log::info!(
+                        "Restoring the persisted desired state, the startup manifest is ignored."
+                    );
+                } else {
+                    log::info!("Restoring the persisted desired state.");
+                }
+                Ok(Some(CompleteState {
+                    desired_state,
+                    ..Default::default()
+                }))
+            }
+            None => Ok(startup_state),
+        }
+    }
+
+    // [impl->swdd~server-handles-not-started-deleted-workloads~1]
+    // [impl->swdd~server-schedules-pending-workloads~1]
+    async fn handle_agent_gone(&mut self, agent_name: String) {
+        // [impl->swdd~server-tracks-agent-heartbeats~1]
+        self.agent_liveness.agent_gone(&agent_name);
+        // [impl->swdd~server-removes-subscriptions-of-gone-requesters~1]
+        self.event_handler
+            .remove_subscriptions_of_requester(&agent_name);
+        // [impl->swdd~server-stops-log-campaigns-of-gone-agents~1]
+        self.stop_log_campaigns_of_agent(&agent_name).await;
+        // [impl->swdd~server-cancels-log-campaigns-of-gone-requesters~1]
+        self.cancel_log_campaigns_of_requester(&agent_name).await;
+        let snapshot = self.take_snapshot();
+
+        // [impl->swdd~server-removes-disconnected-agents-from-state~1]
+        self.server_state.remove_agent(&agent_name);
+
+        // [impl->swdd~server-set-workload-state-on-disconnect~1]
+        self.workload_states_map.agent_disconnected(&agent_name);
+
+        let disconnected_workload_states = self
+            .workload_states_map
+            .get_workload_state_for_agent(&agent_name);
+
+        // [impl->swdd~server-keeps-bounded-workload-state-history~1]
+        self.workload_state_history
+            .record(&disconnected_workload_states);
+
+        // communicate the workload execution states to other agents
+        // [impl->swdd~server-distribute-workload-state-on-disconnect~1]
+        self.to_agents
+            .update_workload_state(disconnected_workload_states)
+            .await
+            .unwrap_or_illegal_state();
+
+        // [impl->swdd~server-sends-events-on-altered-fields~1]
+        self.send_events(
+            snapshot,
+            AlteredFields {
+                removed: vec![agent_path(&agent_name)],
+                ..Default::default()
+            },
+        )
+        .await;
+    }
+
+    // [impl->swdd~server-disconnects-stale-agents~1]
+    async fn disconnect_stale_agents(&mut self) {
+        for agent_name in self.agent_liveness.remove_stale_agents() {
+            log::warn!(
+                "Agent '{}' missed its heartbeat timeout. Handling it as disconnected.",
+                agent_name
+            );
+            // the connection is closed to let a still running agent reconnect and resynchronize
+            self.to_agents
+                .disconnect_agent(agent_name.clone())
+                .await
+                .unwrap_or_illegal_state();
+            self.handle_agent_gone(agent_name).await;
+        }
+    }
+
+    // [impl->swdd~server-reschedules-workloads-of-lost-agents~1]
+    async fn reschedule_workloads_of_lost_agents(&mut self) {
+        let lost_agents = self.agent_liveness.remove_lost_agents();
+        if lost_agents.is_empty() {
+            return;
+        }
+        for agent_name in lost_agents {
+            log::warn!(
+                "Agent '{}' is gone longer than the heartbeat timeout. Scheduling its workloads to other agents.",
+                agent_name
+            );
+            self.server_state.agent_lost(agent_name);
+        }
+        self.schedule_pending_workloads(None).await;
+    }
+
+    async fn schedule_pending_workloads(&mut self, connecting_agent: Option<&str>) {
+        let Some((added_workloads, deleted_workloads)) = self
+            .server_state
+            .schedule_pending_workloads(self.revision_history.current_revision())
+        else {
+            return;
+        };
+        log::debug!(
+            "Scheduled {} pending workloads, {} workloads to delete",
+            added_workloads.len(),
+            deleted_workloads.len()
+        );
+
+        // [impl->swdd~server-sets-state-of-new-workloads-to-pending~1]
+        self.workload_states_map.initial_state(&added_workloads);
+
+        // [impl->swdd~server-handles-not-started-deleted-workloads~1]
+        let retained_deleted_workloads = self
+            .handle_not_started_deleted_workloads(deleted_workloads)
+            .await;
+
+        // the workloads of a connecting agent are sent with the ServerHello
+        let added_workloads: Vec<WorkloadSpec> = added_workloads
+            .into_iter()
+            .filter(|workload| Some(workload.instance_name.agent_name()) != connecting_agent)
+            .collect();
+
+        if !added_workloads.is_empty() || !retained_deleted_workloads.is_empty() {
+            self.to_agents
+                .send(FromServer::UpdateWorkload(UpdateWorkload {
+                    added_workloads,
+                    deleted_workloads: retained_deleted_workloads,
+                }))
+                .await
+                .unwrap_or_illegal_state();
+        }
+    }
+
+    async fn handle_not_started_deleted_workloads(
+        &mut self,
+        mut deleted_workloads: Vec<DeletedWorkload>,
+    ) -> Vec<DeletedWorkload> {
+        let mut deleted_states = vec![];
+        deleted_workloads.retain(|deleted_wl| {
+            if deleted_wl.instance_name.agent_name().is_empty()
+                || self.deleted_workload_never_started_on_agent(deleted_wl)
+            {
+                self.workload_states_map.remove(&deleted_wl.instance_name);
+                deleted_states.push(WorkloadState {
+                    instance_name: deleted_wl.instance_name.clone(),
+                    execution_state: ExecutionState::removed(),
+                });
+
+                return false;
+            }
+            true
+        });
+        if !deleted_states.is_empty() {
+            log::debug!(
+                "Send UpdateWorkloadState for not started deleted workloads: '{:?}'",
+                deleted_states
+            );
+            self.to_agents
+                .update_workload_state(deleted_states)
+                .await
+                .unwrap_or_illegal_state();
+        }
+
+        deleted_workloads
+    }
+
+    fn deleted_workload_never_started_on_agent(&self, deleted_workload: &DeletedWorkload) -> bool {
+        !self
+            .server_state
+            .contains_connected_agent(deleted_workload.instance_name.agent_name())
+            && self
+                .workload_states_map
+                .get_workload_state_for_workload(&deleted_workload.instance_name)
+                .is_some_and(|current_execution_state| current_execution_state.is_pending_initial())
+    }
+}
+
+// The request id is prefixed with the names of the connection and of the workload the
+// request originates from, e.g. 'agent_A@nginx@<id>' or 'cli-conn-<session>-<uuid>@<id>'.
+fn requester_from_request_id(request_id: &str) -> String {
+    request_id
+        .rsplit_once('@')
+        .map(|(requester, _)| requester.to_owned())
+        .unwrap_or_default()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use super::{AnkaiosServer, ServerMetrics};
+    use crate::ankaios_server::audit_log::{
+        AuditEntry, AuditLogError, MockAuditLog, MAX_AUDIT_LOG_ENTRIES,
+    };
+    use crate::ankaios_server::server_state::{MockServerState, UpdateStateError};
+    use crate::ankaios_server::state_store::{MockStateStore, PersistedState, StateStoreError};
+    use crate::ankaios_server::{create_from_server_channel, create_to_server_channel};
+
+    use super::ank_base;
+    use api::ank_base::WorkloadMap;
+    use common::commands::{
+        AgentLoadStatus, CompleteStateRequest, ConnectionResponse, DisconnectAgent, LogsRequest,
+        ServerHello, Stop, UpdateWorkload, UpdateWorkloadState, WorkloadLogsCancel,
+        WorkloadLogsRequest,
+    };
+    use common::from_server_interface::FromServer;
+    use common::objects::{
+        generate_test_stored_workload_spec, generate_test_workload_spec_with_param,
+        generate_test_workload_states_map_with_data, AgentMap, CompleteState, CpuUsage,
+        DeletedWorkload, ExecutionState, ExecutionStateEnum, FreeMemory, PendingSubstate, State,
+        WorkloadInstanceName, WorkloadState,
+    };
+    use common::test_utils::generate_test_proto_workload_with_param;
+    use common::to_server_interface::{ToServer, ToServerInterface, ToServerSender};
+    use mockall::predicate;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const WORKLOAD_NAME_3: &str = "workload_3";
+    const RUNTIME_NAME: &str = "runtime";
+    const REQUEST_ID_A: &str = "agent_A@id1";
+    const REQUEST_ID_B: &str = "agent_A@id2";
+
+    // [utest->swdd~server-uses-async-channels~1]
     // [utest->swdd~server-fails-on-invalid-startup-state~1]
     #[tokio::test]
     async fn utest_server_start_fail_on_invalid_startup_manifest() {
@@ -460,6 +1466,7 @@ mod tests {
             .with(
                 mockall::predicate::eq(startup_state.clone()),
                 mockall::predicate::eq(vec![]),
+                mockall::predicate::always(),
             )
             .once()
             .return_const(Err(UpdateStateError::CycleInDependencies(
@@ -544,6 +1551,7 @@ mod tests {
             .with(
                 mockall::predicate::eq(new_state.clone()),
                 mockall::predicate::eq(update_mask.clone()),
+                mockall::predicate::always(),
             )
             .once()
             .in_sequence(&mut seq)
@@ -559,6 +1567,7 @@ mod tests {
             .with(
                 mockall::predicate::eq(fixed_state.clone()),
                 mockall::predicate::eq(update_mask.clone()),
+                mockall::predicate::always(),
             )
             .once()
             .in_sequence(&mut seq)
@@ -567,6 +1576,9 @@ mod tests {
                 deleted_workloads.clone(),
             ))));
 
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
         let server_task = tokio::spawn(async move { server.start(None).await });
@@ -611,6 +1623,7 @@ mod tests {
                     ank_base::UpdateStateSuccess {
                         added_workloads: vec![updated_workload.instance_name.to_string()],
                         deleted_workloads: Vec::new(),
+                        dry_run: false,
                     }
                 )),
             })
@@ -658,6 +1671,7 @@ mod tests {
             .with(
                 mockall::predicate::eq(startup_state.clone()),
                 mockall::predicate::eq(vec![]),
+                mockall::predicate::always(),
             )
             .once()
             .return_const(Ok(Some((
@@ -665,6 +1679,9 @@ mod tests {
                 deleted_workloads.clone(),
             ))));
 
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
         let server_handle = server.start(Some(startup_state));
@@ -697,72 +1714,348 @@ mod tests {
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
-    // [utest->swdd~server-uses-async-channels~1]
-    // [utest->swdd~server-sends-all-workloads-on-start~2]
-    // [utest->swdd~agent-from-agent-field~1]
-    // [utest->swdd~server-starts-without-startup-config~1]
-    // [utest->swdd~server-stores-newly-connected-agent~1]
+    // [utest->swdd~server-restores-persisted-state~1]
+    // [utest->swdd~server-restores-scheduled-agents~1]
     #[tokio::test]
-    async fn utest_server_sends_workloads_and_workload_states() {
+    async fn utest_server_start_restores_persisted_state_instead_of_startup_manifest() {
         let _ = env_logger::builder().is_test(true).try_init();
         let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
         let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
-        let mut server = AnkaiosServer::new(server_receiver, to_agents);
-
-        let w1 = generate_test_workload_spec_with_param(
-            AGENT_A.to_owned(),
-            WORKLOAD_NAME_1.to_owned(),
+        let startup_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
             RUNTIME_NAME.to_string(),
         );
-
-        let w2 = generate_test_workload_spec_with_param(
-            AGENT_B.to_owned(),
-            WORKLOAD_NAME_2.to_owned(),
+        let persisted_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_2.to_string(),
             RUNTIME_NAME.to_string(),
         );
 
-        let mut mock_server_state = MockServerState::new();
+        let startup_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), startup_workload.into())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let persisted_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_2.to_owned(),
+                    persisted_workload.clone().into(),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
 
-        mock_server_state.expect_cleanup_state().return_const(());
+        let scheduled_agents = HashMap::from([(WORKLOAD_NAME_3.to_owned(), AGENT_B.to_owned())]);
 
-        let mut seq = mockall::Sequence::new();
-        mock_server_state
-            .expect_get_workloads_for_agent()
-            .with(mockall::predicate::eq(AGENT_A.to_string()))
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
             .once()
-            .in_sequence(&mut seq)
-            .return_const(vec![w1.clone()]);
+            .return_const(Ok(Some(PersistedState {
+                desired_state: persisted_state.desired_state.clone(),
+                scheduled_agents: scheduled_agents.clone(),
+                ..Default::default()
+            })));
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.set_state_store(Box::new(mock_state_store));
 
+        let mut mock_server_state = MockServerState::new();
+        let mut seq = mockall::Sequence::new();
         mock_server_state
-            .expect_add_agent()
-            .with(predicate::eq(AGENT_A.to_owned()))
+            .expect_set_state_store()
             .once()
             .in_sequence(&mut seq)
             .return_const(());
-
         mock_server_state
-            .expect_get_workloads_for_agent()
-            .with(mockall::predicate::eq(AGENT_B.to_string()))
+            .expect_restore_scheduled_agents()
+            .with(mockall::predicate::eq(scheduled_agents))
             .once()
             .in_sequence(&mut seq)
-            .return_const(vec![w2.clone()]);
-
+            .return_const(());
         mock_server_state
-            .expect_add_agent()
-            .with(predicate::eq(AGENT_B.to_owned()))
+            .expect_update()
+            .with(
+                mockall::predicate::eq(persisted_state),
+                mockall::predicate::eq(vec![]),
+                mockall::predicate::always(),
+            )
             .once()
             .in_sequence(&mut seq)
-            .return_const(());
-
+            .return_const(Ok(Some((vec![persisted_workload.clone()], vec![]))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
-        let server_task = tokio::spawn(async move { server.start(None).await });
+        let server_handle = server.start(Some(startup_state));
 
-        // first agent connects to the server
-        let agent_hello_result = to_server.agent_hello(AGENT_A.to_string()).await;
-        assert!(agent_hello_result.is_ok());
+        drop(to_server);
+        tokio::join!(server_handle).0.unwrap();
+
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![persisted_workload],
+                deleted_workloads: vec![],
+            })
+        );
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-continues-persisted-revision~2]
+    // [utest->swdd~server-stores-desired-state-on-stop~1]
+    #[tokio::test]
+    async fn utest_server_keeps_persisted_revision_on_restart_without_changes() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        const PERSISTED_REVISION: u64 = 3;
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
+            .once()
+            .return_const(Ok(Some(PersistedState {
+                desired_state: State::default(),
+                last_revision: PERSISTED_REVISION,
+                ..Default::default()
+            })));
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.set_state_store(Box::new(mock_state_store));
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_set_state_store()
+            .once()
+            .return_const(());
+        mock_server_state
+            .expect_restore_scheduled_agents()
+            .once()
+            .return_const(());
+        mock_server_state
+            .expect_update()
+            .withf(|new_state, update_mask, revision_history| {
+                *new_state == CompleteState::default()
+                    && update_mask.is_empty()
+                    && revision_history.revision_of(&new_state.desired_state) == PERSISTED_REVISION
+            })
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        mock_server_state
+            .expect_store_desired_state()
+            .with(mockall::predicate::eq(PERSISTED_REVISION))
+            .once()
+            .return_const(Ok(()));
+        server.server_state = mock_server_state;
+
+        assert!(to_server.stop().await.is_ok());
+        assert!(server.start(None).await.is_ok());
+
+        assert_eq!(
+            server.revision_history.current_revision(),
+            PERSISTED_REVISION
+        );
+    }
+
+    // [utest->swdd~server-continues-persisted-revision~2]
+    #[tokio::test]
+    async fn utest_server_rejects_update_with_revision_before_persisted_revision() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        const REVISION_BEFORE_RESTART: u64 = 3;
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
+            .once()
+            .return_const(Ok(Some(PersistedState {
+                desired_state: State::default(),
+                last_revision: REVISION_BEFORE_RESTART,
+                ..Default::default()
+            })));
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.set_state_store(Box::new(mock_state_store));
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_set_state_store()
+            .once()
+            .return_const(());
+        mock_server_state
+            .expect_restore_scheduled_agents()
+            .once()
+            .return_const(());
+        mock_server_state
+            .expect_update()
+            .with(
+                mockall::predicate::eq(CompleteState::default()),
+                mockall::predicate::eq(vec![]),
+                mockall::predicate::always(),
+            )
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let update_state = CompleteState {
+            revision: Some(REVISION_BEFORE_RESTART - 1),
+            ..Default::default()
+        };
+        assert!(to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, vec![])
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(ank_base::Error {
+                    code,
+                    ..
+                })),
+            })) if request_id == REQUEST_ID_A && code == ank_base::ErrorCode::StaleRevision as i32
+        ));
+    }
+
+    // [utest->swdd~server-restores-persisted-state~1]
+    #[tokio::test]
+    async fn utest_server_start_fails_on_unreadable_persisted_state() {
+        let (_to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
+            .once()
+            .return_const(Err(StateStoreError::Parse("invalid yaml".to_string())));
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.set_state_store(Box::new(mock_state_store));
+        server.server_state = MockServerState::new();
+
+        let result = server.start(None).await;
+        assert_eq!(
+            result,
+            Err("Could not parse the persisted state: 'invalid yaml'".into())
+        );
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-uses-async-channels~1]
+    // [utest->swdd~server-sends-all-workloads-on-start~2]
+    // [utest->swdd~agent-from-agent-field~1]
+    // [utest->swdd~server-starts-without-startup-config~1]
+    // [utest->swdd~server-stores-newly-connected-agent~1]
+    // [utest->swdd~server-stores-labels-of-connected-agent~1]
+    #[tokio::test]
+    async fn utest_server_sends_workloads_and_workload_states() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let w2 = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let agent_a_labels = HashMap::from([("zone".to_string(), "front".to_string())]);
+
+        let mut mock_server_state = MockServerState::new();
+
+        mock_server_state.expect_cleanup_state().return_const(());
+
+        let mut seq = mockall::Sequence::new();
+        mock_server_state
+            .expect_add_agent()
+            .with(
+                predicate::eq(AGENT_A.to_owned()),
+                predicate::eq(agent_a_labels.clone()),
+            )
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(());
+
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(None);
+
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .with(mockall::predicate::eq(AGENT_A.to_string()))
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(vec![w1.clone()]);
+
+        mock_server_state
+            .expect_add_agent()
+            .with(
+                predicate::eq(AGENT_B.to_owned()),
+                predicate::eq(HashMap::new()),
+            )
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(());
+
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(None);
+
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .with(mockall::predicate::eq(AGENT_B.to_string()))
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(vec![w2.clone()]);
+
+        server.server_state = mock_server_state;
+
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        // first agent connects to the server
+        let agent_hello_result = to_server
+            .agent_hello(AGENT_A.to_string(), agent_a_labels)
+            .await;
+        assert!(agent_hello_result.is_ok());
 
         let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
 
@@ -795,7 +2088,9 @@ mod tests {
             from_server_command
         );
 
-        let agent_hello_result = to_server.agent_hello(AGENT_B.to_owned()).await;
+        let agent_hello_result = to_server
+            .agent_hello(AGENT_B.to_owned(), HashMap::new())
+            .await;
         assert!(agent_hello_result.is_ok());
 
         let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
@@ -900,12 +2195,16 @@ mod tests {
             .with(
                 mockall::predicate::eq(update_state.clone()),
                 mockall::predicate::eq(update_mask.clone()),
+                mockall::predicate::always(),
             )
             .once()
             .return_const(Ok(Some((
                 added_workloads.clone(),
                 deleted_workloads.clone(),
             ))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
         let server_task = tokio::spawn(async move { server.start(None).await });
 
@@ -937,7 +2236,8 @@ mod tests {
                         deleted_workloads: deleted_workloads
                             .into_iter()
                             .map(|x| x.instance_name.to_string())
-                            .collect()
+                            .collect(),
+                        dry_run: false,
                     }
                 ))
             }),
@@ -980,9 +2280,13 @@ mod tests {
             .with(
                 mockall::predicate::eq(update_state.clone()),
                 mockall::predicate::eq(update_mask.clone()),
+                mockall::predicate::always(),
             )
             .once()
             .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
         let server_task = tokio::spawn(async move { server.start(None).await });
 
@@ -998,7 +2302,8 @@ mod tests {
                 request_id,
                 response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(ank_base::UpdateStateSuccess {
                     added_workloads,
-                    deleted_workloads
+                    deleted_workloads,
+                    dry_run: false
                 }))
             }) if request_id == REQUEST_ID_A && added_workloads.is_empty() && deleted_workloads.is_empty()
         ));
@@ -1043,6 +2348,7 @@ mod tests {
             .with(
                 mockall::predicate::eq(update_state.clone()),
                 mockall::predicate::eq(update_mask.clone()),
+                mockall::predicate::always(),
             )
             .once()
             .return_const(Err(UpdateStateError::ResultInvalid(
@@ -1115,7 +2421,11 @@ mod tests {
             .expect_get_complete_state_by_field_mask()
             .with(
                 mockall::predicate::function(|request_compl_state| {
-                    request_compl_state == &CompleteStateRequest { field_mask: vec![] }
+                    request_compl_state
+                        == &CompleteStateRequest {
+                            field_mask: vec![],
+                            label_selector: None,
+                        }
                 }),
                 mockall::predicate::always(),
             )
@@ -1129,7 +2439,10 @@ mod tests {
         let request_complete_state_result = to_server
             .request_complete_state(
                 request_id.clone(),
-                CompleteStateRequest { field_mask: vec![] },
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None,
+                },
             )
             .await;
         assert!(request_complete_state_result.is_ok());
@@ -1141,7 +2454,10 @@ mod tests {
             common::from_server_interface::FromServer::Response(ank_base::Response {
                 request_id,
                 response_content: Some(ank_base::response::ResponseContent::CompleteState(
-                    current_complete_state
+                    ank_base::CompleteState {
+                        revision: Some(0),
+                        ..current_complete_state
+                    }
                 ))
             })
         );
@@ -1167,7 +2483,11 @@ mod tests {
             .expect_get_complete_state_by_field_mask()
             .with(
                 mockall::predicate::function(|request_compl_state| {
-                    request_compl_state == &CompleteStateRequest { field_mask: vec![] }
+                    request_compl_state
+                        == &CompleteStateRequest {
+                            field_mask: vec![],
+                            label_selector: None,
+                        }
                 }),
                 mockall::predicate::always(),
             )
@@ -1182,7 +2502,10 @@ mod tests {
         let request_complete_state_result = to_server
             .request_complete_state(
                 request_id.clone(),
-                CompleteStateRequest { field_mask: vec![] },
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None,
+                },
             )
             .await;
         assert!(request_complete_state_result.is_ok());
@@ -1207,6 +2530,46 @@ mod tests {
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
+    // [utest->swdd~server-rejects-invalid-label-selector~1]
+    #[tokio::test]
+    async fn utest_server_rejects_complete_state_request_with_invalid_label_selector() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .never();
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let request_id = format!("{AGENT_A}@my_request_id");
+        assert!(to_server
+            .request_complete_state(
+                request_id.clone(),
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: Some("team in infotainment".to_owned()),
+                },
+            )
+            .await
+            .is_ok());
+
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: received_request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_))
+            }) if received_request_id == request_id
+        ));
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
     // [utest->swdd~server-uses-async-channels~1]
     // [utest->swdd~server-stores-workload-state~1]
     // [utest->swdd~server-set-workload-state-on-disconnect~1]
@@ -1227,6 +2590,10 @@ mod tests {
             .once()
             .return_const(());
 
+        mock_server_state
+            .expect_contains_connected_agent()
+            .with(predicate::eq(AGENT_A))
+            .return_const(true);
         mock_server_state
             .expect_remove_agent()
             .once()
@@ -1348,6 +2715,11 @@ mod tests {
             .times(2)
             .return_const(());
 
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .times(2)
+            .return_const(None);
+
         mock_server_state
             .expect_get_workloads_for_agent()
             .with(mockall::predicate::eq(AGENT_B.to_string()))
@@ -1360,16 +2732,24 @@ mod tests {
             .with(
                 mockall::predicate::eq(update_state.clone()),
                 mockall::predicate::eq(update_mask.clone()),
+                mockall::predicate::always(),
             )
             .once()
             .in_sequence(&mut seq)
             .return_const(Ok(Some((added_workloads, deleted_workloads))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
         server.server_state = mock_server_state;
 
-        let agent_hello1_result = to_server.agent_hello(AGENT_A.to_owned()).await;
+        let agent_hello1_result = to_server
+            .agent_hello(AGENT_A.to_owned(), HashMap::new())
+            .await;
         assert!(agent_hello1_result.is_ok());
 
-        let agent_hello2_result = to_server.agent_hello(AGENT_B.to_owned()).await;
+        let agent_hello2_result = to_server
+            .agent_hello(AGENT_B.to_owned(), HashMap::new())
+            .await;
         assert!(agent_hello2_result.is_ok());
 
         let update_state_result = to_server
@@ -1419,7 +2799,8 @@ mod tests {
                 request_id,
                 response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(ank_base::UpdateStateSuccess {
                     added_workloads,
-                    deleted_workloads
+                    deleted_workloads,
+                    dry_run: false
                 }))
             }) if request_id == REQUEST_ID_A && added_workloads == vec![updated_w1.instance_name.to_string()] && deleted_workloads == vec![w1.instance_name.to_string()]
         ));
@@ -1500,7 +2881,8 @@ mod tests {
                 request_id: REQUEST_ID_A.to_string(),
                 response_content: Some(ank_base::response::ResponseContent::Error(
                     ank_base::Error {
-                        message: error_message
+                        message: error_message,
+                        ..Default::default()
                     }
                 )),
             }),
@@ -1511,261 +2893,2303 @@ mod tests {
         assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
-    // [utest->swdd~update-desired-state-with-missing-version~1]
+    // [utest->swdd~server-collects-metrics~1]
+    // [utest->swdd~server-reports-readiness~1]
     #[tokio::test]
-    async fn utest_server_rejects_update_state_without_api_version() {
+    async fn utest_server_records_metrics_of_rejected_update() {
         let _ = env_logger::builder().is_test(true).try_init();
         let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
         let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
-        let mut update_state_ankaios_no_version: CompleteState = CompleteState {
+        let update_state = CompleteState {
+            desired_state: State {
+                api_version: "incompatible_version".to_string(),
+                ..Default::default()
+            },
             ..Default::default()
         };
-        update_state_ankaios_no_version.desired_state.api_version = "".to_string();
 
-        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_agent_map()
+            .return_const(AgentMap::default());
+        mock_server_state
+            .expect_store_desired_state()
+            .once()
+            .return_const(Ok(()));
+        server.server_state = mock_server_state;
+        let metrics = ServerMetrics::default();
+        server.set_metrics(metrics.clone());
         let server_task = tokio::spawn(async move { server.start(None).await });
 
-        // send new state to server
         let update_state_result = to_server
-            .update_state(
+            .update_state(REQUEST_ID_A.to_string(), update_state, vec![])
+            .await;
+        assert!(update_state_result.is_ok());
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(_)
+        ));
+        assert!(metrics.is_ready());
+
+        assert!(to_server.stop().await.is_ok());
+        assert!(server_task.await.unwrap().is_ok());
+
+        assert!(!metrics.is_ready());
+        let rendered_metrics = metrics.render();
+        assert!(rendered_metrics
+            .contains("ankaios_server_requests_total{request_type=\"UpdateStateRequest\"} 1\n"));
+        assert!(rendered_metrics
+            .contains("ankaios_server_rejected_updates_total{reason=\"invalid_format\"} 1\n"));
+    }
+
+    // [utest->swdd~server-writes-audit-log~1]
+    #[tokio::test]
+    async fn utest_server_writes_audit_log_of_accepted_update() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        server.server_state = mock_server_state;
+
+        let expected_update_mask = update_mask.clone();
+        let mut mock_audit_log = MockAuditLog::new();
+        mock_audit_log
+            .expect_append()
+            .withf(move |entry| {
+                entry.requester == AGENT_A
+                    && entry.request == "UpdateStateRequest"
+                    && entry.update_mask == expected_update_mask
+                    && entry.accepted
+                    && entry.reason.is_none()
+                    && entry.revision.is_some()
+            })
+            .once()
+            .return_const(Ok(()));
+        server.set_audit_log(Box::new(mock_audit_log));
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let update_state_result = to_server
+            .update_state(
                 REQUEST_ID_A.to_string(),
-                update_state_ankaios_no_version.clone(),
+                CompleteState::default(),
                 update_mask,
             )
             .await;
         assert!(update_state_result.is_ok());
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(_)),
+                ..
+            })
+        ));
 
-        let error_message = format!(
-            "Unsupported API version. Received '', expected '{}'",
-            State::default().api_version
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-writes-audit-log~1]
+    #[tokio::test]
+    async fn utest_server_writes_audit_log_of_rejected_update_and_continues_on_error() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_state = CompleteState {
+            desired_state: State {
+                api_version: "incompatible_version".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.server_state = MockServerState::new();
+        let mut mock_audit_log = MockAuditLog::new();
+        mock_audit_log
+            .expect_append()
+            .withf(|entry| {
+                entry.requester == AGENT_A
+                    && entry.request == "UpdateStateRequest"
+                    && !entry.accepted
+                    && entry.reason.is_some()
+                    && entry.revision.is_none()
+            })
+            .once()
+            .return_const(Err(AuditLogError::Write("disk full".to_string())));
+        server.set_audit_log(Box::new(mock_audit_log));
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let update_state_result = to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, vec![])
+            .await;
+        assert!(update_state_result.is_ok());
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+                ..
+            })
+        ));
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-writes-audit-log~1]
+    #[tokio::test]
+    async fn utest_server_writes_audit_log_with_cli_session_name() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let cli_connection_name = "cli-conn-alice-0b2f7d4e";
+        let update_state = CompleteState {
+            desired_state: State {
+                api_version: "incompatible_version".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.server_state = MockServerState::new();
+        let mut mock_audit_log = MockAuditLog::new();
+        mock_audit_log
+            .expect_append()
+            .withf(move |entry| entry.requester == cli_connection_name)
+            .once()
+            .return_const(Ok(()));
+        server.set_audit_log(Box::new(mock_audit_log));
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let update_state_result = to_server
+            .update_state(format!("{cli_connection_name}@id1"), update_state, vec![])
+            .await;
+        assert!(update_state_result.is_ok());
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(_)
+        ));
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-provides-audit-log-interface~1]
+    #[tokio::test]
+    async fn utest_server_provides_audit_log() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let audit_entry = AuditEntry {
+            timestamp: "2025-01-01T00:00:00Z".to_string(),
+            requester: AGENT_A.to_string(),
+            request: "RollbackRequest".to_string(),
+            accepted: true,
+            revision: Some(3),
+            ..Default::default()
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.server_state = MockServerState::new();
+        let mut mock_audit_log = MockAuditLog::new();
+        mock_audit_log
+            .expect_read()
+            .with(mockall::predicate::eq(2))
+            .once()
+            .return_const(Ok(vec![audit_entry.clone()]));
+        server.set_audit_log(Box::new(mock_audit_log));
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_audit_log(REQUEST_ID_A.to_string(), Some(2))
+            .await
+            .is_ok());
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID_A.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::AuditLog(
+                    ank_base::AuditLog {
+                        entries: vec![audit_entry.into()],
+                    }
+                )),
+            })
         );
-        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-provides-audit-log-interface~1]
+    #[tokio::test]
+    async fn utest_server_limits_audit_log_to_max_entries() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.server_state = MockServerState::new();
+        let mut mock_audit_log = MockAuditLog::new();
+        mock_audit_log
+            .expect_read()
+            .with(mockall::predicate::eq(MAX_AUDIT_LOG_ENTRIES))
+            .times(2)
+            .return_const(Ok(vec![]));
+        server.set_audit_log(Box::new(mock_audit_log));
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        for (request_id, tail) in [
+            (REQUEST_ID_A, None),
+            (REQUEST_ID_B, Some(MAX_AUDIT_LOG_ENTRIES as u32 + 1)),
+        ] {
+            assert!(to_server
+                .request_audit_log(request_id.to_string(), tail)
+                .await
+                .is_ok());
+            assert_eq!(
+                comm_middle_ware_receiver.recv().await.unwrap(),
+                FromServer::Response(ank_base::Response {
+                    request_id: request_id.to_string(),
+                    response_content: Some(ank_base::response::ResponseContent::AuditLog(
+                        ank_base::AuditLog { entries: vec![] }
+                    )),
+                })
+            );
+        }
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-provides-workload-description-interface~1]
+    // [utest->swdd~server-keeps-bounded-workload-state-history~1]
+    #[tokio::test]
+    async fn utest_server_provides_workload_description() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+        workload.runtime_config = "rendered config".to_string();
+        let workload_state = WorkloadState {
+            instance_name: workload.instance_name.clone(),
+            execution_state: ExecutionState::running(),
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_cleanup_state().return_const(());
+        let cloned_workload = workload.clone();
+        mock_server_state
+            .expect_get_redacted_workload_spec()
+            .withf(|workload_name, config_names| {
+                workload_name == WORKLOAD_NAME_1 && config_names == ["config_1".to_string()]
+            })
+            .once()
+            .return_once(|_, _| Ok(cloned_workload));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .update_workload_state(vec![workload_state.clone()])
+            .await
+            .is_ok());
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkloadState(_)
+        ));
+
+        assert!(to_server
+            .request_workload_description(
+                REQUEST_ID_A.to_string(),
+                WORKLOAD_NAME_1.to_string(),
+                vec!["config_1".to_string()]
+            )
+            .await
+            .is_ok());
+        let Some(FromServer::Response(ank_base::Response {
+            request_id,
+            response_content:
+                Some(ank_base::response::ResponseContent::WorkloadDescription(description)),
+        })) = comm_middle_ware_receiver.recv().await
+        else {
+            panic!("Expected a workload description");
+        };
+
+        assert_eq!(request_id, REQUEST_ID_A);
+        assert_eq!(
+            description.instance_name,
+            Some(workload.instance_name.clone().into())
+        );
+        assert_eq!(description.rendered_runtime_config, "rendered config");
+        assert_eq!(description.state_history.len(), 1);
+        assert_eq!(
+            description.state_history[0].execution_state,
+            Some(ExecutionState::running().into())
+        );
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-provides-workload-description-interface~1]
+    #[tokio::test]
+    async fn utest_server_rejects_workload_description_of_unknown_workload() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_redacted_workload_spec()
+            .once()
+            .return_once(|_, _| Err("unknown workload".to_string()));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_workload_description(
+                REQUEST_ID_A.to_string(),
+                WORKLOAD_NAME_1.to_string(),
+                vec![]
+            )
+            .await
+            .is_ok());
         assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
             FromServer::Response(ank_base::Response {
                 request_id: REQUEST_ID_A.to_string(),
                 response_content: Some(ank_base::response::ResponseContent::Error(
                     ank_base::Error {
-                        message: error_message
+                        message: "unknown workload".to_string(),
+                        ..Default::default()
                     }
                 )),
-            }),
-            from_server_command
+            })
         );
 
         server_task.abort();
-        assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
-    // [utest->swdd~server-cleans-up-state~1]
+    // [utest->swdd~server-provides-audit-log-interface~1]
+    #[tokio::test]
+    async fn utest_server_rejects_audit_log_request_if_not_enabled() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.server_state = MockServerState::new();
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_audit_log(REQUEST_ID_A.to_string(), None)
+            .await
+            .is_ok());
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            }) if request_id == REQUEST_ID_A
+        ));
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~update-desired-state-with-missing-version~1]
+    #[tokio::test]
+    async fn utest_server_rejects_update_state_without_api_version() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut update_state_ankaios_no_version: CompleteState = CompleteState {
+            ..Default::default()
+        };
+        update_state_ankaios_no_version.desired_state.api_version = "".to_string();
+
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        // send new state to server
+        let update_state_result = to_server
+            .update_state(
+                REQUEST_ID_A.to_string(),
+                update_state_ankaios_no_version.clone(),
+                update_mask,
+            )
+            .await;
+        assert!(update_state_result.is_ok());
+
+        let error_message = format!(
+            "Unsupported API version. Received '', expected '{}'",
+            State::default().api_version
+        );
+        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
+        assert_eq!(
+            FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID_A.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::Error(
+                    ank_base::Error {
+                        message: error_message,
+                        ..Default::default()
+                    }
+                )),
+            }),
+            from_server_command
+        );
+
+        server_task.abort();
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-cleans-up-state~1]
+    #[tokio::test]
+    async fn utest_server_triggers_delete_of_actually_removed_workloads_from_delete_graph() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+
+        let mut mock_server_state = MockServerState::new();
+
+        let workload_states = vec![common::objects::generate_test_workload_state(
+            WORKLOAD_NAME_1,
+            ExecutionState::removed(),
+        )];
+
+        mock_server_state
+            .expect_cleanup_state()
+            .with(mockall::predicate::eq(workload_states.clone()))
+            .return_const(());
+        server.server_state = mock_server_state;
+
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let update_workload_state_result = to_server.update_workload_state(workload_states).await;
+        assert!(update_workload_state_result.is_ok());
+
+        server_task.abort();
+    }
+
+    // [utest->swdd~server-handles-not-started-deleted-workloads~1]
+    #[tokio::test]
+    async fn utest_server_handles_deleted_workload_on_empty_agent() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let workload_without_agent = generate_test_workload_spec_with_param(
+            "".to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let workload_with_agent = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let update_state = CompleteState::default();
+        let update_mask = vec!["desiredState.workloads".to_string()];
+
+        let deleted_workload_without_agent = DeletedWorkload {
+            instance_name: workload_without_agent.instance_name.clone(),
+            ..Default::default()
+        };
+        let deleted_workload_with_agent = DeletedWorkload {
+            instance_name: workload_with_agent.instance_name.clone(),
+            ..Default::default()
+        };
+
+        let deleted_workloads = vec![
+            deleted_workload_without_agent.clone(),
+            deleted_workload_with_agent.clone(),
+        ];
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_contains_connected_agent()
+            .once()
+            .return_const(false);
+        mock_server_state
+            .expect_update()
+            .once()
+            .return_const(Ok(Some((vec![], deleted_workloads.clone()))));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        server.server_state = mock_server_state;
+
+        let update_state_result = to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask.clone())
+            .await;
+        assert!(update_state_result.is_ok());
+
+        let server_handle = server.start(None);
+
+        // The receiver in the server receives the messages and terminates the infinite waiting-loop
+        drop(to_server);
+        tokio::join!(server_handle).0.unwrap();
+
+        // the server sends the ExecutionState removed for the workload with an empty agent name
+        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
+        assert_eq!(
+            FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![WorkloadState {
+                    instance_name: workload_without_agent.instance_name,
+                    execution_state: ExecutionState::removed()
+                }]
+            }),
+            from_server_command
+        );
+
+        // the server sends only a deleted workload for the workload with a non-empty agent name
+        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
+        assert_eq!(
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![],
+                deleted_workloads: vec![deleted_workload_with_agent.clone()],
+            }),
+            from_server_command
+        );
+
+        // ignore UpdateStateSuccessful response
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(_)
+        ));
+
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-receives-resource-availability~1]
+    #[tokio::test]
+    async fn utest_server_receives_agent_status_load() {
+        let payload = AgentLoadStatus {
+            agent_name: AGENT_A.to_string(),
+            cpu_usage: CpuUsage { cpu_usage: 42 },
+            free_memory: FreeMemory { free_memory: 42 },
+        };
+
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update_agent_resource_availability()
+            .with(mockall::predicate::eq(payload.clone()))
+            .return_const(());
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .return_const(None);
+        server.server_state = mock_server_state;
+
+        let agent_resource_result = to_server.agent_load_status(payload).await;
+        assert!(agent_resource_result.is_ok());
+
+        drop(to_server);
+        let result = server.start(None).await;
+
+        assert!(result.is_ok());
+    }
+
+    // [utest->swdd~server-schedules-pending-workloads-on-agent-load-status~1]
+    // [utest->swdd~server-schedules-pending-workloads~1]
+    #[tokio::test]
+    async fn utest_server_schedules_pending_workloads_on_agent_load_status() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let pending_workload = generate_test_workload_spec_with_param(
+            "".to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let scheduled_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .workload_states_map
+            .initial_state(&vec![pending_workload.clone()]);
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update_agent_resource_availability()
+            .return_const(());
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .return_const(Some((
+                vec![scheduled_workload.clone()],
+                vec![DeletedWorkload {
+                    instance_name: pending_workload.instance_name.clone(),
+                    ..Default::default()
+                }],
+            )));
+        server.server_state = mock_server_state;
+
+        let agent_resource_result = to_server
+            .agent_load_status(AgentLoadStatus {
+                agent_name: AGENT_A.to_string(),
+                cpu_usage: CpuUsage { cpu_usage: 42 },
+                free_memory: FreeMemory { free_memory: 42 },
+            })
+            .await;
+        assert!(agent_resource_result.is_ok());
+
+        drop(to_server);
+        let result = server.start(None).await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![WorkloadState {
+                    instance_name: pending_workload.instance_name.clone(),
+                    execution_state: ExecutionState::removed(),
+                }]
+            })
+        );
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![scheduled_workload.clone()],
+                deleted_workloads: vec![],
+            })
+        );
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+
+        assert_eq!(
+            server
+                .workload_states_map
+                .get_workload_state_for_workload(&scheduled_workload.instance_name),
+            Some(&ExecutionState::initial())
+        );
+        assert!(server
+            .workload_states_map
+            .get_workload_state_for_workload(&pending_workload.instance_name)
+            .is_none());
+    }
+
+    // [utest->swdd~server-schedules-pending-workloads-on-agent-hello~1]
+    // [utest->swdd~server-schedules-pending-workloads~1]
+    #[tokio::test]
+    async fn utest_server_schedules_pending_workloads_on_agent_hello() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let workload_for_agent_a = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let workload_for_agent_b = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        let mut seq = mockall::Sequence::new();
+        mock_server_state
+            .expect_add_agent()
+            .with(
+                predicate::eq(AGENT_A.to_owned()),
+                predicate::eq(HashMap::new()),
+            )
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(());
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(Some((
+                vec![workload_for_agent_a.clone(), workload_for_agent_b.clone()],
+                vec![],
+            )));
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .with(predicate::eq(AGENT_A.to_owned()))
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(vec![workload_for_agent_a.clone()]);
+        server.server_state = mock_server_state;
+
+        let agent_hello_result = to_server
+            .agent_hello(AGENT_A.to_owned(), HashMap::new())
+            .await;
+        assert!(agent_hello_result.is_ok());
+
+        drop(to_server);
+        let result = server.start(None).await;
+        assert!(result.is_ok());
+
+        // the workload of the connecting agent is only sent with the ServerHello
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: vec![workload_for_agent_b],
+                deleted_workloads: vec![],
+            })
+        );
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::ServerHello(ServerHello {
+                agent_name: Some(AGENT_A.to_owned()),
+                added_workloads: vec![workload_for_agent_a],
+            })
+        );
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-handles-not-started-deleted-workloads~1]
+    #[tokio::test]
+    async fn utest_server_handles_pending_initial_deleted_workload_on_not_connected_agent() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (_to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_contains_connected_agent()
+            .once()
+            .return_const(false);
+
+        let workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        server.server_state = mock_server_state;
+        server.workload_states_map = generate_test_workload_states_map_with_data(
+            workload.instance_name.agent_name(),
+            workload.instance_name.workload_name(),
+            workload.instance_name.id(),
+            ExecutionState::initial(),
+        );
+
+        let deleted_workload_with_not_connected_agent = DeletedWorkload {
+            instance_name: workload.instance_name.clone(),
+            ..Default::default()
+        };
+
+        let deleted_workloads = vec![deleted_workload_with_not_connected_agent.clone()];
+
+        let retained_deleted_workloads = server
+            .handle_not_started_deleted_workloads(deleted_workloads)
+            .await;
+
+        assert!(retained_deleted_workloads.is_empty());
+
+        assert_eq!(
+            tokio::time::timeout(
+                tokio::time::Duration::from_millis(100),
+                comm_middle_ware_receiver.recv(),
+            )
+            .await,
+            Ok(Some(FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                workload_states: vec![WorkloadState {
+                    instance_name: workload.instance_name,
+                    execution_state: ExecutionState::removed()
+                }]
+            })))
+        );
+    }
+
+    // [utest->swdd~server-creates-revision-on-accepted-update~1]
+    // [utest->swdd~server-provides-revisions-interface~1]
+    #[tokio::test]
+    async fn utest_server_creates_revision_for_accepted_update_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let new_desired_state = State {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+            )]),
+            ..Default::default()
+        };
+        let update_state = CompleteState {
+            desired_state: new_desired_state.clone(),
+            ..Default::default()
+        };
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(new_desired_state);
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask.clone())
+            .await
+            .is_ok());
+        assert!(matches!(
+            comm_middle_ware_receiver.recv().await.unwrap(),
+            FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(_)),
+                ..
+            })
+        ));
+
+        assert!(to_server
+            .request_revisions(REQUEST_ID_A.to_string())
+            .await
+            .is_ok());
+        let Some(FromServer::Response(ank_base::Response {
+            request_id,
+            response_content: Some(ank_base::response::ResponseContent::Revisions(revisions)),
+        })) = comm_middle_ware_receiver.recv().await
+        else {
+            panic!("Expected a revisions response");
+        };
+
+        server_task.abort();
+
+        assert_eq!(request_id, REQUEST_ID_A);
+        assert_eq!(revisions.revisions.len(), 1);
+        assert_eq!(revisions.revisions[0].revision, 1);
+        assert_eq!(revisions.revisions[0].requester, AGENT_A);
+        assert_eq!(revisions.revisions[0].update_mask, update_mask);
+    }
+
+    // [utest->swdd~server-dry-runs-update-state-request~1]
+    #[tokio::test]
+    async fn utest_server_dry_runs_update_state_without_applying() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let w2 = generate_test_workload_spec_with_param(
+            AGENT_B.to_owned(),
+            WORKLOAD_NAME_2.to_owned(),
+            RUNTIME_NAME.to_string(),
+        );
+        let deleted_workloads = vec![DeletedWorkload {
+            instance_name: w2.instance_name.clone(),
+            dependencies: HashMap::new(),
+        }];
+        let update_mask = vec!["desiredState.workloads".to_string()];
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_audit_log = MockAuditLog::new();
+        mock_audit_log.expect_append().never();
+        server.set_audit_log(Box::new(mock_audit_log));
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_update().never();
+        mock_server_state
+            .expect_dry_run_update()
+            .with(
+                predicate::eq(CompleteState::default()),
+                predicate::eq(update_mask.clone()),
+            )
+            .once()
+            .return_const(Ok(Some((vec![w1.clone()], deleted_workloads))));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .dry_run_update_state(
+                REQUEST_ID_A.to_string(),
+                CompleteState::default(),
+                update_mask
+            )
+            .await
+            .is_ok());
+        let dry_run_response = comm_middle_ware_receiver.recv().await;
+
+        assert!(to_server
+            .request_revisions(REQUEST_ID_A.to_string())
+            .await
+            .is_ok());
+        let revisions_response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert_eq!(
+            dry_run_response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID_A.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(
+                    ank_base::UpdateStateSuccess {
+                        added_workloads: vec![w1.instance_name.to_string()],
+                        deleted_workloads: vec![w2.instance_name.to_string()],
+                        dry_run: true,
+                    }
+                )),
+            }))
+        );
+        assert!(matches!(
+            revisions_response,
+            Some(FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::Revisions(ank_base::Revisions {
+                    revisions
+                })),
+                ..
+            })) if revisions.is_empty()
+        ));
+    }
+
+    // [utest->swdd~server-dry-runs-update-state-request~1]
+    #[tokio::test]
+    async fn utest_server_dry_run_rejects_invalid_update() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_dry_run_update()
+            .once()
+            .return_const(Err(UpdateStateError::CycleInDependencies(
+                WORKLOAD_NAME_1.to_owned(),
+            )));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .dry_run_update_state(REQUEST_ID_A.to_string(), CompleteState::default(), vec![])
+            .await
+            .is_ok());
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-provides-rollback-interface~1]
+    #[tokio::test]
+    async fn utest_server_rolls_back_to_revision() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let previous_desired_state = State {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+            )]),
+            ..Default::default()
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let revision = server
+            .revision_history
+            .add(&previous_desired_state, AGENT_A.to_owned(), vec![])
+            .unwrap();
+
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .with(
+                predicate::eq(CompleteState {
+                    desired_state: previous_desired_state.clone(),
+                    ..Default::default()
+                }),
+                predicate::eq(vec![]),
+                mockall::predicate::always(),
+            )
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(previous_desired_state);
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .rollback(REQUEST_ID_A.to_string(), revision)
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-provides-rollback-interface~1]
+    #[tokio::test]
+    async fn utest_server_rejects_rollback_to_unknown_revision() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_update().never();
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .rollback(REQUEST_ID_A.to_string(), 42)
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-rejects-update-with-stale-revision~1]
+    #[tokio::test]
+    async fn utest_server_rejects_update_with_stale_revision() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_state = CompleteState {
+            revision: Some(1),
+            ..Default::default()
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .revision_history
+            .add(&State::default(), AGENT_A.to_string(), vec![]);
+        server.revision_history.add(
+            &State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    generate_test_stored_workload_spec(AGENT_A, RUNTIME_NAME),
+                )]),
+                ..Default::default()
+            },
+            AGENT_A.to_string(),
+            vec![],
+        );
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_update().never();
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, vec![])
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(ank_base::Error {
+                    code,
+                    ..
+                })),
+            })) if request_id == REQUEST_ID_A && code == ank_base::ErrorCode::StaleRevision as i32
+        ));
+    }
+
+    // [utest->swdd~server-rejects-update-with-stale-revision~1]
+    #[tokio::test]
+    async fn utest_server_accepts_update_with_current_revision() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let update_state = CompleteState {
+            revision: Some(1),
+            ..Default::default()
+        };
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .revision_history
+            .add(&State::default(), AGENT_A.to_string(), vec![]);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_update()
+            .with(
+                mockall::predicate::eq(update_state.clone()),
+                mockall::predicate::eq(vec![]),
+                mockall::predicate::always(),
+            )
+            .once()
+            .return_const(Ok(None));
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .update_state(REQUEST_ID_A.to_string(), update_state, vec![])
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::UpdateStateSuccess(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-includes-revision-in-complete-state~1]
+    #[tokio::test]
+    async fn utest_server_includes_revision_in_complete_state() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .revision_history
+            .add(&State::default(), AGENT_A.to_string(), vec![]);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .once()
+            .return_const(Ok(ank_base::CompleteState::default()));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_complete_state(
+                REQUEST_ID_A.to_string(),
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: None
+                }
+            )
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::CompleteState(
+                    ank_base::CompleteState {
+                        revision: Some(1),
+                        ..
+                    }
+                )),
+                ..
+            }))
+        ));
+    }
+
+    // [utest->swdd~server-provides-events-interface~1]
+    // [utest->swdd~server-sends-initial-event~1]
+    #[tokio::test]
+    async fn utest_server_sends_initial_event_on_subscription() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .revision_history
+            .add(&State::default(), AGENT_A.to_string(), vec![]);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .with(
+                predicate::eq(CompleteStateRequest {
+                    field_mask: vec!["agents".to_string()],
+                    label_selector: None,
+                }),
+                predicate::always(),
+            )
+            .once()
+            .return_const(Ok(ank_base::CompleteState::default()));
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_events(REQUEST_ID_A.to_string(), vec!["agents".to_string()])
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Event(ank_base::Event {
+                    complete_state: Some(ank_base::CompleteState {
+                        revision: Some(1),
+                        ..
+                    }),
+                    added_fields,
+                    updated_fields,
+                    removed_fields,
+                })),
+            })) if request_id == REQUEST_ID_A
+                && added_fields.is_empty()
+                && updated_fields.is_empty()
+                && removed_fields.is_empty()
+        ));
+    }
+
+    // [utest->swdd~server-sends-events-on-altered-fields~1]
+    #[tokio::test]
+    async fn utest_server_sends_event_on_agent_hello() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .times(2)
+            .return_const(Ok(ank_base::CompleteState::default()));
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .return_const(vec![]);
+        mock_server_state.expect_add_agent().once().return_const(());
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .return_const(None);
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_events(REQUEST_ID_A.to_string(), vec!["agents".to_string()])
+            .await
+            .is_ok());
+        assert!(to_server
+            .agent_hello(AGENT_B.to_string(), HashMap::new())
+            .await
+            .is_ok());
+
+        let initial_event = comm_middle_ware_receiver.recv().await;
+        let server_hello = comm_middle_ware_receiver.recv().await;
+        let event = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            initial_event,
+            Some(FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::Event(_)),
+                ..
+            }))
+        ));
+        assert!(matches!(server_hello, Some(FromServer::ServerHello(_))));
+        assert!(matches!(
+            event,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Event(ank_base::Event {
+                    added_fields,
+                    ..
+                })),
+            })) if request_id == REQUEST_ID_A && added_fields == vec![format!("agents.{AGENT_B}")]
+        ));
+    }
+
+    // [utest->swdd~server-sends-event-on-agent-load-status~1]
+    #[tokio::test]
+    async fn utest_server_sends_event_on_agent_load_status() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_complete_state_by_field_mask()
+            .times(2)
+            .return_const(Ok(ank_base::CompleteState::default()));
+        mock_server_state
+            .expect_update_agent_resource_availability()
+            .once()
+            .return_const(());
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .return_const(None);
+        mock_server_state
+            .expect_get_desired_state()
+            .return_const(State::default());
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_events(REQUEST_ID_A.to_string(), vec!["agents".to_string()])
+            .await
+            .is_ok());
+        assert!(to_server
+            .agent_load_status(AgentLoadStatus {
+                agent_name: AGENT_A.to_string(),
+                cpu_usage: CpuUsage { cpu_usage: 42 },
+                free_memory: FreeMemory { free_memory: 42 },
+            })
+            .await
+            .is_ok());
+
+        let initial_event = comm_middle_ware_receiver.recv().await;
+        let event = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            initial_event,
+            Some(FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::Event(_)),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            event,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Event(ank_base::Event {
+                    added_fields,
+                    updated_fields,
+                    removed_fields,
+                    ..
+                })),
+            })) if request_id == REQUEST_ID_A
+                && added_fields.is_empty()
+                && updated_fields == vec![format!("agents.{AGENT_A}")]
+                && removed_fields.is_empty()
+        ));
+    }
+
+    // [utest->swdd~server-provides-events-interface~1]
+    #[tokio::test]
+    async fn utest_server_cancels_event_subscription() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .event_handler
+            .add_subscription(REQUEST_ID_A.to_string(), vec![]);
+        server.server_state = MockServerState::new();
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .cancel_events(REQUEST_ID_A.to_string())
+            .await
+            .is_ok());
+        assert!(to_server
+            .cancel_events(REQUEST_ID_A.to_string())
+            .await
+            .is_ok());
+
+        let accepted = comm_middle_ware_receiver.recv().await;
+        let rejected = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            accepted,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::EventsCancelAccepted(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+        assert!(matches!(
+            rejected,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-removes-subscriptions-of-gone-requesters~1]
+    #[tokio::test]
+    async fn utest_server_removes_subscriptions_on_goodbye() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .event_handler
+            .add_subscription(REQUEST_ID_A.to_string(), vec![]);
+        server.server_state = MockServerState::new();
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server.goodbye(AGENT_A.to_string()).await.is_ok());
+        assert!(to_server
+            .cancel_events(REQUEST_ID_A.to_string())
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+                ..
+            }))
+        ));
+    }
+
+    // [utest->swdd~server-stores-desired-state-on-stop~1]
+    // [utest->swdd~server-closes-connections-on-stop~1]
+    #[tokio::test]
+    async fn utest_server_stops_on_stop() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_store_desired_state()
+            .once()
+            .return_const(Ok(()));
+        server.server_state = mock_server_state;
+
+        assert!(to_server.stop().await.is_ok());
+
+        assert!(server.start(None).await.is_ok());
+
+        assert_eq!(
+            comm_middle_ware_receiver.recv().await,
+            Some(FromServer::Stop(Stop {}))
+        );
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~server-forwards-logs-requests-to-agent~1]
+    // [utest->swdd~server-stores-log-campaigns~1]
+    #[tokio::test]
+    async fn utest_server_forwards_logs_request_to_agent() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let workload_spec = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME_NAME.to_string(),
+        );
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_workload_spec()
+            .with(predicate::eq(WORKLOAD_NAME_1))
+            .once()
+            .return_const(Some(workload_spec.clone()));
+        mock_server_state
+            .expect_contains_connected_agent()
+            .with(predicate::eq(AGENT_B))
+            .once()
+            .return_const(true);
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_logs(
+                REQUEST_ID_A.to_string(),
+                LogsRequest {
+                    workload_name: WORKLOAD_NAME_1.to_string(),
+                    follow: true,
+                    since: None,
+                    tail: Some(5),
+                }
+            )
+            .await
+            .is_ok());
+
+        let logs_request = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert_eq!(
+            logs_request,
+            Some(FromServer::WorkloadLogsRequest(WorkloadLogsRequest {
+                request_id: REQUEST_ID_A.to_string(),
+                instance_name: workload_spec.instance_name,
+                runtime: RUNTIME_NAME.to_string(),
+                follow: true,
+                since: None,
+                tail: Some(5),
+            }))
+        );
+    }
+
+    // [utest->swdd~server-forwards-logs-requests-to-agent~1]
+    #[tokio::test]
+    async fn utest_server_rejects_logs_request_for_unknown_workload() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_workload_spec()
+            .once()
+            .return_const(None);
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_logs(
+                REQUEST_ID_A.to_string(),
+                LogsRequest {
+                    workload_name: WORKLOAD_NAME_1.to_string(),
+                    follow: false,
+                    since: None,
+                    tail: None,
+                }
+            )
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-forwards-logs-requests-to-agent~1]
+    #[tokio::test]
+    async fn utest_server_rejects_logs_request_for_disconnected_agent() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_get_workload_spec()
+            .once()
+            .return_const(Some(generate_test_workload_spec_with_param(
+                AGENT_B.to_string(),
+                WORKLOAD_NAME_1.to_string(),
+                RUNTIME_NAME.to_string(),
+            )));
+        mock_server_state
+            .expect_contains_connected_agent()
+            .once()
+            .return_const(false);
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .request_logs(
+                REQUEST_ID_A.to_string(),
+                LogsRequest {
+                    workload_name: WORKLOAD_NAME_1.to_string(),
+                    follow: false,
+                    since: None,
+                    tail: None,
+                }
+            )
+            .await
+            .is_ok());
+
+        let response = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert!(matches!(
+            response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-relays-log-responses-to-requester~2]
+    #[tokio::test]
+    async fn utest_server_relays_log_responses_of_known_campaigns() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let instance_name = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME_NAME.to_string(),
+        )
+        .instance_name;
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .log_campaign_store
+            .add_campaign(REQUEST_ID_A.to_string(), instance_name.clone());
+        server.server_state = MockServerState::new();
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let log_entries = vec![ank_base::LogEntry {
+            workload_name: Some(instance_name.clone().into()),
+            message: "log line".to_string(),
+        }];
+        let log_entries_response_content =
+            ank_base::response::ResponseContent::LogEntriesResponse(ank_base::LogEntriesResponse {
+                log_entries: log_entries.clone(),
+            });
+        send_connection_response(
+            &to_server,
+            AGENT_B,
+            "unknown@id",
+            log_entries_response_content.clone(),
+        )
+        .await;
+        send_connection_response(
+            &to_server,
+            AGENT_B,
+            REQUEST_ID_A,
+            log_entries_response_content.clone(),
+        )
+        .await;
+        send_connection_response(
+            &to_server,
+            AGENT_B,
+            REQUEST_ID_A,
+            ank_base::response::ResponseContent::LogsStopResponse(ank_base::LogsStopResponse {
+                workload_name: Some(instance_name.clone().into()),
+            }),
+        )
+        .await;
+        // the campaign has ended, further responses are dropped
+        send_connection_response(
+            &to_server,
+            AGENT_B,
+            REQUEST_ID_A,
+            log_entries_response_content,
+        )
+        .await;
+
+        let log_entries_response = comm_middle_ware_receiver.recv().await;
+        let logs_stop_response = comm_middle_ware_receiver.recv().await;
+        assert!(tokio::time::timeout(
+            std::time::Duration::from_millis(100),
+            comm_middle_ware_receiver.recv()
+        )
+        .await
+        .is_err());
+        server_task.abort();
+
+        assert_eq!(
+            log_entries_response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID_A.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::LogEntriesResponse(
+                    ank_base::LogEntriesResponse { log_entries }
+                )),
+            }))
+        );
+        assert_eq!(
+            logs_stop_response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID_A.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::LogsStopResponse(
+                    ank_base::LogsStopResponse {
+                        workload_name: Some(instance_name.into()),
+                    }
+                )),
+            }))
+        );
+    }
+
+    // [utest->swdd~server-relays-log-responses-to-requester~2]
+    #[tokio::test]
+    async fn utest_server_drops_log_responses_from_other_connections() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let instance_name = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME_NAME.to_string(),
+        )
+        .instance_name;
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .log_campaign_store
+            .add_campaign(REQUEST_ID_A.to_string(), instance_name.clone());
+        server.server_state = MockServerState::new();
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        let logs_stop_response_content =
+            ank_base::response::ResponseContent::LogsStopResponse(ank_base::LogsStopResponse {
+                workload_name: Some(instance_name.into()),
+            });
+        send_connection_response(
+            &to_server,
+            AGENT_A,
+            REQUEST_ID_A,
+            ank_base::response::ResponseContent::LogEntriesResponse(
+                ank_base::LogEntriesResponse::default(),
+            ),
+        )
+        .await;
+        send_connection_response(
+            &to_server,
+            "cli-conn-1",
+            REQUEST_ID_A,
+            logs_stop_response_content.clone(),
+        )
+        .await;
+        // the campaign is still running and ended by the serving agent
+        send_connection_response(
+            &to_server,
+            AGENT_B,
+            REQUEST_ID_A,
+            logs_stop_response_content.clone(),
+        )
+        .await;
+
+        let logs_stop_response = comm_middle_ware_receiver.recv().await;
+        assert!(tokio::time::timeout(
+            std::time::Duration::from_millis(100),
+            comm_middle_ware_receiver.recv()
+        )
+        .await
+        .is_err());
+        server_task.abort();
+
+        assert_eq!(
+            logs_stop_response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id: REQUEST_ID_A.to_string(),
+                response_content: Some(logs_stop_response_content),
+            }))
+        );
+    }
+
+    async fn send_connection_response(
+        to_server: &ToServerSender,
+        connection_name: &str,
+        request_id: &str,
+        response_content: ank_base::response::ResponseContent,
+    ) {
+        assert!(to_server
+            .send(ToServer::Response(ConnectionResponse {
+                connection_name: connection_name.to_string(),
+                response: ank_base::Response {
+                    request_id: request_id.to_string(),
+                    response_content: Some(response_content),
+                },
+            }))
+            .await
+            .is_ok());
+    }
+
+    // [utest->swdd~server-cancels-log-campaigns~1]
+    #[tokio::test]
+    async fn utest_server_cancels_log_campaign() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.log_campaign_store.add_campaign(
+            REQUEST_ID_A.to_string(),
+            generate_test_workload_spec_with_param(
+                AGENT_B.to_string(),
+                WORKLOAD_NAME_1.to_string(),
+                RUNTIME_NAME.to_string(),
+            )
+            .instance_name,
+        );
+        server.server_state = MockServerState::new();
+        let server_task = tokio::spawn(async move { server.start(None).await });
+
+        assert!(to_server
+            .cancel_logs(REQUEST_ID_A.to_string())
+            .await
+            .is_ok());
+        assert!(to_server
+            .cancel_logs(REQUEST_ID_A.to_string())
+            .await
+            .is_ok());
+
+        let cancel = comm_middle_ware_receiver.recv().await;
+        let accepted = comm_middle_ware_receiver.recv().await;
+        let rejected = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert_eq!(
+            cancel,
+            Some(FromServer::WorkloadLogsCancel(WorkloadLogsCancel {
+                request_id: REQUEST_ID_A.to_string(),
+                agent_name: AGENT_B.to_string(),
+            }))
+        );
+        assert!(matches!(
+            accepted,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::LogsCancelAccepted(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+        assert!(matches!(
+            rejected,
+            Some(FromServer::Response(ank_base::Response {
+                request_id,
+                response_content: Some(ank_base::response::ResponseContent::Error(_)),
+            })) if request_id == REQUEST_ID_A
+        ));
+    }
+
+    // [utest->swdd~server-tracks-agent-heartbeats~1]
+    // [utest->swdd~server-detects-stale-agents~1]
+    // [utest->swdd~server-disconnects-stale-agents~1]
     #[tokio::test]
-    async fn utest_server_triggers_delete_of_actually_removed_workloads_from_delete_graph() {
+    async fn utest_server_disconnects_agent_missing_heartbeat_timeout() {
         let _ = env_logger::builder().is_test(true).try_init();
         let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
-        let (to_agents, _comm_middle_ware_receiver) =
+        let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
-
+        server.set_agent_heartbeat_timeout(Duration::from_millis(100));
         let mut mock_server_state = MockServerState::new();
-
-        let workload_states = vec![common::objects::generate_test_workload_state(
-            WORKLOAD_NAME_1,
-            ExecutionState::removed(),
-        )];
-
+        mock_server_state.expect_add_agent().once().return_const(());
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .times(1..=2)
+            .return_const(None);
+        mock_server_state
+            .expect_get_workloads_for_agent()
+            .once()
+            .return_const(vec![]);
         mock_server_state
             .expect_cleanup_state()
-            .with(mockall::predicate::eq(workload_states.clone()))
+            .once()
+            .return_const(());
+        mock_server_state
+            .expect_remove_agent()
+            .once()
+            .with(predicate::eq(AGENT_A))
+            .return_const(());
+        mock_server_state
+            .expect_agent_lost()
+            .with(predicate::eq(AGENT_A.to_owned()))
             .return_const(());
         server.server_state = mock_server_state;
 
-        let server_task = tokio::spawn(async move { server.start(None).await });
+        let workload_state_running = common::objects::generate_test_workload_state_with_agent(
+            WORKLOAD_NAME_1,
+            AGENT_A,
+            ExecutionState::running(),
+        );
+        assert!(to_server
+            .agent_hello(AGENT_A.to_string(), HashMap::new())
+            .await
+            .is_ok());
+        assert!(to_server
+            .update_workload_state(vec![workload_state_running.clone()])
+            .await
+            .is_ok());
 
-        let update_workload_state_result = to_server.update_workload_state(workload_states).await;
-        assert!(update_workload_state_result.is_ok());
+        let server_task = tokio::spawn(async move { server.start(None).await });
 
+        let mut from_server_commands = Vec::new();
+        for _ in 0..4 {
+            from_server_commands.push(
+                tokio::time::timeout(Duration::from_secs(3), comm_middle_ware_receiver.recv())
+                    .await
+                    .unwrap()
+                    .unwrap(),
+            );
+        }
         server_task.abort();
+
+        assert_eq!(
+            from_server_commands,
+            vec![
+                FromServer::ServerHello(ServerHello {
+                    agent_name: Some(AGENT_A.to_string()),
+                    added_workloads: vec![],
+                }),
+                FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                    workload_states: vec![workload_state_running],
+                }),
+                FromServer::DisconnectAgent(DisconnectAgent {
+                    agent_name: AGENT_A.to_string(),
+                }),
+                FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                    workload_states: vec![
+                        common::objects::generate_test_workload_state_with_agent(
+                            WORKLOAD_NAME_1,
+                            AGENT_A,
+                            ExecutionState::agent_disconnected(),
+                        )
+                    ],
+                }),
+            ]
+        );
     }
 
-    // [utest->swdd~server-handles-not-started-deleted-workloads~1]
+    // [utest->swdd~server-reschedules-workloads-of-lost-agents~1]
     #[tokio::test]
-    async fn utest_server_handles_deleted_workload_on_empty_agent() {
+    async fn utest_server_reschedules_workloads_of_lost_agent() {
         let _ = env_logger::builder().is_test(true).try_init();
         let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
         let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
-        let workload_without_agent = generate_test_workload_spec_with_param(
-            "".to_owned(),
-            WORKLOAD_NAME_1.to_owned(),
-            RUNTIME_NAME.to_string(),
-        );
-
-        let workload_with_agent = generate_test_workload_spec_with_param(
-            AGENT_B.to_owned(),
-            WORKLOAD_NAME_2.to_owned(),
+        let rescheduled_workload = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_1.to_string(),
             RUNTIME_NAME.to_string(),
         );
 
-        let update_state = CompleteState::default();
-        let update_mask = vec!["desiredState.workloads".to_string()];
-
-        let deleted_workload_without_agent = DeletedWorkload {
-            instance_name: workload_without_agent.instance_name.clone(),
-            ..Default::default()
-        };
-        let deleted_workload_with_agent = DeletedWorkload {
-            instance_name: workload_with_agent.instance_name.clone(),
-            ..Default::default()
-        };
-
-        let deleted_workloads = vec![
-            deleted_workload_without_agent.clone(),
-            deleted_workload_with_agent.clone(),
-        ];
-
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.set_agent_heartbeat_timeout(Duration::from_millis(100));
         let mut mock_server_state = MockServerState::new();
+        let mut seq = mockall::Sequence::new();
+        mock_server_state.expect_add_agent().once().return_const(());
         mock_server_state
-            .expect_contains_connected_agent()
+            .expect_schedule_pending_workloads()
             .once()
-            .return_const(false);
+            .in_sequence(&mut seq)
+            .return_const(None);
         mock_server_state
-            .expect_update()
+            .expect_get_workloads_for_agent()
             .once()
-            .return_const(Ok(Some((vec![], deleted_workloads.clone()))));
+            .return_const(vec![]);
+        mock_server_state
+            .expect_remove_agent()
+            .once()
+            .with(predicate::eq(AGENT_A))
+            .return_const(());
+        mock_server_state
+            .expect_agent_lost()
+            .once()
+            .with(predicate::eq(AGENT_A.to_owned()))
+            .in_sequence(&mut seq)
+            .return_const(());
+        mock_server_state
+            .expect_schedule_pending_workloads()
+            .once()
+            .in_sequence(&mut seq)
+            .return_const(Some((vec![rescheduled_workload.clone()], vec![])));
         server.server_state = mock_server_state;
 
-        let update_state_result = to_server
-            .update_state(REQUEST_ID_A.to_string(), update_state, update_mask.clone())
-            .await;
-        assert!(update_state_result.is_ok());
+        assert!(to_server
+            .agent_hello(AGENT_A.to_string(), HashMap::new())
+            .await
+            .is_ok());
 
-        let server_handle = server.start(None);
+        let server_task = tokio::spawn(async move { server.start(None).await });
 
-        // The receiver in the server receives the messages and terminates the infinite waiting-loop
-        drop(to_server);
-        tokio::join!(server_handle).0.unwrap();
+        let mut from_server_commands = Vec::new();
+        for _ in 0..4 {
+            from_server_commands.push(
+                tokio::time::timeout(Duration::from_secs(3), comm_middle_ware_receiver.recv())
+                    .await
+                    .unwrap()
+                    .unwrap(),
+            );
+        }
+        server_task.abort();
 
-        // the server sends the ExecutionState removed for the workload with an empty agent name
-        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
         assert_eq!(
-            FromServer::UpdateWorkloadState(UpdateWorkloadState {
-                workload_states: vec![WorkloadState {
-                    instance_name: workload_without_agent.instance_name,
-                    execution_state: ExecutionState::removed()
-                }]
-            }),
-            from_server_command
+            from_server_commands,
+            vec![
+                FromServer::ServerHello(ServerHello {
+                    agent_name: Some(AGENT_A.to_string()),
+                    added_workloads: vec![],
+                }),
+                FromServer::DisconnectAgent(DisconnectAgent {
+                    agent_name: AGENT_A.to_string(),
+                }),
+                FromServer::UpdateWorkloadState(UpdateWorkloadState {
+                    workload_states: vec![],
+                }),
+                FromServer::UpdateWorkload(UpdateWorkload {
+                    added_workloads: vec![rescheduled_workload],
+                    deleted_workloads: vec![],
+                }),
+            ]
         );
+    }
 
-        // the server sends only a deleted workload for the workload with a non-empty agent name
-        let from_server_command = comm_middle_ware_receiver.recv().await.unwrap();
-        assert_eq!(
-            FromServer::UpdateWorkload(UpdateWorkload {
-                added_workloads: vec![],
-                deleted_workloads: vec![deleted_workload_with_agent.clone()],
-            }),
-            from_server_command
-        );
+    // [utest->swdd~server-detects-lost-agents~1]
+    #[test]
+    fn utest_server_handles_restored_agents_as_gone() {
+        let (_to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
 
-        // ignore UpdateStateSuccessful response
-        assert!(matches!(
-            comm_middle_ware_receiver.recv().await.unwrap(),
-            FromServer::Response(_)
-        ));
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_load()
+            .once()
+            .return_const(Ok(Some(PersistedState {
+                scheduled_agents: HashMap::from([
+                    (WORKLOAD_NAME_1.to_owned(), AGENT_B.to_owned()),
+                    (WORKLOAD_NAME_2.to_owned(), AGENT_B.to_owned()),
+                ]),
+                ..Default::default()
+            })));
 
-        assert!(comm_middle_ware_receiver.try_recv().is_err());
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.set_state_store(Box::new(mock_state_store));
+        server.set_agent_heartbeat_timeout(Duration::ZERO);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state.expect_set_state_store().return_const(());
+        mock_server_state
+            .expect_restore_scheduled_agents()
+            .return_const(());
+        server.server_state = mock_server_state;
+
+        assert!(server.restore_persisted_state(None).is_ok());
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert_eq!(
+            server.agent_liveness.remove_lost_agents(),
+            vec![AGENT_B.to_owned()]
+        );
     }
 
-    // [utest->swdd~server-receives-resource-availability~1]
+    // [utest->swdd~server-ignores-agent-gone-of-disconnected-agents~1]
     #[tokio::test]
-    async fn utest_server_receives_agent_status_load() {
-        let payload = AgentLoadStatus {
-            agent_name: AGENT_A.to_string(),
-            cpu_usage: CpuUsage { cpu_usage: 42 },
-            free_memory: FreeMemory { free_memory: 42 },
-        };
-
+    async fn utest_server_ignores_agent_gone_of_disconnected_agent() {
         let _ = env_logger::builder().is_test(true).try_init();
         let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
-        let (to_agents, _comm_middle_ware_receiver) =
+        let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
         let mut mock_server_state = MockServerState::new();
         mock_server_state
-            .expect_update_agent_resource_availability()
-            .with(mockall::predicate::eq(payload.clone()))
-            .return_const(());
+            .expect_contains_connected_agent()
+            .with(predicate::eq(AGENT_A))
+            .once()
+            .return_const(false);
+        mock_server_state.expect_remove_agent().never();
         server.server_state = mock_server_state;
 
-        let agent_resource_result = to_server.agent_load_status(payload).await;
-        assert!(agent_resource_result.is_ok());
-
+        assert!(to_server.agent_gone(AGENT_A.to_string()).await.is_ok());
         drop(to_server);
-        let result = server.start(None).await;
+        server.start(None).await.unwrap();
 
-        assert!(result.is_ok());
+        assert!(comm_middle_ware_receiver.try_recv().is_err());
     }
 
-    // [utest->swdd~server-handles-not-started-deleted-workloads~1]
+    // [utest->swdd~server-stops-log-campaigns-of-gone-agents~1]
+    // [utest->swdd~server-cancels-log-campaigns-of-gone-requesters~1]
     #[tokio::test]
-    async fn utest_server_handles_pending_initial_deleted_workload_on_not_connected_agent() {
+    async fn utest_server_ends_log_campaigns_on_agent_gone() {
         let _ = env_logger::builder().is_test(true).try_init();
-        let (_to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
         let (to_agents, mut comm_middle_ware_receiver) =
             create_from_server_channel(common::CHANNEL_CAPACITY);
 
+        let instance_name_on_agent_a = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME_NAME.to_string(),
+        )
+        .instance_name;
+        let instance_name_on_agent_b = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_2.to_string(),
+            RUNTIME_NAME.to_string(),
+        )
+        .instance_name;
+        let cli_request_id = "cli-conn-1@id2";
+
         let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server
+            .log_campaign_store
+            .add_campaign(cli_request_id.to_string(), instance_name_on_agent_a.clone());
+        server
+            .log_campaign_store
+            .add_campaign(REQUEST_ID_A.to_string(), instance_name_on_agent_b);
         let mut mock_server_state = MockServerState::new();
         mock_server_state
             .expect_contains_connected_agent()
+            .with(predicate::eq(AGENT_A))
+            .return_const(true);
+        mock_server_state
+            .expect_remove_agent()
+            .with(predicate::eq(AGENT_A))
             .once()
-            .return_const(false);
+            .return_const(());
+        server.server_state = mock_server_state;
+        let server_task = tokio::spawn(async move { server.start(None).await });
 
-        let workload = generate_test_workload_spec_with_param(
-            AGENT_A.to_owned(),
-            WORKLOAD_NAME_1.to_owned(),
-            RUNTIME_NAME.to_string(),
-        );
+        assert!(to_server.agent_gone(AGENT_A.to_string()).await.is_ok());
 
-        server.server_state = mock_server_state;
-        server.workload_states_map = generate_test_workload_states_map_with_data(
-            workload.instance_name.agent_name(),
-            workload.instance_name.workload_name(),
-            workload.instance_name.id(),
-            ExecutionState::initial(),
+        let logs_stop_response = comm_middle_ware_receiver.recv().await;
+        let logs_cancel = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
+
+        assert_eq!(
+            logs_stop_response,
+            Some(FromServer::Response(ank_base::Response {
+                request_id: cli_request_id.to_string(),
+                response_content: Some(ank_base::response::ResponseContent::LogsStopResponse(
+                    ank_base::LogsStopResponse {
+                        workload_name: Some(instance_name_on_agent_a.into()),
+                    }
+                )),
+            }))
+        );
+        assert_eq!(
+            logs_cancel,
+            Some(FromServer::WorkloadLogsCancel(WorkloadLogsCancel {
+                request_id: REQUEST_ID_A.to_string(),
+                agent_name: AGENT_B.to_string(),
+            }))
         );
+    }
 
-        let deleted_workload_with_not_connected_agent = DeletedWorkload {
-            instance_name: workload.instance_name.clone(),
-            ..Default::default()
-        };
+    // [utest->swdd~server-cancels-log-campaigns-of-gone-requesters~1]
+    #[tokio::test]
+    async fn utest_server_cancels_log_campaigns_on_goodbye() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, mut comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
 
-        let deleted_workloads = vec![deleted_workload_with_not_connected_agent.clone()];
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        server.log_campaign_store.add_campaign(
+            REQUEST_ID_A.to_string(),
+            generate_test_workload_spec_with_param(
+                AGENT_B.to_string(),
+                WORKLOAD_NAME_1.to_string(),
+                RUNTIME_NAME.to_string(),
+            )
+            .instance_name,
+        );
+        server.server_state = MockServerState::new();
+        let server_task = tokio::spawn(async move { server.start(None).await });
 
-        let retained_deleted_workloads = server
-            .handle_not_started_deleted_workloads(deleted_workloads)
-            .await;
+        assert!(to_server.goodbye(AGENT_A.to_string()).await.is_ok());
 
-        assert!(retained_deleted_workloads.is_empty());
+        let logs_cancel = comm_middle_ware_receiver.recv().await;
+        server_task.abort();
 
         assert_eq!(
-            tokio::time::timeout(
-                tokio::time::Duration::from_millis(100),
-                comm_middle_ware_receiver.recv(),
-            )
-            .await,
-            Ok(Some(FromServer::UpdateWorkloadState(UpdateWorkloadState {
-                workload_states: vec![WorkloadState {
-                    instance_name: workload.instance_name,
-                    execution_state: ExecutionState::removed()
-                }]
-            })))
+            logs_cancel,
+            Some(FromServer::WorkloadLogsCancel(WorkloadLogsCancel {
+                request_id: REQUEST_ID_A.to_string(),
+                agent_name: AGENT_B.to_string(),
+            }))
         );
     }
 }
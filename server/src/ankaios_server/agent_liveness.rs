@@ -0,0 +1,237 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// Tracks the last heartbeat of the connected agents with a monotonic clock,
+// so that changes of the system time do not let agents time out.
+// [impl->swdd~server-tracks-agent-heartbeats~1]
+#[derive(Default)]
+pub struct AgentLiveness {
+    timeout: Option<Duration>,
+    last_heartbeats: HashMap<String, Instant>,
+    gone_agents: HashMap<String, Instant>,
+}
+
+impl AgentLiveness {
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.timeout.is_some()
+    }
+
+    pub fn agent_connected(&mut self, agent_name: &str) {
+        self.last_heartbeats
+            .insert(agent_name.to_owned(), Instant::now());
+        self.gone_agents.remove(agent_name);
+    }
+
+    // Heartbeats of agents which are not connected, e.g. because they already timed out, are ignored.
+    pub fn heartbeat(&mut self, agent_name: &str) {
+        if let Some(last_heartbeat) = self.last_heartbeats.get_mut(agent_name) {
+            *last_heartbeat = Instant::now();
+        }
+    }
+
+    // An agent which is already gone, e.g. because it missed its heartbeat, keeps the time
+    // it was gone first.
+    // [impl->swdd~server-detects-lost-agents~1]
+    pub fn agent_gone(&mut self, agent_name: &str) {
+        self.last_heartbeats.remove(agent_name);
+        if self.is_enabled() {
+            self.gone_agents
+                .entry(agent_name.to_owned())
+                .or_insert_with(Instant::now);
+        }
+    }
+
+    // [impl->swdd~server-detects-stale-agents~1]
+    pub fn remove_stale_agents(&mut self) -> Vec<String> {
+        let Some(timeout) = self.timeout else {
+            return Vec::new();
+        };
+
+        let mut stale_agents: Vec<String> = self
+            .last_heartbeats
+            .iter()
+            .filter(|(_, last_heartbeat)| last_heartbeat.elapsed() > timeout)
+            .map(|(agent_name, _)| agent_name.clone())
+            .collect();
+        stale_agents.sort();
+
+        for agent_name in &stale_agents {
+            if let Some(last_heartbeat) = self.last_heartbeats.remove(agent_name) {
+                // a stale agent is gone since its last heartbeat
+                self.gone_agents.insert(agent_name.clone(), last_heartbeat);
+            }
+        }
+        stale_agents
+    }
+
+    // Returns the agents which are gone longer than the timeout. Each lost agent is returned once.
+    // [impl->swdd~server-detects-lost-agents~1]
+    pub fn remove_lost_agents(&mut self) -> Vec<String> {
+        let Some(timeout) = self.timeout else {
+            return Vec::new();
+        };
+
+        let mut lost_agents: Vec<String> = self
+            .gone_agents
+            .iter()
+            .filter(|(_, gone_since)| gone_since.elapsed() > timeout)
+            .map(|(agent_name, _)| agent_name.clone())
+            .collect();
+        lost_agents.sort();
+
+        for agent_name in &lost_agents {
+            self.gone_agents.remove(agent_name);
+        }
+        lost_agents
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::AgentLiveness;
+    use std::time::Duration;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+
+    // [utest->swdd~server-detects-stale-agents~1]
+    #[test]
+    fn utest_remove_stale_agents_disabled_without_timeout() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.agent_connected(AGENT_A);
+
+        assert!(!agent_liveness.is_enabled());
+        assert!(agent_liveness.remove_stale_agents().is_empty());
+    }
+
+    // [utest->swdd~server-tracks-agent-heartbeats~1]
+    // [utest->swdd~server-detects-stale-agents~1]
+    #[test]
+    fn utest_remove_stale_agents_returns_agents_exceeding_timeout_once() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.set_timeout(Duration::ZERO);
+        agent_liveness.agent_connected(AGENT_B);
+        agent_liveness.agent_connected(AGENT_A);
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert_eq!(
+            agent_liveness.remove_stale_agents(),
+            vec![AGENT_A.to_string(), AGENT_B.to_string()]
+        );
+        assert!(agent_liveness.remove_stale_agents().is_empty());
+    }
+
+    // [utest->swdd~server-tracks-agent-heartbeats~1]
+    #[test]
+    fn utest_remove_stale_agents_keeps_agents_within_timeout() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.set_timeout(Duration::from_secs(3600));
+        agent_liveness.agent_connected(AGENT_A);
+        agent_liveness.heartbeat(AGENT_A);
+
+        assert!(agent_liveness.remove_stale_agents().is_empty());
+    }
+
+    // [utest->swdd~server-tracks-agent-heartbeats~1]
+    #[test]
+    fn utest_heartbeat_ignores_unknown_and_gone_agents() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.set_timeout(Duration::ZERO);
+        agent_liveness.agent_connected(AGENT_A);
+        agent_liveness.agent_gone(AGENT_A);
+        agent_liveness.heartbeat(AGENT_A);
+        agent_liveness.heartbeat(AGENT_B);
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert!(agent_liveness.remove_stale_agents().is_empty());
+    }
+
+    // [utest->swdd~server-detects-lost-agents~1]
+    #[test]
+    fn utest_remove_lost_agents_returns_agents_gone_longer_than_timeout_once() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.set_timeout(Duration::ZERO);
+        agent_liveness.agent_connected(AGENT_B);
+        agent_liveness.agent_gone(AGENT_B);
+        agent_liveness.agent_gone(AGENT_A);
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert_eq!(
+            agent_liveness.remove_lost_agents(),
+            vec![AGENT_A.to_string(), AGENT_B.to_string()]
+        );
+        assert!(agent_liveness.remove_lost_agents().is_empty());
+    }
+
+    // [utest->swdd~server-detects-lost-agents~1]
+    #[test]
+    fn utest_remove_lost_agents_returns_stale_agents() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.set_timeout(Duration::ZERO);
+        agent_liveness.agent_connected(AGENT_A);
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert_eq!(
+            agent_liveness.remove_stale_agents(),
+            vec![AGENT_A.to_string()]
+        );
+        agent_liveness.agent_gone(AGENT_A);
+
+        assert_eq!(
+            agent_liveness.remove_lost_agents(),
+            vec![AGENT_A.to_string()]
+        );
+    }
+
+    // [utest->swdd~server-detects-lost-agents~1]
+    #[test]
+    fn utest_remove_lost_agents_keeps_reconnected_agents_and_agents_within_timeout() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.set_timeout(Duration::ZERO);
+        agent_liveness.agent_gone(AGENT_A);
+        agent_liveness.agent_connected(AGENT_A);
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert!(agent_liveness.remove_lost_agents().is_empty());
+
+        agent_liveness.set_timeout(Duration::from_secs(3600));
+        agent_liveness.agent_gone(AGENT_B);
+
+        assert!(agent_liveness.remove_lost_agents().is_empty());
+    }
+
+    // [utest->swdd~server-detects-lost-agents~1]
+    #[test]
+    fn utest_remove_lost_agents_disabled_without_timeout() {
+        let mut agent_liveness = AgentLiveness::default();
+        agent_liveness.agent_gone(AGENT_A);
+
+        assert!(agent_liveness.remove_lost_agents().is_empty());
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use super::revision_history::TIMESTAMP_FORMAT;
+use api::ank_base;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    fs::{File, OpenOptions},
+    io::{ErrorKind, Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+// Limits the size of an AuditLog response, as the audit log file grows with every change of the
+// desired state.
+pub const MAX_AUDIT_LOG_ENTRIES: usize = 1000;
+const READ_CHUNK_SIZE: u64 = 8 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditLogError {
+    Read(String),
+    Write(String),
+    Parse(String),
+}
+
+impl fmt::Display for AuditLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditLogError::Read(reason) => {
+                write!(f, "Could not read the audit log: '{}'", reason)
+            }
+            AuditLogError::Write(reason) => {
+                write!(f, "Could not write the audit log: '{}'", reason)
+            }
+            AuditLogError::Parse(reason) => {
+                write!(f, "Could not parse the audit log: '{}'", reason)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub requester: String,
+    pub request: String,
+    #[serde(default)]
+    pub update_mask: Vec<String>,
+    #[serde(default)]
+    pub added_workloads: Vec<String>,
+    #[serde(default)]
+    pub deleted_workloads: Vec<String>,
+    pub accepted: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u64>,
+}
+
+impl AuditEntry {
+    pub fn new(requester: String, request: &str, update_mask: Vec<String>) -> Self {
+        AuditEntry {
+            timestamp: chrono::Utc::now().format(TIMESTAMP_FORMAT).to_string(),
+            requester,
+            request: request.to_owned(),
+            update_mask,
+            ..Default::default()
+        }
+    }
+
+    pub fn accept(
+        self,
+        added_workloads: Vec<String>,
+        deleted_workloads: Vec<String>,
+        revision: u64,
+    ) -> Self {
+        AuditEntry {
+            added_workloads,
+            deleted_workloads,
+            accepted: true,
+            revision: Some(revision),
+            ..self
+        }
+    }
+
+    pub fn reject(self, reason: String) -> Self {
+        AuditEntry {
+            accepted: false,
+            reason: Some(reason),
+            ..self
+        }
+    }
+}
+
+impl From<AuditEntry> for ank_base::AuditLogEntry {
+    fn from(item: AuditEntry) -> Self {
+        ank_base::AuditLogEntry {
+            timestamp: item.timestamp,
+            requester: item.requester,
+            request: item.request,
+            update_mask: item.update_mask,
+            added_workloads: item.added_workloads,
+            deleted_workloads: item.deleted_workloads,
+            accepted: item.accepted,
+            reason: item.reason,
+            revision: item.revision,
+        }
+    }
+}
+
+// [impl->swdd~server-audit-log-interface~1]
+#[cfg_attr(test, automock)]
+pub trait AuditLog: Send {
+    fn append(&mut self, entry: &AuditEntry) -> Result<(), AuditLogError>;
+    fn read(&self, max_entries: usize) -> Result<Vec<AuditEntry>, AuditLogError>;
+}
+
+// [impl->swdd~file-audit-log-appends-json-lines~1]
+pub struct FileAuditLog {
+    path: PathBuf,
+}
+
+impl FileAuditLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    // The file is read backwards in chunks until it contains enough complete lines, so that
+    // reading the latest entries does not depend on the size of the whole audit log.
+    // [impl->swdd~file-audit-log-reads-latest-entries~1]
+    fn read_last_lines(&self, max_lines: usize) -> std::io::Result<Vec<Vec<u8>>> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut position = file.metadata()?.len();
+        let mut content = Vec::new();
+        loop {
+            let chunk_size = position.min(READ_CHUNK_SIZE);
+            position -= chunk_size;
+            file.seek(SeekFrom::Start(position))?;
+            let mut chunk = vec![0; chunk_size as usize];
+            file.read_exact(&mut chunk)?;
+            chunk.append(&mut content);
+            content = chunk;
+
+            let mut lines: Vec<&[u8]> = content.split(|byte| *byte == b'\n').collect();
+            if position > 0 {
+                // the first line can be incomplete
+                lines.remove(0);
+            }
+            lines.retain(|line| !line.trim_ascii().is_empty());
+            if position == 0 || lines.len() >= max_lines {
+                let skipped_lines = lines.len().saturating_sub(max_lines);
+                return Ok(lines
+                    .into_iter()
+                    .skip(skipped_lines)
+                    .map(<[u8]>::to_vec)
+                    .collect());
+            }
+        }
+    }
+}
+
+impl AuditLog for FileAuditLog {
+    fn append(&mut self, entry: &AuditEntry) -> Result<(), AuditLogError> {
+        let mut line =
+            serde_json::to_string(entry).map_err(|err| AuditLogError::Write(err.to_string()))?;
+        line.push('\n');
+
+        // The file is only ever opened for appending, so existing entries are never modified.
+        // The whole line is written at once and synced to disk before the next request is handled.
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| {
+                file.write_all(line.as_bytes())?;
+                file.sync_data()
+            })
+            .map_err(|err| AuditLogError::Write(format!("'{}': {}", self.path.display(), err)))
+    }
+
+    fn read(&self, max_entries: usize) -> Result<Vec<AuditEntry>, AuditLogError> {
+        if max_entries == 0 {
+            return Ok(Vec::new());
+        }
+
+        let lines = self
+            .read_last_lines(max_entries)
+            .map_err(|err| AuditLogError::Read(format!("'{}': {}", self.path.display(), err)))?;
+
+        let line_count = lines.len();
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                serde_json::from_slice(line).map_err(|err| {
+                    AuditLogError::Parse(format!(
+                        "'{}' line {} from the end: {}",
+                        self.path.display(),
+                        line_count - index,
+                        err
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditEntry, AuditLog, AuditLogError, FileAuditLog, MAX_AUDIT_LOG_ENTRIES};
+    use std::fs;
+
+    fn accepted_entry() -> AuditEntry {
+        AuditEntry {
+            timestamp: "2025-01-01T00:00:00Z".to_string(),
+            requester: "cli-conn-1".to_string(),
+            request: "UpdateStateRequest".to_string(),
+            update_mask: vec!["desiredState.workloads.nginx".to_string()],
+            added_workloads: vec!["nginx.hash.agent_A".to_string()],
+            deleted_workloads: vec![],
+            accepted: true,
+            reason: None,
+            revision: Some(2),
+        }
+    }
+
+    fn rejected_entry() -> AuditEntry {
+        AuditEntry {
+            timestamp: "2025-01-01T00:00:01Z".to_string(),
+            requester: "agent_A@controller".to_string(),
+            request: "RollbackRequest".to_string(),
+            accepted: false,
+            reason: Some("revision '7' is not in the revision history".to_string()),
+            ..Default::default()
+        }
+    }
+
+    // [utest->swdd~file-audit-log-appends-json-lines~1]
+    #[test]
+    fn utest_file_audit_log_appends_entries_as_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let mut audit_log = FileAuditLog::new(&path);
+
+        audit_log.append(&accepted_entry()).unwrap();
+        audit_log.append(&rejected_entry()).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                r#"{"timestamp":"2025-01-01T00:00:00Z","requester":"cli-conn-1","request":"UpdateStateRequest","updateMask":["desiredState.workloads.nginx"],"addedWorkloads":["nginx.hash.agent_A"],"deletedWorkloads":[],"accepted":true,"revision":2}"#,
+                r#"{"timestamp":"2025-01-01T00:00:01Z","requester":"agent_A@controller","request":"RollbackRequest","updateMask":[],"addedWorkloads":[],"deletedWorkloads":[],"accepted":false,"reason":"revision '7' is not in the revision history"}"#,
+            ]
+        );
+        assert_eq!(
+            audit_log.read(MAX_AUDIT_LOG_ENTRIES),
+            Ok(vec![accepted_entry(), rejected_entry()])
+        );
+    }
+
+    // [utest->swdd~file-audit-log-appends-json-lines~1]
+    #[test]
+    fn utest_file_audit_log_keeps_existing_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        FileAuditLog::new(&path).append(&accepted_entry()).unwrap();
+
+        let mut audit_log = FileAuditLog::new(&path);
+        audit_log.append(&rejected_entry()).unwrap();
+
+        assert_eq!(
+            audit_log.read(MAX_AUDIT_LOG_ENTRIES),
+            Ok(vec![accepted_entry(), rejected_entry()])
+        );
+    }
+
+    // [utest->swdd~file-audit-log-reads-latest-entries~1]
+    #[test]
+    fn utest_file_audit_log_reads_only_latest_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let mut audit_log = FileAuditLog::new(&path);
+
+        // spans several read chunks
+        let entries: Vec<AuditEntry> = (0..200)
+            .map(|revision| AuditEntry {
+                revision: Some(revision),
+                ..accepted_entry()
+            })
+            .collect();
+        for entry in &entries {
+            audit_log.append(entry).unwrap();
+        }
+
+        assert_eq!(audit_log.read(3), Ok(entries[197..].to_vec()));
+        assert_eq!(audit_log.read(500), Ok(entries.clone()));
+        assert_eq!(audit_log.read(0), Ok(vec![]));
+    }
+
+    // [utest->swdd~file-audit-log-reads-latest-entries~1]
+    #[test]
+    fn utest_file_audit_log_read_ignores_invalid_lines_before_latest_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        fs::write(&path, "not json\n").unwrap();
+
+        let mut audit_log = FileAuditLog::new(&path);
+        audit_log.append(&accepted_entry()).unwrap();
+        audit_log.append(&rejected_entry()).unwrap();
+
+        assert_eq!(audit_log.read(1), Ok(vec![rejected_entry()]));
+        assert!(matches!(audit_log.read(3), Err(AuditLogError::Parse(_))));
+    }
+
+    #[test]
+    fn utest_file_audit_log_read_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let audit_log = FileAuditLog::new(dir.path().join("audit.jsonl"));
+
+        assert_eq!(audit_log.read(MAX_AUDIT_LOG_ENTRIES), Ok(vec![]));
+    }
+
+    #[test]
+    fn utest_file_audit_log_read_fails_on_invalid_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        fs::write(&path, "not json\n").unwrap();
+
+        assert!(matches!(
+            FileAuditLog::new(&path).read(MAX_AUDIT_LOG_ENTRIES),
+            Err(AuditLogError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn utest_file_audit_log_append_fails_on_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut audit_log = FileAuditLog::new(dir.path().join("missing").join("audit.jsonl"));
+
+        assert!(matches!(
+            audit_log.append(&accepted_entry()),
+            Err(AuditLogError::Write(_))
+        ));
+    }
+}
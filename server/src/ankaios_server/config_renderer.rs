@@ -12,6 +12,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+mod template_helpers;
+
 use std::{collections::HashMap, fmt};
 
 use common::objects::{
@@ -19,6 +21,7 @@ use common::objects::{
     WorkloadSpec,
 };
 use handlebars::{Handlebars, RenderError};
+use template_helpers::register_template_helpers;
 
 pub type RenderedWorkloads = HashMap<String, WorkloadSpec>;
 
@@ -29,6 +32,7 @@ use mockall::mock;
 pub enum ConfigRenderError {
     Field(String, String),
     NotExistingConfigKey(String),
+    NotExistingSecret(String),
 }
 
 impl fmt::Display for ConfigRenderError {
@@ -44,6 +48,13 @@ impl fmt::Display for ConfigRenderError {
                     config_key
                 )
             }
+            ConfigRenderError::NotExistingSecret(secret_name) => {
+                write!(
+                    f,
+                    "Workload references secret '{}' that does not exist",
+                    secret_name
+                )
+            }
         }
     }
 }
@@ -77,39 +88,54 @@ impl Default for ConfigRenderer {
         template_engine
             .register_partial("indent", "{{content}}")
             .unwrap();
+
+        // [impl->swdd~config-renderer-provides-template-helpers~1]
+        register_template_helpers(&mut template_engine);
         Self { template_engine }
     }
 }
 
 impl ConfigRenderer {
     // [impl->swdd~config-renderer-renders-workload-configuration~2]
+    // [impl->swdd~config-renderer-renders-secrets-into-workload-configuration~1]
     pub fn render_workloads(
         &self,
         workloads: &HashMap<String, StoredWorkloadSpec>,
         configs: &HashMap<String, ConfigItem>,
+        secrets: &HashMap<String, ConfigItem>,
     ) -> Result<RenderedWorkloads, ConfigRenderError> {
         let mut rendered_workloads = HashMap::new();
         for (workload_name, stored_workload) in workloads {
-            let workload_spec = if stored_workload.configs.is_empty() {
-                log::debug!(
-                    "Skipping to render workload '{}' as no config is assigned to the workload",
-                    workload_name
-                );
-                WorkloadSpec::from((workload_name.to_owned(), stored_workload.clone()))
-            } else {
-                let wl_config_map =
-                    self.create_config_map_for_workload(stored_workload, configs)?;
-                log::debug!(
-                    "Rendering workload '{}' with config '{:?}'",
-                    workload_name,
-                    wl_config_map
-                );
-                self.render_workload_fields(workload_name, stored_workload, &wl_config_map)?
-            };
+            let workload_spec =
+                if stored_workload.configs.is_empty() && stored_workload.secrets.is_empty() {
+                    log::debug!(
+                        "Skipping to render workload '{}' as no config is assigned to the workload",
+                        workload_name
+                    );
+                    WorkloadSpec::from((workload_name.to_owned(), stored_workload.clone()))
+                } else {
+                    let wl_config_map =
+                        self.create_config_map_for_workload(stored_workload, configs)?;
+                    log::debug!(
+                        "Rendering workload '{}' with config '{:?}' and secrets '{:?}'",
+                        workload_name,
+                        wl_config_map,
+                        stored_workload.secrets.keys()
+                    );
+                    let wl_secret_map =
+                        self.create_secret_map_for_workload(stored_workload, secrets)?;
+                    self.render_workload_fields(
+                        workload_name,
+                        stored_workload,
+                        &wl_config_map,
+                        &wl_secret_map,
+                    )?
+                };
 
             rendered_workloads.insert(workload_name.clone(), workload_spec);
         }
-        log::trace!("Rendered CompleteState: {:?}", rendered_workloads);
+        // the rendered workloads are not logged as they might contain secrets
+        log::trace!("Rendered workloads: {:?}", rendered_workloads.keys());
         Ok(rendered_workloads)
     }
 
@@ -130,16 +156,38 @@ impl ConfigRenderer {
         Ok(wl_config_map)
     }
 
+    // [impl->swdd~config-renderer-renders-secrets-into-workload-configuration~1]
+    fn create_secret_map_for_workload<'a>(
+        &self,
+        workload_spec: &'a StoredWorkloadSpec,
+        secrets: &'a HashMap<String, ConfigItem>,
+    ) -> Result<HashMap<&'a String, &'a ConfigItem>, ConfigRenderError> {
+        let mut wl_secret_map = HashMap::new();
+        for (secret_alias, secret_name) in &workload_spec.secrets {
+            if let Some(secret_value) = secrets.get(secret_name) {
+                wl_secret_map.insert(secret_alias, secret_value);
+            } else {
+                return Err(ConfigRenderError::NotExistingSecret(secret_name.clone()));
+            }
+        }
+        Ok(wl_secret_map)
+    }
+
     // [impl->swdd~config-renderer-renders-workload-configuration~2]
     fn render_workload_fields(
         &self,
         workload_name: &str,
         workload: &StoredWorkloadSpec,
         wl_config_map: &HashMap<&String, &ConfigItem>,
+        wl_secret_map: &HashMap<&String, &ConfigItem>,
     ) -> Result<WorkloadSpec, ConfigRenderError> {
+        // the secrets are only rendered into fields that are sent to the agent of the workload
+        let mut wl_config_and_secret_map = wl_config_map.clone();
+        wl_config_and_secret_map.extend(wl_secret_map);
+
         let rendered_runtime_config = self
             .template_engine
-            .render_template(&workload.runtime_config, &wl_config_map)
+            .render_template(&workload.runtime_config, &wl_config_and_secret_map)
             .map_err(ConfigRenderError::for_field("runtimeConfig"))?;
 
         let rendered_agent_name = self
@@ -147,13 +195,20 @@ impl ConfigRenderer {
             .render_template(&workload.agent, &wl_config_map)
             .map_err(ConfigRenderError::for_field("agent"))?;
 
-        let rendered_files = self.render_files_field(&workload.files, wl_config_map)?;
+        let rendered_files = self.render_files_field(&workload.files, &wl_config_and_secret_map)?;
+
+        let instance_id_runtime_config = self.render_instance_id_runtime_config(
+            workload,
+            &rendered_runtime_config,
+            wl_config_map,
+            wl_secret_map,
+        )?;
 
         Ok(WorkloadSpec {
             instance_name: WorkloadInstanceName::builder()
                 .workload_name(workload_name)
                 .agent_name(rendered_agent_name)
-                .config(&rendered_runtime_config)
+                .config(&instance_id_runtime_config)
                 .build(),
             runtime: workload.runtime.clone(),
             runtime_config: rendered_runtime_config,
@@ -162,9 +217,42 @@ impl ConfigRenderer {
             restart_policy: workload.restart_policy.clone(),
             files: rendered_files,
             control_interface_access: workload.control_interface_access.clone(),
+            suspended: workload.suspended,
+            restart_generation: workload.restart_generation,
         })
     }
 
+    // The id of the instance name is an unsalted hash visible in the workload states. It is computed
+    // from the runtime config rendered with redacted secrets, so that the secrets cannot be guessed
+    // from it. A changed secret is still detected as the rendered workloads are compared completely.
+    // [impl->swdd~config-renderer-computes-instance-id-without-secrets~1]
+    fn render_instance_id_runtime_config(
+        &self,
+        workload: &StoredWorkloadSpec,
+        rendered_runtime_config: &str,
+        wl_config_map: &HashMap<&String, &ConfigItem>,
+        wl_secret_map: &HashMap<&String, &ConfigItem>,
+    ) -> Result<String, ConfigRenderError> {
+        if wl_secret_map.is_empty() {
+            return Ok(rendered_runtime_config.to_owned());
+        }
+
+        let redacted_secrets: HashMap<&String, ConfigItem> = wl_secret_map
+            .iter()
+            .map(|(secret_alias, secret_value)| (*secret_alias, secret_value.redacted()))
+            .collect();
+        let mut wl_config_and_redacted_secret_map = wl_config_map.clone();
+        wl_config_and_redacted_secret_map.extend(
+            redacted_secrets
+                .iter()
+                .map(|(secret_alias, redacted_value)| (*secret_alias, redacted_value)),
+        );
+
+        self.template_engine
+            .render_template(&workload.runtime_config, &wl_config_and_redacted_secret_map)
+            .map_err(ConfigRenderError::for_field("runtimeConfig"))
+    }
+
     // [impl->swdd~config-renderer-renders-workload-configuration~2]
     fn render_files_field(
         &self,
@@ -213,6 +301,7 @@ mock! {
             &self,
             workloads: &HashMap<String, StoredWorkloadSpec>,
             configs: &HashMap<String, ConfigItem>,
+            secrets: &HashMap<String, ConfigItem>,
         ) -> Result<RenderedWorkloads, ConfigRenderError>;
     }
 }
@@ -227,8 +316,8 @@ mod tests {
         generate_test_stored_workload_spec_with_config,
         generate_test_stored_workload_spec_with_files,
         generate_test_workload_spec_with_rendered_files,
-        generate_test_workload_spec_with_runtime_config, Base64Data, ConfigItem, Data, File,
-        FileContent,
+        generate_test_workload_spec_with_runtime_config, Base64Data, ConfigHash, ConfigItem, Data,
+        File, FileContent,
     };
 
     const WORKLOAD_NAME_1: &str = "workload_1";
@@ -275,7 +364,73 @@ mod tests {
             "some_value_1: value123\nsome_value_2: list_value_1".to_owned(),
         );
 
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
+
+        assert_eq!(
+            Ok(RenderedWorkloads::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                expected_workload_spec
+            )])),
+            result
+        );
+    }
+
+    // [utest->swdd~config-renderer-renders-workload-configuration~2]
+    #[test]
+    fn utest_render_workloads_keeps_suspended_flag() {
+        let mut stored_workload = generate_test_stored_workload_spec_with_config(
+            "{{ref1.agent_name}}",
+            RUNTIME,
+            "some_value_1: {{ref1.values.value_1}}",
+        );
+        stored_workload.suspended = true;
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let renderer = ConfigRenderer::default();
+
+        let mut expected_workload_spec = generate_test_workload_spec_with_runtime_config(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+            "some_value_1: value123".to_owned(),
+        );
+        expected_workload_spec.suspended = true;
+
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
+
+        assert_eq!(
+            Ok(RenderedWorkloads::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                expected_workload_spec
+            )])),
+            result
+        );
+    }
+
+    // [utest->swdd~config-renderer-renders-workload-configuration~2]
+    #[test]
+    fn utest_render_workloads_keeps_restart_generation() {
+        let mut stored_workload = generate_test_stored_workload_spec_with_config(
+            "{{ref1.agent_name}}",
+            RUNTIME,
+            "some_value_1: {{ref1.values.value_1}}",
+        );
+        stored_workload.restart_generation = 3;
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let renderer = ConfigRenderer::default();
+
+        let mut expected_workload_spec = generate_test_workload_spec_with_runtime_config(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+            "some_value_1: value123".to_owned(),
+        );
+        expected_workload_spec.restart_generation = 3;
+
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
 
         assert_eq!(
             Ok(RenderedWorkloads::from([(
@@ -306,7 +461,7 @@ mod tests {
             generate_test_rendered_workload_files(),
         );
 
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
 
         assert_eq!(
             Ok(RenderedWorkloads::from([(
@@ -335,7 +490,7 @@ mod tests {
         let configs = generate_test_configs();
         let renderer = ConfigRenderer::default();
 
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
         assert!(result.is_err());
         assert!(
             matches!(result.unwrap_err(), ConfigRenderError::Field(field, _) if field.starts_with("files"))
@@ -360,7 +515,9 @@ mod tests {
         let configs = generate_test_configs();
         let renderer = ConfigRenderer::default();
 
-        assert!(renderer.render_workloads(&workloads, &configs).is_err());
+        assert!(renderer
+            .render_workloads(&workloads, &configs, &HashMap::new())
+            .is_err());
     }
 
     // [utest->swdd~config-renderer-renders-workload-configuration~2]
@@ -377,7 +534,9 @@ mod tests {
         let configs = generate_test_configs();
         let renderer = ConfigRenderer::default();
 
-        assert!(renderer.render_workloads(&workloads, &configs).is_err());
+        assert!(renderer
+            .render_workloads(&workloads, &configs, &HashMap::new())
+            .is_err());
     }
 
     // [utest->swdd~config-renderer-renders-workload-configuration~2]
@@ -404,7 +563,7 @@ mod tests {
             templated_runtime_config.to_owned(),
         );
 
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
 
         assert_eq!(
             Ok(RenderedWorkloads::from([(
@@ -431,7 +590,7 @@ mod tests {
         let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
         let configs = generate_test_configs();
         let renderer = ConfigRenderer::default();
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
         assert!(result.is_err());
         assert!(
             matches!(result.unwrap_err(), ConfigRenderError::NotExistingConfigKey(config_key) if config_key == "not_existing_config_key")
@@ -452,13 +611,207 @@ mod tests {
         let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
         let configs = generate_test_configs();
         let renderer = ConfigRenderer::default();
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
         assert!(result.is_err());
         assert!(
             matches!(result.unwrap_err(), ConfigRenderError::NotExistingConfigKey(config_key) if config_key == "not_existing_unused_config_key")
         );
     }
 
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_render_workloads_render_with_template_helpers_successfully() {
+        let templated_runtime_config =
+            "value: {{upper ref1.values.value_1}}\nlist: {{join ref1.values.value_2 \",\"}}";
+        let stored_workload = generate_test_stored_workload_spec_with_config(
+            AGENT_A,
+            RUNTIME,
+            templated_runtime_config,
+        );
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let renderer = ConfigRenderer::default();
+
+        let expected_workload_spec = generate_test_workload_spec_with_runtime_config(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+            "value: VALUE123\nlist: list_value_1,list_value_2".to_owned(),
+        );
+
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
+
+        assert_eq!(
+            Ok(RenderedWorkloads::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                expected_workload_spec
+            )])),
+            result
+        );
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_render_workloads_fails_template_helper_error() {
+        let stored_workload = generate_test_stored_workload_spec_with_config(
+            AGENT_A,
+            RUNTIME,
+            "value: {{base64Decode ref1.values.value_1}}",
+        );
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let renderer = ConfigRenderer::default();
+
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
+
+        assert!(
+            matches!(result, Err(ConfigRenderError::Field(field, reason)) if field == "runtimeConfig" && reason.contains("base64Decode"))
+        );
+    }
+
+    // [utest->swdd~config-renderer-renders-secrets-into-workload-configuration~1]
+    #[test]
+    fn utest_render_workloads_render_secrets_into_runtime_config_and_files() {
+        let mut stored_workload = generate_test_stored_workload_spec_with_config(
+            AGENT_A,
+            RUNTIME,
+            "value: {{ref1.values.value_1}}\npassword: {{db.password}}",
+        );
+        stored_workload
+            .secrets
+            .insert("db".to_owned(), "db_credentials".to_owned());
+        stored_workload.files = vec![File {
+            mount_point: "/password".to_string(),
+            file_content: FileContent::Data(Data {
+                data: "{{db.password}}".into(),
+            }),
+        }];
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let secrets = HashMap::from([(
+            "db_credentials".to_owned(),
+            ConfigItem::ConfigObject(HashMap::from([(
+                "password".to_owned(),
+                ConfigItem::String("s3cr3t".to_owned()),
+            )])),
+        )]);
+        let renderer = ConfigRenderer::default();
+
+        let mut result = renderer
+            .render_workloads(&workloads, &configs, &secrets)
+            .unwrap();
+        let workload_spec = result.remove(WORKLOAD_NAME_1).unwrap();
+
+        assert_eq!(
+            workload_spec.runtime_config,
+            "value: value123\npassword: s3cr3t"
+        );
+        assert_eq!(
+            workload_spec.files,
+            vec![File {
+                mount_point: "/password".to_string(),
+                file_content: FileContent::Data(Data {
+                    data: "s3cr3t".into(),
+                }),
+            }]
+        );
+    }
+
+    // [utest->swdd~config-renderer-computes-instance-id-without-secrets~1]
+    #[test]
+    fn utest_render_workloads_computes_instance_id_without_secrets() {
+        let mut stored_workload = generate_test_stored_workload_spec_with_config(
+            AGENT_A,
+            RUNTIME,
+            "value: {{ref1.values.value_1}}\npassword: {{db.password}}",
+        );
+        stored_workload
+            .secrets
+            .insert("db".to_owned(), "db_credentials".to_owned());
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let secrets_with_password = |password: &str| {
+            HashMap::from([(
+                "db_credentials".to_owned(),
+                ConfigItem::ConfigObject(HashMap::from([(
+                    "password".to_owned(),
+                    ConfigItem::String(password.to_owned()),
+                )])),
+            )])
+        };
+        let renderer = ConfigRenderer::default();
+
+        let workload_spec = renderer
+            .render_workloads(&workloads, &configs, &secrets_with_password("s3cr3t"))
+            .unwrap()
+            .remove(WORKLOAD_NAME_1)
+            .unwrap();
+        let workload_spec_with_changed_secret = renderer
+            .render_workloads(&workloads, &configs, &secrets_with_password("changed"))
+            .unwrap()
+            .remove(WORKLOAD_NAME_1)
+            .unwrap();
+
+        assert_eq!(
+            workload_spec.instance_name.id(),
+            "value: value123\npassword: ******".to_owned().hash_config()
+        );
+        assert_eq!(
+            workload_spec.instance_name,
+            workload_spec_with_changed_secret.instance_name
+        );
+        assert_ne!(workload_spec, workload_spec_with_changed_secret);
+    }
+
+    // [utest->swdd~config-renderer-renders-secrets-into-workload-configuration~1]
+    #[test]
+    fn utest_render_workloads_does_not_render_secrets_into_agent_name() {
+        let mut stored_workload =
+            generate_test_stored_workload_spec_with_config("{{agent}}", RUNTIME, "");
+        stored_workload.configs.clear();
+        stored_workload
+            .secrets
+            .insert("agent".to_owned(), "agent_secret".to_owned());
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let secrets = HashMap::from([(
+            "agent_secret".to_owned(),
+            ConfigItem::String(AGENT_A.to_owned()),
+        )]);
+        let renderer = ConfigRenderer::default();
+
+        let result = renderer.render_workloads(&workloads, &HashMap::new(), &secrets);
+
+        assert!(matches!(result, Err(ConfigRenderError::Field(field, _)) if field == "agent"));
+    }
+
+    // [utest->swdd~config-renderer-renders-secrets-into-workload-configuration~1]
+    #[test]
+    fn utest_render_workloads_fails_workload_references_not_existing_secret() {
+        let mut stored_workload =
+            generate_test_stored_workload_spec_with_config(AGENT_A, RUNTIME, "");
+        stored_workload
+            .secrets
+            .insert("db".to_owned(), "not_existing_secret".to_owned());
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let renderer = ConfigRenderer::default();
+
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
+
+        assert_eq!(
+            result,
+            Err(ConfigRenderError::NotExistingSecret(
+                "not_existing_secret".to_owned()
+            ))
+        );
+    }
+
     // [utest->swdd~config-renderer-renders-workload-configuration~2]
     #[test]
     fn utest_render_workloads_fails_runtime_config_contains_non_existing_config() {
@@ -473,7 +826,7 @@ mod tests {
         let configs = generate_test_configs();
         let renderer = ConfigRenderer::default();
 
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
 
         assert!(result.is_err());
         assert!(
@@ -494,7 +847,7 @@ mod tests {
         let configs = generate_test_configs();
         let renderer = ConfigRenderer::default();
 
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
 
         assert!(result.is_err());
         assert!(
@@ -516,7 +869,9 @@ mod tests {
         let configs = HashMap::default();
         let renderer = ConfigRenderer::default();
 
-        assert!(renderer.render_workloads(&workloads, &configs).is_err());
+        assert!(renderer
+            .render_workloads(&workloads, &configs, &HashMap::new())
+            .is_err());
     }
 
     // [utest->swdd~config-renderer-renders-workload-configuration~2]
@@ -546,7 +901,7 @@ mod tests {
         )]);
         let renderer = ConfigRenderer::default();
 
-        let render_result = renderer.render_workloads(&workloads, &configs);
+        let render_result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
         assert!(render_result.is_ok());
         let rendered_workloads = render_result.unwrap();
 
@@ -593,7 +948,7 @@ mod tests {
             format!("config_of_special_char_sequences: {CONFIG_VALUE}"),
         );
 
-        let result = renderer.render_workloads(&workloads, &configs);
+        let result = renderer.render_workloads(&workloads, &configs, &HashMap::new());
 
         assert_eq!(
             Ok(RenderedWorkloads::from([(
@@ -0,0 +1,369 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine};
+use handlebars::{
+    handlebars_helper, Context, Handlebars, Helper, HelperDef, JsonRender, JsonValue,
+    RenderContext, RenderError, RenderErrorReason, ScopedJson,
+};
+
+// The comparison helpers "eq", "ne", "gt", "gte", "lt", "lte", "and", "or" and "not"
+// are already built into the template engine.
+// [impl->swdd~config-renderer-provides-template-helpers~1]
+pub fn register_template_helpers(template_engine: &mut Handlebars) {
+    template_engine.register_helper("toYaml", Box::new(to_yaml));
+    template_engine.register_helper("toJson", Box::new(to_json));
+    template_engine.register_helper("base64Encode", Box::new(base64_encode));
+    template_engine.register_helper("base64Decode", Box::new(base64_decode));
+    template_engine.register_helper("default", Box::new(DefaultHelper));
+    template_engine.register_helper("upper", Box::new(upper));
+    template_engine.register_helper("lower", Box::new(lower));
+    template_engine.register_helper("join", Box::new(join));
+    template_engine.register_helper("quote", Box::new(quote));
+
+    for (name, integer_operation, float_operation) in ARITHMETIC_OPERATIONS {
+        template_engine.register_helper(
+            name,
+            Box::new(ArithmeticHelper {
+                name,
+                integer_operation,
+                float_operation,
+            }),
+        );
+    }
+}
+
+fn helper_error(helper: &str, reason: impl std::fmt::Display) -> RenderError {
+    RenderErrorReason::Other(format!("Helper '{}' failed: {}", helper, reason)).into()
+}
+
+handlebars_helper!(to_yaml: |value: Json| {
+    serde_yaml::to_string(value)
+        .map_err(|err| helper_error("toYaml", err))?
+        .trim_end_matches('\n')
+        .to_owned()
+});
+
+handlebars_helper!(to_json: |value: Json| {
+    serde_json::to_string(value).map_err(|err| helper_error("toJson", err))?
+});
+
+handlebars_helper!(base64_encode: |value: str| BASE64_STANDARD.encode(value));
+
+handlebars_helper!(base64_decode: |value: str| {
+    let decoded = BASE64_STANDARD
+        .decode(value)
+        .map_err(|err| helper_error("base64Decode", err))?;
+    String::from_utf8(decoded).map_err(|err| helper_error("base64Decode", err))?
+});
+
+handlebars_helper!(upper: |value: str| value.to_uppercase());
+
+handlebars_helper!(lower: |value: str| value.to_lowercase());
+
+handlebars_helper!(join: |values: array, separator: str| {
+    values
+        .iter()
+        .map(|value| value.render())
+        .collect::<Vec<String>>()
+        .join(separator)
+});
+
+// The quoted value is escaped, so it is a valid string in JSON and YAML documents.
+handlebars_helper!(quote: |value: Json| {
+    serde_json::to_string(&value.render()).map_err(|err| helper_error("quote", err))?
+});
+
+// Returns the fallback given as second parameter if the value is missing or empty.
+// In contrast to the other helpers, a missing value is no error even in strict mode.
+struct DefaultHelper;
+
+impl HelperDef for DefaultHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let value = h
+            .param(0)
+            .ok_or(RenderErrorReason::ParamNotFoundForIndex("default", 0))?;
+        let fallback = h
+            .param(1)
+            .ok_or(RenderErrorReason::ParamNotFoundForIndex("default", 1))?;
+
+        let is_empty = value.is_value_missing()
+            || match value.value() {
+                JsonValue::Null => true,
+                JsonValue::String(value) => value.is_empty(),
+                JsonValue::Array(values) => values.is_empty(),
+                JsonValue::Object(values) => values.is_empty(),
+                _ => false,
+            };
+
+        Ok(ScopedJson::Derived(if is_empty {
+            fallback.value().clone()
+        } else {
+            value.value().clone()
+        }))
+    }
+}
+
+type IntegerOperation = fn(i64, i64) -> Option<i64>;
+type FloatOperation = fn(f64, f64) -> f64;
+
+const ARITHMETIC_OPERATIONS: [(&str, IntegerOperation, FloatOperation); 5] = [
+    ("add", i64::checked_add, |a, b| a + b),
+    ("sub", i64::checked_sub, |a, b| a - b),
+    ("mul", i64::checked_mul, |a, b| a * b),
+    ("div", i64::checked_div, |a, b| a / b),
+    ("mod", i64::checked_rem, |a, b| a % b),
+];
+
+enum Number {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Number {
+    // Config values are always strings, so numeric strings are accepted as well as numbers.
+    fn parse(helper: &str, value: &JsonValue) -> Result<Self, RenderError> {
+        let text = match value {
+            JsonValue::String(text) => text.trim().to_owned(),
+            JsonValue::Number(number) => number.to_string(),
+            _ => return Err(helper_error(helper, format!("'{}' is not a number", value))),
+        };
+        if let Ok(integer) = text.parse::<i64>() {
+            Ok(Number::Integer(integer))
+        } else if let Ok(float) = text.parse::<f64>() {
+            Ok(Number::Float(float))
+        } else {
+            Err(helper_error(helper, format!("'{}' is not a number", text)))
+        }
+    }
+
+    fn as_float(&self) -> f64 {
+        match self {
+            Number::Integer(integer) => *integer as f64,
+            Number::Float(float) => *float,
+        }
+    }
+}
+
+// Integers are calculated as integers, e.g. the division truncates the result.
+// As soon as one of the operands is a floating point number, the result is a floating point number.
+struct ArithmeticHelper {
+    name: &'static str,
+    integer_operation: IntegerOperation,
+    float_operation: FloatOperation,
+}
+
+impl HelperDef for ArithmeticHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        r: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        let mut operands = [0, 1].into_iter().map(|index| {
+            let param = h
+                .param(index)
+                .filter(|param| !(r.strict_mode() && param.is_value_missing()))
+                .ok_or(RenderErrorReason::ParamNotFoundForIndex(self.name, index))?;
+            Number::parse(self.name, param.value())
+        });
+        let (left, right) = (operands.next().unwrap()?, operands.next().unwrap()?);
+
+        let result = match (&left, &right) {
+            (Number::Integer(left), Number::Integer(right)) => {
+                JsonValue::from((self.integer_operation)(*left, *right).ok_or_else(|| {
+                    helper_error(
+                        self.name,
+                        format!("invalid operation with '{}' and '{}'", left, right),
+                    )
+                })?)
+            }
+            _ => {
+                let result = (self.float_operation)(left.as_float(), right.as_float());
+                serde_json::Number::from_f64(result)
+                    .map(JsonValue::Number)
+                    .ok_or_else(|| {
+                        helper_error(
+                            self.name,
+                            format!("the result '{}' is not a valid number", result),
+                        )
+                    })?
+            }
+        };
+        Ok(ScopedJson::Derived(result))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::register_template_helpers;
+    use handlebars::Handlebars;
+    use serde_json::{json, Value};
+
+    fn render(template: &str, data: &Value) -> Result<String, String> {
+        let mut template_engine = Handlebars::new();
+        template_engine.set_strict_mode(true);
+        template_engine.register_escape_fn(handlebars::no_escape);
+        register_template_helpers(&mut template_engine);
+        template_engine
+            .render_template(template, data)
+            .map_err(|err| err.to_string())
+    }
+
+    fn test_data() -> Value {
+        json!({
+            "name": "Nginx",
+            "port": "8080",
+            "ratio": "1.5",
+            "empty": "",
+            "hosts": ["a.example", "b.example"],
+            "env": {"LOG_LEVEL": "debug", "TIMEOUT": "10"},
+            "encoded": "aGVsbG8=",
+            "text": "say \"hi\""
+        })
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_template_helpers_to_yaml_and_to_json() {
+        assert_eq!(
+            render("{{toYaml env}}", &test_data()),
+            Ok("LOG_LEVEL: debug\nTIMEOUT: '10'".to_owned())
+        );
+        assert_eq!(
+            render("{{toJson hosts}}", &test_data()),
+            Ok(r#"["a.example","b.example"]"#.to_owned())
+        );
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_template_helpers_base64() {
+        assert_eq!(
+            render("{{base64Encode \"hello\"}}", &test_data()),
+            Ok("aGVsbG8=".to_owned())
+        );
+        assert_eq!(
+            render("{{base64Decode encoded}}", &test_data()),
+            Ok("hello".to_owned())
+        );
+        assert_eq!(
+            render("{{base64Decode (base64Encode name)}}", &test_data()),
+            Ok("Nginx".to_owned())
+        );
+        assert!(render("{{base64Decode name}}", &test_data())
+            .unwrap_err()
+            .contains("Helper 'base64Decode' failed"));
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_template_helpers_default() {
+        assert_eq!(
+            render("{{default port \"80\"}}", &test_data()),
+            Ok("8080".to_owned())
+        );
+        assert_eq!(
+            render("{{default empty \"80\"}}", &test_data()),
+            Ok("80".to_owned())
+        );
+        assert_eq!(
+            render("{{default missing.value \"80\"}}", &test_data()),
+            Ok("80".to_owned())
+        );
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_template_helpers_strings() {
+        assert_eq!(
+            render("{{upper name}} {{lower name}}", &test_data()),
+            Ok("NGINX nginx".to_owned())
+        );
+        assert_eq!(
+            render("{{join hosts \",\"}}", &test_data()),
+            Ok("a.example,b.example".to_owned())
+        );
+        assert_eq!(
+            render("{{quote text}} {{quote port}}", &test_data()),
+            Ok(r#""say \"hi\"" "8080""#.to_owned())
+        );
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_template_helpers_comparisons() {
+        assert_eq!(
+            render(
+                "{{#if (and (eq name \"Nginx\") (or (eq port \"80\") (eq port \"8080\")))}}yes{{else}}no{{/if}}",
+                &test_data()
+            ),
+            Ok("yes".to_owned())
+        );
+        assert_eq!(
+            render(
+                "{{#if (eq name \"Apache\")}}yes{{else}}no{{/if}}",
+                &test_data()
+            ),
+            Ok("no".to_owned())
+        );
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_template_helpers_arithmetic() {
+        assert_eq!(
+            render(
+                "{{add port 1}} {{sub port \"80\"}} {{mul env.TIMEOUT 1000}} {{div port 3}} {{mod port 3}}",
+                &test_data()
+            ),
+            Ok("8081 8000 10000 2693 1".to_owned())
+        );
+        assert_eq!(
+            render("{{mul ratio 2}} {{div 7 2.0}}", &test_data()),
+            Ok("3.0 3.5".to_owned())
+        );
+        assert_eq!(
+            render("{{add (mul port 2) 1}}", &test_data()),
+            Ok("16161".to_owned())
+        );
+    }
+
+    // [utest->swdd~config-renderer-provides-template-helpers~1]
+    #[test]
+    fn utest_template_helpers_arithmetic_fails() {
+        assert!(render("{{div port 0}}", &test_data())
+            .unwrap_err()
+            .contains("Helper 'div' failed"));
+        assert!(render("{{add name 1}}", &test_data())
+            .unwrap_err()
+            .contains("'Nginx' is not a number"));
+        assert!(render("{{add missing 1}}", &test_data()).is_err());
+    }
+}
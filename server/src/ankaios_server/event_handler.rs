@@ -0,0 +1,432 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::{State, WorkloadInstanceName, WorkloadState, WorkloadStatesMap};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+const FIELD_SEPARATOR: char = '.';
+const DESIRED_STATE_WORKLOADS_PATH: &str = "desiredState.workloads";
+const DESIRED_STATE_CONFIGS_PATH: &str = "desiredState.configs";
+const AGENTS_PATH: &str = "agents";
+const WORKLOAD_STATES_PATH: &str = "workloadStates";
+
+pub fn agent_path(agent_name: &str) -> String {
+    format!("{AGENTS_PATH}{FIELD_SEPARATOR}{agent_name}")
+}
+
+fn workload_state_path(instance_name: &WorkloadInstanceName) -> String {
+    [
+        WORKLOAD_STATES_PATH,
+        instance_name.agent_name(),
+        instance_name.workload_name(),
+        instance_name.id(),
+    ]
+    .join(&FIELD_SEPARATOR.to_string())
+}
+
+// [impl->swdd~server-detects-altered-fields~1]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AlteredFields {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl AlteredFields {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+
+    pub fn extend(&mut self, other: AlteredFields) {
+        self.added.extend(other.added);
+        self.updated.extend(other.updated);
+        self.removed.extend(other.removed);
+    }
+
+    fn compare_maps<K: Eq + Hash + Ord, V: PartialEq>(
+        &mut self,
+        old: &HashMap<K, V>,
+        new: &HashMap<K, V>,
+        to_path: impl Fn(&K) -> String,
+    ) {
+        let mut new_keys: Vec<&K> = new.keys().collect();
+        new_keys.sort();
+        for key in new_keys {
+            match old.get(key) {
+                None => self.added.push(to_path(key)),
+                Some(old_value) if *old_value != new[key] => self.updated.push(to_path(key)),
+                Some(_) => {}
+            }
+        }
+
+        let mut removed_keys: Vec<&K> = old.keys().filter(|key| !new.contains_key(key)).collect();
+        removed_keys.sort();
+        self.removed.extend(removed_keys.into_iter().map(to_path));
+    }
+
+    // Keeps only the altered fields matching the field mask. A field matches if it is inside
+    // a field of the mask. If a field of the mask is inside an altered field, the field of the
+    // mask is reported instead, as only this part of the altered field is of interest.
+    fn filter_by_field_mask(&self, field_mask: &[String]) -> AlteredFields {
+        let filter = |fields: &Vec<String>| -> Vec<String> {
+            if field_mask.is_empty() {
+                return fields.clone();
+            }
+            let mut result: Vec<String> = Vec::new();
+            for field in fields {
+                for mask_entry in field_mask {
+                    let matching_field = if is_same_or_inside(field, mask_entry) {
+                        field
+                    } else if is_same_or_inside(mask_entry, field) {
+                        mask_entry
+                    } else {
+                        continue;
+                    };
+                    if !result.contains(matching_field) {
+                        result.push(matching_field.clone());
+                    }
+                }
+            }
+            result
+        };
+
+        AlteredFields {
+            added: filter(&self.added),
+            updated: filter(&self.updated),
+            removed: filter(&self.removed),
+        }
+    }
+}
+
+fn is_same_or_inside(field: &str, parent: &str) -> bool {
+    parent.is_empty()
+        || field == parent
+        || field
+            .strip_prefix(parent)
+            .is_some_and(|rest| rest.starts_with(FIELD_SEPARATOR))
+}
+
+// [impl->swdd~server-detects-altered-fields~1]
+#[derive(Debug, Clone)]
+pub struct StateSnapshot {
+    pub desired_state: State,
+    pub workload_states: WorkloadStatesMap,
+}
+
+impl StateSnapshot {
+    pub fn altered_fields(
+        &self,
+        desired_state: &State,
+        workload_states: &WorkloadStatesMap,
+    ) -> AlteredFields {
+        let mut altered_fields = AlteredFields::default();
+        altered_fields.compare_maps(
+            &self.desired_state.workloads,
+            &desired_state.workloads,
+            |name| format!("{DESIRED_STATE_WORKLOADS_PATH}{FIELD_SEPARATOR}{name}"),
+        );
+        altered_fields.compare_maps(
+            &self.desired_state.configs,
+            &desired_state.configs,
+            |name| format!("{DESIRED_STATE_CONFIGS_PATH}{FIELD_SEPARATOR}{name}"),
+        );
+        altered_fields.compare_maps(
+            &to_workload_state_map(&self.workload_states),
+            &to_workload_state_map(workload_states),
+            |path| path.clone(),
+        );
+        altered_fields
+    }
+}
+
+fn to_workload_state_map(
+    workload_states: &WorkloadStatesMap,
+) -> HashMap<String, common::objects::ExecutionState> {
+    Vec::<WorkloadState>::from(workload_states.clone())
+        .into_iter()
+        .map(|state| {
+            (
+                workload_state_path(&state.instance_name),
+                state.execution_state,
+            )
+        })
+        .collect()
+}
+
+// [impl->swdd~server-manages-event-subscriptions~1]
+#[derive(Debug, Default)]
+pub struct EventHandler {
+    subscriptions: HashMap<String, Vec<String>>,
+}
+
+impl EventHandler {
+    pub fn add_subscription(&mut self, request_id: String, field_mask: Vec<String>) {
+        log::debug!(
+            "Adding event subscription '{}' with field mask '{:?}'",
+            request_id,
+            field_mask
+        );
+        self.subscriptions.insert(request_id, field_mask);
+    }
+
+    pub fn remove_subscription(&mut self, request_id: &str) -> bool {
+        self.subscriptions.remove(request_id).is_some()
+    }
+
+    // [impl->swdd~server-removes-subscriptions-of-gone-requesters~1]
+    pub fn remove_subscriptions_of_requester(&mut self, requester_prefix: &str) {
+        let requester_prefix = format!("{requester_prefix}@");
+        self.subscriptions.retain(|request_id, _| {
+            let keep = !request_id.starts_with(&requester_prefix);
+            if !keep {
+                log::debug!("Removing event subscription '{}'", request_id);
+            }
+            keep
+        });
+    }
+
+    pub fn has_subscribers(&self) -> bool {
+        !self.subscriptions.is_empty()
+    }
+
+    // [impl->swdd~server-filters-events-by-field-mask~1]
+    pub fn matching_subscriptions(
+        &self,
+        altered_fields: &AlteredFields,
+    ) -> Vec<(String, AlteredFields)> {
+        let mut request_ids: Vec<&String> = self.subscriptions.keys().collect();
+        request_ids.sort();
+        request_ids
+            .into_iter()
+            .filter_map(|request_id| {
+                let filtered = altered_fields.filter_by_field_mask(&self.subscriptions[request_id]);
+                (!filtered.is_empty()).then(|| (request_id.clone(), filtered))
+            })
+            .collect()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{agent_path, AlteredFields, EventHandler, StateSnapshot};
+    use common::objects::{
+        generate_test_workload_spec_with_param, ExecutionState, State, WorkloadInstanceName,
+        WorkloadState, WorkloadStatesMap,
+    };
+
+    const REQUEST_ID_A: &str = "agent_A@workload_1@1";
+    const REQUEST_ID_B: &str = "cli-conn-1@2";
+
+    fn altered_fields(added: &[&str], updated: &[&str], removed: &[&str]) -> AlteredFields {
+        let to_vec = |fields: &[&str]| fields.iter().map(|x| x.to_string()).collect();
+        AlteredFields {
+            added: to_vec(added),
+            updated: to_vec(updated),
+            removed: to_vec(removed),
+        }
+    }
+
+    // [utest->swdd~server-manages-event-subscriptions~1]
+    // [utest->swdd~server-filters-events-by-field-mask~1]
+    #[test]
+    fn utest_empty_field_mask_matches_all_altered_fields() {
+        let mut event_handler = EventHandler::default();
+        event_handler.add_subscription(REQUEST_ID_A.to_string(), vec![]);
+
+        let fields = altered_fields(&["agents.agent_A"], &["desiredState.workloads.w1"], &[]);
+
+        assert_eq!(
+            event_handler.matching_subscriptions(&fields),
+            vec![(REQUEST_ID_A.to_string(), fields)]
+        );
+    }
+
+    // [utest->swdd~server-filters-events-by-field-mask~1]
+    #[test]
+    fn utest_field_mask_filters_altered_fields_on_segment_boundaries() {
+        let mut event_handler = EventHandler::default();
+        event_handler.add_subscription(
+            REQUEST_ID_A.to_string(),
+            vec!["workloadStates.agent_A".to_string()],
+        );
+        event_handler.add_subscription(
+            REQUEST_ID_B.to_string(),
+            vec!["desiredState.workloads.w1.agent".to_string()],
+        );
+
+        let fields = altered_fields(
+            &["workloadStates.agent_A.w1.id1"],
+            &[
+                "desiredState.workloads.w1",
+                "workloadStates.agent_AB.w2.id2",
+            ],
+            &[],
+        );
+
+        assert_eq!(
+            event_handler.matching_subscriptions(&fields),
+            vec![
+                (
+                    REQUEST_ID_A.to_string(),
+                    altered_fields(&["workloadStates.agent_A.w1.id1"], &[], &[])
+                ),
+                (
+                    REQUEST_ID_B.to_string(),
+                    altered_fields(&[], &["desiredState.workloads.w1.agent"], &[])
+                ),
+            ]
+        );
+    }
+
+    // [utest->swdd~server-filters-events-by-field-mask~1]
+    #[test]
+    fn utest_no_matching_subscriptions_for_unrelated_fields() {
+        let mut event_handler = EventHandler::default();
+        event_handler.add_subscription(REQUEST_ID_A.to_string(), vec!["agents".to_string()]);
+
+        let fields = altered_fields(&[], &[], &["desiredState.configs.c1"]);
+
+        assert!(event_handler.matching_subscriptions(&fields).is_empty());
+    }
+
+    // [utest->swdd~server-manages-event-subscriptions~1]
+    #[test]
+    fn utest_remove_subscription() {
+        let mut event_handler = EventHandler::default();
+        event_handler.add_subscription(REQUEST_ID_A.to_string(), vec![]);
+
+        assert!(event_handler.has_subscribers());
+        assert!(!event_handler.remove_subscription(REQUEST_ID_B));
+        assert!(event_handler.remove_subscription(REQUEST_ID_A));
+        assert!(!event_handler.has_subscribers());
+    }
+
+    // [utest->swdd~server-removes-subscriptions-of-gone-requesters~1]
+    #[test]
+    fn utest_remove_subscriptions_of_requester() {
+        let mut event_handler = EventHandler::default();
+        event_handler.add_subscription(REQUEST_ID_A.to_string(), vec![]);
+        event_handler.add_subscription("agent_AB@workload_1@1".to_string(), vec![]);
+        event_handler.add_subscription(REQUEST_ID_B.to_string(), vec![]);
+
+        event_handler.remove_subscriptions_of_requester("agent_A");
+
+        let remaining_request_ids: Vec<String> = event_handler
+            .matching_subscriptions(&altered_fields(&["agents"], &[], &[]))
+            .into_iter()
+            .map(|(request_id, _)| request_id)
+            .collect();
+        assert_eq!(
+            remaining_request_ids,
+            vec![
+                "agent_AB@workload_1@1".to_string(),
+                REQUEST_ID_B.to_string()
+            ]
+        );
+
+        event_handler.remove_subscriptions_of_requester("agent_AB@workload_1");
+        event_handler.remove_subscriptions_of_requester("cli-conn-1");
+
+        assert!(!event_handler.has_subscribers());
+    }
+
+    // [utest->swdd~server-detects-altered-fields~1]
+    #[test]
+    fn utest_snapshot_detects_altered_desired_state_fields() {
+        let workload_1 =
+            generate_test_workload_spec_with_param("agent_A".into(), "w1".into(), "r".into());
+        let workload_2 =
+            generate_test_workload_spec_with_param("agent_A".into(), "w2".into(), "r".into());
+        let mut old_state = State::default();
+        old_state
+            .workloads
+            .insert("w1".into(), workload_1.clone().into());
+        old_state.workloads.insert("w2".into(), workload_2.into());
+
+        let mut new_state = old_state.clone();
+        new_state.workloads.remove("w2");
+        let mut updated_workload_1: common::objects::StoredWorkloadSpec = workload_1.into();
+        updated_workload_1.runtime_config = "changed".into();
+        new_state.workloads.insert("w1".into(), updated_workload_1);
+        new_state.configs.insert(
+            "c1".into(),
+            common::objects::ConfigItem::String("value".into()),
+        );
+
+        let snapshot = StateSnapshot {
+            desired_state: old_state,
+            workload_states: WorkloadStatesMap::default(),
+        };
+
+        assert_eq!(
+            snapshot.altered_fields(&new_state, &WorkloadStatesMap::default()),
+            altered_fields(
+                &["desiredState.configs.c1"],
+                &["desiredState.workloads.w1"],
+                &["desiredState.workloads.w2"]
+            )
+        );
+    }
+
+    // [utest->swdd~server-detects-altered-fields~1]
+    #[test]
+    fn utest_snapshot_detects_altered_workload_states() {
+        let state = |workload_name: &str, execution_state: ExecutionState| WorkloadState {
+            instance_name: WorkloadInstanceName::new("agent_A", workload_name, "id"),
+            execution_state,
+        };
+
+        let mut old_workload_states = WorkloadStatesMap::default();
+        old_workload_states.process_new_states(vec![
+            state("w1", ExecutionState::initial()),
+            state("w2", ExecutionState::running()),
+            state("w3", ExecutionState::running()),
+        ]);
+
+        let mut new_workload_states = old_workload_states.clone();
+        new_workload_states.process_new_states(vec![
+            state("w1", ExecutionState::running()),
+            state("w2", ExecutionState::removed()),
+            state("w4", ExecutionState::initial()),
+        ]);
+
+        let snapshot = StateSnapshot {
+            desired_state: State::default(),
+            workload_states: old_workload_states,
+        };
+
+        assert_eq!(
+            snapshot.altered_fields(&State::default(), &new_workload_states),
+            altered_fields(
+                &["workloadStates.agent_A.w4.id"],
+                &["workloadStates.agent_A.w1.id"],
+                &["workloadStates.agent_A.w2.id"]
+            )
+        );
+    }
+
+    #[test]
+    fn utest_agent_path() {
+        assert_eq!(agent_path("agent_A"), "agents.agent_A");
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::objects::WorkloadInstanceName;
+use std::collections::HashMap;
+
+// A log campaign is a running logs request. It is identified by the id of the logs request,
+// which is prefixed with the name of the requester, and is served by the agent of the workload.
+// [impl->swdd~server-stores-log-campaigns~1]
+#[derive(Default)]
+pub struct LogCampaignStore {
+    campaigns: HashMap<String, WorkloadInstanceName>,
+}
+
+impl LogCampaignStore {
+    pub fn add_campaign(&mut self, request_id: String, instance_name: WorkloadInstanceName) {
+        self.campaigns.insert(request_id, instance_name);
+    }
+
+    // Returns the name of the agent serving the campaign.
+    pub fn get_agent_of_campaign(&self, request_id: &str) -> Option<&str> {
+        self.campaigns
+            .get(request_id)
+            .map(|instance_name| instance_name.agent_name())
+    }
+
+    pub fn remove_campaign(&mut self, request_id: &str) -> Option<WorkloadInstanceName> {
+        self.campaigns.remove(request_id)
+    }
+
+    // Removes the campaigns requested by the requester, e.g. a CLI connection or a workload
+    // identified by 'agent@workload', and returns their request ids and agent names.
+    pub fn remove_campaigns_of_requester(&mut self, requester: &str) -> Vec<(String, String)> {
+        let prefix = format!("{requester}@");
+        self.remove_campaigns_where(|request_id, _| request_id.starts_with(&prefix))
+            .into_iter()
+            .map(|(request_id, instance_name)| (request_id, instance_name.agent_name().to_owned()))
+            .collect()
+    }
+
+    // Removes the campaigns served by the agent and returns their request ids and workloads.
+    pub fn remove_campaigns_of_agent(
+        &mut self,
+        agent_name: &str,
+    ) -> Vec<(String, WorkloadInstanceName)> {
+        self.remove_campaigns_where(|_, instance_name| instance_name.agent_name() == agent_name)
+    }
+
+    fn remove_campaigns_where(
+        &mut self,
+        predicate: impl Fn(&str, &WorkloadInstanceName) -> bool,
+    ) -> Vec<(String, WorkloadInstanceName)> {
+        let mut request_ids: Vec<String> = self
+            .campaigns
+            .iter()
+            .filter(|(request_id, instance_name)| predicate(request_id, instance_name))
+            .map(|(request_id, _)| request_id.clone())
+            .collect();
+        request_ids.sort();
+
+        request_ids
+            .into_iter()
+            .filter_map(|request_id| {
+                self.campaigns
+                    .remove(&request_id)
+                    .map(|instance_name| (request_id, instance_name))
+            })
+            .collect()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::LogCampaignStore;
+    use common::objects::WorkloadInstanceName;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const CLI_REQUEST_ID: &str = "cli-conn-1@request_1";
+    const WORKLOAD_REQUEST_ID: &str = "agent_B@workload_2@request_2";
+
+    fn instance_name(agent_name: &str, workload_name: &str) -> WorkloadInstanceName {
+        WorkloadInstanceName::builder()
+            .agent_name(agent_name)
+            .workload_name(workload_name)
+            .build()
+    }
+
+    fn generate_test_store() -> LogCampaignStore {
+        let mut store = LogCampaignStore::default();
+        store.add_campaign(CLI_REQUEST_ID.into(), instance_name(AGENT_A, "workload_1"));
+        store.add_campaign(
+            WORKLOAD_REQUEST_ID.into(),
+            instance_name(AGENT_A, "workload_1"),
+        );
+        store
+    }
+
+    // [utest->swdd~server-stores-log-campaigns~1]
+    #[test]
+    fn utest_add_and_remove_campaign() {
+        let mut store = generate_test_store();
+
+        assert_eq!(store.get_agent_of_campaign(CLI_REQUEST_ID), Some(AGENT_A));
+        assert_eq!(
+            store.remove_campaign(CLI_REQUEST_ID),
+            Some(instance_name(AGENT_A, "workload_1"))
+        );
+        assert!(store.get_agent_of_campaign(CLI_REQUEST_ID).is_none());
+        assert_eq!(store.remove_campaign(CLI_REQUEST_ID), None);
+        assert_eq!(
+            store.get_agent_of_campaign(WORKLOAD_REQUEST_ID),
+            Some(AGENT_A)
+        );
+    }
+
+    // [utest->swdd~server-stores-log-campaigns~1]
+    #[test]
+    fn utest_remove_campaigns_of_requester() {
+        let mut store = generate_test_store();
+
+        assert_eq!(
+            store.remove_campaigns_of_requester("agent_B@workload_2"),
+            vec![(WORKLOAD_REQUEST_ID.to_string(), AGENT_A.to_string())]
+        );
+        assert!(store.remove_campaigns_of_requester("agent_B").is_empty());
+        assert_eq!(store.get_agent_of_campaign(CLI_REQUEST_ID), Some(AGENT_A));
+    }
+
+    // [utest->swdd~server-stores-log-campaigns~1]
+    #[test]
+    fn utest_remove_campaigns_of_agent() {
+        let mut store = generate_test_store();
+
+        assert!(store.remove_campaigns_of_agent(AGENT_B).is_empty());
+        assert_eq!(
+            store.remove_campaigns_of_agent(AGENT_A),
+            vec![
+                (
+                    WORKLOAD_REQUEST_ID.to_string(),
+                    instance_name(AGENT_A, "workload_1")
+                ),
+                (
+                    CLI_REQUEST_ID.to_string(),
+                    instance_name(AGENT_A, "workload_1")
+                ),
+            ]
+        );
+        assert!(store.get_agent_of_campaign(CLI_REQUEST_ID).is_none());
+        assert!(store.get_agent_of_campaign(WORKLOAD_REQUEST_ID).is_none());
+    }
+}
@@ -0,0 +1,312 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use api::ank_base;
+use common::objects::State;
+use std::collections::VecDeque;
+
+pub const DEFAULT_REVISION_HISTORY_SIZE: usize = 20;
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revision {
+    pub revision: u64,
+    pub timestamp: String,
+    pub requester: String,
+    pub update_mask: Vec<String>,
+    pub desired_state: State,
+}
+
+impl From<&Revision> for ank_base::Revision {
+    fn from(item: &Revision) -> Self {
+        ank_base::Revision {
+            revision: item.revision,
+            timestamp: item.timestamp.clone(),
+            requester: item.requester.clone(),
+            update_mask: item.update_mask.clone(),
+        }
+    }
+}
+
+// [impl->swdd~server-keeps-bounded-revision-history~1]
+#[derive(Debug)]
+pub struct RevisionHistory {
+    revisions: VecDeque<Revision>,
+    max_revisions: usize,
+    last_revision: u64,
+}
+
+impl Default for RevisionHistory {
+    fn default() -> Self {
+        RevisionHistory::new(DEFAULT_REVISION_HISTORY_SIZE)
+    }
+}
+
+impl RevisionHistory {
+    pub fn new(max_revisions: usize) -> Self {
+        RevisionHistory {
+            revisions: VecDeque::with_capacity(max_revisions),
+            max_revisions,
+            last_revision: 0,
+        }
+    }
+
+    // The restored desired state is the first revision of the history, so that restoring an
+    // unchanged desired state at startup does not create a new revision.
+    // [impl->swdd~server-continues-persisted-revision~2]
+    pub fn restore(&mut self, desired_state: &State, revision: u64, requester: String) {
+        self.revisions.clear();
+        self.last_revision = revision;
+        if revision == 0 {
+            return;
+        }
+        self.revisions.push_back(Revision {
+            revision,
+            timestamp: chrono::Utc::now().format(TIMESTAMP_FORMAT).to_string(),
+            requester,
+            update_mask: vec![],
+            desired_state: desired_state.clone(),
+        });
+    }
+
+    // [impl->swdd~server-creates-revision-on-accepted-update~1]
+    pub fn revision_of(&self, desired_state: &State) -> u64 {
+        if self.is_latest(desired_state) {
+            self.last_revision
+        } else {
+            self.last_revision + 1
+        }
+    }
+
+    // [impl->swdd~server-creates-revision-on-accepted-update~1]
+    pub fn add(
+        &mut self,
+        desired_state: &State,
+        requester: String,
+        update_mask: Vec<String>,
+    ) -> Option<u64> {
+        if self.is_latest(desired_state) {
+            log::debug!("The desired state did not change, no new revision is created.");
+            return None;
+        }
+
+        self.last_revision += 1;
+        self.revisions.push_back(Revision {
+            revision: self.last_revision,
+            timestamp: chrono::Utc::now().format(TIMESTAMP_FORMAT).to_string(),
+            requester,
+            update_mask,
+            desired_state: desired_state.clone(),
+        });
+
+        // [impl->swdd~server-keeps-bounded-revision-history~1]
+        while self.revisions.len() > self.max_revisions {
+            if let Some(dropped) = self.revisions.pop_front() {
+                log::debug!(
+                    "Dropping revision '{}' from the revision history.",
+                    dropped.revision
+                );
+            }
+        }
+
+        Some(self.last_revision)
+    }
+
+    fn is_latest(&self, desired_state: &State) -> bool {
+        self.revisions
+            .back()
+            .is_some_and(|latest| latest.desired_state == *desired_state)
+    }
+
+    pub fn current_revision(&self) -> u64 {
+        self.last_revision
+    }
+
+    pub fn get(&self, revision: u64) -> Option<&Revision> {
+        self.revisions
+            .iter()
+            .find(|stored_revision| stored_revision.revision == revision)
+    }
+}
+
+impl From<&RevisionHistory> for ank_base::Revisions {
+    fn from(item: &RevisionHistory) -> Self {
+        ank_base::Revisions {
+            revisions: item.revisions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::ank_base;
+    use common::objects::{generate_test_stored_workload_spec, State};
+
+    use super::RevisionHistory;
+
+    const AGENT_A: &str = "agent_A";
+    const RUNTIME: &str = "runtime";
+    const REQUESTER: &str = "cli-conn-1";
+    const UPDATE_MASK: &str = "desiredState.workloads";
+
+    fn generate_test_state(workload_names: &[&str]) -> State {
+        State {
+            workloads: workload_names
+                .iter()
+                .map(|name| {
+                    (
+                        name.to_string(),
+                        generate_test_stored_workload_spec(AGENT_A, RUNTIME),
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    // [utest->swdd~server-creates-revision-on-accepted-update~1]
+    #[test]
+    fn utest_revision_history_numbers_new_revisions() {
+        let mut revision_history = RevisionHistory::new(5);
+
+        assert_eq!(
+            revision_history.add(
+                &generate_test_state(&["workload_1"]),
+                REQUESTER.to_string(),
+                vec![UPDATE_MASK.to_string()]
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            revision_history.add(
+                &generate_test_state(&["workload_1", "workload_2"]),
+                REQUESTER.to_string(),
+                vec![]
+            ),
+            Some(2)
+        );
+
+        let revision = revision_history.get(1).unwrap();
+        assert_eq!(revision.requester, REQUESTER);
+        assert_eq!(revision.update_mask, vec![UPDATE_MASK.to_string()]);
+        assert_eq!(revision.desired_state, generate_test_state(&["workload_1"]));
+        assert!(!revision.timestamp.is_empty());
+    }
+
+    // [utest->swdd~server-creates-revision-on-accepted-update~1]
+    #[test]
+    fn utest_revision_history_skips_unchanged_desired_state() {
+        let mut revision_history = RevisionHistory::new(5);
+        let state = generate_test_state(&["workload_1"]);
+
+        assert_eq!(
+            revision_history.add(&state, REQUESTER.to_string(), vec![]),
+            Some(1)
+        );
+        assert_eq!(
+            revision_history.add(&state, REQUESTER.to_string(), vec![]),
+            None
+        );
+        assert!(revision_history.get(2).is_none());
+    }
+
+    // [utest->swdd~server-continues-persisted-revision~2]
+    #[test]
+    fn utest_revision_history_continues_after_restored_revision() {
+        let mut revision_history = RevisionHistory::new(5);
+        let restored_state = generate_test_state(&["workload_1"]);
+        revision_history.restore(&restored_state, 7, REQUESTER.to_string());
+
+        assert_eq!(revision_history.current_revision(), 7);
+        assert_eq!(
+            revision_history.get(7).unwrap().desired_state,
+            restored_state
+        );
+        assert_eq!(
+            revision_history.add(&restored_state, REQUESTER.to_string(), vec![]),
+            None
+        );
+        assert_eq!(
+            revision_history.add(
+                &generate_test_state(&["workload_1", "workload_2"]),
+                REQUESTER.to_string(),
+                vec![]
+            ),
+            Some(8)
+        );
+    }
+
+    // [utest->swdd~server-continues-persisted-revision~2]
+    #[test]
+    fn utest_revision_history_restores_state_without_revision() {
+        let mut revision_history = RevisionHistory::new(5);
+        let restored_state = generate_test_state(&["workload_1"]);
+        revision_history.restore(&restored_state, 0, REQUESTER.to_string());
+
+        assert_eq!(revision_history.current_revision(), 0);
+        assert_eq!(
+            revision_history.add(&restored_state, REQUESTER.to_string(), vec![]),
+            Some(1)
+        );
+    }
+
+    // [utest->swdd~server-creates-revision-on-accepted-update~1]
+    #[test]
+    fn utest_revision_history_provides_revision_of_desired_state() {
+        let mut revision_history = RevisionHistory::new(5);
+        let state = generate_test_state(&["workload_1"]);
+
+        assert_eq!(revision_history.revision_of(&state), 1);
+        revision_history.add(&state, REQUESTER.to_string(), vec![]);
+        assert_eq!(revision_history.revision_of(&state), 1);
+        assert_eq!(
+            revision_history.revision_of(&generate_test_state(&["workload_2"])),
+            2
+        );
+    }
+
+    // [utest->swdd~server-keeps-bounded-revision-history~1]
+    #[test]
+    fn utest_revision_history_drops_oldest_revisions() {
+        let mut revision_history = RevisionHistory::new(2);
+
+        revision_history.add(&generate_test_state(&["a"]), REQUESTER.to_string(), vec![]);
+        revision_history.add(&generate_test_state(&["b"]), REQUESTER.to_string(), vec![]);
+        revision_history.add(&generate_test_state(&["c"]), REQUESTER.to_string(), vec![]);
+
+        assert!(revision_history.get(1).is_none());
+        assert!(revision_history.get(2).is_some());
+        assert!(revision_history.get(3).is_some());
+
+        let revisions = ank_base::Revisions::from(&revision_history);
+        assert_eq!(
+            revisions
+                .revisions
+                .iter()
+                .map(|revision| revision.revision)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+}
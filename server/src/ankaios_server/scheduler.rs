@@ -0,0 +1,603 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use common::objects::{
+    AgentAttributes, AgentMap, SchedulingConstraints, StoredWorkloadSpec, WorkloadInstanceName,
+    WorkloadSpec,
+};
+
+use super::config_renderer::RenderedWorkloads;
+
+/// The agents the workloads without an agent in the desired state are scheduled to
+pub type ScheduledAgents = HashMap<String, String>;
+
+/// Assigns an agent to all workloads without an agent
+///
+/// A workload without an agent keeps the agent it was scheduled to before as long as
+/// it is otherwise unchanged and the agent still has the required labels (or is not connected
+/// and not lost). A workload without a previous assignment keeps the agent restored from the
+/// persisted state under the same condition. All other workloads without an agent are placed on the connected agent fulfilling the
+/// scheduling constraints of the workload with the fewest workloads assigned, then the
+/// most free memory, then the lowest name. Workloads without a suitable agent are left
+/// without an agent and are reported as not scheduled.
+///
+/// # Arguments
+///
+/// * `workloads` - The rendered workloads to schedule
+/// * `current_workloads` - The rendered workloads as currently sent to the agents
+/// * `stored_workloads` - The workloads of the desired state containing the scheduling constraints
+/// * `restored_agents` - The agents the workloads were scheduled to before a restart of the server
+/// * `lost_agents` - The agents which are gone longer than the agent heartbeat timeout
+/// * `agents` - The currently connected agents with their attributes
+///
+pub fn schedule(
+    workloads: &mut RenderedWorkloads,
+    current_workloads: &RenderedWorkloads,
+    stored_workloads: &HashMap<String, StoredWorkloadSpec>,
+    restored_agents: &ScheduledAgents,
+    lost_agents: &HashSet<String>,
+    agents: &AgentMap,
+) {
+    let default_constraints = SchedulingConstraints::default();
+    let constraints_of = |workload_name: &str| {
+        stored_workloads
+            .get(workload_name)
+            .and_then(|stored_workload| stored_workload.scheduling_constraints.as_ref())
+            .unwrap_or(&default_constraints)
+    };
+
+    // sort the names to have a deterministic placement
+    let mut pending_workloads: Vec<String> = workloads
+        .iter()
+        .filter(|(workload_name, workload)| {
+            let agent_name = workload.instance_name.agent_name();
+            agent_name.is_empty()
+                // [impl->swdd~server-scheduler-moves-workloads-of-lost-agents~1]
+                || (lost_agents.contains(agent_name)
+                    && stored_workloads
+                        .get(workload_name.as_str())
+                        .is_some_and(|stored_workload| stored_workload.agent.is_empty()))
+        })
+        .map(|(workload_name, _)| workload_name.to_owned())
+        .collect();
+    pending_workloads.sort();
+
+    // [impl->swdd~server-scheduler-keeps-assigned-agent~2]
+    // [impl->swdd~server-scheduler-keeps-restored-agent~2]
+    pending_workloads.retain(|workload_name| {
+        let workload = workloads.get_mut(workload_name).unwrap();
+        let current_workload = current_workloads
+            .get(workload_name)
+            .filter(|current_workload| !current_workload.instance_name.agent_name().is_empty());
+        let kept_workload = match current_workload {
+            Some(current_workload) => {
+                let kept_workload =
+                    with_agent(workload, current_workload.instance_name.agent_name());
+                if kept_workload != *current_workload {
+                    return true;
+                }
+                kept_workload
+            }
+            None => match restored_agents.get(workload_name) {
+                Some(restored_agent) => with_agent(workload, restored_agent),
+                None => return true,
+            },
+        };
+
+        let previous_agent = kept_workload.instance_name.agent_name();
+        // [impl->swdd~server-scheduler-moves-workloads-of-lost-agents~1]
+        if lost_agents.contains(previous_agent) {
+            log::info!(
+                "Agent '{}' of workload '{}' is lost, scheduling the workload again",
+                previous_agent,
+                workload_name
+            );
+            return true;
+        }
+
+        let labels_fulfilled = agents.get(previous_agent).is_none_or(|agent_attributes| {
+            constraints_of(workload_name).are_labels_fulfilled_by(agent_attributes)
+        });
+        if labels_fulfilled {
+            *workload = kept_workload;
+        }
+        !labels_fulfilled
+    });
+
+    let mut assigned_workloads: HashMap<String, usize> = HashMap::new();
+    for workload in workloads.values() {
+        *assigned_workloads
+            .entry(workload.instance_name.agent_name().to_owned())
+            .or_default() += 1;
+    }
+
+    for workload_name in pending_workloads {
+        let constraints = constraints_of(&workload_name);
+
+        // [impl->swdd~server-scheduler-selects-agent-fulfilling-constraints~1]
+        // [impl->swdd~server-scheduler-spreads-workloads~1]
+        let selected_agent = agents
+            .iter()
+            .filter(|(_, agent_attributes)| constraints.are_fulfilled_by(agent_attributes))
+            .min_by_key(|(agent_name, agent_attributes)| {
+                (
+                    assigned_workloads
+                        .get(agent_name.as_str())
+                        .copied()
+                        .unwrap_or_default(),
+                    Reverse(free_memory(agent_attributes)),
+                    agent_name.as_str(),
+                )
+            })
+            .map(|(agent_name, _)| agent_name.to_owned());
+
+        let Some(selected_agent) = selected_agent else {
+            // [impl->swdd~server-scheduler-leaves-unschedulable-workloads-pending~1]
+            log::info!(
+                "No connected agent fulfills the scheduling constraints of workload '{}'",
+                workload_name
+            );
+            continue;
+        };
+
+        log::info!(
+            "Scheduling workload '{}' to agent '{}'",
+            workload_name,
+            selected_agent
+        );
+        let workload = workloads.get_mut(&workload_name).unwrap();
+        *workload = with_agent(workload, &selected_agent);
+        *assigned_workloads.entry(selected_agent).or_default() += 1;
+    }
+}
+
+/// Returns the agents the workloads without an agent in the desired state are scheduled to
+///
+/// # Arguments
+///
+/// * `workloads` - The rendered workloads containing the assigned agents
+/// * `stored_workloads` - The workloads of the desired state
+///
+pub fn scheduled_agents(
+    workloads: &RenderedWorkloads,
+    stored_workloads: &HashMap<String, StoredWorkloadSpec>,
+) -> ScheduledAgents {
+    workloads
+        .iter()
+        .filter(|(workload_name, workload)| {
+            !workload.instance_name.agent_name().is_empty()
+                && stored_workloads
+                    .get(workload_name.as_str())
+                    .is_some_and(|stored_workload| stored_workload.agent.is_empty())
+        })
+        .map(|(workload_name, workload)| {
+            (
+                workload_name.to_owned(),
+                workload.instance_name.agent_name().to_owned(),
+            )
+        })
+        .collect()
+}
+
+fn with_agent(workload: &WorkloadSpec, agent_name: &str) -> WorkloadSpec {
+    WorkloadSpec {
+        instance_name: WorkloadInstanceName::new(
+            agent_name,
+            workload.instance_name.workload_name(),
+            workload.instance_name.id(),
+        ),
+        ..workload.clone()
+    }
+}
+
+fn free_memory(agent_attributes: &AgentAttributes) -> u64 {
+    agent_attributes
+        .free_memory
+        .as_ref()
+        .map(|free_memory| free_memory.free_memory)
+        .unwrap_or_default()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use common::objects::{
+        generate_test_stored_workload_spec, generate_test_workload_spec_with_param,
+        AgentAttributes, AgentMap, CpuUsage, FreeMemory, SchedulingConstraints, StoredWorkloadSpec,
+    };
+
+    use super::{schedule, scheduled_agents, ScheduledAgents};
+    use crate::ankaios_server::config_renderer::RenderedWorkloads;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const WORKLOAD_NAME_3: &str = "workload_3";
+    const RUNTIME: &str = "runtime";
+
+    fn agent(
+        agent_map: &mut AgentMap,
+        agent_name: &str,
+        free_memory: u64,
+        cpu_usage: u32,
+        labels: &[(&str, &str)],
+    ) {
+        agent_map
+            .entry(agent_name.to_owned())
+            .or_insert(AgentAttributes {
+                cpu_usage: Some(CpuUsage { cpu_usage }),
+                free_memory: Some(FreeMemory { free_memory }),
+                labels: labels
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+                last_seen: None,
+            });
+    }
+
+    fn rendered_workloads(workloads: &[(&str, &str)]) -> RenderedWorkloads {
+        workloads
+            .iter()
+            .map(|(workload_name, agent_name)| {
+                (
+                    workload_name.to_string(),
+                    generate_test_workload_spec_with_param(
+                        agent_name.to_string(),
+                        workload_name.to_string(),
+                        RUNTIME.to_string(),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn stored_workloads(
+        workloads: &[(&str, SchedulingConstraints)],
+    ) -> HashMap<String, StoredWorkloadSpec> {
+        workloads
+            .iter()
+            .map(|(workload_name, constraints)| {
+                let mut stored_workload = generate_test_stored_workload_spec("", RUNTIME);
+                stored_workload.scheduling_constraints = Some(constraints.clone());
+                (workload_name.to_string(), stored_workload)
+            })
+            .collect()
+    }
+
+    fn agent_of<'a>(workloads: &'a RenderedWorkloads, workload_name: &str) -> &'a str {
+        workloads[workload_name].instance_name.agent_name()
+    }
+
+    // [utest->swdd~server-scheduler-selects-agent-fulfilling-constraints~1]
+    #[test]
+    fn utest_schedule_selects_agent_fulfilling_constraints() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_A, 4096, 10, &[("zone", "rear")]);
+        agent(&mut agents, AGENT_B, 1024, 90, &[("zone", "front")]);
+        agent(&mut agents, "agent_C", 8192, 95, &[("zone", "front")]);
+
+        let mut workloads = rendered_workloads(&[(WORKLOAD_NAME_1, "")]);
+        let stored_workloads = stored_workloads(&[(
+            WORKLOAD_NAME_1,
+            SchedulingConstraints {
+                required_agent_labels: HashMap::from([("zone".into(), "front".into())]),
+                min_free_memory: Some(512),
+                max_cpu_usage: Some(90),
+            },
+        )]);
+
+        schedule(
+            &mut workloads,
+            &RenderedWorkloads::new(),
+            &stored_workloads,
+            &ScheduledAgents::new(),
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), AGENT_B);
+    }
+
+    // [utest->swdd~server-scheduler-selects-agent-fulfilling-constraints~1]
+    #[test]
+    fn utest_schedule_keeps_id_and_ignores_workloads_with_agent() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_B, 1024, 10, &[]);
+
+        let mut workloads =
+            rendered_workloads(&[(WORKLOAD_NAME_1, ""), (WORKLOAD_NAME_2, AGENT_A)]);
+        let expected_id = workloads[WORKLOAD_NAME_1].instance_name.id().to_owned();
+
+        schedule(
+            &mut workloads,
+            &RenderedWorkloads::new(),
+            &HashMap::new(),
+            &ScheduledAgents::new(),
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), AGENT_B);
+        assert_eq!(workloads[WORKLOAD_NAME_1].instance_name.id(), expected_id);
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_2), AGENT_A);
+    }
+
+    // [utest->swdd~server-scheduler-leaves-unschedulable-workloads-pending~1]
+    #[test]
+    fn utest_schedule_leaves_workload_without_suitable_agent_pending() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_A, 256, 10, &[("gpu", "true")]);
+
+        let mut workloads = rendered_workloads(&[(WORKLOAD_NAME_1, "")]);
+        let stored_workloads = stored_workloads(&[(
+            WORKLOAD_NAME_1,
+            SchedulingConstraints {
+                required_agent_labels: HashMap::from([("gpu".into(), "true".into())]),
+                min_free_memory: Some(512),
+                max_cpu_usage: None,
+            },
+        )]);
+
+        schedule(
+            &mut workloads,
+            &RenderedWorkloads::new(),
+            &stored_workloads,
+            &ScheduledAgents::new(),
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), "");
+    }
+
+    // [utest->swdd~server-scheduler-spreads-workloads~1]
+    #[test]
+    fn utest_schedule_spreads_workloads_over_agents() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_A, 1024, 10, &[]);
+        agent(&mut agents, AGENT_B, 2048, 10, &[]);
+
+        let mut workloads = rendered_workloads(&[
+            (WORKLOAD_NAME_1, ""),
+            (WORKLOAD_NAME_2, ""),
+            (WORKLOAD_NAME_3, ""),
+        ]);
+
+        schedule(
+            &mut workloads,
+            &RenderedWorkloads::new(),
+            &HashMap::new(),
+            &ScheduledAgents::new(),
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), AGENT_B);
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_2), AGENT_A);
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_3), AGENT_B);
+    }
+
+    // [utest->swdd~server-scheduler-keeps-assigned-agent~2]
+    #[test]
+    fn utest_schedule_keeps_previously_assigned_agent() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_A, 1024, 99, &[("zone", "front")]);
+        agent(&mut agents, AGENT_B, 8192, 0, &[("zone", "front")]);
+
+        let current_workloads = rendered_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]);
+        let mut workloads = rendered_workloads(&[(WORKLOAD_NAME_1, "")]);
+        let stored_workloads = stored_workloads(&[(
+            WORKLOAD_NAME_1,
+            SchedulingConstraints {
+                required_agent_labels: HashMap::from([("zone".into(), "front".into())]),
+                max_cpu_usage: Some(50),
+                ..Default::default()
+            },
+        )]);
+
+        schedule(
+            &mut workloads,
+            &current_workloads,
+            &stored_workloads,
+            &ScheduledAgents::new(),
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(workloads, current_workloads);
+    }
+
+    // [utest->swdd~server-scheduler-keeps-assigned-agent~2]
+    #[test]
+    fn utest_schedule_keeps_previously_assigned_disconnected_agent() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_B, 8192, 0, &[]);
+
+        let current_workloads = rendered_workloads(&[(WORKLOAD_NAME_1, AGENT_A)]);
+        let mut workloads = rendered_workloads(&[(WORKLOAD_NAME_1, "")]);
+
+        schedule(
+            &mut workloads,
+            &current_workloads,
+            &HashMap::new(),
+            &ScheduledAgents::new(),
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), AGENT_A);
+    }
+
+    // [utest->swdd~server-scheduler-moves-workloads-of-lost-agents~1]
+    #[test]
+    fn utest_schedule_moves_workloads_of_lost_agents() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_B, 8192, 0, &[]);
+
+        let current_workloads =
+            rendered_workloads(&[(WORKLOAD_NAME_1, AGENT_A), ("workload_4", AGENT_A)]);
+        let mut workloads = rendered_workloads(&[
+            (WORKLOAD_NAME_1, AGENT_A),
+            (WORKLOAD_NAME_2, ""),
+            (WORKLOAD_NAME_3, ""),
+            ("workload_4", AGENT_A),
+        ]);
+        let stored_workloads = stored_workloads(&[
+            (WORKLOAD_NAME_1, SchedulingConstraints::default()),
+            (WORKLOAD_NAME_2, SchedulingConstraints::default()),
+            (WORKLOAD_NAME_3, SchedulingConstraints::default()),
+        ]);
+        let restored_agents = ScheduledAgents::from([
+            (WORKLOAD_NAME_2.to_owned(), AGENT_A.to_owned()),
+            (WORKLOAD_NAME_3.to_owned(), "agent_C".to_owned()),
+        ]);
+
+        schedule(
+            &mut workloads,
+            &current_workloads,
+            &stored_workloads,
+            &restored_agents,
+            &HashSet::from([AGENT_A.to_owned()]),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), AGENT_B);
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_2), AGENT_B);
+        // the restored agent is not connected yet, but not lost
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_3), "agent_C");
+        // the agent of the workload is set in the desired state
+        assert_eq!(agent_of(&workloads, "workload_4"), AGENT_A);
+    }
+
+    // [utest->swdd~server-scheduler-keeps-assigned-agent~2]
+    #[test]
+    fn utest_schedule_reschedules_on_changed_labels_or_workload() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_A, 1024, 0, &[("zone", "rear")]);
+        agent(&mut agents, AGENT_B, 1024, 0, &[("zone", "front")]);
+
+        let mut current_workloads =
+            rendered_workloads(&[(WORKLOAD_NAME_1, AGENT_A), (WORKLOAD_NAME_2, AGENT_A)]);
+        current_workloads
+            .get_mut(WORKLOAD_NAME_2)
+            .unwrap()
+            .runtime_config = "changed".into();
+        let mut workloads = rendered_workloads(&[(WORKLOAD_NAME_1, ""), (WORKLOAD_NAME_2, "")]);
+        let stored_workloads = stored_workloads(&[
+            (
+                WORKLOAD_NAME_1,
+                SchedulingConstraints {
+                    required_agent_labels: HashMap::from([("zone".into(), "front".into())]),
+                    ..Default::default()
+                },
+            ),
+            (WORKLOAD_NAME_2, SchedulingConstraints::default()),
+        ]);
+
+        schedule(
+            &mut workloads,
+            &current_workloads,
+            &stored_workloads,
+            &ScheduledAgents::new(),
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), AGENT_B);
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_2), AGENT_A);
+    }
+
+    // [utest->swdd~server-scheduler-keeps-restored-agent~2]
+    #[test]
+    fn utest_schedule_keeps_restored_agent() {
+        let mut agents = AgentMap::new();
+        agent(&mut agents, AGENT_A, 1024, 0, &[("zone", "rear")]);
+        agent(&mut agents, AGENT_B, 8192, 0, &[("zone", "front")]);
+
+        let mut workloads = rendered_workloads(&[
+            (WORKLOAD_NAME_1, ""),
+            (WORKLOAD_NAME_2, ""),
+            (WORKLOAD_NAME_3, ""),
+        ]);
+        let stored_workloads = stored_workloads(&[
+            (WORKLOAD_NAME_1, SchedulingConstraints::default()),
+            (
+                WORKLOAD_NAME_2,
+                SchedulingConstraints {
+                    required_agent_labels: HashMap::from([("zone".into(), "front".into())]),
+                    ..Default::default()
+                },
+            ),
+            (WORKLOAD_NAME_3, SchedulingConstraints::default()),
+        ]);
+        let restored_agents = ScheduledAgents::from([
+            (WORKLOAD_NAME_1.to_owned(), AGENT_A.to_owned()),
+            (WORKLOAD_NAME_2.to_owned(), AGENT_A.to_owned()),
+            (WORKLOAD_NAME_3.to_owned(), "agent_C".to_owned()),
+        ]);
+
+        schedule(
+            &mut workloads,
+            &RenderedWorkloads::new(),
+            &stored_workloads,
+            &restored_agents,
+            &HashSet::new(),
+            &agents,
+        );
+
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_1), AGENT_A);
+        // the restored agent does not have the required labels anymore
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_2), AGENT_B);
+        // the restored agent is not connected yet
+        assert_eq!(agent_of(&workloads, WORKLOAD_NAME_3), "agent_C");
+    }
+
+    // [utest->swdd~server-scheduler-keeps-restored-agent~2]
+    #[test]
+    fn utest_scheduled_agents_contains_only_workloads_without_agent() {
+        let workloads = rendered_workloads(&[
+            (WORKLOAD_NAME_1, AGENT_A),
+            (WORKLOAD_NAME_2, AGENT_B),
+            (WORKLOAD_NAME_3, ""),
+        ]);
+        let mut stored_workloads = stored_workloads(&[
+            (WORKLOAD_NAME_1, SchedulingConstraints::default()),
+            (WORKLOAD_NAME_3, SchedulingConstraints::default()),
+        ]);
+        stored_workloads.insert(
+            WORKLOAD_NAME_2.to_owned(),
+            generate_test_stored_workload_spec(AGENT_B, RUNTIME),
+        );
+
+        assert_eq!(
+            scheduled_agents(&workloads, &stored_workloads),
+            ScheduledAgents::from([(WORKLOAD_NAME_1.to_owned(), AGENT_A.to_owned())])
+        );
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use common::objects::{ConfigItem, State};
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    rand::{SecureRandom, SystemRandom},
+};
+use std::{
+    fmt, fs,
+    io::{ErrorKind, Write},
+    os::unix::fs::OpenOptionsExt,
+    path::Path,
+};
+
+const ENCRYPTED_VALUE_PREFIX: &str = "encrypted:";
+const KEY_LEN: usize = 32;
+const KEY_FILE_MODE: u32 = 0o600;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretCipherError {
+    KeyFile(String),
+    Encrypt(String),
+    Decrypt(String),
+}
+
+impl fmt::Display for SecretCipherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretCipherError::KeyFile(reason) => {
+                write!(f, "Could not use the secrets key file: '{}'", reason)
+            }
+            SecretCipherError::Encrypt(reason) => {
+                write!(f, "Could not encrypt the secrets: '{}'", reason)
+            }
+            SecretCipherError::Decrypt(reason) => {
+                write!(f, "Could not decrypt the secrets: '{}'", reason)
+            }
+        }
+    }
+}
+
+// [impl->swdd~file-state-store-encrypts-secrets~1]
+pub struct SecretCipher {
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl SecretCipher {
+    pub fn new(key: &[u8]) -> Result<Self, SecretCipherError> {
+        let key = UnboundKey::new(&AES_256_GCM, key).map_err(|_| {
+            SecretCipherError::KeyFile(format!("the key must have {} bytes", KEY_LEN))
+        })?;
+        Ok(Self {
+            key: LessSafeKey::new(key),
+            rng: SystemRandom::new(),
+        })
+    }
+
+    // [impl->swdd~server-loads-secrets-key~1]
+    pub fn from_key_file(path: &Path) -> Result<Self, SecretCipherError> {
+        let key_file_error = |err: &dyn fmt::Display| {
+            SecretCipherError::KeyFile(format!("'{}': {}", path.display(), err))
+        };
+
+        let encoded_key = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                log::info!("Generating a new secrets key file '{}'", path.display());
+                let mut key = [0u8; KEY_LEN];
+                SystemRandom::new()
+                    .fill(&mut key)
+                    .map_err(|_| key_file_error(&"could not generate a key"))?;
+                let encoded_key = STANDARD.encode(key);
+
+                // The key file is only readable by the owner, as the key decrypts all persisted secrets.
+                fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .mode(KEY_FILE_MODE)
+                    .open(path)
+                    .and_then(|mut file| {
+                        file.write_all(encoded_key.as_bytes())?;
+                        file.sync_all()
+                    })
+                    .map_err(|err| key_file_error(&err))?;
+                encoded_key
+            }
+            Err(err) => return Err(key_file_error(&err)),
+        };
+
+        let key = STANDARD
+            .decode(encoded_key.trim())
+            .map_err(|err| key_file_error(&err))?;
+        Self::new(&key).map_err(|err| key_file_error(&err))
+    }
+
+    pub fn encrypt_secrets(&self, state: &State) -> Result<State, SecretCipherError> {
+        let mut encrypted_state = state.clone();
+        encrypted_state
+            .secrets
+            .values_mut()
+            .try_for_each(|secret| map_values(secret, &|value| self.encrypt(value)))?;
+        Ok(encrypted_state)
+    }
+
+    pub fn decrypt_secrets(&self, mut state: State) -> Result<State, SecretCipherError> {
+        state
+            .secrets
+            .values_mut()
+            .try_for_each(|secret| map_values(secret, &|value| self.decrypt(value)))?;
+        Ok(state)
+    }
+
+    fn encrypt(&self, value: &str) -> Result<String, SecretCipherError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng
+            .fill(&mut nonce)
+            .map_err(|_| SecretCipherError::Encrypt("could not generate a nonce".into()))?;
+
+        let mut sealed_value = value.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut sealed_value,
+            )
+            .map_err(|_| SecretCipherError::Encrypt("could not seal a value".into()))?;
+
+        let mut encrypted_value = nonce.to_vec();
+        encrypted_value.extend(sealed_value);
+        Ok(format!(
+            "{}{}",
+            ENCRYPTED_VALUE_PREFIX,
+            STANDARD.encode(encrypted_value)
+        ))
+    }
+
+    fn decrypt(&self, value: &str) -> Result<String, SecretCipherError> {
+        let Some(encoded_value) = value.strip_prefix(ENCRYPTED_VALUE_PREFIX) else {
+            log::warn!("Found a secret value that is not encrypted in the persisted state.");
+            return Ok(value.to_owned());
+        };
+
+        let mut encrypted_value = STANDARD
+            .decode(encoded_value)
+            .map_err(|err| SecretCipherError::Decrypt(err.to_string()))?;
+        if encrypted_value.len() < NONCE_LEN {
+            return Err(SecretCipherError::Decrypt("value is too short".into()));
+        }
+        let mut sealed_value = encrypted_value.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&encrypted_value)
+            .map_err(|_| SecretCipherError::Decrypt("invalid nonce".into()))?;
+
+        let value = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut sealed_value)
+            .map_err(|_| {
+                SecretCipherError::Decrypt("the value does not match the secrets key".into())
+            })?;
+        String::from_utf8(value.to_vec()).map_err(|err| SecretCipherError::Decrypt(err.to_string()))
+    }
+}
+
+fn map_values(
+    item: &mut ConfigItem,
+    map_value: &dyn Fn(&str) -> Result<String, SecretCipherError>,
+) -> Result<(), SecretCipherError> {
+    match item {
+        ConfigItem::String(value) => {
+            *value = map_value(value)?;
+            Ok(())
+        }
+        ConfigItem::ConfigArray(array) => array
+            .iter_mut()
+            .try_for_each(|item| map_values(item, map_value)),
+        ConfigItem::ConfigObject(object) => object
+            .values_mut()
+            .try_for_each(|item| map_values(item, map_value)),
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+pub fn generate_test_secret_cipher() -> SecretCipher {
+    SecretCipher::new(&[7u8; KEY_LEN]).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, os::unix::fs::PermissionsExt};
+
+    use common::objects::{ConfigItem, State};
+
+    use super::{
+        generate_test_secret_cipher, SecretCipher, SecretCipherError, ENCRYPTED_VALUE_PREFIX,
+        KEY_LEN,
+    };
+
+    fn generate_test_state_with_secrets() -> State {
+        State {
+            secrets: HashMap::from([(
+                "db_credentials".to_owned(),
+                ConfigItem::ConfigObject(HashMap::from([
+                    ("user".to_owned(), ConfigItem::String("admin".to_owned())),
+                    (
+                        "passwords".to_owned(),
+                        ConfigItem::ConfigArray(vec![ConfigItem::String("s3cr3t".to_owned())]),
+                    ),
+                ])),
+            )]),
+            ..Default::default()
+        }
+    }
+
+    // [utest->swdd~file-state-store-encrypts-secrets~1]
+    #[test]
+    fn utest_secret_cipher_encrypts_and_decrypts_all_secret_values() {
+        let cipher = generate_test_secret_cipher();
+        let state = generate_test_state_with_secrets();
+
+        let encrypted_state = cipher.encrypt_secrets(&state).unwrap();
+
+        let ConfigItem::ConfigObject(encrypted_secret) = &encrypted_state.secrets["db_credentials"]
+        else {
+            panic!("Expected the secret to stay an object");
+        };
+        let ConfigItem::String(encrypted_user) = &encrypted_secret["user"] else {
+            panic!("Expected the user to stay a string");
+        };
+        assert!(encrypted_user.starts_with(ENCRYPTED_VALUE_PREFIX));
+        assert!(!format!("{:?}", encrypted_state).contains("s3cr3t"));
+
+        assert_eq!(cipher.decrypt_secrets(encrypted_state), Ok(state));
+    }
+
+    // [utest->swdd~file-state-store-encrypts-secrets~1]
+    #[test]
+    fn utest_secret_cipher_uses_a_new_nonce_for_each_value() {
+        let cipher = generate_test_secret_cipher();
+
+        assert_ne!(
+            cipher.encrypt("value").unwrap(),
+            cipher.encrypt("value").unwrap()
+        );
+    }
+
+    // [utest->swdd~file-state-store-encrypts-secrets~1]
+    #[test]
+    fn utest_secret_cipher_decrypt_fails_with_other_key() {
+        let encrypted_state = generate_test_secret_cipher()
+            .encrypt_secrets(&generate_test_state_with_secrets())
+            .unwrap();
+
+        let other_cipher = SecretCipher::new(&[8u8; KEY_LEN]).unwrap();
+
+        assert!(matches!(
+            other_cipher.decrypt_secrets(encrypted_state),
+            Err(SecretCipherError::Decrypt(_))
+        ));
+    }
+
+    #[test]
+    fn utest_secret_cipher_new_fails_on_wrong_key_length() {
+        assert!(matches!(
+            SecretCipher::new(&[7u8; 16]),
+            Err(SecretCipherError::KeyFile(_))
+        ));
+    }
+
+    // [utest->swdd~server-loads-secrets-key~1]
+    #[test]
+    fn utest_secret_cipher_from_key_file_generates_and_reuses_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_file = dir.path().join("state.yaml.key");
+
+        let cipher = SecretCipher::from_key_file(&key_file).unwrap();
+        let encrypted_state = cipher
+            .encrypt_secrets(&generate_test_state_with_secrets())
+            .unwrap();
+
+        let permissions = std::fs::metadata(&key_file).unwrap().permissions();
+        assert_eq!(permissions.mode() & 0o777, 0o600);
+
+        let reloaded_cipher = SecretCipher::from_key_file(&key_file).unwrap();
+        assert_eq!(
+            reloaded_cipher.decrypt_secrets(encrypted_state),
+            Ok(generate_test_state_with_secrets())
+        );
+    }
+
+    // [utest->swdd~server-loads-secrets-key~1]
+    #[test]
+    fn utest_secret_cipher_from_key_file_fails_on_invalid_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_file = dir.path().join("state.yaml.key");
+        std::fs::write(&key_file, "not a key").unwrap();
+
+        assert!(matches!(
+            SecretCipher::from_key_file(&key_file),
+            Err(SecretCipherError::KeyFile(_))
+        ));
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use common::commands::RequestContent;
+use common::objects::{AgentMap, ExecutionStateEnum, WorkloadStatesMap};
+use common::std_extensions::IllegalStateResult;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// Upper bounds of the request duration histogram buckets in seconds.
+const REQUEST_DURATION_BUCKETS: [f64; 8] = [0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0];
+
+pub const REJECTED_INVALID_FORMAT: &str = "invalid_format";
+pub const REJECTED_STALE_REVISION: &str = "stale_revision";
+pub const REJECTED_INVALID_STATE: &str = "invalid_state";
+pub const REJECTED_UNKNOWN_REVISION: &str = "unknown_revision";
+
+pub fn request_type(request_content: &RequestContent) -> &'static str {
+    match request_content {
+        RequestContent::CompleteStateRequest(_) => "CompleteStateRequest",
+        RequestContent::UpdateStateRequest(_) => "UpdateStateRequest",
+        RequestContent::RevisionsRequest(_) => "RevisionsRequest",
+        RequestContent::RollbackRequest(_) => "RollbackRequest",
+        RequestContent::EventsRequest(_) => "EventsRequest",
+        RequestContent::EventsCancelRequest(_) => "EventsCancelRequest",
+        RequestContent::LogsRequest(_) => "LogsRequest",
+        RequestContent::LogsCancelRequest(_) => "LogsCancelRequest",
+        RequestContent::AuditLogRequest(_) => "AuditLogRequest",
+        RequestContent::WorkloadDescriptionRequest(_) => "WorkloadDescriptionRequest",
+    }
+}
+
+fn execution_state_name(state: &ExecutionStateEnum) -> &'static str {
+    match state {
+        ExecutionStateEnum::AgentDisconnected => "AgentDisconnected",
+        ExecutionStateEnum::Pending(_) => "Pending",
+        ExecutionStateEnum::Running(_) => "Running",
+        ExecutionStateEnum::Stopping(_) => "Stopping",
+        ExecutionStateEnum::Succeeded(_) => "Succeeded",
+        ExecutionStateEnum::Failed(_) => "Failed",
+        ExecutionStateEnum::NotScheduled => "NotScheduled",
+        ExecutionStateEnum::Removed => "Removed",
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[derive(Default)]
+struct RequestStatistics {
+    bucket_counts: [u64; REQUEST_DURATION_BUCKETS.len()],
+    count: u64,
+    duration_sum: f64,
+}
+
+#[derive(Default)]
+struct MetricValues {
+    ready: bool,
+    connected_agents: usize,
+    agent_cpu_usage: BTreeMap<String, u32>,
+    agent_free_memory: BTreeMap<String, u64>,
+    workloads: BTreeMap<(String, &'static str), u64>,
+    requests: BTreeMap<&'static str, RequestStatistics>,
+    rejected_updates: BTreeMap<&'static str, u64>,
+}
+
+// The metrics are written by the AnkaiosServer and read by the monitoring endpoint,
+// which runs in another task. Both only hold the lock while copying plain values.
+// [impl->swdd~server-collects-metrics~1]
+#[derive(Clone, Default)]
+pub struct ServerMetrics {
+    values: Arc<Mutex<MetricValues>>,
+}
+
+impl ServerMetrics {
+    pub fn set_ready(&self, ready: bool) {
+        self.values.lock().unwrap_or_illegal_state().ready = ready;
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.values.lock().unwrap_or_illegal_state().ready
+    }
+
+    pub fn update_state(&self, agent_map: &AgentMap, workload_states_map: &WorkloadStatesMap) {
+        let mut connected_agents = 0;
+        let mut agent_cpu_usage = BTreeMap::new();
+        let mut agent_free_memory = BTreeMap::new();
+        for (agent_name, agent_attributes) in agent_map.iter() {
+            connected_agents += 1;
+            if let Some(cpu_usage) = &agent_attributes.cpu_usage {
+                agent_cpu_usage.insert(agent_name.clone(), cpu_usage.cpu_usage);
+            }
+            if let Some(free_memory) = &agent_attributes.free_memory {
+                agent_free_memory.insert(agent_name.clone(), free_memory.free_memory);
+            }
+        }
+
+        let mut workloads = BTreeMap::new();
+        for (agent_name, execution_state) in workload_states_map.iter_execution_states() {
+            *workloads
+                .entry((
+                    agent_name.to_owned(),
+                    execution_state_name(&execution_state.state),
+                ))
+                .or_default() += 1;
+        }
+
+        let mut values = self.values.lock().unwrap_or_illegal_state();
+        values.connected_agents = connected_agents;
+        values.agent_cpu_usage = agent_cpu_usage;
+        values.agent_free_memory = agent_free_memory;
+        values.workloads = workloads;
+    }
+
+    pub fn record_request(&self, request_type: &'static str, duration: Duration) {
+        let duration = duration.as_secs_f64();
+        let mut values = self.values.lock().unwrap_or_illegal_state();
+        let statistics = values.requests.entry(request_type).or_default();
+        statistics.count += 1;
+        statistics.duration_sum += duration;
+        for (bucket_count, upper_bound) in statistics
+            .bucket_counts
+            .iter_mut()
+            .zip(REQUEST_DURATION_BUCKETS)
+        {
+            if duration <= upper_bound {
+                *bucket_count += 1;
+            }
+        }
+    }
+
+    pub fn record_rejected_update(&self, reason: &'static str) {
+        *self
+            .values
+            .lock()
+            .unwrap_or_illegal_state()
+            .rejected_updates
+            .entry(reason)
+            .or_default() += 1;
+    }
+
+    // Renders the metrics in the Prometheus text exposition format.
+    // Writing to a String cannot fail, so the results of writeln! are ignored.
+    // [impl->swdd~server-collects-metrics~1]
+    pub fn render(&self) -> String {
+        let values = self.values.lock().unwrap_or_illegal_state();
+        let mut output = String::new();
+
+        let _ = writeln!(
+            output,
+            "# HELP ankaios_connected_agents Number of agents connected to the server.\n\
+             # TYPE ankaios_connected_agents gauge\n\
+             ankaios_connected_agents {}",
+            values.connected_agents
+        );
+
+        let _ = writeln!(
+            output,
+            "# HELP ankaios_workloads Number of workloads per agent and execution state.\n\
+             # TYPE ankaios_workloads gauge"
+        );
+        for ((agent_name, execution_state), count) in &values.workloads {
+            let _ = writeln!(
+                output,
+                "ankaios_workloads{{agent=\"{}\",execution_state=\"{}\"}} {}",
+                escape_label_value(agent_name),
+                execution_state,
+                count
+            );
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP ankaios_agent_cpu_usage_percent CPU usage of the agent in percent.\n\
+             # TYPE ankaios_agent_cpu_usage_percent gauge"
+        );
+        for (agent_name, cpu_usage) in &values.agent_cpu_usage {
+            let _ = writeln!(
+                output,
+                "ankaios_agent_cpu_usage_percent{{agent=\"{}\"}} {}",
+                escape_label_value(agent_name),
+                cpu_usage
+            );
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP ankaios_agent_free_memory_bytes Free memory of the agent in bytes.\n\
+             # TYPE ankaios_agent_free_memory_bytes gauge"
+        );
+        for (agent_name, free_memory) in &values.agent_free_memory {
+            let _ = writeln!(
+                output,
+                "ankaios_agent_free_memory_bytes{{agent=\"{}\"}} {}",
+                escape_label_value(agent_name),
+                free_memory
+            );
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP ankaios_server_requests_total Number of handled requests per request type.\n\
+             # TYPE ankaios_server_requests_total counter"
+        );
+        for (request_type, statistics) in &values.requests {
+            let _ = writeln!(
+                output,
+                "ankaios_server_requests_total{{request_type=\"{}\"}} {}",
+                request_type, statistics.count
+            );
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP ankaios_server_request_duration_seconds Time the server needed to handle a request.\n\
+             # TYPE ankaios_server_request_duration_seconds histogram"
+        );
+        for (request_type, statistics) in &values.requests {
+            for (bucket_count, upper_bound) in statistics
+                .bucket_counts
+                .iter()
+                .zip(REQUEST_DURATION_BUCKETS)
+            {
+                let _ = writeln!(
+                    output,
+                    "ankaios_server_request_duration_seconds_bucket{{request_type=\"{}\",le=\"{}\"}} {}",
+                    request_type, upper_bound, bucket_count
+                );
+            }
+            let _ = writeln!(
+                output,
+                "ankaios_server_request_duration_seconds_bucket{{request_type=\"{}\",le=\"+Inf\"}} {}\n\
+                 ankaios_server_request_duration_seconds_sum{{request_type=\"{}\"}} {}\n\
+                 ankaios_server_request_duration_seconds_count{{request_type=\"{}\"}} {}",
+                request_type,
+                statistics.count,
+                request_type,
+                statistics.duration_sum,
+                request_type,
+                statistics.count
+            );
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP ankaios_server_rejected_updates_total Number of rejected updates of the desired state per reason.\n\
+             # TYPE ankaios_server_rejected_updates_total counter"
+        );
+        for (reason, count) in &values.rejected_updates {
+            let _ = writeln!(
+                output,
+                "ankaios_server_rejected_updates_total{{reason=\"{}\"}} {}",
+                reason, count
+            );
+        }
+
+        output
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ServerMetrics, REJECTED_STALE_REVISION};
+    use common::commands::AgentLoadStatus;
+    use common::objects::{
+        generate_test_workload_state_with_agent, AgentMap, CpuUsage, ExecutionState, FreeMemory,
+        WorkloadStatesMap,
+    };
+    use std::time::Duration;
+
+    const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
+
+    // [utest->swdd~server-collects-metrics~1]
+    #[test]
+    fn utest_server_metrics_render_state_gauges() {
+        let server_metrics = ServerMetrics::default();
+
+        let mut agent_map = AgentMap::new();
+        agent_map.entry(AGENT_A.to_owned()).or_default();
+        agent_map.entry(AGENT_B.to_owned()).or_default();
+        agent_map.update_resource_availability(AgentLoadStatus {
+            agent_name: AGENT_A.to_owned(),
+            cpu_usage: CpuUsage { cpu_usage: 42 },
+            free_memory: FreeMemory { free_memory: 1024 },
+        });
+
+        let mut workload_states_map = WorkloadStatesMap::new();
+        workload_states_map.process_new_states(vec![
+            generate_test_workload_state_with_agent(
+                "workload_1",
+                AGENT_A,
+                ExecutionState::running(),
+            ),
+            generate_test_workload_state_with_agent(
+                "workload_2",
+                AGENT_A,
+                ExecutionState::running(),
+            ),
+            generate_test_workload_state_with_agent(
+                "workload_3",
+                AGENT_B,
+                ExecutionState::starting("info"),
+            ),
+        ]);
+
+        server_metrics.update_state(&agent_map, &workload_states_map);
+        let metrics = server_metrics.render();
+
+        assert!(metrics.contains("ankaios_connected_agents 2\n"));
+        assert!(metrics
+            .contains("ankaios_workloads{agent=\"agent_A\",execution_state=\"Running\"} 2\n"));
+        assert!(metrics
+            .contains("ankaios_workloads{agent=\"agent_B\",execution_state=\"Pending\"} 1\n"));
+        assert!(metrics.contains("ankaios_agent_cpu_usage_percent{agent=\"agent_A\"} 42\n"));
+        assert!(metrics.contains("ankaios_agent_free_memory_bytes{agent=\"agent_A\"} 1024\n"));
+        assert!(!metrics.contains("ankaios_agent_cpu_usage_percent{agent=\"agent_B\"}"));
+    }
+
+    // [utest->swdd~server-collects-metrics~1]
+    #[test]
+    fn utest_server_metrics_render_requests_and_rejected_updates() {
+        let server_metrics = ServerMetrics::default();
+
+        server_metrics.record_request("UpdateStateRequest", Duration::from_millis(2));
+        server_metrics.record_request("UpdateStateRequest", Duration::from_secs(2));
+        server_metrics.record_rejected_update(REJECTED_STALE_REVISION);
+
+        let metrics = server_metrics.render();
+
+        assert!(metrics
+            .contains("ankaios_server_requests_total{request_type=\"UpdateStateRequest\"} 2\n"));
+        assert!(metrics.contains(
+            "ankaios_server_request_duration_seconds_bucket{request_type=\"UpdateStateRequest\",le=\"0.001\"} 0\n"
+        ));
+        assert!(metrics.contains(
+            "ankaios_server_request_duration_seconds_bucket{request_type=\"UpdateStateRequest\",le=\"0.005\"} 1\n"
+        ));
+        assert!(metrics.contains(
+            "ankaios_server_request_duration_seconds_bucket{request_type=\"UpdateStateRequest\",le=\"+Inf\"} 2\n"
+        ));
+        assert!(metrics.contains(
+            "ankaios_server_request_duration_seconds_count{request_type=\"UpdateStateRequest\"} 2\n"
+        ));
+        assert!(metrics
+            .contains("ankaios_server_rejected_updates_total{reason=\"stale_revision\"} 1\n"));
+    }
+
+    // [utest->swdd~server-collects-metrics~1]
+    #[test]
+    fn utest_server_metrics_escape_label_values() {
+        let server_metrics = ServerMetrics::default();
+        let mut workload_states_map = WorkloadStatesMap::new();
+        workload_states_map.process_new_states(vec![generate_test_workload_state_with_agent(
+            "workload_1",
+            "a\"b",
+            ExecutionState::running(),
+        )]);
+
+        server_metrics.update_state(&AgentMap::new(), &workload_states_map);
+
+        assert!(server_metrics
+            .render()
+            .contains("ankaios_workloads{agent=\"a\\\"b\",execution_state=\"Running\"} 1\n"));
+    }
+
+    #[test]
+    fn utest_server_metrics_ready_flag() {
+        let server_metrics = ServerMetrics::default();
+        assert!(!server_metrics.is_ready());
+
+        server_metrics.clone().set_ready(true);
+        assert!(server_metrics.is_ready());
+    }
+}
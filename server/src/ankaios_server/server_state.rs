@@ -22,8 +22,12 @@ use super::config_renderer::ConfigRenderer;
 use super::cycle_check;
 #[cfg_attr(test, mockall_double::double)]
 use super::delete_graph::DeleteGraph;
+use super::revision_history::{RevisionHistory, TIMESTAMP_FORMAT};
+use super::scheduler::{self, ScheduledAgents};
+use super::state_store::StateStore;
 use common::objects::{
-    AgentAttributes, CpuUsage, FreeMemory, State, WorkloadState, WorkloadStatesMap,
+    AgentAttributes, AgentMap, CpuUsage, FreeMemory, LabelSelector, State, WorkloadState,
+    WorkloadStatesMap,
 };
 use common::std_extensions::IllegalStateResult;
 use common::{
@@ -31,11 +35,16 @@ use common::{
     objects::{CompleteState, DeletedWorkload, WorkloadSpec},
     state_manipulation::{Object, Path},
 };
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 
 #[cfg(test)]
 use mockall::automock;
 
+fn current_timestamp() -> String {
+    chrono::Utc::now().format(TIMESTAMP_FORMAT).to_string()
+}
+
 fn extract_added_and_deleted_workloads(
     current_workloads: &RenderedWorkloads,
     new_workloads: &RenderedWorkloads,
@@ -84,6 +93,7 @@ pub enum UpdateStateError {
     FieldNotFound(String),
     ResultInvalid(String),
     CycleInDependencies(String),
+    StateNotPersisted(String),
 }
 
 impl Display for UpdateStateError {
@@ -102,6 +112,9 @@ impl Display for UpdateStateError {
                     workload_part_of_cycle
                 )
             }
+            UpdateStateError::StateNotPersisted(reason) => {
+                write!(f, "Could not persist the new state: '{}'", reason)
+            }
         }
     }
 }
@@ -112,6 +125,10 @@ pub struct ServerState {
     rendered_workloads: RenderedWorkloads,
     delete_graph: DeleteGraph,
     config_renderer: ConfigRenderer,
+    state_store: Option<Box<dyn StateStore>>,
+    scheduler_enabled: bool,
+    restored_scheduled_agents: ScheduledAgents,
+    lost_agents: HashSet<String>,
 }
 
 pub type AddedDeletedWorkloads = Option<(Vec<WorkloadSpec>, Vec<DeletedWorkload>)>;
@@ -128,10 +145,32 @@ impl ServerState {
         request_complete_state: CompleteStateRequest,
         workload_states_map: &WorkloadStatesMap,
     ) -> Result<ank_base::CompleteState, String> {
+        // [impl->swdd~server-state-redacts-secrets-in-complete-state~1]
+        let mut desired_state = self.state.desired_state.clone();
+        desired_state.redact_secrets();
+
+        let mut workload_states = workload_states_map.clone();
+
+        // [impl->swdd~server-filters-complete-state-by-label-selector~1]
+        if let Some(label_selector) = request_complete_state
+            .label_selector
+            .as_deref()
+            .map(str::parse::<LabelSelector>)
+            .transpose()?
+        {
+            desired_state
+                .workloads
+                .retain(|_, workload| label_selector.matches(&workload.tags));
+            workload_states.retain_workloads(|workload_name| {
+                desired_state.workloads.contains_key(workload_name)
+            });
+        }
+
         let current_complete_state: ank_base::CompleteState = CompleteState {
-            desired_state: self.state.desired_state.clone(),
-            workload_states: workload_states_map.clone(),
+            desired_state,
+            workload_states,
             agents: self.state.agents.clone(),
+            revision: None,
         }
         .into();
 
@@ -171,6 +210,14 @@ impl ServerState {
         }
     }
 
+    pub fn get_desired_state(&self) -> &State {
+        &self.state.desired_state
+    }
+
+    pub fn get_agent_map(&self) -> &AgentMap {
+        &self.state.agents
+    }
+
     // [impl->swdd~agent-from-agent-field~1]
     pub fn get_workloads_for_agent(&self, agent_name: &str) -> Vec<WorkloadSpec> {
         self.rendered_workloads
@@ -180,84 +227,207 @@ impl ServerState {
             .collect()
     }
 
+    // [impl->swdd~server-state-provides-rendered-workload-by-name~1]
+    pub fn get_workload_spec(&self, workload_name: &str) -> Option<WorkloadSpec> {
+        self.rendered_workloads.get(workload_name).cloned()
+    }
+
+    // The workload is only rendered if all configs it references are listed in the config names,
+    // as the requester is only authorized to read these configs.
+    // [impl->swdd~server-state-renders-workload-with-redacted-secrets~2]
+    pub fn get_redacted_workload_spec(
+        &self,
+        workload_name: &str,
+        config_names: &[String],
+    ) -> Result<WorkloadSpec, String> {
+        let Some(stored_workload) = self.state.desired_state.workloads.get(workload_name) else {
+            return Err(format!(
+                "Workload '{}' is not part of the desired state",
+                workload_name
+            ));
+        };
+
+        let mut unlisted_configs: Vec<&String> = stored_workload
+            .configs
+            .values()
+            .filter(|config_name| !config_names.contains(config_name))
+            .collect();
+        if !unlisted_configs.is_empty() {
+            unlisted_configs.sort();
+            return Err(format!(
+                "Workload '{}' references the configs {:?} which are not listed in the request",
+                workload_name, unlisted_configs
+            ));
+        }
+
+        let mut desired_state = self.state.desired_state.clone();
+        desired_state.redact_secrets();
+
+        let mut rendered_workloads = self
+            .config_renderer
+            .render_workloads(
+                &HashMap::from([(workload_name.to_owned(), stored_workload.clone())]),
+                &desired_state.configs,
+                &desired_state.secrets,
+            )
+            .map_err(|err| err.to_string())?;
+        let mut workload_spec = rendered_workloads
+            .remove(workload_name)
+            .ok_or_else(|| format!("Could not render workload '{}'", workload_name))?;
+
+        // the rendered workload keeps the instance name of the scheduled workload
+        if let Some(scheduled_workload) = self.rendered_workloads.get(workload_name) {
+            workload_spec.instance_name = scheduled_workload.instance_name.clone();
+        }
+        Ok(workload_spec)
+    }
+
     pub fn update(
         &mut self,
         new_state: CompleteState,
         update_mask: Vec<String>,
+        revision_history: &RevisionHistory,
     ) -> Result<AddedDeletedWorkloads, UpdateStateError> {
-        // [impl->swdd~update-desired-state-with-update-mask~1]
-        // [impl->swdd~update-desired-state-empty-update-mask~1]
-        match self.generate_new_state(new_state, update_mask) {
-            Ok(new_templated_state) => {
-                // [impl->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
-                let new_rendered_workloads = self
-                    .config_renderer
-                    .render_workloads(
-                        &new_templated_state.desired_state.workloads,
-                        &new_templated_state.desired_state.configs,
-                    )
-                    .map_err(|err| UpdateStateError::ResultInvalid(err.to_string()))?;
-
-                // [impl->swdd~server-state-triggers-validation-of-workload-fields~1]
-                self.verify_workload_fields_format(&new_rendered_workloads)?;
-
-                // [impl->swdd~server-state-compares-rendered-workloads~1]
-                let cmd = extract_added_and_deleted_workloads(
-                    &self.rendered_workloads,
-                    &new_rendered_workloads,
-                );
-
-                if let Some((added_workloads, mut deleted_workloads)) = cmd {
-                    let start_nodes: Vec<&str> = added_workloads
-                        .iter()
-                        .filter_map(|w| {
-                            if !w.dependencies.is_empty() {
-                                Some(w.instance_name.workload_name())
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-
-                    // [impl->swdd~server-state-rejects-state-with-cyclic-dependencies~1]
-                    if let Some(workload_part_of_cycle) =
-                        cycle_check::dfs(&new_templated_state.desired_state, Some(start_nodes))
-                    {
-                        return Err(UpdateStateError::CycleInDependencies(
-                            workload_part_of_cycle,
-                        ));
-                    }
+        let (new_templated_state, new_rendered_workloads, cmd) =
+            self.prepare_update(new_state, update_mask)?;
+        let revision = revision_history.revision_of(&new_templated_state.desired_state);
+
+        // [impl->swdd~server-state-persists-scheduled-agents~1]
+        let scheduled_agents = scheduler::scheduled_agents(
+            &new_rendered_workloads,
+            &new_templated_state.desired_state.workloads,
+        );
+
+        if let Some((added_workloads, mut deleted_workloads)) = cmd {
+            // [impl->swdd~server-state-persists-desired-state~1]
+            self.persist_desired_state(
+                &new_templated_state.desired_state,
+                revision,
+                &scheduled_agents,
+            )?;
+            // the restored agents are only needed until the rendered workloads contain them
+            // [impl->swdd~server-state-keeps-restored-scheduled-agents~1]
+            self.restored_scheduled_agents.clear();
+
+            // [impl->swdd~server-state-stores-delete-condition~1]
+            self.delete_graph.insert(&added_workloads);
+
+            // [impl->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
+            self.delete_graph
+                .apply_delete_conditions_to(&mut deleted_workloads);
+
+            self.set_desired_state(new_templated_state.desired_state);
+            self.rendered_workloads = new_rendered_workloads;
+            Ok(Some((added_workloads, deleted_workloads)))
+        } else {
+            // update state with changed fields not affecting workloads, e.g. config items
+            // [impl->swdd~server-state-updates-state-on-unmodified-workloads~1]
+            // [impl->swdd~server-state-persists-desired-state~1]
+            self.persist_desired_state(
+                &new_templated_state.desired_state,
+                revision,
+                &scheduled_agents,
+            )?;
+            // [impl->swdd~server-state-keeps-restored-scheduled-agents~1]
+            self.restored_scheduled_agents.clear();
+            self.set_desired_state(new_templated_state.desired_state);
+            Ok(None)
+        }
+    }
 
-                    // [impl->swdd~server-state-stores-delete-condition~1]
-                    self.delete_graph.insert(&added_workloads);
+    // [impl->swdd~server-state-dry-runs-update~1]
+    pub fn dry_run_update(
+        &mut self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<AddedDeletedWorkloads, UpdateStateError> {
+        let (_, _, cmd) = self.prepare_update(new_state, update_mask)?;
+        Ok(cmd)
+    }
 
-                    // [impl->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
-                    self.delete_graph
-                        .apply_delete_conditions_to(&mut deleted_workloads);
+    // [impl->swdd~server-state-schedules-workloads-without-agent~1]
+    pub fn enable_scheduler(&mut self) {
+        self.scheduler_enabled = true;
+    }
 
-                    self.set_desired_state(new_templated_state.desired_state);
-                    self.rendered_workloads = new_rendered_workloads;
-                    Ok(Some((added_workloads, deleted_workloads)))
-                } else {
-                    // update state with changed fields not affecting workloads, e.g. config items
-                    // [impl->swdd~server-state-updates-state-on-unmodified-workloads~1]
-                    self.set_desired_state(new_templated_state.desired_state);
-                    Ok(None)
-                }
-            }
-            Err(error) => Err(error),
+    // [impl->swdd~server-state-keeps-restored-scheduled-agents~1]
+    pub fn restore_scheduled_agents(&mut self, scheduled_agents: ScheduledAgents) {
+        self.restored_scheduled_agents = scheduled_agents;
+    }
+
+    // [impl->swdd~server-state-keeps-lost-agents~1]
+    pub fn agent_lost(&mut self, agent_name: String) {
+        self.lost_agents.insert(agent_name);
+    }
+
+    // [impl->swdd~server-state-schedules-pending-workloads~1]
+    pub fn schedule_pending_workloads(&mut self, revision: u64) -> AddedDeletedWorkloads {
+        if !self.scheduler_enabled {
+            return None;
+        }
+
+        let mut new_rendered_workloads = self.rendered_workloads.clone();
+        scheduler::schedule(
+            &mut new_rendered_workloads,
+            &self.rendered_workloads,
+            &self.state.desired_state.workloads,
+            &self.restored_scheduled_agents,
+            &self.lost_agents,
+            &self.state.agents,
+        );
+
+        let (added_workloads, mut deleted_workloads) =
+            extract_added_and_deleted_workloads(&self.rendered_workloads, &new_rendered_workloads)?;
+
+        // A failing store shall not prevent starting the scheduled workloads.
+        // [impl->swdd~server-state-persists-scheduled-agents~1]
+        let desired_state = self.state.desired_state.clone();
+        let scheduled_agents =
+            scheduler::scheduled_agents(&new_rendered_workloads, &desired_state.workloads);
+        if let Err(err) = self.persist_desired_state(&desired_state, revision, &scheduled_agents) {
+            log::error!("{}", err);
         }
+
+        // [impl->swdd~server-state-stores-delete-condition~1]
+        self.delete_graph.insert(&added_workloads);
+        self.delete_graph
+            .apply_delete_conditions_to(&mut deleted_workloads);
+
+        self.rendered_workloads = new_rendered_workloads;
+        Some((added_workloads, deleted_workloads))
+    }
+
+    // [impl->swdd~server-state-persists-desired-state~1]
+    pub fn set_state_store(&mut self, state_store: Box<dyn StateStore>) {
+        self.state_store = Some(state_store);
+    }
+
+    // [impl->swdd~server-state-stores-current-desired-state~1]
+    pub fn store_desired_state(&mut self, revision: u64) -> Result<(), UpdateStateError> {
+        let desired_state = self.state.desired_state.clone();
+        let scheduled_agents =
+            scheduler::scheduled_agents(&self.rendered_workloads, &desired_state.workloads);
+        self.persist_desired_state(&desired_state, revision, &scheduled_agents)
     }
 
     // [impl->swdd~server-state-stores-agent-in-complete-state~1]
-    pub fn add_agent(&mut self, agent_name: String) {
-        self.state
+    // [impl->swdd~server-state-stores-agent-labels~1]
+    pub fn add_agent(&mut self, agent_name: String, labels: HashMap<String, String>) {
+        // [impl->swdd~server-state-keeps-lost-agents~1]
+        self.lost_agents.remove(&agent_name);
+        let agent_attributes = self
+            .state
             .agents
             .entry(agent_name)
             .or_insert(AgentAttributes {
                 cpu_usage: Some(CpuUsage::default()),
                 free_memory: Some(FreeMemory::default()),
+                labels: HashMap::new(),
+                last_seen: None,
             });
+        agent_attributes.labels = labels;
+        // [impl->swdd~server-state-stores-agent-last-seen~1]
+        agent_attributes.last_seen = Some(current_timestamp());
     }
 
     // [impl->swdd~server-state-removes-agent-from-complete-state~1]
@@ -275,9 +445,14 @@ impl ServerState {
         &mut self,
         agent_load_status: commands::AgentLoadStatus,
     ) {
+        let agent_name = agent_load_status.agent_name.clone();
         self.state
             .agents
             .update_resource_availability(agent_load_status);
+        // [impl->swdd~server-state-stores-agent-last-seen~1]
+        self.state
+            .agents
+            .update_last_seen(&agent_name, current_timestamp());
     }
 
     // [impl->swdd~server-cleans-up-state~1]
@@ -287,6 +462,75 @@ impl ServerState {
             .remove_deleted_workloads_from_delete_graph(new_workload_states);
     }
 
+    fn prepare_update(
+        &mut self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<(CompleteState, RenderedWorkloads, AddedDeletedWorkloads), UpdateStateError> {
+        // [impl->swdd~update-desired-state-with-update-mask~1]
+        // [impl->swdd~update-desired-state-empty-update-mask~1]
+        let mut new_templated_state = self.generate_new_state(new_state, update_mask)?;
+
+        // [impl->swdd~server-state-restores-redacted-secrets~1]
+        new_templated_state
+            .desired_state
+            .restore_redacted_secrets(&self.state.desired_state)
+            .map_err(UpdateStateError::ResultInvalid)?;
+
+        // [impl->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+        let mut new_rendered_workloads = self
+            .config_renderer
+            .render_workloads(
+                &new_templated_state.desired_state.workloads,
+                &new_templated_state.desired_state.configs,
+                &new_templated_state.desired_state.secrets,
+            )
+            .map_err(|err| UpdateStateError::ResultInvalid(err.to_string()))?;
+
+        // [impl->swdd~server-state-triggers-validation-of-workload-fields~1]
+        self.verify_workload_fields_format(&new_rendered_workloads)?;
+
+        // [impl->swdd~server-state-schedules-workloads-without-agent~1]
+        if self.scheduler_enabled {
+            scheduler::schedule(
+                &mut new_rendered_workloads,
+                &self.rendered_workloads,
+                &new_templated_state.desired_state.workloads,
+                &self.restored_scheduled_agents,
+                &self.lost_agents,
+                &self.state.agents,
+            );
+        }
+
+        // [impl->swdd~server-state-compares-rendered-workloads~1]
+        let cmd =
+            extract_added_and_deleted_workloads(&self.rendered_workloads, &new_rendered_workloads);
+
+        if let Some((added_workloads, _)) = &cmd {
+            let start_nodes: Vec<&str> = added_workloads
+                .iter()
+                .filter_map(|w| {
+                    if !w.dependencies.is_empty() {
+                        Some(w.instance_name.workload_name())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            // [impl->swdd~server-state-rejects-state-with-cyclic-dependencies~1]
+            if let Some(workload_part_of_cycle) =
+                cycle_check::dfs(&new_templated_state.desired_state, Some(start_nodes))
+            {
+                return Err(UpdateStateError::CycleInDependencies(
+                    workload_part_of_cycle,
+                ));
+            }
+        }
+
+        Ok((new_templated_state, new_rendered_workloads, cmd))
+    }
+
     fn generate_new_state(
         &mut self,
         updated_state: CompleteState,
@@ -324,6 +568,21 @@ impl ServerState {
         })
     }
 
+    // [impl->swdd~server-state-persists-desired-state~1]
+    fn persist_desired_state(
+        &mut self,
+        new_desired_state: &State,
+        revision: u64,
+        scheduled_agents: &ScheduledAgents,
+    ) -> Result<(), UpdateStateError> {
+        if let Some(state_store) = self.state_store.as_mut() {
+            state_store
+                .store(new_desired_state, revision, scheduled_agents)
+                .map_err(|err| UpdateStateError::StateNotPersisted(err.to_string()))?;
+        }
+        Ok(())
+    }
+
     fn set_desired_state(&mut self, new_desired_state: State) {
         self.state.desired_state = new_desired_state;
     }
@@ -334,8 +593,13 @@ impl ServerState {
         workloads: &RenderedWorkloads,
     ) -> Result<(), UpdateStateError> {
         for workload_spec in workloads.values() {
-            WorkloadSpec::verify_fields_format(workload_spec)
-                .map_err(UpdateStateError::ResultInvalid)?;
+            // [impl->swdd~server-state-accepts-workloads-without-agent-for-scheduling~1]
+            if self.scheduler_enabled && workload_spec.instance_name.agent_name().is_empty() {
+                WorkloadSpec::verify_fields_format_without_agent(workload_spec)
+            } else {
+                WorkloadSpec::verify_fields_format(workload_spec)
+            }
+            .map_err(UpdateStateError::ResultInvalid)?;
         }
         Ok(())
     }
@@ -357,18 +621,27 @@ mod tests {
         commands::{AgentLoadStatus, CompleteStateRequest},
         objects::{
             generate_test_agent_map, generate_test_configs, generate_test_stored_workload_spec,
+            generate_test_stored_workload_spec_with_config,
             generate_test_workload_spec_with_control_interface_access,
             generate_test_workload_spec_with_param, AgentMap, CompleteState, ConfigItem, CpuUsage,
-            DeletedWorkload, FreeMemory, State, WorkloadSpec, WorkloadStatesMap,
+            DeletedWorkload, ExecutionState, FreeMemory, State, StoredWorkloadSpec, Tag,
+            WorkloadInstanceName, WorkloadSpec, WorkloadState, WorkloadStatesMap,
+            REDACTED_SECRET_VALUE,
         },
         test_utils::{self, generate_test_complete_state},
     };
     use mockall::predicate;
 
     use crate::ankaios_server::{
-        config_renderer::{ConfigRenderError, MockConfigRenderer, RenderedWorkloads},
+        config_renderer::{
+            ConfigRenderError, ConfigRenderer, MockConfigRenderer, RenderedWorkloads,
+        },
         delete_graph::MockDeleteGraph,
+        revision_history::RevisionHistory,
+        scheduler::ScheduledAgents,
+        secret_cipher::generate_test_secret_cipher,
         server_state::UpdateStateError,
+        state_store::{FileStateStore, MockStateStore, StateStore, StateStoreError},
     };
 
     use super::ServerState;
@@ -420,7 +693,10 @@ mod tests {
             ..Default::default()
         };
 
-        let request_complete_state = CompleteStateRequest { field_mask: vec![] };
+        let request_complete_state = CompleteStateRequest {
+            field_mask: vec![],
+            label_selector: None,
+        };
 
         let mut workload_state_db = WorkloadStatesMap::default();
         workload_state_db.process_new_states(server_state.state.workload_states.clone().into());
@@ -453,6 +729,7 @@ mod tests {
                 "workloads.invalidMask".to_string(), // invalid not existing workload
                 format!("desiredState.workloads.{}", WORKLOAD_NAME_1),
             ],
+            label_selector: None,
         };
 
         let mut workload_state_map = WorkloadStatesMap::default();
@@ -466,9 +743,11 @@ mod tests {
             desired_state: Some(server_state.state.desired_state.clone().into()),
             workload_states: None,
             agents: None,
+            revision: None,
         };
         if let Some(expected_desired_state) = &mut expected_complete_state.desired_state {
             expected_desired_state.configs = None;
+            expected_desired_state.secrets = None;
         }
 
         assert_eq!(received_complete_state, expected_complete_state);
@@ -506,6 +785,7 @@ mod tests {
                 format!("desiredState.workloads.{}", WORKLOAD_NAME_1),
                 format!("desiredState.workloads.{}.agent", WORKLOAD_NAME_3),
             ],
+            label_selector: None,
         };
 
         let mut workload_state_map = WorkloadStatesMap::default();
@@ -528,6 +808,10 @@ mod tests {
                     control_interface_access: None,
                     configs: None,
                     files: None,
+                    scheduling_constraints: None,
+                    secrets: None,
+                    suspended: None,
+                    restart_generation: None,
                 },
             ),
             (
@@ -552,6 +836,10 @@ mod tests {
                     files: Some(ank_base::Files {
                         files: w1.files.into_iter().map(Into::into).collect(),
                     }),
+                    scheduling_constraints: None,
+                    secrets: Some(Default::default()),
+                    suspended: None,
+                    restart_generation: None,
                 },
             ),
         ];
@@ -559,11 +847,32 @@ mod tests {
             test_utils::generate_test_proto_complete_state(&expected_workloads);
         if let Some(expected_desired_state) = &mut expected_complete_state.desired_state {
             expected_desired_state.configs = None;
+            expected_desired_state.secrets = None;
         }
 
         assert_eq!(expected_complete_state, complete_state);
     }
 
+    #[test]
+    fn utest_server_state_get_desired_state() {
+        let w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        let complete_state = generate_test_complete_state(vec![w1]);
+
+        let server_state = ServerState {
+            state: complete_state.clone(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            server_state.get_desired_state(),
+            &complete_state.desired_state
+        );
+    }
+
     // [utest->swdd~agent-from-agent-field~1]
     #[test]
     fn utest_server_state_get_workloads_per_agent() {
@@ -656,7 +965,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| {
+            .returning(move |_, _, _| {
                 Ok(generate_rendered_workloads_from_state(
                     &cloned_rejected_state,
                 ))
@@ -667,9 +976,10 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
-        let result = server_state.update(rejected_new_state, vec![]);
+        let result = server_state.update(rejected_new_state, vec![], &RevisionHistory::default());
         assert_eq!(
             result,
             Err(UpdateStateError::CycleInDependencies(
@@ -704,7 +1014,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| {
+            .returning(move |_, _, _| {
                 Ok(generate_rendered_workloads_from_state(&clone_updated_state))
             });
 
@@ -713,10 +1023,15 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         server_state
-            .update(update_state.clone(), update_mask)
+            .update(
+                update_state.clone(),
+                update_mask,
+                &RevisionHistory::default(),
+            )
             .unwrap();
 
         assert_eq!(update_state.desired_state, server_state.state.desired_state);
@@ -757,7 +1072,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| {
+            .returning(move |_, _, _| {
                 Ok(generate_rendered_workloads_from_state(
                     &cloned_expected_state,
                 ))
@@ -768,175 +1083,761 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
-        server_state.update(update_state, update_mask).unwrap();
+        server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap();
 
         assert_eq!(expected, server_state.state);
     }
 
-    // [utest->swdd~update-desired-state-with-update-mask~1]
-    // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
-    // [utest->swdd~server-state-triggers-validation-of-workload-fields~1]
+    // [utest->swdd~server-state-persists-desired-state~1]
     #[test]
-    fn utest_server_state_update_state_add_workload() {
+    fn utest_server_state_update_state_persists_new_desired_state() {
         let old_state = generate_test_old_state();
         let update_state = generate_test_update_state();
-        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_4)];
-
-        let new_workload = update_state
-            .desired_state
-            .workloads
-            .get(WORKLOAD_NAME_4)
-            .unwrap()
-            .clone();
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
 
         let mut expected = old_state.clone();
-        expected
-            .desired_state
-            .workloads
-            .insert(WORKLOAD_NAME_4.into(), new_workload.clone());
+        expected.desired_state.workloads.insert(
+            WORKLOAD_NAME_1.to_owned(),
+            update_state.desired_state.workloads[WORKLOAD_NAME_1].clone(),
+        );
 
         let mut delete_graph_mock = MockDeleteGraph::new();
         delete_graph_mock.expect_insert().once().return_const(());
-
         delete_graph_mock
             .expect_apply_delete_conditions_to()
             .once()
             .return_const(());
 
         let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_expected_state = expected.desired_state.clone();
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| {
-                Ok(RenderedWorkloads::from([(
-                    WORKLOAD_NAME_4.to_owned(),
-                    generate_test_workload_spec_with_param(
-                        new_workload.agent.clone(),
-                        WORKLOAD_NAME_4.to_owned(),
-                        new_workload.runtime.clone(),
-                    ),
-                )]))
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &cloned_expected_state,
+                ))
             });
 
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_store()
+            .with(
+                predicate::eq(expected.desired_state.clone()),
+                predicate::eq(1),
+                predicate::eq(ScheduledAgents::new()),
+            )
+            .once()
+            .return_const(Ok(()));
+
         let mut server_state = ServerState {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
-        server_state.update(update_state, update_mask).unwrap();
+        server_state.set_state_store(Box::new(mock_state_store));
+        server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap();
 
         assert_eq!(expected, server_state.state);
     }
 
-    // [utest->swdd~update-desired-state-with-update-mask~1]
-    // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
-    // [utest->swdd~server-state-updates-state-on-unmodified-workloads~1]
+    // [utest->swdd~server-state-dry-runs-update~1]
     #[test]
-    fn utest_server_state_update_state_update_configs_not_affecting_workloads() {
+    fn utest_server_state_dry_run_update_returns_workloads_without_applying() {
         let old_state = generate_test_old_state();
-        let mut state_with_updated_config = old_state.clone();
-        state_with_updated_config.desired_state.configs = generate_test_configs();
+        let update_state = generate_test_update_state();
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
 
-        let update_mask = vec!["desiredState".to_string()];
+        let mut new_desired_state = old_state.desired_state.clone();
+        new_desired_state.workloads.insert(
+            WORKLOAD_NAME_1.to_owned(),
+            update_state.desired_state.workloads[WORKLOAD_NAME_1].clone(),
+        );
+        let new_rendered_workloads = generate_rendered_workloads_from_state(&new_desired_state);
 
         let mut delete_graph_mock = MockDeleteGraph::new();
         delete_graph_mock.expect_insert().never();
-
         delete_graph_mock
             .expect_apply_delete_conditions_to()
             .never();
 
         let mut mock_config_renderer = MockConfigRenderer::new();
-        let cloned_state_with_updated_config = state_with_updated_config.desired_state.clone();
+        let cloned_rendered_workloads = new_rendered_workloads.clone();
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .with(
-                predicate::eq(state_with_updated_config.desired_state.workloads.clone()),
-                predicate::eq(state_with_updated_config.desired_state.configs.clone()),
-            )
-            .returning(move |_, _| {
-                Ok(generate_rendered_workloads_from_state(
-                    &cloned_state_with_updated_config,
-                ))
-            });
+            .returning(move |_, _, _| Ok(cloned_rendered_workloads.clone()));
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store.expect_store().never();
 
         let mut server_state = ServerState {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
+        server_state.set_state_store(Box::new(mock_state_store));
 
-        let expected = state_with_updated_config.clone();
-
-        let added_deleted_workloads = server_state
-            .update(state_with_updated_config, update_mask)
+        let (added_workloads, deleted_workloads) = server_state
+            .dry_run_update(update_state, update_mask)
+            .unwrap()
             .unwrap();
 
-        assert!(added_deleted_workloads.is_none());
-        assert_eq!(expected, server_state.state);
+        assert_eq!(
+            added_workloads,
+            vec![new_rendered_workloads[WORKLOAD_NAME_1].clone()]
+        );
+        assert_eq!(deleted_workloads.len(), 1);
+        assert_eq!(
+            deleted_workloads[0].instance_name,
+            server_state.rendered_workloads[WORKLOAD_NAME_1].instance_name
+        );
+        assert_eq!(old_state, server_state.state);
     }
 
-    // [utest->swdd~update-desired-state-with-update-mask~1]
-    // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+    // [utest->swdd~server-state-dry-runs-update~1]
     #[test]
-    fn utest_server_state_update_state_update_workload_with_existing_configs() {
-        let mut old_state = generate_test_old_state();
-        old_state.desired_state.configs = generate_test_configs();
+    fn utest_server_state_dry_run_update_rejects_invalid_update() {
+        let old_state = generate_test_old_state();
+        let update_state = generate_test_update_state();
 
-        let mut updated_state = old_state.clone();
-        updated_state.desired_state.configs = HashMap::from([(
-            "config_1".to_string(),
-            ConfigItem::ConfigObject(HashMap::from([(
-                "agent_name".to_string(),
-                ConfigItem::String(AGENT_B.to_owned()), // changed agent name in configs
-            )])),
-        )]);
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(|_, _, _| {
+                Err(ConfigRenderError::NotExistingConfigKey(
+                    "config_1".to_owned(),
+                ))
+            });
 
-        let updated_workload = updated_state
-            .desired_state
-            .workloads
-            .get_mut(WORKLOAD_NAME_1)
-            .unwrap();
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
 
-        updated_workload.runtime_config = "updated runtime config".to_string(); // changed runtime config
+        assert!(matches!(
+            server_state.dry_run_update(update_state, vec![]),
+            Err(UpdateStateError::ResultInvalid(_))
+        ));
+        assert_eq!(old_state, server_state.state);
+    }
 
-        // update mask references only changed workload
-        let update_mask = vec![format!("desiredState.workloads.{WORKLOAD_NAME_1}")];
+    // [utest->swdd~server-state-stores-current-desired-state~1]
+    #[test]
+    fn utest_server_state_stores_current_desired_state() {
+        let old_state = generate_test_old_state();
 
-        let mut delete_graph_mock = MockDeleteGraph::new();
-        delete_graph_mock.expect_insert().once().return_const(());
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_store()
+            .with(
+                predicate::eq(old_state.desired_state.clone()),
+                predicate::eq(7),
+                predicate::eq(ScheduledAgents::new()),
+            )
+            .once()
+            .return_const(Err(StateStoreError::Write("disk full".into())));
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            ..Default::default()
+        };
+        assert!(server_state.store_desired_state(7).is_ok());
+
+        server_state.set_state_store(Box::new(mock_state_store));
+        assert!(matches!(
+            server_state.store_desired_state(7),
+            Err(UpdateStateError::StateNotPersisted(_))
+        ));
+    }
+
+    // [utest->swdd~server-state-persists-desired-state~1]
+    #[test]
+    fn utest_server_state_update_state_rejects_update_not_persisted() {
+        let old_state = generate_test_old_state();
+        let update_state = generate_test_update_state();
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_1)];
 
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
         delete_graph_mock
             .expect_apply_delete_conditions_to()
-            .once()
-            .return_const(());
+            .never();
 
         let mut mock_config_renderer = MockConfigRenderer::new();
-        let state_to_render = updated_state.desired_state.clone();
+        let cloned_update_state = update_state.desired_state.clone();
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .with(
-                predicate::eq(updated_state.desired_state.workloads.clone()),
-                predicate::eq(old_state.desired_state.configs.clone()), // existing configs due to update mask
-            )
-            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&state_to_render)));
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(&cloned_update_state))
+            });
+
+        let mut mock_state_store = MockStateStore::new();
+        mock_state_store
+            .expect_store()
+            .once()
+            .return_const(Err(StateStoreError::Write("disk full".to_string())));
 
         let mut server_state = ServerState {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
+        server_state.set_state_store(Box::new(mock_state_store));
 
-        let mut expected = updated_state.clone();
-        expected.desired_state.configs = old_state.desired_state.configs.clone(); // existing configs due to update mask
+        assert_eq!(
+            server_state.update(update_state, update_mask, &RevisionHistory::default()),
+            Err(UpdateStateError::StateNotPersisted(
+                "Could not write the state: 'disk full'".to_string()
+            ))
+        );
+        assert_eq!(old_state, server_state.state);
+    }
+
+    // [utest->swdd~update-desired-state-with-update-mask~1]
+    // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+    // [utest->swdd~server-state-triggers-validation-of-workload-fields~1]
+    #[test]
+    fn utest_server_state_update_state_add_workload() {
+        let old_state = generate_test_old_state();
+        let update_state = generate_test_update_state();
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_4)];
+
+        let new_workload = update_state
+            .desired_state
+            .workloads
+            .get(WORKLOAD_NAME_4)
+            .unwrap()
+            .clone();
+
+        let mut expected = old_state.clone();
+        expected
+            .desired_state
+            .workloads
+            .insert(WORKLOAD_NAME_4.into(), new_workload.clone());
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _, _| {
+                Ok(RenderedWorkloads::from([(
+                    WORKLOAD_NAME_4.to_owned(),
+                    generate_test_workload_spec_with_param(
+                        new_workload.agent.clone(),
+                        WORKLOAD_NAME_4.to_owned(),
+                        new_workload.runtime.clone(),
+                    ),
+                )]))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap();
+
+        assert_eq!(expected, server_state.state);
+    }
+
+    // [utest->swdd~server-filters-complete-state-by-label-selector~1]
+    #[test]
+    fn utest_server_state_get_complete_state_by_field_mask_filters_by_label_selector() {
+        let mut w1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        w1.tags = vec![Tag {
+            key: "team".to_owned(),
+            value: "infotainment".to_owned(),
+        }];
+        let mut w2 = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_2.to_string(),
+            RUNTIME.to_string(),
+        );
+        w2.tags = vec![Tag {
+            key: "team".to_owned(),
+            value: "adas".to_owned(),
+        }];
 
-        let result = server_state.update(updated_state, update_mask);
+        let server_state = ServerState {
+            state: generate_test_complete_state(vec![w1.clone(), w2.clone()]),
+            ..Default::default()
+        };
+        let mut workload_state_map = WorkloadStatesMap::default();
+        for workload in [&w1, &w2] {
+            workload_state_map.process_new_states(vec![WorkloadState {
+                instance_name: workload.instance_name.clone(),
+                execution_state: ExecutionState::running(),
+            }]);
+        }
+
+        let received_complete_state = server_state
+            .get_complete_state_by_field_mask(
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: Some("team=infotainment".to_owned()),
+                },
+                &workload_state_map,
+            )
+            .unwrap();
+
+        let received_workloads = received_complete_state
+            .desired_state
+            .unwrap()
+            .workloads
+            .unwrap()
+            .workloads;
+        assert_eq!(
+            received_workloads.keys().collect::<Vec<_>>(),
+            vec![WORKLOAD_NAME_1]
+        );
+        let received_workload_states = received_complete_state
+            .workload_states
+            .unwrap()
+            .agent_state_map;
+        assert_eq!(
+            received_workload_states.keys().collect::<Vec<_>>(),
+            vec![AGENT_A]
+        );
+        assert!(received_workload_states[AGENT_A]
+            .wl_name_state_map
+            .contains_key(WORKLOAD_NAME_1));
+    }
+
+    // [utest->swdd~server-filters-complete-state-by-label-selector~1]
+    #[test]
+    fn utest_server_state_get_complete_state_by_field_mask_rejects_invalid_label_selector() {
+        let server_state = ServerState {
+            state: generate_test_complete_state(vec![]),
+            ..Default::default()
+        };
+
+        assert!(server_state
+            .get_complete_state_by_field_mask(
+                CompleteStateRequest {
+                    field_mask: vec![],
+                    label_selector: Some("team in infotainment".to_owned()),
+                },
+                &WorkloadStatesMap::default(),
+            )
+            .is_err());
+    }
+
+    // [utest->swdd~server-state-redacts-secrets-in-complete-state~1]
+    #[test]
+    fn utest_server_state_get_complete_state_by_field_mask_redacts_secrets() {
+        let mut state = generate_test_complete_state(vec![]);
+        state.desired_state.secrets = HashMap::from([(
+            "db_credentials".to_owned(),
+            ConfigItem::ConfigObject(HashMap::from([(
+                "password".to_owned(),
+                ConfigItem::String("s3cr3t".to_owned()),
+            )])),
+        )]);
+        let server_state = ServerState {
+            state,
+            ..Default::default()
+        };
+        let expected_secret =
+            ank_base::ConfigItem::from(ConfigItem::ConfigObject(HashMap::from([(
+                "password".to_owned(),
+                ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()),
+            )])));
+
+        for field_mask in [
+            vec![],
+            vec!["desiredState.secrets".to_owned()],
+            vec!["desiredState.secrets.db_credentials".to_owned()],
+        ] {
+            let received_complete_state = server_state
+                .get_complete_state_by_field_mask(
+                    CompleteStateRequest {
+                        field_mask,
+                        label_selector: None,
+                    },
+                    &WorkloadStatesMap::default(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                received_complete_state
+                    .desired_state
+                    .unwrap()
+                    .secrets
+                    .unwrap()
+                    .configs
+                    .get("db_credentials"),
+                Some(&expected_secret)
+            );
+        }
+
+        let received_complete_state = server_state
+            .get_complete_state_by_field_mask(
+                CompleteStateRequest {
+                    field_mask: vec!["desiredState.secrets.db_credentials.password".to_owned()],
+                    label_selector: None,
+                },
+                &WorkloadStatesMap::default(),
+            )
+            .unwrap();
+        assert!(!format!("{:?}", received_complete_state).contains("s3cr3t"));
+    }
+
+    // [utest->swdd~server-state-renders-workload-with-redacted-secrets~2]
+    #[test]
+    fn utest_server_state_get_redacted_workload_spec() {
+        let mut state = generate_test_complete_state(vec![]);
+        state.desired_state.workloads = HashMap::from([(
+            WORKLOAD_NAME_1.to_owned(),
+            generate_test_stored_workload_spec(AGENT_A, RUNTIME),
+        )]);
+        state.desired_state.secrets = HashMap::from([(
+            "password".to_owned(),
+            ConfigItem::String("s3cr3t".to_owned()),
+        )]);
+
+        let scheduled_instance_name = WorkloadInstanceName::builder()
+            .agent_name(AGENT_B)
+            .workload_name(WORKLOAD_NAME_1)
+            .id("scheduled_id")
+            .build();
+        let mut scheduled_workload = WorkloadSpec::from((
+            WORKLOAD_NAME_1.to_owned(),
+            generate_test_stored_workload_spec(AGENT_A, RUNTIME),
+        ));
+        scheduled_workload.instance_name = scheduled_instance_name.clone();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .withf(|workloads, _, secrets| {
+                workloads.len() == 1
+                    && workloads.contains_key(WORKLOAD_NAME_1)
+                    && secrets.get("password")
+                        == Some(&ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()))
+            })
+            .returning(|workloads, _, _| {
+                Ok(workloads
+                    .iter()
+                    .map(|(name, stored_workload)| {
+                        let mut workload_spec =
+                            WorkloadSpec::from((name.to_owned(), stored_workload.to_owned()));
+                        workload_spec.runtime_config = "rendered config".to_owned();
+                        (name.to_owned(), workload_spec)
+                    })
+                    .collect())
+            });
+
+        let server_state = ServerState {
+            state,
+            rendered_workloads: HashMap::from([(WORKLOAD_NAME_1.to_owned(), scheduled_workload)]),
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let workload_spec = server_state
+            .get_redacted_workload_spec(
+                WORKLOAD_NAME_1,
+                &["config_1".to_owned(), "config_2".to_owned()],
+            )
+            .unwrap();
+
+        assert_eq!(workload_spec.runtime_config, "rendered config");
+        assert_eq!(workload_spec.instance_name, scheduled_instance_name);
+    }
+
+    // [utest->swdd~server-state-renders-workload-with-redacted-secrets~2]
+    #[test]
+    fn utest_server_state_get_redacted_workload_spec_fails_for_unknown_workload() {
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        mock_config_renderer.expect_render_workloads().never();
+
+        let server_state = ServerState {
+            state: generate_test_complete_state(vec![]),
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        assert!(server_state
+            .get_redacted_workload_spec(WORKLOAD_NAME_1, &[])
+            .is_err());
+    }
+
+    // [utest->swdd~server-state-renders-workload-with-redacted-secrets~2]
+    #[test]
+    fn utest_server_state_get_redacted_workload_spec_fails_for_unlisted_config() {
+        let mut state = generate_test_complete_state(vec![]);
+        let mut stored_workload = generate_test_stored_workload_spec(AGENT_A, RUNTIME);
+        stored_workload.configs = HashMap::from([
+            ("db".to_owned(), "db_config".to_owned()),
+            ("credentials".to_owned(), "db_credentials".to_owned()),
+        ]);
+        state.desired_state.workloads =
+            HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        mock_config_renderer.expect_render_workloads().never();
+
+        let server_state = ServerState {
+            state,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            server_state.get_redacted_workload_spec(WORKLOAD_NAME_1, &["db_config".to_owned()]),
+            Err(format!(
+                "Workload '{}' references the configs [\"db_credentials\"] which are not listed in the request",
+                WORKLOAD_NAME_1
+            ))
+        );
+    }
+
+    // [utest->swdd~server-state-restores-redacted-secrets~1]
+    #[test]
+    fn utest_server_state_update_state_keeps_redacted_secrets() {
+        let mut old_state = generate_test_old_state();
+        old_state.desired_state.secrets = HashMap::from([
+            (
+                "secret_1".to_owned(),
+                ConfigItem::String("old_value_1".to_owned()),
+            ),
+            (
+                "secret_2".to_owned(),
+                ConfigItem::String("old_value_2".to_owned()),
+            ),
+        ]);
+        let mut updated_state = old_state.clone();
+        updated_state.desired_state.secrets = HashMap::from([
+            (
+                "secret_1".to_owned(),
+                ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()),
+            ),
+            (
+                "secret_2".to_owned(),
+                ConfigItem::String("new_value_2".to_owned()),
+            ),
+        ]);
+
+        let mut expected = old_state.clone();
+        expected.desired_state.secrets.insert(
+            "secret_2".to_owned(),
+            ConfigItem::String("new_value_2".to_owned()),
+        );
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_old_state = old_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .with(
+                predicate::always(),
+                predicate::always(),
+                predicate::eq(expected.desired_state.secrets.clone()),
+            )
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(&cloned_old_state))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let added_deleted_workloads = server_state
+            .update(
+                updated_state,
+                vec!["desiredState.secrets".to_owned()],
+                &RevisionHistory::default(),
+            )
+            .unwrap();
+
+        assert!(added_deleted_workloads.is_none());
+        assert_eq!(expected, server_state.state);
+    }
+
+    // [utest->swdd~server-state-restores-redacted-secrets~1]
+    #[test]
+    fn utest_server_state_update_state_rejects_redacted_value_of_new_secret() {
+        let old_state = generate_test_old_state();
+        let mut updated_state = old_state.clone();
+        updated_state.desired_state.secrets = HashMap::from([(
+            "new_secret".to_owned(),
+            ConfigItem::String(REDACTED_SECRET_VALUE.to_owned()),
+        )]);
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        mock_config_renderer.expect_render_workloads().never();
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let result = server_state.update(
+            updated_state,
+            vec!["desiredState.secrets".to_owned()],
+            &RevisionHistory::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(UpdateStateError::ResultInvalid(reason)) if reason.contains("new_secret")
+        ));
+        assert_eq!(old_state, server_state.state);
+    }
+
+    // [utest->swdd~update-desired-state-with-update-mask~1]
+    // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+    // [utest->swdd~server-state-updates-state-on-unmodified-workloads~1]
+    #[test]
+    fn utest_server_state_update_state_update_configs_not_affecting_workloads() {
+        let old_state = generate_test_old_state();
+        let mut state_with_updated_config = old_state.clone();
+        state_with_updated_config.desired_state.configs = generate_test_configs();
+
+        let update_mask = vec!["desiredState".to_string()];
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_state_with_updated_config = state_with_updated_config.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .with(
+                predicate::eq(state_with_updated_config.desired_state.workloads.clone()),
+                predicate::eq(state_with_updated_config.desired_state.configs.clone()),
+                predicate::always(),
+            )
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &cloned_state_with_updated_config,
+                ))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let expected = state_with_updated_config.clone();
+
+        let added_deleted_workloads = server_state
+            .update(
+                state_with_updated_config,
+                update_mask,
+                &RevisionHistory::default(),
+            )
+            .unwrap();
+
+        assert!(added_deleted_workloads.is_none());
+        assert_eq!(expected, server_state.state);
+    }
+
+    // [utest->swdd~update-desired-state-with-update-mask~1]
+    // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+    #[test]
+    fn utest_server_state_update_state_update_workload_with_existing_configs() {
+        let mut old_state = generate_test_old_state();
+        old_state.desired_state.configs = generate_test_configs();
+
+        let mut updated_state = old_state.clone();
+        updated_state.desired_state.configs = HashMap::from([(
+            "config_1".to_string(),
+            ConfigItem::ConfigObject(HashMap::from([(
+                "agent_name".to_string(),
+                ConfigItem::String(AGENT_B.to_owned()), // changed agent name in configs
+            )])),
+        )]);
+
+        let updated_workload = updated_state
+            .desired_state
+            .workloads
+            .get_mut(WORKLOAD_NAME_1)
+            .unwrap();
+
+        updated_workload.runtime_config = "updated runtime config".to_string(); // changed runtime config
+
+        // update mask references only changed workload
+        let update_mask = vec![format!("desiredState.workloads.{WORKLOAD_NAME_1}")];
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let state_to_render = updated_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .with(
+                predicate::eq(updated_state.desired_state.workloads.clone()),
+                predicate::eq(old_state.desired_state.configs.clone()), // existing configs due to update mask
+                predicate::always(),
+            )
+            .returning(move |_, _, _| Ok(generate_rendered_workloads_from_state(&state_to_render)));
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let mut expected = updated_state.clone();
+        expected.desired_state.configs = old_state.desired_state.configs.clone(); // existing configs due to update mask
+
+        let result = server_state.update(updated_state, update_mask, &RevisionHistory::default());
         assert!(result.is_ok());
 
         let (added_workloads, _) = result.unwrap().unwrap_or_default();
@@ -989,19 +1890,21 @@ mod tests {
             .with(
                 predicate::eq(updated_state.desired_state.workloads.clone()),
                 predicate::eq(updated_state.desired_state.configs.clone()),
+                predicate::always(),
             )
-            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&state_to_render)));
+            .returning(move |_, _, _| Ok(generate_rendered_workloads_from_state(&state_to_render)));
 
         let mut server_state = ServerState {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let expected = updated_state.clone();
 
-        let result = server_state.update(updated_state, update_mask);
+        let result = server_state.update(updated_state, update_mask, &RevisionHistory::default());
         assert!(result.is_ok());
 
         let (added_workloads, deleted_workloads) = result.unwrap().unwrap_or_default();
@@ -1049,7 +1952,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| {
+            .returning(move |_, _, _| {
                 Err(ConfigRenderError::Field(
                     "agent".to_string(),
                     "config item does not exist".to_string(),
@@ -1061,9 +1964,10 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
-        let result = server_state.update(updated_state, update_mask);
+        let result = server_state.update(updated_state, update_mask, &RevisionHistory::default());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -1101,15 +2005,20 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&cloned_new_state)));
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(&cloned_new_state))
+            });
 
         let mut server_state = ServerState {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
-        server_state.update(update_state, update_mask).unwrap();
+        server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap();
 
         assert_eq!(expected, server_state.state);
     }
@@ -1135,7 +2044,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| {
+            .returning(move |_, _, _| {
                 Ok(generate_rendered_workloads_from_state(
                     &cloned_old_state.desired_state,
                 ))
@@ -1146,8 +2055,11 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
-        server_state.update(update_state, update_mask).unwrap();
+        server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap();
 
         assert_eq!(*expected, server_state.state);
     }
@@ -1173,8 +2085,9 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
-        let result = server_state.update(update_state, update_mask);
+        let result = server_state.update(update_state, update_mask, &RevisionHistory::default());
 
         assert!(result.is_err());
         assert_eq!(server_state.state, old_state);
@@ -1199,7 +2112,7 @@ mod tests {
             delete_graph: delete_graph_mock,
             ..Default::default()
         };
-        let result = server_state.update(update_state, update_mask);
+        let result = server_state.update(update_state, update_mask, &RevisionHistory::default());
         assert!(result.is_err());
         assert_eq!(server_state.state, old_state);
     }
@@ -1220,7 +2133,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(|_, _| Ok(HashMap::new()));
+            .returning(|_, _, _| Ok(HashMap::new()));
 
         let mut server_state = ServerState {
             delete_graph: delete_graph_mock,
@@ -1229,7 +2142,11 @@ mod tests {
         };
 
         let added_deleted_workloads = server_state
-            .update(CompleteState::default(), vec![])
+            .update(
+                CompleteState::default(),
+                vec![],
+                &RevisionHistory::default(),
+            )
             .unwrap();
         assert!(added_deleted_workloads.is_none());
         assert_eq!(server_state.state, CompleteState::default());
@@ -1258,7 +2175,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
+            .returning(move |_, _, _| Ok(generate_rendered_workloads_from_state(&new_state_clone)));
 
         let mut server_state = ServerState {
             delete_graph: delete_graph_mock,
@@ -1266,7 +2183,9 @@ mod tests {
             ..Default::default()
         };
 
-        let added_deleted_workloads = server_state.update(new_state.clone(), update_mask).unwrap();
+        let added_deleted_workloads = server_state
+            .update(new_state.clone(), update_mask, &RevisionHistory::default())
+            .unwrap();
         assert!(added_deleted_workloads.is_some());
 
         let (mut added_workloads, deleted_workloads) = added_deleted_workloads.unwrap();
@@ -1319,7 +2238,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(|_, _| Ok(HashMap::new()));
+            .returning(|_, _, _| Ok(HashMap::new()));
 
         let mut server_state = ServerState {
             state: current_complete_state.clone(),
@@ -1328,9 +2247,12 @@ mod tests {
                 &current_complete_state.desired_state,
             ),
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
-        let added_deleted_workloads = server_state.update(update_state, update_mask).unwrap();
+        let added_deleted_workloads = server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap();
         assert!(added_deleted_workloads.is_some());
 
         let (added_workloads, mut deleted_workloads) = added_deleted_workloads.unwrap();
@@ -1381,12 +2303,171 @@ mod tests {
         let updated_workload = generate_test_workload_spec_with_param(
             AGENT_B.into(),
             WORKLOAD_NAME_1.to_string(),
-            "runtime_2".into(),
-        );
-        new_complete_state
-            .desired_state
-            .workloads
-            .insert(WORKLOAD_NAME_1.to_string(), updated_workload.clone().into());
+            "runtime_2".into(),
+        );
+        new_complete_state
+            .desired_state
+            .workloads
+            .insert(WORKLOAD_NAME_1.to_string(), updated_workload.clone().into());
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_new_state = new_complete_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(&cloned_new_state))
+            });
+
+        let mut server_state = ServerState {
+            state: current_complete_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(
+                &current_complete_state.desired_state,
+            ),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let added_deleted_workloads = server_state
+            .update(
+                new_complete_state.clone(),
+                update_mask,
+                &RevisionHistory::default(),
+            )
+            .unwrap();
+        assert!(added_deleted_workloads.is_some());
+
+        let (added_workloads, deleted_workloads) = added_deleted_workloads.unwrap();
+
+        assert_eq!(added_workloads, vec![updated_workload]);
+
+        assert_eq!(
+            deleted_workloads,
+            vec![DeletedWorkload {
+                instance_name: (WORKLOAD_NAME_1.to_string(), workload_to_update).into(),
+                dependencies: HashMap::new(),
+            }]
+        );
+
+        assert_eq!(server_state.state, new_complete_state);
+    }
+
+    // [utest->swdd~server-detects-changed-workload~1]
+    // [utest->swdd~common-workload-restart-generation~1]
+    #[test]
+    fn utest_server_state_update_state_restart_generation_restarts_workload() {
+        let current_complete_state = generate_test_old_state();
+
+        let mut expected_complete_state = current_complete_state.clone();
+        expected_complete_state
+            .desired_state
+            .workloads
+            .get_mut(WORKLOAD_NAME_1)
+            .unwrap()
+            .restart_generation = 1;
+
+        let update_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_string(),
+                    StoredWorkloadSpec {
+                        restart_generation: 1,
+                        ..Default::default()
+                    },
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let update_mask = vec![format!(
+            "desiredState.workloads.{}.restartGeneration",
+            WORKLOAD_NAME_1
+        )];
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_expected_state = expected_complete_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &cloned_expected_state,
+                ))
+            });
+
+        let mut server_state = ServerState {
+            state: current_complete_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(
+                &current_complete_state.desired_state,
+            ),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let (added_workloads, deleted_workloads) = server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(added_workloads.len(), 1);
+        assert_eq!(added_workloads[0].restart_generation, 1);
+        assert_eq!(
+            deleted_workloads,
+            vec![DeletedWorkload {
+                instance_name: added_workloads[0].instance_name.clone(),
+                dependencies: HashMap::new(),
+            }]
+        );
+        assert_eq!(server_state.state, expected_complete_state);
+    }
+
+    // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+    #[test]
+    fn utest_server_state_update_state_restart_generation_restarts_workload_with_configs() {
+        let mut current_complete_state = CompleteState::default();
+        current_complete_state.desired_state.workloads = HashMap::from([(
+            WORKLOAD_NAME_1.to_string(),
+            generate_test_stored_workload_spec_with_config(
+                "{{ref1.agent_name}}",
+                RUNTIME,
+                "some_value_1: {{ref1.values.value_1}}",
+            ),
+        )]);
+        current_complete_state.desired_state.configs = generate_test_configs();
+
+        let update_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_string(),
+                    StoredWorkloadSpec {
+                        restart_generation: 1,
+                        ..Default::default()
+                    },
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let update_mask = vec![format!(
+            "desiredState.workloads.{}.restartGeneration",
+            WORKLOAD_NAME_1
+        )];
 
         let mut delete_graph_mock = MockDeleteGraph::new();
         delete_graph_mock.expect_insert().once().return_const(());
@@ -1395,40 +2476,44 @@ mod tests {
             .once()
             .return_const(());
 
+        // delegate to the real renderer to see the fields it actually keeps
         let mut mock_config_renderer = MockConfigRenderer::new();
-        let cloned_new_state = new_complete_state.desired_state.clone();
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&cloned_new_state)));
+            .returning(|workloads, configs, secrets| {
+                ConfigRenderer::default().render_workloads(workloads, configs, secrets)
+            });
 
         let mut server_state = ServerState {
             state: current_complete_state.clone(),
-            rendered_workloads: generate_rendered_workloads_from_state(
-                &current_complete_state.desired_state,
-            ),
+            rendered_workloads: ConfigRenderer::default()
+                .render_workloads(
+                    &current_complete_state.desired_state.workloads,
+                    &current_complete_state.desired_state.configs,
+                    &HashMap::new(),
+                )
+                .unwrap(),
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
-        let added_deleted_workloads = server_state
-            .update(new_complete_state.clone(), update_mask)
+        let (added_workloads, deleted_workloads) = server_state
+            .update(update_state, update_mask, &RevisionHistory::default())
+            .unwrap()
             .unwrap();
-        assert!(added_deleted_workloads.is_some());
-
-        let (added_workloads, deleted_workloads) = added_deleted_workloads.unwrap();
-
-        assert_eq!(added_workloads, vec![updated_workload]);
 
+        assert_eq!(added_workloads.len(), 1);
+        assert_eq!(added_workloads[0].instance_name.agent_name(), AGENT_A);
+        assert_eq!(added_workloads[0].restart_generation, 1);
         assert_eq!(
             deleted_workloads,
             vec![DeletedWorkload {
-                instance_name: (WORKLOAD_NAME_1.to_string(), workload_to_update).into(),
+                instance_name: added_workloads[0].instance_name.clone(),
                 dependencies: HashMap::new(),
             }]
         );
-
-        assert_eq!(server_state.state, new_complete_state);
     }
 
     // [utest->swdd~server-state-stores-delete-condition~1]
@@ -1492,7 +2577,7 @@ mod tests {
         mock_config_renderer
             .expect_render_workloads()
             .once()
-            .returning(move |_, _| {
+            .returning(move |_, _, _| {
                 Ok(generate_rendered_workloads_from_state(
                     &cloned_expected_state,
                 ))
@@ -1505,10 +2590,11 @@ mod tests {
             state: current_complete_state,
             delete_graph: delete_graph_mock,
             config_renderer: mock_config_renderer,
+            ..Default::default()
         };
 
         let added_deleted_workloads = server_state
-            .update(new_complete_state, update_mask)
+            .update(new_complete_state, update_mask, &RevisionHistory::default())
             .unwrap();
         assert!(added_deleted_workloads.is_some());
     }
@@ -1545,6 +2631,41 @@ mod tests {
         assert_eq!(stored_state.free_memory, Some(free_memory));
     }
 
+    // [utest->swdd~server-state-stores-agent-last-seen~1]
+    #[test]
+    fn utest_server_state_update_agent_resource_availability_updates_last_seen() {
+        let mut server_state = ServerState::default();
+        server_state.add_agent(AGENT_A.to_string(), HashMap::new());
+        server_state
+            .state
+            .agents
+            .update_last_seen(AGENT_A, "1970-01-01T00:00:00Z".to_string());
+
+        server_state.update_agent_resource_availability(AgentLoadStatus {
+            agent_name: AGENT_A.to_string(),
+            cpu_usage: CpuUsage { cpu_usage: 42 },
+            free_memory: FreeMemory { free_memory: 42 },
+        });
+
+        let last_seen = server_state
+            .state
+            .agents
+            .get(AGENT_A)
+            .and_then(|agent| agent.last_seen.clone());
+        assert!(last_seen.is_some_and(|timestamp| timestamp != "1970-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn utest_server_state_get_agent_map_returns_connected_agents() {
+        let mut server_state = ServerState::default();
+        server_state.add_agent(AGENT_A.to_string(), HashMap::new());
+
+        let agent_map = server_state.get_agent_map();
+
+        assert!(agent_map.contains_key(AGENT_A));
+        assert_eq!(agent_map.iter().count(), 1);
+    }
+
     // [utest->swdd~server-removes-obsolete-delete-graph-entires~1]
     #[test]
     fn utest_remove_deleted_workloads_from_delete_graph() {
@@ -1568,18 +2689,376 @@ mod tests {
     #[test]
     fn utest_add_agent() {
         let mut server_state = ServerState::default();
-        server_state.add_agent(AGENT_A.to_string());
+        server_state.add_agent(AGENT_A.to_string(), HashMap::new());
         server_state.update_agent_resource_availability(AgentLoadStatus {
             agent_name: AGENT_A.to_string(),
             cpu_usage: CpuUsage { cpu_usage: 42 },
             free_memory: FreeMemory { free_memory: 42 },
         });
 
+        let last_seen = server_state
+            .state
+            .agents
+            .entry(AGENT_A.to_string())
+            .or_default()
+            .last_seen
+            .take();
+        assert!(last_seen.is_some());
+
         let expected_agent_map = generate_test_agent_map(AGENT_A);
 
         assert_eq!(server_state.state.agents, expected_agent_map);
     }
 
+    // [utest->swdd~server-state-stores-agent-labels~1]
+    #[test]
+    fn utest_add_agent_stores_labels_of_latest_hello() {
+        let mut server_state = ServerState::default();
+        server_state.add_agent(
+            AGENT_A.to_string(),
+            HashMap::from([("zone".to_string(), "front".to_string())]),
+        );
+        server_state.add_agent(
+            AGENT_A.to_string(),
+            HashMap::from([("zone".to_string(), "rear".to_string())]),
+        );
+
+        assert_eq!(
+            server_state.state.agents.get(AGENT_A).map(|a| &a.labels),
+            Some(&HashMap::from([("zone".to_string(), "rear".to_string())]))
+        );
+    }
+
+    // [utest->swdd~server-state-schedules-workloads-without-agent~1]
+    // [utest->swdd~server-state-accepts-workloads-without-agent-for-scheduling~1]
+    #[test]
+    fn utest_server_state_update_schedules_workloads_without_agent() {
+        let workload_without_agent = generate_test_workload_spec_with_param(
+            "".to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        let scheduled_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let new_complete_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    workload_without_agent.into(),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock
+            .expect_insert()
+            .with(predicate::eq(vec![scheduled_workload.clone()]))
+            .once()
+            .return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_new_state = new_complete_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _, _| {
+                Ok(generate_rendered_workloads_from_state(&cloned_new_state))
+            });
+
+        let mut server_state = ServerState {
+            state: CompleteState {
+                agents: generate_test_agent_map(AGENT_A),
+                ..Default::default()
+            },
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        server_state.enable_scheduler();
+
+        let added_deleted_workloads = server_state
+            .update(new_complete_state, vec![], &RevisionHistory::default())
+            .unwrap();
+
+        assert_eq!(
+            added_deleted_workloads,
+            Some((vec![scheduled_workload.clone()], vec![]))
+        );
+        assert_eq!(
+            server_state.get_workloads_for_agent(AGENT_A),
+            vec![scheduled_workload]
+        );
+    }
+
+    // [utest->swdd~server-state-schedules-pending-workloads~1]
+    #[test]
+    fn utest_server_state_schedule_pending_workloads() {
+        let workload_without_agent = generate_test_workload_spec_with_param(
+            "".to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        let scheduled_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock
+            .expect_insert()
+            .with(predicate::eq(vec![scheduled_workload.clone()]))
+            .once()
+            .return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut server_state = ServerState {
+            state: generate_test_complete_state(vec![workload_without_agent.clone()]),
+            rendered_workloads: RenderedWorkloads::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                workload_without_agent.clone(),
+            )]),
+            delete_graph: delete_graph_mock,
+            ..Default::default()
+        };
+        server_state.enable_scheduler();
+
+        assert_eq!(server_state.schedule_pending_workloads(1), None);
+
+        server_state.add_agent(AGENT_A.to_owned(), HashMap::new());
+
+        assert_eq!(
+            server_state.schedule_pending_workloads(1),
+            Some((
+                vec![scheduled_workload.clone()],
+                vec![DeletedWorkload {
+                    instance_name: workload_without_agent.instance_name,
+                    ..Default::default()
+                }]
+            ))
+        );
+        assert_eq!(
+            server_state.get_workload_spec(WORKLOAD_NAME_1),
+            Some(scheduled_workload)
+        );
+    }
+
+    // [utest->swdd~server-state-keeps-lost-agents~1]
+    // [utest->swdd~server-state-schedules-pending-workloads~1]
+    #[test]
+    fn utest_server_state_schedule_pending_workloads_moves_workloads_of_lost_agent() {
+        let workload_without_agent = generate_test_workload_spec_with_param(
+            "".to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        let workload_on_agent_a = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        let workload_on_agent_b = generate_test_workload_spec_with_param(
+            AGENT_B.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock
+            .expect_insert()
+            .with(predicate::eq(vec![workload_on_agent_b.clone()]))
+            .once()
+            .return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut server_state = ServerState {
+            state: CompleteState {
+                desired_state: generate_test_complete_state(vec![workload_without_agent])
+                    .desired_state,
+                ..Default::default()
+            },
+            rendered_workloads: RenderedWorkloads::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                workload_on_agent_a.clone(),
+            )]),
+            delete_graph: delete_graph_mock,
+            ..Default::default()
+        };
+        server_state.enable_scheduler();
+        server_state.add_agent(AGENT_B.to_owned(), HashMap::new());
+
+        // the workload sticks to the disconnected agent until the agent is lost
+        assert_eq!(server_state.schedule_pending_workloads(1), None);
+
+        server_state.agent_lost(AGENT_A.to_owned());
+
+        assert_eq!(
+            server_state.schedule_pending_workloads(1),
+            Some((
+                vec![workload_on_agent_b.clone()],
+                vec![DeletedWorkload {
+                    instance_name: workload_on_agent_a.instance_name,
+                    ..Default::default()
+                }]
+            ))
+        );
+        assert_eq!(
+            server_state.get_workload_spec(WORKLOAD_NAME_1),
+            Some(workload_on_agent_b)
+        );
+
+        server_state.add_agent(AGENT_A.to_owned(), HashMap::new());
+
+        assert!(server_state.lost_agents.is_empty());
+    }
+
+    // [utest->swdd~server-state-schedules-workloads-without-agent~1]
+    #[test]
+    fn utest_server_state_schedule_pending_workloads_scheduler_disabled() {
+        let workload_without_agent = generate_test_workload_spec_with_param(
+            "".to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+
+        let mut server_state = ServerState {
+            state: CompleteState {
+                agents: generate_test_agent_map(AGENT_A),
+                ..Default::default()
+            },
+            rendered_workloads: RenderedWorkloads::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                workload_without_agent.clone(),
+            )]),
+            ..Default::default()
+        };
+
+        assert_eq!(server_state.schedule_pending_workloads(1), None);
+        assert_eq!(
+            server_state.get_workload_spec(WORKLOAD_NAME_1),
+            Some(workload_without_agent)
+        );
+    }
+
+    // [utest->swdd~server-state-persists-scheduled-agents~1]
+    // [utest->swdd~server-state-keeps-restored-scheduled-agents~1]
+    #[test]
+    fn utest_server_state_keeps_scheduled_agents_after_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join("state.yaml");
+
+        let new_server_state = || {
+            let mut mock_config_renderer = MockConfigRenderer::new();
+            mock_config_renderer
+                .expect_render_workloads()
+                .returning(|workloads, _, _| {
+                    Ok(workloads
+                        .iter()
+                        .map(|(name, spec)| {
+                            (
+                                name.to_owned(),
+                                WorkloadSpec::from((name.to_owned(), spec.to_owned())),
+                            )
+                        })
+                        .collect())
+                });
+            let mut delete_graph_mock = MockDeleteGraph::new();
+            delete_graph_mock.expect_insert().return_const(());
+            delete_graph_mock
+                .expect_apply_delete_conditions_to()
+                .return_const(());
+
+            let mut server_state = ServerState {
+                delete_graph: delete_graph_mock,
+                config_renderer: mock_config_renderer,
+                ..Default::default()
+            };
+            server_state.enable_scheduler();
+            server_state
+        };
+        let agent_of = |server_state: &ServerState, workload_name: &str| {
+            server_state
+                .get_workload_spec(workload_name)
+                .unwrap()
+                .instance_name
+                .agent_name()
+                .to_owned()
+        };
+
+        let desired_state = State {
+            workloads: HashMap::from([
+                (
+                    WORKLOAD_NAME_1.to_owned(),
+                    generate_test_stored_workload_spec("", RUNTIME),
+                ),
+                (
+                    WORKLOAD_NAME_2.to_owned(),
+                    generate_test_stored_workload_spec("", RUNTIME),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let mut server_state = new_server_state();
+        server_state.set_state_store(Box::new(FileStateStore::new(
+            &state_file,
+            generate_test_secret_cipher(),
+        )));
+        server_state.add_agent(AGENT_A.to_owned(), HashMap::new());
+        server_state.add_agent(AGENT_B.to_owned(), HashMap::new());
+        server_state
+            .update(
+                CompleteState {
+                    desired_state,
+                    ..Default::default()
+                },
+                vec![],
+                &RevisionHistory::default(),
+            )
+            .unwrap();
+        assert_eq!(agent_of(&server_state, WORKLOAD_NAME_1), AGENT_A);
+        assert_eq!(agent_of(&server_state, WORKLOAD_NAME_2), AGENT_B);
+
+        let mut state_store = FileStateStore::new(&state_file, generate_test_secret_cipher());
+        let persisted_state = state_store.load().unwrap().unwrap();
+
+        let mut restarted_server_state = new_server_state();
+        restarted_server_state.set_state_store(Box::new(state_store));
+        restarted_server_state.restore_scheduled_agents(persisted_state.scheduled_agents);
+        restarted_server_state
+            .update(
+                CompleteState {
+                    desired_state: persisted_state.desired_state,
+                    ..Default::default()
+                },
+                vec![],
+                &RevisionHistory::default(),
+            )
+            .unwrap();
+
+        // the first reconnecting agent does not take over the workloads of the other agent
+        restarted_server_state.add_agent(AGENT_B.to_owned(), HashMap::new());
+        assert_eq!(restarted_server_state.schedule_pending_workloads(1), None);
+        assert_eq!(agent_of(&restarted_server_state, WORKLOAD_NAME_1), AGENT_A);
+        assert_eq!(agent_of(&restarted_server_state, WORKLOAD_NAME_2), AGENT_B);
+    }
+
     // [utest->swdd~server-state-removes-agent-from-complete-state~1]
     #[test]
     fn utest_remove_agent() {
@@ -1612,6 +3091,22 @@ mod tests {
         assert!(!server_state.contains_connected_agent(AGENT_B));
     }
 
+    // [utest->swdd~server-state-provides-rendered-workload-by-name~1]
+    #[test]
+    fn utest_get_workload_spec() {
+        let old_state = generate_test_old_state();
+        let server_state = ServerState {
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            state: old_state,
+            ..Default::default()
+        };
+
+        let workload_spec = server_state.get_workload_spec(WORKLOAD_NAME_1).unwrap();
+        assert_eq!(workload_spec.instance_name.workload_name(), WORKLOAD_NAME_1);
+        assert_eq!(workload_spec.instance_name.agent_name(), AGENT_A);
+        assert!(server_state.get_workload_spec(WORKLOAD_NAME_4).is_none());
+    }
+
     fn generate_test_old_state() -> CompleteState {
         generate_test_complete_state(vec![
             generate_test_workload_spec_with_param(
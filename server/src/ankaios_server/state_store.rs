@@ -0,0 +1,408 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{scheduler::ScheduledAgents, secret_cipher::SecretCipher};
+use common::objects::State;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    io::{ErrorKind, Write},
+    path::PathBuf,
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+const TEMPORARY_FILE_EXTENSION: &str = "tmp";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateStoreError {
+    Read(String),
+    Write(String),
+    Parse(String),
+}
+
+impl fmt::Display for StateStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateStoreError::Read(reason) => {
+                write!(f, "Could not read the persisted state: '{}'", reason)
+            }
+            StateStoreError::Write(reason) => {
+                write!(f, "Could not write the state: '{}'", reason)
+            }
+            StateStoreError::Parse(reason) => {
+                write!(f, "Could not parse the persisted state: '{}'", reason)
+            }
+        }
+    }
+}
+
+// The desired state is flattened into the state file, so that state files written without the
+// last revision can still be loaded.
+// [impl->swdd~file-state-store-persists-last-revision~2]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedState {
+    #[serde(flatten)]
+    pub desired_state: State,
+    #[serde(default)]
+    pub last_revision: u64,
+    // [impl->swdd~file-state-store-persists-scheduled-agents~1]
+    #[serde(default, skip_serializing_if = "ScheduledAgents::is_empty")]
+    pub scheduled_agents: ScheduledAgents,
+}
+
+// [impl->swdd~server-state-store-interface~1]
+#[cfg_attr(test, automock)]
+pub trait StateStore: Send {
+    fn load(&mut self) -> Result<Option<PersistedState>, StateStoreError>;
+    fn store(
+        &mut self,
+        state: &State,
+        revision: u64,
+        scheduled_agents: &ScheduledAgents,
+    ) -> Result<(), StateStoreError>;
+}
+
+// [impl->swdd~file-state-store-writes-atomically~1]
+// [impl->swdd~file-state-store-encrypts-secrets~1]
+pub struct FileStateStore {
+    path: PathBuf,
+    cipher: SecretCipher,
+}
+
+impl FileStateStore {
+    pub fn new(path: impl Into<PathBuf>, cipher: SecretCipher) -> Self {
+        Self {
+            path: path.into(),
+            cipher,
+        }
+    }
+
+    fn temporary_path(&self) -> PathBuf {
+        let mut temporary_path = self.path.clone().into_os_string();
+        temporary_path.push(".");
+        temporary_path.push(TEMPORARY_FILE_EXTENSION);
+        temporary_path.into()
+    }
+
+    fn sync_parent_directory(&self) {
+        if let Some(parent) = self
+            .path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            if let Err(err) = fs::File::open(parent).and_then(|dir| dir.sync_all()) {
+                log::warn!(
+                    "Could not sync the directory '{}' of the state file: '{}'",
+                    parent.display(),
+                    err
+                );
+            }
+        }
+    }
+}
+
+impl StateStore for FileStateStore {
+    fn load(&mut self) -> Result<Option<PersistedState>, StateStoreError> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => {
+                let persisted_state: PersistedState =
+                    serde_yaml::from_str(&content).map_err(|err| {
+                        StateStoreError::Parse(format!("'{}': {}", self.path.display(), err))
+                    })?;
+                let desired_state = self
+                    .cipher
+                    .decrypt_secrets(persisted_state.desired_state)
+                    .map_err(|err| {
+                        StateStoreError::Parse(format!("'{}': {}", self.path.display(), err))
+                    })?;
+                Ok(Some(PersistedState {
+                    desired_state,
+                    ..persisted_state
+                }))
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                log::debug!("No persisted state found at '{}'.", self.path.display());
+                Ok(None)
+            }
+            Err(err) => Err(StateStoreError::Read(format!(
+                "'{}': {}",
+                self.path.display(),
+                err
+            ))),
+        }
+    }
+
+    fn store(
+        &mut self,
+        state: &State,
+        revision: u64,
+        scheduled_agents: &ScheduledAgents,
+    ) -> Result<(), StateStoreError> {
+        let encrypted_state = self
+            .cipher
+            .encrypt_secrets(state)
+            .map_err(|err| StateStoreError::Write(err.to_string()))?;
+        // [impl->swdd~file-state-store-persists-last-revision~2]
+        let content = serde_yaml::to_string(&PersistedState {
+            desired_state: encrypted_state,
+            last_revision: revision,
+            scheduled_agents: scheduled_agents.clone(),
+        })
+        .map_err(|err| StateStoreError::Write(err.to_string()))?;
+
+        // The state is written to a temporary file first, which replaces the state file only after
+        // it is completely on disk. A crash in between leaves the previous state file untouched.
+        let temporary_path = self.temporary_path();
+        let write_temporary_file = || -> std::io::Result<()> {
+            let mut file = fs::File::create(&temporary_path)?;
+            file.write_all(content.as_bytes())?;
+            file.sync_all()
+        };
+        write_temporary_file().map_err(|err| {
+            StateStoreError::Write(format!("'{}': {}", temporary_path.display(), err))
+        })?;
+
+        fs::rename(&temporary_path, &self.path)
+            .map_err(|err| StateStoreError::Write(format!("'{}': {}", self.path.display(), err)))?;
+
+        self.sync_parent_directory();
+        log::trace!("Persisted the state to '{}'.", self.path.display());
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use common::objects::{generate_test_stored_workload_spec, ConfigItem, State};
+
+    use super::{FileStateStore, PersistedState, StateStore, StateStoreError};
+    use crate::ankaios_server::{
+        scheduler::ScheduledAgents, secret_cipher::generate_test_secret_cipher,
+    };
+
+    const AGENT_A: &str = "agent_A";
+    const RUNTIME: &str = "runtime";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const STATE_FILE_NAME: &str = "state.yaml";
+
+    fn generate_test_state() -> State {
+        State {
+            workloads: HashMap::from([(
+                WORKLOAD_NAME_1.to_string(),
+                generate_test_stored_workload_spec(AGENT_A, RUNTIME),
+            )]),
+            ..Default::default()
+        }
+    }
+
+    // [utest->swdd~file-state-store-writes-atomically~1]
+    #[test]
+    fn utest_file_state_store_stores_and_loads_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join(STATE_FILE_NAME);
+        let mut state_store = FileStateStore::new(&state_file, generate_test_secret_cipher());
+
+        let state = generate_test_state();
+        assert_eq!(
+            state_store.store(&state, 1, &ScheduledAgents::new()),
+            Ok(())
+        );
+
+        assert_eq!(
+            FileStateStore::new(&state_file, generate_test_secret_cipher()).load(),
+            Ok(Some(PersistedState {
+                desired_state: state,
+                last_revision: 1,
+                ..Default::default()
+            }))
+        );
+        assert!(!dir.path().join("state.yaml.tmp").exists());
+    }
+
+    // [utest->swdd~file-state-store-encrypts-secrets~1]
+    #[test]
+    fn utest_file_state_store_stores_secrets_encrypted() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join(STATE_FILE_NAME);
+        let mut state_store = FileStateStore::new(&state_file, generate_test_secret_cipher());
+
+        let mut state = generate_test_state();
+        state.secrets = HashMap::from([(
+            "db_password".to_string(),
+            ConfigItem::String("s3cr3t".to_string()),
+        )]);
+        assert_eq!(
+            state_store.store(&state, 1, &ScheduledAgents::new()),
+            Ok(())
+        );
+
+        let content = std::fs::read_to_string(&state_file).unwrap();
+        assert!(content.contains("db_password"));
+        assert!(!content.contains("s3cr3t"));
+
+        assert_eq!(
+            state_store
+                .load()
+                .map(|loaded| loaded.map(|loaded| loaded.desired_state)),
+            Ok(Some(state))
+        );
+    }
+
+    // [utest->swdd~file-state-store-writes-atomically~1]
+    #[test]
+    fn utest_file_state_store_overwrites_previous_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(
+            dir.path().join(STATE_FILE_NAME),
+            generate_test_secret_cipher(),
+        );
+
+        assert_eq!(
+            state_store.store(&generate_test_state(), 1, &ScheduledAgents::new()),
+            Ok(())
+        );
+        assert_eq!(
+            state_store.store(&State::default(), 1, &ScheduledAgents::new()),
+            Ok(())
+        );
+
+        assert_eq!(
+            state_store
+                .load()
+                .map(|loaded| loaded.map(|loaded| loaded.desired_state)),
+            Ok(Some(State::default()))
+        );
+    }
+
+    // [utest->swdd~file-state-store-persists-last-revision~2]
+    #[test]
+    fn utest_file_state_store_stores_given_revision() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join(STATE_FILE_NAME);
+        let mut state_store = FileStateStore::new(&state_file, generate_test_secret_cipher());
+
+        assert_eq!(
+            state_store.store(&generate_test_state(), 5, &ScheduledAgents::new()),
+            Ok(())
+        );
+        assert_eq!(
+            state_store.store(&generate_test_state(), 5, &ScheduledAgents::new()),
+            Ok(())
+        );
+
+        assert_eq!(
+            FileStateStore::new(&state_file, generate_test_secret_cipher()).load(),
+            Ok(Some(PersistedState {
+                desired_state: generate_test_state(),
+                last_revision: 5,
+                ..Default::default()
+            }))
+        );
+    }
+
+    // [utest->swdd~file-state-store-persists-scheduled-agents~1]
+    #[test]
+    fn utest_file_state_store_stores_and_loads_scheduled_agents() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join(STATE_FILE_NAME);
+        let mut state_store = FileStateStore::new(&state_file, generate_test_secret_cipher());
+
+        let scheduled_agents =
+            ScheduledAgents::from([(WORKLOAD_NAME_1.to_string(), AGENT_A.to_string())]);
+        assert_eq!(
+            state_store.store(&generate_test_state(), 1, &scheduled_agents),
+            Ok(())
+        );
+
+        assert_eq!(
+            FileStateStore::new(&state_file, generate_test_secret_cipher()).load(),
+            Ok(Some(PersistedState {
+                desired_state: generate_test_state(),
+                last_revision: 1,
+                scheduled_agents,
+            }))
+        );
+    }
+
+    // [utest->swdd~file-state-store-persists-last-revision~2]
+    #[test]
+    fn utest_file_state_store_loads_state_without_last_revision() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join(STATE_FILE_NAME);
+        std::fs::write(&state_file, "apiVersion: v0.1\nworkloads: {}\n").unwrap();
+
+        let mut state_store = FileStateStore::new(&state_file, generate_test_secret_cipher());
+
+        assert_eq!(
+            state_store.load(),
+            Ok(Some(PersistedState {
+                desired_state: State::default(),
+                last_revision: 0,
+                ..Default::default()
+            }))
+        );
+    }
+
+    // [utest->swdd~file-state-store-writes-atomically~1]
+    #[test]
+    fn utest_file_state_store_load_returns_none_when_nothing_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(
+            dir.path().join(STATE_FILE_NAME),
+            generate_test_secret_cipher(),
+        );
+
+        assert_eq!(state_store.load(), Ok(None));
+    }
+
+    // [utest->swdd~file-state-store-writes-atomically~1]
+    #[test]
+    fn utest_file_state_store_load_fails_on_invalid_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join(STATE_FILE_NAME);
+        std::fs::write(&state_file, "workloads: [not, a, map]").unwrap();
+
+        let mut state_store = FileStateStore::new(&state_file, generate_test_secret_cipher());
+
+        assert!(matches!(state_store.load(), Err(StateStoreError::Parse(_))));
+    }
+
+    // [utest->swdd~file-state-store-writes-atomically~1]
+    #[test]
+    fn utest_file_state_store_store_fails_on_not_existing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state_store = FileStateStore::new(
+            dir.path().join("not_existing").join(STATE_FILE_NAME),
+            generate_test_secret_cipher(),
+        );
+
+        assert!(matches!(
+            state_store.store(&generate_test_state(), 1, &ScheduledAgents::new()),
+            Err(StateStoreError::Write(_))
+        ));
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use super::revision_history::TIMESTAMP_FORMAT;
+use api::ank_base;
+use common::objects::{ExecutionState, WorkloadInstanceName, WorkloadState, WorkloadStatesMap};
+use std::collections::{HashMap, VecDeque};
+
+pub const DEFAULT_WORKLOAD_STATE_HISTORY_SIZE: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionStateRecord {
+    pub timestamp: String,
+    pub instance_name: WorkloadInstanceName,
+    pub execution_state: ExecutionState,
+}
+
+impl From<&ExecutionStateRecord> for ank_base::ExecutionStateRecord {
+    fn from(item: &ExecutionStateRecord) -> Self {
+        ank_base::ExecutionStateRecord {
+            timestamp: item.timestamp.clone(),
+            instance_name: Some(item.instance_name.clone().into()),
+            execution_state: Some(item.execution_state.clone().into()),
+        }
+    }
+}
+
+// [impl->swdd~server-keeps-bounded-workload-state-history~1]
+#[derive(Debug)]
+pub struct WorkloadStateHistory {
+    records: HashMap<String, VecDeque<ExecutionStateRecord>>,
+    max_records: usize,
+}
+
+impl Default for WorkloadStateHistory {
+    fn default() -> Self {
+        WorkloadStateHistory::new(DEFAULT_WORKLOAD_STATE_HISTORY_SIZE)
+    }
+}
+
+impl WorkloadStateHistory {
+    pub fn new(max_records: usize) -> Self {
+        WorkloadStateHistory {
+            records: HashMap::new(),
+            max_records,
+        }
+    }
+
+    // [impl->swdd~server-keeps-bounded-workload-state-history~1]
+    pub fn record(&mut self, workload_states: &[WorkloadState]) {
+        for workload_state in workload_states {
+            let workload_records = self
+                .records
+                .entry(workload_state.instance_name.workload_name().to_owned())
+                .or_default();
+
+            if workload_records
+                .iter()
+                .rev()
+                .find(|record| record.instance_name == workload_state.instance_name)
+                .is_some_and(|record| record.execution_state == workload_state.execution_state)
+            {
+                continue;
+            }
+
+            workload_records.push_back(ExecutionStateRecord {
+                timestamp: chrono::Utc::now().format(TIMESTAMP_FORMAT).to_string(),
+                instance_name: workload_state.instance_name.clone(),
+                execution_state: workload_state.execution_state.clone(),
+            });
+            while workload_records.len() > self.max_records {
+                workload_records.pop_front();
+            }
+        }
+    }
+
+    // The history of a workload is dropped together with its last workload state.
+    // [impl->swdd~server-keeps-bounded-workload-state-history~1]
+    pub fn remove_unknown_workloads(&mut self, workload_states_map: &WorkloadStatesMap) {
+        self.records
+            .retain(|workload_name, _| workload_states_map.contains_workload(workload_name));
+    }
+
+    pub fn get(&self, workload_name: &str) -> Vec<ExecutionStateRecord> {
+        self.records
+            .get(workload_name)
+            .map(|records| records.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::WorkloadStateHistory;
+    use common::objects::{ExecutionState, WorkloadInstanceName, WorkloadState, WorkloadStatesMap};
+
+    const AGENT_A: &str = "agent_A";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+
+    fn workload_state(workload_name: &str, id: &str, state: ExecutionState) -> WorkloadState {
+        WorkloadState {
+            instance_name: WorkloadInstanceName::builder()
+                .agent_name(AGENT_A)
+                .workload_name(workload_name)
+                .id(id)
+                .build(),
+            execution_state: state,
+        }
+    }
+
+    fn recorded_states(history: &WorkloadStateHistory, workload_name: &str) -> Vec<ExecutionState> {
+        history
+            .get(workload_name)
+            .into_iter()
+            .map(|record| record.execution_state)
+            .collect()
+    }
+
+    // [utest->swdd~server-keeps-bounded-workload-state-history~1]
+    #[test]
+    fn utest_workload_state_history_records_transitions() {
+        let mut history = WorkloadStateHistory::default();
+
+        history.record(&[
+            workload_state(
+                WORKLOAD_NAME_1,
+                "id_1",
+                ExecutionState::starting_triggered(),
+            ),
+            workload_state(WORKLOAD_NAME_2, "id_2", ExecutionState::running()),
+        ]);
+        history.record(&[workload_state(
+            WORKLOAD_NAME_1,
+            "id_1",
+            ExecutionState::running(),
+        )]);
+
+        assert_eq!(
+            recorded_states(&history, WORKLOAD_NAME_1),
+            vec![
+                ExecutionState::starting_triggered(),
+                ExecutionState::running()
+            ]
+        );
+        assert_eq!(
+            recorded_states(&history, WORKLOAD_NAME_2),
+            vec![ExecutionState::running()]
+        );
+        assert!(history.get("unknown_workload").is_empty());
+    }
+
+    // [utest->swdd~server-keeps-bounded-workload-state-history~1]
+    #[test]
+    fn utest_workload_state_history_skips_unchanged_states() {
+        let mut history = WorkloadStateHistory::default();
+
+        history.record(&[workload_state(
+            WORKLOAD_NAME_1,
+            "id_1",
+            ExecutionState::running(),
+        )]);
+        history.record(&[
+            workload_state(WORKLOAD_NAME_1, "id_2", ExecutionState::running()),
+            workload_state(WORKLOAD_NAME_1, "id_1", ExecutionState::running()),
+        ]);
+
+        let history = history.get(WORKLOAD_NAME_1);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].instance_name.id(), "id_1");
+        assert_eq!(history[1].instance_name.id(), "id_2");
+    }
+
+    // [utest->swdd~server-keeps-bounded-workload-state-history~1]
+    #[test]
+    fn utest_workload_state_history_drops_oldest_records() {
+        let mut history = WorkloadStateHistory::new(2);
+
+        history.record(&[workload_state(
+            WORKLOAD_NAME_1,
+            "id_1",
+            ExecutionState::starting_triggered(),
+        )]);
+        history.record(&[workload_state(
+            WORKLOAD_NAME_1,
+            "id_1",
+            ExecutionState::running(),
+        )]);
+        history.record(&[workload_state(
+            WORKLOAD_NAME_1,
+            "id_1",
+            ExecutionState::succeeded(),
+        )]);
+
+        assert_eq!(
+            recorded_states(&history, WORKLOAD_NAME_1),
+            vec![ExecutionState::running(), ExecutionState::succeeded()]
+        );
+    }
+
+    // [utest->swdd~server-keeps-bounded-workload-state-history~1]
+    #[test]
+    fn utest_workload_state_history_removes_unknown_workloads() {
+        let mut history = WorkloadStateHistory::default();
+        let remaining_state = workload_state(WORKLOAD_NAME_2, "id_2", ExecutionState::running());
+        history.record(&[
+            workload_state(WORKLOAD_NAME_1, "id_1", ExecutionState::removed()),
+            remaining_state.clone(),
+        ]);
+
+        let mut workload_states_map = WorkloadStatesMap::default();
+        workload_states_map.process_new_states(vec![remaining_state]);
+        history.remove_unknown_workloads(&workload_states_map);
+
+        assert!(history.get(WORKLOAD_NAME_1).is_empty());
+        assert_eq!(history.get(WORKLOAD_NAME_2).len(), 1);
+    }
+}
@@ -34,12 +34,43 @@ pub struct Arguments {
     /// The path to the server config file.
     /// The default path is /etc/ankaios/ank-server.conf
     pub config_path: Option<String>,
+    #[clap(required = false, long = "state-file", env = "ANKSERVER_STATE_FILE")]
+    /// The path to the file the desired state is persisted to and restored from on startup.
+    /// By default, the desired state is not persisted.
+    pub state_file: Option<String>,
+    #[clap(long = "enable-scheduler", action=ArgAction::Set, num_args=0, default_missing_value="true", env = "ANKSERVER_ENABLE_SCHEDULER")]
+    /// Flag to let the server assign an agent to workloads without an agent.
+    pub enable_scheduler: Option<bool>,
+    #[clap(
+        long = "agent-heartbeat-timeout-ms",
+        env = "ANKSERVER_AGENT_HEARTBEAT_TIMEOUT_MS"
+    )]
+    /// Time in milliseconds after which an agent without a heartbeat is handled as disconnected.
+    /// Agents send a heartbeat every 2 seconds, so the timeout must be greater than 4000.
+    /// By default, agents are not checked for heartbeats.
+    pub agent_heartbeat_timeout_ms: Option<u64>,
+    #[clap(long = "monitoring-address", env = "ANKSERVER_MONITORING_ADDRESS")]
+    /// The address, including the port, of an HTTP listener providing /metrics, /healthz and /readyz.
+    /// By default, no HTTP listener is started.
+    pub monitoring_address: Option<SocketAddr>,
+    #[clap(long = "audit-log-file", env = "ANKSERVER_AUDIT_LOG_FILE")]
+    /// The path to the file the accepted and rejected changes of the desired state are appended to.
+    /// By default, no audit log is written.
+    pub audit_log_file: Option<String>,
+    #[clap(long = "secrets-key-file", env = "ANKSERVER_SECRETS_KEY_FILE")]
+    /// The path to the key file used to encrypt the secrets in the state file.
+    /// By default, the state file path with the suffix '.key' is used. A new key is generated if the file does not exist.
+    pub secrets_key_file: Option<String>,
     #[clap(required = false, short = 'a', long = "address")]
     /// The address, including the port, the server shall listen at [default: 127.0.0.1:25551].
     pub addr: Option<SocketAddr>,
     #[clap(short = 'k', long = "insecure", action=ArgAction::Set, num_args=0, default_missing_value="true", env = "ANKSERVER_INSECURE")]
     /// Flag to disable TLS communication between Ankaios server, agent and ank CLI.
     pub insecure: Option<bool>,
+    #[clap(long = "bind-agent-names", action=ArgAction::Set, num_args=0, default_missing_value="true", env = "ANKSERVER_BIND_AGENT_NAMES")]
+    /// Flag to only accept agents whose name matches the common name or a subject alternative name of their certificate.
+    /// Agents are also prevented from reporting workload states of other agents. Requires TLS.
+    pub bind_agent_names: Option<bool>,
     #[clap(long = "ca_pem", env = "ANKSERVER_CA_PEM")]
     /// Path to server ca certificate pem file.
     pub ca_pem: Option<String>,
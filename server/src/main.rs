@@ -14,21 +14,28 @@
 
 mod ankaios_server;
 mod cli;
+mod monitoring_endpoint;
 mod server_config;
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use common::objects::CompleteState;
 
 use common::communications_server::CommunicationsServer;
 use common::objects::State;
 use common::std_extensions::GracefulExitResult;
+use common::to_server_interface::ToServerInterface;
+use tokio::signal::unix::{signal, SignalKind};
 
-use ankaios_server::{create_from_server_channel, create_to_server_channel, AnkaiosServer};
+use ankaios_server::{
+    create_from_server_channel, create_to_server_channel, AnkaiosServer, FileAuditLog,
+    FileStateStore, SecretCipher, ServerMetrics,
+};
 use server_config::{ServerConfig, DEFAULT_SERVER_CONFIG_FILE_PATH};
 
-use grpc::{security::TLSConfig, server::GRPCCommunicationsServer};
+use grpc::{cli_roles::CliRoles, security::TLSConfig, server::GRPCCommunicationsServer};
 
 fn handle_sever_config(config_path: &Option<String>, default_path: &str) -> ServerConfig {
     match config_path {
@@ -67,14 +74,22 @@ async fn main() {
     let mut server_config = handle_sever_config(&args.config_path, DEFAULT_SERVER_CONFIG_FILE_PATH);
 
     server_config.update_with_args(&args);
+    // [impl->swdd~server-rejects-too-short-agent-heartbeat-timeout~1]
+    server_config
+        .verify_agent_heartbeat_timeout()
+        .unwrap_or_exit("Invalid agent heartbeat timeout");
 
     log::debug!(
-        "Starting the Ankaios server with \n\tserver address: '{}', \n\tstartup manifest path: '{}'",
+        "Starting the Ankaios server with \n\tserver address: '{}', \n\tstartup manifest path: '{}', \n\tstate file path: '{}'",
         server_config.address,
         server_config
             .startup_manifest
             .clone()
             .unwrap_or("[no manifest file provided]".to_string()),
+        server_config
+            .state_file
+            .clone()
+            .unwrap_or("[no state file provided]".to_string()),
     );
 
     let startup_state = match &server_config.startup_manifest {
@@ -125,20 +140,107 @@ async fn main() {
         // [impl->swdd~server-fails-on-missing-file-paths-and-insecure-cli-arguments~1]
         tls_config.unwrap_or_exit("Missing certificates files"),
     );
+    // [impl->swdd~server-binds-agent-names-to-certificates~1]
+    communications_server.set_bind_agent_names(server_config.bind_agent_names);
+    // [impl->swdd~server-configures-cli-roles~1]
+    communications_server
+        .set_cli_roles(CliRoles::new(server_config.cli_roles).unwrap_or_exit("Invalid CLI roles"));
     let mut server = AnkaiosServer::new(server_receiver, to_agents.clone());
 
+    // [impl->swdd~server-restores-persisted-state~1]
+    if let Some(state_file) = &server_config.state_file {
+        log::info!("Persisting the desired state to '{}'", state_file);
+        let secrets_key_file = server_config
+            .secrets_key_file
+            .clone()
+            .unwrap_or_else(|| format!("{}.key", state_file));
+        let secret_cipher = SecretCipher::from_key_file(Path::new(&secrets_key_file))
+            .unwrap_or_exit("Could not load the secrets key");
+        server.set_state_store(Box::new(FileStateStore::new(state_file, secret_cipher)));
+    }
+
+    // [impl->swdd~server-writes-audit-log~1]
+    if let Some(audit_log_file) = &server_config.audit_log_file {
+        log::info!("Writing the audit log to '{}'", audit_log_file);
+        server.set_audit_log(Box::new(FileAuditLog::new(audit_log_file)));
+    }
+
+    if server_config.enable_scheduler {
+        log::info!("Scheduling workloads without an agent to the connected agents");
+        server.enable_scheduler();
+    }
+
+    if let Some(agent_heartbeat_timeout_ms) = server_config.agent_heartbeat_timeout_ms {
+        log::info!(
+            "Handling agents without a heartbeat for {}ms as disconnected",
+            agent_heartbeat_timeout_ms
+        );
+        server.set_agent_heartbeat_timeout(Duration::from_millis(agent_heartbeat_timeout_ms));
+    }
+
+    // [impl->swdd~server-provides-monitoring-endpoint~1]
+    if let Some(monitoring_address) = server_config.monitoring_address {
+        let metrics = ServerMetrics::default();
+        server.set_metrics(metrics.clone());
+        let monitoring_endpoint = monitoring_endpoint::bind(monitoring_address, metrics)
+            .unwrap_or_exit("Could not start the monitoring endpoint");
+        log::info!(
+            "Providing metrics and health checks at 'http://{}'",
+            monitoring_address
+        );
+        tokio::spawn(async move {
+            if let Err(error) = monitoring_endpoint.await {
+                log::error!("The monitoring endpoint stopped: '{}'", error);
+            }
+        });
+    }
+
+    // [impl->swdd~server-stops-on-termination-signal~1]
+    let to_server_on_signal = to_server.clone();
+    tokio::spawn(async move {
+        wait_for_termination_signal().await;
+        log::info!("Received termination signal. Stopping the Ankaios server.");
+        if let Err(error) = to_server_on_signal.stop().await {
+            log::warn!("Could not stop the Ankaios server: '{}'", error);
+        }
+    });
+
+    let server_address = server_config.address;
+    // [impl->swdd~server-default-communication-grpc~1]
+    let mut communications_task = tokio::spawn(async move {
+        communications_server
+            .start(agents_receiver, server_address)
+            .await
+    });
+
     tokio::select! {
-        // [impl->swdd~server-default-communication-grpc~1]
-        communication_result = communications_server.start(agents_receiver, server_config.address) => {
-            communication_result.unwrap_or_exit("server error")
+        communication_result = &mut communications_task => {
+            communication_result
+                .unwrap_or_exit("server error")
+                .unwrap_or_exit("server error")
         }
 
         server_result = server.start(startup_state) => {
-            server_result.unwrap_or_exit("server error")
+            server_result.unwrap_or_exit("server error");
+            // [impl->swdd~server-waits-for-closed-connections-on-stop~1]
+            communications_task
+                .await
+                .unwrap_or_exit("server error")
+                .unwrap_or_exit("server error");
+            log::info!("The Ankaios server stopped.");
         }
     }
 }
 
+async fn wait_for_termination_signal() {
+    let mut sigterm =
+        signal(SignalKind::terminate()).unwrap_or_exit("Could not install the handler for SIGTERM");
+    tokio::select! {
+        _ = sigterm.recv() => {}
+        _ = tokio::signal::ctrl_c() => {}
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -152,10 +254,7 @@ mod tests {
     use crate::{
         handle_sever_config, server_config::DEFAULT_SERVER_CONFIG_FILE_PATH, ServerConfig,
     };
-    use std::{
-        io::Write,
-        net::SocketAddr,
-    };
+    use std::{io::Write, net::SocketAddr};
     use tempfile::NamedTempFile;
 
     const VALID_SERVER_CONFIG_CONTENT: &str = r"#
@@ -0,0 +1,166 @@
+// Copyright (c) 2025 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::SocketAddr;
+
+use hyper::header::CONTENT_TYPE;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+
+use crate::ankaios_server::ServerMetrics;
+
+const METRICS_PATH: &str = "/metrics";
+const HEALTH_PATH: &str = "/healthz";
+const READINESS_PATH: &str = "/readyz";
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+const TEXT_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
+
+// Binds the HTTP listener immediately, so that an unusable address is reported on startup.
+// The returned future serves the requests until it is dropped.
+// [impl->swdd~server-provides-monitoring-endpoint~1]
+pub fn bind(
+    address: SocketAddr,
+    metrics: ServerMetrics,
+) -> Result<impl Future<Output = Result<(), String>>, String> {
+    let make_service = make_service_fn(move |_connection| {
+        let metrics = metrics.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let response = handle_request(&request, &metrics);
+                async move { Ok::<_, Infallible>(response) }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&address)
+        .map_err(|error| format!("Could not bind to '{}': '{}'", address, error))?
+        .serve(make_service);
+    Ok(async move { server.await.map_err(|error| error.to_string()) })
+}
+
+// [impl->swdd~server-provides-monitoring-endpoint~1]
+// [impl->swdd~server-reports-readiness~1]
+fn handle_request(request: &Request<Body>, metrics: &ServerMetrics) -> Response<Body> {
+    let path = request.uri().path();
+    if ![METRICS_PATH, HEALTH_PATH, READINESS_PATH].contains(&path) {
+        return text_response(StatusCode::NOT_FOUND, "not found");
+    }
+    if request.method() != Method::GET {
+        return text_response(StatusCode::METHOD_NOT_ALLOWED, "method not allowed");
+    }
+
+    match path {
+        METRICS_PATH => Response::builder()
+            .header(CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)
+            .body(Body::from(metrics.render()))
+            .unwrap_or_else(|_| text_response(StatusCode::INTERNAL_SERVER_ERROR, "error")),
+        READINESS_PATH if !metrics.is_ready() => {
+            text_response(StatusCode::SERVICE_UNAVAILABLE, "not ready")
+        }
+        READINESS_PATH => text_response(StatusCode::OK, "ready"),
+        _ => text_response(StatusCode::OK, "ok"),
+    }
+}
+
+fn text_response(status: StatusCode, text: &'static str) -> Response<Body> {
+    let mut response = Response::new(Body::from(text));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static(TEXT_CONTENT_TYPE),
+    );
+    response
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::handle_request;
+    use crate::ankaios_server::ServerMetrics;
+    use hyper::{body::to_bytes, Body, Method, Request, StatusCode};
+
+    fn request(method: Method, path: &str) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(path)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    // [utest->swdd~server-provides-monitoring-endpoint~1]
+    #[tokio::test]
+    async fn utest_monitoring_endpoint_serves_metrics() {
+        let metrics = ServerMetrics::default();
+        metrics.record_request("RevisionsRequest", std::time::Duration::from_millis(1));
+
+        let response = handle_request(&request(Method::GET, "/metrics"), &metrics);
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["content-type"],
+            "text/plain; version=0.0.4; charset=utf-8"
+        );
+        let body = to_bytes(response.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&body)
+            .contains("ankaios_server_requests_total{request_type=\"RevisionsRequest\"} 1\n"));
+    }
+
+    // [utest->swdd~server-provides-monitoring-endpoint~1]
+    #[test]
+    fn utest_monitoring_endpoint_health_is_always_ok() {
+        let response = handle_request(&request(Method::GET, "/healthz"), &ServerMetrics::default());
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    // [utest->swdd~server-reports-readiness~1]
+    #[test]
+    fn utest_monitoring_endpoint_readiness_follows_server() {
+        let metrics = ServerMetrics::default();
+        assert_eq!(
+            handle_request(&request(Method::GET, "/readyz"), &metrics).status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        metrics.set_ready(true);
+        assert_eq!(
+            handle_request(&request(Method::GET, "/readyz"), &metrics).status(),
+            StatusCode::OK
+        );
+    }
+
+    // [utest->swdd~server-provides-monitoring-endpoint~1]
+    #[test]
+    fn utest_monitoring_endpoint_rejects_unknown_paths_and_methods() {
+        let metrics = ServerMetrics::default();
+
+        assert_eq!(
+            handle_request(&request(Method::GET, "/unknown"), &metrics).status(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            handle_request(&request(Method::POST, "/metrics"), &metrics).status(),
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+    }
+}
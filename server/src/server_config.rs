@@ -14,10 +14,12 @@
 
 use crate::cli::Arguments;
 use common::std_extensions::{UnreachableOption, UnreachableResult};
-use common::DEFAULT_SOCKET_ADDRESS;
+use common::{AGENT_LOAD_STATUS_INTERVAL_MS, DEFAULT_SOCKET_ADDRESS};
+use grpc::cli_roles::CliRole;
 use grpc::security::read_pem_file;
 
 use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
 use std::fmt;
 use std::fs::read_to_string;
 use std::net::SocketAddr;
@@ -25,6 +27,8 @@ use std::path::PathBuf;
 use toml::from_str;
 
 const CONFIG_VERSION: &str = "v1";
+// a single delayed heartbeat must not disconnect an agent
+const MIN_AGENT_HEARTBEAT_TIMEOUT_MS: u64 = 2 * AGENT_LOAD_STATUS_INTERVAL_MS;
 
 pub const DEFAULT_SERVER_CONFIG_FILE_PATH: &str = "/etc/ankaios/ank-server.conf";
 
@@ -77,11 +81,22 @@ where
 pub struct ServerConfig {
     pub version: String,
     pub startup_manifest: Option<String>,
+    pub state_file: Option<String>,
+    #[serde(default)]
+    pub enable_scheduler: bool,
+    pub agent_heartbeat_timeout_ms: Option<u64>,
+    pub monitoring_address: Option<SocketAddr>,
+    pub audit_log_file: Option<String>,
+    pub secrets_key_file: Option<String>,
+    #[serde(default)]
+    pub cli_roles: HashMap<String, CliRole>,
     #[serde(deserialize_with = "convert_to_socket_address")]
     #[serde(default = "get_default_address")]
     pub address: SocketAddr,
     #[serde(default)]
     pub insecure: Option<bool>,
+    #[serde(default)]
+    pub bind_agent_names: bool,
     ca_pem: Option<String>,
     crt_pem: Option<String>,
     key_pem: Option<String>,
@@ -95,8 +110,16 @@ impl Default for ServerConfig {
         ServerConfig {
             version: CONFIG_VERSION.to_string(),
             startup_manifest: None,
+            state_file: None,
+            enable_scheduler: false,
+            agent_heartbeat_timeout_ms: None,
+            monitoring_address: None,
+            audit_log_file: None,
+            secrets_key_file: None,
+            cli_roles: HashMap::new(),
             address: get_default_address(),
             insecure: Some(bool::default()),
+            bind_agent_names: false,
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
@@ -151,6 +174,30 @@ impl ServerConfig {
             self.startup_manifest = Some(path.to_string());
         }
 
+        if let Some(state_file) = &args.state_file {
+            self.state_file = Some(state_file.to_string());
+        }
+
+        if let Some(enable_scheduler) = args.enable_scheduler {
+            self.enable_scheduler = enable_scheduler;
+        }
+
+        if let Some(agent_heartbeat_timeout_ms) = args.agent_heartbeat_timeout_ms {
+            self.agent_heartbeat_timeout_ms = Some(agent_heartbeat_timeout_ms);
+        }
+
+        if let Some(monitoring_address) = args.monitoring_address {
+            self.monitoring_address = Some(monitoring_address);
+        }
+
+        if let Some(audit_log_file) = &args.audit_log_file {
+            self.audit_log_file = Some(audit_log_file.to_string());
+        }
+
+        if let Some(secrets_key_file) = &args.secrets_key_file {
+            self.secrets_key_file = Some(secrets_key_file.to_string());
+        }
+
         if let Some(addr) = &args.addr {
             self.address = *addr;
         }
@@ -159,6 +206,10 @@ impl ServerConfig {
             self.insecure = Some(insecure);
         }
 
+        if let Some(bind_agent_names) = args.bind_agent_names {
+            self.bind_agent_names = bind_agent_names;
+        }
+
         if let Some(ca_pem_path) = &args.ca_pem {
             self.ca_pem = Some(ca_pem_path.to_owned());
             let ca_pem_content = read_pem_file(ca_pem_path, false).unwrap_or_default();
@@ -175,6 +226,19 @@ impl ServerConfig {
             self.key_pem_content = Some(key_pem_content);
         }
     }
+
+    // [impl->swdd~server-rejects-too-short-agent-heartbeat-timeout~1]
+    pub fn verify_agent_heartbeat_timeout(&self) -> Result<(), ConversionErrors> {
+        match self.agent_heartbeat_timeout_ms {
+            Some(timeout_ms) if timeout_ms <= MIN_AGENT_HEARTBEAT_TIMEOUT_MS => {
+                Err(ConversionErrors::InvalidServerConfig(format!(
+                    "agent_heartbeat_timeout_ms must be greater than {}ms, twice the heartbeat interval of the agents, but is {}ms",
+                    MIN_AGENT_HEARTBEAT_TIMEOUT_MS, timeout_ms
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -200,7 +264,11 @@ mod tests {
     use super::DEFAULT_SERVER_CONFIG_FILE_PATH;
 
     const STARTUP_MANIFEST_PATH: &str = "some_path_to_config/config.yaml";
+    const STATE_FILE_PATH: &str = "some_path_to_state/state.yaml";
     const TEST_SOCKET_ADDRESS: &str = "127.0.0.1:3333";
+    const TEST_MONITORING_ADDRESS: &str = "127.0.0.1:9100";
+    const AUDIT_LOG_FILE_PATH: &str = "some_path_to_audit/audit.jsonl";
+    const SECRETS_KEY_FILE_PATH: &str = "some_path_to_key/state.yaml.key";
     const CA_PEM_PATH: &str = "some_path_to_ca_pem/ca.pem";
     const CRT_PEM_PATH: &str = "some_path_to_crt_pem/crt.pem";
     const KEY_PEM_PATH: &str = "some_path_to_key_pem/key.pem";
@@ -268,14 +336,22 @@ mod tests {
     }
 
     // [utest->swdd~server-loads-config-file~1]
+    // [utest->swdd~server-binds-agent-names-to-certificates~1]
     #[test]
     fn utest_server_config_update_with_args() {
         let mut server_config = ServerConfig::default();
         let args = Arguments {
             manifest_path: Some(STARTUP_MANIFEST_PATH.to_string()),
             config_path: Some(DEFAULT_SERVER_CONFIG_FILE_PATH.to_string()),
+            state_file: Some(STATE_FILE_PATH.to_string()),
+            enable_scheduler: Some(true),
+            agent_heartbeat_timeout_ms: Some(10000),
+            monitoring_address: TEST_MONITORING_ADDRESS.parse::<SocketAddr>().ok(),
+            audit_log_file: Some(AUDIT_LOG_FILE_PATH.to_string()),
+            secrets_key_file: Some(SECRETS_KEY_FILE_PATH.to_string()),
             addr: TEST_SOCKET_ADDRESS.parse::<SocketAddr>().ok(),
             insecure: Some(false),
+            bind_agent_names: Some(true),
             ca_pem: Some(CA_PEM_PATH.to_string()),
             crt_pem: Some(CRT_PEM_PATH.to_string()),
             key_pem: Some(KEY_PEM_PATH.to_string()),
@@ -287,11 +363,27 @@ mod tests {
             server_config.startup_manifest,
             Some(STARTUP_MANIFEST_PATH.to_string())
         );
+        assert_eq!(server_config.state_file, Some(STATE_FILE_PATH.to_string()));
+        assert!(server_config.enable_scheduler);
+        assert_eq!(server_config.agent_heartbeat_timeout_ms, Some(10000));
+        assert_eq!(
+            server_config.monitoring_address,
+            TEST_MONITORING_ADDRESS.parse::<SocketAddr>().ok()
+        );
+        assert_eq!(
+            server_config.audit_log_file,
+            Some(AUDIT_LOG_FILE_PATH.to_string())
+        );
+        assert_eq!(
+            server_config.secrets_key_file,
+            Some(SECRETS_KEY_FILE_PATH.to_string())
+        );
         assert_eq!(
             server_config.address,
             TEST_SOCKET_ADDRESS.parse::<SocketAddr>().unwrap()
         );
         assert_eq!(server_config.insecure, Some(false));
+        assert!(server_config.bind_agent_names);
         assert_eq!(server_config.ca_pem, Some(CA_PEM_PATH.to_string()));
         assert_eq!(server_config.crt_pem, Some(CRT_PEM_PATH.to_string()));
         assert_eq!(server_config.key_pem, Some(KEY_PEM_PATH.to_string()));
@@ -318,8 +410,15 @@ mod tests {
         let args = Arguments {
             manifest_path: Some(STARTUP_MANIFEST_PATH.to_string()),
             config_path: Some(DEFAULT_SERVER_CONFIG_FILE_PATH.to_string()),
+            state_file: None,
+            enable_scheduler: None,
+            agent_heartbeat_timeout_ms: None,
+            monitoring_address: None,
+            audit_log_file: None,
+            secrets_key_file: None,
             addr: TEST_SOCKET_ADDRESS.parse::<SocketAddr>().ok(),
             insecure: Some(false),
+            bind_agent_names: None,
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
@@ -348,8 +447,15 @@ mod tests {
             r"#
         version = 'v1'
         startup_manifest = '/workspaces/ankaios/server/resources/startConfig.yaml'
+        state_file = '/var/lib/ankaios/state.yaml'
+        enable_scheduler = true
+        agent_heartbeat_timeout_ms = 6000
+        monitoring_address = '127.0.0.1:9100'
+        audit_log_file = '/var/lib/ankaios/audit.jsonl'
+        secrets_key_file = '/var/lib/ankaios/state.yaml.key'
         address = '127.0.0.1:25551'
         insecure = true
+        bind_agent_names = true
         ca_pem_content = '''{}'''
         crt_pem_content = '''{}'''
         key_pem_content = '''{}'''
@@ -383,9 +489,92 @@ mod tests {
             Some(KEY_PEM_CONTENT.to_string())
         );
         assert_eq!(server_config.insecure, Some(true));
+        assert!(server_config.bind_agent_names);
         assert_eq!(
             server_config.startup_manifest,
             Some("/workspaces/ankaios/server/resources/startConfig.yaml".to_string())
         );
+        assert_eq!(
+            server_config.state_file,
+            Some("/var/lib/ankaios/state.yaml".to_string())
+        );
+        assert!(server_config.enable_scheduler);
+        assert_eq!(server_config.agent_heartbeat_timeout_ms, Some(6000));
+        assert_eq!(
+            server_config.monitoring_address,
+            "127.0.0.1:9100".parse::<SocketAddr>().ok()
+        );
+        assert_eq!(
+            server_config.audit_log_file,
+            Some("/var/lib/ankaios/audit.jsonl".to_string())
+        );
+        assert_eq!(
+            server_config.secrets_key_file,
+            Some("/var/lib/ankaios/state.yaml.key".to_string())
+        );
+    }
+
+    // [utest->swdd~server-loads-config-file~1]
+    // [utest->swdd~server-configures-cli-roles~1]
+    #[test]
+    fn utest_server_config_from_file_with_cli_roles() {
+        let server_config_content = r#"
+        version = 'v1'
+
+        [cli_roles.monitoring]
+        identities = ["prometheus", "CN=grafana"]
+        allowRules = [{ type = "StateRule", operation = "Read", filterMask = ["workloadStates"] }]
+
+        [cli_roles.admin]
+        identities = ["ank"]
+        allowRules = [{ type = "StateRule", operation = "ReadWrite", filterMask = ["*"] }]
+        denyRules = [{ type = "StateRule", operation = "Write", filterMask = ["desiredState.workloads.databroker"] }]
+        "#;
+
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{}", server_config_content).unwrap();
+
+        let server_config = ServerConfig::from_file(PathBuf::from(tmp_config_file.path())).unwrap();
+
+        assert_eq!(server_config.cli_roles.len(), 2);
+        let monitoring = &server_config.cli_roles["monitoring"];
+        assert_eq!(
+            monitoring.identities,
+            vec!["prometheus".to_string(), "CN=grafana".to_string()]
+        );
+        assert_eq!(monitoring.access.allow_rules.len(), 1);
+        assert!(monitoring.access.deny_rules.is_empty());
+        let admin = &server_config.cli_roles["admin"];
+        assert_eq!(admin.identities, vec!["ank".to_string()]);
+        assert_eq!(admin.access.allow_rules.len(), 1);
+        assert_eq!(admin.access.deny_rules.len(), 1);
+    }
+
+    // [utest->swdd~server-rejects-too-short-agent-heartbeat-timeout~1]
+    #[test]
+    fn utest_server_config_verify_agent_heartbeat_timeout() {
+        let server_config_with_timeout = |agent_heartbeat_timeout_ms| ServerConfig {
+            agent_heartbeat_timeout_ms,
+            ..Default::default()
+        };
+
+        assert!(server_config_with_timeout(None)
+            .verify_agent_heartbeat_timeout()
+            .is_ok());
+        assert!(server_config_with_timeout(Some(4001))
+            .verify_agent_heartbeat_timeout()
+            .is_ok());
+        assert!(matches!(
+            server_config_with_timeout(Some(0)).verify_agent_heartbeat_timeout(),
+            Err(ConversionErrors::InvalidServerConfig(_))
+        ));
+        assert!(matches!(
+            server_config_with_timeout(Some(2000)).verify_agent_heartbeat_timeout(),
+            Err(ConversionErrors::InvalidServerConfig(_))
+        ));
+        assert!(matches!(
+            server_config_with_timeout(Some(4000)).verify_agent_heartbeat_timeout(),
+            Err(ConversionErrors::InvalidServerConfig(_))
+        ));
     }
 }
@@ -260,6 +260,7 @@ impl Connection {
                 UpdateStateRequest {
                     new_state: Some(state.into()),
                     update_mask: update_state_command.update_mask,
+                    dry_run: false,
                 }
                 .try_into()
                 .map_err(CommandError::GenericError)?,
@@ -303,6 +304,7 @@ impl Connection {
             request_content: common::commands::RequestContent::CompleteStateRequest(
                 common::commands::CompleteStateRequest {
                     field_mask: get_state_command.field_mask,
+                    label_selector: None,
                 },
             ),
         };